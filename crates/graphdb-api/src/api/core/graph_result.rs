@@ -0,0 +1,298 @@
+//! Graph-shaped query results
+//!
+//! Visualisation clients want the vertices and edges of a result rather than its rows.
+//! [`GraphElements`] collects them from vertex, edge, path and collection values and
+//! de-duplicates them; the transport layers decide how the collected elements are rendered.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::core::types::VertexId;
+use crate::core::{DataSet, Edge, Value, Vertex};
+
+/// Shape of a query response
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultFormat {
+    /// Rows keyed by column name
+    #[default]
+    Table,
+    /// Ordered rows, column types and the de-duplicated nodes and relationships
+    Graph,
+}
+
+impl ResultFormat {
+    /// Parse a format name; an empty name selects the default format
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "" | "table" => Ok(ResultFormat::Table),
+            "graph" => Ok(ResultFormat::Graph),
+            other => Err(format!(
+                "Unknown result format '{}', expected 'table' or 'graph'",
+                other
+            )),
+        }
+    }
+}
+
+/// Identity of an edge: (src, dst, edge_type, ranking)
+type EdgeKey = (VertexId, VertexId, String, i64);
+
+/// De-duplicated vertices and edges of a query result
+///
+/// Elements keep the order in which they first appear. A vertex seen again with more
+/// tags or properties is merged into the first occurrence. The endpoints of every edge
+/// are added as vertices, so each relationship refers to a node of the result.
+#[derive(Debug, Default)]
+pub struct GraphElements {
+    nodes: Vec<Vertex>,
+    node_index: HashMap<VertexId, usize>,
+    relationships: Vec<Edge>,
+    relationship_index: HashMap<EdgeKey, usize>,
+}
+
+impl GraphElements {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the elements of every value of a data set
+    pub fn from_data_set(data_set: &DataSet) -> Self {
+        let mut elements = Self::new();
+        for row in &data_set.rows {
+            for value in row {
+                elements.add_value(value);
+            }
+        }
+        elements
+    }
+
+    /// Collect the vertices and edges contained in a value
+    pub fn add_value(&mut self, value: &Value) {
+        match value {
+            Value::Vertex(vertex) => self.add_vertex(vertex),
+            Value::Edge(edge) => self.add_edge(edge),
+            Value::Path(path) => {
+                self.add_vertex(&path.src);
+                for step in &path.steps {
+                    self.add_vertex(&step.dst);
+                    self.add_edge(&step.edge);
+                }
+            }
+            Value::List(list) => {
+                for item in &list.values {
+                    self.add_value(item);
+                }
+            }
+            Value::Set(set) => {
+                for item in set.iter() {
+                    self.add_value(item);
+                }
+            }
+            Value::Map(map) => {
+                for item in map.values() {
+                    self.add_value(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn add_vertex(&mut self, vertex: &Vertex) {
+        match self.node_index.get(&vertex.vid) {
+            Some(&index) => merge_vertex(&mut self.nodes[index], vertex),
+            None => {
+                self.node_index.insert(vertex.vid, self.nodes.len());
+                self.nodes.push(vertex.clone());
+            }
+        }
+    }
+
+    pub fn add_edge(&mut self, edge: &Edge) {
+        for endpoint in [edge.src, edge.dst] {
+            if !self.node_index.contains_key(&endpoint) {
+                self.add_vertex(&Vertex::with_vid(endpoint));
+            }
+        }
+
+        let key = (edge.src, edge.dst, edge.edge_type.clone(), edge.ranking);
+        match self.relationship_index.get(&key) {
+            Some(&index) => {
+                let existing = &mut self.relationships[index];
+                for (name, value) in &edge.props {
+                    existing
+                        .props
+                        .entry(name.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            }
+            None => {
+                self.relationship_index
+                    .insert(key, self.relationships.len());
+                self.relationships.push(edge.clone());
+            }
+        }
+    }
+
+    pub fn nodes(&self) -> &[Vertex] {
+        &self.nodes
+    }
+
+    pub fn relationships(&self) -> &[Edge] {
+        &self.relationships
+    }
+
+    pub fn into_parts(self) -> (Vec<Vertex>, Vec<Edge>) {
+        (self.nodes, self.relationships)
+    }
+}
+
+/// Identifier of a relationship in a graph-shaped result: `<src>-[<type>@<rank>]-><dst>`
+pub fn relationship_id(edge: &Edge) -> String {
+    format!(
+        "{}-[{}@{}]->{}",
+        edge.src, edge.edge_type, edge.ranking, edge.dst
+    )
+}
+
+/// Add the tags and properties of `other` that `vertex` does not have yet
+fn merge_vertex(vertex: &mut Vertex, other: &Vertex) {
+    for tag in &other.tags {
+        match vertex.tags.iter_mut().find(|t| t.name == tag.name) {
+            Some(existing) => {
+                for (name, value) in &tag.properties {
+                    existing
+                        .properties
+                        .entry(name.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            }
+            None => vertex.tags.push(tag.clone()),
+        }
+    }
+    for (name, value) in &other.properties {
+        vertex
+            .properties
+            .entry(name.clone())
+            .or_insert_with(|| value.clone());
+    }
+}
+
+/// Type name of each column of a data set
+///
+/// A column takes the type of its non-null values, `ANY` when they differ and `NULL`
+/// when the column holds no value.
+pub fn column_types(data_set: &DataSet) -> Vec<String> {
+    (0..data_set.col_names.len())
+        .map(|col| {
+            let mut column_type = None;
+            for value in data_set.rows.iter().filter_map(|row| row.get(col)) {
+                if matches!(value, Value::Null(_) | Value::Empty) {
+                    continue;
+                }
+                let value_type = value.get_type().to_string();
+                match &column_type {
+                    None => column_type = Some(value_type),
+                    Some(existing) if *existing != value_type => return "ANY".to_string(),
+                    Some(_) => {}
+                }
+            }
+            column_type.unwrap_or_else(|| "NULL".to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{NullType, Path, Step, Tag};
+
+    fn person(id: i64, props: &[(&str, Value)]) -> Vertex {
+        let properties = props
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect();
+        Vertex::new(
+            VertexId::from_int64(id),
+            vec![Tag::new("person".to_string(), properties)],
+        )
+    }
+
+    fn knows(src: i64, dst: i64) -> Edge {
+        Edge::new(
+            VertexId::from_int64(src),
+            VertexId::from_int64(dst),
+            "knows".to_string(),
+            0,
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_result_format_parse() {
+        assert_eq!(ResultFormat::parse("").unwrap(), ResultFormat::Table);
+        assert_eq!(ResultFormat::parse("Graph").unwrap(), ResultFormat::Graph);
+        assert!(ResultFormat::parse("tree").is_err());
+    }
+
+    #[test]
+    fn test_graph_elements_deduplicate_across_rows_and_paths() {
+        let mut path = Path::new(person(1, &[]));
+        path.add_step(Step::new_with_edge(person(2, &[]), knows(1, 2)));
+
+        let mut data_set = DataSet::new();
+        data_set.col_names = vec!["p".to_string(), "n".to_string(), "e".to_string()];
+        data_set.rows = vec![
+            vec![
+                Value::Path(Box::new(path)),
+                Value::Vertex(Box::new(person(1, &[("name", Value::from("Alice"))]))),
+                Value::Edge(Box::new(knows(1, 2))),
+            ],
+            vec![
+                Value::Null(NullType::Null),
+                Value::List(Box::new(
+                    vec![Value::Vertex(Box::new(person(2, &[])))].into(),
+                )),
+                Value::Edge(Box::new(knows(2, 3))),
+            ],
+        ];
+
+        let elements = GraphElements::from_data_set(&data_set);
+        let ids: Vec<_> = elements
+            .nodes()
+            .iter()
+            .map(|v| v.vid.as_int64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(elements.relationships().len(), 2);
+
+        // The first occurrence of vertex 1 had no properties; the later one fills them in
+        assert_eq!(
+            elements.nodes()[0].get_property("person", "name"),
+            Some(&Value::from("Alice"))
+        );
+        // Vertex 3 only appears as an edge endpoint
+        assert!(elements.nodes()[2].tags.is_empty());
+    }
+
+    #[test]
+    fn test_column_types() {
+        let mut data_set = DataSet::new();
+        data_set.col_names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        data_set.rows = vec![
+            vec![
+                Value::BigInt(1),
+                Value::Null(NullType::Null),
+                Value::BigInt(1),
+            ],
+            vec![
+                Value::BigInt(2),
+                Value::Null(NullType::Null),
+                Value::from("x"),
+            ],
+        ];
+
+        assert_eq!(column_types(&data_set), vec!["BIGINT", "NULL", "ANY"]);
+    }
+}
@@ -5,6 +5,7 @@
 
 pub mod batch;
 pub mod error;
+pub mod graph_result;
 pub mod query_api;
 pub mod schema_api;
 pub mod sync_api;
@@ -18,6 +19,7 @@ pub use batch::{
     BatchResult,
 };
 pub use error::{CoreError, CoreResult, ExtendedErrorCode};
+pub use graph_result::{column_types, relationship_id, GraphElements, ResultFormat};
 pub use query_api::QueryApi;
 pub use schema_api::SchemaApi;
 pub use sync_api::SyncApi;
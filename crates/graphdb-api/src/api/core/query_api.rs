@@ -180,7 +180,14 @@ impl<S: StorageClient + Clone + 'static> QueryApi<S> {
         let start_time = Instant::now();
 
         // Constructing a QueryRequestContext
-        let rctx = Arc::new(crate::query::QueryRequestContext::new(query.to_string()));
+        let mut rctx = crate::query::QueryRequestContext::new(query.to_string());
+        if let Some(user_name) = ctx.user_name.clone() {
+            rctx = rctx.with_user_name(user_name);
+        }
+        if let Some(role) = ctx.role {
+            rctx = rctx.with_role(role);
+        }
+        let rctx = Arc::new(rctx);
 
         // Build space info from request context if space_id is provided
         let space_info = ctx.space_id.map(|id| {
@@ -217,10 +224,86 @@ impl<S: StorageClient + Clone + 'static> QueryApi<S> {
             auto_commit: ctx.auto_commit,
             transaction_id: ctx.transaction_id,
             parameters: Some(params),
+            user_name: ctx.user_name,
+            role: ctx.role,
         };
         self.execute(query, new_ctx)
     }
 
+    /// Prepare a parameterized query and cache its plan
+    ///
+    /// # Return
+    /// Names of the `$name` placeholders, in order of first appearance
+    pub fn prepare(&mut self, query: &str, ctx: QueryRequest) -> CoreResult<Vec<String>> {
+        let space_info = Self::space_info(&ctx);
+        let positions = self
+            .pipeline_manager
+            .prepare(query, space_info)
+            .map_err(|e| CoreError::QueryExecutionFailed(e.to_string()))?;
+
+        let mut names: Vec<String> = Vec::with_capacity(positions.len());
+        for position in positions {
+            let name = position.name.unwrap_or_else(|| position.index.to_string());
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
+    /// Execute a prepared query, binding `ctx.parameters` to its placeholders
+    ///
+    /// The plan is taken from the plan cache when the query was prepared before.
+    pub fn execute_prepared(&mut self, query: &str, ctx: QueryRequest) -> CoreResult<QueryResult> {
+        let start_time = Instant::now();
+
+        let space_info = Self::space_info(&ctx);
+        let mut rctx = crate::query::QueryRequestContext::new(query.to_string())
+            .with_parameters(ctx.parameters.unwrap_or_default());
+        if let Some(user_name) = ctx.user_name {
+            rctx = rctx.with_user_name(user_name);
+        }
+        if let Some(role) = ctx.role {
+            rctx = rctx.with_role(role);
+        }
+        let rctx = Arc::new(rctx);
+
+        let execution_result = self
+            .pipeline_manager
+            .execute_prepared(query, rctx, space_info)
+            .map_err(|e| CoreError::QueryExecutionFailed(e.to_string()))?;
+
+        let mut result = Self::convert_to_query_result(execution_result)?;
+        result.metadata.execution_time_ms = start_time.elapsed().as_millis() as u64;
+
+        Ok(result)
+    }
+
+    /// Install the transaction control that commits the batches of CALL { } IN TRANSACTIONS
+    pub fn set_batch_transaction_control(
+        &mut self,
+        control: crate::query::executor::control_flow::SharedBatchTransactionControl,
+    ) {
+        self.pipeline_manager.set_batch_transaction_control(control);
+    }
+
+    /// Procedure registry of this database, used to register custom procedures
+    pub fn procedure_registry(
+        &self,
+    ) -> crate::query::executor::expression::functions::SharedProcedureRegistry {
+        self.pipeline_manager.procedure_registry()
+    }
+
+    /// Build space info from the request context if space_id is provided
+    fn space_info(ctx: &QueryRequest) -> Option<crate::core::types::SpaceInfo> {
+        ctx.space_id.map(|id| {
+            let space_name = ctx.space_name.clone().unwrap_or_default();
+            let mut space_info = crate::core::types::SpaceInfo::new(space_name);
+            space_info.space_id = id;
+            space_info
+        })
+    }
+
     /// Convert execution results to structured query results
     fn convert_to_query_result(
         execution: crate::query::executor::base::ExecutionResult,
@@ -2,7 +2,9 @@
 //!
 //! Provides transport layer independent sync system management operations.
 
-use crate::sync::SyncManager;
+#[cfg(feature = "fulltext-search")]
+use crate::api::core::error::{CoreError, CoreResult};
+use crate::sync::{DeadLetterEntry, SyncManager};
 use std::sync::Arc;
 
 /// Sync Management API – Core Layer
@@ -36,6 +38,40 @@ impl SyncApi {
         self.sync_manager.get_unrecovered_dlq_size()
     }
 
+    /// List dead letter queue entries
+    pub fn list_dead_letters(&self) -> Vec<DeadLetterEntry> {
+        self.sync_manager.get_dead_letter_entries()
+    }
+
+    /// Retry a single dead letter entry
+    #[cfg(feature = "fulltext-search")]
+    pub async fn retry_dead_letter(&self, id: u64) -> CoreResult<()> {
+        if self.sync_manager.get_dead_letter_entry(id).is_none() {
+            return Err(CoreError::NotFound(format!(
+                "Dead letter entry {} not found",
+                id
+            )));
+        }
+        self.sync_manager
+            .retry_dead_letter_entry(id)
+            .await
+            .map_err(|e| CoreError::SyncError(e.to_string()))
+    }
+
+    /// Retry every unrecovered dead letter entry
+    #[cfg(feature = "fulltext-search")]
+    pub async fn retry_dead_letters(&self) -> CoreResult<crate::sync::RecoveryResult> {
+        self.sync_manager
+            .retry_dead_letters(None)
+            .await
+            .map_err(|e| CoreError::SyncError(e.to_string()))
+    }
+
+    /// Purge dead letter entries, optionally only the recovered ones
+    pub fn purge_dead_letters(&self, recovered_only: bool) -> usize {
+        self.sync_manager.purge_dead_letters(recovered_only)
+    }
+
     /// Get vector coordinator
     #[cfg(feature = "qdrant")]
     pub fn vector_coordinator(&self) -> Option<&Arc<crate::sync::VectorSyncCoordinator>> {
@@ -36,19 +36,29 @@ impl TransactionApi {
     ///
     /// # Parameters
     /// - `handle`: transaction handle
-    pub fn commit(&self, handle: TransactionHandle) -> CoreResult<()> {
+    /// - `storage`: storage that keeps the unique keys claimed by the transaction
+    pub fn commit(
+        &self,
+        handle: TransactionHandle,
+        storage: &impl graphdb_storage::storage::UndoTarget,
+    ) -> CoreResult<()> {
         self.txn_manager
-            .commit_transaction(handle.0)
+            .commit_transaction_with_undo(handle.0, storage)
             .map_err(|e| CoreError::TransactionFailed(e.to_string()))
     }
 
     /// Rolling back (aborting) transactions
     ///
     /// # Parameters
-    /// `handle`: Transaction handler
-    pub fn rollback(&self, handle: TransactionHandle) -> CoreResult<()> {
+    /// - `handle`: Transaction handler
+    /// - `storage`: reference to storage for undo operations
+    pub fn rollback(
+        &self,
+        handle: TransactionHandle,
+        storage: &impl graphdb_storage::storage::UndoTarget,
+    ) -> CoreResult<()> {
         self.txn_manager
-            .abort_transaction(handle.0)
+            .abort_transaction_with_undo(handle.0, storage)
             .map_err(|e| CoreError::TransactionFailed(e.to_string()))
     }
 
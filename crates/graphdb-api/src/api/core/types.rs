@@ -3,7 +3,7 @@
 //! Business types that are independent of the transport layer
 
 use crate::core::types::TransactionId;
-use crate::core::Value;
+use crate::core::{RoleType, Value};
 use std::collections::HashMap;
 
 /// Query request
@@ -14,6 +14,10 @@ pub struct QueryRequest {
     pub auto_commit: bool,
     pub transaction_id: Option<TransactionId>,
     pub parameters: Option<HashMap<String, Value>>,
+    /// Session user, checked against the fine-grained privileges of custom roles
+    pub user_name: Option<String>,
+    /// Role of the session user in the space, checked against the mode of called procedures
+    pub role: Option<RoleType>,
 }
 
 impl Default for QueryRequest {
@@ -24,6 +28,8 @@ impl Default for QueryRequest {
             auto_commit: true,
             transaction_id: None,
             parameters: None,
+            user_name: None,
+            role: None,
         }
     }
 }
@@ -168,6 +168,10 @@ pub enum graphdb_error_code_t {
     GRAPHDB_RANGE = 21,
     /// Not implemented
     GRAPHDB_NOT_IMPLEMENTED = 22,
+    /// `graphdb_step` has another row ready
+    GRAPHDB_ROW = 100,
+    /// `graphdb_step` has finished executing
+    GRAPHDB_DONE = 101,
 }
 
 /// Converting from core error codes to C error codes and extended error codes
@@ -309,6 +313,8 @@ pub fn error_code_to_message(code: graphdb_error_code_t) -> &'static [u8] {
         graphdb_error_code_t::GRAPHDB_MISUSE => "Misuse\0".as_bytes(),
         graphdb_error_code_t::GRAPHDB_RANGE => "Out of range\0".as_bytes(),
         graphdb_error_code_t::GRAPHDB_NOT_IMPLEMENTED => "Not implemented\0".as_bytes(),
+        graphdb_error_code_t::GRAPHDB_ROW => "Another row available\0".as_bytes(),
+        graphdb_error_code_t::GRAPHDB_DONE => "No more rows available\0".as_bytes(),
     }
 }
 
@@ -421,6 +427,9 @@ pub extern "C" fn graphdb_error_string(code: i32) -> *const std::ffi::c_char {
         19 => graphdb_error_code_t::GRAPHDB_MISMATCH,
         20 => graphdb_error_code_t::GRAPHDB_MISUSE,
         21 => graphdb_error_code_t::GRAPHDB_RANGE,
+        22 => graphdb_error_code_t::GRAPHDB_NOT_IMPLEMENTED,
+        100 => graphdb_error_code_t::GRAPHDB_ROW,
+        101 => graphdb_error_code_t::GRAPHDB_DONE,
         _ => graphdb_error_code_t::GRAPHDB_ERROR,
     };
 
@@ -461,6 +470,9 @@ pub extern "C" fn graphdb_errstr(code: i32) -> *const std::ffi::c_char {
         19 => graphdb_error_code_t::GRAPHDB_MISMATCH,
         20 => graphdb_error_code_t::GRAPHDB_MISUSE,
         21 => graphdb_error_code_t::GRAPHDB_RANGE,
+        22 => graphdb_error_code_t::GRAPHDB_NOT_IMPLEMENTED,
+        100 => graphdb_error_code_t::GRAPHDB_ROW,
+        101 => graphdb_error_code_t::GRAPHDB_DONE,
         _ => graphdb_error_code_t::GRAPHDB_ERROR,
     };
 
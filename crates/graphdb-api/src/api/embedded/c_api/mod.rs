@@ -11,6 +11,7 @@ pub mod function;
 pub mod query;
 pub mod result;
 pub mod session;
+pub mod statement;
 pub mod statistics;
 pub mod transaction;
 pub mod types;
@@ -25,6 +26,7 @@ pub use function::*;
 pub use query::*;
 pub use result::*;
 pub use session::*;
+pub use statement::*;
 pub use statistics::*;
 pub use transaction::*;
 pub use types::*;
@@ -202,7 +202,7 @@ pub unsafe fn convert_c_value_to_rust(c_value: &graphdb_value_t) -> Value {
 ///
 /// Return a tuple of (operation type, row ID). If it is not a data modification operation, return None.
 /// Operation type: 1=INSERT, 2=UPDATE, 3=DELETE
-fn detect_data_modification(
+pub(crate) fn detect_data_modification(
     query: &str,
     _result: &crate::api::embedded::result::QueryResult,
 ) -> Option<(i32, i64)> {
@@ -3,7 +3,8 @@
 //! Provide processing functions for query results
 
 use crate::api::embedded::c_api::error::graphdb_error_code_t;
-use crate::api::embedded::c_api::types::graphdb_result_t;
+use crate::api::embedded::c_api::types::{graphdb_result_t, graphdb_value_handle_t};
+use crate::api::embedded::c_api::value::write_value_handle;
 use crate::api::embedded::result::QueryResult;
 use std::ffi::{c_char, c_int, CStr, CString};
 use std::ptr;
@@ -614,6 +615,58 @@ pub unsafe extern "C" fn graphdb_get_blob_by_index(
     }
 }
 
+/// Get a value handle (indexed by column)
+///
+/// Use this for composite values (vertices, edges, paths, lists, maps, vectors and
+/// temporal values), which are navigated with the `graphdb_value_*` family of functions.
+///
+/// # Arguments
+/// - `result`: Result set handle
+/// - `row`: Row index (starting from 0)
+/// - `col`: Column index (starting from 0)
+/// - `out`: Output parameter, value handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Memory Management
+/// The handle stored in `out` is an independent copy owned by the caller. It stays valid
+/// after the result set is freed and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+/// - `out` must be a valid pointer to store the handle
+/// - `row` must be a valid row index (0 <= row < row count)
+/// - `col` must be a valid column index (0 <= col < column count)
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_get_value_by_index(
+    result: *mut graphdb_result_t,
+    row: c_int,
+    col: c_int,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if result.is_null() || out.is_null() || col < 0 || row < 0 {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &*(result as *mut GraphDbResultHandle);
+
+    let columns = handle.inner.columns();
+    let col_name = match columns.get(col as usize) {
+        Some(name) => name.as_str(),
+        None => return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    };
+
+    match handle.inner.get(row as usize) {
+        Some(row_data) => match row_data.get(col_name) {
+            Some(v) => write_value_handle(v.clone(), out),
+            None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+        },
+        None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+}
+
 /// Get column type
 ///
 /// # Arguments
@@ -649,20 +702,7 @@ pub unsafe extern "C" fn graphdb_column_type(
             };
 
             match row.get(col_name) {
-                Some(value) => match value {
-                    crate::core::Value::Null(_) => graphdb_value_type_t::GRAPHDB_NULL,
-                    crate::core::Value::Bool(_) => graphdb_value_type_t::GRAPHDB_BOOL,
-                    crate::core::Value::Int(_) => graphdb_value_type_t::GRAPHDB_INT,
-                    crate::core::Value::Float(_) => graphdb_value_type_t::GRAPHDB_FLOAT,
-                    crate::core::Value::String(_) => graphdb_value_type_t::GRAPHDB_STRING,
-                    crate::core::Value::Blob(_) => graphdb_value_type_t::GRAPHDB_BLOB,
-                    crate::core::Value::List(_) => graphdb_value_type_t::GRAPHDB_LIST,
-                    crate::core::Value::Map(_) => graphdb_value_type_t::GRAPHDB_MAP,
-                    crate::core::Value::Vertex(_) => graphdb_value_type_t::GRAPHDB_VERTEX,
-                    crate::core::Value::Edge(_) => graphdb_value_type_t::GRAPHDB_EDGE,
-                    crate::core::Value::Path(_) => graphdb_value_type_t::GRAPHDB_PATH,
-                    _ => graphdb_value_type_t::GRAPHDB_NULL,
-                },
+                Some(value) => crate::api::embedded::c_api::value::value_type_of(value),
                 None => graphdb_value_type_t::GRAPHDB_NULL,
             }
         }
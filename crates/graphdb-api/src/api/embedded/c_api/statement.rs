@@ -0,0 +1,1040 @@
+//! C API Prepared Statement Module
+//!
+//! Provides a prepare/bind/step lifecycle for parameterized queries. A statement is
+//! planned once by `graphdb_prepare` and its plan is kept in the query plan cache, so
+//! executing it again with new bindings skips parsing, validation and planning.
+
+use crate::api::embedded::c_api::error::{
+    error_code_from_core_error, extended_error_code_from_core_error, graphdb_error_code_t,
+};
+use crate::api::embedded::c_api::query::detect_data_modification;
+use crate::api::embedded::c_api::session::GraphDbSessionHandle;
+use crate::api::embedded::c_api::types::{
+    graphdb_session_t, graphdb_stmt_t, graphdb_value_handle_t, graphdb_value_type_t,
+};
+use crate::api::embedded::c_api::value::{value_type_of, write_value_handle};
+use crate::api::embedded::result::QueryResult;
+use crate::core::value::NullType;
+use crate::core::Value;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+/// Execution state of a prepared statement
+enum StmtState {
+    /// Not executed since it was prepared or reset
+    Ready,
+    /// Executed; `next` is the index of the row the following step will return
+    Rows { result: QueryResult, next: usize },
+    /// All rows have been returned
+    Done,
+}
+
+/// Internal structure of prepared statement handles
+pub struct GraphDbStmtHandle {
+    pub(crate) session: *mut graphdb_session_t,
+    pub(crate) query: String,
+    /// Placeholder names in order of first appearance; index `i` binds `names[i - 1]`
+    pub(crate) param_names: Vec<String>,
+    pub(crate) bindings: HashMap<String, Value>,
+    state: StmtState,
+}
+
+impl GraphDbStmtHandle {
+    /// Row returned by the last successful `graphdb_step`
+    fn current_value(&self, col: c_int) -> Option<&Value> {
+        if col < 0 {
+            return None;
+        }
+        match &self.state {
+            StmtState::Rows { result, next } if *next > 0 => {
+                let col_name = result.columns().get(col as usize)?;
+                result.get(*next - 1)?.get(col_name)
+            }
+            _ => None,
+        }
+    }
+
+    fn columns(&self) -> &[String] {
+        match &self.state {
+            StmtState::Rows { result, .. } => result.columns(),
+            _ => &[],
+        }
+    }
+
+    fn bind(&mut self, name: String, value: Value) -> c_int {
+        if !matches!(self.state, StmtState::Ready) {
+            // Like SQLite, bindings can only change before the first step or after a reset
+            return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+        }
+        self.bindings.insert(name, value);
+        graphdb_error_code_t::GRAPHDB_OK as c_int
+    }
+
+    fn bind_by_index(&mut self, index: c_int, value: Value) -> c_int {
+        if index < 1 || index as usize > self.param_names.len() {
+            return graphdb_error_code_t::GRAPHDB_RANGE as c_int;
+        }
+        let name = self.param_names[index as usize - 1].clone();
+        self.bind(name, value)
+    }
+
+    unsafe fn bind_by_name(&mut self, name: *const c_char, value: Value) -> c_int {
+        let name = match CStr::from_ptr(name).to_str() {
+            Ok(s) => s.trim_start_matches('$'),
+            Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+        };
+        if !self.param_names.iter().any(|n| n == name) {
+            return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int;
+        }
+        self.bind(name.to_string(), value)
+    }
+}
+
+/// Prepare a parameterized query
+///
+/// Placeholders are written as `$name`. The query is parsed, validated and planned
+/// immediately, so syntax and semantic errors are reported here rather than by
+/// `graphdb_step`.
+///
+/// # Arguments
+/// - `session`: Session handle
+/// - `query`: Query statement (UTF-8 encoded)
+/// - `stmt`: Output parameter, prepared statement handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `session` must be a valid session handle created by `graphdb_session_create`
+/// - `query` must be a valid pointer to a null-terminated UTF-8 string
+/// - `stmt` must be a valid pointer to store the statement handle
+/// - The statement must be released with `graphdb_finalize` before the session is closed
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_prepare(
+    session: *mut graphdb_session_t,
+    query: *const c_char,
+    stmt: *mut *mut graphdb_stmt_t,
+) -> c_int {
+    if session.is_null() || query.is_null() || stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let query_str = match CStr::from_ptr(query).to_str() {
+        Ok(s) => s,
+        Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    let handle = &mut *(session as *mut GraphDbSessionHandle);
+
+    match handle.inner.prepare(query_str) {
+        Ok(param_names) => {
+            handle.clear_error();
+            let stmt_handle = Box::new(GraphDbStmtHandle {
+                session,
+                query: query_str.to_string(),
+                param_names,
+                bindings: HashMap::new(),
+                state: StmtState::Ready,
+            });
+            *stmt = Box::into_raw(stmt_handle) as *mut graphdb_stmt_t;
+            graphdb_error_code_t::GRAPHDB_OK as c_int
+        }
+        Err(e) => {
+            let (error_code, _) = error_code_from_core_error(&e);
+            let offset = e.error_offset();
+            let extended_code = Some(extended_error_code_from_core_error(&e));
+            handle.set_error(format!("{}", e), offset, extended_code);
+            *stmt = ptr::null_mut();
+            error_code
+        }
+    }
+}
+
+/// Get the number of distinct placeholders in a prepared statement
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - Number of placeholders, returns -1 on error
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_parameter_count(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return -1;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    handle.param_names.len() as c_int
+}
+
+/// Get the index of a named placeholder
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+///
+/// # Returns
+/// - Index of the placeholder (starting from 1), returns 0 if there is no such placeholder
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_parameter_index(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+) -> c_int {
+    if stmt.is_null() || name.is_null() {
+        return 0;
+    }
+
+    let name_str = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s.trim_start_matches('$'),
+        Err(_) => return 0,
+    };
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    handle
+        .param_names
+        .iter()
+        .position(|n| n == name_str)
+        .map(|i| i as c_int + 1)
+        .unwrap_or(0)
+}
+
+/// Bind NULL to a placeholder (by name)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the statement has no such placeholder
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_null(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+) -> c_int {
+    if stmt.is_null() || name.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_name(name, Value::Null(NullType::Null))
+}
+
+/// Bind a boolean value to a placeholder (by name)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+/// - `value`: Boolean value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the statement has no such placeholder
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_bool(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+    value: bool,
+) -> c_int {
+    if stmt.is_null() || name.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_name(name, Value::Bool(value))
+}
+
+/// Bind an integer value to a placeholder (by name)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+/// - `value`: Integer value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the statement has no such placeholder
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_int(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+    value: i64,
+) -> c_int {
+    if stmt.is_null() || name.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_name(name, int_value(value))
+}
+
+/// Bind a floating-point value to a placeholder (by name)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+/// - `value`: Floating-point value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the statement has no such placeholder
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_float(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+    value: f64,
+) -> c_int {
+    if stmt.is_null() || name.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_name(name, Value::Double(value))
+}
+
+/// Bind a string value to a placeholder (by name)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `name`: Placeholder name, with or without the leading `$`
+/// - `value`: String value (UTF-8 encoded)
+/// - `len`: Length of `value` in bytes, or -1 if it is null-terminated
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the statement has no such placeholder
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+/// - `value` must point to at least `len` bytes, or to a null-terminated string if `len` is negative
+/// - The string is copied; the caller keeps ownership of `value`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_string(
+    stmt: *mut graphdb_stmt_t,
+    name: *const c_char,
+    value: *const c_char,
+    len: c_int,
+) -> c_int {
+    if stmt.is_null() || name.is_null() || value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let value = match string_value(value, len) {
+        Some(v) => v,
+        None => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_name(name, value)
+}
+
+/// Bind NULL to a placeholder (by index)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `index`: Placeholder index (starting from 1)
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_null_by_index(
+    stmt: *mut graphdb_stmt_t,
+    index: c_int,
+) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_index(index, Value::Null(NullType::Null))
+}
+
+/// Bind a boolean value to a placeholder (by index)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `index`: Placeholder index (starting from 1)
+/// - `value`: Boolean value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_bool_by_index(
+    stmt: *mut graphdb_stmt_t,
+    index: c_int,
+    value: bool,
+) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_index(index, Value::Bool(value))
+}
+
+/// Bind an integer value to a placeholder (by index)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `index`: Placeholder index (starting from 1)
+/// - `value`: Integer value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_int_by_index(
+    stmt: *mut graphdb_stmt_t,
+    index: c_int,
+    value: i64,
+) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_index(index, int_value(value))
+}
+
+/// Bind a floating-point value to a placeholder (by index)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `index`: Placeholder index (starting from 1)
+/// - `value`: Floating-point value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_float_by_index(
+    stmt: *mut graphdb_stmt_t,
+    index: c_int,
+    value: f64,
+) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_index(index, Value::Double(value))
+}
+
+/// Bind a string value to a placeholder (by index)
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `index`: Placeholder index (starting from 1)
+/// - `value`: String value (UTF-8 encoded)
+/// - `len`: Length of `value` in bytes, or -1 if it is null-terminated
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `value` must point to at least `len` bytes, or to a null-terminated string if `len` is negative
+/// - The string is copied; the caller keeps ownership of `value`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_bind_string_by_index(
+    stmt: *mut graphdb_stmt_t,
+    index: c_int,
+    value: *const c_char,
+    len: c_int,
+) -> c_int {
+    if stmt.is_null() || value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let value = match string_value(value, len) {
+        Some(v) => v,
+        None => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.bind_by_index(index, value)
+}
+
+/// Remove all bound values
+///
+/// Unbound placeholders evaluate to NULL.
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISUSE if the statement has been stepped and not reset
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_clear_bindings(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    if !matches!(handle.state, StmtState::Ready) {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+    handle.bindings.clear();
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Advance a prepared statement to its next row
+///
+/// The first step after `graphdb_prepare` or `graphdb_reset` executes the statement
+/// with the current bindings. Stepping a statement that has returned GRAPHDB_DONE
+/// resets it and executes it again.
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - GRAPHDB_ROW: a row is available through the `graphdb_stmt_*` column accessors
+/// - GRAPHDB_DONE: the statement has finished executing
+/// - Failure: Error code; the message is available through the session's error functions
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - The session the statement was prepared on must still be open
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_step(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+
+    if !matches!(handle.state, StmtState::Rows { .. }) {
+        let session = &mut *(handle.session as *mut GraphDbSessionHandle);
+        session.trace(&handle.query);
+
+        let mut params = handle.bindings.clone();
+        for name in &handle.param_names {
+            params
+                .entry(name.clone())
+                .or_insert(Value::Null(NullType::Null));
+        }
+
+        match session.inner.execute_prepared(&handle.query, params) {
+            Ok(result) => {
+                session.clear_error();
+
+                if let Some((operation, rowid)) = detect_data_modification(&handle.query, &result) {
+                    let space_name_owned = session.inner.current_space();
+                    let space_name = space_name_owned.as_deref().unwrap_or("default");
+                    session.invoke_update_hook(operation, space_name, rowid);
+                }
+
+                handle.state = StmtState::Rows { result, next: 0 };
+            }
+            Err(e) => {
+                let (error_code, _) = error_code_from_core_error(&e);
+                let offset = e.error_offset();
+                let extended_code = Some(extended_error_code_from_core_error(&e));
+                session.set_error(format!("{}", e), offset, extended_code);
+                handle.state = StmtState::Ready;
+                return error_code;
+            }
+        }
+    }
+
+    if let StmtState::Rows { result, next } = &mut handle.state {
+        if *next < result.len() {
+            *next += 1;
+            return graphdb_error_code_t::GRAPHDB_ROW as c_int;
+        }
+    }
+
+    handle.state = StmtState::Done;
+    graphdb_error_code_t::GRAPHDB_DONE as c_int
+}
+
+/// Reset a prepared statement so it can be executed again
+///
+/// Bindings are kept; use `graphdb_clear_bindings` to remove them.
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_reset(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &mut *(stmt as *mut GraphDbStmtHandle);
+    handle.state = StmtState::Ready;
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Release a prepared statement
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - After calling this function, the statement handle becomes invalid and must not be used
+/// - Any string pointers obtained from the statement's column accessors remain valid
+///   until freed with `graphdb_free_string`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_finalize(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let _ = Box::from_raw(stmt as *mut GraphDbStmtHandle);
+
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get the number of columns returned by a stepped statement
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+///
+/// # Returns
+/// - Number of columns, 0 if the statement has not been stepped, returns -1 on error
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_column_count(stmt: *mut graphdb_stmt_t) -> c_int {
+    if stmt.is_null() {
+        return -1;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    handle.columns().len() as c_int
+}
+
+/// Get the column name of a stepped statement
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+///
+/// # Returns
+/// - Column name (UTF-8 encoded), returns NULL on error
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - The returned pointer must be freed by the caller to avoid memory leaks
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_column_name(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+) -> *mut c_char {
+    if stmt.is_null() || col < 0 {
+        return ptr::null_mut();
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.columns().get(col as usize) {
+        Some(name) => match CString::new(name.as_str()) {
+            Ok(c_str) => c_str.into_raw(),
+            Err(_) => ptr::null_mut(),
+        },
+        None => ptr::null_mut(),
+    }
+}
+
+/// Get the value type of a column in the current row
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+///
+/// # Returns
+/// - Column type, returns GRAPHDB_NULL on error
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_column_type(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+) -> graphdb_value_type_t {
+    if stmt.is_null() {
+        return graphdb_value_type_t::GRAPHDB_NULL;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(value) => value_type_of(value),
+        None => graphdb_value_type_t::GRAPHDB_NULL,
+    }
+}
+
+/// Get an integer value from the current row
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+/// - `value`: Output parameter, integer value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `value` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_get_int(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+    value: *mut i64,
+) -> c_int {
+    if stmt.is_null() || value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(Value::SmallInt(i)) => *value = *i as i64,
+        Some(Value::Int(i)) => *value = *i as i64,
+        Some(Value::BigInt(i)) => *value = *i,
+        Some(_) => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+        None => return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a floating-point value from the current row
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+/// - `value`: Output parameter, floating-point value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `value` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_get_float(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+    value: *mut f64,
+) -> c_int {
+    if stmt.is_null() || value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(Value::Float(f)) => *value = *f as f64,
+        Some(Value::Double(f)) => *value = *f,
+        Some(_) => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+        None => return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a boolean value from the current row
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+/// - `value`: Output parameter, boolean value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `value` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_get_bool(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+    value: *mut bool,
+) -> c_int {
+    if stmt.is_null() || value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(Value::Bool(b)) => *value = *b,
+        Some(_) => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+        None => return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a string value from the current row
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+/// - `len`: Output parameter, string length
+///
+/// # Returns
+/// - String value (UTF-8 encoded), returns NULL on error
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `len` must be a valid pointer to store the string length, or NULL if not needed
+/// - The returned pointer must be freed by the caller to avoid memory leaks
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_get_string(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+    len: *mut c_int,
+) -> *mut c_char {
+    if !len.is_null() {
+        *len = -1;
+    }
+    if stmt.is_null() {
+        return ptr::null_mut();
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(Value::String(s)) => match CString::new(s.as_str()) {
+            Ok(c_str) => {
+                if !len.is_null() {
+                    *len = s.len() as c_int;
+                }
+                c_str.into_raw()
+            }
+            Err(_) => ptr::null_mut(),
+        },
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Get a value handle from the current row
+///
+/// Use this for composite values (vertices, edges, paths, lists, maps, vectors and
+/// temporal values), which are navigated with the `graphdb_value_*` family of functions.
+///
+/// # Arguments
+/// - `stmt`: Statement handle
+/// - `col`: Column index (starting from 0)
+/// - `out`: Output parameter, value handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Memory Management
+/// The handle stored in `out` is an independent copy owned by the caller. It stays valid
+/// after the statement steps, resets or is finalized, and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `stmt` must be a valid statement handle created by `graphdb_prepare`
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_stmt_get_value(
+    stmt: *mut graphdb_stmt_t,
+    col: c_int,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if stmt.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let handle = &*(stmt as *mut GraphDbStmtHandle);
+    match handle.current_value(col) {
+        Some(v) => write_value_handle(v.clone(), out),
+        None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+}
+
+/// Integers that fit are bound as `Int`, matching what literals in a query produce
+fn int_value(value: i64) -> Value {
+    match i32::try_from(value) {
+        Ok(v) => Value::Int(v),
+        Err(_) => Value::BigInt(value),
+    }
+}
+
+/// Copy a C string into a `Value::String`, returning None for invalid UTF-8
+unsafe fn string_value(value: *const c_char, len: c_int) -> Option<Value> {
+    let bytes = if len < 0 {
+        CStr::from_ptr(value).to_bytes()
+    } else {
+        std::slice::from_raw_parts(value as *const u8, len as usize)
+    };
+    std::str::from_utf8(bytes)
+        .ok()
+        .map(|s| Value::String(s.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::embedded::c_api::database::{graphdb_close, graphdb_open};
+    use crate::api::embedded::c_api::session::{graphdb_session_close, graphdb_session_create};
+    use crate::api::embedded::c_api::types::graphdb_t;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    fn create_test_db() -> *mut graphdb_t {
+        let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let temp_dir = std::env::temp_dir().join("graphdb_c_api_stmt_test");
+        std::fs::create_dir_all(&temp_dir).ok();
+        let db_path = temp_dir.join(format!("test_{}_{}.db", std::process::id(), counter));
+
+        let path_cstring = CString::new(db_path.to_str().expect("Invalid path"))
+            .expect("Failed to create CString");
+        let mut db: *mut graphdb_t = ptr::null_mut();
+
+        let rc = unsafe { graphdb_open(path_cstring.as_ptr(), &mut db) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+        assert!(!db.is_null());
+
+        db
+    }
+
+    #[test]
+    fn test_stmt_null_params() {
+        let mut stmt: *mut graphdb_stmt_t = ptr::null_mut();
+        let rc = unsafe { graphdb_prepare(ptr::null_mut(), ptr::null(), &mut stmt) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_MISUSE as c_int);
+
+        let rc = unsafe { graphdb_step(ptr::null_mut()) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_MISUSE as c_int);
+
+        let rc = unsafe { graphdb_bind_int_by_index(ptr::null_mut(), 1, 1) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_MISUSE as c_int);
+
+        let rc = unsafe { graphdb_finalize(ptr::null_mut()) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_MISUSE as c_int);
+
+        assert_eq!(unsafe { graphdb_bind_parameter_count(ptr::null_mut()) }, -1);
+    }
+
+    #[test]
+    fn test_prepare_bind_step() {
+        let db = create_test_db();
+        let mut session: *mut graphdb_session_t = ptr::null_mut();
+        let rc = unsafe { graphdb_session_create(db, &mut session) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+
+        let query = CString::new("RETURN $a + $b AS sum, $a AS a").expect("Failed to create query");
+        let mut stmt: *mut graphdb_stmt_t = ptr::null_mut();
+        let rc = unsafe { graphdb_prepare(session, query.as_ptr(), &mut stmt) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+        assert!(!stmt.is_null());
+
+        assert_eq!(unsafe { graphdb_bind_parameter_count(stmt) }, 2);
+        let name_b = CString::new("$b").expect("Failed to create name");
+        assert_eq!(
+            unsafe { graphdb_bind_parameter_index(stmt, name_b.as_ptr()) },
+            2
+        );
+
+        for (a, b) in [(1i64, 2i64), (10, 20)] {
+            let rc = unsafe { graphdb_bind_int_by_index(stmt, 1, a) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+            let rc = unsafe { graphdb_bind_int(stmt, name_b.as_ptr(), b) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+
+            let rc = unsafe { graphdb_step(stmt) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_ROW as c_int);
+
+            let mut sum = 0i64;
+            let rc = unsafe { graphdb_stmt_get_int(stmt, 0, &mut sum) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+            assert_eq!(sum, a + b);
+
+            // Bindings cannot change while the statement is being stepped
+            let rc = unsafe { graphdb_bind_int_by_index(stmt, 1, 0) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_MISUSE as c_int);
+
+            let rc = unsafe { graphdb_step(stmt) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_DONE as c_int);
+
+            let rc = unsafe { graphdb_reset(stmt) };
+            assert_eq!(rc, graphdb_error_code_t::GRAPHDB_OK as c_int);
+        }
+
+        let rc = unsafe { graphdb_bind_int_by_index(stmt, 3, 0) };
+        assert_eq!(rc, graphdb_error_code_t::GRAPHDB_RANGE as c_int);
+
+        unsafe { graphdb_finalize(stmt) };
+        unsafe { graphdb_session_close(session) };
+        unsafe { graphdb_close(db) };
+    }
+}
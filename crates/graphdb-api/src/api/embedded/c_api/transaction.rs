@@ -210,6 +210,8 @@ pub unsafe extern "C" fn graphdb_txn_execute(
         auto_commit: false,
         transaction_id: Some(txn_handle.0),
         parameters: None,
+        user_name: None,
+        role: None,
     };
 
     let mut query_api = session.inner.query_api_mut();
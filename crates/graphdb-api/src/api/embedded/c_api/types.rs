@@ -30,6 +30,14 @@ pub struct graphdb_result_t;
 #[repr(C)]
 pub struct graphdb_batch_t;
 
+/// Prepared statement handle (opaque pointer)
+#[repr(C)]
+pub struct graphdb_stmt_t;
+
+/// Value handle (opaque pointer)
+#[repr(C)]
+pub struct graphdb_value_handle_t;
+
 /// Database Configuration
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
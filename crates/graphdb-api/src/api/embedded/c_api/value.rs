@@ -1,5 +1,31 @@
-use crate::api::embedded::c_api::types::graphdb_value_t;
+//! C API Value Module
+//!
+//! Provides opaque handles for composite values (vertices, edges, paths, lists,
+//! maps, vectors and temporal values) together with type inspection and
+//! navigation functions.
+//!
+//! # Ownership
+//! Every function that hands out a `graphdb_value_handle_t` transfers ownership of
+//! an independent copy to the caller, who must release it with `graphdb_value_free`.
+//! Child handles obtained by navigation (a vertex property, a list element, a path
+//! step) do not borrow from their parent, so handles may be freed in any order.
+//! Strings are returned as newly allocated buffers that must be released with
+//! `graphdb_free_string`.
+
+use crate::api::embedded::c_api::error::graphdb_error_code_t;
+use crate::api::embedded::c_api::types::{
+    graphdb_value_handle_t, graphdb_value_t, graphdb_value_type_t,
+};
+use crate::core::types::VertexId;
 use crate::core::Value;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+/// Internal structure of value handles
+pub struct GraphDbValueHandle {
+    pub(crate) inner: Value,
+}
 
 /// Convert a C value to a core Value.
 ///
@@ -8,3 +34,1199 @@ use crate::core::Value;
 pub unsafe fn graphdb_value_to_core(value: *const graphdb_value_t) -> Value {
     super::query::convert_c_value_to_rust(&*value)
 }
+
+/// Map a core value onto the C value type enumeration
+pub(crate) fn value_type_of(value: &Value) -> graphdb_value_type_t {
+    match value {
+        Value::Bool(_) => graphdb_value_type_t::GRAPHDB_BOOL,
+        Value::SmallInt(_) | Value::Int(_) | Value::BigInt(_) => graphdb_value_type_t::GRAPHDB_INT,
+        Value::Float(_) | Value::Double(_) => graphdb_value_type_t::GRAPHDB_FLOAT,
+        Value::String(_) | Value::FixedString { .. } => graphdb_value_type_t::GRAPHDB_STRING,
+        Value::Blob(_) => graphdb_value_type_t::GRAPHDB_BLOB,
+        Value::List(_) | Value::Set(_) => graphdb_value_type_t::GRAPHDB_LIST,
+        Value::Map(_) => graphdb_value_type_t::GRAPHDB_MAP,
+        Value::Vertex(_) => graphdb_value_type_t::GRAPHDB_VERTEX,
+        Value::Edge(_) => graphdb_value_type_t::GRAPHDB_EDGE,
+        Value::Path(_) => graphdb_value_type_t::GRAPHDB_PATH,
+        Value::Vector(_) => graphdb_value_type_t::GRAPHDB_VECTOR,
+        Value::Date(_) => graphdb_value_type_t::GRAPHDB_DATE,
+        Value::Time(_) => graphdb_value_type_t::GRAPHDB_TIME,
+        Value::DateTime(_) => graphdb_value_type_t::GRAPHDB_DATETIME,
+        Value::Interval(_) => graphdb_value_type_t::GRAPHDB_DURATION,
+        _ => graphdb_value_type_t::GRAPHDB_NULL,
+    }
+}
+
+/// Hand a copy of `value` to the caller through `out`
+pub(crate) unsafe fn write_value_handle(
+    value: Value,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    let handle = Box::new(GraphDbValueHandle { inner: value });
+    *out = Box::into_raw(handle) as *mut graphdb_value_handle_t;
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Vertex IDs are exposed as integers when they were created from one, otherwise as strings
+fn vertex_id_value(vid: &VertexId) -> Value {
+    if let Some(id) = vid.as_int64() {
+        Value::BigInt(id)
+    } else if let Some(s) = vid.as_str() {
+        Value::String(s.to_string())
+    } else {
+        Value::Blob(vid.as_bytes().to_vec())
+    }
+}
+
+fn new_c_string(s: &str) -> *mut c_char {
+    match CString::new(s) {
+        Ok(c_str) => c_str.into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+unsafe fn handle_ref<'a>(value: *mut graphdb_value_handle_t) -> &'a Value {
+    &(*(value as *mut GraphDbValueHandle)).inner
+}
+
+/// Release a value handle
+///
+/// # Arguments
+/// - `value`: Value handle
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - Failure: Error code
+///
+/// # Safety
+/// - `value` must be a valid value handle returned by a GraphDB C API function
+/// - After calling this function, the handle becomes invalid and must not be used
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_free(value: *mut graphdb_value_handle_t) -> c_int {
+    if value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let _ = Box::from_raw(value as *mut GraphDbValueHandle);
+
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get the type of a value
+///
+/// # Arguments
+/// - `value`: Value handle
+///
+/// # Returns
+/// - Value type, returns GRAPHDB_NULL on error
+///
+/// # Safety
+/// - `value` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_type(
+    value: *mut graphdb_value_handle_t,
+) -> graphdb_value_type_t {
+    if value.is_null() {
+        return graphdb_value_type_t::GRAPHDB_NULL;
+    }
+
+    value_type_of(handle_ref(value))
+}
+
+/// Get an integer from a value handle
+///
+/// # Arguments
+/// - `value`: Value handle
+/// - `out`: Output parameter, integer value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not an integer
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_int(
+    value: *mut graphdb_value_handle_t,
+    out: *mut i64,
+) -> c_int {
+    if value.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(value) {
+        Value::SmallInt(i) => *out = *i as i64,
+        Value::Int(i) => *out = *i as i64,
+        Value::BigInt(i) => *out = *i,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a floating-point number from a value handle
+///
+/// # Arguments
+/// - `value`: Value handle
+/// - `out`: Output parameter, floating-point value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a floating-point number
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_float(
+    value: *mut graphdb_value_handle_t,
+    out: *mut f64,
+) -> c_int {
+    if value.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(value) {
+        Value::Float(f) => *out = *f as f64,
+        Value::Double(f) => *out = *f,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a boolean from a value handle
+///
+/// # Arguments
+/// - `value`: Value handle
+/// - `out`: Output parameter, boolean value
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a boolean
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_bool(
+    value: *mut graphdb_value_handle_t,
+    out: *mut bool,
+) -> c_int {
+    if value.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(value) {
+        Value::Bool(b) => *out = *b,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Get a string from a value handle
+///
+/// # Arguments
+/// - `value`: Value handle
+/// - `len`: Output parameter, string length
+///
+/// # Returns
+/// - String value (UTF-8 encoded), returns NULL if the value is not a string
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - `len` must be a valid pointer to store the string length, or NULL if not needed
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_string(
+    value: *mut graphdb_value_handle_t,
+    len: *mut c_int,
+) -> *mut c_char {
+    if !len.is_null() {
+        *len = -1;
+    }
+    if value.is_null() {
+        return ptr::null_mut();
+    }
+
+    let s = match handle_ref(value) {
+        Value::String(s) => s.as_str(),
+        Value::FixedString { data, .. } => data.as_str(),
+        _ => return ptr::null_mut(),
+    };
+    let c_str = new_c_string(s);
+    if !c_str.is_null() && !len.is_null() {
+        *len = s.len() as c_int;
+    }
+    c_str
+}
+
+/// Get the ID of a vertex
+///
+/// The ID is an integer value when the vertex was created with an integer ID,
+/// otherwise a string value.
+///
+/// # Arguments
+/// - `vertex`: Value handle holding a vertex
+/// - `out`: Output parameter, value handle of the ID
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a vertex
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `vertex` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vertex_id(
+    vertex: *mut graphdb_value_handle_t,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if vertex.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(vertex) {
+        Value::Vertex(v) => write_value_handle(vertex_id_value(&v.vid), out),
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get the number of tags on a vertex
+///
+/// # Arguments
+/// - `vertex`: Value handle holding a vertex
+///
+/// # Returns
+/// - Number of tags, returns -1 on error
+///
+/// # Safety
+/// - `vertex` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vertex_tag_count(vertex: *mut graphdb_value_handle_t) -> c_int {
+    if vertex.is_null() {
+        return -1;
+    }
+
+    match handle_ref(vertex) {
+        Value::Vertex(v) => v.tags.len() as c_int,
+        _ => -1,
+    }
+}
+
+/// Get the name of a vertex tag
+///
+/// # Arguments
+/// - `vertex`: Value handle holding a vertex
+/// - `index`: Tag index (starting from 0)
+///
+/// # Returns
+/// - Tag name (UTF-8 encoded), returns NULL on error
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `vertex` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vertex_tag_name(
+    vertex: *mut graphdb_value_handle_t,
+    index: c_int,
+) -> *mut c_char {
+    if vertex.is_null() || index < 0 {
+        return ptr::null_mut();
+    }
+
+    match handle_ref(vertex) {
+        Value::Vertex(v) => match v.tags.get(index as usize) {
+            Some(tag) => new_c_string(&tag.name),
+            None => ptr::null_mut(),
+        },
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Get the properties of a vertex as a map
+///
+/// # Arguments
+/// - `vertex`: Value handle holding a vertex
+/// - `tag`: Tag name (UTF-8 encoded), or NULL for the properties of all tags
+/// - `out`: Output parameter, value handle of the property map
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the vertex has no such tag
+/// - GRAPHDB_MISMATCH if the value is not a vertex
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `vertex` must be a valid value handle
+/// - `tag` must be NULL or a valid pointer to a null-terminated UTF-8 string
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vertex_properties(
+    vertex: *mut graphdb_value_handle_t,
+    tag: *const c_char,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if vertex.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let v = match handle_ref(vertex) {
+        Value::Vertex(v) => v,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+
+    let props = if tag.is_null() {
+        let mut props = v.properties.clone();
+        for t in &v.tags {
+            props.extend(t.properties.iter().map(|(k, val)| (k.clone(), val.clone())));
+        }
+        props
+    } else {
+        let tag_name = match CStr::from_ptr(tag).to_str() {
+            Ok(s) => s,
+            Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+        };
+        match v.tags.iter().find(|t| t.name == tag_name) {
+            Some(t) => t.properties.clone(),
+            None => return graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+        }
+    };
+
+    write_value_handle(Value::Map(Box::new(props)), out)
+}
+
+/// Get a single vertex property
+///
+/// Properties stored directly on the vertex are searched first, then each tag in order.
+///
+/// # Arguments
+/// - `vertex`: Value handle holding a vertex
+/// - `name`: Property name (UTF-8 encoded)
+/// - `out`: Output parameter, value handle of the property
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the vertex has no such property
+/// - GRAPHDB_MISMATCH if the value is not a vertex
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `vertex` must be a valid value handle
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vertex_property(
+    vertex: *mut graphdb_value_handle_t,
+    name: *const c_char,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if vertex.is_null() || name.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let name_str = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    let v = match handle_ref(vertex) {
+        Value::Vertex(v) => v,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+
+    let found = v
+        .properties
+        .get(name_str)
+        .or_else(|| v.tags.iter().find_map(|t| t.properties.get(name_str)));
+    match found {
+        Some(prop) => write_value_handle(prop.clone(), out),
+        None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+    }
+}
+
+/// Get the source vertex ID of an edge
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+/// - `out`: Output parameter, value handle of the ID
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not an edge
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_src(
+    edge: *mut graphdb_value_handle_t,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if edge.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(edge) {
+        Value::Edge(e) => write_value_handle(vertex_id_value(&e.src), out),
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get the destination vertex ID of an edge
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+/// - `out`: Output parameter, value handle of the ID
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not an edge
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_dst(
+    edge: *mut graphdb_value_handle_t,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if edge.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(edge) {
+        Value::Edge(e) => write_value_handle(vertex_id_value(&e.dst), out),
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get the type name of an edge
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+///
+/// # Returns
+/// - Edge type name (UTF-8 encoded), returns NULL on error
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_type(edge: *mut graphdb_value_handle_t) -> *mut c_char {
+    if edge.is_null() {
+        return ptr::null_mut();
+    }
+
+    match handle_ref(edge) {
+        Value::Edge(e) => new_c_string(&e.edge_type),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Get the rank of an edge
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+/// - `out`: Output parameter, edge rank
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not an edge
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_rank(
+    edge: *mut graphdb_value_handle_t,
+    out: *mut i64,
+) -> c_int {
+    if edge.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(edge) {
+        Value::Edge(e) => {
+            *out = e.ranking;
+            graphdb_error_code_t::GRAPHDB_OK as c_int
+        }
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get the properties of an edge as a map
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+/// - `out`: Output parameter, value handle of the property map
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not an edge
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_properties(
+    edge: *mut graphdb_value_handle_t,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if edge.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(edge) {
+        Value::Edge(e) => write_value_handle(Value::Map(Box::new(e.props.clone())), out),
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get a single edge property
+///
+/// # Arguments
+/// - `edge`: Value handle holding an edge
+/// - `name`: Property name (UTF-8 encoded)
+/// - `out`: Output parameter, value handle of the property
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the edge has no such property
+/// - GRAPHDB_MISMATCH if the value is not an edge
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `edge` must be a valid value handle
+/// - `name` must be a valid pointer to a null-terminated UTF-8 string
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_edge_property(
+    edge: *mut graphdb_value_handle_t,
+    name: *const c_char,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if edge.is_null() || name.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let name_str = match CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    match handle_ref(edge) {
+        Value::Edge(e) => match e.props.get(name_str) {
+            Some(prop) => write_value_handle(prop.clone(), out),
+            None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+        },
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+/// Get the length of a path (its number of edges)
+///
+/// A path of length `n` has `n + 1` vertices.
+///
+/// # Arguments
+/// - `path`: Value handle holding a path
+///
+/// # Returns
+/// - Path length, returns -1 on error
+///
+/// # Safety
+/// - `path` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_path_length(path: *mut graphdb_value_handle_t) -> c_int {
+    if path.is_null() {
+        return -1;
+    }
+
+    match handle_ref(path) {
+        Value::Path(p) => p.steps.len() as c_int,
+        _ => -1,
+    }
+}
+
+/// Get a vertex of a path
+///
+/// # Arguments
+/// - `path`: Value handle holding a path
+/// - `index`: Vertex index, from 0 (the start vertex) to the path length
+/// - `out`: Output parameter, value handle of the vertex
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISMATCH if the value is not a path
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `path` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_path_vertex(
+    path: *mut graphdb_value_handle_t,
+    index: c_int,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if path.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let p = match handle_ref(path) {
+        Value::Path(p) => p,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+
+    let vertex = match index {
+        0 => Some(&p.src),
+        i if i > 0 => p.steps.get(i as usize - 1).map(|step| &step.dst),
+        _ => None,
+    };
+    match vertex {
+        Some(v) => write_value_handle(Value::Vertex(v.clone()), out),
+        None => graphdb_error_code_t::GRAPHDB_RANGE as c_int,
+    }
+}
+
+/// Get an edge of a path
+///
+/// # Arguments
+/// - `path`: Value handle holding a path
+/// - `index`: Edge index, from 0 to the path length minus one
+/// - `out`: Output parameter, value handle of the edge
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISMATCH if the value is not a path
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `path` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_path_edge(
+    path: *mut graphdb_value_handle_t,
+    index: c_int,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if path.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let p = match handle_ref(path) {
+        Value::Path(p) => p,
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+
+    if index < 0 {
+        return graphdb_error_code_t::GRAPHDB_RANGE as c_int;
+    }
+    match p.steps.get(index as usize) {
+        Some(step) => write_value_handle(Value::Edge(step.edge.clone()), out),
+        None => graphdb_error_code_t::GRAPHDB_RANGE as c_int,
+    }
+}
+
+/// Get the number of elements in a list (or set)
+///
+/// # Arguments
+/// - `list`: Value handle holding a list
+///
+/// # Returns
+/// - Number of elements, returns -1 on error
+///
+/// # Safety
+/// - `list` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_list_size(list: *mut graphdb_value_handle_t) -> c_int {
+    if list.is_null() {
+        return -1;
+    }
+
+    match handle_ref(list) {
+        Value::List(l) => l.values.len() as c_int,
+        Value::Set(s) => s.len() as c_int,
+        _ => -1,
+    }
+}
+
+/// Get an element of a list (or set)
+///
+/// Sets have no defined order, but the order is stable for a given handle.
+///
+/// # Arguments
+/// - `list`: Value handle holding a list
+/// - `index`: Element index (starting from 0)
+/// - `out`: Output parameter, value handle of the element
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_RANGE if the index is out of range
+/// - GRAPHDB_MISMATCH if the value is not a list
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `list` must be a valid value handle
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_list_get(
+    list: *mut graphdb_value_handle_t,
+    index: c_int,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if list.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+    if index < 0 {
+        return graphdb_error_code_t::GRAPHDB_RANGE as c_int;
+    }
+
+    let element = match handle_ref(list) {
+        Value::List(l) => l.values.get(index as usize),
+        Value::Set(s) => s.iter().nth(index as usize),
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+    match element {
+        Some(v) => write_value_handle(v.clone(), out),
+        None => graphdb_error_code_t::GRAPHDB_RANGE as c_int,
+    }
+}
+
+/// Get the number of entries in a map
+///
+/// # Arguments
+/// - `map`: Value handle holding a map
+///
+/// # Returns
+/// - Number of entries, returns -1 on error
+///
+/// # Safety
+/// - `map` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_map_size(map: *mut graphdb_value_handle_t) -> c_int {
+    if map.is_null() {
+        return -1;
+    }
+
+    match handle_ref(map) {
+        Value::Map(m) => m.len() as c_int,
+        _ => -1,
+    }
+}
+
+/// Get a map key by position
+///
+/// Keys are ordered lexicographically, so `0..graphdb_map_size` iterates the map
+/// in a stable order.
+///
+/// # Arguments
+/// - `map`: Value handle holding a map
+/// - `index`: Key index (starting from 0)
+///
+/// # Returns
+/// - Key (UTF-8 encoded), returns NULL on error
+///
+/// # Memory Management
+/// The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+/// to avoid memory leaks.
+///
+/// # Safety
+/// - `map` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_map_key(
+    map: *mut graphdb_value_handle_t,
+    index: c_int,
+) -> *mut c_char {
+    if map.is_null() || index < 0 {
+        return ptr::null_mut();
+    }
+
+    match handle_ref(map) {
+        Value::Map(m) => match sorted_keys(m).get(index as usize) {
+            Some(key) => new_c_string(key),
+            None => ptr::null_mut(),
+        },
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Look up a map entry by key
+///
+/// # Arguments
+/// - `map`: Value handle holding a map
+/// - `key`: Key (UTF-8 encoded)
+/// - `out`: Output parameter, value handle of the entry
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_NOTFOUND if the map has no such key
+/// - GRAPHDB_MISMATCH if the value is not a map
+///
+/// # Memory Management
+/// The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+///
+/// # Safety
+/// - `map` must be a valid value handle
+/// - `key` must be a valid pointer to a null-terminated UTF-8 string
+/// - `out` must be a valid pointer to store the handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_map_get(
+    map: *mut graphdb_value_handle_t,
+    key: *const c_char,
+    out: *mut *mut graphdb_value_handle_t,
+) -> c_int {
+    if map.is_null() || key.is_null() || out.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let key_str = match CStr::from_ptr(key).to_str() {
+        Ok(s) => s,
+        Err(_) => return graphdb_error_code_t::GRAPHDB_MISUSE as c_int,
+    };
+
+    match handle_ref(map) {
+        Value::Map(m) => match m.get(key_str) {
+            Some(v) => write_value_handle(v.clone(), out),
+            None => graphdb_error_code_t::GRAPHDB_NOTFOUND as c_int,
+        },
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+fn sorted_keys(map: &HashMap<String, Value>) -> Vec<&String> {
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+    keys
+}
+
+/// Get the dimension of a vector
+///
+/// # Arguments
+/// - `vector`: Value handle holding a vector
+///
+/// # Returns
+/// - Vector dimension, returns -1 on error
+///
+/// # Safety
+/// - `vector` must be a valid value handle
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vector_dimension(vector: *mut graphdb_value_handle_t) -> c_int {
+    if vector.is_null() {
+        return -1;
+    }
+
+    match handle_ref(vector) {
+        Value::Vector(v) => v.dimension() as c_int,
+        _ => -1,
+    }
+}
+
+/// Copy the components of a vector into a caller-provided buffer
+///
+/// Sparse vectors are expanded to their dense form.
+///
+/// # Arguments
+/// - `vector`: Value handle holding a vector
+/// - `buf`: Output buffer
+/// - `capacity`: Number of floats `buf` can hold
+///
+/// # Returns
+/// - Number of floats written, returns -1 on error or if `capacity` is smaller than the dimension
+///
+/// # Safety
+/// - `vector` must be a valid value handle
+/// - `buf` must be a valid pointer to at least `capacity` floats
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_vector_data(
+    vector: *mut graphdb_value_handle_t,
+    buf: *mut f32,
+    capacity: usize,
+) -> c_int {
+    if vector.is_null() || buf.is_null() {
+        return -1;
+    }
+
+    match handle_ref(vector) {
+        Value::Vector(v) => {
+            let data = v.to_dense();
+            if data.len() > capacity {
+                return -1;
+            }
+            ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
+            data.len() as c_int
+        }
+        _ => -1,
+    }
+}
+
+/// Decompose a date or datetime value
+///
+/// # Arguments
+/// - `value`: Value handle holding a date or datetime
+/// - `year`, `month`, `day`: Output parameters, each may be NULL if not needed
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a date or datetime
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - Non-NULL output pointers must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_date(
+    value: *mut graphdb_value_handle_t,
+    year: *mut i32,
+    month: *mut u32,
+    day: *mut u32,
+) -> c_int {
+    if value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let (y, m, d) = match handle_ref(value) {
+        Value::Date(date) => (date.year, date.month, date.day),
+        Value::DateTime(dt) => (dt.year, dt.month, dt.day),
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+    write_opt(year, y);
+    write_opt(month, m);
+    write_opt(day, d);
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Decompose a time or datetime value
+///
+/// # Arguments
+/// - `value`: Value handle holding a time or datetime
+/// - `hour`, `minute`, `second`, `microsecond`: Output parameters, each may be NULL if not needed
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a time or datetime
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - Non-NULL output pointers must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_time(
+    value: *mut graphdb_value_handle_t,
+    hour: *mut u32,
+    minute: *mut u32,
+    second: *mut u32,
+    microsecond: *mut u32,
+) -> c_int {
+    if value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    let (h, m, s, us) = match handle_ref(value) {
+        Value::Time(t) => (t.hour, t.minute, t.sec, t.microsec),
+        Value::DateTime(dt) => (dt.hour, dt.minute, dt.sec, dt.microsec),
+        _ => return graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    };
+    write_opt(hour, h);
+    write_opt(minute, m);
+    write_opt(second, s);
+    write_opt(microsecond, us);
+    graphdb_error_code_t::GRAPHDB_OK as c_int
+}
+
+/// Decompose a duration value
+///
+/// Months, days and microseconds are kept separate because months and days do not
+/// have a fixed length.
+///
+/// # Arguments
+/// - `value`: Value handle holding a duration
+/// - `months`, `days`, `microseconds`: Output parameters, each may be NULL if not needed
+///
+/// # Returns
+/// - Success: GRAPHDB_OK
+/// - GRAPHDB_MISMATCH if the value is not a duration
+///
+/// # Safety
+/// - `value` must be a valid value handle
+/// - Non-NULL output pointers must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn graphdb_value_get_duration(
+    value: *mut graphdb_value_handle_t,
+    months: *mut i32,
+    days: *mut i32,
+    microseconds: *mut i64,
+) -> c_int {
+    if value.is_null() {
+        return graphdb_error_code_t::GRAPHDB_MISUSE as c_int;
+    }
+
+    match handle_ref(value) {
+        Value::Interval(interval) => {
+            write_opt(months, interval.months);
+            write_opt(days, interval.days);
+            write_opt(microseconds, interval.microseconds);
+            graphdb_error_code_t::GRAPHDB_OK as c_int
+        }
+        _ => graphdb_error_code_t::GRAPHDB_MISMATCH as c_int,
+    }
+}
+
+unsafe fn write_opt<T>(out: *mut T, value: T) {
+    if !out.is_null() {
+        *out = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::value::list::List;
+    use crate::core::vertex_edge_path::{Edge, Path, Step, Tag, Vertex};
+
+    unsafe fn handle(value: Value) -> *mut graphdb_value_handle_t {
+        let mut out: *mut graphdb_value_handle_t = ptr::null_mut();
+        assert_eq!(
+            write_value_handle(value, &mut out),
+            graphdb_error_code_t::GRAPHDB_OK as c_int
+        );
+        out
+    }
+
+    fn test_vertex(id: i64) -> Vertex {
+        let mut props = HashMap::new();
+        props.insert("name".to_string(), Value::String(format!("v{}", id)));
+        Vertex {
+            vid: VertexId::from_int64(id),
+            id,
+            tags: vec![Tag::new("person".to_string(), props)],
+            properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_value_null_params() {
+        unsafe {
+            assert_eq!(
+                graphdb_value_free(ptr::null_mut()),
+                graphdb_error_code_t::GRAPHDB_MISUSE as c_int
+            );
+            assert_eq!(
+                graphdb_value_type(ptr::null_mut()),
+                graphdb_value_type_t::GRAPHDB_NULL
+            );
+            assert_eq!(graphdb_list_size(ptr::null_mut()), -1);
+            assert!(graphdb_edge_type(ptr::null_mut()).is_null());
+        }
+    }
+
+    #[test]
+    fn test_vertex_accessors() {
+        unsafe {
+            let vertex = handle(Value::Vertex(Box::new(test_vertex(7))));
+            assert_eq!(
+                graphdb_value_type(vertex),
+                graphdb_value_type_t::GRAPHDB_VERTEX
+            );
+            assert_eq!(graphdb_vertex_tag_count(vertex), 1);
+
+            let mut id: *mut graphdb_value_handle_t = ptr::null_mut();
+            assert_eq!(graphdb_vertex_id(vertex, &mut id), 0);
+            let mut id_value = 0i64;
+            assert_eq!(graphdb_value_get_int(id, &mut id_value), 0);
+            assert_eq!(id_value, 7);
+
+            let name = CString::new("name").expect("Failed to create CString");
+            let mut prop: *mut graphdb_value_handle_t = ptr::null_mut();
+            assert_eq!(graphdb_vertex_property(vertex, name.as_ptr(), &mut prop), 0);
+
+            // Child handles are independent of their parent
+            graphdb_value_free(vertex);
+            let mut len = 0;
+            let s = graphdb_value_get_string(prop, &mut len);
+            assert_eq!(CStr::from_ptr(s).to_str().expect("Invalid UTF-8"), "v7");
+            assert_eq!(len, 2);
+
+            crate::api::embedded::c_api::database::graphdb_free_string(s);
+            graphdb_value_free(prop);
+            graphdb_value_free(id);
+        }
+    }
+
+    #[test]
+    fn test_path_and_list_accessors() {
+        unsafe {
+            let edge = Edge::new_empty(
+                VertexId::from_int64(1),
+                VertexId::from_int64(2),
+                "knows".to_string(),
+                3,
+            );
+            let path = Path {
+                src: Box::new(test_vertex(1)),
+                steps: vec![Step::new_with_edge(test_vertex(2), edge)],
+            };
+            let path = handle(Value::Path(Box::new(path)));
+            assert_eq!(graphdb_path_length(path), 1);
+
+            let mut e: *mut graphdb_value_handle_t = ptr::null_mut();
+            assert_eq!(graphdb_path_edge(path, 0, &mut e), 0);
+            let mut rank = 0i64;
+            assert_eq!(graphdb_edge_rank(e, &mut rank), 0);
+            assert_eq!(rank, 3);
+
+            let mut v: *mut graphdb_value_handle_t = ptr::null_mut();
+            assert_eq!(
+                graphdb_path_vertex(path, 2, &mut v),
+                graphdb_error_code_t::GRAPHDB_RANGE as c_int
+            );
+
+            let list = handle(Value::List(Box::new(List {
+                values: vec![Value::Int(1), Value::Double(2.5)],
+            })));
+            assert_eq!(graphdb_list_size(list), 2);
+            let mut element: *mut graphdb_value_handle_t = ptr::null_mut();
+            assert_eq!(graphdb_list_get(list, 1, &mut element), 0);
+            let mut f = 0f64;
+            assert_eq!(graphdb_value_get_float(element, &mut f), 0);
+            assert_eq!(f, 2.5);
+
+            graphdb_value_free(element);
+            graphdb_value_free(list);
+            graphdb_value_free(e);
+            graphdb_value_free(path);
+        }
+    }
+}
@@ -6,12 +6,15 @@ use crate::api::core::{CoreError, CoreResult, QueryApi, SchemaApi, SpaceConfig};
 use crate::api::embedded::config::DatabaseConfig;
 use crate::api::embedded::result::QueryResult;
 use crate::api::embedded::session::{GraphDatabaseInner, Session};
+use crate::core::error::{DBError, DBResult};
+use crate::core::types::{TransactionContextInfo, TransactionId};
 use crate::core::{StatsManager, Value};
+use crate::query::executor::control_flow::BatchTransactionControl;
 use crate::search::{FulltextConfig, FulltextIndexManager, SyncFailurePolicy};
-use crate::storage::{GraphStorage, StorageClient};
+use crate::storage::{GraphStorage, StorageClient, StorageTransactionContextOps};
 use crate::sync::{SyncConfig, SyncManager};
 use crate::transaction::wal::SyncPolicy;
-use crate::transaction::{TransactionManager, TransactionManagerConfig};
+use crate::transaction::{TransactionManager, TransactionManagerConfig, TransactionOptions};
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::path::Path;
@@ -22,6 +25,64 @@ use vector_client::{VectorClientConfig, VectorManager};
 #[cfg(test)]
 use crate::storage::MockStorage;
 
+/// Runs each batch of `CALL { } IN TRANSACTIONS`, and the write-back of the `.write`
+/// procedures, in its own transaction of the database's transaction manager.
+///
+/// The batch transaction is installed on the storage until it is committed or rolled
+/// back, so the writes of the statement are recorded in its undo log.
+struct EmbeddedBatchTransactions<S: StorageClient + StorageTransactionContextOps> {
+    transaction_manager: Arc<TransactionManager>,
+    storage: Arc<RwLock<S>>,
+}
+
+impl<S: StorageClient + StorageTransactionContextOps> BatchTransactionControl
+    for EmbeddedBatchTransactions<S>
+{
+    fn in_explicit_transaction(&self) -> bool {
+        self.storage.read().get_transaction_context().is_some()
+    }
+
+    fn begin_batch(&self) -> DBResult<TransactionId> {
+        let txn_id = self
+            .transaction_manager
+            .begin_transaction(TransactionOptions::default())
+            .map_err(|e| DBError::transaction(e.to_string()))?;
+        let ctx = self
+            .transaction_manager
+            .get_context(txn_id)
+            .map_err(|e| DBError::transaction(e.to_string()))?;
+        self.storage
+            .read()
+            .set_transaction_context(Some(Arc::new(TransactionContextInfo::new(
+                ctx.id,
+                ctx.start_timestamp,
+                ctx.read_only,
+                0,
+            ))));
+        Ok(txn_id)
+    }
+
+    fn commit_batch(&self, txn_id: TransactionId) -> DBResult<()> {
+        let storage = self.storage.write();
+        let result = self
+            .transaction_manager
+            .commit_transaction_with_undo(txn_id, &*storage)
+            .map_err(|e| DBError::transaction(e.to_string()));
+        storage.set_transaction_context(None);
+        result
+    }
+
+    fn rollback_batch(&self, txn_id: TransactionId) -> DBResult<()> {
+        let storage = self.storage.write();
+        let result = self
+            .transaction_manager
+            .abort_transaction_with_undo(txn_id, &*storage)
+            .map_err(|e| DBError::transaction(e.to_string()));
+        storage.set_transaction_context(None);
+        result
+    }
+}
+
 /// Create a VectorManager from the default configuration.
 ///
 /// Uses the provided runtime handle to block on async VectorManager initialization.
@@ -234,10 +295,17 @@ impl GraphDatabase<GraphStorage> {
                 };
 
                 let batch_config = crate::sync::batch::BatchConfig::from(sync_config.clone());
-                let sync_coordinator = Arc::new(crate::sync::coordinator::SyncCoordinator::new(
-                    manager.clone(),
-                    batch_config,
-                ));
+                let mut sync_coordinator =
+                    crate::sync::coordinator::SyncCoordinator::new(manager.clone(), batch_config);
+                if let Some(path) = config.path() {
+                    sync_coordinator = sync_coordinator.with_dead_letter_queue(Arc::new(
+                        crate::sync::DeadLetterQueue::open_in_data_dir(
+                            path,
+                            crate::sync::DeadLetterQueueConfig::default(),
+                        ),
+                    ));
+                }
+                let sync_coordinator = Arc::new(sync_coordinator);
 
                 let sync = SyncManager::with_sync_config(sync_coordinator.clone(), sync_config);
 
@@ -281,6 +349,12 @@ impl GraphDatabase<GraphStorage> {
                 stats_manager.clone(),
             )))
         };
+        query_api
+            .write()
+            .set_batch_transaction_control(Arc::new(EmbeddedBatchTransactions {
+                transaction_manager: txn_manager.clone(),
+                storage: storage.clone(),
+            }));
         let schema_api = SchemaApi::new(storage.clone());
 
         let inner = Arc::new(GraphDatabaseInner {
@@ -39,6 +39,7 @@ pub mod result;
 pub mod session;
 pub mod statistics;
 pub mod transaction;
+pub mod typed;
 
 // C API module
 pub mod c_api;
@@ -52,6 +53,10 @@ pub use result::{QueryResult, ResultMetadata, Row};
 pub use session::Session;
 pub use statistics::QueryStatistics;
 pub use transaction::{Transaction, TransactionConfig, TransactionInfo};
+pub use typed::{GraphEdge, GraphTag, PropertyDef, PropertyValue};
+
+// Derive macros for `GraphTag` and `GraphEdge`
+pub use graphdb_derive::{GraphEdge, GraphTag};
 
 // Re-export SessionStatistics from core
 pub use crate::core::SessionStatistics;
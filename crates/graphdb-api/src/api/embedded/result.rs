@@ -3,7 +3,9 @@
 //! Provides comprehensive query result processing capabilities, extending the core layer of QueryResult and Row
 
 use crate::api::core::{CoreError, CoreResult, QueryResult as CoreQueryResult, Row as CoreRow};
+use crate::api::embedded::typed::to_serde_value;
 use crate::core::{Edge, Path, Value, Vertex};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -118,6 +120,16 @@ impl QueryResult {
         serde_json::to_value(self)
             .map_err(|e| CoreError::Internal(format!("JSON serialization failed: {}", e)))
     }
+
+    /// Decode every row into `T`, see [`Row::deserialize`]
+    pub fn deserialize<T: DeserializeOwned>(&self) -> CoreResult<Vec<T>> {
+        self.rows.iter().map(Row::deserialize).collect()
+    }
+
+    /// Decode one column of every row into `T`, see [`Row::get_as`]
+    pub fn deserialize_column<T: DeserializeOwned>(&self, column: &str) -> CoreResult<Vec<T>> {
+        self.rows.iter().map(|row| row.get_as(column)).collect()
+    }
 }
 
 impl IntoIterator for QueryResult {
@@ -265,6 +277,27 @@ impl Row {
         &self.values
     }
 
+    /// Decode the row into `T`, one field per column
+    ///
+    /// Columns are matched by name, so `RETURN p.name AS name` fills a field `name`.
+    /// Vertices, edges and paths decode as described in [`to_serde_value`].
+    pub fn deserialize<T: DeserializeOwned>(&self) -> CoreResult<T> {
+        let object = self
+            .values
+            .iter()
+            .map(|(column, value)| (column.clone(), to_serde_value(value)))
+            .collect();
+        decode(serde_json::Value::Object(object))
+    }
+
+    /// Decode one column into `T`, e.g. the properties of a returned vertex into a struct
+    pub fn get_as<T: DeserializeOwned>(&self, column: &str) -> CoreResult<T> {
+        let value = self
+            .get(column)
+            .ok_or_else(|| CoreError::NotFound(format!("Column {} not found", column)))?;
+        decode(to_serde_value(value))
+    }
+
     /// Translate from "Convert to JSON string" to English: "Convert to JSON string"
     pub fn to_json(&self) -> CoreResult<String> {
         serde_json::to_string_pretty(self)
@@ -272,6 +305,11 @@ impl Row {
     }
 }
 
+fn decode<T: DeserializeOwned>(value: serde_json::Value) -> CoreResult<T> {
+    serde_json::from_value(value)
+        .map_err(|e| CoreError::InvalidParameter(format!("Failed to decode result: {}", e)))
+}
+
 impl Default for ResultMetadata {
     fn default() -> Self {
         Self {
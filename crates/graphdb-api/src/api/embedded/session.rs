@@ -6,9 +6,12 @@ use crate::api::core::{CoreError, CoreResult, QueryApi, QueryRequest, SchemaApi}
 use crate::api::embedded::batch::BatchInserter;
 use crate::api::embedded::result::QueryResult;
 use crate::api::embedded::transaction::{Transaction, TransactionConfig};
+use crate::api::embedded::typed::{self, GraphEdge, GraphTag};
 use crate::core::Value;
 use crate::core::{SessionStatistics, StatsManager};
-use crate::query::executor::expression::functions::{CustomFunction, FunctionRegistry};
+use crate::query::executor::expression::functions::{
+    CustomFunction, CustomProcedure, FunctionRegistry,
+};
 use crate::search::FulltextIndexManager;
 use crate::storage::StorageClient;
 #[cfg(feature = "qdrant")]
@@ -98,6 +101,30 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
         Arc::clone(&self.function_registry)
     }
 
+    /// Register a custom procedure, callable with `CALL name(args)`
+    ///
+    /// Procedures are shared by every session of the database. Registering a name that is
+    /// already taken by a built-in procedure fails, a custom one is replaced.
+    pub fn register_procedure(&self, procedure: CustomProcedure) -> CoreResult<()> {
+        self.db
+            .query_api
+            .read()
+            .procedure_registry()
+            .write()
+            .register_custom(procedure)
+            .map_err(|e| CoreError::InvalidParameter(e.to_string()))
+    }
+
+    /// Remove a custom procedure, returning whether it was registered
+    pub fn unregister_procedure(&self, name: &str) -> bool {
+        self.db
+            .query_api
+            .read()
+            .procedure_registry()
+            .write()
+            .unregister_custom(name)
+    }
+
     /// Get the number of rows affected by the last operation.
     pub fn changes(&self) -> u64 {
         self.statistics.last_changes()
@@ -205,6 +232,8 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
             auto_commit: self.auto_commit,
             transaction_id: None,
             parameters: None,
+            user_name: None,
+            role: None,
         };
 
         let mut query_api = self.db.query_api.write();
@@ -240,6 +269,8 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
             auto_commit: self.auto_commit,
             transaction_id: None,
             parameters: Some(params),
+            user_name: None,
+            role: None,
         };
 
         let mut query_api = self.db.query_api.write();
@@ -251,6 +282,66 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
         Ok(QueryResult::from_core(result))
     }
 
+    /// Prepare a parameterized query
+    ///
+    /// The query is planned once and the plan is kept in the plan cache, so
+    /// repeated [`Session::execute_prepared`] calls only bind new values.
+    ///
+    /// # Parameters
+    /// - `query` - query statement string with `$name` placeholders
+    ///
+    /// # Return
+    /// - Returns the placeholder names, in order of first appearance
+    /// - Return error if the query cannot be parsed, validated or planned
+    pub fn prepare(&self, query: &str) -> CoreResult<Vec<String>> {
+        let ctx = QueryRequest {
+            space_id: *self.space_id.read(),
+            space_name: self.space_name.read().clone(),
+            auto_commit: self.auto_commit,
+            transaction_id: None,
+            parameters: None,
+            user_name: None,
+            role: None,
+        };
+
+        self.db.query_api.write().prepare(query, ctx)
+    }
+
+    /// Execute a prepared query with bound parameters
+    ///
+    /// # Parameters
+    /// - `query` - query statement string previously passed to [`Session::prepare`]
+    /// - `params` - values for the `$name` placeholders, keyed without the `$`
+    ///
+    /// # Return
+    /// - Returns query results on success
+    /// - Return error on failure
+    pub fn execute_prepared(
+        &self,
+        query: &str,
+        params: HashMap<String, Value>,
+    ) -> CoreResult<QueryResult> {
+        self.statistics.reset_last();
+
+        let ctx = QueryRequest {
+            space_id: *self.space_id.read(),
+            space_name: self.space_name.read().clone(),
+            auto_commit: self.auto_commit,
+            transaction_id: None,
+            parameters: Some(params),
+            user_name: None,
+            role: None,
+        };
+
+        let mut query_api = self.db.query_api.write();
+        let result = query_api.execute_prepared(query, ctx)?;
+
+        self.statistics
+            .record_changes(result.metadata.rows_returned);
+
+        Ok(QueryResult::from_core(result))
+    }
+
     /// Start a transaction
     ///
     /// # Return
@@ -481,6 +572,124 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
         Ok(count)
     }
 
+    /// Create the tag of a [`GraphTag`] struct, unless it already exists
+    pub fn create_tag_schema<T: GraphTag>(&self) -> CoreResult<()> {
+        self.execute(&T::create_statement()).map(|_| ())
+    }
+
+    /// Create the edge type of a [`GraphEdge`] struct, unless it already exists
+    pub fn create_edge_schema<E: GraphEdge>(&self) -> CoreResult<()> {
+        self.execute(&E::create_statement()).map(|_| ())
+    }
+
+    /// Insert typed vertices, each with the tag of `T`
+    ///
+    /// # Return
+    /// - Returns the number of vertices inserted on success
+    /// - Return error on failure
+    pub fn insert_vertices<T: GraphTag>(&self, items: &[T]) -> CoreResult<usize> {
+        let vertices = items
+            .iter()
+            .map(GraphTag::to_vertex)
+            .collect::<CoreResult<Vec<_>>>()?;
+        self.batch_insert_vertices(vertices)
+    }
+
+    /// Insert typed edges
+    ///
+    /// # Return
+    /// - Returns the number of edges inserted on success
+    /// - Return error on failure
+    pub fn insert_edges<E: GraphEdge>(&self, items: &[E]) -> CoreResult<usize> {
+        let edges = items
+            .iter()
+            .map(GraphEdge::to_edge)
+            .collect::<CoreResult<Vec<_>>>()?;
+        self.batch_insert_edges(edges)
+    }
+
+    /// Fetch the tag `T` of a vertex
+    ///
+    /// # Return
+    /// - Returns `None` if the vertex does not exist or does not have the tag
+    /// - Return error if the stored properties do not match `T`
+    pub fn fetch_vertex<T: GraphTag>(&self, vid: impl Into<Value>) -> CoreResult<Option<T>> {
+        let space_name = self
+            .space_name()
+            .ok_or_else(|| CoreError::InvalidParameter("No graph space selected".to_string()))?;
+        let vid = typed::vertex_id(&vid.into())?;
+
+        let vertex = self
+            .db
+            .storage
+            .read()
+            .get_vertex(&space_name, &vid)
+            .map_err(|e| CoreError::StorageError(e.to_string()))?;
+        match vertex {
+            Some(vertex) if vertex.has_tag(T::TAG_NAME) => T::from_vertex(&vertex).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Fetch a typed edge
+    ///
+    /// # Return
+    /// - Returns `None` if the edge does not exist
+    /// - Return error if the stored properties do not match `E`
+    pub fn fetch_edge<E: GraphEdge>(
+        &self,
+        src: impl Into<Value>,
+        dst: impl Into<Value>,
+        rank: i64,
+    ) -> CoreResult<Option<E>> {
+        let space_name = self
+            .space_name()
+            .ok_or_else(|| CoreError::InvalidParameter("No graph space selected".to_string()))?;
+        let src = typed::vertex_id(&src.into())?;
+        let dst = typed::vertex_id(&dst.into())?;
+
+        let edge = self
+            .db
+            .storage
+            .read()
+            .get_edge(&space_name, &src, &dst, E::EDGE_TYPE, rank)
+            .map_err(|e| CoreError::StorageError(e.to_string()))?;
+        edge.map(|edge| E::from_edge(&edge)).transpose()
+    }
+
+    /// Overwrite every property of the tag `T` of a vertex
+    ///
+    /// # Return
+    /// - Returns false if the vertex does not exist or does not have the tag
+    /// - Return error on failure
+    pub fn update_vertex<T: GraphTag>(&self, item: &T) -> CoreResult<bool> {
+        if self.fetch_vertex::<T>(item.vid())?.is_none() {
+            return Ok(false);
+        }
+        if let Some(update) = typed::update_vertex_statement(item)? {
+            self.execute_prepared(&update.query, update.params)?;
+        }
+        Ok(true)
+    }
+
+    /// Overwrite every property of a typed edge
+    ///
+    /// # Return
+    /// - Returns false if the edge does not exist
+    /// - Return error on failure
+    pub fn update_edge<E: GraphEdge>(&self, item: &E) -> CoreResult<bool> {
+        if self
+            .fetch_edge::<E>(item.src(), item.dst(), item.rank())?
+            .is_none()
+        {
+            return Ok(false);
+        }
+        if let Some(update) = typed::update_edge_statement(item)? {
+            self.execute_prepared(&update.query, update.params)?;
+        }
+        Ok(true)
+    }
+
     /// Commit a transaction by handle (for C API use)
     ///
     /// # Parameters
@@ -493,8 +702,9 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
         &self,
         txn_handle: crate::api::core::TransactionHandle,
     ) -> CoreResult<()> {
+        let storage = self.storage_mut();
         self.txn_manager()
-            .commit_transaction(txn_handle.0)
+            .commit_transaction_with_undo(txn_handle.0, &*storage)
             .map_err(|e| CoreError::TransactionFailed(e.to_string()))
     }
 
@@ -510,8 +720,9 @@ impl<S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoTarget>
         &self,
         txn_handle: crate::api::core::TransactionHandle,
     ) -> CoreResult<()> {
+        let storage = self.storage_mut();
         self.txn_manager()
-            .abort_transaction(txn_handle.0)
+            .abort_transaction_with_undo(txn_handle.0, &*storage)
             .map_err(|e| CoreError::TransactionFailed(e.to_string()))
     }
 
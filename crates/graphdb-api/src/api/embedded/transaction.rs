@@ -201,6 +201,8 @@ impl<'sess, S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoT
             auto_commit: false,
             transaction_id: Some(self.txn_handle.0),
             parameters: None,
+            user_name: None,
+            role: None,
         };
 
         let mut query_api = self.session.query_api_mut();
@@ -237,6 +239,8 @@ impl<'sess, S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoT
             auto_commit: false,
             transaction_id: Some(self.txn_handle.0),
             parameters: Some(params),
+            user_name: None,
+            role: None,
         };
 
         let mut query_api = self.session.query_api_mut();
@@ -262,8 +266,9 @@ impl<'sess, S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoT
             CoreError::TransactionFailed(format!("Transaction timeout: {}", e))
         })?;
 
+        let storage = self.session.storage_mut();
         txn_manager
-            .commit_transaction(self.txn_handle.0)
+            .commit_transaction_with_undo(self.txn_handle.0, &*storage)
             .map_err(|e| crate::api::core::CoreError::TransactionFailed(e.to_string()))?;
         self.committed = true;
         Ok(())
@@ -286,8 +291,9 @@ impl<'sess, S: StorageClient + Clone + 'static + graphdb_storage::storage::UndoT
             CoreError::TransactionFailed(format!("Transaction timeout: {}", e))
         })?;
 
+        let storage = self.session.storage_mut();
         txn_manager
-            .abort_transaction(self.txn_handle.0)
+            .abort_transaction_with_undo(self.txn_handle.0, &*storage)
             .map_err(|e| crate::api::core::CoreError::TransactionFailed(e.to_string()))?;
 
         self.rolled_back = true;
@@ -483,10 +489,11 @@ impl<'sess, S: StorageClient + Clone + 'static> Drop for Transaction<'sess, S> {
     fn drop(&mut self) {
         // If the transaction is still active, it will be automatically rolled back.
         if self.is_active() {
+            let storage = self.session.storage_mut();
             let _ = self
                 .session
                 .txn_manager()
-                .abort_transaction(self.txn_handle.0);
+                .abort_transaction_with_undo(self.txn_handle.0, &*storage);
         }
     }
 }
@@ -0,0 +1,704 @@
+//! Typed Mapping Module
+//!
+//! Maps Rust structs to tags and edge types, and query results to serde types.
+//!
+//! `#[derive(GraphTag)]` and `#[derive(GraphEdge)]` implement [`GraphTag`] and [`GraphEdge`]
+//! for a struct; the session uses them to create the schema, insert, fetch and update
+//! typed values. [`QueryResult::deserialize`](super::QueryResult::deserialize) decodes
+//! rows with serde, see [`to_serde_value`] for the shape of graph values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use graphdb::api::embedded::{GraphDatabase, GraphEdge, GraphTag};
+//!
+//! #[derive(GraphTag)]
+//! #[graph(tag = "person")]
+//! struct Person {
+//!     #[graph(vid)]
+//!     id: i64,
+//!     name: String,
+//!     age: Option<i64>,
+//! }
+//!
+//! #[derive(GraphEdge)]
+//! #[graph(edge = "follow")]
+//! struct Follow {
+//!     #[graph(src)]
+//!     from: i64,
+//!     #[graph(dst)]
+//!     to: i64,
+//!     degree: f64,
+//! }
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let db = GraphDatabase::open("my_db")?;
+//! let mut session = db.session()?;
+//! session.use_space("test_space")?;
+//!
+//! session.create_tag_schema::<Person>()?;
+//! session.create_edge_schema::<Follow>()?;
+//! session.insert_vertices(&[Person { id: 1, name: "Alice".into(), age: Some(30) }])?;
+//!
+//! let alice: Option<Person> = session.fetch_vertex(1i64)?;
+//! let query = format!("MATCH (p:person) RETURN p.{} AS name", Person::NAME);
+//! let names: Vec<String> = session.execute(&query)?.deserialize_column("name")?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::api::core::CoreResult;
+pub use crate::core::{DateValue, Edge, List, Value, Vertex};
+pub use std::collections::HashMap;
+
+use crate::api::core::CoreError;
+use crate::core::types::VertexId;
+use crate::core::{NullType, Tag};
+
+/// Schema of one property of a tag or edge type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertyDef {
+    /// Property name
+    pub name: &'static str,
+    /// Type name used in `CREATE TAG` / `CREATE EDGE`
+    pub data_type: &'static str,
+    /// Whether the property may be NULL
+    pub nullable: bool,
+}
+
+/// A Rust type that can be stored as a property or used as a vertex ID
+pub trait PropertyValue: Sized {
+    /// Type name used in `CREATE TAG` / `CREATE EDGE`
+    const DATA_TYPE: &'static str;
+    /// Whether the property may be NULL
+    const NULLABLE: bool = false;
+
+    /// Convert to a stored value
+    fn to_value(&self) -> Value;
+
+    /// Convert from a stored value, `None` when the value has another type
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl PropertyValue for String {
+    const DATA_TYPE: &'static str = "STRING";
+
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::FixedString { data, .. } => Some(data.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for bool {
+    const DATA_TYPE: &'static str = "BOOL";
+
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+/// Integers are read back from any integer width that fits
+fn integer(value: &Value) -> Option<i64> {
+    match value {
+        Value::SmallInt(i) => Some(i64::from(*i)),
+        Value::Int(i) => Some(i64::from(*i)),
+        Value::BigInt(i) => Some(*i),
+        _ => None,
+    }
+}
+
+macro_rules! integer_property {
+    ($ty:ty, $data_type:literal) => {
+        impl PropertyValue for $ty {
+            const DATA_TYPE: &'static str = $data_type;
+
+            fn to_value(&self) -> Value {
+                Value::from(*self)
+            }
+
+            fn from_value(value: &Value) -> Option<Self> {
+                integer(value).and_then(|i| <$ty>::try_from(i).ok())
+            }
+        }
+    };
+}
+
+integer_property!(i16, "INT16");
+integer_property!(i32, "INT32");
+
+impl PropertyValue for i64 {
+    const DATA_TYPE: &'static str = "INT64";
+
+    fn to_value(&self) -> Value {
+        Value::BigInt(*self)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        integer(value)
+    }
+}
+
+impl PropertyValue for f32 {
+    const DATA_TYPE: &'static str = "FLOAT";
+
+    fn to_value(&self) -> Value {
+        Value::Float(*self)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Float(f) => Some(*f),
+            Value::Double(d) => Some(*d as f32),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for f64 {
+    const DATA_TYPE: &'static str = "DOUBLE";
+
+    fn to_value(&self) -> Value {
+        Value::Double(*self)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Float(f) => Some(*f as f64),
+            Value::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
+impl PropertyValue for DateValue {
+    const DATA_TYPE: &'static str = "DATE";
+
+    fn to_value(&self) -> Value {
+        Value::Date(self.clone())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Date(date) => Some(date.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Lists are stored untyped, every element has to read back as `T`
+impl<T: PropertyValue> PropertyValue for Vec<T> {
+    const DATA_TYPE: &'static str = "LIST";
+
+    fn to_value(&self) -> Value {
+        Value::list(List {
+            values: self.iter().map(PropertyValue::to_value).collect(),
+        })
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::List(list) => list.values.iter().map(T::from_value).collect(),
+            _ => None,
+        }
+    }
+}
+
+impl<T: PropertyValue> PropertyValue for Option<T> {
+    const DATA_TYPE: &'static str = T::DATA_TYPE;
+    const NULLABLE: bool = true;
+
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Null(NullType::Null),
+        }
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null(_) | Value::Empty => Some(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+/// A struct stored as one tag of a vertex, usually implemented with `#[derive(GraphTag)]`
+pub trait GraphTag: Sized {
+    /// Tag name
+    const TAG_NAME: &'static str;
+    /// Properties of the tag, in field order
+    const PROPERTIES: &'static [PropertyDef];
+
+    /// The vertex ID
+    fn vid(&self) -> Value;
+
+    /// Property values by name
+    fn properties(&self) -> HashMap<String, Value>;
+
+    /// Read the struct from a vertex that has the tag
+    fn from_vertex(vertex: &Vertex) -> CoreResult<Self>;
+
+    /// The vertex holding only this tag
+    fn to_vertex(&self) -> CoreResult<Vertex> {
+        Ok(Vertex::new(
+            vertex_id(&self.vid())?,
+            vec![Tag::new(Self::TAG_NAME.to_string(), self.properties())],
+        ))
+    }
+
+    /// `CREATE TAG IF NOT EXISTS` statement for the struct
+    fn create_statement() -> String {
+        create_statement("TAG", Self::TAG_NAME, Self::PROPERTIES)
+    }
+}
+
+/// A struct stored as an edge, usually implemented with `#[derive(GraphEdge)]`
+pub trait GraphEdge: Sized {
+    /// Edge type name
+    const EDGE_TYPE: &'static str;
+    /// Properties of the edge type, in field order
+    const PROPERTIES: &'static [PropertyDef];
+
+    /// The source vertex ID
+    fn src(&self) -> Value;
+
+    /// The destination vertex ID
+    fn dst(&self) -> Value;
+
+    /// The edge rank
+    fn rank(&self) -> i64;
+
+    /// Property values by name
+    fn properties(&self) -> HashMap<String, Value>;
+
+    /// Read the struct from an edge of this type
+    fn from_edge(edge: &Edge) -> CoreResult<Self>;
+
+    /// The edge to store
+    fn to_edge(&self) -> CoreResult<Edge> {
+        Ok(Edge::new(
+            vertex_id(&self.src())?,
+            vertex_id(&self.dst())?,
+            Self::EDGE_TYPE.to_string(),
+            self.rank(),
+            self.properties(),
+        ))
+    }
+
+    /// `CREATE EDGE IF NOT EXISTS` statement for the struct
+    fn create_statement() -> String {
+        create_statement("EDGE", Self::EDGE_TYPE, Self::PROPERTIES)
+    }
+}
+
+fn create_statement(kind: &str, name: &str, properties: &[PropertyDef]) -> String {
+    let columns: Vec<String> = properties
+        .iter()
+        .map(|property| {
+            let null = if property.nullable {
+                "NULL"
+            } else {
+                "NOT NULL"
+            };
+            format!("{}: {} {}", property.name, property.data_type, null)
+        })
+        .collect();
+    format!(
+        "CREATE {} IF NOT EXISTS {}({})",
+        kind,
+        name,
+        columns.join(", ")
+    )
+}
+
+/// Convert a vertex ID value to a [`VertexId`]
+pub fn vertex_id(value: &Value) -> CoreResult<VertexId> {
+    VertexId::try_from(value)
+        .map_err(|e| CoreError::InvalidParameter(format!("Invalid vertex ID {}: {}", value, e)))
+}
+
+/// Properties of `tag` on the vertex
+pub fn tag_properties<'a>(vertex: &'a Vertex, tag: &str) -> CoreResult<&'a HashMap<String, Value>> {
+    vertex
+        .get_tag(tag)
+        .map(|tag| &tag.properties)
+        .ok_or_else(|| CoreError::NotFound(format!("Vertex {} has no tag {}", vertex.vid, tag)))
+}
+
+/// Properties of the edge, which must be of `edge_type`
+pub fn edge_properties<'a>(
+    edge: &'a Edge,
+    edge_type: &str,
+) -> CoreResult<&'a HashMap<String, Value>> {
+    if edge.edge_type != edge_type {
+        return Err(CoreError::InvalidParameter(format!(
+            "Expected an edge of type {}, got {}",
+            edge_type, edge.edge_type
+        )));
+    }
+    Ok(&edge.props)
+}
+
+/// Read a property, a missing one reads as NULL
+pub fn decode_property<T: PropertyValue>(
+    properties: &HashMap<String, Value>,
+    name: &str,
+) -> CoreResult<T> {
+    let null = Value::Null(NullType::Null);
+    let value = properties.get(name).unwrap_or(&null);
+    T::from_value(value).ok_or_else(|| {
+        CoreError::InvalidParameter(format!(
+            "Property {} cannot be read as {}: {}",
+            name,
+            T::DATA_TYPE,
+            value
+        ))
+    })
+}
+
+/// Read a vertex ID into the field `name`
+pub fn decode_vid<T: PropertyValue>(vid: &VertexId, name: &str) -> CoreResult<T> {
+    let value = Value::from(*vid);
+    T::from_value(&value).ok_or_else(|| {
+        CoreError::InvalidParameter(format!(
+            "Vertex ID {} cannot be read into {} as {}",
+            vid,
+            name,
+            T::DATA_TYPE
+        ))
+    })
+}
+
+/// `UPDATE` statement whose property values are bound as `$p0`, `$p1`, ...
+pub(crate) struct UpdateStatement {
+    pub(crate) query: String,
+    pub(crate) params: HashMap<String, Value>,
+}
+
+/// `UPDATE VERTEX` statement setting every property of the tag, `None` without properties
+///
+/// The vertex ID is written as a literal, the UPDATE target is resolved when the
+/// statement is validated and cannot be a parameter.
+pub(crate) fn update_vertex_statement<T: GraphTag>(
+    item: &T,
+) -> CoreResult<Option<UpdateStatement>> {
+    let Some((assignments, params)) = set_clause(T::PROPERTIES, item.properties()) else {
+        return Ok(None);
+    };
+    let query = format!(
+        "UPDATE VERTEX {} ON {} SET {}",
+        vid_literal(&item.vid())?,
+        T::TAG_NAME,
+        assignments
+    );
+    Ok(Some(UpdateStatement { query, params }))
+}
+
+/// `UPDATE EDGE` statement setting every property of the edge, `None` without properties
+pub(crate) fn update_edge_statement<E: GraphEdge>(item: &E) -> CoreResult<Option<UpdateStatement>> {
+    let Some((assignments, params)) = set_clause(E::PROPERTIES, item.properties()) else {
+        return Ok(None);
+    };
+    let query = format!(
+        "UPDATE EDGE {} -> {} @{} OF {} SET {}",
+        vid_literal(&item.src())?,
+        vid_literal(&item.dst())?,
+        item.rank(),
+        E::EDGE_TYPE,
+        assignments
+    );
+    Ok(Some(UpdateStatement { query, params }))
+}
+
+fn set_clause(
+    properties: &[PropertyDef],
+    mut values: HashMap<String, Value>,
+) -> Option<(String, HashMap<String, Value>)> {
+    if properties.is_empty() {
+        return None;
+    }
+    let mut assignments = Vec::with_capacity(properties.len());
+    let mut params = HashMap::with_capacity(properties.len());
+    for (i, property) in properties.iter().enumerate() {
+        let param = format!("p{}", i);
+        let value = values
+            .remove(property.name)
+            .unwrap_or(Value::Null(NullType::Null));
+        assignments.push(format!("{} = ${}", property.name, param));
+        params.insert(param, value);
+    }
+    Some((assignments.join(", "), params))
+}
+
+/// Write a vertex ID as a query literal
+fn vid_literal(value: &Value) -> CoreResult<String> {
+    match value {
+        Value::SmallInt(i) => Ok(i.to_string()),
+        Value::Int(i) => Ok(i.to_string()),
+        Value::BigInt(i) => Ok(i.to_string()),
+        Value::String(s) | Value::FixedString { data: s, .. } => {
+            let mut quoted = String::with_capacity(s.len() + 2);
+            quoted.push('"');
+            for ch in s.chars() {
+                match ch {
+                    '"' => quoted.push_str("\\\""),
+                    '\\' => quoted.push_str("\\\\"),
+                    '\n' => quoted.push_str("\\n"),
+                    '\r' => quoted.push_str("\\r"),
+                    '\t' => quoted.push_str("\\t"),
+                    '\0' => quoted.push_str("\\0"),
+                    ch => quoted.push(ch),
+                }
+            }
+            quoted.push('"');
+            Ok(quoted)
+        }
+        value => Err(CoreError::InvalidParameter(format!(
+            "Vertex ID {} cannot be written as a literal",
+            value
+        ))),
+    }
+}
+
+/// Convert a value to the shape that serde types are decoded from
+///
+/// Scalars map to JSON scalars, lists and sets to arrays, maps to objects. A vertex maps
+/// to an object of all its properties plus `_vid` and `_tags`, an edge to an object of its
+/// properties plus `_src`, `_dst`, `_type` and `_rank`, and a path to
+/// `{"nodes": [...], "edges": [...]}`. Temporal and other values map to their text form.
+pub fn to_serde_value(value: &Value) -> serde_json::Value {
+    use serde_json::Value as Json;
+
+    let number = |f: f64| {
+        serde_json::Number::from_f64(f)
+            .map(Json::Number)
+            .unwrap_or(Json::Null)
+    };
+    match value {
+        Value::Empty | Value::Null(_) => Json::Null,
+        Value::Bool(b) => Json::Bool(*b),
+        Value::SmallInt(i) => Json::from(*i),
+        Value::Int(i) => Json::from(*i),
+        Value::BigInt(i) => Json::from(*i),
+        Value::Float(f) => number(*f as f64),
+        Value::Double(d) => number(*d),
+        Value::String(s) | Value::FixedString { data: s, .. } => Json::String(s.clone()),
+        Value::Blob(bytes) => Json::from(bytes.clone()),
+        Value::Vertex(vertex) => vertex_to_serde(vertex),
+        Value::Edge(edge) => edge_to_serde(edge),
+        Value::Path(path) => {
+            let mut nodes = vec![vertex_to_serde(&path.src)];
+            let mut edges = Vec::with_capacity(path.steps.len());
+            for step in &path.steps {
+                nodes.push(vertex_to_serde(&step.dst));
+                edges.push(edge_to_serde(&step.edge));
+            }
+            serde_json::json!({ "nodes": nodes, "edges": edges })
+        }
+        Value::List(list) => Json::Array(list.iter().map(to_serde_value).collect()),
+        Value::Set(set) => Json::Array(set.iter().map(to_serde_value).collect()),
+        Value::Map(map) => Json::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), to_serde_value(value)))
+                .collect(),
+        ),
+        Value::Vector(vector) => Json::Array(
+            vector
+                .to_dense()
+                .into_iter()
+                .map(|f| number(f as f64))
+                .collect(),
+        ),
+        Value::Json(json) => json
+            .to_value()
+            .unwrap_or_else(|_| Json::String(json.as_str().to_string())),
+        Value::JsonB(json) => json.as_value().clone(),
+        value => Json::String(value.to_string()),
+    }
+}
+
+fn vertex_to_serde(vertex: &Vertex) -> serde_json::Value {
+    let mut object: serde_json::Map<String, serde_json::Value> = vertex
+        .get_all_properties()
+        .into_iter()
+        .map(|(name, value)| (name, to_serde_value(value)))
+        .collect();
+    object.insert("_vid".to_string(), to_serde_value(&Value::from(vertex.vid)));
+    object.insert(
+        "_tags".to_string(),
+        vertex.tags.iter().map(|tag| tag.name.clone()).collect(),
+    );
+    serde_json::Value::Object(object)
+}
+
+fn edge_to_serde(edge: &Edge) -> serde_json::Value {
+    let mut object: serde_json::Map<String, serde_json::Value> = edge
+        .props
+        .iter()
+        .map(|(name, value)| (name.clone(), to_serde_value(value)))
+        .collect();
+    object.insert("_src".to_string(), to_serde_value(&Value::from(edge.src)));
+    object.insert("_dst".to_string(), to_serde_value(&Value::from(edge.dst)));
+    object.insert("_type".to_string(), edge.edge_type.clone().into());
+    object.insert("_rank".to_string(), edge.ranking.into());
+    serde_json::Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::core::Row as CoreRow;
+    use crate::api::embedded::{GraphEdge, GraphTag, Row};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, GraphTag)]
+    #[graph(crate = "crate", tag = "person")]
+    struct Person {
+        #[graph(vid)]
+        id: i64,
+        name: String,
+        #[graph(rename = "years")]
+        age: Option<i32>,
+    }
+
+    #[derive(Debug, PartialEq, GraphEdge)]
+    #[graph(crate = "crate", edge = "follow")]
+    struct Follow {
+        #[graph(src)]
+        from: String,
+        #[graph(dst)]
+        to: String,
+        #[graph(rank)]
+        rank: i64,
+        degree: f64,
+    }
+
+    #[test]
+    fn test_tag_round_trip() {
+        let person = Person {
+            id: 7,
+            name: "Alice \"A\"".to_string(),
+            age: None,
+        };
+        let vertex = person.to_vertex().expect("vertex");
+        assert_eq!(vertex.vid.as_int64(), Some(7));
+        assert_eq!(
+            vertex.get_property("person", "name"),
+            Some(&Value::String("Alice \"A\"".to_string()))
+        );
+        assert_eq!(Person::from_vertex(&vertex).expect("person"), person);
+        assert!(Person::from_vertex(&Vertex::with_vid(VertexId::from(7i64))).is_err());
+    }
+
+    #[test]
+    fn test_schema_and_property_names() {
+        assert_eq!(Person::NAME, "name");
+        assert_eq!(Person::AGE, "years");
+        assert_eq!(
+            Person::create_statement(),
+            "CREATE TAG IF NOT EXISTS person(name: STRING NOT NULL, years: INT32 NULL)"
+        );
+        assert_eq!(
+            Follow::create_statement(),
+            "CREATE EDGE IF NOT EXISTS follow(degree: DOUBLE NOT NULL)"
+        );
+    }
+
+    #[test]
+    fn test_edge_round_trip_and_update() {
+        let follow = Follow {
+            from: "a".to_string(),
+            to: "b".to_string(),
+            rank: 2,
+            degree: 0.5,
+        };
+        let edge = follow.to_edge().expect("edge");
+        assert_eq!(edge.ranking, 2);
+        assert_eq!(Follow::from_edge(&edge).expect("follow"), follow);
+        let update = update_edge_statement(&follow)
+            .expect("statement")
+            .expect("edge has properties");
+        assert_eq!(
+            update.query,
+            "UPDATE EDGE \"a\" -> \"b\" @2 OF follow SET degree = $p0"
+        );
+        assert_eq!(update.params.get("p0"), Some(&Value::Double(0.5)));
+    }
+
+    #[test]
+    fn test_integer_properties_accept_any_width() {
+        assert_eq!(i64::from_value(&Value::Int(3)), Some(3));
+        assert_eq!(i16::from_value(&Value::BigInt(70_000)), None);
+        assert_eq!(
+            Option::<i32>::from_value(&Value::Null(NullType::Null)),
+            Some(None)
+        );
+        assert_eq!(
+            vid_literal(&Value::String("a\\b\n".to_string())).unwrap(),
+            "\"a\\\\b\\n\""
+        );
+        assert!(vid_literal(&Value::Double(1.0)).is_err());
+    }
+
+    #[test]
+    fn test_row_decodes_columns_and_vertices() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct NameAge {
+            name: String,
+            age: Option<i64>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct PersonNode {
+            #[serde(rename = "_vid")]
+            id: i64,
+            name: String,
+            years: i64,
+        }
+
+        let vertex = Person {
+            id: 1,
+            name: "Bob".to_string(),
+            age: Some(30),
+        }
+        .to_vertex()
+        .expect("vertex");
+        let mut values = HashMap::new();
+        values.insert("name".to_string(), Value::String("Bob".to_string()));
+        values.insert("age".to_string(), Value::Null(NullType::Null));
+        values.insert("p".to_string(), Value::Vertex(Box::new(vertex)));
+        let row = Row::from_core(CoreRow { values });
+
+        assert_eq!(
+            row.deserialize::<NameAge>().expect("row"),
+            NameAge {
+                name: "Bob".to_string(),
+                age: None,
+            }
+        );
+        assert_eq!(
+            row.get_as::<PersonNode>("p").expect("vertex"),
+            PersonNode {
+                id: 1,
+                name: "Bob".to_string(),
+                years: 30,
+            }
+        );
+        assert!(row.get_as::<i64>("name").is_err());
+        assert!(row.get_as::<i64>("missing").is_err());
+    }
+}
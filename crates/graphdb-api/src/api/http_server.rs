@@ -57,9 +57,13 @@ pub async fn start_http_server<
 
     info!("HTTP server listening on {}", addr);
 
-    serve(listener, app)
-        .with_graceful_shutdown(async_shutdown_signal())
-        .await?;
+    // Connect info exposes the client IP to the rate limiter
+    serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .with_graceful_shutdown(async_shutdown_signal())
+    .await?;
 
     Ok(())
 }
@@ -119,9 +123,12 @@ pub async fn start_http_and_grpc_servers<
     // Start HTTP server
     let http_future = async move {
         let http_listener = TcpListener::bind(&http_addr).await?;
-        serve(http_listener, http_app)
-            .with_graceful_shutdown(async_shutdown_signal())
-            .await?;
+        serve(
+            http_listener,
+            http_app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+        )
+        .with_graceful_shutdown(async_shutdown_signal())
+        .await?;
         Ok::<(), crate::core::error::DBError>(())
     };
 
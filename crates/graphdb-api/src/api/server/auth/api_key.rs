@@ -0,0 +1,80 @@
+//! API Key Authenticator
+//!
+//! Accepts `gdb_<id>_<secret>` bearer tokens issued by `CREATE API KEY`. Keys are
+//! looked up through a verifier callback so the authenticator stays independent
+//! of the storage engine.
+
+use std::sync::Arc;
+
+use super::{AuthError, AuthMethod, AuthResult, AuthenticatedPrincipal, TokenAuthenticator};
+use crate::core::types::{ApiKeyInfo, API_KEY_PREFIX};
+
+/// API Key Lookup Callback - Resolves a token to its active key
+pub type ApiKeyVerifier = Arc<dyn Fn(&str) -> Option<ApiKeyInfo> + Send + Sync>;
+
+/// API Key Authenticator
+pub struct ApiKeyAuthenticator {
+    verifier: ApiKeyVerifier,
+}
+
+impl ApiKeyAuthenticator {
+    pub fn new<F>(verifier: F) -> Self
+    where
+        F: Fn(&str) -> Option<ApiKeyInfo> + Send + Sync + 'static,
+    {
+        Self {
+            verifier: Arc::new(verifier),
+        }
+    }
+}
+
+impl TokenAuthenticator for ApiKeyAuthenticator {
+    fn authenticate_token(&self, token: &str) -> AuthResult<Option<AuthenticatedPrincipal>> {
+        if !token.starts_with(API_KEY_PREFIX) {
+            return Ok(None);
+        }
+
+        // Unknown, revoked and expired keys are reported alike
+        let key = (self.verifier)(token).ok_or_else(|| {
+            AuthError::InvalidToken("API key is invalid, revoked or expired".to_string())
+        })?;
+
+        Ok(Some(AuthenticatedPrincipal {
+            username: key.username,
+            method: AuthMethod::ApiKey,
+            role: None,
+            scopes: key.scopes,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Permission;
+
+    #[test]
+    fn test_api_key_authenticator() {
+        let (key, token) = ApiKeyInfo::generate("alice".to_string(), vec![Permission::Read], None);
+        let stored = key.clone();
+        let auth = ApiKeyAuthenticator::new(move |candidate: &str| {
+            let (id, secret) = ApiKeyInfo::parse_token(candidate)?;
+            (id == stored.id && stored.matches_secret(secret)).then(|| stored.clone())
+        });
+
+        let principal = auth
+            .authenticate_token(&token)
+            .expect("valid key should authenticate")
+            .expect("API key format should be recognized");
+        assert_eq!(principal.username, "alice");
+        assert_eq!(principal.method, AuthMethod::ApiKey);
+        assert_eq!(principal.scopes, vec![Permission::Read]);
+
+        let forged = format!("{}{}_{}", API_KEY_PREFIX, key.id, "0".repeat(64));
+        assert!(matches!(
+            auth.authenticate_token(&forged),
+            Err(AuthError::InvalidToken(_))
+        ));
+        assert!(matches!(auth.authenticate_token("eyJ.e30.sig"), Ok(None)));
+    }
+}
@@ -1,14 +1,40 @@
 use dashmap::DashMap;
 use std::sync::Arc;
 
-use super::AuthResult;
+use super::{AuthError, AuthResult};
 use crate::config::AuthConfig;
+use crate::core::{Permission, RoleType};
 
 /// Authentication trait
 pub trait Authenticator: Send + Sync {
     fn authenticate(&self, username: &str, password: &str) -> AuthResult<()>;
 }
 
+/// How a principal proved its identity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    Password,
+    Jwt,
+    ApiKey,
+}
+
+/// Identity established from a bearer token
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedPrincipal {
+    pub username: String,
+    pub method: AuthMethod,
+    /// Built-in role granted by the token itself, applied to every space
+    pub role: Option<RoleType>,
+    /// Permissions the token is limited to (empty means no limit)
+    pub scopes: Vec<Permission>,
+}
+
+/// Bearer token authentication trait
+pub trait TokenAuthenticator: Send + Sync {
+    /// Returns `Ok(None)` when the token is not in a format this authenticator handles.
+    fn authenticate_token(&self, token: &str) -> AuthResult<Option<AuthenticatedPrincipal>>;
+}
+
 /// Login Failure Record
 #[derive(Debug, Clone)]
 struct LoginAttempt {
@@ -131,6 +157,59 @@ impl Authenticator for PasswordAuthenticator {
     }
 }
 
+/// Authenticator Chain - Password login plus any number of bearer token authenticators
+///
+/// Tokens are offered to the token authenticators in order; the first one that
+/// recognizes the token format decides.
+pub struct AuthenticatorChain {
+    password: PasswordAuthenticator,
+    token_authenticators: Vec<Box<dyn TokenAuthenticator>>,
+}
+
+impl AuthenticatorChain {
+    pub fn new(password: PasswordAuthenticator) -> Self {
+        Self {
+            password,
+            token_authenticators: Vec::new(),
+        }
+    }
+
+    /// Append a token authenticator to the chain
+    pub fn with_token_authenticator<T>(mut self, authenticator: T) -> Self
+    where
+        T: TokenAuthenticator + 'static,
+    {
+        self.token_authenticators.push(Box::new(authenticator));
+        self
+    }
+
+    /// Whether any bearer token authenticator is configured
+    pub fn accepts_tokens(&self) -> bool {
+        !self.token_authenticators.is_empty()
+    }
+
+    /// Authenticate a bearer token against the chain
+    pub fn authenticate_token(&self, token: &str) -> AuthResult<AuthenticatedPrincipal> {
+        if token.is_empty() {
+            return Err(AuthError::EmptyCredentials);
+        }
+        for authenticator in &self.token_authenticators {
+            if let Some(principal) = authenticator.authenticate_token(token)? {
+                return Ok(principal);
+            }
+        }
+        Err(AuthError::InvalidToken(
+            "Unsupported token format".to_string(),
+        ))
+    }
+}
+
+impl Authenticator for AuthenticatorChain {
+    fn authenticate(&self, username: &str, password: &str) -> AuthResult<()> {
+        self.password.authenticate(username, password)
+    }
+}
+
 /// Authenticator Factory
 pub struct AuthenticatorFactory;
 
@@ -161,6 +240,7 @@ mod tests {
             default_username: "test".to_string(),
             default_password: "test123".to_string(),
             force_change_default_password: true,
+            ..Default::default()
         }
     }
 
@@ -189,6 +269,7 @@ mod tests {
             default_username: "admin".to_string(),
             default_password: "admin123".to_string(),
             force_change_default_password: false,
+            ..Default::default()
         };
 
         let auth = PasswordAuthenticator::new_default(config);
@@ -209,6 +290,7 @@ mod tests {
             default_username: "test".to_string(),
             default_password: "test123".to_string(),
             force_change_default_password: false,
+            ..Default::default()
         };
 
         let auth = PasswordAuthenticator::new(|_username: &str, _password: &str| Ok(false), config);
@@ -251,6 +333,7 @@ mod tests {
             default_username: "test".to_string(),
             default_password: "test123".to_string(),
             force_change_default_password: false,
+            ..Default::default()
         };
 
         let success = Arc::new(AtomicBool::new(false));
@@ -290,6 +373,7 @@ mod tests {
             default_username: "test".to_string(),
             default_password: "test123".to_string(),
             force_change_default_password: false,
+            ..Default::default()
         };
 
         let _auth =
@@ -299,4 +383,57 @@ mod tests {
         let _auth_default = AuthenticatorFactory::create_default(&config);
         // Verify successful creation
     }
+
+    struct PrefixAuthenticator(&'static str);
+
+    impl TokenAuthenticator for PrefixAuthenticator {
+        fn authenticate_token(&self, token: &str) -> AuthResult<Option<AuthenticatedPrincipal>> {
+            let Some(username) = token.strip_prefix(self.0) else {
+                return Ok(None);
+            };
+            if username.is_empty() {
+                return Err(AuthError::InvalidToken("missing user".to_string()));
+            }
+            Ok(Some(AuthenticatedPrincipal {
+                username: username.to_string(),
+                method: AuthMethod::ApiKey,
+                role: None,
+                scopes: vec![Permission::Read],
+            }))
+        }
+    }
+
+    #[test]
+    fn test_authenticator_chain_tokens() {
+        let chain =
+            AuthenticatorChain::new(PasswordAuthenticator::new_default(create_test_config()));
+        assert!(!chain.accepts_tokens());
+        assert!(chain.authenticate("test", "test123").is_ok());
+        assert!(chain.authenticate_token("a:alice").is_err());
+
+        let chain = chain
+            .with_token_authenticator(PrefixAuthenticator("a:"))
+            .with_token_authenticator(PrefixAuthenticator("b:"));
+        assert!(chain.accepts_tokens());
+
+        let principal = chain
+            .authenticate_token("b:bob")
+            .expect("second authenticator should accept the token");
+        assert_eq!(principal.username, "bob");
+        assert_eq!(principal.scopes, vec![Permission::Read]);
+
+        // The first authenticator that recognizes the format decides
+        assert!(matches!(
+            chain.authenticate_token("a:"),
+            Err(AuthError::InvalidToken(_))
+        ));
+        assert!(matches!(
+            chain.authenticate_token("c:carol"),
+            Err(AuthError::InvalidToken(_))
+        ));
+        assert!(matches!(
+            chain.authenticate_token(""),
+            Err(AuthError::EmptyCredentials)
+        ));
+    }
 }
@@ -24,6 +24,12 @@ pub enum AuthError {
     #[error("Maximum attempts exceeded")]
     MaxAttemptsExceeded,
 
+    #[error("Account {0} is locked")]
+    AccountLocked(String),
+
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+
     #[error("Authenticator error: {0}")]
     AuthenticatorError(String),
 }
@@ -39,6 +45,8 @@ impl ToPublicError for AuthError {
             AuthError::EmptyCredentials => ErrorCode::InvalidInput,
             AuthError::InvalidCredentials(_) => ErrorCode::Unauthorized,
             AuthError::MaxAttemptsExceeded => ErrorCode::ResourceExhausted,
+            AuthError::AccountLocked(_) => ErrorCode::Forbidden,
+            AuthError::InvalidToken(_) => ErrorCode::Unauthorized,
             AuthError::AuthenticatorError(_) => ErrorCode::InternalError,
         }
     }
@@ -0,0 +1,286 @@
+//! JWT Authenticator
+//!
+//! Verifies bearer tokens issued by an external identity provider. Signatures are
+//! checked against PEM public keys or a JWKS file; the username and roles are read
+//! from configurable claims.
+
+use std::collections::HashMap;
+
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde_json::Value as JsonValue;
+
+use super::{AuthError, AuthMethod, AuthResult, AuthenticatedPrincipal, TokenAuthenticator};
+use crate::config::JwtConfig;
+use crate::core::RoleType;
+
+/// Verification key, optionally bound to a JWKS key id
+struct VerificationKey {
+    kid: Option<String>,
+    key: DecodingKey,
+}
+
+/// JWT Authenticator
+pub struct JwtAuthenticator {
+    keys: Vec<VerificationKey>,
+    algorithms: Vec<Algorithm>,
+    issuers: Vec<String>,
+    audiences: Vec<String>,
+    leeway_secs: u64,
+    username_claim: String,
+    roles_claim: String,
+    role_mapping: HashMap<String, RoleType>,
+}
+
+impl JwtAuthenticator {
+    /// Create an authenticator without keys; add them with [`Self::add_key`]
+    pub fn new(config: &JwtConfig) -> AuthResult<Self> {
+        let algorithms = config
+            .algorithms
+            .iter()
+            .map(|name| {
+                name.parse::<Algorithm>().map_err(|_| {
+                    AuthError::AuthenticatorError(format!("Unsupported JWT algorithm: {}", name))
+                })
+            })
+            .collect::<AuthResult<Vec<_>>>()?;
+
+        let role_mapping = config
+            .role_mapping
+            .iter()
+            .map(|(claim, role)| {
+                role.parse::<RoleType>()
+                    .map(|role| (claim.clone(), role))
+                    .map_err(AuthError::AuthenticatorError)
+            })
+            .collect::<AuthResult<HashMap<_, _>>>()?;
+
+        Ok(Self {
+            keys: Vec::new(),
+            algorithms,
+            issuers: config.issuers.clone(),
+            audiences: config.audiences.clone(),
+            leeway_secs: config.leeway_secs,
+            username_claim: config.username_claim.clone(),
+            roles_claim: config.roles_claim.clone(),
+            role_mapping,
+        })
+    }
+
+    /// Create an authenticator and load the configured PEM keys and JWKS file
+    pub fn from_config(config: &JwtConfig) -> AuthResult<Self> {
+        let mut authenticator = Self::new(config)?;
+
+        for path in &config.pem_keys {
+            let pem = std::fs::read(path).map_err(|e| {
+                AuthError::AuthenticatorError(format!("Failed to read JWT key {}: {}", path, e))
+            })?;
+            let key = DecodingKey::from_rsa_pem(&pem)
+                .or_else(|_| DecodingKey::from_ec_pem(&pem))
+                .or_else(|_| DecodingKey::from_ed_pem(&pem))
+                .map_err(|e| {
+                    AuthError::AuthenticatorError(format!("Invalid JWT key {}: {}", path, e))
+                })?;
+            authenticator.add_key(None, key);
+        }
+
+        if let Some(path) = &config.jwks_file {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                AuthError::AuthenticatorError(format!("Failed to read JWKS file {}: {}", path, e))
+            })?;
+            let jwks: JwkSet = serde_json::from_str(&content).map_err(|e| {
+                AuthError::AuthenticatorError(format!("Invalid JWKS file {}: {}", path, e))
+            })?;
+            for jwk in &jwks.keys {
+                let key = DecodingKey::from_jwk(jwk).map_err(|e| {
+                    AuthError::AuthenticatorError(format!(
+                        "Invalid key in JWKS file {}: {}",
+                        path, e
+                    ))
+                })?;
+                authenticator.add_key(jwk.common.key_id.clone(), key);
+            }
+        }
+
+        Ok(authenticator)
+    }
+
+    /// Register a verification key; keys without a kid are tried for every token
+    pub fn add_key(&mut self, kid: Option<String>, key: DecodingKey) {
+        self.keys.push(VerificationKey { kid, key });
+    }
+
+    fn validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+        validation.algorithms = vec![algorithm];
+        validation.leeway = self.leeway_secs;
+        validation.set_required_spec_claims(&["exp"]);
+        if !self.issuers.is_empty() {
+            validation.set_issuer(&self.issuers);
+        }
+        if self.audiences.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.audiences);
+        }
+        validation
+    }
+
+    /// Most privileged built-in role the roles claim maps to
+    ///
+    /// Only values listed in `role_mapping` count; a claim value that happens to
+    /// spell a built-in role name grants nothing unless it is mapped.
+    fn resolve_role(&self, claims: &JsonValue) -> Option<RoleType> {
+        let values: Vec<&str> = match claims.get(&self.roles_claim)? {
+            JsonValue::String(role) => vec![role.as_str()],
+            JsonValue::Array(roles) => roles.iter().filter_map(JsonValue::as_str).collect(),
+            _ => return None,
+        };
+
+        values
+            .into_iter()
+            .filter_map(|value| self.role_mapping.get(value).copied())
+            .min_by_key(|role| *role as u8)
+    }
+}
+
+impl TokenAuthenticator for JwtAuthenticator {
+    fn authenticate_token(&self, token: &str) -> AuthResult<Option<AuthenticatedPrincipal>> {
+        if token.split('.').count() != 3 {
+            return Ok(None);
+        }
+
+        let header = decode_header(token)
+            .map_err(|e| AuthError::InvalidToken(format!("Malformed JWT header: {}", e)))?;
+        if !self.algorithms.contains(&header.alg) {
+            return Err(AuthError::InvalidToken(format!(
+                "JWT algorithm {:?} is not allowed",
+                header.alg
+            )));
+        }
+
+        let validation = self.validation(header.alg);
+        let mut last_error = None;
+        let mut claims = None;
+        for candidate in self
+            .keys
+            .iter()
+            .filter(|k| k.kid.is_none() || k.kid == header.kid)
+        {
+            match decode::<JsonValue>(token, &candidate.key, &validation) {
+                Ok(data) => {
+                    claims = Some(data.claims);
+                    break;
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        let claims = claims.ok_or_else(|| match last_error {
+            Some(e) => AuthError::InvalidToken(format!("JWT verification failed: {}", e)),
+            None => AuthError::InvalidToken("No key available to verify JWT".to_string()),
+        })?;
+
+        let username = claims
+            .get(&self.username_claim)
+            .and_then(JsonValue::as_str)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| {
+                AuthError::InvalidToken(format!("JWT is missing the {} claim", self.username_claim))
+            })?
+            .to_string();
+
+        Ok(Some(AuthenticatedPrincipal {
+            username,
+            method: AuthMethod::Jwt,
+            role: self.resolve_role(&claims),
+            scopes: Vec::new(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    fn authenticator() -> JwtAuthenticator {
+        let mut config = JwtConfig {
+            enabled: true,
+            issuers: vec!["https://idp.example.com".to_string()],
+            algorithms: vec!["HS256".to_string()],
+            ..Default::default()
+        };
+        config
+            .role_mapping
+            .insert("graph-admins".to_string(), "ADMIN".to_string());
+        let mut auth = JwtAuthenticator::new(&config).expect("valid config");
+        auth.add_key(None, DecodingKey::from_secret(SECRET));
+        auth
+    }
+
+    fn token(claims: JsonValue, secret: &[u8]) -> String {
+        encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(secret),
+        )
+        .expect("encode token")
+    }
+
+    fn exp() -> i64 {
+        chrono::Utc::now().timestamp() + 3600
+    }
+
+    #[test]
+    fn test_jwt_authenticator() {
+        let auth = authenticator();
+
+        let valid = token(
+            json!({"sub": "alice", "iss": "https://idp.example.com", "exp": exp(),
+                   "roles": ["reader", "graph-admins", "USER"]}),
+            SECRET,
+        );
+        let principal = auth
+            .authenticate_token(&valid)
+            .expect("valid token")
+            .expect("JWT format should be recognized");
+        assert_eq!(principal.username, "alice");
+        assert_eq!(principal.method, AuthMethod::Jwt);
+        assert_eq!(principal.role, Some(RoleType::Admin));
+
+        let unmapped = token(
+            json!({"sub": "mallory", "iss": "https://idp.example.com", "exp": exp(),
+                   "roles": ["GOD", "admin"]}),
+            SECRET,
+        );
+        let principal = auth
+            .authenticate_token(&unmapped)
+            .expect("valid token")
+            .expect("JWT format should be recognized");
+        assert_eq!(principal.role, None);
+
+        let wrong_issuer = token(
+            json!({"sub": "alice", "iss": "https://other.example.com", "exp": exp()}),
+            SECRET,
+        );
+        assert!(auth.authenticate_token(&wrong_issuer).is_err());
+
+        let bad_signature = token(
+            json!({"sub": "alice", "iss": "https://idp.example.com", "exp": exp()}),
+            b"other-secret",
+        );
+        assert!(auth.authenticate_token(&bad_signature).is_err());
+
+        let expired = token(
+            json!({"sub": "alice", "iss": "https://idp.example.com", "exp": exp() - 7200}),
+            SECRET,
+        );
+        assert!(auth.authenticate_token(&expired).is_err());
+
+        assert!(matches!(auth.authenticate_token("gdb_abc_def"), Ok(None)));
+    }
+}
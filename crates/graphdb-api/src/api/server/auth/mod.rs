@@ -2,8 +2,15 @@
 //!
 //! Provide user authentication and authorization features.
 
+pub mod api_key;
 pub mod authenticator;
 pub mod error;
+pub mod jwt;
 pub use crate::core::UserStorage;
-pub use authenticator::{Authenticator, AuthenticatorFactory, PasswordAuthenticator, UserVerifier};
+pub use api_key::{ApiKeyAuthenticator, ApiKeyVerifier};
+pub use authenticator::{
+    AuthMethod, AuthenticatedPrincipal, Authenticator, AuthenticatorChain, AuthenticatorFactory,
+    PasswordAuthenticator, TokenAuthenticator, UserVerifier,
+};
 pub use error::{AuthError, AuthResult};
+pub use jwt::JwtAuthenticator;
@@ -79,6 +79,26 @@ impl ClientSession {
         self.role_context.set_role(space, role);
     }
 
+    pub fn set_scopes(&self, scopes: Vec<crate::core::Permission>) {
+        self.role_context.set_scopes(scopes);
+    }
+
+    pub fn scopes(&self) -> Vec<crate::core::Permission> {
+        self.role_context.scopes()
+    }
+
+    pub fn allows_permission(&self, permission: crate::core::Permission) -> bool {
+        self.role_context.allows_permission(permission)
+    }
+
+    pub fn set_password_expired(&self, expired: bool) {
+        self.role_context.set_password_expired(expired);
+    }
+
+    pub fn password_expired(&self) -> bool {
+        self.role_context.password_expired()
+    }
+
     pub fn idle_seconds(&self) -> u64 {
         self.idle_start_time.read().elapsed().as_secs()
     }
@@ -1,11 +1,16 @@
-use crate::core::RoleType;
+use crate::core::{Permission, RoleType};
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct RoleContext {
     roles: Arc<RwLock<HashMap<i64, RoleType>>>,
+    /// Permissions an API key limits the session to (empty means no limit)
+    scopes: Arc<RwLock<Vec<Permission>>>,
+    /// Set when the user logged in with an expired password; only CHANGE PASSWORD is allowed
+    password_expired: Arc<AtomicBool>,
 }
 
 impl Default for RoleContext {
@@ -18,6 +23,8 @@ impl RoleContext {
     pub fn new() -> Self {
         Self {
             roles: Arc::new(RwLock::new(HashMap::new())),
+            scopes: Arc::new(RwLock::new(Vec::new())),
+            password_expired: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -46,6 +53,27 @@ impl RoleContext {
             .values()
             .any(|role| *role == RoleType::Admin || *role == RoleType::God)
     }
+
+    pub fn set_scopes(&self, scopes: Vec<Permission>) {
+        *self.scopes.write() = scopes;
+    }
+
+    pub fn scopes(&self) -> Vec<Permission> {
+        self.scopes.read().clone()
+    }
+
+    pub fn allows_permission(&self, permission: Permission) -> bool {
+        let scopes = self.scopes.read();
+        scopes.is_empty() || scopes.contains(&permission)
+    }
+
+    pub fn set_password_expired(&self, expired: bool) {
+        self.password_expired.store(expired, Ordering::Relaxed);
+    }
+
+    pub fn password_expired(&self) -> bool {
+        self.password_expired.load(Ordering::Relaxed)
+    }
 }
 
 #[cfg(test)]
@@ -66,5 +94,14 @@ mod tests {
 
         context.set_role(2, RoleType::God);
         assert!(context.is_god());
+
+        assert!(context.allows_permission(Permission::Write));
+        context.set_scopes(vec![Permission::Read]);
+        assert!(context.allows_permission(Permission::Read));
+        assert!(!context.allows_permission(Permission::Write));
+
+        assert!(!context.password_expired());
+        context.set_password_expired(true);
+        assert!(context.password_expired());
     }
 }
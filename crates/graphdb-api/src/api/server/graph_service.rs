@@ -2,24 +2,37 @@ use crate::api::core::{QueryApi, SyncApi};
 
 #[cfg(feature = "qdrant")]
 use crate::api::core::VectorApi;
-use crate::api::server::auth::{Authenticator, AuthenticatorFactory, PasswordAuthenticator};
-use crate::api::server::permission::PermissionManager;
-use crate::api::server::session::{ClientSession, GraphSessionManager};
+use crate::api::server::auth::{
+    ApiKeyAuthenticator, AuthError, Authenticator, AuthenticatorChain, AuthenticatorFactory,
+    JwtAuthenticator, PasswordAuthenticator,
+};
+use crate::api::server::permission::{PermissionManager, GOD_SPACE_ID};
+use crate::api::server::session::{ClientSession, GraphSessionManager, QuotaExceeded, QuotaLimits};
 use crate::api::server::session::{SessionError, SessionResult};
-use crate::config::Config;
+use crate::config::{AuthConfig, Config};
+use crate::core::error::{DBError, DBResult};
 use crate::core::metadata::SchemaManager;
 use crate::core::stats::StatsManager;
 use crate::core::types::SpaceSummary;
-use crate::core::types::TransactionContextInfo;
-use crate::core::{DataType, MetricType, Permission};
+use crate::core::types::{LoginCheck, PasswordPolicy, UserInfo};
+use crate::core::types::{TransactionContextInfo, TransactionId};
+use crate::core::{DataType, MetricType, Permission, RoleType};
+use crate::query::executor::control_flow::BatchTransactionControl;
 use crate::query::executor::ExecutionResult;
+use crate::query::parser::Parser;
+use crate::query::validator::helpers::required_permission;
 use crate::query::DataSet;
 use crate::storage::{
     StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
+    UndoTarget,
 };
-use crate::transaction::TransactionManager;
+use crate::transaction::{TransactionManager, TransactionOptions};
+use dashmap::DashMap;
 use log::{info, warn};
 use parking_lot::RwLock;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::Duration;
 #[cfg(feature = "qdrant")]
@@ -42,11 +55,66 @@ impl<S: StorageTransactionContextOps + ?Sized> Drop for TransactionContextGuard<
     }
 }
 
+/// Runs each batch of `CALL { } IN TRANSACTIONS`, and the write-back of the `.write`
+/// procedures, in its own transaction.
+///
+/// The batch transaction is installed on the storage the same way a session transaction is,
+/// so the writes of the statement go to it until it is committed or rolled back.
+struct ServiceBatchTransactions<S: StorageTransactionContextOps + UndoTarget> {
+    transaction_manager: Arc<TransactionManager>,
+    storage: Arc<S>,
+}
+
+impl<S: StorageTransactionContextOps + UndoTarget + Send + Sync> BatchTransactionControl
+    for ServiceBatchTransactions<S>
+{
+    fn in_explicit_transaction(&self) -> bool {
+        self.storage.get_transaction_context().is_some()
+    }
+
+    fn begin_batch(&self) -> DBResult<TransactionId> {
+        let txn_id = self
+            .transaction_manager
+            .begin_transaction(TransactionOptions::default())
+            .map_err(|e| DBError::transaction(e.to_string()))?;
+        let ctx = self
+            .transaction_manager
+            .get_context(txn_id)
+            .map_err(|e| DBError::transaction(e.to_string()))?;
+        self.storage
+            .set_transaction_context(Some(Arc::new(TransactionContextInfo::new(
+                ctx.id,
+                ctx.start_timestamp,
+                ctx.read_only,
+                0,
+            ))));
+        Ok(txn_id)
+    }
+
+    fn commit_batch(&self, txn_id: TransactionId) -> DBResult<()> {
+        let _guard = TransactionContextGuard::new(self.storage.as_ref());
+        self.transaction_manager
+            .commit_transaction_with_undo(txn_id, self.storage.as_ref())
+            .map_err(|e| DBError::transaction(e.to_string()))
+    }
+
+    fn rollback_batch(&self, txn_id: TransactionId) -> DBResult<()> {
+        let _guard = TransactionContextGuard::new(self.storage.as_ref());
+        self.transaction_manager
+            .abort_transaction_with_undo(txn_id, self.storage.as_ref())
+            .map_err(|e| DBError::transaction(e.to_string()))
+    }
+}
+
 pub struct GraphService<S: StorageClient + Clone + 'static> {
     session_manager: Arc<GraphSessionManager>,
     query_api: Arc<RwLock<QueryApi<S>>>,
-    authenticator: PasswordAuthenticator,
+    authenticator: AuthenticatorChain,
+    /// Password policy installed on the user store, used to flag expired passwords at login
+    password_policy: PasswordPolicy,
     permission_manager: Arc<PermissionManager>,
+    /// Sessions opened by bearer tokens, keyed by the token's SHA-256 digest
+    token_sessions: DashMap<String, i64>,
     pub stats_manager: Arc<StatsManager>,
     storage: Arc<S>,
     #[cfg(feature = "qdrant")]
@@ -236,7 +304,44 @@ impl<
             Arc::new(RwLock::new(api))
         };
 
-        let authenticator = AuthenticatorFactory::create_default(&config.server.auth);
+        if let Some(ref transaction_manager) = transaction_manager {
+            query_api
+                .write()
+                .set_batch_transaction_control(Arc::new(ServiceBatchTransactions {
+                    transaction_manager: transaction_manager.clone(),
+                    storage: storage.clone(),
+                }));
+        }
+
+        let password_policy = config.server.security.password_policy.to_password_policy();
+        storage.set_password_policy(password_policy.clone());
+        Self::bootstrap_default_user(&storage, &config.server.auth);
+
+        let mut authenticator = AuthenticatorChain::new(Self::build_password_authenticator(
+            &storage,
+            &config.server.auth,
+        ));
+        if config.server.auth.jwt.enabled {
+            match JwtAuthenticator::from_config(&config.server.auth.jwt) {
+                Ok(jwt) => authenticator = authenticator.with_token_authenticator(jwt),
+                Err(e) => warn!("JWT authentication disabled: {}", e),
+            }
+        }
+        let quota_storage = storage.clone();
+        session_manager
+            .quota_manager()
+            .set_limits_resolver(move |username: &str| {
+                quota_storage
+                    .get_user(username)
+                    .map(|user| QuotaLimits::from(&user))
+                    .unwrap_or_default()
+            });
+
+        let key_storage = storage.clone();
+        let authenticator =
+            authenticator.with_token_authenticator(ApiKeyAuthenticator::new(move |token: &str| {
+                key_storage.verify_api_key(token)
+            }));
         let permission_manager = Arc::new(PermissionManager::new());
 
         // Create sync API if storage supports it
@@ -248,7 +353,9 @@ impl<
             session_manager,
             query_api,
             authenticator,
+            password_policy,
             permission_manager,
+            token_sessions: DashMap::new(),
             stats_manager,
             storage,
             #[cfg(feature = "qdrant")]
@@ -259,6 +366,53 @@ impl<
         Arc::new(service)
     }
 
+    /// Store the configured default account on first start.
+    ///
+    /// With `force_change_default_password` its password is created expired, so the
+    /// first session must run CHANGE PASSWORD before anything else.
+    fn bootstrap_default_user(storage: &Arc<S>, auth: &AuthConfig) {
+        if auth.default_username.is_empty() || storage.user_exists(&auth.default_username) {
+            return;
+        }
+        let user = match UserInfo::new(auth.default_username.clone(), auth.default_password.clone())
+        {
+            Ok(user) => user.with_password_expired(auth.force_change_default_password),
+            Err(e) => {
+                warn!("Failed to create the default user: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = (**storage).clone().create_user(&user) {
+            warn!("Failed to store the default user: {}", e);
+        }
+    }
+
+    /// Password logins are verified against the stored users.
+    ///
+    /// Failed attempts and lockouts of stored users are persisted with the user; names
+    /// that are not stored fall back to the configured default credentials.
+    fn build_password_authenticator(storage: &Arc<S>, auth: &AuthConfig) -> PasswordAuthenticator {
+        let storage = storage.clone();
+        let max_failed_attempts = auth.failed_login_attempts;
+        let default_username = auth.default_username.clone();
+        let default_password = auth.default_password.clone();
+        AuthenticatorFactory::create(auth, move |username: &str, password: &str| {
+            match storage.verify_login(username, password, max_failed_attempts) {
+                LoginCheck::Accepted { .. } => Ok(true),
+                LoginCheck::Rejected {
+                    remaining_attempts: Some(remaining),
+                } => Err(AuthError::InvalidCredentials(remaining)),
+                LoginCheck::Rejected { .. } => Err(AuthError::AuthenticationFailed(
+                    "Invalid username or password".to_string(),
+                )),
+                LoginCheck::Locked => Err(AuthError::AccountLocked(username.to_string())),
+                LoginCheck::UnknownUser => {
+                    Ok(username == default_username && password == default_password)
+                }
+            }
+        })
+    }
+
     /// Shared helper: build a QueryApi with optional SchemaManager
     fn build_query_api(
         storage: &Arc<S>,
@@ -298,6 +452,13 @@ impl<
                     .await
                     .map_err(|e| format!("Creating a session failed: {}", e))?;
 
+                let now = chrono::Utc::now().timestamp_millis();
+                let password_expired = self
+                    .storage
+                    .get_user(username)
+                    .is_some_and(|user| user.is_password_expired(&self.password_policy, now));
+                session.set_password_expired(password_expired);
+
                 Ok(session)
             }
             Err(e) => {
@@ -308,14 +469,76 @@ impl<
         }
     }
 
+    /// Authenticate a bearer token (JWT or API key) and return its session.
+    ///
+    /// The token is verified on every call so revoked keys and expired JWTs are
+    /// rejected immediately; the session itself is reused across requests.
+    /// `client_ip` is the peer address of the request, when the transport knows it.
+    pub async fn authenticate_token(
+        &self,
+        token: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<Arc<ClientSession>, String> {
+        let principal = match self.authenticator.authenticate_token(token) {
+            Ok(principal) => principal,
+            Err(e) => {
+                self.stats_manager
+                    .add_value(MetricType::NumAuthFailedSessions);
+                return Err(format!("authentication failure: {}", e));
+            }
+        };
+
+        let token_key = format!("{:x}", Sha256::digest(token.as_bytes()));
+        if let Some(session_id) = self.token_sessions.get(&token_key).map(|id| *id) {
+            if let Some(session) = self.session_manager.find_session(session_id) {
+                return Ok(session);
+            }
+            self.token_sessions.remove(&token_key);
+        }
+
+        if self.session_manager.is_out_of_connections().await {
+            self.stats_manager
+                .add_value(MetricType::NumAuthFailedSessions);
+            return Err("More than the maximum number of connections limit".to_string());
+        }
+
+        let session = self
+            .session_manager
+            .create_session(
+                principal.username,
+                client_ip.map_or_else(|| "unknown".to_string(), |ip| ip.to_string()),
+            )
+            .await
+            .map_err(|e| format!("Creating a session failed: {}", e))?;
+        if let Some(role) = principal.role {
+            session.set_role(GOD_SPACE_ID, role);
+        }
+        session.set_scopes(principal.scopes);
+        self.token_sessions.insert(token_key, session.id());
+
+        Ok(session)
+    }
+
+    /// Check a statement against the session user's quotas without charging it.
+    ///
+    /// Transports call this first to report a typed error with a retry-after;
+    /// execution itself re-checks and records the statement.
+    pub fn check_query_quota(&self, session_id: i64, stmt: &str) -> Result<(), QuotaExceeded> {
+        let Some(session) = self.session_manager.find_session(session_id) else {
+            return Ok(());
+        };
+        let is_update = self.extract_permission_from_statement(stmt) != Permission::Read;
+        self.session_manager
+            .quota_manager()
+            .check_query(&session.user(), is_update)
+    }
+
     pub async fn execute(&self, session_id: i64, stmt: &str) -> Result<ExecutionResult, String> {
         let session = self
             .session_manager
             .find_session(session_id)
             .ok_or_else(|| format!("Invalid session ID: {}", session_id))?;
 
-        let space_id = session.space().map(|s| s.id as i64).unwrap_or(0);
-
         // Cleanup expired transactions before processing any statement.
         // This prevents stale transactions from blocking new write operations.
         if let Some(ref txn_manager) = self.transaction_manager {
@@ -337,7 +560,7 @@ impl<
         }
 
         // Perform a regular query using core layer QueryApi
-        let mut result = self.execute_query_with_permission(session_id, stmt, space_id);
+        let result = self.execute_query_with_permission(session_id, stmt, session.space(), None);
 
         // Handle SpaceSwitched result from USE statement
         // The core QueryApi converts SpaceSwitched to a DataSet with space_name/space_id columns,
@@ -350,11 +573,19 @@ impl<
             }
         }
 
-        // Automatic submission mode processing
+        self.finish_auto_commit(&session, result)
+    }
+
+    /// Commit the session's transaction after a successful statement in auto-commit mode
+    fn finish_auto_commit(
+        &self,
+        session: &ClientSession,
+        mut result: Result<ExecutionResult, String>,
+    ) -> Result<ExecutionResult, String> {
         if result.is_ok() && session.is_auto_commit() {
             if let Some(txn_id) = session.current_transaction() {
                 if let Some(ref txn_manager) = self.transaction_manager {
-                    match txn_manager.commit_transaction(txn_id) {
+                    match txn_manager.commit_transaction_with_undo(txn_id, &*self.storage) {
                         Ok(()) => {
                             session.unbind_transaction();
                         }
@@ -371,11 +602,82 @@ impl<
         result
     }
 
+    /// Prepare a parameterised statement, caching its plan for later executions
+    ///
+    /// The statement is planned against `space` when given, otherwise against the
+    /// session's current space. Returns the placeholder names it expects.
+    pub fn prepare(
+        &self,
+        session_id: i64,
+        stmt: &str,
+        space: Option<&str>,
+    ) -> Result<Vec<String>, String> {
+        let session = self
+            .session_manager
+            .find_session(session_id)
+            .ok_or_else(|| format!("Invalid session ID: {}", session_id))?;
+        let space = self.resolve_space(&session, space)?;
+
+        let query_request = crate::api::core::QueryRequest {
+            space_id: space.as_ref().map(|s| s.id),
+            space_name: space.map(|s| s.name),
+            auto_commit: session.is_auto_commit(),
+            transaction_id: session.current_transaction(),
+            parameters: None,
+            user_name: Some(session.user()),
+            role: None,
+        };
+
+        self.query_api
+            .write()
+            .prepare(stmt, query_request)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Execute a parameterised statement with the given parameter bindings
+    ///
+    /// Goes through the same permission and transaction handling as [`Self::execute`],
+    /// but reuses the plan cached by [`Self::prepare`].
+    pub async fn execute_prepared(
+        &self,
+        session_id: i64,
+        stmt: &str,
+        space: Option<&str>,
+        parameters: HashMap<String, crate::core::Value>,
+    ) -> Result<ExecutionResult, String> {
+        let session = self
+            .session_manager
+            .find_session(session_id)
+            .ok_or_else(|| format!("Invalid session ID: {}", session_id))?;
+        let space = self.resolve_space(&session, space)?;
+
+        let result = self.execute_query_with_permission(session_id, stmt, space, Some(parameters));
+        self.finish_auto_commit(&session, result)
+    }
+
+    /// Resolve an explicitly named space, falling back to the session's current one
+    pub fn resolve_space(
+        &self,
+        session: &ClientSession,
+        space: Option<&str>,
+    ) -> Result<Option<SpaceSummary>, String> {
+        match space {
+            Some(name) => self
+                .storage
+                .get_space(name)
+                .map_err(|e| e.to_string())?
+                .map(|info| Some(SpaceSummary::from(info)))
+                .ok_or_else(|| format!("Space not found: {}", name)),
+            None => Ok(session.space()),
+        }
+    }
+
     fn execute_query_with_permission(
         &self,
         session_id: i64,
         stmt: &str,
-        space_id: i64,
+        space: Option<SpaceSummary>,
+        parameters: Option<HashMap<String, crate::core::Value>>,
     ) -> Result<ExecutionResult, String> {
         let session = self
             .session_manager
@@ -384,23 +686,58 @@ impl<
 
         session.charge();
 
+        let space_id = space.as_ref().map(|s| s.id as i64).unwrap_or(0);
+
         let username = session.user();
 
+        let permission = self.extract_permission_from_statement(stmt);
+
+        // An expired password has to be changed before anything else runs
+        let is_change_password = stmt
+            .trim_start()
+            .to_uppercase()
+            .starts_with("CHANGE PASSWORD");
+        if session.password_expired() && !is_change_password {
+            return Err(
+                "Password expired: change it with CHANGE PASSWORD before running other statements"
+                    .to_string(),
+            );
+        }
+
         // Permission check: The admin has all permissions, so no check is required.
         // USE is a session-level operation that does not access data — skip permission
         // check so any authenticated user can switch to a space.
-        if !self.permission_manager.is_admin(&username)
-            && !stmt.trim().to_uppercase().starts_with("USE ")
-        {
-            let permission = self.extract_permission_from_statement(stmt);
-            if let Err(e) = self
-                .permission_manager
-                .check_permission(&username, space_id, permission)
-            {
-                return Err(format!("Permission check failed: {}", e));
+        if !stmt.trim().to_uppercase().starts_with("USE ") {
+            // API key scopes bound every user, administrators included
+            if !session.allows_permission(permission) {
+                return Err(format!(
+                    "Permission check failed: API key scope does not include {}",
+                    permission
+                ));
+            }
+
+            if !self.permission_manager.is_admin(&username) && !session.is_admin() {
+                if let Err(e) = self
+                    .permission_manager
+                    .check_permission(&username, space_id, permission)
+                {
+                    // Roles granted by a JWT live on the session rather than in the catalog
+                    let token_role = session
+                        .role_with_space(space_id)
+                        .or_else(|| session.role_with_space(GOD_SPACE_ID));
+                    if !token_role.is_some_and(|role| role.has_permission(permission)) {
+                        return Err(format!("Permission check failed: {}", e));
+                    }
+                }
             }
         }
 
+        // Only permitted statements are charged against the user's hourly quotas
+        self.session_manager
+            .quota_manager()
+            .acquire_query(&username, permission != Permission::Read)
+            .map_err(|e| e.to_string())?;
+
         // If session has an active transaction, set the transaction context on storage
         // so that subsequent queries execute within the same transaction
         let txn_context = if let Some(txn_id) = session.current_transaction() {
@@ -449,15 +786,21 @@ impl<
 
         // Use core layer QueryApi to execute query
         let query_request = crate::api::core::QueryRequest {
-            space_id: session.space().map(|s| s.id),
-            space_name: session.space().map(|s| s.name),
+            space_id: space.as_ref().map(|s| s.id),
+            space_name: space.map(|s| s.name),
             auto_commit: session.is_auto_commit(),
             transaction_id: session.current_transaction(),
-            parameters: None,
+            parameters,
+            user_name: Some(session.user()),
+            role: self.session_role(&session, space_id),
         };
 
         let mut query_api = self.query_api.write();
-        let result = query_api.execute(stmt, query_request);
+        let result = if query_request.parameters.is_some() {
+            query_api.execute_prepared(stmt, query_request)
+        } else {
+            query_api.execute(stmt, query_request)
+        };
 
         // If the query failed and we have an active transaction, check if the
         // transaction is still in a valid state. If the transaction has become
@@ -471,7 +814,9 @@ impl<
                                 "Transaction {} is in invalid state {} after failed query, cleaning up",
                                 txn_id, ctx.state()
                             );
-                            if let Err(e) = txn_manager.abort_transaction(txn_id) {
+                            if let Err(e) =
+                                txn_manager.abort_transaction_with_undo(txn_id, &*self.storage)
+                            {
                                 warn!("Failed to rollback invalid transaction {}: {}", txn_id, e);
                             }
                             session.unbind_transaction();
@@ -483,7 +828,17 @@ impl<
         }
 
         match result {
-            Ok(query_result) => Ok(Self::convert_to_execution_result(query_result)),
+            Ok(query_result) => {
+                if is_change_password {
+                    let now = chrono::Utc::now().timestamp_millis();
+                    let still_expired = self
+                        .storage
+                        .get_user(&username)
+                        .is_some_and(|user| user.is_password_expired(&self.password_policy, now));
+                    session.set_password_expired(still_expired);
+                }
+                Ok(Self::convert_to_execution_result(query_result))
+            }
             Err(e) => Err(e.to_string()),
         }
     }
@@ -513,22 +868,95 @@ impl<
         })
     }
 
+    /// Role the session user acts with in `space_id`, resolved like the permission
+    /// check: administrators are unrestricted, JWT roles live on the session
+    fn session_role(&self, session: &ClientSession, space_id: i64) -> Option<RoleType> {
+        let username = session.user();
+        if self.permission_manager.is_admin(&username) || session.is_admin() {
+            return Some(RoleType::God);
+        }
+        self.permission_manager
+            .get_role(&username, space_id)
+            .or_else(|| self.permission_manager.get_role(&username, GOD_SPACE_ID))
+            .or_else(|| session.role_with_space(space_id))
+            .or_else(|| session.role_with_space(GOD_SPACE_ID))
+    }
+
     fn extract_permission_from_statement(&self, stmt: &str) -> Permission {
-        let stmt_upper = stmt.trim().to_uppercase();
+        let permission = self.permission_from_prefix(stmt);
+        if !matches!(permission, Permission::Read | Permission::Write) {
+            return permission;
+        }
+        // The leading keyword hides writes behind read clauses, such as
+        // `MATCH ... | CALL { ... }` or `UNWIND $rows AS r FOREACH (...)`
+        let Ok(result) = Parser::new(stmt).parse() else {
+            return permission;
+        };
+        let procedure = |name: &str| self.procedure_permission(name);
+        match required_permission(&result.ast.stmt, &procedure) {
+            Permission::Read => permission,
+            required => required,
+        }
+    }
 
-        if stmt_upper.starts_with("SELECT") || stmt_upper.starts_with("MATCH") {
+    fn permission_from_prefix(&self, stmt: &str) -> Permission {
+        let stmt_upper = stmt.trim().to_uppercase();
+        let leading: Vec<&str> = stmt_upper.split_whitespace().take(3).collect();
+
+        if matches!(
+            leading.as_slice(),
+            ["CREATE" | "REVOKE", "API", "KEY"] | ["SHOW", "API", "KEYS"]
+        ) {
+            // API keys authenticate as their user, so only admins manage them
+            Permission::Admin
+        } else if matches!(
+            leading.as_slice(),
+            ["GRANT" | "REVOKE", ..] | ["CREATE", "ROLE", ..]
+        ) {
+            // Roles and privileges decide what every other user can see, including
+            // masked properties, so granting them is never left to the grantee
+            Permission::Admin
+        } else if matches!(
+            leading.as_slice(),
+            ["CREATE" | "DROP" | "REFRESH", "MATERIALIZED", "VIEW"]
+        ) {
+            // Materialized views are catalog objects shared by all users of the space
+            Permission::Schema
+        } else if stmt_upper.starts_with("SELECT") || stmt_upper.starts_with("MATCH") {
             Permission::Read
-        } else if stmt_upper.starts_with("INSERT") || stmt_upper.starts_with("CREATE") {
+        } else if stmt_upper.starts_with("INSERT")
+            || stmt_upper.starts_with("CREATE")
+            || stmt_upper.starts_with("FOREACH")
+        {
             Permission::Write
         } else if stmt_upper.starts_with("DELETE") || stmt_upper.starts_with("DROP") {
             Permission::Delete
         } else if stmt_upper.starts_with("ALTER") || stmt_upper.starts_with("ADD") {
             Permission::Schema
+        } else if stmt_upper.starts_with("RETRY") || stmt_upper.starts_with("PURGE") {
+            // RETRY / PURGE SYNC DLQ replay or drop failed index sync operations
+            Permission::Admin
+        } else if stmt_upper.starts_with("CALL") && stmt_upper[4..].trim_start().starts_with('{') {
+            // CALL { } subqueries exist to run batched writes
+            Permission::Write
         } else {
+            // Procedure calls are classified from the parsed procedure name
             Permission::Read
         }
     }
 
+    /// Permission required to call the procedure `name`, derived from its mode.
+    /// Unknown procedures fall back to read, the executor reports them as errors.
+    fn procedure_permission(&self, name: &str) -> Permission {
+        self.query_api
+            .read()
+            .procedure_registry()
+            .read()
+            .get(name)
+            .map(|procedure| procedure.mode().permission())
+            .unwrap_or(Permission::Read)
+    }
+
     /// Parse a DataType from its Display string representation.
     /// Mirrors the Display impl in graphdb_core::core::types::mod.rs.
     fn parse_data_type(s: &str) -> DataType {
@@ -791,7 +1219,7 @@ impl<
             .as_ref()
             .ok_or("Transaction manager not initialized")?;
 
-        match txn_manager.commit_transaction(txn_id) {
+        match txn_manager.commit_transaction_with_undo(txn_id, &*self.storage) {
             Ok(()) => {
                 session.unbind_transaction();
                 session.set_auto_commit(true);
@@ -856,7 +1284,7 @@ impl<
                 .as_ref()
                 .ok_or("Transaction manager not initialized")?;
 
-            match txn_manager.abort_transaction(txn_id) {
+            match txn_manager.abort_transaction_with_undo(txn_id, &*self.storage) {
                 Ok(()) => {
                     session.unbind_transaction();
                     session.set_auto_commit(true);
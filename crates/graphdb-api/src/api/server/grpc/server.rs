@@ -7,6 +7,7 @@ use std::time::Instant;
 use tonic::{transport::Server, Request, Response, Status};
 
 use crate::api::server::http::AppState;
+use crate::api::server::rate_limit::RateLimiter;
 use crate::config::Config;
 use crate::storage::{
     StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
@@ -18,6 +19,32 @@ use super::proto::graph_db_service_server::{
 };
 use super::proto::*;
 
+/// Build a RESOURCE_EXHAUSTED status carrying a `retry-after` hint in seconds
+fn resource_exhausted(message: String, retry_after_secs: Option<u64>) -> Status {
+    let mut status = Status::resource_exhausted(message);
+    if let Some(value) = retry_after_secs.and_then(|secs| secs.to_string().parse().ok()) {
+        status.metadata_mut().insert("retry-after", value);
+    }
+    status
+}
+
+/// Interceptor rejecting calls from client IPs over the configured request rate
+fn rate_limit_interceptor(
+    limiter: Option<&RateLimiter>,
+    request: Request<()>,
+) -> Result<Request<()>, Status> {
+    if let (Some(limiter), Some(addr)) = (limiter, request.remote_addr()) {
+        if let Err(wait) = limiter.try_acquire(addr.ip()) {
+            let retry_after = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+            return Err(resource_exhausted(
+                format!("Rate limit exceeded for {}", addr.ip()),
+                Some(retry_after),
+            ));
+        }
+    }
+    Ok(request)
+}
+
 // Type alias for the streaming response
 type ExecuteQueryStreamStream = std::pin::Pin<
     Box<dyn tokio_stream::Stream<Item = Result<QueryResultChunk, Status>> + Send + 'static>,
@@ -165,17 +192,113 @@ impl<
 
     async fn execute_query(
         &self,
-        _request: Request<ExecuteQueryRequest>,
+        request: Request<ExecuteQueryRequest>,
     ) -> Result<Response<ExecuteQueryResponse>, Status> {
-        // TODO: Implement query execution logic
-        // This should integrate with the existing QueryApi
-
-        Ok(Response::new(ExecuteQueryResponse {
-            success: true,
-            result: None,
-            error: String::new(),
-            metadata: None,
-        }))
+        let bearer = request
+            .metadata()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(|token| token.trim().to_string());
+        let client_ip = request.remote_addr().map(|addr| addr.ip());
+        let request = request.into_inner();
+        let graph_format = request.result_format() == ResultFormat::Graph;
+        let session_id = match (request.session_id.as_deref(), bearer) {
+            (Some(id), _) => id
+                .parse::<i64>()
+                .map_err(|_| Status::invalid_argument("A valid session_id is required"))?,
+            // Without a session, the call authenticates with a JWT or API key
+            (None, Some(token)) => self
+                .app_state
+                .server
+                .get_graph_service()
+                .authenticate_token(&token, client_ip)
+                .await
+                .map_err(Status::unauthenticated)?
+                .id(),
+            (None, None) => {
+                return Err(Status::invalid_argument(
+                    "A valid session_id or bearer token is required",
+                ))
+            }
+        };
+
+        let params = request
+            .parameters
+            .map(|p| p.params)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, value)| {
+                proto_to_value(value)
+                    .map(|v| (name.clone(), v))
+                    .map_err(|e| Status::invalid_argument(format!("Parameter ${}: {}", name, e)))
+            })
+            .collect::<Result<std::collections::HashMap<_, _>, Status>>()?;
+
+        // Parameterised queries are bound into a cached plan rather than interpolated
+        let graph_service = self.app_state.server.get_graph_service();
+        graph_service
+            .check_query_quota(session_id, &request.query)
+            .map_err(|e| resource_exhausted(e.to_string(), e.retry_after_secs()))?;
+        let start = Instant::now();
+        let execution = if params.is_empty() {
+            graph_service.execute(session_id, &request.query).await
+        } else {
+            graph_service
+                .execute_prepared(session_id, &request.query, None, params)
+                .await
+        };
+
+        let response = match execution {
+            Ok(crate::query::executor::ExecutionResult::Error(e)) | Err(e) => {
+                ExecuteQueryResponse {
+                    success: false,
+                    result: None,
+                    error: e,
+                    metadata: None,
+                }
+            }
+            Ok(result) => {
+                let (column_names, rows, column_types, graph) = match result {
+                    crate::query::executor::ExecutionResult::DataSet(ds) if graph_format => {
+                        let column_types = crate::api::core::column_types(&ds);
+                        let graph =
+                            graph_to_proto(crate::api::core::GraphElements::from_data_set(&ds));
+                        (ds.col_names, ds.rows, column_types, Some(graph))
+                    }
+                    crate::query::executor::ExecutionResult::DataSet(ds) => {
+                        (ds.col_names, ds.rows, Vec::new(), None)
+                    }
+                    _ => (Vec::new(), Vec::new(), Vec::new(), None),
+                };
+                let rows: Vec<Row> = rows
+                    .into_iter()
+                    .map(|row| Row {
+                        values: row.into_iter().map(value_to_proto).collect(),
+                    })
+                    .collect();
+
+                ExecuteQueryResponse {
+                    success: true,
+                    metadata: Some(ExecutionMetadata {
+                        rows_returned: rows.len() as u64,
+                        execution_time_ms: start.elapsed().as_millis() as u64,
+                        rows_scanned: 0,
+                        custom_stats: Default::default(),
+                    }),
+                    result: Some(QueryResult {
+                        column_names,
+                        rows,
+                        plan_descriptions: Default::default(),
+                        column_types,
+                        graph,
+                    }),
+                    error: String::new(),
+                }
+            }
+        };
+
+        Ok(Response::new(response))
     }
 
     async fn validate_query(
@@ -476,6 +599,119 @@ impl<
     }
 }
 
+/// Convert a proto parameter value into a query value
+fn proto_to_value(value: Value) -> Result<crate::core::Value, String> {
+    use super::proto::value::Value as Kind;
+
+    match value.value {
+        None => Ok(crate::core::Value::Null(crate::core::NullType::Null)),
+        Some(Kind::StringValue(s)) => Ok(crate::core::Value::String(s)),
+        Some(Kind::IntValue(i)) => Ok(crate::core::Value::BigInt(i)),
+        Some(Kind::DoubleValue(d)) | Some(Kind::FloatValue(d)) => Ok(crate::core::Value::Double(d)),
+        Some(Kind::BoolValue(b)) => Ok(crate::core::Value::Bool(b)),
+        Some(Kind::BytesValue(b)) => Ok(crate::core::Value::Blob(b)),
+        Some(Kind::TimestampValue(secs)) => {
+            use chrono::{Datelike, Timelike};
+
+            let dt = chrono::DateTime::from_timestamp(secs, 0)
+                .ok_or_else(|| format!("Timestamp out of range: {}", secs))?;
+            Ok(crate::core::Value::DateTime(
+                crate::core::value::DateTimeValue {
+                    year: dt.year(),
+                    month: dt.month(),
+                    day: dt.day(),
+                    hour: dt.hour(),
+                    minute: dt.minute(),
+                    sec: dt.second(),
+                    microsec: 0,
+                },
+            ))
+        }
+        Some(Kind::JsonValue(text)) => {
+            let json: serde_json::Value =
+                serde_json::from_str(&text).map_err(|e| format!("Invalid JSON: {}", e))?;
+            crate::core::Value::from_param_json(json)
+        }
+    }
+}
+
+/// Convert a result value into its proto form; non-scalar values are sent as JSON
+fn value_to_proto(value: crate::core::Value) -> Value {
+    use super::proto::value::Value as Kind;
+    use crate::core::Value as V;
+
+    let kind = match value {
+        V::Null(_) | V::Empty => None,
+        V::Bool(b) => Some(Kind::BoolValue(b)),
+        V::SmallInt(i) => Some(Kind::IntValue(i as i64)),
+        V::Int(i) => Some(Kind::IntValue(i as i64)),
+        V::BigInt(i) => Some(Kind::IntValue(i)),
+        V::Float(f) => Some(Kind::FloatValue(f as f64)),
+        V::Double(d) => Some(Kind::DoubleValue(d)),
+        V::String(s) => Some(Kind::StringValue(s)),
+        V::Blob(b) => Some(Kind::BytesValue(b)),
+        other => Some(Kind::JsonValue(
+            crate::api::server::http::handlers::query::value_to_json(other).to_string(),
+        )),
+    };
+    Value { value: kind }
+}
+
+/// Convert collected graph elements into their proto form
+fn graph_to_proto(elements: crate::api::core::GraphElements) -> GraphResult {
+    use std::collections::HashMap;
+
+    fn properties_to_proto(props: HashMap<String, crate::core::Value>) -> HashMap<String, Value> {
+        props
+            .into_iter()
+            .map(|(name, value)| (name, value_to_proto(value)))
+            .collect()
+    }
+
+    let (nodes, relationships) = elements.into_parts();
+
+    GraphResult {
+        nodes: nodes
+            .into_iter()
+            .map(|vertex| GraphNode {
+                id: Some(vid_to_proto(&vertex.vid)),
+                tags: vertex
+                    .tags
+                    .into_iter()
+                    .map(|tag| GraphTag {
+                        name: tag.name,
+                        properties: properties_to_proto(tag.properties),
+                    })
+                    .collect(),
+                properties: properties_to_proto(vertex.properties),
+            })
+            .collect(),
+        relationships: relationships
+            .into_iter()
+            .map(|edge| GraphRelationship {
+                id: crate::api::core::relationship_id(&edge),
+                start: Some(vid_to_proto(&edge.src)),
+                end: Some(vid_to_proto(&edge.dst)),
+                rank: edge.ranking,
+                properties: properties_to_proto(edge.props),
+                r#type: edge.edge_type,
+            })
+            .collect(),
+    }
+}
+
+/// Integer vertex ids are sent as integers, all others as strings
+fn vid_to_proto(vid: &crate::core::types::VertexId) -> Value {
+    use super::proto::value::Value as Kind;
+
+    let kind = match (vid.as_int64(), vid.as_str()) {
+        (Some(id), _) => Kind::IntValue(id),
+        (None, Some(id)) => Kind::StringValue(id.to_string()),
+        (None, None) => Kind::StringValue(vid.to_string()),
+    };
+    Value { value: Some(kind) }
+}
+
 /// Run the gRPC server
 pub async fn run_server<
     S: StorageClient
@@ -493,14 +729,7 @@ pub async fn run_server<
 ) -> Result<(), Box<dyn std::error::Error>> {
     let service = GraphDBService::new(app_state, config);
 
-    tracing::info!("GraphDB gRPC service listening on {}", addr);
-
-    Server::builder()
-        .add_service(GraphDbServiceServer::new(service))
-        .serve(addr)
-        .await?;
-
-    Ok(())
+    run_server_with_grpc_service(service, addr).await
 }
 
 /// Run the gRPC server with custom service instance
@@ -519,8 +748,12 @@ pub async fn run_server_with_grpc_service<
 ) -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("GraphDB gRPC service listening on {}", addr);
 
+    let limiter = service.app_state.server.get_rate_limiter();
     Server::builder()
-        .add_service(GraphDbServiceServer::new(service))
+        .add_service(GraphDbServiceServer::with_interceptor(
+            service,
+            move |request: Request<()>| rate_limit_interceptor(limiter.as_deref(), request),
+        ))
         .serve(addr)
         .await?;
 
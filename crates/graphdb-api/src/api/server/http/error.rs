@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -11,6 +11,8 @@ pub enum HttpError {
     BadRequest(String),
     Unauthorized(String),
     NotFound(String),
+    /// Quota or rate limit exceeded, with the seconds to wait before retrying
+    TooManyRequests(String, Option<u64>),
     InternalError(String),
 }
 
@@ -20,6 +22,7 @@ impl fmt::Display for HttpError {
             HttpError::BadRequest(msg) => write!(f, "Bad Request: {}", msg),
             HttpError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
             HttpError::NotFound(msg) => write!(f, "Not Found: {}", msg),
+            HttpError::TooManyRequests(msg, _) => write!(f, "Too Many Requests: {}", msg),
             HttpError::InternalError(msg) => write!(f, "Internal Error: {}", msg),
         }
     }
@@ -29,10 +32,15 @@ impl std::error::Error for HttpError {}
 
 impl IntoResponse for HttpError {
     fn into_response(self) -> Response {
+        let mut retry_after = None;
         let (status, message) = match self {
             HttpError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             HttpError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
             HttpError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            HttpError::TooManyRequests(msg, secs) => {
+                retry_after = secs;
+                (StatusCode::TOO_MANY_REQUESTS, msg)
+            }
             HttpError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
         };
 
@@ -41,7 +49,10 @@ impl IntoResponse for HttpError {
             "status": status.as_u16(),
         }));
 
-        (status, body).into_response()
+        match retry_after {
+            Some(secs) => (status, [(header::RETRY_AFTER, secs.to_string())], body).into_response(),
+            None => (status, body).into_response(),
+        }
     }
 }
 
@@ -61,6 +72,11 @@ impl HttpError {
         HttpError::Unauthorized(msg.into())
     }
 
+    /// Generate a TooManyRequests error from an exceeded user quota.
+    pub fn quota_exceeded(err: &crate::api::server::session::QuotaExceeded) -> Self {
+        HttpError::TooManyRequests(err.to_string(), err.retry_after_secs())
+    }
+
     /// Generate an InternalError.
     pub fn internal<T: Into<String>>(msg: T) -> Self {
         HttpError::InternalError(msg.into())
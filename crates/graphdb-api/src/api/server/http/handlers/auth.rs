@@ -22,6 +22,8 @@ pub struct LoginResponse {
     pub session_id: i64,
     pub username: String,
     pub expires_at: Option<u64>,
+    /// The password has expired; only CHANGE PASSWORD is accepted until it is changed
+    pub password_change_required: bool,
 }
 
 pub async fn login<
@@ -37,16 +39,13 @@ pub async fn login<
     State(state): State<AppState<S>>,
     Json(request): Json<LoginRequest>,
 ) -> Result<JsonResponse<LoginResponse>, HttpError> {
-    // TODO: Implement proper authentication with password verification
-    // For now, accept any username/password and create a session
-
-    let session_manager = state.server.get_session_manager();
-
-    // Create a new session for the user
-    let session = session_manager
-        .create_session(request.username.clone(), "127.0.0.1".to_string())
+    // Verify the password against the user store and open a session
+    let session = state
+        .server
+        .get_graph_service()
+        .authenticate(&request.username, &request.password)
         .await
-        .map_err(|e| HttpError::InternalError(format!("Failed to create session: {}", e)))?;
+        .map_err(HttpError::Unauthorized)?;
 
     let session_id = session.id();
     info!(
@@ -58,6 +57,7 @@ pub async fn login<
         session_id,
         username: request.username,
         expires_at: None,
+        password_change_required: session.password_expired(),
     }))
 }
 
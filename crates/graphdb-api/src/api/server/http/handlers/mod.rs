@@ -26,7 +26,8 @@ pub use function::{info as function_info, list, register, unregister};
 pub use health::check;
 pub use query::{execute, validate};
 pub use query_types::{
-    QueryData, QueryError, QueryMetadata, QueryRequest, QueryResponse, ValidateResponse,
+    GraphData, GraphNode, GraphRelationship, QueryData, QueryError, QueryMetadata, QueryRequest,
+    QueryResponse, ValidateResponse,
 };
 pub use schema::{
     create_edge_type, create_space, create_tag, drop_space, get_space, list_edge_types,
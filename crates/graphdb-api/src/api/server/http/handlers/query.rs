@@ -1,8 +1,9 @@
 use axum::{
-    extract::{Json, State},
+    extract::{Extension, Json, State},
     response::Json as JsonResponse,
 };
 
+use crate::api::core::ResultFormat;
 use crate::api::server::http::handlers::query_types::*;
 use crate::api::server::http::{error::HttpError, state::AppState};
 use crate::query::executor::ExecutionResult;
@@ -21,18 +22,40 @@ pub async fn execute<
         + 'static,
 >(
     State(state): State<AppState<S>>,
+    Extension(auth_session_id): Extension<i64>,
     Json(request): Json<QueryRequest>,
 ) -> Result<JsonResponse<QueryResponse>, HttpError> {
     let graph_service = state.server.get_graph_service();
+    let session_id = request.session_id.unwrap_or(auth_session_id);
+    graph_service
+        .check_query_quota(session_id, &request.query)
+        .map_err(|e| HttpError::quota_exceeded(&e))?;
+    let result_format = request.result_format;
 
-    // Executing Queries with GraphService
-    let result = match graph_service
-        .execute(request.session_id, &request.query)
-        .await
-    {
+    // Executing Queries with GraphService. Parameterised queries are bound into
+    // a cached plan instead of being interpolated into the query text.
+    let execution = if request.parameters.is_empty() {
+        graph_service.execute(session_id, &request.query).await
+    } else {
+        let params = match convert_parameters(request.parameters) {
+            Ok(params) => params,
+            Err(e) => {
+                return Ok(JsonResponse(QueryResponse::error(
+                    "INVALID_PARAMETER".to_string(),
+                    e,
+                    None,
+                )))
+            }
+        };
+        graph_service
+            .execute_prepared(session_id, &request.query, None, params)
+            .await
+    };
+
+    let result = match execution {
         Ok(exec_result) => {
             // Converting ExecutionResult to QueryResponse
-            Ok::<_, HttpError>(execution_result_to_response(exec_result))
+            Ok::<_, HttpError>(execution_result_to_response(exec_result, result_format))
         }
         Err(e) => Ok::<_, HttpError>(QueryResponse::error(
             "QUERY_ERROR".to_string(),
@@ -68,9 +91,37 @@ pub async fn validate<
     Ok(JsonResponse(ValidateResponse { valid, message }))
 }
 
+/// Convert typed JSON request parameters into query values
+pub(crate) fn convert_parameters(
+    parameters: std::collections::HashMap<String, serde_json::Value>,
+) -> Result<std::collections::HashMap<String, crate::core::Value>, String> {
+    parameters
+        .into_iter()
+        .map(|(name, json)| {
+            crate::core::Value::from_param_json(json)
+                .map(|value| (name.clone(), value))
+                .map_err(|e| format!("Parameter ${}: {}", name, e))
+        })
+        .collect()
+}
+
 /// Converting ExecutionResult to QueryResponse
-fn execution_result_to_response(result: ExecutionResult) -> QueryResponse {
+fn execution_result_to_response(result: ExecutionResult, format: ResultFormat) -> QueryResponse {
     match result {
+        ExecutionResult::DataSet(dataset) if format == ResultFormat::Graph => {
+            let data = QueryData::graph(dataset);
+            let row_count = data.row_count;
+
+            QueryResponse::success(
+                data,
+                QueryMetadata {
+                    execution_time_ms: 0,
+                    rows_scanned: 0,
+                    rows_returned: row_count,
+                    space_id: None,
+                },
+            )
+        }
         ExecutionResult::DataSet(dataset) => {
             let columns: Vec<String> = dataset.col_names.clone();
             let rows: Vec<std::collections::HashMap<String, serde_json::Value>> = dataset
@@ -151,7 +202,7 @@ fn execution_result_to_response(result: ExecutionResult) -> QueryResponse {
     }
 }
 
-fn value_to_json(value: crate::core::Value) -> serde_json::Value {
+pub(crate) fn value_to_json(value: crate::core::Value) -> serde_json::Value {
     match value {
         crate::core::Value::Null(_) => serde_json::Value::Null,
         crate::core::Value::Bool(b) => serde_json::Value::Bool(b),
@@ -3,13 +3,26 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::api::core::{column_types, relationship_id, GraphElements, ResultFormat};
+use crate::api::server::http::handlers::query::value_to_json;
+use crate::core::types::VertexId;
+use crate::core::{DataSet, Edge, Vertex};
+
 /// Query request
 #[derive(Debug, Deserialize)]
 pub struct QueryRequest {
     pub query: String,
-    pub session_id: i64,
+    /// Defaults to the session the request authenticated with
+    #[serde(default)]
+    pub session_id: Option<i64>,
+    /// Typed JSON values bound to `$name` placeholders, see [`Value::from_param_json`]
+    ///
+    /// [`Value::from_param_json`]: crate::core::Value::from_param_json
+    #[serde(default)]
+    pub parameters: HashMap<String, serde_json::Value>,
+    /// `table` (default) or `graph`
     #[serde(default)]
-    pub parameters: HashMap<String, String>,
+    pub result_format: ResultFormat,
 }
 
 /// Query response (structured)
@@ -22,11 +35,48 @@ pub struct QueryResponse {
 }
 
 /// Query data
+///
+/// The graph format leaves `rows` empty and returns the rows in column order in `values`.
 #[derive(Debug, Serialize)]
 pub struct QueryData {
     pub columns: Vec<String>,
     pub rows: Vec<HashMap<String, serde_json::Value>>,
     pub row_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Vec<serde_json::Value>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graph: Option<GraphData>,
+}
+
+/// De-duplicated nodes and relationships of a graph-format result
+#[derive(Debug, Serialize)]
+pub struct GraphData {
+    pub nodes: Vec<GraphNode>,
+    pub relationships: Vec<GraphRelationship>,
+}
+
+/// Vertex of a graph-format result
+#[derive(Debug, Serialize)]
+pub struct GraphNode {
+    pub id: serde_json::Value,
+    pub tags: Vec<String>,
+    /// Tag properties grouped by tag name, next to any vertex-level properties
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Edge of a graph-format result
+#[derive(Debug, Serialize)]
+pub struct GraphRelationship {
+    /// `<src>-[<type>@<rank>]-><dst>`, unique within the result
+    pub id: String,
+    #[serde(rename = "type")]
+    pub edge_type: String,
+    pub start: serde_json::Value,
+    pub end: serde_json::Value,
+    pub rank: i64,
+    pub properties: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Query metadata
@@ -101,6 +151,95 @@ impl QueryData {
             columns,
             rows,
             row_count,
+            column_types: None,
+            values: None,
+            graph: None,
+        }
+    }
+
+    /// Create graph-format query data: ordered rows, column types and graph elements.
+    pub fn graph(dataset: DataSet) -> Self {
+        let column_types = column_types(&dataset);
+        let graph = GraphData::from_elements(GraphElements::from_data_set(&dataset));
+        let values: Vec<Vec<serde_json::Value>> = dataset
+            .rows
+            .into_iter()
+            .map(|row| row.into_iter().map(value_to_json).collect())
+            .collect();
+        Self {
+            columns: dataset.col_names,
+            rows: Vec::new(),
+            row_count: values.len(),
+            column_types: Some(column_types),
+            values: Some(values),
+            graph: Some(graph),
+        }
+    }
+}
+
+impl GraphData {
+    /// Render collected graph elements as JSON nodes and relationships.
+    pub fn from_elements(elements: GraphElements) -> Self {
+        let (nodes, relationships) = elements.into_parts();
+        Self {
+            nodes: nodes.into_iter().map(GraphNode::from_vertex).collect(),
+            relationships: relationships
+                .into_iter()
+                .map(GraphRelationship::from_edge)
+                .collect(),
+        }
+    }
+}
+
+impl GraphNode {
+    fn from_vertex(vertex: Vertex) -> Self {
+        let mut properties: serde_json::Map<String, serde_json::Value> = vertex
+            .properties
+            .into_iter()
+            .map(|(name, value)| (name, value_to_json(value)))
+            .collect();
+        let mut tags = Vec::with_capacity(vertex.tags.len());
+        for tag in vertex.tags {
+            let tag_properties = tag
+                .properties
+                .into_iter()
+                .map(|(name, value)| (name, value_to_json(value)))
+                .collect();
+            properties.insert(tag.name.clone(), serde_json::Value::Object(tag_properties));
+            tags.push(tag.name);
+        }
+        Self {
+            id: vid_to_json(&vertex.vid),
+            tags,
+            properties,
         }
     }
 }
+
+impl GraphRelationship {
+    fn from_edge(edge: Edge) -> Self {
+        Self {
+            id: relationship_id(&edge),
+            start: vid_to_json(&edge.src),
+            end: vid_to_json(&edge.dst),
+            rank: edge.ranking,
+            properties: edge
+                .props
+                .into_iter()
+                .map(|(name, value)| (name, value_to_json(value)))
+                .collect(),
+            edge_type: edge.edge_type,
+        }
+    }
+}
+
+/// Integer vertex ids stay numbers so that node ids and relationship endpoints compare equal.
+pub(crate) fn vid_to_json(vid: &VertexId) -> serde_json::Value {
+    if let Some(id) = vid.as_int64() {
+        serde_json::Value::Number(id.into())
+    } else if let Some(id) = vid.as_str() {
+        serde_json::Value::String(id.to_string())
+    } else {
+        serde_json::Value::String(vid.to_string())
+    }
+}
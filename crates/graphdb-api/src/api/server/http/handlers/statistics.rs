@@ -57,6 +57,9 @@ pub async fn session<
     let last_insert_vertex_id = session_stats.last_insert_vertex_id();
     let last_insert_edge_id = session_stats.last_insert_edge_id();
 
+    // Hourly quota usage is tracked per user, across all of the user's sessions
+    let quota = session_manager.quota_manager().usage(&session.user());
+
     Ok(JsonResponse(serde_json::json!({
         "session_id": session_id,
         "username": session.user(),
@@ -67,9 +70,38 @@ pub async fn session<
             "last_insert_edge_id": last_insert_edge_id,
             "avg_execution_time_ms": avg_execution_time_ms,
         },
+        "quota": quota.to_json(),
     })))
 }
 
+/// Obtain per-user quota usage over the last hour
+pub async fn quotas<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+) -> Result<JsonResponse<serde_json::Value>, HttpError> {
+    let session_manager = state.server.get_session_manager();
+    let users: Vec<serde_json::Value> = session_manager
+        .quota_manager()
+        .all_usage()
+        .iter()
+        .map(|usage| {
+            let mut entry = usage.to_json();
+            entry["open_sessions"] = session_manager.user_session_count(&usage.username).into();
+            entry
+        })
+        .collect();
+
+    Ok(JsonResponse(serde_json::json!({ "users": users })))
+}
+
 /// Obtain query statistics
 pub async fn queries<
     S: StorageClient
@@ -1,13 +1,17 @@
 //! Streaming Results HTTP Processor
 
 use axum::{
-    extract::{Json, State},
+    extract::{Extension, Json, State},
     response::{sse::Event, Sse},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
 use tokio_stream::wrappers::ReceiverStream;
 
+use crate::api::core::{column_types, GraphElements, ResultFormat};
+use crate::api::server::http::handlers::query::convert_parameters;
+use crate::api::server::http::handlers::query_types::GraphData;
 use crate::api::server::http::{error::HttpError, state::AppState};
 use crate::query::executor::ExecutionResult;
 use crate::storage::{
@@ -18,9 +22,17 @@ use crate::storage::{
 #[derive(Debug, Clone, Deserialize)]
 pub struct StreamQueryRequest {
     pub query: String,
-    pub session_id: i64,
+    /// Defaults to the session the request authenticated with
+    #[serde(default)]
+    pub session_id: Option<i64>,
+    /// Typed JSON values bound to `$name` placeholders, as for `/query`
+    #[serde(default)]
+    pub parameters: HashMap<String, serde_json::Value>,
     #[serde(default = "default_batch_size")]
     pub batch_size: usize,
+    /// `table` (default) or `graph`; graph rows are arrays in column order
+    #[serde(default)]
+    pub result_format: ResultFormat,
 }
 
 fn default_batch_size() -> usize {
@@ -40,6 +52,8 @@ struct StreamMetadata {
     pub rows_returned: usize,
     pub execution_time_ms: u64,
     pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_types: Option<Vec<String>>,
 }
 
 /// Execute the query and stream the results
@@ -54,11 +68,18 @@ pub async fn execute_stream<
         + 'static,
 >(
     State(state): State<AppState<S>>,
+    Extension(auth_session_id): Extension<i64>,
     Json(request): Json<StreamQueryRequest>,
 ) -> Result<
     Sse<impl tokio_stream::Stream<Item = Result<Event, HttpError>> + Send + 'static>,
     HttpError,
 > {
+    let session_id = request.session_id.unwrap_or(auth_session_id);
+    state
+        .server
+        .get_graph_service()
+        .check_query_quota(session_id, &request.query)
+        .map_err(|e| HttpError::quota_exceeded(&e))?;
     let batch_size = request.batch_size.clamp(1, 1000);
     let server = state.server.clone();
 
@@ -69,30 +90,43 @@ pub async fn execute_stream<
         let graph_service = server.get_graph_service();
         let request = request.clone();
 
+        // Parameterised queries are bound into a cached plan, as for `/query`
+        let execution = if request.parameters.is_empty() {
+            graph_service.execute(session_id, &request.query).await
+        } else {
+            match convert_parameters(request.parameters.clone()) {
+                Ok(params) => {
+                    graph_service
+                        .execute_prepared(session_id, &request.query, None, params)
+                        .await
+                }
+                Err(e) => {
+                    send_error(&tx, &e, "INVALID_PARAMETER").await;
+                    return;
+                }
+            }
+        };
+
         // perform a search
-        let exec_result = match graph_service
-            .execute(request.session_id, &request.query)
-            .await
-        {
+        let exec_result = match execution {
             Ok(result) => result,
             Err(e) => {
-                let error_msg = json!({
-                    "error": true,
-                    "message": e,
-                    "code": "QUERY_ERROR"
-                });
-                let _ = tx
-                    .send(Ok(Event::default()
-                        .event("error")
-                        .data(error_msg.to_string())))
-                    .await;
-                let _ = tx.send(Ok(Event::default().event("done").data("{}"))).await;
+                send_error(&tx, &e, "QUERY_ERROR").await;
                 return;
             }
         };
 
+        // The graph is collected over the whole result, so it is sent after the rows
+        let graph = match (&exec_result, request.result_format) {
+            (ExecutionResult::DataSet(dataset), ResultFormat::Graph) => Some((
+                column_types(dataset),
+                GraphData::from_elements(GraphElements::from_data_set(dataset)),
+            )),
+            _ => None,
+        };
+
         // Convert execution results to streaming data
-        let (rows, columns) = execution_result_to_stream_data(exec_result);
+        let (rows, columns) = execution_result_to_stream_data(exec_result, request.result_format);
         let total_rows = rows.len();
 
         // Send data in batches
@@ -112,11 +146,29 @@ pub async fn execute_stream<
             }
         }
 
+        // Send graph elements
+        let column_types = match graph {
+            Some((column_types, graph)) => {
+                if let Ok(graph_str) = serde_json::to_string(&graph) {
+                    if tx
+                        .send(Ok(Event::default().event("graph").data(graph_str)))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                Some(column_types)
+            }
+            None => None,
+        };
+
         // Send metadata
         let metadata = StreamMetadata {
             rows_returned: total_rows,
             execution_time_ms: start_time.elapsed().as_millis() as u64,
             columns,
+            column_types,
         };
 
         if let Ok(meta_str) = serde_json::to_string(&metadata) {
@@ -136,11 +188,39 @@ pub async fn execute_stream<
     ))
 }
 
+/// Send an error event followed by the completion event
+async fn send_error(
+    tx: &tokio::sync::mpsc::Sender<Result<Event, HttpError>>,
+    message: &str,
+    code: &str,
+) {
+    let error_msg = json!({
+        "error": true,
+        "message": message,
+        "code": code
+    });
+    let _ = tx
+        .send(Ok(Event::default()
+            .event("error")
+            .data(error_msg.to_string())))
+        .await;
+    let _ = tx.send(Ok(Event::default().event("done").data("{}"))).await;
+}
+
 /// Converting ExecutionResult to Streaming Data
 fn execution_result_to_stream_data(
     result: ExecutionResult,
+    format: ResultFormat,
 ) -> (Vec<serde_json::Value>, Vec<String>) {
     match result {
+        ExecutionResult::DataSet(dataset) if format == ResultFormat::Graph => {
+            let rows = dataset
+                .rows
+                .into_iter()
+                .map(|row| serde_json::Value::Array(row.into_iter().map(value_to_json).collect()))
+                .collect();
+            (rows, dataset.col_names)
+        }
         ExecutionResult::DataSet(dataset) => {
             let columns = dataset.col_names.clone();
             let rows: Vec<serde_json::Value> = dataset
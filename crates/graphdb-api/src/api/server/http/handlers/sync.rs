@@ -1,10 +1,14 @@
-use axum::{extract::State, response::Json as JsonResponse};
-use serde::Serialize;
+use axum::{
+    extract::{Path, Query, State},
+    response::Json as JsonResponse,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::api::server::http::{error::HttpError, state::AppState};
 use crate::storage::{
     StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
 };
+use crate::sync::DeadLetterEntry;
 
 /// Sync status response
 #[derive(Debug, Serialize)]
@@ -45,3 +49,190 @@ pub async fn status<
         }))
     }
 }
+
+/// Dead letter entry as returned by `GET /sync/dlq`
+#[derive(Debug, Serialize)]
+pub struct DeadLetterEntryInfo {
+    pub id: u64,
+    pub space_id: u64,
+    pub tag_name: String,
+    pub field_name: String,
+    pub change_type: String,
+    pub doc_id: String,
+    pub error: String,
+    pub retry_attempts: u32,
+    pub first_failure: String,
+    pub last_failure: String,
+    pub recovered: bool,
+}
+
+impl From<DeadLetterEntry> for DeadLetterEntryInfo {
+    fn from(entry: DeadLetterEntry) -> Self {
+        let to_rfc3339 =
+            |time: std::time::SystemTime| chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339();
+        Self {
+            id: entry.id,
+            space_id: entry.operation.key.space_id,
+            tag_name: entry.operation.key.tag_name,
+            field_name: entry.operation.key.field_name,
+            change_type: format!("{:?}", entry.operation.change_type),
+            doc_id: entry.operation.id,
+            error: entry.error,
+            retry_attempts: entry.retry_attempts,
+            first_failure: to_rfc3339(entry.first_failure),
+            last_failure: to_rfc3339(entry.last_failure),
+            recovered: entry.recovered,
+        }
+    }
+}
+
+/// Dead letter queue listing
+#[derive(Debug, Serialize)]
+pub struct DeadLetterListResponse {
+    pub total: usize,
+    pub unrecovered: usize,
+    pub entries: Vec<DeadLetterEntryInfo>,
+}
+
+/// Outcome of retrying dead letter entries
+#[derive(Debug, Serialize)]
+pub struct DeadLetterRetryResponse {
+    pub total: usize,
+    pub recovered: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+/// Dead letter purge parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct DeadLetterPurgeQuery {
+    /// Only drop entries that have already been recovered
+    #[serde(default)]
+    pub recovered_only: bool,
+}
+
+/// Dead letter purge outcome
+#[derive(Debug, Serialize)]
+pub struct DeadLetterPurgeResponse {
+    pub purged: usize,
+}
+
+/// List dead letter queue entries
+pub async fn list_dead_letters<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+) -> Result<JsonResponse<DeadLetterListResponse>, HttpError> {
+    let graph_service = state.server.get_graph_service();
+    let entries = graph_service
+        .sync_api()
+        .map(|sync_api| sync_api.list_dead_letters())
+        .unwrap_or_default();
+
+    Ok(JsonResponse(DeadLetterListResponse {
+        total: entries.len(),
+        unrecovered: entries.iter().filter(|e| !e.recovered).count(),
+        entries: entries.into_iter().map(DeadLetterEntryInfo::from).collect(),
+    }))
+}
+
+/// Retry a single dead letter entry
+pub async fn retry_dead_letter<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+    Path(id): Path<u64>,
+) -> Result<JsonResponse<DeadLetterRetryResponse>, HttpError> {
+    let graph_service = state.server.get_graph_service();
+    let sync_api = graph_service
+        .sync_api()
+        .ok_or_else(|| HttpError::bad_request("Sync is not enabled"))?;
+
+    #[cfg(feature = "fulltext-search")]
+    {
+        sync_api.retry_dead_letter(id).await?;
+        Ok(JsonResponse(DeadLetterRetryResponse {
+            total: 1,
+            recovered: 1,
+            failed: 0,
+            skipped: 0,
+        }))
+    }
+    #[cfg(not(feature = "fulltext-search"))]
+    {
+        let _ = (sync_api, id);
+        Err(HttpError::bad_request("Fulltext sync is not enabled"))
+    }
+}
+
+/// Retry all unrecovered dead letter entries
+pub async fn retry_dead_letters<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+) -> Result<JsonResponse<DeadLetterRetryResponse>, HttpError> {
+    let graph_service = state.server.get_graph_service();
+    let sync_api = graph_service
+        .sync_api()
+        .ok_or_else(|| HttpError::bad_request("Sync is not enabled"))?;
+
+    #[cfg(feature = "fulltext-search")]
+    {
+        let result = sync_api.retry_dead_letters().await?;
+        Ok(JsonResponse(DeadLetterRetryResponse {
+            total: result.total,
+            recovered: result.recovered,
+            failed: result.failed,
+            skipped: result.skipped,
+        }))
+    }
+    #[cfg(not(feature = "fulltext-search"))]
+    {
+        let _ = sync_api;
+        Err(HttpError::bad_request("Fulltext sync is not enabled"))
+    }
+}
+
+/// Purge dead letter queue entries
+pub async fn purge_dead_letters<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+    Query(params): Query<DeadLetterPurgeQuery>,
+) -> Result<JsonResponse<DeadLetterPurgeResponse>, HttpError> {
+    let graph_service = state.server.get_graph_service();
+    let purged = graph_service
+        .sync_api()
+        .map(|sync_api| sync_api.purge_dead_letters(params.recovered_only))
+        .unwrap_or(0);
+
+    Ok(JsonResponse(DeadLetterPurgeResponse { purged }))
+}
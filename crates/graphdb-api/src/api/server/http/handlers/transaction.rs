@@ -100,8 +100,10 @@ pub async fn commit<
     let result = task::spawn_blocking(move || {
         let txn_api = state.server.get_txn_api();
         let handle = TransactionHandle::from(txn_id);
+        let storage = state.server.get_storage();
+        let storage_guard = storage.read();
 
-        match txn_api.commit(handle) {
+        match txn_api.commit(handle, &*storage_guard) {
             Ok(()) => Ok::<_, HttpError>(serde_json::json!({
                 "message": "Transaction committed successfully",
                 "transaction_id": txn_id,
@@ -135,8 +137,10 @@ pub async fn rollback<
     let result = task::spawn_blocking(move || {
         let txn_api = state.server.get_txn_api();
         let handle = TransactionHandle::from(txn_id);
+        let storage = state.server.get_storage();
+        let storage_guard = storage.read();
 
-        match txn_api.rollback(handle) {
+        match txn_api.rollback(handle, &*storage_guard) {
             Ok(()) => Ok::<_, HttpError>(serde_json::json!({
                 "message": "Transaction rolled back successfully",
                 "transaction_id": txn_id,
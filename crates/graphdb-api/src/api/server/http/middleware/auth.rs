@@ -3,11 +3,12 @@ use crate::storage::{
     StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
 };
 use axum::{
-    extract::{Request, State},
+    extract::{ConnectInfo, Request, State},
     middleware::Next,
     response::Response,
 };
 use http::StatusCode;
+use std::net::SocketAddr;
 
 pub async fn auth_middleware<
     S: StorageClient
@@ -23,22 +24,45 @@ pub async fn auth_middleware<
     mut request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let session_id = request
+    let session_id = match request
         .headers()
         .get("X-Session-ID")
         .and_then(|h| h.to_str().ok())
-        .and_then(|s| s.parse::<i64>().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
-
-    let valid = state
-        .server
-        .get_session_manager()
-        .find_session(session_id)
-        .is_some();
-
-    if !valid {
-        return Err(StatusCode::UNAUTHORIZED);
-    }
+    {
+        Some(header) => {
+            let session_id = header
+                .parse::<i64>()
+                .map_err(|_| StatusCode::UNAUTHORIZED)?;
+            state
+                .server
+                .get_session_manager()
+                .find_session(session_id)
+                .ok_or(StatusCode::UNAUTHORIZED)?;
+            session_id
+        }
+        None => {
+            // Bearer tokens (JWT or API key) are verified on every request
+            let token = request
+                .headers()
+                .get(http::header::AUTHORIZATION)
+                .and_then(|h| h.to_str().ok())
+                .and_then(|h| h.strip_prefix("Bearer "))
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .ok_or(StatusCode::UNAUTHORIZED)?;
+            let client_ip = request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip());
+            state
+                .server
+                .get_graph_service()
+                .authenticate_token(token, client_ip)
+                .await
+                .map_err(|_| StatusCode::UNAUTHORIZED)?
+                .id()
+        }
+    };
 
     request.extensions_mut().insert(session_id);
 
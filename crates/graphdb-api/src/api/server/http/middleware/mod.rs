@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod error;
 pub mod logging;
+pub mod rate_limit;
 
 pub use error::error_handling_middleware;
@@ -0,0 +1,45 @@
+use crate::api::server::http::{error::HttpError, state::AppState};
+use crate::storage::{
+    StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
+};
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::net::SocketAddr;
+
+/// Reject requests from client IPs that exceed the configured request rate
+pub async fn rate_limit_middleware<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(state): State<AppState<S>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // The peer address is only known when the router is served with connect info
+    let client = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    if let (Some(limiter), Some(client)) = (state.server.get_rate_limiter(), client) {
+        if let Err(wait) = limiter.try_acquire(client) {
+            let retry_after = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+            return HttpError::TooManyRequests(
+                format!("Rate limit exceeded for {}", client),
+                Some(retry_after),
+            )
+            .into_response();
+        }
+    }
+
+    next.run(request).await
+}
@@ -34,11 +34,11 @@ use super::{
         health, query, schema,
 
         session::{create as create_session, delete_session, get_session},
-        statistics::{database, freeze_stats, queries, search as search_stats, session, system, trigger_freeze},
+        statistics::{database, freeze_stats, queries, quotas, search as search_stats, session, system, trigger_freeze},
         stream::execute_stream,
         sync, transaction,
     },
-    middleware::{auth::auth_middleware, error, logging},
+    middleware::{auth::auth_middleware, error, logging, rate_limit::rate_limit_middleware},
     state::AppState,
 };
 
@@ -107,6 +107,7 @@ pub fn create_router<
          // Statistical information routing
         .route("/statistics/sessions/{id}", get(session))
         .route("/statistics/queries", get(queries))
+        .route("/statistics/quotas", get(quotas))
         .route("/statistics/database", get(database))
         .route("/statistics/system", get(system))
          .route("/statistics/search", get(search_stats))
@@ -124,6 +125,12 @@ pub fn create_router<
         .route("/query/stream", post(execute_stream))
         // Sync Management Routes
         .route("/sync/status", get(sync::status))
+        .route(
+            "/sync/dlq",
+            get(sync::list_dead_letters).delete(sync::purge_dead_letters),
+        )
+        .route("/sync/dlq/retry", post(sync::retry_dead_letters))
+        .route("/sync/dlq/{id}/retry", post(sync::retry_dead_letter))
         // Schema Routes
         .route(
             "/schema/spaces",
@@ -150,6 +157,10 @@ pub fn create_router<
 
     let router = Router::new()
         .nest("/v1", public_routes.merge(protected_routes))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit_middleware,
+        ))
         .layer(middleware::from_fn(logging::logging_middleware))
         .layer(middleware::from_fn(error::error_handling_middleware))
         .layer(TraceLayer::new_for_http())
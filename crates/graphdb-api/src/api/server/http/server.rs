@@ -6,6 +6,7 @@ use crate::api::core::{QueryApi, SchemaApi, TransactionApi};
 use crate::api::server::auth::PasswordAuthenticator;
 use crate::api::server::batch::BatchManager;
 use crate::api::server::graph_service::GraphService;
+use crate::api::server::rate_limit::RateLimiter;
 use crate::api::server::session::GraphSessionManager;
 use crate::config::Config;
 use crate::query::executor::expression::functions::FunctionRegistry;
@@ -38,6 +39,7 @@ pub struct HttpServer<
     storage: Arc<RwLock<S>>,
     config: Config,
     function_registry: Arc<RwLock<FunctionRegistry>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl<
@@ -69,9 +71,15 @@ impl<
             storage: storage.clone(),
             config: config.clone(),
             function_registry: Arc::new(RwLock::new(FunctionRegistry::new())),
+            rate_limiter: RateLimiter::from_config(&config.server.security.rate_limit),
         }
     }
 
+    /// Per-client-IP rate limiter shared with the gRPC listener, if enabled
+    pub fn get_rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
     /// Get GraphService
     pub fn get_graph_service(&self) -> Arc<GraphService<S>> {
         self.graph_service.clone()
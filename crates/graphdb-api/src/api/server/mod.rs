@@ -10,6 +10,7 @@ pub mod graph_service;
 pub mod grpc;
 pub mod http;
 pub mod permission;
+pub mod rate_limit;
 pub mod session;
 pub mod web;
 
@@ -21,5 +22,6 @@ pub use graph_service::GraphService;
 pub use grpc::{run_server, GraphDBService};
 pub use http::HttpServer;
 pub use permission::{Permission, PermissionChecker, PermissionManager, RoleType};
+pub use rate_limit::RateLimiter;
 pub use session::GraphSessionManager;
 pub use web::WebState;
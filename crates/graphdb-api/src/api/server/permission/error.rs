@@ -10,6 +10,7 @@
 use std::error::Error;
 
 use crate::core::error::codes::{ErrorCode, PublicError, ToPublicError};
+use crate::core::RoleType;
 
 /// Thread-safe boxed error type
 type BoxedError = Box<dyn Error + Send + Sync>;
@@ -44,6 +45,7 @@ pub enum PermissionErrorKind {
     CannotModifyOwnRole,
     ChangePasswordTargetUserRequired,
     CanOnlyChangeOwnPassword,
+    RoleRequired,
 }
 
 impl PermissionErrorKind {
@@ -79,6 +81,7 @@ impl PermissionErrorKind {
                 "change_password_target_user_required"
             }
             PermissionErrorKind::CanOnlyChangeOwnPassword => "can_only_change_own_password",
+            PermissionErrorKind::RoleRequired => "role_required",
         }
     }
 }
@@ -288,6 +291,18 @@ impl PermissionError {
             "Can only change own password",
         )
     }
+
+    pub fn role_required(role: RoleType, user: impl Into<String>, space_id: i64) -> Self {
+        Self::new(
+            PermissionErrorKind::RoleRequired,
+            format!(
+                "Permission denied: role {} required for user {} in space {}",
+                role,
+                user.into(),
+                space_id
+            ),
+        )
+    }
 }
 
 impl std::fmt::Display for PermissionError {
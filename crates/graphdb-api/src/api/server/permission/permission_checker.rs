@@ -433,6 +433,38 @@ impl PermissionChecker {
         self.check_permission(session, OperationType::Show, None, None, None)
     }
 
+    /// Check that the session holds at least the given role in a space
+    ///
+    /// Roles are ranked God > Admin > Dba > User > Guest; a global (God) role
+    /// satisfies every requirement.
+    pub fn require_role(
+        &self,
+        session: &ClientSession,
+        space_id: i64,
+        required: RoleType,
+    ) -> PermissionResult<()> {
+        use crate::api::server::permission::PermissionError;
+
+        if !self.is_authorization_enabled() {
+            return Ok(());
+        }
+
+        let username = session.user();
+        if self.permission_manager.is_god(&username) {
+            return Ok(());
+        }
+
+        let role = self
+            .permission_manager
+            .get_role(&username, space_id)
+            .or_else(|| session.role_with_space(space_id));
+
+        match role {
+            Some(role) if role.to_byte() <= required.to_byte() => Ok(()),
+            _ => Err(PermissionError::role_required(required, username, space_id)),
+        }
+    }
+
     // ==================== Getting internal components (for advanced scenarios) ====================
 
     /// Getting a reference to the rights manager
@@ -462,6 +494,7 @@ mod tests {
             default_username: "root".to_string(),
             default_password: "root".to_string(),
             force_change_default_password: true,
+            ..Default::default()
         }
     }
 
@@ -615,4 +648,28 @@ mod tests {
             .can_write_role(&admin_session, 1, "admin2", RoleType::Admin)
             .is_err());
     }
+
+    #[test]
+    fn test_require_role_ranks_roles() {
+        let checker = create_test_checker();
+        let admin_session = create_user_session("admin1", RoleType::Admin, 1);
+        let guest_session = create_user_session("guest1", RoleType::Guest, 1);
+        let god_session = create_test_session("root", true);
+
+        assert!(checker
+            .require_role(&admin_session, 1, RoleType::User)
+            .is_ok());
+        assert!(checker
+            .require_role(&guest_session, 1, RoleType::Guest)
+            .is_ok());
+        assert!(checker
+            .require_role(&guest_session, 1, RoleType::User)
+            .is_err());
+        assert!(checker
+            .require_role(&admin_session, 2, RoleType::Guest)
+            .is_err());
+        assert!(checker
+            .require_role(&god_session, 2, RoleType::Admin)
+            .is_ok());
+    }
 }
@@ -22,6 +22,9 @@ pub const GOD_SPACE_ID: i64 = -1;
 /// Performance optimization:
 /// Use DashMap to achieve true concurrent access without the need for explicit locking.
 /// Outstanding performance in scenarios where more reading and less writing are required.
+///
+/// Cloning is cheap and the clones share the same role and permission maps.
+#[derive(Clone)]
 pub struct PermissionManager {
     /// User role mapping：username -> {space_id -> role}
     /// The “God” role uses a special space_id: -1 to indicate that it is a global role, which is not associated with a specific Space.
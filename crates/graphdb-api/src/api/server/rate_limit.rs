@@ -0,0 +1,110 @@
+//! Client Rate Limiting
+//!
+//! A token bucket per client IP, shared by the HTTP and gRPC listeners. Each
+//! request takes one token; buckets refill at `requests_per_second` up to
+//! `burst` tokens.
+
+use dashmap::DashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::config::RateLimitConfig;
+
+/// Number of tracked clients above which idle buckets are evicted
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Per-client-IP token bucket rate limiter
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    buckets: DashMap<IpAddr, TokenBucket>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            rate: requests_per_second,
+            burst: f64::from(burst.max(1)),
+            buckets: DashMap::new(),
+        }
+    }
+
+    /// Build the limiter for a configuration; `None` when rate limiting is disabled
+    pub fn from_config(config: &RateLimitConfig) -> Option<Arc<Self>> {
+        config
+            .enabled
+            .then(|| Arc::new(Self::new(config.requests_per_second, config.burst)))
+    }
+
+    /// Take a token for `client`, or return how long to wait for the next one
+    pub fn try_acquire(&self, client: IpAddr) -> Result<(), Duration> {
+        self.try_acquire_at(client, Instant::now())
+    }
+
+    fn try_acquire_at(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        if self.buckets.len() > MAX_TRACKED_CLIENTS {
+            self.evict_full(now);
+        }
+
+        let mut bucket = self.buckets.entry(client).or_insert(TokenBucket {
+            tokens: self.burst,
+            updated_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.rate).min(self.burst);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    /// Drop buckets that have refilled completely; they behave like new clients
+    fn evict_full(&self, now: Instant) {
+        self.buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated_at);
+            bucket.tokens + elapsed.as_secs_f64() * self.rate < self.burst
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_per_client() {
+        let limiter = RateLimiter::new(2.0, 3);
+        let client: IpAddr = "10.0.0.1".parse().expect("valid address");
+        let other: IpAddr = "10.0.0.2".parse().expect("valid address");
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.try_acquire_at(client, start).is_ok());
+        }
+        let wait = limiter
+            .try_acquire_at(client, start)
+            .expect_err("burst is exhausted");
+        assert_eq!(wait, Duration::from_millis(500));
+
+        // Buckets are independent per client
+        assert!(limiter.try_acquire_at(other, start).is_ok());
+
+        // Two tokens refill per second
+        let later = start + Duration::from_secs(1);
+        assert!(limiter.try_acquire_at(client, later).is_ok());
+        assert!(limiter.try_acquire_at(client, later).is_ok());
+        assert!(limiter.try_acquire_at(client, later).is_err());
+    }
+}
@@ -0,0 +1,486 @@
+//! Per-User Resource Quotas
+//!
+//! Enforces the hourly limits stored on `UserInfo` (`max_queries_per_hour`,
+//! `max_updates_per_hour`, `max_connections_per_hour`) and the concurrent
+//! session limit (`max_user_connections`). Hourly usage is tracked with a
+//! sliding window of one-minute buckets, so a burst at the end of one hour
+//! still counts against the start of the next.
+
+use dashmap::DashMap;
+use parking_lot::RwLock;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use crate::core::error::codes::{ErrorCode, PublicError, ToPublicError};
+use crate::core::types::UserInfo;
+
+/// Length of the quota window
+pub const QUOTA_WINDOW: Duration = Duration::from_secs(3600);
+/// Granularity of the sliding window
+const BUCKET_SECS: u64 = 60;
+const BUCKETS_PER_WINDOW: u64 = QUOTA_WINDOW.as_secs() / BUCKET_SECS;
+
+/// Resolves the current limits of a user
+pub type QuotaLimitsResolver = Arc<dyn Fn(&str) -> QuotaLimits + Send + Sync>;
+
+/// Quota-limited resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaKind {
+    QueriesPerHour,
+    UpdatesPerHour,
+    ConnectionsPerHour,
+    UserConnections,
+}
+
+impl fmt::Display for QuotaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuotaKind::QueriesPerHour => write!(f, "queries per hour"),
+            QuotaKind::UpdatesPerHour => write!(f, "updates per hour"),
+            QuotaKind::ConnectionsPerHour => write!(f, "connections per hour"),
+            QuotaKind::UserConnections => write!(f, "concurrent connections"),
+        }
+    }
+}
+
+/// Quota limits of a user (0 means unlimited)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuotaLimits {
+    pub max_queries_per_hour: u32,
+    pub max_updates_per_hour: u32,
+    pub max_connections_per_hour: u32,
+    pub max_user_connections: u32,
+}
+
+impl QuotaLimits {
+    fn get(&self, kind: QuotaKind) -> u32 {
+        match kind {
+            QuotaKind::QueriesPerHour => self.max_queries_per_hour,
+            QuotaKind::UpdatesPerHour => self.max_updates_per_hour,
+            QuotaKind::ConnectionsPerHour => self.max_connections_per_hour,
+            QuotaKind::UserConnections => self.max_user_connections,
+        }
+    }
+}
+
+impl From<&UserInfo> for QuotaLimits {
+    fn from(user: &UserInfo) -> Self {
+        let limit = |value: i32| value.max(0) as u32;
+        Self {
+            max_queries_per_hour: limit(user.max_queries_per_hour),
+            max_updates_per_hour: limit(user.max_updates_per_hour),
+            max_connections_per_hour: limit(user.max_connections_per_hour),
+            max_user_connections: limit(user.max_user_connections),
+        }
+    }
+}
+
+/// Quota exceeded error
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Quota exceeded: user {username} is limited to {limit} {kind}{}", retry_hint(.retry_after))]
+pub struct QuotaExceeded {
+    pub username: String,
+    pub kind: QuotaKind,
+    pub limit: u32,
+    /// Time until a request would be admitted again; `None` when it depends on
+    /// other sessions being closed
+    pub retry_after: Option<Duration>,
+}
+
+fn retry_hint(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!(", retry after {}s", delay.as_secs().max(1)),
+        None => String::new(),
+    }
+}
+
+impl QuotaExceeded {
+    /// Whole seconds to wait before retrying, rounded up
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        self.retry_after
+            .map(|delay| delay.as_secs() + u64::from(delay.subsec_nanos() > 0))
+    }
+}
+
+impl ToPublicError for QuotaExceeded {
+    fn to_public_error(&self) -> PublicError {
+        PublicError::new(self.to_error_code(), self.to_public_message())
+    }
+
+    fn to_error_code(&self) -> ErrorCode {
+        ErrorCode::QuotaExceeded
+    }
+
+    fn to_public_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Event counter over a sliding one-hour window of one-minute buckets
+#[derive(Debug, Default)]
+struct SlidingWindowCounter {
+    /// (bucket index, events) in ascending bucket order
+    buckets: VecDeque<(u64, u32)>,
+}
+
+impl SlidingWindowCounter {
+    fn prune(&mut self, bucket: u64) {
+        while let Some(&(index, _)) = self.buckets.front() {
+            if index + BUCKETS_PER_WINDOW > bucket {
+                break;
+            }
+            self.buckets.pop_front();
+        }
+    }
+
+    fn count(&mut self, bucket: u64) -> u64 {
+        self.prune(bucket);
+        self.buckets.iter().map(|&(_, n)| u64::from(n)).sum()
+    }
+
+    fn record(&mut self, bucket: u64) {
+        self.prune(bucket);
+        match self.buckets.back_mut() {
+            Some((index, n)) if *index == bucket => *n = n.saturating_add(1),
+            _ => self.buckets.push_back((bucket, 1)),
+        }
+    }
+
+    /// Time until enough old events leave the window to admit one more
+    fn retry_after(&mut self, elapsed: Duration, limit: u32) -> Duration {
+        let bucket = elapsed.as_secs() / BUCKET_SECS;
+        let excess = self.count(bucket).saturating_sub(u64::from(limit)) + 1;
+        let mut released = 0;
+        for &(index, n) in &self.buckets {
+            released += u64::from(n);
+            if released >= excess {
+                let expires = Duration::from_secs((index + BUCKETS_PER_WINDOW) * BUCKET_SECS);
+                return expires.saturating_sub(elapsed);
+            }
+        }
+        Duration::ZERO
+    }
+}
+
+#[derive(Debug, Default)]
+struct UserCounters {
+    queries: SlidingWindowCounter,
+    updates: SlidingWindowCounter,
+    connections: SlidingWindowCounter,
+}
+
+impl UserCounters {
+    fn counter(&mut self, kind: QuotaKind) -> &mut SlidingWindowCounter {
+        match kind {
+            QuotaKind::QueriesPerHour => &mut self.queries,
+            QuotaKind::UpdatesPerHour => &mut self.updates,
+            QuotaKind::ConnectionsPerHour | QuotaKind::UserConnections => &mut self.connections,
+        }
+    }
+}
+
+/// Usage snapshot of a user, as shown in session statistics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotaUsage {
+    pub username: String,
+    pub queries_last_hour: u64,
+    pub updates_last_hour: u64,
+    pub connections_last_hour: u64,
+    pub limits: QuotaLimits,
+}
+
+impl QuotaUsage {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "username": self.username,
+            "queries_last_hour": self.queries_last_hour,
+            "updates_last_hour": self.updates_last_hour,
+            "connections_last_hour": self.connections_last_hour,
+            "max_queries_per_hour": self.limits.max_queries_per_hour,
+            "max_updates_per_hour": self.limits.max_updates_per_hour,
+            "max_connections_per_hour": self.limits.max_connections_per_hour,
+            "max_user_connections": self.limits.max_user_connections,
+        })
+    }
+}
+
+/// Quota Manager - Tracks per-user usage and admits or rejects requests
+pub struct QuotaManager {
+    epoch: Instant,
+    counters: DashMap<String, UserCounters>,
+    resolver: RwLock<Option<QuotaLimitsResolver>>,
+}
+
+impl fmt::Debug for QuotaManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuotaManager")
+            .field("tracked_users", &self.counters.len())
+            .finish()
+    }
+}
+
+impl Default for QuotaManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuotaManager {
+    pub fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+            counters: DashMap::new(),
+            resolver: RwLock::new(None),
+        }
+    }
+
+    /// Set the lookup used to read a user's limits; without one nothing is limited
+    pub fn set_limits_resolver<F>(&self, resolver: F)
+    where
+        F: Fn(&str) -> QuotaLimits + Send + Sync + 'static,
+    {
+        *self.resolver.write() = Some(Arc::new(resolver));
+    }
+
+    /// Current limits of a user
+    pub fn limits(&self, username: &str) -> QuotaLimits {
+        let resolver = self.resolver.read().clone();
+        resolver.map(|r| r(username)).unwrap_or_default()
+    }
+
+    /// Check whether a statement would be admitted, without recording it
+    pub fn check_query(&self, username: &str, is_update: bool) -> Result<(), QuotaExceeded> {
+        self.admit_query_at(username, is_update, Instant::now(), false)
+    }
+
+    /// Admit a statement and record it against the user's hourly quotas
+    pub fn acquire_query(&self, username: &str, is_update: bool) -> Result<(), QuotaExceeded> {
+        self.admit_query_at(username, is_update, Instant::now(), true)
+    }
+
+    /// Admit a new session for a user that currently holds `open_sessions`
+    pub fn acquire_connection(
+        &self,
+        username: &str,
+        open_sessions: usize,
+    ) -> Result<(), QuotaExceeded> {
+        self.acquire_connection_at(username, open_sessions, Instant::now())
+    }
+
+    /// Usage snapshot of a user
+    pub fn usage(&self, username: &str) -> QuotaUsage {
+        let bucket = self.bucket(Instant::now());
+        let limits = self.limits(username);
+        let (queries, updates, connections) = match self.counters.get_mut(username) {
+            Some(mut counters) => (
+                counters.queries.count(bucket),
+                counters.updates.count(bucket),
+                counters.connections.count(bucket),
+            ),
+            None => (0, 0, 0),
+        };
+        QuotaUsage {
+            username: username.to_string(),
+            queries_last_hour: queries,
+            updates_last_hour: updates,
+            connections_last_hour: connections,
+            limits,
+        }
+    }
+
+    /// Usage snapshots of every user seen in the last hour
+    pub fn all_usage(&self) -> Vec<QuotaUsage> {
+        let mut names: Vec<String> = self.counters.iter().map(|e| e.key().clone()).collect();
+        names.sort();
+        names
+            .iter()
+            .map(|name| self.usage(name))
+            .filter(|usage| {
+                usage.queries_last_hour + usage.updates_last_hour + usage.connections_last_hour > 0
+            })
+            .collect()
+    }
+
+    /// Forget users without activity in the last hour
+    pub fn purge_idle(&self) {
+        let bucket = self.bucket(Instant::now());
+        self.counters.retain(|_, counters| {
+            counters.queries.count(bucket)
+                + counters.updates.count(bucket)
+                + counters.connections.count(bucket)
+                > 0
+        });
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.epoch)
+    }
+
+    fn bucket(&self, now: Instant) -> u64 {
+        self.elapsed(now).as_secs() / BUCKET_SECS
+    }
+
+    fn admit_query_at(
+        &self,
+        username: &str,
+        is_update: bool,
+        now: Instant,
+        record: bool,
+    ) -> Result<(), QuotaExceeded> {
+        let limits = self.limits(username);
+        let mut kinds = vec![QuotaKind::QueriesPerHour];
+        if is_update {
+            kinds.push(QuotaKind::UpdatesPerHour);
+        }
+        self.admit_at(username, &kinds, limits, now, record)
+    }
+
+    fn acquire_connection_at(
+        &self,
+        username: &str,
+        open_sessions: usize,
+        now: Instant,
+    ) -> Result<(), QuotaExceeded> {
+        let limits = self.limits(username);
+        let max_sessions = limits.max_user_connections;
+        if max_sessions > 0 && open_sessions >= max_sessions as usize {
+            return Err(QuotaExceeded {
+                username: username.to_string(),
+                kind: QuotaKind::UserConnections,
+                limit: max_sessions,
+                retry_after: None,
+            });
+        }
+        self.admit_at(
+            username,
+            &[QuotaKind::ConnectionsPerHour],
+            limits,
+            now,
+            true,
+        )
+    }
+
+    fn admit_at(
+        &self,
+        username: &str,
+        kinds: &[QuotaKind],
+        limits: QuotaLimits,
+        now: Instant,
+        record: bool,
+    ) -> Result<(), QuotaExceeded> {
+        let elapsed = self.elapsed(now);
+        let bucket = elapsed.as_secs() / BUCKET_SECS;
+        let mut counters = self.counters.entry(username.to_string()).or_default();
+
+        // Check every limit before recording so a rejected request costs nothing
+        for &kind in kinds {
+            let limit = limits.get(kind);
+            if limit > 0 && counters.counter(kind).count(bucket) >= u64::from(limit) {
+                return Err(QuotaExceeded {
+                    username: username.to_string(),
+                    kind,
+                    limit,
+                    retry_after: Some(counters.counter(kind).retry_after(elapsed, limit)),
+                });
+            }
+        }
+
+        if record {
+            for &kind in kinds {
+                counters.counter(kind).record(bucket);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(limits: QuotaLimits) -> QuotaManager {
+        let manager = QuotaManager::new();
+        manager.set_limits_resolver(move |name: &str| {
+            if name == "batch" {
+                limits
+            } else {
+                QuotaLimits::default()
+            }
+        });
+        manager
+    }
+
+    #[test]
+    fn test_query_quota_sliding_window() {
+        let manager = manager(QuotaLimits {
+            max_queries_per_hour: 3,
+            max_updates_per_hour: 1,
+            ..Default::default()
+        });
+        let start = manager.epoch;
+
+        manager
+            .admit_query_at("batch", true, start, true)
+            .expect("first update is admitted");
+        let err = manager
+            .admit_query_at("batch", true, start + Duration::from_secs(90), true)
+            .expect_err("second update exceeds the update quota");
+        assert_eq!(err.kind, QuotaKind::UpdatesPerHour);
+        assert_eq!(err.retry_after, Some(Duration::from_secs(3600 - 90)));
+
+        for offset in [600, 1200] {
+            manager
+                .admit_query_at("batch", false, start + Duration::from_secs(offset), true)
+                .expect("reads within the query quota are admitted");
+        }
+        let err = manager
+            .admit_query_at("batch", false, start + Duration::from_secs(1800), true)
+            .expect_err("fourth query exceeds the query quota");
+        assert_eq!(err.kind, QuotaKind::QueriesPerHour);
+        assert_eq!(err.retry_after_secs(), Some(1800));
+
+        // The first query leaves the window after an hour
+        manager
+            .admit_query_at("batch", false, start + Duration::from_secs(3600), true)
+            .expect("query is admitted once the oldest bucket expires");
+
+        // Other users are unlimited
+        for _ in 0..10 {
+            manager
+                .acquire_query("root", true)
+                .expect("unlimited user is admitted");
+        }
+        assert_eq!(manager.usage("root").updates_last_hour, 10);
+    }
+
+    #[test]
+    fn test_connection_quota() {
+        let manager = manager(QuotaLimits {
+            max_connections_per_hour: 2,
+            max_user_connections: 1,
+            ..Default::default()
+        });
+
+        manager
+            .acquire_connection("batch", 0)
+            .expect("first session is admitted");
+        let err = manager
+            .acquire_connection("batch", 1)
+            .expect_err("concurrent session limit is enforced");
+        assert_eq!(err.kind, QuotaKind::UserConnections);
+        assert_eq!(err.retry_after, None);
+
+        manager
+            .acquire_connection("batch", 0)
+            .expect("second session is admitted");
+        let err = manager
+            .acquire_connection("batch", 0)
+            .expect_err("hourly connection limit is enforced");
+        assert_eq!(err.kind, QuotaKind::ConnectionsPerHour);
+        assert!(err.to_string().contains("retry after"));
+        assert_eq!(manager.usage("batch").connections_last_hour, 2);
+    }
+}
@@ -308,5 +308,6 @@ pub fn build_query_request_context(
         space_name: session.space_name(),
         query,
         parameters,
+        role: None,
     }
 }
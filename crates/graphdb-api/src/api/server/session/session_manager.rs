@@ -9,6 +9,7 @@ use std::sync::{
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::time;
 
+use super::quota::{QuotaManager, QuotaUsage};
 use super::{SessionError, SessionResult};
 use crate::api::server::client::{ClientSession, Session};
 
@@ -29,6 +30,8 @@ pub struct SessionInfo {
     pub last_access_time: SystemTime,
     pub active_queries: usize,
     pub timezone: Option<i32>,
+    /// Hourly quota usage of the session's user
+    pub quota_usage: Option<QuotaUsage>,
 }
 
 impl SessionInfo {
@@ -42,6 +45,7 @@ impl SessionInfo {
             last_access_time: SystemTime::now() - Duration::from_millis(session.idle_seconds()),
             active_queries: session.active_queries_count(),
             timezone: session.timezone(),
+            quota_usage: None,
         }
     }
 
@@ -72,6 +76,7 @@ impl SessionInfo {
             last_access_time: now,
             active_queries: 0,
             timezone: None,
+            quota_usage: None,
         })
     }
 
@@ -92,6 +97,8 @@ pub struct GraphSessionManager {
     session_idle_timeout: Duration,
     /// Is the background cleanup task currently running?
     cleanup_task_running: Arc<AtomicBool>,
+    /// Per-user resource quotas
+    quota_manager: Arc<QuotaManager>,
 }
 
 impl GraphSessionManager {
@@ -112,6 +119,7 @@ impl GraphSessionManager {
             max_connections,
             session_idle_timeout,
             cleanup_task_running: Arc::new(AtomicBool::new(false)),
+            quota_manager: Arc::new(QuotaManager::new()),
         })
     }
 
@@ -144,6 +152,19 @@ impl GraphSessionManager {
         self.cleanup_task_running.load(Ordering::SeqCst)
     }
 
+    /// Per-user resource quotas
+    pub fn quota_manager(&self) -> &Arc<QuotaManager> {
+        &self.quota_manager
+    }
+
+    /// Number of open sessions owned by a user
+    pub fn user_session_count(&self, user_name: &str) -> usize {
+        self.sessions
+            .iter()
+            .filter(|entry| entry.value().user() == user_name)
+            .count()
+    }
+
     /// Creates a new session
     pub async fn create_session(
         &self,
@@ -161,6 +182,14 @@ impl GraphSessionManager {
             return Err("Exceeded maximum allowed connections".to_string());
         }
 
+        if let Err(e) = self
+            .quota_manager
+            .acquire_connection(&user_name, self.user_session_count(&user_name))
+        {
+            warn!("Failed to create session for user {}: {}", user_name, e);
+            return Err(e.to_string());
+        }
+
         // Generate a new session ID
         let session_id = self.generate_session_id();
         info!(
@@ -248,7 +277,9 @@ impl GraphSessionManager {
                 let session_id = entry.key();
                 let client_session = entry.value();
                 create_times.get(session_id).map(|&create_time| {
-                    SessionInfo::from_client_session(client_session, create_time)
+                    let mut info = SessionInfo::from_client_session(client_session, create_time);
+                    info.quota_usage = Some(self.quota_manager.usage(&info.user_name));
+                    info
                 })
             })
             .collect()
@@ -261,9 +292,11 @@ impl GraphSessionManager {
 
         // Read lock acquisition time
         let create_times = self.session_create_times.read();
-        create_times
-            .get(&session_id)
-            .map(|&create_time| SessionInfo::from_client_session(&client_session, create_time))
+        create_times.get(&session_id).map(|&create_time| {
+            let mut info = SessionInfo::from_client_session(&client_session, create_time);
+            info.quota_usage = Some(self.quota_manager.usage(&info.user_name));
+            info
+        })
     }
 
     /// Terminate the specified session (KILL SESSION)
@@ -396,6 +429,7 @@ impl GraphSessionManager {
             }
 
             self.reclaim_expired_sessions().await;
+            self.quota_manager.purge_idle();
         }
 
         info!("Session cleanup task has stopped");
@@ -0,0 +1,181 @@
+//! Query Endpoint Handlers
+//!
+//! Publish saved favorites as parameterised REST endpoints and invoke them:
+//! - `POST /v1/endpoints` publishes a favorite as a new endpoint version
+//! - `POST /v1/endpoints/{name}` runs the endpoint with JSON parameters
+
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+
+use crate::api::server::permission::PermissionChecker;
+use crate::api::server::web::{
+    error::WebResult,
+    models::{
+        endpoint::{
+            EndpointItem, EndpointListResponse, InvokeEndpointRequest, PublishEndpointRequest,
+        },
+        ApiResponse,
+    },
+    services::endpoint_service::EndpointService,
+    WebState,
+};
+use crate::storage::{
+    StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
+};
+
+/// Create endpoint routes (without state)
+pub fn create_routes<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>() -> Router<WebState<S>> {
+    Router::new()
+        .route("/", get(list_endpoints).post(publish_endpoint))
+        .route(
+            "/{name}",
+            get(get_endpoint)
+                .post(invoke_endpoint)
+                .delete(delete_endpoint),
+        )
+}
+
+/// Version selector for endpoint lookups
+#[derive(Debug, Deserialize)]
+pub struct VersionParams {
+    pub version: Option<i64>,
+}
+
+/// Publish a favorite as an endpoint
+async fn publish_endpoint<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    Extension(session_id): Extension<i64>,
+    State(web_state): State<WebState<S>>,
+    Json(request): Json<PublishEndpointRequest>,
+) -> WebResult<(StatusCode, Json<ApiResponse<EndpointItem>>)> {
+    let graph_service = web_state.core_state.server.get_graph_service();
+
+    let service = EndpointService::new(web_state.metadata_storage.clone());
+    let item = service
+        .publish(graph_service.as_ref(), session_id, request)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(item))))
+}
+
+/// List published endpoints
+async fn list_endpoints<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(web_state): State<WebState<S>>,
+) -> WebResult<Json<ApiResponse<EndpointListResponse>>> {
+    let service = EndpointService::new(web_state.metadata_storage.clone());
+    let items = service.list_endpoints().await?;
+
+    Ok(Json(ApiResponse::success(EndpointListResponse { items })))
+}
+
+/// Get an endpoint definition
+async fn get_endpoint<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    State(web_state): State<WebState<S>>,
+    Path(name): Path<String>,
+    Query(params): Query<VersionParams>,
+) -> WebResult<Json<ApiResponse<EndpointItem>>> {
+    let service = EndpointService::new(web_state.metadata_storage.clone());
+    let item = service.get_endpoint(&name, params.version).await?;
+
+    Ok(Json(ApiResponse::success(item)))
+}
+
+/// Invoke an endpoint
+async fn invoke_endpoint<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    Extension(session_id): Extension<i64>,
+    State(web_state): State<WebState<S>>,
+    Path(name): Path<String>,
+    Json(request): Json<InvokeEndpointRequest>,
+) -> WebResult<Json<ApiResponse<serde_json::Value>>> {
+    let server = &web_state.core_state.server;
+    let graph_service = server.get_graph_service();
+    let checker = PermissionChecker::new(
+        graph_service.get_permission_manager().as_ref().clone(),
+        server.get_config().server.auth.clone(),
+    );
+
+    let service = EndpointService::new(web_state.metadata_storage.clone());
+    let data = service
+        .invoke(graph_service.as_ref(), &checker, session_id, &name, request)
+        .await?;
+
+    Ok(Json(ApiResponse::success(data)))
+}
+
+/// Delete an endpoint and all its versions
+async fn delete_endpoint<
+    S: StorageClient
+        + StorageSchemaContextOps
+        + StorageSyncContextOps
+        + StorageTransactionContextOps
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    Extension(session_id): Extension<i64>,
+    State(web_state): State<WebState<S>>,
+    Path(name): Path<String>,
+) -> WebResult<(StatusCode, Json<ApiResponse<serde_json::Value>>)> {
+    let graph_service = web_state.core_state.server.get_graph_service();
+
+    let service = EndpointService::new(web_state.metadata_storage.clone());
+    service
+        .delete_endpoint(graph_service.as_ref(), session_id, &name)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(serde_json::json!({"deleted": true}))),
+    ))
+}
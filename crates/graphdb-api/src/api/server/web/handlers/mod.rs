@@ -1,6 +1,7 @@
 //! Web Module HTTP Handlers
 
 pub mod data_browser;
+pub mod endpoints;
 pub mod graph_data;
 pub mod metadata;
 pub mod schema_ext;
@@ -3,6 +3,7 @@
 //! Provides Web management interface API for GraphDB frontend:
 //! - Query history management
 //! - Query favorites management
+//! - Query endpoints published from favorites
 //! - Extended Schema management
 //! - Data browsing
 //! - Graph data queries
@@ -81,12 +82,14 @@ pub fn create_router<
     let schema_routes = handlers::schema_ext::create_routes();
     let data_routes = handlers::data_browser::create_routes();
     let graph_routes = handlers::graph_data::create_routes();
+    let endpoint_routes = handlers::endpoints::create_routes();
 
     Router::new()
         .nest("/v1/queries", queries_routes)
         .nest("/v1/schema", schema_routes)
         .nest("/v1/data", data_routes)
         .nest("/v1/graph", graph_routes)
+        .nest("/v1/endpoints", endpoint_routes)
         .layer(axum_middleware::from_fn_with_state(
             web_state.clone(),
             middleware::web_auth_middleware,
@@ -0,0 +1,80 @@
+//! Query Endpoint Models
+//!
+//! A query endpoint is a saved favorite published under a stable name, so that it
+//! can be invoked over REST with JSON parameters bound to its `$param` placeholders.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Published query endpoint (one item per version)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointItem {
+    pub name: String,
+    pub version: i64,
+    pub query: String,
+    pub description: Option<String>,
+    /// Space the query runs in; the caller's current space when absent
+    pub space: Option<String>,
+    /// Placeholder names the query expects
+    pub params: Vec<String>,
+    /// Minimum role required to invoke the endpoint (GOD, ADMIN, DBA, USER, GUEST)
+    pub required_role: Option<String>,
+    pub response: ResponseShape,
+    pub published_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// How an endpoint shapes its result rows
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResponseShape {
+    #[serde(default)]
+    pub format: ResponseFormat,
+    /// Columns to return, in order; all columns when absent
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+    /// Maximum number of rows to return
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Response body format of an endpoint
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Array of row objects keyed by column name
+    #[default]
+    Rows,
+    /// Object of column name to array of values
+    Columnar,
+    /// First row as an object, or null
+    First,
+    /// First value of the first row, or null
+    Scalar,
+}
+
+/// Request to publish a favorite as an endpoint
+#[derive(Debug, Deserialize)]
+pub struct PublishEndpointRequest {
+    pub name: String,
+    pub favorite_id: String,
+    pub description: Option<String>,
+    pub space: Option<String>,
+    pub required_role: Option<String>,
+    #[serde(default)]
+    pub response: ResponseShape,
+}
+
+/// Request to invoke an endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct InvokeEndpointRequest {
+    #[serde(default)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// Version to invoke; the latest when absent
+    pub version: Option<i64>,
+}
+
+/// Endpoint list response
+#[derive(Debug, Serialize)]
+pub struct EndpointListResponse {
+    pub items: Vec<EndpointItem>,
+}
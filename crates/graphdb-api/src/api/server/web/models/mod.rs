@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod endpoint;
 pub mod metadata;
 pub mod schema;
 
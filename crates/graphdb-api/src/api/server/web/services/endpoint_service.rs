@@ -0,0 +1,360 @@
+//! Query Endpoint Service
+//!
+//! Publishes saved favorites as named, versioned REST endpoints and invokes them
+//! with JSON parameters bound to the query's `$param` placeholders.
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::server::graph_service::GraphService;
+use crate::api::server::http::handlers::query::{convert_parameters, value_to_json};
+use crate::api::server::permission::PermissionChecker;
+use crate::api::server::web::error::{WebError, WebResult};
+use crate::api::server::web::models::endpoint::{
+    EndpointItem, InvokeEndpointRequest, PublishEndpointRequest, ResponseFormat, ResponseShape,
+};
+use crate::api::server::web::storage::{MetadataStorage, SqliteStorage};
+use crate::core::{RoleType, Value};
+use crate::query::executor::ExecutionResult;
+use crate::storage::{
+    StorageClient, StorageSchemaContextOps, StorageSyncContextOps, StorageTransactionContextOps,
+};
+
+/// Query endpoint service
+pub struct EndpointService {
+    storage: Arc<SqliteStorage>,
+}
+
+impl EndpointService {
+    /// Create a new endpoint service
+    pub fn new(storage: Arc<SqliteStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// Publish a favorite of the session as the next version of an endpoint
+    ///
+    /// The query is prepared up front, which validates it, caches its plan and
+    /// yields the placeholder names the endpoint accepts.
+    pub async fn publish<S>(
+        &self,
+        graph_service: &GraphService<S>,
+        session_id: i64,
+        request: PublishEndpointRequest,
+    ) -> WebResult<EndpointItem>
+    where
+        S: StorageClient
+            + StorageSchemaContextOps
+            + StorageSyncContextOps
+            + StorageTransactionContextOps
+            + Clone
+            + 'static,
+    {
+        let name = request.name.trim().to_string();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(WebError::BadRequest(
+                "Endpoint name must be non-empty and contain only letters, digits, '_' or '-'"
+                    .to_string(),
+            ));
+        }
+
+        let required_role = request
+            .required_role
+            .as_deref()
+            .map(parse_role)
+            .transpose()?
+            .map(|role| role.to_string());
+
+        let publisher = graph_service
+            .get_session_manager()
+            .find_session(session_id)
+            .map(|session| session.user())
+            .ok_or_else(|| WebError::Unauthorized("Invalid session".to_string()))?;
+
+        let favorite = self
+            .storage
+            .get_favorite(&request.favorite_id, &session_id.to_string())
+            .await?;
+
+        let params = graph_service
+            .prepare(session_id, &favorite.query, request.space.as_deref())
+            .map_err(WebError::Query)?;
+
+        let mut item = EndpointItem {
+            name,
+            version: 0,
+            query: favorite.query,
+            description: request.description.or(favorite.description),
+            space: request.space,
+            params,
+            required_role,
+            response: request.response,
+            published_by: publisher,
+            created_at: Utc::now(),
+        };
+
+        item.version = self.storage.add_endpoint(&item).await?;
+        Ok(item)
+    }
+
+    /// Get an endpoint version, or its latest version
+    pub async fn get_endpoint(&self, name: &str, version: Option<i64>) -> WebResult<EndpointItem> {
+        self.storage.get_endpoint(name, version).await
+    }
+
+    /// List the latest version of every endpoint
+    pub async fn list_endpoints(&self) -> WebResult<Vec<EndpointItem>> {
+        self.storage.list_endpoints().await
+    }
+
+    /// Delete all versions of an endpoint
+    ///
+    /// Only the publisher of the latest version or an admin may delete an endpoint.
+    pub async fn delete_endpoint<S>(
+        &self,
+        graph_service: &GraphService<S>,
+        session_id: i64,
+        name: &str,
+    ) -> WebResult<()>
+    where
+        S: StorageClient
+            + StorageSchemaContextOps
+            + StorageSyncContextOps
+            + StorageTransactionContextOps
+            + Clone
+            + 'static,
+    {
+        let user = graph_service
+            .get_session_manager()
+            .find_session(session_id)
+            .map(|session| session.user())
+            .ok_or_else(|| WebError::Unauthorized("Invalid session".to_string()))?;
+
+        let latest = self.storage.get_endpoint(name, None).await?;
+        if latest.published_by != user && !graph_service.get_permission_manager().is_admin(&user) {
+            return Err(WebError::Unauthorized(format!(
+                "Only the publisher or an admin can delete endpoint '{}'",
+                name
+            )));
+        }
+
+        self.storage.delete_endpoint(name).await
+    }
+
+    /// Invoke an endpoint with JSON parameters and return its shaped result
+    pub async fn invoke<S>(
+        &self,
+        graph_service: &GraphService<S>,
+        checker: &PermissionChecker,
+        session_id: i64,
+        name: &str,
+        request: InvokeEndpointRequest,
+    ) -> WebResult<serde_json::Value>
+    where
+        S: StorageClient
+            + StorageSchemaContextOps
+            + StorageSyncContextOps
+            + StorageTransactionContextOps
+            + Clone
+            + 'static,
+    {
+        let endpoint = self.storage.get_endpoint(name, request.version).await?;
+
+        if let Some(ref role) = endpoint.required_role {
+            let required = parse_role(role)?;
+            let session = graph_service
+                .get_session_manager()
+                .find_session(session_id)
+                .ok_or_else(|| WebError::Unauthorized("Invalid session".to_string()))?;
+            let space_id = graph_service
+                .resolve_space(&session, endpoint.space.as_deref())
+                .map_err(WebError::BadRequest)?
+                .map(|space| space.id as i64)
+                .unwrap_or(0);
+
+            checker
+                .require_role(&session, space_id, required)
+                .map_err(|e| WebError::Unauthorized(e.to_string()))?;
+        }
+
+        let params = bind_params(&endpoint.params, request.params)?;
+        let result = graph_service
+            .execute_prepared(
+                session_id,
+                &endpoint.query,
+                endpoint.space.as_deref(),
+                params,
+            )
+            .await
+            .map_err(WebError::Query)?;
+
+        Ok(shape_result(result, &endpoint.response))
+    }
+}
+
+/// Parse a role name such as `USER` or `admin`
+fn parse_role(name: &str) -> WebResult<RoleType> {
+    match name.trim().to_uppercase().as_str() {
+        "GOD" => Ok(RoleType::God),
+        "ADMIN" => Ok(RoleType::Admin),
+        "DBA" => Ok(RoleType::Dba),
+        "USER" => Ok(RoleType::User),
+        "GUEST" => Ok(RoleType::Guest),
+        other => Err(WebError::BadRequest(format!("Unknown role: {}", other))),
+    }
+}
+
+/// Bind JSON arguments to the endpoint's placeholders
+///
+/// Every placeholder must be given and unknown arguments are rejected, so a typo
+/// fails loudly instead of silently binding NULL.
+fn bind_params(
+    expected: &[String],
+    args: serde_json::Map<String, serde_json::Value>,
+) -> WebResult<HashMap<String, Value>> {
+    if let Some(unknown) = args.keys().find(|key| !expected.contains(key)) {
+        return Err(WebError::BadRequest(format!(
+            "Unknown parameter: {}",
+            unknown
+        )));
+    }
+    if let Some(missing) = expected.iter().find(|name| !args.contains_key(*name)) {
+        return Err(WebError::BadRequest(format!(
+            "Missing parameter: {}",
+            missing
+        )));
+    }
+
+    convert_parameters(args.into_iter().collect()).map_err(WebError::BadRequest)
+}
+
+/// Apply an endpoint's response shape to an execution result
+fn shape_result(result: ExecutionResult, shape: &ResponseShape) -> serde_json::Value {
+    let (columns, rows) = match result {
+        ExecutionResult::DataSet(ds) => (ds.col_names, ds.rows),
+        _ => (Vec::new(), Vec::new()),
+    };
+
+    let selected: Vec<(String, usize)> = match shape.columns {
+        Some(ref wanted) => wanted
+            .iter()
+            .filter_map(|name| {
+                columns
+                    .iter()
+                    .position(|c| c == name)
+                    .map(|i| (name.clone(), i))
+            })
+            .collect(),
+        None => columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (c, i))
+            .collect(),
+    };
+
+    let limit = match shape.format {
+        ResponseFormat::First | ResponseFormat::Scalar => 1,
+        _ => shape.limit.unwrap_or(usize::MAX),
+    };
+    let rows: Vec<Vec<serde_json::Value>> = rows
+        .into_iter()
+        .take(limit)
+        .map(|row| {
+            selected
+                .iter()
+                .map(|(_, i)| row.get(*i).cloned().map(value_to_json).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    let to_object = |row: Vec<serde_json::Value>| -> serde_json::Value {
+        serde_json::Value::Object(
+            selected
+                .iter()
+                .map(|(name, _)| name.clone())
+                .zip(row)
+                .collect(),
+        )
+    };
+
+    match shape.format {
+        ResponseFormat::Rows => serde_json::Value::Array(rows.into_iter().map(to_object).collect()),
+        ResponseFormat::Columnar => {
+            let mut object = serde_json::Map::new();
+            for (index, (name, _)) in selected.iter().enumerate() {
+                let values = rows.iter().map(|row| row[index].clone()).collect();
+                object.insert(name.clone(), serde_json::Value::Array(values));
+            }
+            serde_json::Value::Object(object)
+        }
+        ResponseFormat::First => rows
+            .into_iter()
+            .next()
+            .map(to_object)
+            .unwrap_or(serde_json::Value::Null),
+        ResponseFormat::Scalar => rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next())
+            .unwrap_or(serde_json::Value::Null),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::DataSet;
+
+    fn sample() -> ExecutionResult {
+        ExecutionResult::DataSet(DataSet {
+            col_names: vec!["name".to_string(), "age".to_string()],
+            rows: vec![
+                vec![Value::String("alice".to_string()), Value::BigInt(30)],
+                vec![Value::String("bob".to_string()), Value::BigInt(25)],
+            ],
+        })
+    }
+
+    #[test]
+    fn test_bind_params_requires_exact_names() {
+        let expected = vec!["name".to_string()];
+        let args = serde_json::json!({"name": "alice"});
+        let bound = bind_params(&expected, args.as_object().cloned().unwrap_or_default())
+            .expect("bind should succeed");
+        assert_eq!(bound.get("name"), Some(&Value::String("alice".to_string())));
+
+        let extra = serde_json::json!({"name": "alice", "nmae": "typo"});
+        assert!(bind_params(&expected, extra.as_object().cloned().unwrap_or_default()).is_err());
+        assert!(bind_params(&expected, serde_json::Map::new()).is_err());
+    }
+
+    #[test]
+    fn test_shape_result_formats() {
+        let rows = shape_result(sample(), &ResponseShape::default());
+        assert_eq!(
+            rows,
+            serde_json::json!([{"name": "alice", "age": 30}, {"name": "bob", "age": 25}])
+        );
+
+        let shape = ResponseShape {
+            format: ResponseFormat::Columnar,
+            columns: Some(vec!["age".to_string()]),
+            limit: Some(1),
+        };
+        assert_eq!(
+            shape_result(sample(), &shape),
+            serde_json::json!({"age": [30]})
+        );
+
+        let shape = ResponseShape {
+            format: ResponseFormat::Scalar,
+            columns: Some(vec!["age".to_string()]),
+            limit: None,
+        };
+        assert_eq!(shape_result(sample(), &shape), serde_json::json!(30));
+    }
+}
@@ -1,4 +1,5 @@
 //! Web Module Services
 
+pub mod endpoint_service;
 pub mod metadata_service;
 pub mod schema_service;
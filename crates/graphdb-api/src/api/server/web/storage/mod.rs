@@ -3,10 +3,12 @@
 //! Provides SQLite-based storage for:
 //! - Query history
 //! - Query favorites
+//! - Published query endpoints
 
 use async_trait::async_trait;
 
 use crate::api::server::web::error::WebResult;
+use crate::api::server::web::models::endpoint::EndpointItem;
 use crate::api::server::web::models::metadata::{FavoriteItem, HistoryItem};
 
 mod sqlite;
@@ -55,4 +57,20 @@ pub trait MetadataStorage: Send + Sync {
 
     /// Delete all favorites for a session
     async fn delete_all_favorites(&self, session_id: &str) -> WebResult<()>;
+
+    /// Add a new endpoint version
+    ///
+    /// The version is allocated by the insert itself, one past the latest version
+    /// of the name, so concurrent publishes never reuse a number; `item.version`
+    /// is ignored. Returns the allocated version.
+    async fn add_endpoint(&self, item: &EndpointItem) -> WebResult<i64>;
+
+    /// Get an endpoint version, or its latest version when `version` is `None`
+    async fn get_endpoint(&self, name: &str, version: Option<i64>) -> WebResult<EndpointItem>;
+
+    /// List the latest version of every endpoint
+    async fn list_endpoints(&self) -> WebResult<Vec<EndpointItem>>;
+
+    /// Delete all versions of an endpoint
+    async fn delete_endpoint(&self, name: &str) -> WebResult<()>;
 }
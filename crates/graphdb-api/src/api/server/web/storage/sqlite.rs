@@ -4,6 +4,7 @@ use chrono::Utc;
 use sqlx::{sqlite::SqlitePoolOptions, Pool, Row, Sqlite};
 
 use crate::api::server::web::error::{WebError, WebResult};
+use crate::api::server::web::models::endpoint::EndpointItem;
 use crate::api::server::web::models::metadata::{FavoriteItem, HistoryItem};
 
 use super::MetadataStorage;
@@ -66,6 +67,28 @@ impl SqliteStorage {
         .await
         .map_err(|e| WebError::Storage(format!("Failed to create favorites table: {}", e)))?;
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS query_endpoints (
+                name TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                query TEXT NOT NULL,
+                description TEXT,
+                space TEXT,
+                params TEXT NOT NULL,
+                required_role TEXT,
+                response TEXT NOT NULL,
+                published_by TEXT NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                -- Also keeps a version from being published twice
+                PRIMARY KEY (name, version)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| WebError::Storage(format!("Failed to create endpoints table: {}", e)))?;
+
         // Create indexes
         sqlx::query(
             "CREATE INDEX IF NOT EXISTS idx_history_session ON query_history(session_id, executed_at DESC)"
@@ -278,6 +301,92 @@ impl MetadataStorage for SqliteStorage {
 
         Ok(())
     }
+
+    async fn add_endpoint(&self, item: &EndpointItem) -> WebResult<i64> {
+        let params = serde_json::to_string(&item.params)
+            .map_err(|e| WebError::Internal(format!("Failed to encode params: {}", e)))?;
+        let response = serde_json::to_string(&item.response)
+            .map_err(|e| WebError::Internal(format!("Failed to encode response shape: {}", e)))?;
+
+        // A single statement runs under SQLite's write lock, so reading the latest
+        // version and inserting the next one cannot interleave with another publish
+        let version: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO query_endpoints (name, version, query, description, space, params, required_role, response, published_by, created_at)
+            SELECT ?1, COALESCE(MAX(version), 0) + 1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9
+            FROM query_endpoints WHERE name = ?1
+            RETURNING version
+            "#,
+        )
+        .bind(&item.name)
+        .bind(&item.query)
+        .bind(&item.description)
+        .bind(&item.space)
+        .bind(params)
+        .bind(&item.required_role)
+        .bind(response)
+        .bind(&item.published_by)
+        .bind(item.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| WebError::Storage(format!("Failed to add endpoint: {}", e)))?;
+
+        Ok(version)
+    }
+
+    async fn get_endpoint(&self, name: &str, version: Option<i64>) -> WebResult<EndpointItem> {
+        let item: EndpointItem = sqlx::query_as(
+            r#"
+            SELECT name, version, query, description, space, params, required_role, response, published_by, created_at
+            FROM query_endpoints
+            WHERE name = ?1 AND (?2 IS NULL OR version = ?2)
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(name)
+        .bind(version)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                WebError::NotFound(format!("Endpoint '{}' not found", name))
+            }
+            _ => WebError::Storage(format!("Failed to get endpoint: {}", e)),
+        })?;
+
+        Ok(item)
+    }
+
+    async fn list_endpoints(&self) -> WebResult<Vec<EndpointItem>> {
+        let items: Vec<EndpointItem> = sqlx::query_as(
+            r#"
+            SELECT e.name, e.version, e.query, e.description, e.space, e.params, e.required_role, e.response, e.published_by, e.created_at
+            FROM query_endpoints e
+            WHERE e.version = (SELECT MAX(version) FROM query_endpoints WHERE name = e.name)
+            ORDER BY e.name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| WebError::Storage(format!("Failed to list endpoints: {}", e)))?;
+
+        Ok(items)
+    }
+
+    async fn delete_endpoint(&self, name: &str) -> WebResult<()> {
+        let result = sqlx::query("DELETE FROM query_endpoints WHERE name = ?1")
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| WebError::Storage(format!("Failed to delete endpoint: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(WebError::NotFound(format!("Endpoint '{}' not found", name)));
+        }
+
+        Ok(())
+    }
 }
 
 // SQLx row mapping for HistoryItem
@@ -309,3 +418,28 @@ impl sqlx::FromRow<'_, sqlx::sqlite::SqliteRow> for FavoriteItem {
         })
     }
 }
+
+// SQLx row mapping for EndpointItem; params and response shape are stored as JSON text
+impl sqlx::FromRow<'_, sqlx::sqlite::SqliteRow> for EndpointItem {
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        let decode = |index: &str, e: serde_json::Error| sqlx::Error::ColumnDecode {
+            index: index.to_string(),
+            source: Box::new(e),
+        };
+        let params: String = row.try_get("params")?;
+        let response: String = row.try_get("response")?;
+
+        Ok(EndpointItem {
+            name: row.try_get("name")?,
+            version: row.try_get("version")?,
+            query: row.try_get("query")?,
+            description: row.try_get("description")?,
+            space: row.try_get("space")?,
+            params: serde_json::from_str(&params).map_err(|e| decode("params", e))?,
+            required_role: row.try_get("required_role")?,
+            response: serde_json::from_str(&response).map_err(|e| decode("response", e))?,
+            published_by: row.try_get("published_by")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
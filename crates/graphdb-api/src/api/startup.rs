@@ -112,10 +112,15 @@ pub async fn start_service_with_config(config: Config) -> DBResult<()> {
                 };
 
                 let batch_config = crate::sync::batch::BatchConfig::from(sync_config.clone());
-                let sync_coordinator = Arc::new(crate::sync::coordinator::SyncCoordinator::new(
-                    manager.clone(),
-                    batch_config,
+                // Failed index updates are journaled under the data directory and replayed here.
+                let dead_letter_queue = Arc::new(crate::sync::DeadLetterQueue::open_in_data_dir(
+                    config.storage_path(),
+                    crate::sync::DeadLetterQueueConfig::default(),
                 ));
+                let sync_coordinator = Arc::new(
+                    crate::sync::coordinator::SyncCoordinator::new(manager.clone(), batch_config)
+                        .with_dead_letter_queue(dead_letter_queue),
+                );
 
                 let sync_manager = SyncManager::with_sync_config(sync_coordinator, sync_config);
 
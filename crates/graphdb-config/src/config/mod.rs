@@ -58,7 +58,7 @@ pub use common::storage::{
 pub use common::transaction::TransactionConfig;
 
 #[cfg(feature = "server")]
-pub use server::auth::AuthConfig;
+pub use server::auth::{AuthConfig, JwtConfig};
 #[cfg(feature = "server")]
 pub use server::bootstrap::BootstrapConfig;
 #[cfg(feature = "server")]
@@ -68,7 +68,9 @@ pub use server::grpc::GrpcConfig;
 #[cfg(feature = "server")]
 pub use server::http::HttpServerConfig;
 #[cfg(feature = "server")]
-pub use server::security::{AuditConfig, PasswordPolicyConfig, SecurityConfig, SslConfig};
+pub use server::security::{
+    AuditConfig, PasswordPolicyConfig, RateLimitConfig, SecurityConfig, SslConfig,
+};
 
 #[cfg(feature = "qdrant")]
 use vector_client::VectorClientConfig;
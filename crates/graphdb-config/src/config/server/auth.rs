@@ -1,6 +1,7 @@
 //! Authentication configuration
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Authorization configuration
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -17,6 +18,9 @@ pub struct AuthConfig {
     pub default_username: String,
     /// Default password (used only on first start or in single-user mode)
     pub default_password: String,
+    /// JWT bearer token authentication
+    #[serde(default)]
+    pub jwt: JwtConfig,
 }
 
 impl Default for AuthConfig {
@@ -28,6 +32,7 @@ impl Default for AuthConfig {
             force_change_default_password: true,
             default_username: "root".to_string(),
             default_password: "root".to_string(),
+            jwt: JwtConfig::default(),
         }
     }
 }
@@ -43,6 +48,72 @@ impl AuthConfig {
             return Err("Default password cannot be empty".to_string());
         }
 
+        self.jwt.validate()
+    }
+}
+
+/// JWT bearer token configuration
+///
+/// Tokens are verified locally against the configured PEM keys and JWKS file;
+/// no identity provider is contacted at runtime.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct JwtConfig {
+    /// Whether to accept JWT bearer tokens
+    pub enabled: bool,
+    /// Accepted `iss` values (empty means any issuer)
+    pub issuers: Vec<String>,
+    /// Accepted `aud` values (empty means the audience is not checked)
+    pub audiences: Vec<String>,
+    /// Accepted signing algorithms, e.g. "RS256", "ES256"
+    pub algorithms: Vec<String>,
+    /// PEM encoded public key files
+    pub pem_keys: Vec<String>,
+    /// JWKS file; its keys are selected by the token's `kid`
+    pub jwks_file: Option<String>,
+    /// Clock skew tolerated for `exp` and `nbf` (seconds)
+    pub leeway_secs: u64,
+    /// Claim holding the username
+    pub username_claim: String,
+    /// Claim holding the roles, a string or an array of strings
+    pub roles_claim: String,
+    /// Maps claim role values to built-in roles (GOD, ADMIN, DBA, USER, GUEST);
+    /// values that already name a built-in role need no entry
+    pub role_mapping: HashMap<String, String>,
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            issuers: Vec::new(),
+            audiences: Vec::new(),
+            algorithms: vec!["RS256".to_string()],
+            pem_keys: Vec::new(),
+            jwks_file: None,
+            leeway_secs: 60,
+            username_claim: "sub".to_string(),
+            roles_claim: "roles".to_string(),
+            role_mapping: HashMap::new(),
+        }
+    }
+}
+
+impl JwtConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if self.pem_keys.is_empty() && self.jwks_file.is_none() {
+            return Err("JWT authentication requires pem_keys or jwks_file".to_string());
+        }
+        if self.algorithms.is_empty() {
+            return Err("JWT authentication requires at least one algorithm".to_string());
+        }
+        if self.username_claim.is_empty() {
+            return Err("JWT username_claim cannot be empty".to_string());
+        }
         Ok(())
     }
 }
@@ -73,4 +144,17 @@ mod tests {
         };
         assert!(invalid_config.validate().is_err());
     }
+
+    #[test]
+    fn test_jwt_config_validate() {
+        let mut config = AuthConfig::default();
+        assert!(!config.jwt.enabled);
+        assert!(config.validate().is_ok());
+
+        config.jwt.enabled = true;
+        assert!(config.validate().is_err());
+
+        config.jwt.jwks_file = Some("conf/jwks.json".to_string());
+        assert!(config.validate().is_ok());
+    }
 }
@@ -98,6 +98,7 @@ impl AuditConfig {
 
 /// Password policy configuration
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct PasswordPolicyConfig {
     /// Minimum password length
     pub min_length: usize,
@@ -156,6 +157,56 @@ impl PasswordPolicyConfig {
     pub fn has_history(&self) -> bool {
         self.history_size > 0
     }
+
+    /// Convert to the policy enforced by the user store
+    pub fn to_password_policy(&self) -> crate::core::types::PasswordPolicy {
+        crate::core::types::PasswordPolicy {
+            min_length: self.min_length,
+            require_uppercase: self.require_uppercase,
+            require_lowercase: self.require_lowercase,
+            require_digit: self.require_digit,
+            require_special: self.require_special,
+            max_age_days: self.max_age_days,
+            history_size: self.history_size,
+        }
+    }
+}
+
+/// Per-client-IP request rate limit, shared by the HTTP and gRPC listeners
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Enable the rate limiter
+    pub enabled: bool,
+    /// Sustained requests per second allowed for each client IP
+    pub requests_per_second: f64,
+    /// Maximum burst size (token bucket capacity)
+    pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            requests_per_second: 100.0,
+            burst: 200,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.enabled {
+            if !self.requests_per_second.is_finite() || self.requests_per_second <= 0.0 {
+                return Err("Rate limit requests_per_second must be greater than 0".to_string());
+            }
+            if self.burst == 0 {
+                return Err("Rate limit burst must be greater than 0".to_string());
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Security configuration aggregator
@@ -172,6 +223,10 @@ pub struct SecurityConfig {
     /// Password policy configuration
     #[serde(default)]
     pub password_policy: PasswordPolicyConfig,
+
+    /// Per-client-IP rate limit configuration
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
 }
 
 impl SecurityConfig {
@@ -180,6 +235,7 @@ impl SecurityConfig {
         self.ssl.validate()?;
         self.audit.validate()?;
         self.password_policy.validate()?;
+        self.rate_limit.validate()?;
         Ok(())
     }
 
@@ -247,6 +303,27 @@ mod tests {
         assert!(!config.has_history());
     }
 
+    #[test]
+    fn test_rate_limit_config_validate() {
+        let config = RateLimitConfig::default();
+        assert!(!config.enabled);
+        assert!(config.validate().is_ok());
+
+        let config = RateLimitConfig {
+            enabled: true,
+            requests_per_second: 0.0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = RateLimitConfig {
+            enabled: true,
+            burst: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_security_config_default() {
         let config = SecurityConfig::default();
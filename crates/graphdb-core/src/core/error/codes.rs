@@ -44,6 +44,8 @@ pub enum ErrorCode {
     Conflict = 203,
     /// Deadlock detection
     Deadlock = 204,
+    /// A per-user quota or rate limit was exceeded
+    QuotaExceeded = 205,
 
     // Verification error (03xx)
     /// General Verification Error
@@ -99,6 +101,7 @@ impl ErrorCode {
             202 => Some(ErrorCode::ResourceExhausted),
             203 => Some(ErrorCode::Conflict),
             204 => Some(ErrorCode::Deadlock),
+            205 => Some(ErrorCode::QuotaExceeded),
             300 => Some(ErrorCode::ValidationError),
             301 => Some(ErrorCode::TypeError),
             302 => Some(ErrorCode::InvalidInput),
@@ -143,6 +146,7 @@ impl ErrorCode {
             ErrorCode::ResourceExhausted => "resource exhausted",
             ErrorCode::Conflict => "conflict",
             ErrorCode::Deadlock => "deadlock detected",
+            ErrorCode::QuotaExceeded => "quota exceeded",
             ErrorCode::ValidationError => "validation error",
             ErrorCode::TypeError => "type error",
             ErrorCode::InvalidInput => "invalid input",
@@ -183,6 +187,7 @@ impl ErrorCode {
             ErrorCode::Timeout
                 | ErrorCode::Conflict
                 | ErrorCode::Deadlock
+                | ErrorCode::QuotaExceeded
                 | ErrorCode::ResourceExhausted
                 | ErrorCode::ServiceUnavailable
         )
@@ -315,6 +320,7 @@ mod tests {
         assert!(ErrorCode::Timeout.is_retryable());
         assert!(ErrorCode::Conflict.is_retryable());
         assert!(ErrorCode::Deadlock.is_retryable());
+        assert!(ErrorCode::QuotaExceeded.is_retryable());
         assert!(!ErrorCode::SyntaxError.is_retryable());
         assert!(!ErrorCode::PermissionDenied.is_retryable());
     }
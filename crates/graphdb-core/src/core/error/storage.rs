@@ -26,6 +26,7 @@ pub enum StorageErrorKind {
     EdgeNotFound,
     NotSupported,
     Conflict,
+    ConstraintViolation,
     LockTimeout,
     Deadlock,
     IOError,
@@ -64,6 +65,7 @@ impl StorageErrorKind {
             StorageErrorKind::EdgeNotFound => "edge_not_found",
             StorageErrorKind::NotSupported => "not_supported",
             StorageErrorKind::Conflict => "conflict",
+            StorageErrorKind::ConstraintViolation => "constraint_violation",
             StorageErrorKind::LockTimeout => "lock_timeout",
             StorageErrorKind::Deadlock => "deadlock",
             StorageErrorKind::IOError => "io_error",
@@ -181,6 +183,10 @@ impl StorageError {
         Self::new(StorageErrorKind::Conflict, message)
     }
 
+    pub fn constraint_violation(message: impl Into<String>) -> Self {
+        Self::new(StorageErrorKind::ConstraintViolation, message)
+    }
+
     pub fn lock_timeout(message: impl Into<String>) -> Self {
         Self::new(StorageErrorKind::LockTimeout, message)
     }
@@ -370,6 +376,7 @@ impl ToPublicError for StorageError {
             StorageErrorKind::LockTimeout => ErrorCode::Timeout,
             StorageErrorKind::Deadlock => ErrorCode::Deadlock,
             StorageErrorKind::Conflict => ErrorCode::Conflict,
+            StorageErrorKind::ConstraintViolation => ErrorCode::ConstraintViolation,
             StorageErrorKind::NotSupported => ErrorCode::InvalidStatement,
             _ => ErrorCode::InternalError,
         }
@@ -381,6 +388,9 @@ impl ToPublicError for StorageError {
             StorageErrorKind::EdgeNotFound => "Edge does not exist".to_string(),
             StorageErrorKind::NotFound => format!("Resource not found: {}", self.message),
             StorageErrorKind::AlreadyExists => format!("Resource already exists: {}", self.message),
+            StorageErrorKind::ConstraintViolation => {
+                format!("Constraint violated: {}", self.message)
+            }
             _ => "Storage operation failed".to_string(),
         }
     }
@@ -1,4 +1,4 @@
-use crate::core::types::Index;
+use crate::core::types::{Constraint, Index};
 use crate::core::StorageError;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,8 @@ struct IndexSnapshot {
     version: u32,
     tag_indexes: Vec<(u64, String, Index)>,
     edge_indexes: Vec<(u64, String, Index)>,
+    #[serde(default)]
+    constraints: Vec<(u64, String, Constraint)>,
 }
 
 pub trait IndexMetadataManager: Send + Sync + std::fmt::Debug {
@@ -33,11 +35,22 @@ pub trait IndexMetadataManager: Send + Sync + std::fmt::Debug {
     fn list_edge_indexes(&self, space_id: u64) -> Result<Vec<Index>, StorageError>;
     fn drop_edge_indexes_by_type(&self, space_id: u64, edge_type: &str)
         -> Result<(), StorageError>;
+
+    fn create_constraint(
+        &self,
+        space_id: u64,
+        constraint: &Constraint,
+    ) -> Result<bool, StorageError>;
+    fn drop_constraint(&self, space_id: u64, name: &str) -> Result<bool, StorageError>;
+    fn get_constraint(&self, space_id: u64, name: &str)
+        -> Result<Option<Constraint>, StorageError>;
+    fn list_constraints(&self, space_id: u64) -> Result<Vec<Constraint>, StorageError>;
 }
 
 pub struct IndexManager {
     tag_indexes: Arc<RwLock<HashMap<(u64, String), Index>>>,
     edge_indexes: Arc<RwLock<HashMap<(u64, String), Index>>>,
+    constraints: Arc<RwLock<HashMap<(u64, String), Constraint>>>,
 }
 
 impl std::fmt::Debug for IndexManager {
@@ -45,6 +58,7 @@ impl std::fmt::Debug for IndexManager {
         f.debug_struct("IndexManager")
             .field("tag_indexes_count", &self.tag_indexes.read().len())
             .field("edge_indexes_count", &self.edge_indexes.read().len())
+            .field("constraints_count", &self.constraints.read().len())
             .finish()
     }
 }
@@ -54,6 +68,7 @@ impl IndexManager {
         Self {
             tag_indexes: Arc::new(RwLock::new(HashMap::new())),
             edge_indexes: Arc::new(RwLock::new(HashMap::new())),
+            constraints: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -79,10 +94,18 @@ impl IndexManager {
             .map(|((space_id, name), index)| (*space_id, name.clone(), index.clone()))
             .collect();
 
+        let constraints: Vec<(u64, String, Constraint)> = self
+            .constraints
+            .read()
+            .iter()
+            .map(|((space_id, name), constraint)| (*space_id, name.clone(), constraint.clone()))
+            .collect();
+
         let snapshot = IndexSnapshot {
             version: INDEX_FORMAT_VERSION,
             tag_indexes,
             edge_indexes,
+            constraints,
         };
 
         let json = serde_json::to_string_pretty(&snapshot)
@@ -120,6 +143,7 @@ impl IndexManager {
 
         self.tag_indexes.write().clear();
         self.edge_indexes.write().clear();
+        self.constraints.write().clear();
 
         for (space_id, name, index) in snapshot.tag_indexes {
             self.tag_indexes.write().insert((space_id, name), index);
@@ -129,6 +153,12 @@ impl IndexManager {
             self.edge_indexes.write().insert((space_id, name), index);
         }
 
+        for (space_id, name, constraint) in snapshot.constraints {
+            self.constraints
+                .write()
+                .insert((space_id, name), constraint);
+        }
+
         Ok(())
     }
 }
@@ -179,6 +209,9 @@ impl IndexMetadataManager for IndexManager {
     fn drop_tag_indexes_by_tag(&self, space_id: u64, tag_name: &str) -> Result<(), StorageError> {
         let mut indexes = self.tag_indexes.write();
         indexes.retain(|_, index| !(index.space_id == space_id && index.schema_name == tag_name));
+        self.constraints.write().retain(|(sid, _), constraint| {
+            !(*sid == space_id && constraint.schema_name == tag_name)
+        });
         Ok(())
     }
 
@@ -227,4 +260,43 @@ impl IndexMetadataManager for IndexManager {
         indexes.retain(|_, index| !(index.space_id == space_id && index.schema_name == edge_type));
         Ok(())
     }
+
+    fn create_constraint(
+        &self,
+        space_id: u64,
+        constraint: &Constraint,
+    ) -> Result<bool, StorageError> {
+        let mut constraints = self.constraints.write();
+        let key = (space_id, constraint.name.clone());
+        if constraints.contains_key(&key) {
+            return Ok(false);
+        }
+        let mut constraint_with_space_id = constraint.clone();
+        constraint_with_space_id.space_id = space_id;
+        constraints.insert(key, constraint_with_space_id);
+        Ok(true)
+    }
+
+    fn drop_constraint(&self, space_id: u64, name: &str) -> Result<bool, StorageError> {
+        let mut constraints = self.constraints.write();
+        Ok(constraints.remove(&(space_id, name.to_string())).is_some())
+    }
+
+    fn get_constraint(
+        &self,
+        space_id: u64,
+        name: &str,
+    ) -> Result<Option<Constraint>, StorageError> {
+        let constraints = self.constraints.read();
+        Ok(constraints.get(&(space_id, name.to_string())).cloned())
+    }
+
+    fn list_constraints(&self, space_id: u64) -> Result<Vec<Constraint>, StorageError> {
+        let constraints = self.constraints.read();
+        Ok(constraints
+            .iter()
+            .filter(|((sid, _), _)| *sid == space_id)
+            .map(|(_, constraint)| constraint.clone())
+            .collect())
+    }
 }
@@ -1,5 +1,7 @@
-use crate::core::types::{EdgeTypeInfo, PropertyDef, SpaceInfo, TagInfo};
-use crate::core::StorageError;
+use crate::core::types::{
+    EdgeTypeInfo, MaterializedViewData, MaterializedViewInfo, PropertyDef, SpaceInfo, TagInfo,
+};
+use crate::core::{DataType, StorageError};
 use dashmap::DashMap;
 use parking_lot::RwLock;
 use std::collections::HashMap;
@@ -18,6 +20,8 @@ struct SchemaSnapshot {
     space_id_counter: u64,
     tag_id_counters: Vec<(u64, u32)>,
     edge_type_id_counters: Vec<(u64, u32)>,
+    #[serde(default)]
+    materialized_views: Vec<MaterializedViewInfo>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +39,13 @@ struct EdgeTypeData {
     info: EdgeTypeInfo,
 }
 
+/// Materialized view definition and its rows; `None` rows mean the view is stale
+#[derive(Debug, Clone)]
+struct MaterializedViewEntry {
+    info: MaterializedViewInfo,
+    data: Option<Arc<MaterializedViewData>>,
+}
+
 pub struct SchemaManager {
     spaces: Arc<RwLock<HashMap<u64, SpaceData>>>,
     space_name_index: Arc<RwLock<HashMap<String, u64>>>,
@@ -43,6 +54,7 @@ pub struct SchemaManager {
     space_id_counter: Arc<AtomicU64>,
     tag_id_counter: Arc<DashMap<u64, AtomicU32>>,
     edge_type_id_counter: Arc<DashMap<u64, AtomicU32>>,
+    materialized_views: Arc<RwLock<HashMap<(u64, String), MaterializedViewEntry>>>,
 }
 
 impl Clone for SchemaManager {
@@ -55,6 +67,7 @@ impl Clone for SchemaManager {
             space_id_counter: self.space_id_counter.clone(),
             tag_id_counter: self.tag_id_counter.clone(),
             edge_type_id_counter: self.edge_type_id_counter.clone(),
+            materialized_views: self.materialized_views.clone(),
         }
     }
 }
@@ -77,6 +90,7 @@ impl SchemaManager {
             space_id_counter: Arc::new(AtomicU64::new(0)),
             tag_id_counter: Arc::new(DashMap::new()),
             edge_type_id_counter: Arc::new(DashMap::new()),
+            materialized_views: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -174,6 +188,9 @@ impl SchemaManager {
             let mut edge_types = self.edge_types.write();
             edge_types.retain(|(sid, _), _| *sid != space_id);
 
+            let mut views = self.materialized_views.write();
+            views.retain(|(sid, _), _| *sid != space_id);
+
             Ok(true)
         } else {
             Ok(false)
@@ -193,6 +210,12 @@ impl SchemaManager {
         let mut edge_types = self.edge_types.write();
         edge_types.retain(|(sid, _), _| *sid != space_id);
 
+        self.materialized_views
+            .write()
+            .iter_mut()
+            .filter(|((sid, _), _)| *sid == space_id)
+            .for_each(|(_, entry)| entry.data = None);
+
         Ok(true)
     }
 
@@ -635,6 +658,34 @@ impl SchemaManager {
         Ok(false)
     }
 
+    pub fn change_tag_property_type(
+        &self,
+        space_name: &str,
+        tag_name: &str,
+        prop_name: &str,
+        data_type: DataType,
+    ) -> Result<bool, StorageError> {
+        let space_info = self.get_space(space_name)?.ok_or_else(|| {
+            StorageError::db_error(format!("Space \"{}\" does not exist", space_name))
+        })?;
+
+        let mut tags = self.tags.write();
+        let tag_key = tags
+            .iter()
+            .find(|((sid, _), data)| *sid == space_info.space_id && data.info.tag_name == tag_name)
+            .map(|(k, _)| *k);
+
+        if let Some(key) = tag_key {
+            if let Some(data) = tags.get_mut(&key) {
+                if let Some(prop) = data.info.properties.iter_mut().find(|p| p.name == prop_name) {
+                    prop.data_type = data_type;
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
     pub fn alter_edge_type(
         &self,
         space_name: &str,
@@ -670,6 +721,85 @@ impl SchemaManager {
         Ok(false)
     }
 
+    /// Register a materialized view; returns `false` when the name is taken
+    pub fn create_materialized_view(
+        &self,
+        info: MaterializedViewInfo,
+    ) -> Result<bool, StorageError> {
+        if self.get_space_by_id(info.space_id)?.is_none() {
+            return Err(StorageError::db_error(format!(
+                "Space {} does not exist",
+                info.space_id
+            )));
+        }
+
+        let mut views = self.materialized_views.write();
+        let key = (info.space_id, info.name.clone());
+        if views.contains_key(&key) {
+            return Ok(false);
+        }
+        views.insert(key, MaterializedViewEntry { info, data: None });
+        Ok(true)
+    }
+
+    pub fn drop_materialized_view(&self, space_id: u64, name: &str) -> bool {
+        self.materialized_views
+            .write()
+            .remove(&(space_id, name.to_string()))
+            .is_some()
+    }
+
+    pub fn get_materialized_view(&self, space_id: u64, name: &str) -> Option<MaterializedViewInfo> {
+        self.materialized_views
+            .read()
+            .get(&(space_id, name.to_string()))
+            .map(|entry| entry.info.clone())
+    }
+
+    pub fn list_materialized_views(&self, space_id: u64) -> Vec<MaterializedViewInfo> {
+        let mut views: Vec<MaterializedViewInfo> = self
+            .materialized_views
+            .read()
+            .iter()
+            .filter(|((sid, _), _)| *sid == space_id)
+            .map(|(_, entry)| entry.info.clone())
+            .collect();
+        views.sort_by(|a, b| a.name.cmp(&b.name));
+        views
+    }
+
+    /// Current rows of a view; `None` when the view is stale or does not exist
+    pub fn materialized_view_data(
+        &self,
+        space_id: u64,
+        name: &str,
+    ) -> Option<Arc<MaterializedViewData>> {
+        self.materialized_views
+            .read()
+            .get(&(space_id, name.to_string()))
+            .and_then(|entry| entry.data.clone())
+    }
+
+    /// Replace the rows of a view; pass `None` to mark it stale
+    pub fn set_materialized_view_data(
+        &self,
+        space_id: u64,
+        name: &str,
+        data: Option<Arc<MaterializedViewData>>,
+    ) -> bool {
+        match self
+            .materialized_views
+            .write()
+            .get_mut(&(space_id, name.to_string()))
+        {
+            Some(entry) => {
+                entry.data = data;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn save_schema(&self, path: &Path) -> Result<(), StorageError> {
         use std::fs::{self, File};
         use std::io::Write;
@@ -715,6 +845,13 @@ impl SchemaManager {
             .map(|entry| (*entry.key(), entry.value().load(Ordering::SeqCst)))
             .collect();
 
+        let materialized_views: Vec<MaterializedViewInfo> = self
+            .materialized_views
+            .read()
+            .values()
+            .map(|entry| entry.info.clone())
+            .collect();
+
         let snapshot = SchemaSnapshot {
             version: SCHEMA_FORMAT_VERSION,
             spaces,
@@ -723,6 +860,7 @@ impl SchemaManager {
             space_id_counter,
             tag_id_counters,
             edge_type_id_counters,
+            materialized_views,
         };
 
         let json = serde_json::to_string_pretty(&snapshot)
@@ -766,6 +904,7 @@ impl SchemaManager {
         self.edge_types.write().clear();
         self.tag_id_counter.clear();
         self.edge_type_id_counter.clear();
+        self.materialized_views.write().clear();
 
         let max_tag_counter = snapshot
             .tag_id_counters
@@ -835,6 +974,17 @@ impl SchemaManager {
         self.edge_type_id_counter
             .insert(0, AtomicU32::new(max_edge_type_counter));
 
+        // Rows are not persisted; views are refreshed on first use
+        for view in snapshot.materialized_views {
+            self.materialized_views.write().insert(
+                (view.space_id, view.name.clone()),
+                MaterializedViewEntry {
+                    info: view,
+                    data: None,
+                },
+            );
+        }
+
         Ok(())
     }
 }
@@ -906,6 +1056,52 @@ mod tests {
             second_edge_id
         );
     }
+
+    #[test]
+    fn materialized_views_follow_space_lifecycle() {
+        let manager = SchemaManager::new();
+        let mut space = SpaceInfo::new("social".to_string());
+        manager.create_space(&mut space).expect("create space");
+
+        let info = MaterializedViewInfo::new(
+            "adults".to_string(),
+            space.space_id,
+            "MATCH (p:person) WHERE p.age >= 18 RETURN p.name".to_string(),
+        );
+        assert!(manager
+            .create_materialized_view(info.clone())
+            .expect("create view"));
+        assert!(!manager
+            .create_materialized_view(info)
+            .expect("duplicate view"));
+        assert!(manager
+            .materialized_view_data(space.space_id, "adults")
+            .is_none());
+
+        let data = Arc::new(MaterializedViewData::new(vec![vec![
+            crate::core::Value::from("alice"),
+        ]]));
+        assert!(manager.set_materialized_view_data(space.space_id, "adults", Some(data)));
+        assert_eq!(
+            manager
+                .materialized_view_data(space.space_id, "adults")
+                .expect("fresh view")
+                .rows
+                .len(),
+            1
+        );
+
+        manager.clear_space("social").expect("clear space");
+        assert!(manager
+            .materialized_view_data(space.space_id, "adults")
+            .is_none());
+        assert_eq!(manager.list_materialized_views(space.space_id).len(), 1);
+
+        manager.drop_space("social").expect("drop space");
+        assert!(manager
+            .get_materialized_view(space.space_id, "adults")
+            .is_none());
+    }
 }
 
 impl Default for SchemaManager {
@@ -46,7 +46,10 @@ pub use types::YieldColumn;
 pub use type_system::TypeUtils;
 
 // Permission type
-pub use permission::{Permission, RoleType};
+pub use permission::{
+    CustomRole, EffectivePrivileges, Permission, PrivilegeAction, PrivilegeGrant, PrivilegeObject,
+    PropertyScope, RoleType,
+};
 
 // Statistical type
 pub use stats::{
@@ -1,9 +1,17 @@
 //! Permission Type Definition
 //!
 //! Provide core permission model and role type definitions
+//!
+//! Besides the five built-in [`RoleType`]s granted per space, users can be given
+//! custom roles ([`CustomRole`]) that grant READ or WRITE on individual tags and
+//! edge types, optionally narrowed to a list of properties.
+
+use crate::core::{Edge, Value, Vertex};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// Permission Type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Permission {
     Read,
     Write,
@@ -114,3 +122,491 @@ impl std::str::FromStr for RoleType {
         }
     }
 }
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Permission::Read => write!(f, "READ"),
+            Permission::Write => write!(f, "WRITE"),
+            Permission::Delete => write!(f, "DELETE"),
+            Permission::Schema => write!(f, "SCHEMA"),
+            Permission::Admin => write!(f, "ADMIN"),
+        }
+    }
+}
+
+impl std::str::FromStr for Permission {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "READ" => Ok(Permission::Read),
+            "WRITE" => Ok(Permission::Write),
+            "DELETE" => Ok(Permission::Delete),
+            "SCHEMA" => Ok(Permission::Schema),
+            "ADMIN" => Ok(Permission::Admin),
+            _ => Err(format!("Unknown permission: {}", s)),
+        }
+    }
+}
+
+/// Action granted by a fine-grained privilege
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrivilegeAction {
+    Read,
+    Write,
+}
+
+impl std::fmt::Display for PrivilegeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrivilegeAction::Read => write!(f, "READ"),
+            PrivilegeAction::Write => write!(f, "WRITE"),
+        }
+    }
+}
+
+/// Schema object a fine-grained privilege applies to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrivilegeObject {
+    Tag(String),
+    Edge(String),
+}
+
+impl PrivilegeObject {
+    pub fn name(&self) -> &str {
+        match self {
+            PrivilegeObject::Tag(name) | PrivilegeObject::Edge(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for PrivilegeObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrivilegeObject::Tag(name) => write!(f, "TAG {}", name),
+            PrivilegeObject::Edge(name) => write!(f, "EDGE {}", name),
+        }
+    }
+}
+
+/// A single `GRANT READ|WRITE ON TAG t[(props)] / EDGE e` entry of a custom role
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrivilegeGrant {
+    pub action: PrivilegeAction,
+    pub object: PrivilegeObject,
+    /// Properties covered by the grant; `None` covers every property
+    pub properties: Option<Vec<String>>,
+}
+
+impl PrivilegeGrant {
+    pub fn new(action: PrivilegeAction, object: PrivilegeObject) -> Self {
+        Self {
+            action,
+            object,
+            properties: None,
+        }
+    }
+
+    pub fn with_properties(mut self, properties: Vec<String>) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+}
+
+impl std::fmt::Display for PrivilegeGrant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ON {}", self.action, self.object)?;
+        if let Some(ref properties) = self.properties {
+            write!(f, "({})", properties.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// User-defined role holding fine-grained grants
+///
+/// A role also receives every grant of the roles it inherits from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomRole {
+    pub name: String,
+    #[serde(default)]
+    pub parents: Vec<String>,
+    #[serde(default)]
+    pub grants: Vec<PrivilegeGrant>,
+}
+
+impl CustomRole {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            parents: Vec::new(),
+            grants: Vec::new(),
+        }
+    }
+
+    pub fn with_parents(mut self, parents: Vec<String>) -> Self {
+        self.parents = parents;
+        self
+    }
+
+    /// Add a grant, merging it with an existing grant on the same object and action
+    pub fn add_grant(&mut self, grant: PrivilegeGrant) {
+        let existing = self
+            .grants
+            .iter_mut()
+            .find(|g| g.action == grant.action && g.object == grant.object);
+        match existing {
+            Some(existing) => match (&mut existing.properties, grant.properties) {
+                (Some(current), Some(added)) => {
+                    for property in added {
+                        if !current.contains(&property) {
+                            current.push(property);
+                        }
+                    }
+                }
+                (current, None) => *current = None,
+                _ => {}
+            },
+            None => self.grants.push(grant),
+        }
+    }
+}
+
+/// Properties of one object a user may access
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyScope {
+    All,
+    Only(HashSet<String>),
+}
+
+impl PropertyScope {
+    pub fn contains(&self, property: &str) -> bool {
+        match self {
+            PropertyScope::All => true,
+            PropertyScope::Only(properties) => properties.contains(property),
+        }
+    }
+
+    fn merge(&mut self, properties: Option<&Vec<String>>) {
+        match (self, properties) {
+            (PropertyScope::All, _) => {}
+            (scope, None) => *scope = PropertyScope::All,
+            (PropertyScope::Only(current), Some(added)) => {
+                current.extend(added.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Fine-grained privileges of a user, with role inheritance resolved
+///
+/// Only users holding at least one custom role are subject to these checks; for
+/// them, any tag or edge type without a matching grant is inaccessible.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectivePrivileges {
+    roles: Vec<String>,
+    scopes: HashMap<(PrivilegeAction, PrivilegeObject), PropertyScope>,
+}
+
+impl EffectivePrivileges {
+    /// Resolve the grants reachable from `user_roles`, following parent roles
+    ///
+    /// Unknown role names are skipped and inheritance cycles are visited once.
+    pub fn resolve(user_roles: &[String], roles: &HashMap<String, CustomRole>) -> Self {
+        let mut resolved = Self::default();
+        let mut visited = HashSet::new();
+        let mut pending: Vec<&str> = user_roles.iter().map(|r| r.as_str()).collect();
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.to_string()) {
+                continue;
+            }
+            let Some(role) = roles.get(name) else {
+                continue;
+            };
+            resolved.roles.push(role.name.clone());
+            for grant in &role.grants {
+                resolved
+                    .scopes
+                    .entry((grant.action, grant.object.clone()))
+                    .or_insert_with(|| PropertyScope::Only(HashSet::new()))
+                    .merge(grant.properties.as_ref());
+            }
+            pending.extend(role.parents.iter().map(|p| p.as_str()));
+        }
+
+        resolved.roles.sort();
+        resolved
+    }
+
+    /// Names of the roles that contributed grants, including inherited ones
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /// Property scope granted for `action` on `object`, if any
+    pub fn scope(
+        &self,
+        action: PrivilegeAction,
+        object: &PrivilegeObject,
+    ) -> Option<&PropertyScope> {
+        self.scopes.get(&(action, object.clone()))
+    }
+
+    /// Whether `action` is granted on `object` for at least one property
+    pub fn allows(&self, action: PrivilegeAction, object: &PrivilegeObject) -> bool {
+        self.scope(action, object).is_some()
+    }
+
+    /// Whether `action` is granted on `property` of `object`
+    pub fn allows_property(
+        &self,
+        action: PrivilegeAction,
+        object: &PrivilegeObject,
+        property: &str,
+    ) -> bool {
+        self.scope(action, object)
+            .map(|scope| scope.contains(property))
+            .unwrap_or(false)
+    }
+
+    /// Whether `action` is granted on every property of `object`
+    pub fn allows_all_properties(&self, action: PrivilegeAction, object: &PrivilegeObject) -> bool {
+        matches!(self.scope(action, object), Some(PropertyScope::All))
+    }
+
+    /// Resolved grants in a stable order, one per action and object
+    pub fn grants(&self) -> Vec<PrivilegeGrant> {
+        let mut grants: Vec<PrivilegeGrant> = self
+            .scopes
+            .iter()
+            .map(|((action, object), scope)| {
+                let grant = PrivilegeGrant::new(*action, object.clone());
+                match scope {
+                    PropertyScope::All => grant,
+                    PropertyScope::Only(properties) => {
+                        let mut properties: Vec<String> = properties.iter().cloned().collect();
+                        properties.sort();
+                        grant.with_properties(properties)
+                    }
+                }
+            })
+            .collect();
+        grants.sort_by_key(|g| g.to_string());
+        grants
+    }
+
+    /// Strip the tags, edge properties and vertex properties the user may not read
+    ///
+    /// Descends into paths, lists, maps, sets and nested datasets.
+    pub fn mask_value(&self, value: &mut Value) {
+        match value {
+            Value::Vertex(vertex) => self.mask_vertex(vertex),
+            Value::Edge(edge) => self.mask_edge(edge),
+            Value::Path(path) => {
+                self.mask_vertex(&mut path.src);
+                for step in &mut path.steps {
+                    self.mask_vertex(&mut step.dst);
+                    self.mask_edge(&mut step.edge);
+                }
+            }
+            Value::List(list) => list.values.iter_mut().for_each(|v| self.mask_value(v)),
+            Value::Map(map) => map.values_mut().for_each(|v| self.mask_value(v)),
+            Value::Set(set) => {
+                **set = std::mem::take(&mut **set)
+                    .into_iter()
+                    .map(|mut v| {
+                        self.mask_value(&mut v);
+                        v
+                    })
+                    .collect();
+            }
+            Value::DataSet(dataset) => dataset
+                .rows
+                .iter_mut()
+                .flatten()
+                .for_each(|v| self.mask_value(v)),
+            _ => {}
+        }
+    }
+
+    fn mask_vertex(&self, vertex: &mut Vertex) {
+        vertex.tags.retain(|tag| {
+            self.allows(
+                PrivilegeAction::Read,
+                &PrivilegeObject::Tag(tag.name.clone()),
+            )
+        });
+        for tag in &mut vertex.tags {
+            let object = PrivilegeObject::Tag(tag.name.clone());
+            tag.properties
+                .retain(|name, _| self.allows_property(PrivilegeAction::Read, &object, name));
+        }
+
+        let readable: Vec<PrivilegeObject> = vertex
+            .tags
+            .iter()
+            .map(|tag| PrivilegeObject::Tag(tag.name.clone()))
+            .collect();
+        vertex.properties.retain(|name, _| {
+            readable
+                .iter()
+                .any(|object| self.allows_property(PrivilegeAction::Read, object, name))
+        });
+    }
+
+    fn mask_edge(&self, edge: &mut Edge) {
+        let object = PrivilegeObject::Edge(edge.edge_type.clone());
+        edge.props
+            .retain(|name, _| self.allows_property(PrivilegeAction::Read, &object, name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str) -> PrivilegeObject {
+        PrivilegeObject::Tag(name.to_string())
+    }
+
+    fn roles(list: Vec<CustomRole>) -> HashMap<String, CustomRole> {
+        list.into_iter().map(|r| (r.name.clone(), r)).collect()
+    }
+
+    #[test]
+    fn test_property_level_read() {
+        let mut analyst = CustomRole::new("analyst".to_string());
+        analyst.add_grant(
+            PrivilegeGrant::new(PrivilegeAction::Read, tag("person"))
+                .with_properties(vec!["name".to_string(), "age".to_string()]),
+        );
+
+        let privileges =
+            EffectivePrivileges::resolve(&["analyst".to_string()], &roles(vec![analyst]));
+        assert!(privileges.allows(PrivilegeAction::Read, &tag("person")));
+        assert!(privileges.allows_property(PrivilegeAction::Read, &tag("person"), "name"));
+        assert!(!privileges.allows_property(PrivilegeAction::Read, &tag("person"), "ssn"));
+        assert!(!privileges.allows(PrivilegeAction::Write, &tag("person")));
+        assert!(!privileges.allows(PrivilegeAction::Read, &tag("company")));
+    }
+
+    #[test]
+    fn test_inheritance_merges_scopes() {
+        let mut reader = CustomRole::new("reader".to_string());
+        reader.add_grant(PrivilegeGrant::new(PrivilegeAction::Read, tag("person")));
+        let mut analyst =
+            CustomRole::new("analyst".to_string()).with_parents(vec!["reader".to_string()]);
+        analyst.add_grant(
+            PrivilegeGrant::new(PrivilegeAction::Read, tag("person"))
+                .with_properties(vec!["name".to_string()]),
+        );
+
+        let privileges =
+            EffectivePrivileges::resolve(&["analyst".to_string()], &roles(vec![reader, analyst]));
+        assert_eq!(
+            privileges.roles(),
+            ["analyst".to_string(), "reader".to_string()]
+        );
+        assert!(privileges.allows_all_properties(PrivilegeAction::Read, &tag("person")));
+        assert_eq!(privileges.grants().len(), 1);
+        assert_eq!(privileges.grants()[0].to_string(), "READ ON TAG person");
+    }
+
+    #[test]
+    fn test_inheritance_cycle_terminates() {
+        let a = CustomRole::new("a".to_string()).with_parents(vec!["b".to_string()]);
+        let mut b = CustomRole::new("b".to_string()).with_parents(vec!["a".to_string()]);
+        b.add_grant(PrivilegeGrant::new(
+            PrivilegeAction::Write,
+            PrivilegeObject::Edge("click".to_string()),
+        ));
+
+        let privileges = EffectivePrivileges::resolve(&["a".to_string()], &roles(vec![a, b]));
+        assert_eq!(privileges.roles().len(), 2);
+        assert!(privileges.allows(
+            PrivilegeAction::Write,
+            &PrivilegeObject::Edge("click".to_string())
+        ));
+    }
+
+    #[test]
+    fn test_add_grant_merges_properties() {
+        let mut role = CustomRole::new("r".to_string());
+        role.add_grant(
+            PrivilegeGrant::new(PrivilegeAction::Read, tag("person"))
+                .with_properties(vec!["name".to_string()]),
+        );
+        role.add_grant(
+            PrivilegeGrant::new(PrivilegeAction::Read, tag("person"))
+                .with_properties(vec!["age".to_string(), "name".to_string()]),
+        );
+        assert_eq!(role.grants.len(), 1);
+        assert_eq!(
+            role.grants[0].properties,
+            Some(vec!["name".to_string(), "age".to_string()])
+        );
+        assert_eq!(role.grants[0].to_string(), "READ ON TAG person(name, age)");
+
+        role.add_grant(PrivilegeGrant::new(PrivilegeAction::Read, tag("person")));
+        assert_eq!(role.grants[0].properties, None);
+    }
+
+    #[test]
+    fn test_mask_value_hides_properties_and_tags() {
+        use crate::core::types::VertexId;
+        use crate::core::Tag;
+
+        let mut analyst = CustomRole::new("analyst".to_string());
+        analyst.add_grant(
+            PrivilegeGrant::new(PrivilegeAction::Read, tag("person"))
+                .with_properties(vec!["name".to_string()]),
+        );
+        let privileges =
+            EffectivePrivileges::resolve(&["analyst".to_string()], &roles(vec![analyst]));
+
+        let person = Tag::new(
+            "person".to_string(),
+            HashMap::from([
+                ("name".to_string(), Value::String("Tom".to_string())),
+                ("ssn".to_string(), Value::String("123".to_string())),
+            ]),
+        );
+        let employee = Tag::new(
+            "employee".to_string(),
+            HashMap::from([("salary".to_string(), Value::Int(1))]),
+        );
+        let mut vertex = Vertex::new(VertexId::from_int64(1), vec![person, employee]);
+        vertex
+            .properties
+            .insert("ssn".to_string(), Value::String("123".to_string()));
+        let mut edge = Edge::new_empty(
+            VertexId::from_int64(1),
+            VertexId::from_int64(2),
+            "click".to_string(),
+            0,
+        );
+        edge.props.insert("ts".to_string(), Value::Int(7));
+
+        let mut value = Value::List(Box::new(crate::core::value::List {
+            values: vec![Value::Vertex(Box::new(vertex)), Value::Edge(Box::new(edge))],
+        }));
+        privileges.mask_value(&mut value);
+
+        let Value::List(list) = value else {
+            panic!("expected a list");
+        };
+        let Value::Vertex(ref vertex) = list.values[0] else {
+            panic!("expected a vertex");
+        };
+        assert_eq!(vertex.tags.len(), 1);
+        assert!(vertex.tags[0].properties.contains_key("name"));
+        assert!(!vertex.tags[0].properties.contains_key("ssn"));
+        assert!(vertex.properties.is_empty());
+        let Value::Edge(ref edge) = list.values[1] else {
+            panic!("expected an edge");
+        };
+        assert!(edge.props.is_empty());
+    }
+}
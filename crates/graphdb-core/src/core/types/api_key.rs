@@ -0,0 +1,151 @@
+//! API Key Type Definition
+//!
+//! Long-lived credentials for non-interactive clients. A key is shown to the user
+//! once, as `gdb_<id>_<secret>`; only the SHA-256 hash of the secret is stored.
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::Permission;
+
+/// Prefix that identifies an API key among bearer tokens
+pub const API_KEY_PREFIX: &str = "gdb_";
+
+const API_KEY_ID_BYTES: usize = 8;
+const API_KEY_SECRET_BYTES: usize = 32;
+
+/// Stored API key - the plaintext secret is never kept
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiKeyInfo {
+    /// Public key identifier, part of the token
+    pub id: String,
+    /// User the key authenticates as
+    pub username: String,
+    /// SHA-256 of the secret, hex encoded
+    pub key_hash: String,
+    /// Permissions the key is limited to (empty means all permissions of the user)
+    #[serde(default)]
+    pub scopes: Vec<Permission>,
+    /// Creation time
+    pub created_at: i64,
+    /// Expiration time, `None` for keys that never expire
+    pub expires_at: Option<i64>,
+    /// Revoked keys are kept for auditing but no longer authenticate
+    #[serde(default)]
+    pub revoked: bool,
+}
+
+impl ApiKeyInfo {
+    /// Generate a new key for `username`. Returns the stored record and the plaintext token.
+    pub fn generate(
+        username: String,
+        scopes: Vec<Permission>,
+        expires_at: Option<i64>,
+    ) -> (Self, String) {
+        let mut rng = rand::thread_rng();
+        let mut id = [0u8; API_KEY_ID_BYTES];
+        let mut secret = [0u8; API_KEY_SECRET_BYTES];
+        rng.fill_bytes(&mut id);
+        rng.fill_bytes(&mut secret);
+
+        let id = to_hex(&id);
+        let secret = to_hex(&secret);
+        let token = format!("{}{}_{}", API_KEY_PREFIX, id, secret);
+
+        let info = Self {
+            id,
+            username,
+            key_hash: hash_secret(&secret),
+            scopes,
+            created_at: chrono::Utc::now().timestamp_millis(),
+            expires_at,
+            revoked: false,
+        };
+        (info, token)
+    }
+
+    /// Split a `gdb_<id>_<secret>` token into its id and secret.
+    pub fn parse_token(token: &str) -> Option<(&str, &str)> {
+        let (id, secret) = token.strip_prefix(API_KEY_PREFIX)?.split_once('_')?;
+        if id.is_empty() || secret.is_empty() {
+            return None;
+        }
+        Some((id, secret))
+    }
+
+    /// Check a secret against the stored hash in constant time.
+    pub fn matches_secret(&self, secret: &str) -> bool {
+        let actual = hash_secret(secret);
+        actual.len() == self.key_hash.len()
+            && actual
+                .bytes()
+                .zip(self.key_hash.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    /// Whether the key has expired at `now` (milliseconds since the epoch)
+    pub fn is_expired_at(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Whether the key can still be used to authenticate
+    pub fn is_active(&self) -> bool {
+        !self.revoked && !self.is_expired_at(chrono::Utc::now().timestamp_millis())
+    }
+
+    /// Whether the key's scopes allow the permission
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.scopes.is_empty() || self.scopes.contains(&permission)
+    }
+}
+
+fn hash_secret(secret: &str) -> String {
+    to_hex(&Sha256::digest(secret.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_key_round_trip() {
+        let (info, token) = ApiKeyInfo::generate("alice".to_string(), vec![Permission::Read], None);
+        assert!(token.starts_with(API_KEY_PREFIX));
+        assert!(!token.contains(&info.key_hash));
+
+        let (id, secret) = ApiKeyInfo::parse_token(&token).expect("token should parse");
+        assert_eq!(id, info.id);
+        assert!(info.matches_secret(secret));
+        assert!(!info.matches_secret("not-the-secret"));
+        assert!(info.is_active());
+        assert!(info.allows(Permission::Read));
+        assert!(!info.allows(Permission::Write));
+    }
+
+    #[test]
+    fn test_parse_token_rejects_other_formats() {
+        assert!(ApiKeyInfo::parse_token("eyJhbGciOiJIUzI1NiJ9.e30.sig").is_none());
+        assert!(ApiKeyInfo::parse_token("gdb_missingsecret").is_none());
+        assert!(ApiKeyInfo::parse_token("gdb__secret").is_none());
+    }
+
+    #[test]
+    fn test_expired_and_revoked_keys_are_inactive() {
+        let (mut info, _) = ApiKeyInfo::generate("alice".to_string(), Vec::new(), Some(0));
+        assert!(info.is_expired_at(1));
+        assert!(!info.is_active());
+
+        info.expires_at = None;
+        assert!(info.is_active());
+        assert!(info.allows(Permission::Admin));
+
+        info.revoked = true;
+        assert!(!info.is_active());
+    }
+}
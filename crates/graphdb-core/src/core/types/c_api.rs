@@ -32,6 +32,16 @@ pub enum graphdb_value_type_t {
     GRAPHDB_PATH = 9,
     /// binary data
     GRAPHDB_BLOB = 10,
+    /// vector
+    GRAPHDB_VECTOR = 11,
+    /// date
+    GRAPHDB_DATE = 12,
+    /// time of day
+    GRAPHDB_TIME = 13,
+    /// date and time
+    GRAPHDB_DATETIME = 14,
+    /// duration (interval)
+    GRAPHDB_DURATION = 15,
 }
 
 /// binary data structure
@@ -130,6 +130,8 @@ fn requires_runtime_context(expression: &Expression) -> bool {
         Expression::PathBuild(exprs) => exprs.iter().any(requires_runtime_context),
         Expression::Parameter(_) => true,
         Expression::Vector(_) => false,
+        // Pattern subqueries read the graph
+        Expression::PatternSubquery(_) => true,
     }
 }
 
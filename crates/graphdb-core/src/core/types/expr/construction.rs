@@ -2,6 +2,7 @@
 //!
 //! Provide methods for creating various types of expressions.
 
+use crate::core::types::expr::pattern::PatternSubquery;
 use crate::core::types::expr::Expression;
 use crate::core::types::operators::{AggregateFunction, BinaryOperator, UnaryOperator};
 use crate::core::types::DataType;
@@ -201,6 +202,11 @@ impl Expression {
         Expression::Vector(data)
     }
 
+    /// Create a pattern subquery expression
+    pub fn pattern_subquery(subquery: PatternSubquery) -> Self {
+        Expression::PatternSubquery(Box::new(subquery))
+    }
+
     /// Creating a boolean literal
     pub fn bool(value: bool) -> Self {
         Expression::Literal(Value::Bool(value))
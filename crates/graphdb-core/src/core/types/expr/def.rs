@@ -2,6 +2,7 @@
 //!
 //! This module defines the `Expression` enumeration of uniform expression types used in the query engine.
 
+use crate::core::types::expr::pattern::PatternSubquery;
 pub use crate::core::types::operators::{AggregateFunction, BinaryOperator, UnaryOperator};
 pub use crate::core::types::DataType;
 use crate::core::Value;
@@ -145,4 +146,9 @@ pub enum Expression {
     ///
     /// Represents vector literals like VECTOR[0.1, 0.2, 0.3] or [0.1, 0.2]::VECTOR
     Vector(Vec<f32>),
+
+    /// Pattern subquery expression
+    ///
+    /// `EXISTS { ... }`, `COUNT { ... }` and pattern comprehensions; see [`PatternSubquery`].
+    PatternSubquery(Box<PatternSubquery>),
 }
@@ -203,6 +203,7 @@ impl Expression {
                         .join(", ")
                 )
             }
+            Expression::PatternSubquery(subquery) => subquery.to_expression_string(),
         }
     }
 }
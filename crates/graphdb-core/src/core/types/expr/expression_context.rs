@@ -7,7 +7,7 @@ use super::{Expression, ExpressionId, ExpressionMeta};
 use crate::core::types::operators::BinaryOperator;
 use crate::core::types::operators::UnaryOperator;
 use crate::core::types::DataType;
-use crate::core::Value;
+use crate::core::{NullType, Value};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct OptimizationFlags {
@@ -22,6 +22,10 @@ pub struct ExpressionAnalysisContext {
     type_cache: Arc<RwLock<HashMap<ExpressionId, DataType>>>,
     constant_cache: Arc<RwLock<HashMap<ExpressionId, Value>>>,
     optimization_flags: Arc<RwLock<HashMap<ExpressionId, OptimizationFlags>>>,
+    /// Values of the `$name` placeholders this view is bound to, see [`Self::with_bindings`]
+    parameters: Arc<HashMap<String, Value>>,
+    /// Values of the row variables this view is bound to
+    variables: Arc<HashMap<String, Value>>,
 }
 
 impl ExpressionAnalysisContext {
@@ -31,6 +35,29 @@ impl ExpressionAnalysisContext {
             type_cache: Arc::new(RwLock::new(HashMap::new())),
             constant_cache: Arc::new(RwLock::new(HashMap::new())),
             optimization_flags: Arc::new(RwLock::new(HashMap::new())),
+            parameters: Arc::new(HashMap::new()),
+            variables: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// View of this context with `$name` placeholders bound to `parameters` and
+    /// row variables bound to `variables`
+    ///
+    /// The view shares the registered expressions and caches but has its own
+    /// bindings, so one execution binding its values never affects another
+    /// execution of the same plan.
+    pub fn with_bindings(
+        &self,
+        parameters: Arc<HashMap<String, Value>>,
+        variables: Arc<HashMap<String, Value>>,
+    ) -> Self {
+        Self {
+            expressions: self.expressions.clone(),
+            type_cache: self.type_cache.clone(),
+            constant_cache: self.constant_cache.clone(),
+            optimization_flags: self.optimization_flags.clone(),
+            parameters,
+            variables,
         }
     }
 
@@ -44,8 +71,38 @@ impl ExpressionAnalysisContext {
         id
     }
 
+    /// Get a registered expression
+    ///
+    /// In a view from [`Self::with_bindings`], `$name` variables and parameter
+    /// references with a bound value are returned as literals. The same holds for bound row
+    /// variables and for property accesses on bound maps, vertices and edges,
+    /// except where a list comprehension or `reduce` rebinds the name. The
+    /// registered expression itself is left untouched, so a cached plan can be
+    /// re-bound and executed again with different values.
     pub fn get_expression(&self, id: &ExpressionId) -> Option<Arc<ExpressionMeta>> {
-        self.expressions.read().get(id).cloned()
+        let meta = self.expressions.read().get(id).cloned()?;
+
+        if self.parameters.is_empty() && self.variables.is_empty() {
+            return Some(meta);
+        }
+
+        let bindings = Bindings {
+            parameters: &self.parameters,
+            variables: &self.variables,
+        };
+        let bound_expr = bindings.substitute(meta.inner(), &[]);
+        if &bound_expr == meta.inner() {
+            return Some(meta);
+        }
+
+        let mut bound = meta.as_ref().clone();
+        *bound.make_mut() = bound_expr;
+        Some(Arc::new(bound))
+    }
+
+    /// Row variables this view is bound to
+    pub fn bound_variables(&self) -> HashMap<String, Value> {
+        self.variables.as_ref().clone()
     }
 
     pub fn set_type(&self, id: &ExpressionId, data_type: DataType) {
@@ -116,6 +173,11 @@ impl ExpressionAnalysisContext {
             .unwrap_or(false)
     }
 
+    /// Snapshot of every registered expression, in no particular order
+    pub fn expressions(&self) -> Vec<Arc<ExpressionMeta>> {
+        self.expressions.read().values().cloned().collect()
+    }
+
     pub fn expression_count(&self) -> usize {
         self.expressions.read().len()
     }
@@ -249,10 +311,95 @@ impl Clone for ExpressionAnalysisContext {
             type_cache: Arc::new(RwLock::new(self.type_cache.read().clone())),
             constant_cache: Arc::new(RwLock::new(self.constant_cache.read().clone())),
             optimization_flags: Arc::new(RwLock::new(self.optimization_flags.read().clone())),
+            parameters: self.parameters.clone(),
+            variables: self.variables.clone(),
         }
     }
 }
 
+/// Values substituted into an expression by [`ExpressionAnalysisContext::get_expression`]
+struct Bindings<'a> {
+    parameters: &'a HashMap<String, Value>,
+    variables: &'a HashMap<String, Value>,
+}
+
+impl Bindings<'_> {
+    /// `expr` with bound names replaced by literals; names in `shadowed` are
+    /// rebound by an enclosing list comprehension or `reduce` and stay variables
+    fn substitute(&self, expr: &Expression, shadowed: &[&str]) -> Expression {
+        expr.transform(&|e| match e {
+            Expression::ListComprehension {
+                variable,
+                source,
+                filter,
+                map,
+            } => {
+                let inner = [shadowed, &[variable.as_str()]].concat();
+                Some(Expression::ListComprehension {
+                    variable: variable.clone(),
+                    source: Box::new(self.substitute(source, shadowed)),
+                    filter: filter
+                        .as_ref()
+                        .map(|f| Box::new(self.substitute(f, &inner))),
+                    map: map.as_ref().map(|m| Box::new(self.substitute(m, &inner))),
+                })
+            }
+            Expression::Reduce {
+                accumulator,
+                initial,
+                variable,
+                source,
+                mapping,
+            } => {
+                let inner = [shadowed, &[accumulator.as_str(), variable.as_str()]].concat();
+                Some(Expression::Reduce {
+                    accumulator: accumulator.clone(),
+                    initial: Box::new(self.substitute(initial, shadowed)),
+                    variable: variable.clone(),
+                    source: Box::new(self.substitute(source, shadowed)),
+                    mapping: Box::new(self.substitute(mapping, &inner)),
+                })
+            }
+            _ => self.lookup(e, shadowed).map(Expression::Literal),
+        })
+    }
+
+    fn lookup(&self, expr: &Expression, shadowed: &[&str]) -> Option<Value> {
+        let variable = |name: &str| {
+            if shadowed.contains(&name) {
+                None
+            } else {
+                self.variables.get(name)
+            }
+        };
+        match expr {
+            Expression::Variable(name) => match name.strip_prefix('$') {
+                Some(param) => self.parameters.get(param).cloned(),
+                None => variable(name).cloned(),
+            },
+            Expression::Parameter(name) => {
+                self.parameters.get(name.trim_start_matches('$')).cloned()
+            }
+            Expression::Property { object, property } => match object.as_ref() {
+                Expression::Variable(name) => variable(name).and_then(|v| property_of(v, property)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Property of a bound map, vertex or edge; missing properties read as NULL
+fn property_of(value: &Value, property: &str) -> Option<Value> {
+    let found = match value {
+        Value::Map(map) => map.get(property),
+        Value::Vertex(vertex) => vertex.get_property_any(property),
+        Value::Edge(edge) => edge.get_property(property),
+        _ => return None,
+    };
+    Some(found.cloned().unwrap_or(Value::Null(NullType::Null)))
+}
+
 impl Default for ExpressionAnalysisContext {
     fn default() -> Self {
         Self::new()
@@ -394,4 +541,116 @@ mod tests {
         let ctx = ExpressionAnalysisContext::default();
         assert_eq!(ctx.expression_count(), 0);
     }
+
+    #[test]
+    fn test_bind_parameters() {
+        let ctx = ExpressionAnalysisContext::new();
+        let expr = Expression::binary(
+            Expression::variable("$age"),
+            BinaryOperator::Add,
+            Expression::literal(1),
+        );
+        let id = ctx.register_expression(ExpressionMeta::new(expr.clone()));
+
+        let mut params = HashMap::new();
+        params.insert("age".to_string(), Value::Int(41));
+        let bound_ctx = ctx.with_bindings(Arc::new(params), Arc::new(HashMap::new()));
+
+        let bound = bound_ctx
+            .get_expression(&id)
+            .expect("Expression should exist");
+        assert_eq!(
+            bound.inner(),
+            &Expression::binary(
+                Expression::literal(41),
+                BinaryOperator::Add,
+                Expression::literal(1),
+            )
+        );
+
+        let unbound = ctx.get_expression(&id).expect("Expression should exist");
+        assert_eq!(unbound.inner(), &expr);
+    }
+
+    #[test]
+    fn test_bind_variables() {
+        let ctx = ExpressionAnalysisContext::new();
+        let expr = Expression::binary(
+            Expression::property(Expression::variable("row"), "age"),
+            BinaryOperator::Add,
+            Expression::variable("n"),
+        );
+        let id = ctx.register_expression(ExpressionMeta::new(expr.clone()));
+
+        let mut row = HashMap::new();
+        row.insert("age".to_string(), Value::Int(41));
+        let mut variables = HashMap::new();
+        variables.insert("row".to_string(), Value::Map(Box::new(row)));
+        variables.insert("n".to_string(), Value::Int(1));
+        let row_ctx = ctx.with_bindings(Arc::new(HashMap::new()), Arc::new(variables));
+
+        let bound = row_ctx
+            .get_expression(&id)
+            .expect("Expression should exist");
+        assert_eq!(
+            bound.inner(),
+            &Expression::binary(
+                Expression::literal(41),
+                BinaryOperator::Add,
+                Expression::literal(1),
+            )
+        );
+
+        let unbound = ctx.get_expression(&id).expect("Expression should exist");
+        assert_eq!(unbound.inner(), &expr);
+    }
+
+    #[test]
+    fn test_bind_variables_respects_inner_scopes() {
+        let ctx = ExpressionAnalysisContext::new();
+        // [x IN m | x * 2] + [x]
+        let comprehension = Expression::list_comprehension(
+            "x",
+            Expression::variable("m"),
+            None,
+            Some(Expression::binary(
+                Expression::variable("x"),
+                BinaryOperator::Multiply,
+                Expression::literal(2),
+            )),
+        );
+        let expr = Expression::binary(
+            comprehension.clone(),
+            BinaryOperator::Add,
+            Expression::list(vec![Expression::variable("x")]),
+        );
+        let id = ctx.register_expression(ExpressionMeta::new(expr));
+
+        let m = Value::list(crate::core::value::List::from(vec![Value::Int(3)]));
+        let mut variables = HashMap::new();
+        variables.insert("x".to_string(), Value::Int(1));
+        variables.insert("m".to_string(), m.clone());
+        let row_ctx = ctx.with_bindings(Arc::new(HashMap::new()), Arc::new(variables));
+
+        let bound = row_ctx
+            .get_expression(&id)
+            .expect("Expression should exist");
+        assert_eq!(
+            bound.inner(),
+            &Expression::binary(
+                Expression::list_comprehension(
+                    "x",
+                    Expression::literal(m),
+                    None,
+                    Some(Expression::binary(
+                        Expression::variable("x"),
+                        BinaryOperator::Multiply,
+                        Expression::literal(2),
+                    )),
+                ),
+                BinaryOperator::Add,
+                Expression::list(vec![Expression::literal(1)]),
+            )
+        );
+    }
 }
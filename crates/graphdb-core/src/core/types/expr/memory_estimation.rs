@@ -2,7 +2,7 @@
 //!
 //! This module provides memory estimation for the Expression enum and related types.
 
-use crate::core::types::expr::Expression;
+use crate::core::types::expr::{Expression, PatternSubquery};
 use crate::core::types::memory_estimation::MemoryEstimatable;
 use crate::core::value::Value;
 
@@ -133,6 +133,20 @@ impl MemoryEstimatable for Expression {
                     + mapping.estimate_memory()
             }
             Expression::Vector(data) => base_size + data.len() * std::mem::size_of::<f32>(),
+            Expression::PatternSubquery(subquery) => {
+                base_size
+                    + std::mem::size_of::<PatternSubquery>()
+                    + subquery
+                        .variables()
+                        .iter()
+                        .map(estimate_string_memory)
+                        .sum::<usize>()
+                    + subquery
+                        .expressions()
+                        .iter()
+                        .map(|e| e.estimate_memory())
+                        .sum::<usize>()
+            }
         }
     }
 }
@@ -336,6 +350,12 @@ impl Expression {
                 Expression::Vector(_) => {
                     // Vector leaf node, base already counted
                 }
+
+                // Pattern subquery
+                Expression::PatternSubquery(subquery) => {
+                    total += std::mem::size_of::<PatternSubquery>();
+                    stack.extend(subquery.expressions());
+                }
             }
         }
 
@@ -33,6 +33,7 @@
 //! | `Range` | Range expression |
 //! | `Path` | Path expression |
 //! | `Label` | Label Expression |
+//! | `PatternSubquery` | `EXISTS { }`, `COUNT { }` and pattern comprehensions |
 //!
 //! ## Usage Examples
 //!
@@ -93,6 +94,9 @@ pub mod group_utils;
 // Inspection utilities
 mod inspection;
 
+// Graph patterns embedded in expressions
+pub mod pattern;
+
 // Memory estimation
 pub mod memory_estimation;
 
@@ -115,6 +119,7 @@ pub mod visitor_collectors;
 pub use contextual::ContextualExpression;
 pub use def::Expression;
 pub use expression::{ExpressionId, ExpressionMeta};
+pub use pattern::{PatternEdge, PatternNode, PatternPath, PatternSubquery, PatternSubqueryKind};
 pub use serializable::SerializableExpression;
 
 // Unified Export - Visitor pattern
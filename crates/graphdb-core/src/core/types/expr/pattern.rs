@@ -0,0 +1,298 @@
+//! Pattern subquery definitions
+//!
+//! Graph patterns embedded inside expressions:
+//! - `EXISTS { MATCH (a)-[:KNOWS]->(b) WHERE b.age > 30 }` – whether the pattern matches at least once
+//! - `COUNT { (a)-[:KNOWS]->(b) }` – the number of matches
+//! - `[(a)-[:KNOWS]->(b) WHERE b.age > 30 | b.name]` – pattern comprehension, one list item per match
+//!
+//! Variables that are already bound by the enclosing query (for example `a` above) anchor the
+//! pattern; every other variable is local to the subquery and is not visible outside of it.
+
+use crate::core::types::expr::Expression;
+use crate::core::types::graph_schema::EdgeDirection;
+use serde::{Deserialize, Serialize};
+
+/// The value a pattern subquery produces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PatternSubqueryKind {
+    /// `EXISTS { ... }`, evaluates to a boolean
+    Exists,
+    /// `COUNT { ... }`, evaluates to the number of matches
+    Count,
+    /// `[pattern WHERE ... | ...]`, evaluates to a list
+    Collect,
+}
+
+/// Node of an embedded pattern, e.g. `(b:Person {name: 'Tom'})`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternNode {
+    pub variable: Option<String>,
+    pub labels: Vec<String>,
+    pub properties: Vec<(String, Expression)>,
+}
+
+/// Relationship of an embedded pattern, e.g. `-[e:KNOWS|LIKES {since: 2020}]->`
+///
+/// Only single-hop relationships are supported inside expressions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternEdge {
+    pub variable: Option<String>,
+    pub edge_types: Vec<String>,
+    pub direction: EdgeDirection,
+    pub properties: Vec<(String, Expression)>,
+}
+
+/// A chain of nodes connected by relationships; `edges[i]` connects `nodes[i]` and `nodes[i + 1]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternPath {
+    pub nodes: Vec<PatternNode>,
+    pub edges: Vec<PatternEdge>,
+}
+
+/// Pattern subquery embedded in an expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternSubquery {
+    pub kind: PatternSubqueryKind,
+    pub paths: Vec<PatternPath>,
+    pub filter: Option<Expression>,
+    /// Projection of every match; only used by pattern comprehensions.
+    pub map: Option<Expression>,
+}
+
+impl PatternNode {
+    pub fn new(variable: Option<String>) -> Self {
+        Self {
+            variable,
+            labels: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl PatternEdge {
+    pub fn new(variable: Option<String>, direction: EdgeDirection) -> Self {
+        Self {
+            variable,
+            edge_types: Vec::new(),
+            direction,
+            properties: Vec::new(),
+        }
+    }
+}
+
+impl PatternPath {
+    pub fn new(start: PatternNode) -> Self {
+        Self {
+            nodes: vec![start],
+            edges: Vec::new(),
+        }
+    }
+
+    /// Append a relationship and the node it leads to.
+    pub fn push(&mut self, edge: PatternEdge, node: PatternNode) {
+        self.edges.push(edge);
+        self.nodes.push(node);
+    }
+}
+
+impl PatternSubquery {
+    pub fn new(
+        kind: PatternSubqueryKind,
+        paths: Vec<PatternPath>,
+        filter: Option<Expression>,
+        map: Option<Expression>,
+    ) -> Self {
+        Self {
+            kind,
+            paths,
+            filter,
+            map,
+        }
+    }
+
+    /// Variables named by the pattern, in order of appearance and without duplicates.
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables: Vec<String> = Vec::new();
+        for path in &self.paths {
+            let edge_vars = path.edges.iter().map(|e| &e.variable);
+            for variable in path.nodes.iter().map(|n| &n.variable).chain(edge_vars) {
+                if let Some(name) = variable {
+                    if !variables.contains(name) {
+                        variables.push(name.clone());
+                    }
+                }
+            }
+        }
+        variables
+    }
+
+    /// Expressions nested in the subquery: property constraints, the filter and the projection.
+    pub fn expressions(&self) -> Vec<&Expression> {
+        let mut expressions = Vec::new();
+        for path in &self.paths {
+            for node in &path.nodes {
+                expressions.extend(node.properties.iter().map(|(_, e)| e));
+            }
+            for edge in &path.edges {
+                expressions.extend(edge.properties.iter().map(|(_, e)| e));
+            }
+        }
+        expressions.extend(self.filter.iter());
+        expressions.extend(self.map.iter());
+        expressions
+    }
+
+    /// Mutable variant of [`PatternSubquery::expressions`].
+    pub fn expressions_mut(&mut self) -> Vec<&mut Expression> {
+        let mut expressions = Vec::new();
+        for path in &mut self.paths {
+            for node in &mut path.nodes {
+                expressions.extend(node.properties.iter_mut().map(|(_, e)| e));
+            }
+            for edge in &mut path.edges {
+                expressions.extend(edge.properties.iter_mut().map(|(_, e)| e));
+            }
+        }
+        expressions.extend(self.filter.iter_mut());
+        expressions.extend(self.map.iter_mut());
+        expressions
+    }
+
+    /// Copy of the subquery with every nested expression rewritten by `f`.
+    pub fn map_expressions<F>(&self, mut f: F) -> PatternSubquery
+    where
+        F: FnMut(&Expression) -> Expression,
+    {
+        let mut subquery = self.clone();
+        for expression in subquery.expressions_mut() {
+            *expression = f(expression);
+        }
+        subquery
+    }
+
+    /// Cypher text of the pattern part, e.g. `(a)-[:KNOWS]->(b), (b)--(c)`.
+    pub fn pattern_string(&self) -> String {
+        self.paths
+            .iter()
+            .map(|path| {
+                let mut text = node_string(&path.nodes[0]);
+                for (edge, node) in path.edges.iter().zip(path.nodes.iter().skip(1)) {
+                    text.push_str(&edge_string(edge));
+                    text.push_str(&node_string(node));
+                }
+                text
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn to_expression_string(&self) -> String {
+        let filter = self
+            .filter
+            .as_ref()
+            .map(|f| format!(" WHERE {}", f.to_expression_string()))
+            .unwrap_or_default();
+        match self.kind {
+            PatternSubqueryKind::Exists => {
+                format!("EXISTS {{ MATCH {}{} }}", self.pattern_string(), filter)
+            }
+            PatternSubqueryKind::Count => {
+                format!("COUNT {{ MATCH {}{} }}", self.pattern_string(), filter)
+            }
+            PatternSubqueryKind::Collect => {
+                let map = self
+                    .map
+                    .as_ref()
+                    .map(|m| format!(" | {}", m.to_expression_string()))
+                    .unwrap_or_default();
+                format!("[{}{}{}]", self.pattern_string(), filter, map)
+            }
+        }
+    }
+}
+
+fn properties_string(properties: &[(String, Expression)]) -> String {
+    if properties.is_empty() {
+        return String::new();
+    }
+    let entries = properties
+        .iter()
+        .map(|(k, v)| format!("{}: {}", k, v.to_expression_string()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(" {{{}}}", entries)
+}
+
+fn node_string(node: &PatternNode) -> String {
+    let labels: String = node.labels.iter().map(|l| format!(":{}", l)).collect();
+    format!(
+        "({}{}{})",
+        node.variable.as_deref().unwrap_or(""),
+        labels,
+        properties_string(&node.properties)
+    )
+}
+
+fn edge_string(edge: &PatternEdge) -> String {
+    let types = if edge.edge_types.is_empty() {
+        String::new()
+    } else {
+        format!(":{}", edge.edge_types.join("|"))
+    };
+    // Anonymous relationships keep their brackets: `--` starts a comment in the lexer.
+    let body = format!(
+        "[{}{}{}]",
+        edge.variable.as_deref().unwrap_or(""),
+        types,
+        properties_string(&edge.properties)
+    );
+    match edge.direction {
+        EdgeDirection::Out => format!("-{}->", body),
+        EdgeDirection::In => format!("<-{}-", body),
+        EdgeDirection::Both => format!("-{}-", body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn knows_subquery(kind: PatternSubqueryKind) -> PatternSubquery {
+        let mut path = PatternPath::new(PatternNode::new(Some("a".to_string())));
+        let mut edge = PatternEdge::new(None, EdgeDirection::Out);
+        edge.edge_types.push("KNOWS".to_string());
+        let mut friend = PatternNode::new(Some("b".to_string()));
+        friend.labels.push("Person".to_string());
+        path.push(edge, friend);
+        let filter = Expression::gt(
+            Expression::property(Expression::variable("b"), "age"),
+            Expression::literal(30),
+        );
+        let map = (kind == PatternSubqueryKind::Collect)
+            .then(|| Expression::property(Expression::variable("b"), "name"));
+        PatternSubquery::new(kind, vec![path], Some(filter), map)
+    }
+
+    #[test]
+    fn test_pattern_subquery_display() {
+        let exists = knows_subquery(PatternSubqueryKind::Exists);
+        assert_eq!(exists.pattern_string(), "(a)-[:KNOWS]->(b:Person)");
+        assert!(exists
+            .to_expression_string()
+            .starts_with("EXISTS { MATCH (a)"));
+
+        let collect = knows_subquery(PatternSubqueryKind::Collect);
+        assert!(collect.to_expression_string().ends_with(" | b.name]"));
+    }
+
+    #[test]
+    fn test_pattern_subquery_variables_and_expressions() {
+        let subquery = knows_subquery(PatternSubqueryKind::Collect);
+        assert_eq!(subquery.variables(), vec!["a", "b"]);
+        assert_eq!(subquery.expressions().len(), 2);
+
+        let renamed = subquery.map_expressions(|_| Expression::literal(true));
+        assert_eq!(renamed.filter, Some(Expression::literal(true)));
+        assert_eq!(renamed.paths, subquery.paths);
+    }
+}
@@ -85,6 +85,7 @@ impl Expression {
             Expression::PathBuild(items) => items.iter().collect(),
             Expression::Parameter(_) => vec![],
             Expression::Vector(_) => vec![],
+            Expression::PatternSubquery(subquery) => subquery.expressions(),
         }
     }
 
@@ -168,6 +169,7 @@ impl Expression {
             Expression::PathBuild(items) => items.iter_mut().collect(),
             Expression::Parameter(_) => vec![],
             Expression::Vector(_) => vec![],
+            Expression::PatternSubquery(subquery) => subquery.expressions_mut(),
         }
     }
 
@@ -367,6 +369,9 @@ impl Expression {
             ),
             Expression::Parameter(_) => self.clone(),
             Expression::Vector(_) => self.clone(),
+            Expression::PatternSubquery(subquery) => Expression::PatternSubquery(Box::new(
+                subquery.map_expressions(|e| e.transform(transformer)),
+            )),
         }
     }
 }
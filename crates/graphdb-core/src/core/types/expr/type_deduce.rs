@@ -2,7 +2,7 @@
 //!
 //! Provide expression type derivation functions.
 
-use crate::core::types::expr::Expression;
+use crate::core::types::expr::{Expression, PatternSubqueryKind};
 use crate::core::types::operators::{AggregateFunction, BinaryOperator, UnaryOperator};
 use crate::core::types::DataType;
 use crate::core::Value;
@@ -42,6 +42,11 @@ impl Expression {
             Expression::PathBuild(_) => DataType::Path,
             Expression::Parameter(_) => DataType::Empty,
             Expression::Vector(_) => DataType::Vector,
+            Expression::PatternSubquery(subquery) => match subquery.kind {
+                PatternSubqueryKind::Exists => DataType::Bool,
+                PatternSubqueryKind::Count => DataType::BigInt,
+                PatternSubqueryKind::Collect => DataType::List,
+            },
         }
     }
 
@@ -14,6 +14,7 @@
 //! assert_eq!(collector.properties, vec!["name".to_string()]);
 //! ```
 
+use crate::core::types::expr::PatternSubquery;
 use crate::core::types::operators::{AggregateFunction, BinaryOperator, UnaryOperator};
 use crate::core::types::DataType;
 use crate::core::Expression;
@@ -150,6 +151,9 @@ pub trait ExpressionVisitor {
             Expression::Vector(data) => {
                 self.visit_vector(data);
             }
+            Expression::PatternSubquery(subquery) => {
+                self.visit_pattern_subquery(subquery);
+            }
         }
     }
 
@@ -318,4 +322,11 @@ pub trait ExpressionVisitor {
 
     /// Accessing query parameter expressions
     fn visit_parameter(&mut self, _name: &str) {}
+
+    /// Accessing pattern subquery expressions
+    fn visit_pattern_subquery(&mut self, subquery: &PatternSubquery) {
+        for expression in subquery.expressions() {
+            self.visit(expression);
+        }
+    }
 }
@@ -69,6 +69,11 @@ impl ExpressionVisitor for ConstantChecker {
         self.is_constant = false;
     }
 
+    fn visit_pattern_subquery(&mut self, _subquery: &crate::core::types::expr::PatternSubquery) {
+        // The result depends on the graph, never fold it.
+        self.is_constant = false;
+    }
+
     fn visit_binary(&mut self, _op: BinaryOperator, left: &Expression, right: &Expression) {
         if self.is_constant {
             self.visit(left);
@@ -753,6 +758,9 @@ impl WildcardReplacer {
             ),
             Expression::Parameter(name) => Expression::Parameter(name.clone()),
             Expression::Vector(data) => Expression::Vector(data.clone()),
+            Expression::PatternSubquery(subquery) => Expression::PatternSubquery(Box::new(
+                subquery.map_expressions(|e| self.replace_internal(e)),
+            )),
         }
     }
 }
@@ -110,7 +110,7 @@ impl From<bool> for OrderDirection {
 /// Type of orientation of the edge
 ///
 /// Used to represent the traversal direction of an edge, supports outgoing, incoming and bi-directional traversal.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EdgeDirection {
     /// Outgoing edge: from source node to target node
     Out,
@@ -272,6 +272,108 @@ impl Index {
             None => true,
         }
     }
+
+    /// Key of a unique index for the given properties
+    ///
+    /// A single-field index is keyed by the value itself and a composite index by
+    /// the list of values in field order. Returns None when any field is missing
+    /// or null, since null keys never collide.
+    pub fn unique_key(&self, props: &[(String, Value)]) -> Option<Value> {
+        let mut values = Vec::with_capacity(self.fields.len());
+        for field in &self.fields {
+            match props.iter().find(|(name, _)| name == &field.name) {
+                Some((_, value)) if !value.is_null() => values.push(value.clone()),
+                _ => return None,
+            }
+        }
+        match values.len() {
+            0 => None,
+            1 => values.pop(),
+            _ => Some(Value::List(Box::new(crate::core::value::List { values }))),
+        }
+    }
+}
+
+/// Kind of a property constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    #[serde(rename = "unique")]
+    Unique,
+    #[serde(rename = "not_null")]
+    NotNull,
+}
+
+impl std::fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintKind::Unique => write!(f, "UNIQUE"),
+            ConstraintKind::NotNull => write!(f, "NOT NULL"),
+        }
+    }
+}
+
+/// Property constraint on a tag
+///
+/// A UNIQUE constraint is backed by a unique tag index of the same name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constraint {
+    pub name: String,
+    pub space_id: u64,
+    pub schema_name: String,
+    pub kind: ConstraintKind,
+    pub properties: Vec<String>,
+}
+
+impl Constraint {
+    pub fn new(
+        name: String,
+        schema_name: String,
+        kind: ConstraintKind,
+        properties: Vec<String>,
+    ) -> Self {
+        Self {
+            name,
+            space_id: 0,
+            schema_name,
+            kind,
+            properties,
+        }
+    }
+
+    /// Backing unique index of a UNIQUE constraint
+    pub fn backing_index(&self) -> Option<Index> {
+        if self.kind != ConstraintKind::Unique {
+            return None;
+        }
+        let fields = self
+            .properties
+            .iter()
+            .map(|prop| IndexField::new(prop.clone(), Value::String("string".to_string()), false))
+            .collect();
+        Some(Index::new(IndexConfig {
+            id: 0,
+            name: self.name.clone(),
+            space_id: self.space_id,
+            schema_name: self.schema_name.clone(),
+            fields,
+            properties: self.properties.clone(),
+            index_type: IndexType::TagIndex,
+            is_unique: true,
+            partial_condition: None,
+        }))
+    }
+
+    /// Find the first constrained property that is missing or null
+    pub fn null_property<'a>(&'a self, props: &[(String, Value)]) -> Option<&'a str> {
+        self.properties
+            .iter()
+            .find(|prop| {
+                !props
+                    .iter()
+                    .any(|(name, value)| name == *prop && !value.is_null())
+            })
+            .map(|prop| prop.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -348,6 +450,39 @@ mod tests {
         assert_eq!(index.fields.len(), 1);
         assert_eq!(index.status, IndexStatus::Active);
     }
+
+    #[test]
+    fn test_constraint_unique_key() {
+        let constraint = Constraint::new(
+            "person_email".to_string(),
+            "person".to_string(),
+            ConstraintKind::Unique,
+            vec!["first".to_string(), "last".to_string()],
+        );
+        let index = constraint
+            .backing_index()
+            .expect("UNIQUE constraint should have a backing index");
+        assert!(index.is_unique);
+
+        let props = vec![
+            ("last".to_string(), Value::String("doe".to_string())),
+            ("first".to_string(), Value::String("john".to_string())),
+        ];
+        assert_eq!(
+            index.unique_key(&props),
+            Some(Value::List(Box::new(crate::core::value::List {
+                values: vec![
+                    Value::String("john".to_string()),
+                    Value::String("doe".to_string()),
+                ],
+            })))
+        );
+        assert_eq!(constraint.null_property(&props), None);
+
+        let partial = vec![("first".to_string(), Value::String("john".to_string()))];
+        assert_eq!(index.unique_key(&partial), None);
+        assert_eq!(constraint.null_property(&partial), Some("last"));
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,56 @@
+//! Materialized View Type Definition
+//!
+//! A materialized view stores the result of a read query so dashboards can read it
+//! without running the query again. The catalog keeps the definition; the rows live
+//! in memory and are rebuilt from the definition after a restart.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::Value;
+
+/// Materialized view definition stored in the catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaterializedViewInfo {
+    pub name: String,
+    /// Space the view belongs to
+    pub space_id: u64,
+    /// Query text the view was defined with
+    pub query: String,
+    /// Output columns, in order
+    pub columns: Vec<String>,
+    /// Whether data changes are applied as deltas rather than by a full refresh
+    pub incremental: bool,
+    /// Creation time
+    pub created_at: i64,
+}
+
+impl MaterializedViewInfo {
+    pub fn new(name: String, space_id: u64, query: String) -> Self {
+        Self {
+            name,
+            space_id,
+            query,
+            columns: Vec::new(),
+            incremental: false,
+            created_at: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+}
+
+/// Materialized rows of a view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaterializedViewData {
+    /// Result rows; bookkeeping columns may follow the visible ones
+    pub rows: Vec<Vec<Value>>,
+    /// Time of the last full refresh or delta
+    pub refreshed_at: i64,
+}
+
+impl MaterializedViewData {
+    pub fn new(rows: Vec<Vec<Value>>) -> Self {
+        Self {
+            rows,
+            refreshed_at: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+}
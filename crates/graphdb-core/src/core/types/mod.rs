@@ -1,3 +1,4 @@
+pub mod api_key;
 pub mod cluster;
 pub mod compact;
 pub mod data_modification;
@@ -7,6 +8,7 @@ pub mod expr;
 pub mod graph_schema;
 pub mod import_export;
 pub mod index;
+pub mod materialized_view;
 pub mod metadata_version;
 pub mod operators;
 pub mod property;
@@ -197,7 +199,9 @@ impl DataType {
 
 // Exporting Base Schema Types from Atomic Modules
 pub use self::edge::{EdgeStrategy, EdgeTypeInfo};
-pub use self::index::{Index, IndexConfig, IndexField, IndexStatus, IndexType};
+pub use self::index::{
+    Constraint, ConstraintKind, Index, IndexConfig, IndexField, IndexStatus, IndexType,
+};
 // Export full-text index types
 pub use self::index::{
     BM25IndexConfig, FulltextEngineType, FulltextIndexField, FulltextIndexOptions,
@@ -217,18 +221,20 @@ pub use self::tag::TagInfo;
 pub use self::metadata_version::{MetadataVersion, SchemaHistory, SchemaVersion};
 
 // Exporting types from split submodules
+pub use self::api_key::{ApiKeyInfo, API_KEY_PREFIX};
 pub use self::cluster::ClusterInfo;
 pub use self::compact::{CompactConfig, CompactError, CompactResult, CompactStats, CompactTarget, CompactionStrategy, AdaptiveCompactionConfig};
 pub use self::data_modification::{
     InsertEdgeInfo, InsertVertexInfo, UpdateInfo, UpdateOp, UpdateTarget,
 };
 pub use self::import_export::{ExportFormat, SchemaExportConfig, SchemaImportResult};
+pub use self::materialized_view::{MaterializedViewData, MaterializedViewInfo};
 pub use self::schema_change::{
     AlterTargetType, FieldChangeType, SchemaAlterOperation, SchemaChange, SchemaChangeType,
     SchemaFieldChange,
 };
 pub use self::space::CharsetInfo;
-pub use self::user::{PasswordInfo, UserAlterInfo, UserInfo};
+pub use self::user::{LoginCheck, PasswordInfo, PasswordPolicy, UserAlterInfo, UserInfo};
 
 pub use self::expr::{ContextualExpression, Expression, ExpressionMeta, SerializableExpression};
 pub use self::graph_schema::{
@@ -4,8 +4,8 @@
 
 use super::property_value::PropertyValue;
 use super::storage_ids::{
-    ColumnId, EdgeDeletionContext, EdgeId, EdgeIdentifier, EdgeKey, LabelId, Timestamp, VertexId,
-    VertexIdentifier,
+    ColumnId, EdgeDeletionContext, EdgeId, EdgeIdentifier, EdgeKey, LabelId, Timestamp,
+    TransactionId, VertexId, VertexIdentifier,
 };
 
 /// Undo log error
@@ -90,4 +90,16 @@ pub trait UndoTarget: Send + Sync {
         current_names: &[String],
         original_names: &[String],
     ) -> UndoLogResult<()>;
+
+    /// Hand back the unique index keys claimed by an aborted transaction.
+    ///
+    /// Unique keys are claimed when a statement writes them, so that concurrent
+    /// writers of the same key conflict; the claims outlive the statement and are
+    /// only settled when the transaction ends.
+    fn release_unique_claims(&self, _txn_id: TransactionId) -> UndoLogResult<()> {
+        Ok(())
+    }
+
+    /// Keep the unique index keys claimed by a committed transaction.
+    fn retain_unique_claims(&self, _txn_id: TransactionId) {}
 }
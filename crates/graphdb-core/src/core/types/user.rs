@@ -32,6 +32,15 @@ pub struct UserInfo {
     pub last_login_at: Option<i64>,
     /// Password last modified time
     pub password_changed_at: i64,
+    /// Consecutive failed logins since the last successful one
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    /// The password must be changed before any other statement runs
+    #[serde(default)]
+    pub password_expired: bool,
+    /// Hashes of previous passwords, most recent first
+    #[serde(default)]
+    pub password_history: Vec<String>,
 }
 
 impl UserInfo {
@@ -54,6 +63,9 @@ impl UserInfo {
             created_at: now,
             last_login_at: None,
             password_changed_at: now,
+            failed_login_attempts: 0,
+            password_expired: false,
+            password_history: Vec::new(),
         })
     }
 
@@ -62,18 +74,54 @@ impl UserInfo {
         bcrypt::verify(password, &self.password_hash).unwrap_or(false)
     }
 
-    /// change your password
+    /// Change the password after checking it against the policy and the password history
     pub fn change_password(
         &mut self,
-        new_password: String,
+        new_password: &str,
+        policy: &PasswordPolicy,
     ) -> Result<(), crate::core::StorageError> {
-        self.password_hash = bcrypt::hash(new_password, bcrypt::DEFAULT_COST).map_err(|e| {
+        policy
+            .check(new_password)
+            .map_err(crate::core::StorageError::db_error)?;
+
+        // The current password counts as the most recent entry of the history
+        if policy.history_size > 0 {
+            let reused = self.verify_password(new_password)
+                || self
+                    .password_history
+                    .iter()
+                    .take(policy.history_size - 1)
+                    .any(|hash| bcrypt::verify(new_password, hash).unwrap_or(false));
+            if reused {
+                return Err(crate::core::StorageError::db_error(format!(
+                    "Password must differ from the last {} passwords",
+                    policy.history_size
+                )));
+            }
+        }
+
+        let password_hash = bcrypt::hash(new_password, bcrypt::DEFAULT_COST).map_err(|e| {
             crate::core::StorageError::db_error(format!("Password encryption failed: {}", e))
         })?;
+        let previous = std::mem::replace(&mut self.password_hash, password_hash);
+        self.password_history.insert(0, previous);
+        self.password_history
+            .truncate(policy.history_size.saturating_sub(1));
         self.password_changed_at = chrono::Utc::now().timestamp_millis();
+        self.password_expired = false;
         Ok(())
     }
 
+    /// Whether the password must be changed before the user can run statements
+    pub fn is_password_expired(&self, policy: &PasswordPolicy, now_millis: i64) -> bool {
+        self.password_expired || policy.is_expired(self.password_changed_at, now_millis)
+    }
+
+    pub fn with_password_expired(mut self, expired: bool) -> Self {
+        self.password_expired = expired;
+        self
+    }
+
     pub fn with_locked(mut self, is_locked: bool) -> Self {
         self.is_locked = is_locked;
         self
@@ -103,6 +151,9 @@ impl UserInfo {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserAlterInfo {
     pub username: String,
+    /// New plaintext password, set by an administrator without the old one
+    #[serde(skip)]
+    pub password: Option<String>,
     /// New lock status
     pub is_locked: Option<bool>,
     /// New maximum number of queries per hour
@@ -119,6 +170,7 @@ impl UserAlterInfo {
     pub fn new(username: String) -> Self {
         Self {
             username,
+            password: None,
             is_locked: None,
             max_queries_per_hour: None,
             max_updates_per_hour: None,
@@ -132,3 +184,125 @@ impl UserAlterInfo {
         self
     }
 }
+
+/// Password rules applied whenever a password is set
+///
+/// The default policy accepts any password and never expires it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    /// Days after which a password must be changed (0 = never)
+    pub max_age_days: u64,
+    /// Number of most recent passwords that cannot be reused (0 = no history)
+    pub history_size: usize,
+}
+
+impl PasswordPolicy {
+    /// Check a new password against the length and complexity rules
+    pub fn check(&self, password: &str) -> Result<(), String> {
+        let mut missing = Vec::new();
+        if password.chars().count() < self.min_length {
+            missing.push(format!("at least {} characters", self.min_length));
+        }
+        if self.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+            missing.push("an uppercase letter".to_string());
+        }
+        if self.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+            missing.push("a lowercase letter".to_string());
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            missing.push("a digit".to_string());
+        }
+        if self.require_special && password.chars().all(|c| c.is_alphanumeric()) {
+            missing.push("a special character".to_string());
+        }
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Password does not meet the password policy: requires {}",
+                missing.join(", ")
+            ))
+        }
+    }
+
+    /// Whether a password changed at `changed_at_millis` has expired by `now_millis`
+    pub fn is_expired(&self, changed_at_millis: i64, now_millis: i64) -> bool {
+        let max_age_millis = (self.max_age_days as i64).saturating_mul(86_400_000);
+        self.max_age_days > 0 && now_millis.saturating_sub(changed_at_millis) >= max_age_millis
+    }
+}
+
+/// Outcome of a password login against a stored user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginCheck {
+    /// The password is correct; an expired password must be changed first
+    Accepted { password_expired: bool },
+    /// The password is wrong; `None` when failed logins are not limited
+    Rejected { remaining_attempts: Option<u32> },
+    /// The account is locked, either by an administrator or by failed logins
+    Locked,
+    /// No user with that name is stored
+    UnknownUser,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_policy_check() {
+        let policy = PasswordPolicy {
+            min_length: 8,
+            require_uppercase: true,
+            require_digit: true,
+            ..Default::default()
+        };
+
+        assert!(policy.check("Secret123").is_ok());
+        let error = policy.check("secret").expect_err("weak password");
+        assert!(error.contains("at least 8 characters"));
+        assert!(error.contains("an uppercase letter"));
+        assert!(error.contains("a digit"));
+        assert!(PasswordPolicy::default().check("x").is_ok());
+
+        let expiring = PasswordPolicy {
+            max_age_days: 30,
+            ..Default::default()
+        };
+        let day = 86_400_000;
+        assert!(!expiring.is_expired(0, 29 * day));
+        assert!(expiring.is_expired(0, 30 * day));
+        assert!(!PasswordPolicy::default().is_expired(0, i64::MAX));
+    }
+
+    #[test]
+    fn test_change_password_history() {
+        let policy = PasswordPolicy {
+            history_size: 2,
+            ..Default::default()
+        };
+        let mut user = UserInfo::new("alice".to_string(), "first".to_string())
+            .expect("UserInfo::new should succeed")
+            .with_password_expired(true);
+
+        assert!(user.change_password("first", &policy).is_err());
+        user.change_password("second", &policy)
+            .expect("new password should be accepted");
+        assert!(user.verify_password("second"));
+        assert!(!user.password_expired);
+        assert_eq!(user.password_history.len(), 1);
+
+        // Only the two most recent passwords are remembered
+        assert!(user.change_password("first", &policy).is_err());
+        user.change_password("third", &policy)
+            .expect("new password should be accepted");
+        user.change_password("first", &policy)
+            .expect("password outside the history should be accepted");
+    }
+}
@@ -1,12 +1,20 @@
 //! User Storage Manager
 //!
-//! Manages user account creation, modification, deletion, and role authorization.
+//! Manages user account creation, modification, deletion, and role authorization,
+//! including the custom roles that carry tag/edge/property level grants
+//! and the hashed API keys issued to users.
+//! Passwords are checked against the installed password policy, and failed
+//! logins lock the account once the configured limit is reached.
 //! This storage is in-memory by default and can be persisted to a JSON snapshot.
 
-use crate::core::types::{PasswordInfo, UserAlterInfo, UserInfo};
-use crate::core::{RoleType, StorageError, StorageResult};
+use crate::core::types::{
+    ApiKeyInfo, LoginCheck, PasswordInfo, PasswordPolicy, UserAlterInfo, UserInfo,
+};
+use crate::core::{
+    CustomRole, EffectivePrivileges, PrivilegeGrant, RoleType, StorageError, StorageResult,
+};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 use std::sync::Arc;
@@ -20,12 +28,26 @@ const USER_STORAGE_FILE_NAME: &str = "users.json";
 struct UserStorageSnapshot {
     version: u32,
     users: Vec<UserInfo>,
+    #[serde(default)]
+    roles: Vec<CustomRole>,
+    #[serde(default)]
+    user_roles: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    api_keys: Vec<ApiKeyInfo>,
 }
 
 /// Manages user accounts and role assignments in memory.
 #[derive(Clone)]
 pub struct UserStorage {
     users: Arc<RwLock<HashMap<String, UserInfo>>>,
+    /// Custom roles by name
+    roles: Arc<RwLock<HashMap<String, CustomRole>>>,
+    /// Custom roles assigned to each user
+    user_roles: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    /// API keys by key id
+    api_keys: Arc<RwLock<HashMap<String, ApiKeyInfo>>>,
+    /// Rules for new passwords; configuration, so not part of the snapshot
+    password_policy: Arc<RwLock<PasswordPolicy>>,
 }
 
 impl std::fmt::Debug for UserStorage {
@@ -47,22 +69,55 @@ impl UserStorage {
     pub fn new() -> Self {
         Self {
             users: Arc::new(RwLock::new(HashMap::new())),
+            roles: Arc::new(RwLock::new(HashMap::new())),
+            user_roles: Arc::new(RwLock::new(HashMap::new())),
+            api_keys: Arc::new(RwLock::new(HashMap::new())),
+            password_policy: Arc::new(RwLock::new(PasswordPolicy::default())),
         }
     }
 
+    /// Install the policy that new passwords are checked against.
+    pub fn set_password_policy(&self, policy: PasswordPolicy) {
+        *self.password_policy.write() = policy;
+    }
+
+    /// Check a password for a new user against the password policy.
+    pub fn check_password_policy(&self, password: &str) -> Result<(), StorageError> {
+        self.password_policy
+            .read()
+            .check(password)
+            .map_err(StorageError::db_error)
+    }
+
     fn snapshot(&self) -> UserStorageSnapshot {
         let mut users: Vec<UserInfo> = self.users.write().values().cloned().collect();
         users.sort_by(|left, right| left.username.cmp(&right.username));
+        let mut roles: Vec<CustomRole> = self.roles.read().values().cloned().collect();
+        roles.sort_by(|left, right| left.name.cmp(&right.name));
+        let user_roles = self
+            .user_roles
+            .read()
+            .iter()
+            .map(|(user, roles)| (user.clone(), roles.clone()))
+            .collect();
+        let mut api_keys: Vec<ApiKeyInfo> = self.api_keys.read().values().cloned().collect();
+        api_keys.sort_by(|left, right| left.created_at.cmp(&right.created_at));
 
         UserStorageSnapshot {
             version: USER_STORAGE_FORMAT_VERSION,
             users,
+            roles,
+            user_roles,
+            api_keys,
         }
     }
 
     /// Clear all users.
     pub fn clear(&self) {
         self.users.write().clear();
+        self.roles.write().clear();
+        self.user_roles.write().clear();
+        self.api_keys.write().clear();
     }
 
     /// Persist users to a directory snapshot.
@@ -123,7 +178,20 @@ impl UserStorage {
             }
         }
 
+        let roles = snapshot
+            .roles
+            .into_iter()
+            .map(|role| (role.name.clone(), role))
+            .collect();
+
         *self.users.write() = users;
+        *self.roles.write() = roles;
+        *self.user_roles.write() = snapshot.user_roles.into_iter().collect();
+        *self.api_keys.write() = snapshot
+            .api_keys
+            .into_iter()
+            .map(|key| (key.id.clone(), key))
+            .collect();
         Ok(())
     }
 
@@ -142,7 +210,8 @@ impl UserStorage {
                 ));
             }
             // Change to new password
-            user.change_password(info.new_password.clone())?;
+            let policy = self.password_policy.read().clone();
+            user.change_password(&info.new_password, &policy)?;
             Ok(true)
         } else {
             Err(StorageError::db_error(format!(
@@ -166,8 +235,15 @@ impl UserStorage {
     pub fn alter_user(&self, info: &UserAlterInfo) -> Result<bool, StorageError> {
         let mut users = self.users.write();
         if let Some(user) = users.get_mut(&info.username) {
+            if let Some(password) = &info.password {
+                let policy = self.password_policy.read().clone();
+                user.change_password(password, &policy)?;
+            }
             if let Some(is_locked) = info.is_locked {
                 user.is_locked = is_locked;
+                if !is_locked {
+                    user.failed_login_attempts = 0;
+                }
             }
             if let Some(limit) = info.max_queries_per_hour {
                 user.max_queries_per_hour = limit;
@@ -194,6 +270,10 @@ impl UserStorage {
     pub fn drop_user(&self, username: &str) -> Result<bool, StorageError> {
         let mut users = self.users.write();
         let existed = users.remove(username).is_some();
+        self.user_roles.write().remove(username);
+        self.api_keys
+            .write()
+            .retain(|_, key| key.username != username);
         Ok(existed)
     }
 
@@ -202,6 +282,49 @@ impl UserStorage {
         self.users.write().get(username).cloned()
     }
 
+    /// Check a password login, counting failures towards the account lockout.
+    ///
+    /// With `max_failed_attempts` of 0 failed logins are never counted. The
+    /// counter and the lock are stored with the user, so they survive restarts.
+    pub fn verify_login(
+        &self,
+        username: &str,
+        password: &str,
+        max_failed_attempts: u32,
+    ) -> LoginCheck {
+        let policy = self.password_policy.read().clone();
+        let mut users = self.users.write();
+        let Some(user) = users.get_mut(username) else {
+            return LoginCheck::UnknownUser;
+        };
+        if user.is_locked {
+            return LoginCheck::Locked;
+        }
+
+        if !user.verify_password(password) {
+            if max_failed_attempts == 0 {
+                return LoginCheck::Rejected {
+                    remaining_attempts: None,
+                };
+            }
+            user.failed_login_attempts = user.failed_login_attempts.saturating_add(1);
+            if user.failed_login_attempts >= max_failed_attempts {
+                user.is_locked = true;
+                return LoginCheck::Locked;
+            }
+            return LoginCheck::Rejected {
+                remaining_attempts: Some(max_failed_attempts - user.failed_login_attempts),
+            };
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        user.failed_login_attempts = 0;
+        user.last_login_at = Some(now);
+        LoginCheck::Accepted {
+            password_expired: user.is_password_expired(&policy, now),
+        }
+    }
+
     /// Check whether the user exists.
     pub fn user_exists(&self, username: &str) -> bool {
         self.users.write().contains_key(username)
@@ -237,6 +360,129 @@ impl UserStorage {
             )))
         }
     }
+
+    /// Create a custom role. Returns `false` if a role with that name already exists.
+    pub fn create_role(&self, role: CustomRole) -> Result<bool, StorageError> {
+        let mut roles = self.roles.write();
+        if roles.contains_key(&role.name) {
+            return Ok(false);
+        }
+        for parent in &role.parents {
+            if !roles.contains_key(parent) {
+                return Err(StorageError::db_error(format!("Role {} not found", parent)));
+            }
+        }
+        roles.insert(role.name.clone(), role);
+        Ok(true)
+    }
+
+    /// Get a custom role by name.
+    pub fn get_role(&self, name: &str) -> Option<CustomRole> {
+        self.roles.read().get(name).cloned()
+    }
+
+    /// Add a tag/edge privilege to a custom role.
+    pub fn grant_privilege(&self, role: &str, grant: PrivilegeGrant) -> Result<bool, StorageError> {
+        let mut roles = self.roles.write();
+        match roles.get_mut(role) {
+            Some(custom_role) => {
+                custom_role.add_grant(grant);
+                Ok(true)
+            }
+            None => Err(StorageError::db_error(format!("Role {} not found", role))),
+        }
+    }
+
+    /// Assign a custom role to a user.
+    pub fn grant_custom_role(&self, username: &str, role: &str) -> Result<bool, StorageError> {
+        if !self.user_exists(username) {
+            return Err(StorageError::db_error(format!(
+                "User {} not found",
+                username
+            )));
+        }
+        if !self.roles.read().contains_key(role) {
+            return Err(StorageError::db_error(format!("Role {} not found", role)));
+        }
+
+        let mut user_roles = self.user_roles.write();
+        let assigned = user_roles.entry(username.to_string()).or_default();
+        if !assigned.iter().any(|r| r == role) {
+            assigned.push(role.to_string());
+        }
+        Ok(true)
+    }
+
+    /// Custom roles assigned directly to a user, in assignment order.
+    pub fn user_custom_roles(&self, username: &str) -> Vec<CustomRole> {
+        let roles = self.roles.read();
+        self.user_roles
+            .read()
+            .get(username)
+            .map(|names| names.iter().filter_map(|n| roles.get(n).cloned()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Fine-grained privileges of a user, or `None` when the user holds no custom role.
+    pub fn effective_privileges(&self, username: &str) -> Option<EffectivePrivileges> {
+        let user_roles = self.user_roles.read();
+        let assigned = user_roles.get(username).filter(|r| !r.is_empty())?;
+        Some(EffectivePrivileges::resolve(assigned, &self.roles.read()))
+    }
+
+    /// Store an API key issued to an existing user.
+    pub fn create_api_key(&self, key: ApiKeyInfo) -> Result<bool, StorageError> {
+        if !self.user_exists(&key.username) {
+            return Err(StorageError::db_error(format!(
+                "User {} not found",
+                key.username
+            )));
+        }
+        let mut api_keys = self.api_keys.write();
+        if api_keys.contains_key(&key.id) {
+            return Ok(false);
+        }
+        api_keys.insert(key.id.clone(), key);
+        Ok(true)
+    }
+
+    /// Revoke an API key. Returns `false` if no such key exists.
+    pub fn revoke_api_key(&self, key_id: &str) -> Result<bool, StorageError> {
+        match self.api_keys.write().get_mut(key_id) {
+            Some(key) => {
+                key.revoked = true;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// API keys of one user, or of all users, oldest first.
+    pub fn list_api_keys(&self, username: Option<&str>) -> Vec<ApiKeyInfo> {
+        let mut keys: Vec<ApiKeyInfo> = self
+            .api_keys
+            .read()
+            .values()
+            .filter(|key| username.is_none_or(|name| key.username == name))
+            .cloned()
+            .collect();
+        keys.sort_by(|left, right| left.created_at.cmp(&right.created_at));
+        keys
+    }
+
+    /// Resolve a `gdb_<id>_<secret>` token to its key, if the key is active
+    /// and its user exists and is not locked.
+    pub fn verify_api_key(&self, token: &str) -> Option<ApiKeyInfo> {
+        let (key_id, secret) = ApiKeyInfo::parse_token(token)?;
+        let key = self.api_keys.read().get(key_id).cloned()?;
+        if !key.is_active() || !key.matches_secret(secret) {
+            return None;
+        }
+        match self.get_user(&key.username) {
+            Some(user) if !user.is_locked => Some(key),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -258,6 +504,9 @@ mod tests {
             created_at: 0,
             last_login_at: None,
             password_changed_at: 0,
+            failed_login_attempts: 0,
+            password_expired: false,
+            password_history: Vec::new(),
         };
 
         assert!(storage
@@ -280,6 +529,9 @@ mod tests {
             created_at: 0,
             last_login_at: None,
             password_changed_at: 0,
+            failed_login_attempts: 0,
+            password_expired: false,
+            password_history: Vec::new(),
         };
 
         storage
@@ -303,6 +555,9 @@ mod tests {
             created_at: 0,
             last_login_at: None,
             password_changed_at: 0,
+            failed_login_attempts: 0,
+            password_expired: false,
+            password_history: Vec::new(),
         };
 
         storage
@@ -328,6 +583,9 @@ mod tests {
             created_at: 0,
             last_login_at: None,
             password_changed_at: 0,
+            failed_login_attempts: 0,
+            password_expired: false,
+            password_history: Vec::new(),
         };
 
         storage
@@ -336,6 +594,7 @@ mod tests {
 
         let alter_info = UserAlterInfo {
             username: "test_user".to_string(),
+            password: None,
             is_locked: Some(true),
             max_queries_per_hour: Some(100),
             max_updates_per_hour: None,
@@ -354,6 +613,84 @@ mod tests {
         assert_eq!(updated_user.max_queries_per_hour, 100);
     }
 
+    #[test]
+    fn test_password_policy_and_lockout() {
+        let base_dir = std::env::temp_dir()
+            .join("graphdb_user_storage_test")
+            .join(format!("lockout_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base_dir);
+
+        let storage = UserStorage::new();
+        storage.set_password_policy(PasswordPolicy {
+            min_length: 8,
+            require_digit: true,
+            history_size: 3,
+            ..Default::default()
+        });
+        assert!(storage.check_password_policy("short").is_err());
+        assert!(storage.check_password_policy("longer123").is_ok());
+
+        let alice = UserInfo::new("alice".to_string(), "initial123".to_string())
+            .expect("UserInfo::new should succeed");
+        storage
+            .create_user(&alice)
+            .expect("create alice should succeed");
+
+        let change = |old: &str, new: &str| {
+            storage.change_password(&PasswordInfo {
+                username: Some("alice".to_string()),
+                old_password: old.to_string(),
+                new_password: new.to_string(),
+            })
+        };
+        assert!(change("initial123", "weak").is_err());
+        assert!(change("initial123", "initial123").is_err());
+        change("initial123", "second123").expect("valid change should succeed");
+
+        assert_eq!(
+            storage.verify_login("alice", "wrong", 2),
+            LoginCheck::Rejected {
+                remaining_attempts: Some(1)
+            }
+        );
+        assert_eq!(
+            storage.verify_login("alice", "wrong", 2),
+            LoginCheck::Locked
+        );
+        assert_eq!(
+            storage.verify_login("alice", "second123", 2),
+            LoginCheck::Locked
+        );
+        assert_eq!(
+            storage.verify_login("bob", "second123", 2),
+            LoginCheck::UnknownUser
+        );
+
+        // The lock survives a restart and is cleared by ACCOUNT UNLOCK
+        storage
+            .save_to_dir(&base_dir)
+            .expect("save_to_dir should succeed");
+        let restored = UserStorage::new();
+        restored
+            .load_from_dir(&base_dir)
+            .expect("load_from_dir should succeed");
+        assert_eq!(
+            restored.verify_login("alice", "second123", 2),
+            LoginCheck::Locked
+        );
+        restored
+            .alter_user(&UserAlterInfo::new("alice".to_string()).with_locked(false))
+            .expect("unlock should succeed");
+        assert_eq!(
+            restored.verify_login("alice", "second123", 2),
+            LoginCheck::Accepted {
+                password_expired: false
+            }
+        );
+
+        let _ = std::fs::remove_dir_all(&base_dir);
+    }
+
     #[test]
     fn test_grant_role_user_not_found() {
         let storage = UserStorage::new();
@@ -415,4 +752,127 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&base_dir);
     }
+
+    #[test]
+    fn test_custom_roles_round_trip() {
+        use crate::core::{PrivilegeAction, PrivilegeObject};
+
+        let base_dir = std::env::temp_dir()
+            .join("graphdb_user_storage_test")
+            .join(format!("custom_roles_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base_dir);
+
+        let storage = UserStorage::new();
+        let alice = UserInfo::new("alice".to_string(), "secret".to_string())
+            .expect("UserInfo::new should succeed");
+        storage
+            .create_user(&alice)
+            .expect("create alice should succeed");
+
+        let person = PrivilegeObject::Tag("person".to_string());
+        assert!(storage
+            .create_role(CustomRole::new("reader".to_string()))
+            .expect("create reader should succeed"));
+        assert!(storage
+            .create_role(
+                CustomRole::new("analyst".to_string()).with_parents(vec!["reader".to_string()])
+            )
+            .expect("create analyst should succeed"));
+        assert!(!storage
+            .create_role(CustomRole::new("reader".to_string()))
+            .expect("duplicate role should not fail"));
+        assert!(storage
+            .create_role(
+                CustomRole::new("orphan".to_string()).with_parents(vec!["missing".to_string()])
+            )
+            .is_err());
+
+        storage
+            .grant_privilege(
+                "reader",
+                PrivilegeGrant::new(PrivilegeAction::Read, person.clone())
+                    .with_properties(vec!["name".to_string()]),
+            )
+            .expect("grant should succeed");
+        assert!(storage.effective_privileges("alice").is_none());
+        storage
+            .grant_custom_role("alice", "analyst")
+            .expect("assign should succeed");
+        assert!(storage.grant_custom_role("nobody", "analyst").is_err());
+
+        storage
+            .save_to_dir(&base_dir)
+            .expect("save_to_dir should succeed");
+        let restored = UserStorage::new();
+        restored
+            .load_from_dir(&base_dir)
+            .expect("load_from_dir should succeed");
+
+        let privileges = restored
+            .effective_privileges("alice")
+            .expect("alice should hold custom roles");
+        assert!(privileges.allows_property(PrivilegeAction::Read, &person, "name"));
+        assert!(!privileges.allows_property(PrivilegeAction::Read, &person, "ssn"));
+        assert_eq!(restored.user_custom_roles("alice").len(), 1);
+
+        restored.drop_user("alice").expect("drop should succeed");
+        assert!(restored.effective_privileges("alice").is_none());
+
+        let _ = std::fs::remove_dir_all(&base_dir);
+    }
+
+    #[test]
+    fn test_api_keys_round_trip() {
+        use crate::core::Permission;
+
+        let base_dir = std::env::temp_dir()
+            .join("graphdb_user_storage_test")
+            .join(format!("api_keys_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base_dir);
+
+        let storage = UserStorage::new();
+        let alice = UserInfo::new("alice".to_string(), "secret".to_string())
+            .expect("UserInfo::new should succeed");
+        storage
+            .create_user(&alice)
+            .expect("create alice should succeed");
+
+        let (key, token) = ApiKeyInfo::generate("alice".to_string(), vec![Permission::Read], None);
+        let (orphan, _) = ApiKeyInfo::generate("nobody".to_string(), Vec::new(), None);
+        assert!(storage
+            .create_api_key(key.clone())
+            .expect("create key should succeed"));
+        assert!(storage.create_api_key(orphan).is_err());
+
+        storage
+            .save_to_dir(&base_dir)
+            .expect("save_to_dir should succeed");
+        let restored = UserStorage::new();
+        restored
+            .load_from_dir(&base_dir)
+            .expect("load_from_dir should succeed");
+
+        let verified = restored
+            .verify_api_key(&token)
+            .expect("key should verify after load");
+        assert_eq!(verified.username, "alice");
+        assert!(restored
+            .verify_api_key(&format!("gdb_{}_wrong", key.id))
+            .is_none());
+        assert_eq!(restored.list_api_keys(Some("alice")).len(), 1);
+        assert!(restored.list_api_keys(Some("bob")).is_empty());
+
+        assert!(restored
+            .revoke_api_key(&key.id)
+            .expect("revoke should succeed"));
+        assert!(restored.verify_api_key(&token).is_none());
+        assert!(!restored
+            .revoke_api_key("missing")
+            .expect("revoke should not fail"));
+
+        restored.drop_user("alice").expect("drop should succeed");
+        assert!(restored.list_api_keys(None).is_empty());
+
+        let _ = std::fs::remove_dir_all(&base_dir);
+    }
 }
@@ -8,6 +8,7 @@ use crate::core::{
     value::null::NullType,
     value::uuid::UuidValue,
     value::value_def::Value,
+    value::vector::VectorValue,
 };
 use chrono::{Datelike, Timelike};
 
@@ -617,6 +618,88 @@ impl Value {
         }
     }
 
+    /// Convert a JSON query parameter into a value
+    ///
+    /// Plain JSON maps onto the natural types: integers become BIGINT, other numbers
+    /// DOUBLE, arrays LIST and objects MAP. Types JSON cannot express are written as
+    /// single-key tagged objects: `{"$date": "2024-01-31"}`, `{"$time": "12:30:00"}`,
+    /// `{"$datetime": "2024-01-31T12:30:00"}`, `{"$interval": "1 day"}`,
+    /// `{"$uuid": "..."}` and `{"$vector": [0.1, 0.2]}`.
+    pub fn from_param_json(json: serde_json::Value) -> Result<Value, String> {
+        match json {
+            serde_json::Value::Null => Ok(Value::Null(NullType::Null)),
+            serde_json::Value::Bool(b) => Ok(Value::Bool(b)),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Ok(Value::BigInt(i)),
+                None => n
+                    .as_f64()
+                    .map(Value::Double)
+                    .ok_or_else(|| format!("Unsupported number: {}", n)),
+            },
+            serde_json::Value::String(s) => Ok(Value::String(s)),
+            serde_json::Value::Array(items) => {
+                let values = items
+                    .into_iter()
+                    .map(Value::from_param_json)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::list(List::from(values)))
+            }
+            serde_json::Value::Object(mut object) => {
+                if object.len() == 1 {
+                    let tag = object.keys().next().cloned().unwrap_or_default();
+                    if tag.starts_with('$') {
+                        let inner = object.remove(&tag).unwrap_or_default();
+                        return Self::from_tagged_param(&tag, inner);
+                    }
+                }
+
+                let map = object
+                    .into_iter()
+                    .map(|(key, value)| Value::from_param_json(value).map(|v| (key, v)))
+                    .collect::<Result<_, _>>()?;
+                Ok(Value::map(map))
+            }
+        }
+    }
+
+    fn from_tagged_param(tag: &str, inner: serde_json::Value) -> Result<Value, String> {
+        let text = || {
+            inner
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| format!("{} expects a string", tag))
+        };
+
+        let value = match tag {
+            "$date" => Self::parse_date_string(&text()?),
+            "$time" => Self::parse_time_string(&text()?),
+            "$datetime" => Self::parse_datetime_string(&text()?),
+            "$interval" => IntervalValue::parse(&text()?)
+                .map(Value::Interval)
+                .map_err(|e| format!("Invalid $interval: {}", e))?,
+            "$uuid" => UuidValue::parse_str(&text()?)
+                .map(Value::Uuid)
+                .map_err(|e| format!("Invalid $uuid: {}", e))?,
+            "$vector" => {
+                let items = inner
+                    .as_array()
+                    .ok_or_else(|| "$vector expects an array of numbers".to_string())?;
+                let data = items
+                    .iter()
+                    .map(|item| item.as_f64().map(|f| f as f32))
+                    .collect::<Option<Vec<f32>>>()
+                    .ok_or_else(|| "$vector expects an array of numbers".to_string())?;
+                Value::Vector(VectorValue::dense(data))
+            }
+            _ => return Err(format!("Unknown parameter type tag: {}", tag)),
+        };
+
+        if matches!(value, Value::Null(NullType::BadData)) {
+            return Err(format!("Invalid {} value: {}", tag, inner));
+        }
+        Ok(value)
+    }
+
     /// Try to cast this value to the target data type.
     /// Returns the converted value on success, or a type_mismatch error.
     /// Supports implicit type conversions that are allowed by TypeUtils::can_cast.
@@ -735,3 +818,53 @@ impl From<(i64, String)> for Value {
         ]))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_param_json_plain_types() {
+        assert_eq!(Value::from_param_json(json!(42)), Ok(Value::BigInt(42)));
+        assert_eq!(Value::from_param_json(json!(1.5)), Ok(Value::Double(1.5)));
+        assert_eq!(
+            Value::from_param_json(json!(null)),
+            Ok(Value::Null(NullType::Null))
+        );
+
+        let map = Value::from_param_json(json!({"ids": [1, 2], "ok": true}))
+            .expect("object should convert to a map");
+        match map {
+            Value::Map(map) => {
+                assert_eq!(map.get("ok"), Some(&Value::Bool(true)));
+                assert_eq!(
+                    map.get("ids"),
+                    Some(&Value::list(List::from(vec![
+                        Value::BigInt(1),
+                        Value::BigInt(2)
+                    ])))
+                );
+            }
+            other => panic!("expected map, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_param_json_tagged_types() {
+        assert_eq!(
+            Value::from_param_json(json!({"$date": "2024-01-31"})),
+            Ok(Value::Date(DateValue {
+                year: 2024,
+                month: 1,
+                day: 31
+            }))
+        );
+        assert_eq!(
+            Value::from_param_json(json!({"$vector": [0.5, 1.0]})),
+            Ok(Value::Vector(VectorValue::dense(vec![0.5, 1.0])))
+        );
+        assert!(Value::from_param_json(json!({"$date": "not a date"})).is_err());
+        assert!(Value::from_param_json(json!({"$unknown": 1})).is_err());
+    }
+}
@@ -157,6 +157,15 @@ pub struct RenameVertexPropRedo {
     pub new_name: String,
 }
 
+/// A property type change; `data_type` is the target type's display name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeVertexPropTypeRedo {
+    pub label: LabelId,
+    pub prop_name: String,
+    pub data_type: String,
+    pub allow_lossy: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RenameEdgePropRedo {
     pub src_label: LabelId,
@@ -6,10 +6,10 @@ use crate::core::error::StorageResult;
 use crate::core::types::{LabelId, Timestamp, VertexId};
 
 use super::redo::{
-    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ClearSpaceRedo, CreateEdgeTypeRedo,
-    CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo, DeleteEdgeRedo, DeleteEdgeTypeRedo,
-    DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo, InsertEdgeRedo, RenameEdgePropRedo,
-    RenameVertexPropRedo, UpdateEdgePropRedo,
+    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ChangeVertexPropTypeRedo,
+    ClearSpaceRedo, CreateEdgeTypeRedo, CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo,
+    DeleteEdgeRedo, DeleteEdgeTypeRedo, DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo,
+    InsertEdgeRedo, RenameEdgePropRedo, RenameVertexPropRedo, UpdateEdgePropRedo,
 };
 use super::types::WalResult;
 
@@ -130,6 +130,12 @@ pub trait RecoveryApplier {
         ts: Timestamp,
     ) -> StorageResult<()>;
 
+    fn replay_change_vertex_prop_type(
+        &self,
+        redo: &ChangeVertexPropTypeRedo,
+        ts: Timestamp,
+    ) -> StorageResult<()>;
+
     // ========================================================================
     // System Operations
     // ========================================================================
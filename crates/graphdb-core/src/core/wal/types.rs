@@ -115,6 +115,7 @@ pub enum WalOpType {
     DropSpace = 18,
     ClearSpace = 19,
     AlterSpaceComment = 20,
+    ChangeVertexPropType = 21,
 }
 
 impl TryFrom<u8> for WalOpType {
@@ -143,6 +144,7 @@ impl TryFrom<u8> for WalOpType {
             18 => Ok(WalOpType::DropSpace),
             19 => Ok(WalOpType::ClearSpace),
             20 => Ok(WalOpType::AlterSpaceComment),
+            21 => Ok(WalOpType::ChangeVertexPropType),
             _ => Err(WalError::InvalidOpType(value)),
         }
     }
@@ -172,6 +174,7 @@ impl fmt::Display for WalOpType {
             WalOpType::DropSpace => write!(f, "DropSpace"),
             WalOpType::ClearSpace => write!(f, "ClearSpace"),
             WalOpType::AlterSpaceComment => write!(f, "AlterSpaceComment"),
+            WalOpType::ChangeVertexPropType => write!(f, "ChangeVertexPropType"),
         }
     }
 }
@@ -370,18 +373,25 @@ pub enum RecordType {
 
 impl RecordType {
     pub fn from_u8(value: u8) -> Self {
+        Self::try_from_u8(value).unwrap_or(RecordType::Full)
+    }
+
+    /// Record type for a known value, `None` for anything else
+    pub fn try_from_u8(value: u8) -> Option<Self> {
         match value {
-            0 => RecordType::Full,
-            1 => RecordType::First,
-            2 => RecordType::Middle,
-            3 => RecordType::Last,
-            _ => RecordType::Full,
+            0 => Some(RecordType::Full),
+            1 => Some(RecordType::First),
+            2 => Some(RecordType::Middle),
+            3 => Some(RecordType::Last),
+            _ => None,
         }
     }
 }
 
 impl WalHeader {
     pub const SIZE: usize = WAL_HEADER_SIZE;
+    /// Byte offset of the record type within a serialized header
+    pub const RECORD_TYPE_OFFSET: usize = 6;
 
     pub fn new(op_type: WalOpType, timestamp: Timestamp, length: u32) -> Self {
         let is_update = matches!(
@@ -396,6 +406,7 @@ impl WalHeader {
                 | WalOpType::DeleteEdgeProp
                 | WalOpType::RenameVertexProp
                 | WalOpType::RenameEdgeProp
+                | WalOpType::ChangeVertexPropType
                 | WalOpType::Compact
         );
 
@@ -461,6 +472,14 @@ impl WalHeader {
         self.compression() != WalCompression::None
     }
 
+    /// Whether the flags hold only a known compression algorithm
+    pub fn has_valid_flags(&self) -> bool {
+        let compression = (self.flags & wal_flags::COMPRESSION_MASK) as u8;
+        self.flags & !wal_flags::COMPRESSION_MASK == 0
+            && (compression == WalCompression::None.flag_byte()
+                || compression == WalCompression::Zstd.flag_byte())
+    }
+
     pub fn lsn(&self) -> Lsn {
         Lsn(self.lsn)
     }
@@ -0,0 +1,362 @@
+//! Derive macros for the embedded GraphDB API
+//!
+//! `#[derive(GraphTag)]` maps a struct to a tag and `#[derive(GraphEdge)]` maps a struct
+//! to an edge type. The generated impls of `graphdb::api::embedded::GraphTag` and
+//! `graphdb::api::embedded::GraphEdge` carry the schema of the struct, convert it to
+//! and from stored vertices and edges, and add a `&'static str` constant for each
+//! property (`Person::NAME` for a field `name`), so that queries built from these
+//! constants are checked by the compiler.
+//!
+//! # Attributes
+//!
+//! On the struct:
+//! - `#[graph(tag = "person")]` / `#[graph(edge = "follow")]` - schema name, defaults to
+//!   the struct name
+//! - `#[graph(crate = "graphdb_api")]` - path of the crate that exports the embedded API,
+//!   defaults to `graphdb`
+//!
+//! On a field:
+//! - `#[graph(vid)]` - the vertex ID of a tag, required once
+//! - `#[graph(src)]`, `#[graph(dst)]` - the endpoints of an edge, both required
+//! - `#[graph(rank)]` - the `i64` rank of an edge, 0 when absent
+//! - `#[graph(rename = "...")]` - property name, defaults to the field name
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Path, Type};
+
+/// Derive `GraphTag` for a struct with named fields
+#[proc_macro_derive(GraphTag, attributes(graph))]
+pub fn derive_graph_tag(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::Tag)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `GraphEdge` for a struct with named fields
+#[proc_macro_derive(GraphEdge, attributes(graph))]
+pub fn derive_graph_edge(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::Edge)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Tag,
+    Edge,
+}
+
+impl Kind {
+    fn name_key(self) -> &'static str {
+        match self {
+            Kind::Tag => "tag",
+            Kind::Edge => "edge",
+        }
+    }
+}
+
+/// What a field of the struct stands for
+#[derive(PartialEq, Eq)]
+enum Role {
+    Vid,
+    Src,
+    Dst,
+    Rank,
+    Property(String),
+}
+
+struct Field {
+    ident: Ident,
+    ty: Type,
+    role: Role,
+}
+
+/// Attributes of the struct
+struct Container {
+    name: String,
+    krate: Path,
+}
+
+fn parse_container(input: &DeriveInput, kind: Kind) -> syn::Result<Container> {
+    let mut name = None;
+    let mut krate = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("graph"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(kind.name_key()) {
+                let value: LitStr = meta.value()?.parse()?;
+                name = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                let value: LitStr = meta.value()?.parse()?;
+                krate = Some(value.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error(format!(
+                    "expected `{} = \"...\"` or `crate = \"...\"`",
+                    kind.name_key()
+                )))
+            }
+        })?;
+    }
+    Ok(Container {
+        name: name.unwrap_or_else(|| input.ident.to_string()),
+        krate: match krate {
+            Some(krate) => krate,
+            None => syn::parse_quote!(::graphdb),
+        },
+    })
+}
+
+fn parse_fields(input: &DeriveInput, kind: Kind) -> syn::Result<Vec<Field>> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "only structs with named fields can be mapped to a schema",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs can be mapped to a schema",
+            ))
+        }
+    };
+
+    let mut fields = Vec::with_capacity(named.len());
+    for field in named {
+        let ident = field.ident.clone().expect("named field");
+        let mut role = None;
+        let mut rename = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("graph"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let found = if meta.path.is_ident("vid") && kind == Kind::Tag {
+                    Role::Vid
+                } else if meta.path.is_ident("src") && kind == Kind::Edge {
+                    Role::Src
+                } else if meta.path.is_ident("dst") && kind == Kind::Edge {
+                    Role::Dst
+                } else if meta.path.is_ident("rank") && kind == Kind::Edge {
+                    Role::Rank
+                } else if meta.path.is_ident("rename") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    rename = Some(value.value());
+                    return Ok(());
+                } else {
+                    return Err(meta.error(match kind {
+                        Kind::Tag => "expected `vid` or `rename = \"...\"`",
+                        Kind::Edge => "expected `src`, `dst`, `rank` or `rename = \"...\"`",
+                    }));
+                };
+                if role.replace(found).is_some() {
+                    return Err(meta.error("a field can only have one role"));
+                }
+                Ok(())
+            })?;
+        }
+        let role = match role {
+            Some(role) => role,
+            None => Role::Property(rename.unwrap_or_else(|| unraw(&ident))),
+        };
+        fields.push(Field {
+            ident,
+            ty: field.ty.clone(),
+            role,
+        });
+    }
+    Ok(fields)
+}
+
+/// The single field with `role`, or an error naming the missing attribute
+fn find_role<'a>(
+    input: &DeriveInput,
+    fields: &'a [Field],
+    role: Role,
+    attribute: &str,
+    required: bool,
+) -> syn::Result<Option<&'a Field>> {
+    let mut found = fields.iter().filter(|field| field.role == role);
+    let first = found.next();
+    if let Some(second) = found.next() {
+        return Err(syn::Error::new_spanned(
+            &second.ident,
+            format!("only one field can be marked `#[graph({})]`", attribute),
+        ));
+    }
+    if first.is_none() && required {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("one field must be marked `#[graph({})]`", attribute),
+        ));
+    }
+    Ok(first)
+}
+
+fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    name.strip_prefix("r#").map(str::to_string).unwrap_or(name)
+}
+
+fn expand(input: &DeriveInput, kind: Kind) -> syn::Result<TokenStream2> {
+    let container = parse_container(input, kind)?;
+    let fields = parse_fields(input, kind)?;
+
+    let krate = &container.krate;
+    let typed = quote!(#krate::api::embedded::typed);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let schema_name = &container.name;
+
+    let properties: Vec<(&Field, &String)> = fields
+        .iter()
+        .filter_map(|field| match &field.role {
+            Role::Property(name) => Some((field, name)),
+            _ => None,
+        })
+        .collect();
+    let property_defs = properties.iter().map(|(field, name)| {
+        let ty = &field.ty;
+        quote! {
+            #typed::PropertyDef {
+                name: #name,
+                data_type: <#ty as #typed::PropertyValue>::DATA_TYPE,
+                nullable: <#ty as #typed::PropertyValue>::NULLABLE,
+            }
+        }
+    });
+    let property_entries: Vec<TokenStream2> = properties
+        .iter()
+        .map(|(field, name)| {
+            let field = &field.ident;
+            quote!((#name.to_string(), #typed::PropertyValue::to_value(&self.#field)))
+        })
+        .collect();
+    // Structs without properties only check that the vertex has the tag (the edge its type)
+    let properties_binding = if properties.is_empty() {
+        quote!(_)
+    } else {
+        quote!(properties)
+    };
+    let property_reads: Vec<TokenStream2> = properties
+        .iter()
+        .map(|(field, name)| {
+            let field = &field.ident;
+            quote!(#field: #typed::decode_property(properties, #name)?)
+        })
+        .collect();
+    let property_consts = properties.iter().map(|(field, name)| {
+        let constant = format_ident!("{}", unraw(&field.ident).to_uppercase());
+        let doc = format!("Name of the `{}` property", name);
+        quote! {
+            #[doc = #doc]
+            pub const #constant: &'static str = #name;
+        }
+    });
+
+    let trait_impl = match kind {
+        Kind::Tag => {
+            let vid = find_role(input, &fields, Role::Vid, "vid", true)?
+                .expect("required role")
+                .ident
+                .clone();
+            let vid_name = unraw(&vid);
+            quote! {
+                impl #impl_generics #typed::GraphTag for #ident #ty_generics #where_clause {
+                    const TAG_NAME: &'static str = #schema_name;
+                    const PROPERTIES: &'static [#typed::PropertyDef] = &[#(#property_defs),*];
+
+                    fn vid(&self) -> #typed::Value {
+                        #typed::PropertyValue::to_value(&self.#vid)
+                    }
+
+                    fn properties(&self) -> #typed::HashMap<String, #typed::Value> {
+                        #typed::HashMap::from([#(#property_entries),*])
+                    }
+
+                    fn from_vertex(vertex: &#typed::Vertex) -> #typed::CoreResult<Self> {
+                        let #properties_binding = #typed::tag_properties(vertex, #schema_name)?;
+                        Ok(Self {
+                            #vid: #typed::decode_vid(&vertex.vid, #vid_name)?,
+                            #(#property_reads,)*
+                        })
+                    }
+                }
+            }
+        }
+        Kind::Edge => {
+            let src = find_role(input, &fields, Role::Src, "src", true)?
+                .expect("required role")
+                .ident
+                .clone();
+            let dst = find_role(input, &fields, Role::Dst, "dst", true)?
+                .expect("required role")
+                .ident
+                .clone();
+            let rank = find_role(input, &fields, Role::Rank, "rank", false)?;
+            let (rank_value, rank_read) = match rank {
+                Some(field) => {
+                    let rank = &field.ident;
+                    (quote!(self.#rank), quote!(#rank: edge.ranking,))
+                }
+                None => (quote!(0), quote!()),
+            };
+            let src_name = unraw(&src);
+            let dst_name = unraw(&dst);
+            quote! {
+                impl #impl_generics #typed::GraphEdge for #ident #ty_generics #where_clause {
+                    const EDGE_TYPE: &'static str = #schema_name;
+                    const PROPERTIES: &'static [#typed::PropertyDef] = &[#(#property_defs),*];
+
+                    fn src(&self) -> #typed::Value {
+                        #typed::PropertyValue::to_value(&self.#src)
+                    }
+
+                    fn dst(&self) -> #typed::Value {
+                        #typed::PropertyValue::to_value(&self.#dst)
+                    }
+
+                    fn rank(&self) -> i64 {
+                        #rank_value
+                    }
+
+                    fn properties(&self) -> #typed::HashMap<String, #typed::Value> {
+                        #typed::HashMap::from([#(#property_entries),*])
+                    }
+
+                    fn from_edge(edge: &#typed::Edge) -> #typed::CoreResult<Self> {
+                        let #properties_binding = #typed::edge_properties(edge, #schema_name)?;
+                        Ok(Self {
+                            #src: #typed::decode_vid(&edge.src, #src_name)?,
+                            #dst: #typed::decode_vid(&edge.dst, #dst_name)?,
+                            #rank_read
+                            #(#property_reads,)*
+                        })
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #trait_impl
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#property_consts)*
+        }
+    })
+}
@@ -0,0 +1,886 @@
+//! Materialized View Maintenance
+//!
+//! Keeps the rows of materialized views in step with data changes. The catalog entry
+//! and the rows live in the schema manager; this module decides which views a write
+//! statement touches and how their rows are brought up to date:
+//!
+//! - Views over a single node pattern or a one-hop path whose RETURN clause only
+//!   projects columns, or groups them under `count` and `sum`, are maintained
+//!   incrementally. The view query is run restricted to the vertices the statement
+//!   writes, once before and once after the write, and the difference is applied to
+//!   the stored rows.
+//! - Any other view, and any write whose vertices are not known up front, marks the
+//!   view stale. A stale view is fully refreshed the next time it is read.
+//!
+//! Grouped views keep a hidden `count(*)` column so that a group disappears once its
+//! last row is gone.
+
+use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use super::invalidation::{CacheKey, DependencyTracker};
+use crate::core::types::expr::{ContextualExpression, Expression, ExpressionMeta};
+use crate::core::types::operators::{AggregateFunction, BinaryOperator};
+use crate::core::types::MaterializedViewInfo;
+use crate::core::Value;
+use crate::query::parser::ast::pattern::{NodePattern, PathElement, Pattern};
+use crate::query::parser::ast::stmt::{
+    Ast, CreateTarget, DeleteTarget, InsertTarget, MatchStmt, ReturnItem, Stmt, UpdateTarget,
+};
+use crate::query::parser::Parser;
+use crate::query::validator::context::ExpressionAnalysisContext;
+
+/// Name of the hidden row count kept by grouped views
+pub const HIDDEN_COUNT_COLUMN: &str = "__mv_count";
+
+/// Dependency of views that read vertices or edges of any tag or edge type
+const ANY_LABEL: &str = "*";
+
+/// Aggregate a view column is maintained with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+    Count,
+    Sum,
+}
+
+/// Analysed definition of a materialized view
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewDefinition {
+    /// Query text the view was defined with
+    pub query: String,
+    /// Tags and edge types the view reads; `*` when it reads any
+    pub labels: Vec<String>,
+    /// Node variables every row is anchored on; set when the view is maintained incrementally
+    pub anchors: Option<Vec<String>>,
+    /// Aggregate of each output column; `None` for projected columns and group keys
+    pub aggregates: Vec<Option<AggregateKind>>,
+}
+
+impl ViewDefinition {
+    /// Parse and analyse a view query
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let ast = parse_query(query)?;
+        match &ast.stmt {
+            Stmt::Match(match_stmt) => Ok(Self::analyze(query, match_stmt)),
+            other => Err(format!(
+                "A materialized view must be defined by a MATCH query, found {}",
+                other.kind()
+            )),
+        }
+    }
+
+    fn analyze(query: &str, stmt: &MatchStmt) -> Self {
+        let mut labels = Vec::new();
+        for pattern in &stmt.patterns {
+            collect_labels(pattern, &mut labels);
+        }
+
+        let items = stmt
+            .return_clause
+            .as_ref()
+            .map(|clause| clause.items.as_slice())
+            .unwrap_or_default();
+        let aggregates: Option<Vec<Option<AggregateKind>>> =
+            items.iter().map(Self::item_aggregate).collect();
+
+        let reads_subqueries = !stmt.where_clause.as_ref().is_none_or(is_plain)
+            || items.iter().any(|item| {
+                let ReturnItem::Expression { expression, .. } = item;
+                !is_plain(expression)
+            });
+        if reads_subqueries && !labels.iter().any(|label| label == ANY_LABEL) {
+            labels.push(ANY_LABEL.to_string());
+        }
+
+        let anchors = match &aggregates {
+            Some(_) if !reads_subqueries => Self::anchors(stmt),
+            _ => None,
+        };
+
+        Self {
+            query: query.to_string(),
+            labels,
+            anchors,
+            aggregates: aggregates.unwrap_or_else(|| vec![None; items.len()]),
+        }
+    }
+
+    /// Aggregate of a RETURN item; `None` when the item cannot be maintained incrementally
+    fn item_aggregate(item: &ReturnItem) -> Option<Option<AggregateKind>> {
+        let ReturnItem::Expression { expression, .. } = item;
+        match expression.get_expression()? {
+            Expression::Aggregate {
+                func: AggregateFunction::Count(_),
+                distinct: false,
+                ..
+            } => Some(Some(AggregateKind::Count)),
+            Expression::Aggregate {
+                func: AggregateFunction::Sum(_),
+                distinct: false,
+                ..
+            } => Some(Some(AggregateKind::Sum)),
+            expr if expr.contains_aggregate() => None,
+            _ => Some(None),
+        }
+    }
+
+    /// Node variables of a single node pattern or one-hop path
+    fn anchors(stmt: &MatchStmt) -> Option<Vec<String>> {
+        if stmt.optional
+            || stmt.delete_clause.is_some()
+            || stmt.order_by.is_some()
+            || stmt.limit.is_some()
+            || stmt.skip.is_some()
+        {
+            return None;
+        }
+        let clause = stmt.return_clause.as_ref()?;
+        if clause.distinct
+            || clause.order_by.is_some()
+            || clause.limit.is_some()
+            || clause.skip.is_some()
+            || clause.sample.is_some()
+            || clause.having_clause.is_some()
+        {
+            return None;
+        }
+
+        match stmt.patterns.as_slice() {
+            [Pattern::Node(node)] => Some(vec![node_anchor(node)?]),
+            [Pattern::Path(path)] => match path.elements.as_slice() {
+                [PathElement::Node(src), PathElement::Edge(edge), PathElement::Node(dst)]
+                    if edge.range.is_none()
+                        && edge.properties.as_ref().is_none_or(is_plain)
+                        && edge.predicates.iter().all(is_plain) =>
+                {
+                    Some(vec![node_anchor(src)?, node_anchor(dst)?])
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether data changes are applied as deltas rather than by a full refresh
+    pub fn is_incremental(&self) -> bool {
+        self.anchors.is_some()
+    }
+
+    /// Whether the view groups its rows under aggregates
+    pub fn is_grouped(&self) -> bool {
+        self.aggregates.iter().any(Option::is_some)
+    }
+
+    /// Statement computing the stored rows of the view
+    ///
+    /// Incrementally maintained grouped views gain the hidden row count. With `ids`,
+    /// only rows anchored on one of the given vertices are computed.
+    pub fn statement(&self, ids: Option<&[Value]>) -> Result<Arc<Ast>, String> {
+        let mut ast = (*parse_query(&self.query)?).clone();
+        let context = ast.expr_context.clone();
+        let Stmt::Match(match_stmt) = &mut ast.stmt else {
+            return Err("A materialized view must be defined by a MATCH query".to_string());
+        };
+
+        if self.is_incremental() && self.is_grouped() {
+            if let Some(clause) = match_stmt.return_clause.as_mut() {
+                let count = Expression::aggregate(
+                    AggregateFunction::Count(None),
+                    Expression::literal(Value::String("*".to_string())),
+                    false,
+                );
+                clause.items.push(ReturnItem::Expression {
+                    expression: contextual(&context, count),
+                    alias: Some(HIDDEN_COUNT_COLUMN.to_string()),
+                });
+            }
+        }
+
+        if let (Some(ids), Some(anchors)) = (ids, &self.anchors) {
+            let id_list = Expression::list(ids.iter().cloned().map(Expression::Literal).collect());
+            let restriction = anchors
+                .iter()
+                .map(|anchor| {
+                    Expression::binary(
+                        Expression::function("id", vec![Expression::variable(anchor)]),
+                        BinaryOperator::In,
+                        id_list.clone(),
+                    )
+                })
+                .reduce(|left, right| Expression::binary(left, BinaryOperator::Or, right))
+                .ok_or_else(|| "The view has no anchor variable".to_string())?;
+            let condition = match match_stmt
+                .where_clause
+                .as_ref()
+                .and_then(|clause| clause.get_expression())
+            {
+                Some(existing) => Expression::binary(existing, BinaryOperator::And, restriction),
+                None => restriction,
+            };
+            match_stmt.where_clause = Some(contextual(&context, condition));
+        }
+
+        Ok(Arc::new(ast))
+    }
+
+    /// Apply the rows computed before and after a write to the stored rows
+    ///
+    /// `before` and `after` are the results of the restricted statement. An error means
+    /// the stored rows are out of step with the data and the view needs a full refresh.
+    pub fn apply_delta(
+        &self,
+        stored: &[Vec<Value>],
+        before: Vec<Vec<Value>>,
+        after: Vec<Vec<Value>>,
+    ) -> Result<Vec<Vec<Value>>, String> {
+        if self.is_grouped() {
+            self.merge_groups(stored, before, after)
+        } else {
+            replace_rows(stored, before, after)
+        }
+    }
+
+    fn merge_groups(
+        &self,
+        stored: &[Vec<Value>],
+        before: Vec<Vec<Value>>,
+        after: Vec<Vec<Value>>,
+    ) -> Result<Vec<Vec<Value>>, String> {
+        let hidden = self.aggregates.len();
+        if stored
+            .iter()
+            .chain(&before)
+            .chain(&after)
+            .any(|row| row.len() != hidden + 1)
+        {
+            return Err("Grouped view rows lack the hidden row count".to_string());
+        }
+
+        let keys: Vec<usize> = (0..hidden)
+            .filter(|&i| self.aggregates[i].is_none())
+            .collect();
+        let folded: Vec<usize> = (0..hidden)
+            .filter(|&i| self.aggregates[i].is_some())
+            .chain(std::iter::once(hidden))
+            .collect();
+        let key_of =
+            |row: &[Value]| -> Vec<Value> { keys.iter().map(|&i| row[i].clone()).collect() };
+
+        let mut rows = stored.to_vec();
+        let mut index: HashMap<Vec<Value>, usize> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| (key_of(row), i))
+            .collect();
+
+        for row in &before {
+            let i = *index
+                .get(&key_of(row))
+                .ok_or_else(|| "A group of the view is missing from its rows".to_string())?;
+            for &column in &folded {
+                rows[i][column] = fold(&rows[i][column], &row[column], Value::sub)?;
+            }
+        }
+        for row in after {
+            match index.get(&key_of(&row)) {
+                Some(&i) => {
+                    for &column in &folded {
+                        rows[i][column] = fold(&rows[i][column], &row[column], Value::add)?;
+                    }
+                }
+                None => {
+                    index.insert(key_of(&row), rows.len());
+                    rows.push(row);
+                }
+            }
+        }
+
+        // Without group keys the query always returns its single row, even over no data
+        if !keys.is_empty() {
+            rows.retain(|row| !is_zero(&row[hidden]));
+        }
+        Ok(rows)
+    }
+}
+
+/// Data written by a statement
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ViewChange {
+    /// Tags and edge types written; `None` when any may be
+    pub labels: Option<Vec<String>>,
+    /// Vertices written, both endpoints for edges; `None` when not known up front
+    pub ids: Option<Vec<Value>>,
+}
+
+impl ViewChange {
+    fn new(labels: Option<Vec<String>>, ids: Option<Vec<Value>>) -> Self {
+        Self { labels, ids }
+    }
+
+    /// Change of a statement whose targets are not known up front
+    pub fn unknown() -> Self {
+        Self::default()
+    }
+
+    /// Data written by a statement; `None` when the statement writes no data
+    pub fn from_stmt(stmt: &Stmt) -> Option<Self> {
+        let change = match stmt {
+            Stmt::Insert(insert) => match &insert.target {
+                InsertTarget::Vertices { tags, values } => Self::new(
+                    Some(tags.iter().map(|tag| tag.tag_name.clone()).collect()),
+                    literal_ids(values.iter().map(|row| &row.vid)),
+                ),
+                InsertTarget::Edge {
+                    edge_name, edges, ..
+                } => Self::new(
+                    Some(vec![edge_name.clone()]),
+                    literal_ids(edges.iter().flat_map(|(src, dst, _, _)| [src, dst])),
+                ),
+            },
+            Stmt::Delete(delete) => match &delete.target {
+                DeleteTarget::Vertices(vids) => Self::new(None, literal_ids(vids)),
+                DeleteTarget::Edges { edge_type, edges } => Self::new(
+                    edge_type.clone().map(|edge_type| vec![edge_type]),
+                    literal_ids(edges.iter().flat_map(|(src, dst, _)| [src, dst])),
+                ),
+                DeleteTarget::Tags {
+                    tag_names,
+                    vertex_ids,
+                    is_all_tags,
+                } => Self::new(
+                    (!is_all_tags).then(|| tag_names.clone()),
+                    literal_ids(vertex_ids),
+                ),
+                DeleteTarget::Index(_) => return None,
+            },
+            Stmt::Update(update) => match &update.target {
+                UpdateTarget::Vertex(vid) => Self::new(None, literal_ids([vid])),
+                UpdateTarget::Edge {
+                    src,
+                    dst,
+                    edge_type,
+                    ..
+                } => Self::new(
+                    edge_type.clone().map(|edge_type| vec![edge_type]),
+                    literal_ids([src, dst]),
+                ),
+                UpdateTarget::Tag(tag_name) => Self::new(Some(vec![tag_name.clone()]), None),
+                UpdateTarget::TagOnVertex { vid, tag_name } => {
+                    Self::new(Some(vec![tag_name.clone()]), literal_ids([&**vid]))
+                }
+            },
+            Stmt::Create(create) => match &create.target {
+                CreateTarget::Node { labels, .. } => Self::new(Some(labels.clone()), None),
+                CreateTarget::Edge {
+                    edge_type,
+                    src,
+                    dst,
+                    ..
+                } => Self::new(Some(vec![edge_type.clone()]), literal_ids([src, dst])),
+                CreateTarget::Path { patterns } => {
+                    let mut labels = Vec::new();
+                    for pattern in patterns {
+                        collect_labels(pattern, &mut labels);
+                    }
+                    // Unlabelled nodes of a created path are existing vertices or carry no tag
+                    labels.retain(|label| label != ANY_LABEL);
+                    Self::new(Some(labels), None)
+                }
+                _ => return None,
+            },
+            Stmt::Match(match_stmt) if match_stmt.delete_clause.is_some() => Self::unknown(),
+            Stmt::Merge(_)
+            | Stmt::Set(_)
+            | Stmt::Remove(_)
+            | Stmt::Foreach(_)
+            | Stmt::Call(_)
+            | Stmt::CallSubquery(_)
+            | Stmt::Pipe(_)
+            | Stmt::Query(_)
+            | Stmt::Drop(_)
+            | Stmt::Alter(_)
+            | Stmt::ClearSpace(_)
+            | Stmt::RollbackTransaction(_) => Self::unknown(),
+            _ => return None,
+        };
+        Some(change)
+    }
+}
+
+/// Materialized view manager
+///
+/// Caches the analysis of view definitions and tracks which tags and edge types each
+/// view reads. Shared by all sessions through the optimizer engine.
+#[derive(Debug, Default)]
+pub struct MaterializedViewManager {
+    definitions: RwLock<HashMap<CacheKey, Arc<ViewDefinition>>>,
+    dependencies: DependencyTracker,
+}
+
+impl MaterializedViewManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analysis of a view, kept until the view is dropped or redefined
+    pub fn definition(&self, info: &MaterializedViewInfo) -> Result<Arc<ViewDefinition>, String> {
+        let key = view_key(info.space_id, &info.name);
+        if let Some(definition) = self.definitions.read().get(&key) {
+            if definition.query == info.query {
+                return Ok(definition.clone());
+            }
+        }
+
+        let definition = Arc::new(ViewDefinition::parse(&info.query)?);
+        self.dependencies.remove_key(&key);
+        self.dependencies
+            .register_dependencies(key.clone(), definition.labels.clone());
+        self.definitions.write().insert(key, definition.clone());
+        Ok(definition)
+    }
+
+    /// Drop the cached analysis of a view
+    pub fn forget(&self, space_id: u64, name: &str) {
+        let key = view_key(space_id, name);
+        self.definitions.write().remove(&key);
+        self.dependencies.remove_key(&key);
+    }
+
+    /// Views among `views` whose rows a change may alter
+    pub fn affected_views(
+        &self,
+        views: &[MaterializedViewInfo],
+        change: &ViewChange,
+    ) -> Vec<MaterializedViewInfo> {
+        // Analysing every view registers the dependencies of views not seen since startup;
+        // views that cannot be analysed are refreshed on every change.
+        let mut keys: HashSet<CacheKey> = views
+            .iter()
+            .filter(|info| self.definition(info).is_err())
+            .map(|info| view_key(info.space_id, &info.name))
+            .collect();
+
+        let Some(labels) = &change.labels else {
+            return views.to_vec();
+        };
+        keys.extend(self.dependencies.get_dependent_keys(ANY_LABEL));
+        for label in labels {
+            keys.extend(self.dependencies.get_dependent_keys(label));
+        }
+
+        views
+            .iter()
+            .filter(|info| keys.contains(&view_key(info.space_id, &info.name)))
+            .cloned()
+            .collect()
+    }
+
+    /// View defined by the given query text, so the query can be answered from its rows
+    ///
+    /// Matching is on the text only: whitespace outside string literals and a trailing
+    /// semicolon are ignored, but a query that differs in any other way, such as a renamed
+    /// variable or a different literal, does not match even when it is equivalent.
+    pub fn find_view_for_query<'a>(
+        &self,
+        views: &'a [MaterializedViewInfo],
+        query_text: &str,
+    ) -> Option<&'a MaterializedViewInfo> {
+        let query = normalize_query(query_text);
+        views
+            .iter()
+            .find(|info| normalize_query(&info.query) == query)
+    }
+}
+
+fn view_key(space_id: u64, name: &str) -> CacheKey {
+    format!("{}/{}", space_id, name)
+}
+
+fn parse_query(query: &str) -> Result<Arc<Ast>, String> {
+    Parser::new(query)
+        .parse()
+        .map(|result| result.ast)
+        .map_err(|e| e.to_string())
+}
+
+fn contextual(context: &Arc<ExpressionAnalysisContext>, expr: Expression) -> ContextualExpression {
+    let id = context.register_expression(ExpressionMeta::new(expr));
+    ContextualExpression::new(id, context.clone())
+}
+
+/// Whether an expression can be evaluated on a single row, without pattern subqueries
+fn is_plain(expression: &ContextualExpression) -> bool {
+    expression.get_expression().is_some_and(|expr| {
+        expr.find(&|e| matches!(e, Expression::PatternSubquery(_)))
+            .is_none()
+    })
+}
+
+fn node_anchor(node: &NodePattern) -> Option<String> {
+    if node.properties.as_ref().is_none_or(is_plain) && node.predicates.iter().all(is_plain) {
+        node.variable.clone()
+    } else {
+        None
+    }
+}
+
+fn collect_labels(pattern: &Pattern, labels: &mut Vec<String>) {
+    match pattern {
+        Pattern::Node(node) => push_labels(&node.labels, labels),
+        Pattern::Edge(edge) => push_labels(&edge.edge_types, labels),
+        Pattern::Path(path) => {
+            for element in &path.elements {
+                collect_element_labels(element, labels);
+            }
+        }
+        Pattern::Variable(_) => push_labels(&[], labels),
+    }
+}
+
+fn collect_element_labels(element: &PathElement, labels: &mut Vec<String>) {
+    match element {
+        PathElement::Node(node) => push_labels(&node.labels, labels),
+        PathElement::Edge(edge) => push_labels(&edge.edge_types, labels),
+        PathElement::Alternative(patterns) => {
+            for pattern in patterns {
+                collect_labels(pattern, labels);
+            }
+        }
+        PathElement::Optional(inner) | PathElement::Repeated(inner, _) => {
+            collect_element_labels(inner, labels)
+        }
+    }
+}
+
+fn push_labels(names: &[String], labels: &mut Vec<String>) {
+    let any = [ANY_LABEL.to_string()];
+    let names = if names.is_empty() { &any[..] } else { names };
+    for name in names {
+        if !labels.contains(name) {
+            labels.push(name.clone());
+        }
+    }
+}
+
+/// Literal vertex ids, `None` when any of them is computed
+fn literal_ids<'a>(
+    exprs: impl IntoIterator<Item = &'a ContextualExpression>,
+) -> Option<Vec<Value>> {
+    let mut ids = Vec::new();
+    for expr in exprs {
+        match expr.get_expression()? {
+            Expression::Literal(value) => {
+                if !ids.contains(&value) {
+                    ids.push(value);
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(ids)
+}
+
+/// Remove the `before` rows from the stored rows and append the `after` rows
+fn replace_rows(
+    stored: &[Vec<Value>],
+    before: Vec<Vec<Value>>,
+    after: Vec<Vec<Value>>,
+) -> Result<Vec<Vec<Value>>, String> {
+    let mut removed: HashMap<&Vec<Value>, usize> = HashMap::new();
+    for row in &before {
+        *removed.entry(row).or_default() += 1;
+    }
+
+    let mut rows = Vec::with_capacity(stored.len() + after.len());
+    for row in stored {
+        match removed.get_mut(row) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => rows.push(row.clone()),
+        }
+    }
+    if removed.values().any(|&count| count > 0) {
+        return Err("A row of the view is missing from its rows".to_string());
+    }
+
+    rows.extend(after);
+    Ok(rows)
+}
+
+/// Add or subtract an aggregate delta; null stands for an empty input
+fn fold(
+    current: &Value,
+    delta: &Value,
+    op: fn(&Value, &Value) -> Result<Value, String>,
+) -> Result<Value, String> {
+    match (current, delta) {
+        (_, Value::Null(_)) => Ok(current.clone()),
+        (Value::Null(_), _) => op(&Value::BigInt(0), delta),
+        _ => op(current, delta),
+    }
+}
+
+fn is_zero(value: &Value) -> bool {
+    matches!(value, Value::SmallInt(0) | Value::Int(0) | Value::BigInt(0))
+}
+
+/// Collapse whitespace outside string literals and drop a trailing semicolon
+fn normalize_query(query: &str) -> String {
+    let query = query.trim().trim_end_matches(';').trim_end();
+    let mut normalized = String::with_capacity(query.len());
+    let mut quote = None;
+    let mut pending_space = false;
+    for c in query.chars() {
+        match quote {
+            Some(q) => {
+                normalized.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c.is_whitespace() => pending_space = true,
+            None => {
+                if pending_space {
+                    normalized.push(' ');
+                    pending_space = false;
+                }
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+                normalized.push(c);
+            }
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(query: &str) -> ViewDefinition {
+        ViewDefinition::parse(query).expect("view query should parse")
+    }
+
+    fn statement(query: &str) -> Stmt {
+        parse_query(query)
+            .expect("statement should parse")
+            .stmt
+            .clone()
+    }
+
+    fn info(name: &str, query: &str) -> MaterializedViewInfo {
+        MaterializedViewInfo::new(name.to_string(), 1, query.to_string())
+    }
+
+    #[test]
+    fn test_view_shapes() {
+        let view = definition("MATCH (p:person) WHERE p.age > 30 RETURN p.name AS name");
+        assert_eq!(view.labels, vec!["person".to_string()]);
+        assert_eq!(view.anchors, Some(vec!["p".to_string()]));
+        assert!(!view.is_grouped());
+
+        let view = definition(
+            "MATCH (a:person)-[e:knows]->(b:person) RETURN a.city AS city, count(*) AS total",
+        );
+        assert_eq!(view.labels, vec!["person".to_string(), "knows".to_string()]);
+        assert_eq!(view.anchors, Some(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(view.aggregates, vec![None, Some(AggregateKind::Count)]);
+
+        let view = definition("MATCH (p:person) RETURN p.city AS city, max(p.age) AS oldest");
+        assert!(!view.is_incremental());
+
+        let view = definition("MATCH (p) RETURN p.name AS name ORDER BY name");
+        assert_eq!(view.labels, vec![ANY_LABEL.to_string()]);
+        assert!(!view.is_incremental());
+
+        assert!(ViewDefinition::parse("GO FROM 1 OVER knows").is_err());
+    }
+
+    #[test]
+    fn test_restricted_statement() {
+        let view = definition(
+            "MATCH (p:person) WHERE p.age > 30 RETURN p.city AS city, sum(p.age) AS ages",
+        );
+        let ast = view
+            .statement(Some(&[Value::BigInt(1), Value::BigInt(2)]))
+            .expect("restricted statement should build");
+        let Stmt::Match(match_stmt) = &ast.stmt else {
+            panic!("Expected a MATCH statement");
+        };
+
+        let condition = match_stmt
+            .where_clause
+            .as_ref()
+            .and_then(|clause| clause.get_expression())
+            .expect("restriction should be added");
+        assert!(matches!(
+            condition,
+            Expression::Binary {
+                op: BinaryOperator::And,
+                ..
+            }
+        ));
+
+        let items = &match_stmt
+            .return_clause
+            .as_ref()
+            .expect("RETURN clause should be kept")
+            .items;
+        assert_eq!(items.len(), 3);
+        let ReturnItem::Expression { alias, .. } = &items[2];
+        assert_eq!(alias.as_deref(), Some(HIDDEN_COUNT_COLUMN));
+    }
+
+    #[test]
+    fn test_apply_delta_to_rows() {
+        let view = definition("MATCH (p:person) RETURN p.name AS name");
+        let stored = vec![
+            vec![Value::from("a")],
+            vec![Value::from("b")],
+            vec![Value::from("b")],
+        ];
+
+        let rows = view
+            .apply_delta(
+                &stored,
+                vec![vec![Value::from("b")]],
+                vec![vec![Value::from("c")]],
+            )
+            .expect("delta should apply");
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::from("a")],
+                vec![Value::from("b")],
+                vec![Value::from("c")],
+            ]
+        );
+
+        assert!(view
+            .apply_delta(&stored, vec![vec![Value::from("z")]], Vec::new())
+            .is_err());
+    }
+
+    #[test]
+    fn test_apply_delta_to_groups() {
+        let view = definition("MATCH (p:person) RETURN p.city AS city, count(*) AS total");
+        let stored = vec![
+            vec![Value::from("x"), Value::BigInt(2), Value::BigInt(2)],
+            vec![Value::from("y"), Value::BigInt(1), Value::BigInt(1)],
+        ];
+
+        // One person moves from y to z
+        let rows = view
+            .apply_delta(
+                &stored,
+                vec![vec![Value::from("y"), Value::BigInt(1), Value::BigInt(1)]],
+                vec![vec![Value::from("z"), Value::BigInt(1), Value::BigInt(1)]],
+            )
+            .expect("delta should apply");
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::from("x"), Value::BigInt(2), Value::BigInt(2)],
+                vec![Value::from("z"), Value::BigInt(1), Value::BigInt(1)],
+            ]
+        );
+
+        // One more person in x
+        let rows = view
+            .apply_delta(
+                &rows,
+                Vec::new(),
+                vec![vec![Value::from("x"), Value::BigInt(1), Value::BigInt(1)]],
+            )
+            .expect("delta should apply");
+        assert_eq!(
+            rows[0],
+            vec![Value::from("x"), Value::BigInt(3), Value::BigInt(3)]
+        );
+    }
+
+    #[test]
+    fn test_change_of_statements() {
+        let change = ViewChange::from_stmt(&statement(
+            "INSERT VERTEX person(name) VALUES 1:('a'), 2:('b')",
+        ))
+        .expect("INSERT writes data");
+        assert_eq!(change.labels, Some(vec!["person".to_string()]));
+        assert_eq!(change.ids.map(|ids| ids.len()), Some(2));
+
+        let change = ViewChange::from_stmt(&statement("DELETE EDGE knows 1 -> 2"))
+            .expect("DELETE writes data");
+        assert_eq!(change.labels, Some(vec!["knows".to_string()]));
+        assert_eq!(change.ids.map(|ids| ids.len()), Some(2));
+
+        assert!(ViewChange::from_stmt(&statement("MATCH (p:person) RETURN p")).is_none());
+    }
+
+    #[test]
+    fn test_manager_tracks_dependencies() {
+        let manager = MaterializedViewManager::new();
+        let views = vec![
+            info("people", "MATCH (p:person) RETURN p.name AS name"),
+            info(
+                "friends",
+                "MATCH (a:person)-[e:knows]->(b:person) RETURN count(*) AS total",
+            ),
+            info("anything", "MATCH (n) RETURN count(*) AS total"),
+        ];
+
+        let change = ViewChange::new(Some(vec!["knows".to_string()]), None);
+        let mut affected: Vec<String> = manager
+            .affected_views(&views, &change)
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        affected.sort();
+        assert_eq!(
+            affected,
+            vec!["anything".to_string(), "friends".to_string()]
+        );
+
+        assert_eq!(
+            manager.affected_views(&views, &ViewChange::unknown()).len(),
+            3
+        );
+
+        manager.forget(1, "friends");
+        assert!(manager.dependencies.get_dependent_keys("knows").is_empty());
+    }
+
+    #[test]
+    fn test_find_view_for_query() {
+        let manager = MaterializedViewManager::new();
+        let views = vec![info(
+            "names",
+            "MATCH (p:person) WHERE p.name <> 'a  b' RETURN p.name AS name",
+        )];
+
+        assert!(manager
+            .find_view_for_query(
+                &views,
+                "MATCH  (p:person)\n WHERE p.name <> 'a  b'   RETURN p.name AS name;"
+            )
+            .is_some());
+        assert!(manager
+            .find_view_for_query(
+                &views,
+                "MATCH (p:person) WHERE p.name <> 'a b' RETURN p.name AS name"
+            )
+            .is_none());
+        assert!(manager
+            .find_view_for_query(
+                &views,
+                "MATCH (n:person) WHERE n.name <> 'a  b' RETURN n.name AS name"
+            )
+            .is_none());
+        assert!(manager
+            .find_view_for_query(
+                &views,
+                "match (p:person) where p.name <> 'a  b' return p.name as name"
+            )
+            .is_none());
+    }
+}
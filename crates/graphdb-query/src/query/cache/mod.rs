@@ -3,6 +3,7 @@
 //! Provide a unified cache management function, including:
 //! Query plan cache (Prepared Statement)
 //! CTE result caching
+//! Materialized view maintenance
 //! Other cache types that may be added in the future:
 //!
 //! # Design Goals
@@ -20,6 +21,7 @@
 //! - `invalidation`: Cache invalidation strategies
 //! - `plan_cache`: Query plan cache (Prepared Statement style)
 //! - `cte_cache`: CTE result cache
+//! - `materialized_view`: Incremental maintenance of materialized views
 //! - `manager`: Unified cache manager
 //! - `warmup`: Cache warmup functionality
 
@@ -28,6 +30,7 @@ pub mod config;
 pub mod cte_cache;
 pub mod invalidation;
 pub mod manager;
+pub mod materialized_view;
 pub mod plan_cache;
 pub mod stats;
 pub mod warmup;
@@ -58,6 +61,11 @@ pub use plan_cache::{
 // Re-export the CTE cache types
 pub use cte_cache::{CteCacheDecision, CteCacheDecisionMaker, CteCacheEntry, CteCacheManager};
 
+// Re-export the materialized view types
+pub use materialized_view::{
+    AggregateKind, MaterializedViewManager, ViewChange, ViewDefinition, HIDDEN_COUNT_COLUMN,
+};
+
 // Re-export the manager types
 pub use manager::{CacheManager, CacheStatsSummary, GlobalCacheManager, GlobalCacheStats};
 
@@ -48,21 +48,30 @@ pub struct PlanCacheKey {
     pub hash: u64,
     /// Query text (for conflict detection, not just debugging)
     query_text: String,
+    /// Space the plan was built for; the same text plans differently per space
+    space_id: Option<u64>,
 }
 
 impl PlanCacheKey {
     /// Creating Cache Keys from Query Text
     pub fn from_query(query: &str) -> Self {
+        Self::for_space(query, None)
+    }
+
+    /// Creating Cache Keys from Query Text planned in a space
+    pub fn for_space(query: &str, space_id: Option<u64>) -> Self {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
         let mut hasher = DefaultHasher::new();
         query.hash(&mut hasher);
+        space_id.hash(&mut hasher);
         let hash = hasher.finish();
 
         Self {
             hash,
             query_text: query.to_string(),
+            space_id,
         }
     }
 
@@ -75,6 +84,11 @@ impl PlanCacheKey {
     pub fn query_text(&self) -> &str {
         &self.query_text
     }
+
+    /// Space the plan was built for
+    pub fn space_id(&self) -> Option<u64> {
+        self.space_id
+    }
 }
 
 /// Cached query plan entries
@@ -251,8 +265,15 @@ impl QueryPlanCache {
     /// - `Some(Arc<CachedPlan>)`: Cached plan
     /// - `None`: No results were found, or there was a hash collision.
     pub fn get(&self, query: &str) -> Option<Arc<CachedPlan>> {
-        let key = PlanCacheKey::from_query(query);
+        self.get_by_key(PlanCacheKey::from_query(query), query)
+    }
+
+    /// Obtaining the plan cached for a query in a space, see [`Self::put_prepared`]
+    pub fn get_prepared(&self, query: &str, space_id: Option<u64>) -> Option<Arc<CachedPlan>> {
+        self.get_by_key(PlanCacheKey::for_space(query, space_id), query)
+    }
 
+    fn get_by_key(&self, key: PlanCacheKey, query: &str) -> Option<Arc<CachedPlan>> {
         if let Some(plan) = self.cache.get(&key) {
             if plan.query_template != query {
                 log::warn!(
@@ -307,6 +328,35 @@ impl QueryPlanCache {
         dependent_tables: Vec<String>,
     ) {
         let key = PlanCacheKey::from_query(query);
+        self.insert_plan(key, query, plan, param_positions, dependent_tables);
+    }
+
+    /// Put a prepared plan in the cache.
+    ///
+    /// The plan keeps its `$name` placeholders; each execution binds its own values.
+    /// The plan is keyed by the query text and `space_id`, and `dependent_tables`
+    /// names the tags and edge types it reads, so that schema changes to them
+    /// evict it through [`Self::invalidate_by_table`].
+    pub fn put_prepared(
+        &self,
+        query: &str,
+        space_id: Option<u64>,
+        plan: ExecutionPlan,
+        param_positions: Vec<ParamPosition>,
+        dependent_tables: Vec<String>,
+    ) {
+        let key = PlanCacheKey::for_space(query, space_id);
+        self.insert_plan(key, query, plan, param_positions, dependent_tables);
+    }
+
+    fn insert_plan(
+        &self,
+        key: PlanCacheKey,
+        query: &str,
+        plan: ExecutionPlan,
+        param_positions: Vec<ParamPosition>,
+        dependent_tables: Vec<String>,
+    ) {
         let query_bytes = query.len();
 
         let priority = if self.config.priority_config.enable_priority {
@@ -407,6 +457,7 @@ impl QueryPlanCache {
             PlanNodeEnum::HashLeftJoin(_) => 55,
             PlanNodeEnum::FullOuterJoin(_) => 60,
             PlanNodeEnum::SemiJoin(_) => 40,
+            PlanNodeEnum::MultiwayJoin(_) => 60,
 
             // Traversal nodes
             PlanNodeEnum::Expand(_) => 25,
@@ -431,6 +482,9 @@ impl QueryPlanCache {
             PlanNodeEnum::DataCollect(_) => 15,
             PlanNodeEnum::Remove(_) => 15,
             PlanNodeEnum::PatternApply(_) => 35,
+            PlanNodeEnum::PatternSubquery(_) => 35,
+            PlanNodeEnum::CallSubquery(_) => 40,
+            PlanNodeEnum::Foreach(_) => 40,
             PlanNodeEnum::RollUpApply(_) => 35,
             PlanNodeEnum::Union(_) => 25,
             PlanNodeEnum::Minus(_) => 30,
@@ -471,11 +525,20 @@ impl QueryPlanCache {
             // Stats nodes
             PlanNodeEnum::ShowStats(_) => 10,
 
+            // Procedure nodes
+            PlanNodeEnum::ProcedureCall(_) => 20,
+
+            // Materialized view nodes
+            PlanNodeEnum::MaterializedViewScan(_) => 5,
+
             // Full-text search nodes
             PlanNodeEnum::FulltextSearch(_) => 30,
             PlanNodeEnum::FulltextLookup(_) => 25,
             PlanNodeEnum::MatchFulltext(_) => 30,
 
+            // Hybrid search nodes
+            PlanNodeEnum::HybridSearch(_) => 40,
+
             // Vector search nodes
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => 35,
@@ -512,8 +575,20 @@ impl QueryPlanCache {
     /// - `query`: Query content
     /// - `execution_time_ms`: Execution time (in milliseconds)
     pub fn record_execution(&self, query: &str, execution_time_ms: f64) {
-        let key = PlanCacheKey::from_query(query);
+        self.record_execution_by_key(PlanCacheKey::from_query(query), execution_time_ms);
+    }
 
+    /// Record the statistics on the execution of a prepared plan.
+    pub fn record_prepared_execution(
+        &self,
+        query: &str,
+        space_id: Option<u64>,
+        execution_time_ms: f64,
+    ) {
+        self.record_execution_by_key(PlanCacheKey::for_space(query, space_id), execution_time_ms);
+    }
+
+    fn record_execution_by_key(&self, key: PlanCacheKey, execution_time_ms: f64) {
         if let Some(plan) = self.cache.get(&key) {
             let alpha = 0.1;
             let new_avg = plan.avg_execution_time_ms * (1.0 - alpha) + execution_time_ms * alpha;
@@ -547,6 +622,47 @@ impl QueryPlanCache {
         removed
     }
 
+    /// Invalidate the plans of a space that depend on a tag or edge type
+    ///
+    /// # Returns
+    /// The number of plans removed
+    pub fn invalidate_by_table(&self, space_id: Option<u64>, table_name: &str) -> usize {
+        self.invalidate_where(|key, plan| {
+            key.space_id == space_id && plan.dependent_tables.iter().any(|t| t == table_name)
+        })
+    }
+
+    /// Invalidate all plans built for a space
+    ///
+    /// # Returns
+    /// The number of plans removed
+    pub fn invalidate_space(&self, space_id: Option<u64>) -> usize {
+        self.invalidate_where(|key, _| key.space_id == space_id)
+    }
+
+    fn invalidate_where(&self, predicate: impl Fn(&PlanCacheKey, &CachedPlan) -> bool) -> usize {
+        let keys_to_remove: Vec<Arc<PlanCacheKey>> = self
+            .cache
+            .iter()
+            .filter(|(key, plan)| predicate(key, plan))
+            .map(|(key, _)| key)
+            .collect();
+
+        let mut count = 0;
+        for key in keys_to_remove {
+            if self.cache.remove(key.as_ref()).is_some() {
+                self.stats.counters.record_eviction();
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            self.update_stats();
+        }
+
+        count
+    }
+
     /// Get cache entries for eviction (internal use)
     pub fn get_cache_entries(&self) -> Vec<(Arc<PlanCacheKey>, f64, usize)> {
         self.cache
@@ -737,6 +853,35 @@ mod tests {
         assert_eq!(cache.len(), 0);
     }
 
+    #[test]
+    fn test_prepared_plans_keyed_by_space() {
+        let cache = QueryPlanCache::default();
+        let query = "MATCH (p:Person) WHERE p.age > $age RETURN p";
+        let put = |space_id, tables: &[&str]| {
+            cache.put_prepared(
+                query,
+                space_id,
+                ExecutionPlan::new(None),
+                Vec::new(),
+                tables.iter().map(|t| t.to_string()).collect(),
+            )
+        };
+        put(Some(1), &["Person"]);
+        put(Some(2), &["Person", "KNOWS"]);
+
+        assert!(cache.get(query).is_none());
+        assert!(cache.get_prepared(query, Some(1)).is_some());
+        assert!(cache.get_prepared(query, Some(3)).is_none());
+
+        assert_eq!(cache.invalidate_by_table(Some(1), "KNOWS"), 0);
+        assert_eq!(cache.invalidate_by_table(Some(1), "Person"), 1);
+        assert!(cache.get_prepared(query, Some(1)).is_none());
+        assert!(cache.get_prepared(query, Some(2)).is_some());
+
+        assert_eq!(cache.invalidate_space(Some(2)), 1);
+        assert!(cache.get_prepared(query, Some(2)).is_none());
+    }
+
     #[test]
     fn test_cache_priority_ordering() {
         assert!(CachePriority::Critical > CachePriority::High);
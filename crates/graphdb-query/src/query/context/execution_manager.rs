@@ -4,6 +4,7 @@
 
 use crate::query::planning::plan::ExecutionPlan;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// Query Execution Manager
 ///
@@ -15,8 +16,8 @@ pub struct QueryExecutionManager {
     /// Execution Plan
     plan: Option<Box<ExecutionPlan>>,
 
-    /// Has it been marked as terminated?
-    killed: AtomicBool,
+    /// Has it been marked as terminated? Shared with the executors, which poll it
+    killed: Arc<AtomicBool>,
 }
 
 impl QueryExecutionManager {
@@ -24,7 +25,7 @@ impl QueryExecutionManager {
     pub fn new() -> Self {
         Self {
             plan: None,
-            killed: AtomicBool::new(false),
+            killed: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -54,6 +55,11 @@ impl QueryExecutionManager {
         self.killed.load(Ordering::SeqCst)
     }
 
+    /// Flag set when the query is killed, for executors that run without the query context
+    pub fn kill_flag(&self) -> Arc<AtomicBool> {
+        self.killed.clone()
+    }
+
     /// Reset the Execution Manager
     pub fn reset(&mut self) {
         self.plan = None;
@@ -30,6 +30,7 @@
 //! - Expression evaluation with temporary values
 //! - Graph traversal with path accumulation
 
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 use crate::core::types::{CharsetInfo, SpaceInfo};
@@ -199,6 +200,11 @@ impl QueryContext {
         self.execution_manager.is_killed()
     }
 
+    /// Flag set when the query is killed, handed to the executors
+    pub fn kill_flag(&self) -> Arc<AtomicBool> {
+        self.execution_manager.kill_flag()
+    }
+
     /// Check whether the parameters exist.
     pub fn exist_parameter(&self, param: &str) -> bool {
         self.rctx.get_parameter(param).is_some()
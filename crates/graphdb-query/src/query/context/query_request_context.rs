@@ -2,7 +2,7 @@
 //!
 //! This module provides the minimum amount of contextual information required to execute the query, thereby avoiding the need for the query layer to rely on the API layer.
 
-use crate::core::Value;
+use crate::core::{RoleType, Value};
 use std::collections::HashMap;
 
 /// Query request context – Simplified version
@@ -13,6 +13,7 @@ use std::collections::HashMap;
 /// - Graph Space Name
 /// - Query string
 /// - Query parameters
+/// - Role of the session user in the space
 #[derive(Debug, Clone, Default)]
 pub struct QueryRequestContext {
     /// Session ID
@@ -25,6 +26,9 @@ pub struct QueryRequestContext {
     pub query: String,
     /// Query parameters
     pub parameters: HashMap<String, Value>,
+    /// Role the session user holds in the space, checked against the mode of
+    /// called procedures; `None` when the caller enforces no roles
+    pub role: Option<RoleType>,
 }
 
 impl QueryRequestContext {
@@ -36,6 +40,7 @@ impl QueryRequestContext {
             space_name: None,
             query,
             parameters: HashMap::new(),
+            role: None,
         }
     }
 
@@ -63,6 +68,12 @@ impl QueryRequestContext {
         self
     }
 
+    /// Set the role of the session user
+    pub fn with_role(mut self, role: RoleType) -> Self {
+        self.role = Some(role);
+        self
+    }
+
     /// Obtain parameters
     pub fn get_parameter(&self, param: &str) -> Option<Value> {
         self.parameters.get(param).cloned()
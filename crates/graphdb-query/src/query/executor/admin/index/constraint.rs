@@ -0,0 +1,289 @@
+//! Constraint Executor
+//!
+//! Provide functions for creating, deleting, and listing tag constraints.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::core::types::Constraint;
+use crate::core::Value;
+use crate::query::executor::base::{BaseExecutor, ExecutionResult, Executor, HasStorage};
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+
+use crate::storage::StorageClient;
+
+/// Create a Constraint Executor
+#[derive(Debug)]
+pub struct CreateConstraintExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    space_name: String,
+    constraint: Constraint,
+    if_not_exists: bool,
+}
+
+impl<S: StorageClient> CreateConstraintExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: String,
+        constraint: Constraint,
+        if_not_exists: bool,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "CreateConstraintExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+            constraint,
+            if_not_exists,
+        }
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for CreateConstraintExecutor<S> {
+    fn execute(&mut self) -> crate::query::executor::base::DBResult<ExecutionResult> {
+        let storage = self.get_storage();
+        let mut storage_guard = storage.write();
+
+        let result = storage_guard.create_constraint(&self.space_name, &self.constraint);
+
+        match result {
+            Ok(true) => Ok(ExecutionResult::Success),
+            Ok(false) => {
+                if self.if_not_exists {
+                    Ok(ExecutionResult::Success)
+                } else {
+                    Ok(ExecutionResult::Error(format!(
+                        "Constraint '{}' already exists",
+                        self.constraint.name
+                    )))
+                }
+            }
+            Err(e) => Ok(ExecutionResult::Error(format!(
+                "Failed to create constraint: {}",
+                e
+            ))),
+        }
+    }
+
+    fn open(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.open()
+    }
+    fn close(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.close()
+    }
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+    fn name(&self) -> &str {
+        "CreateConstraintExecutor"
+    }
+    fn description(&self) -> &str {
+        "Creates a tag constraint"
+    }
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for CreateConstraintExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+/// Delete the Constraint Executor
+#[derive(Debug)]
+pub struct DropConstraintExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    space_name: String,
+    constraint_name: String,
+    if_exists: bool,
+}
+
+impl<S: StorageClient> DropConstraintExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: String,
+        constraint_name: String,
+        if_exists: bool,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "DropConstraintExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+            constraint_name,
+            if_exists,
+        }
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for DropConstraintExecutor<S> {
+    fn execute(&mut self) -> crate::query::executor::base::DBResult<ExecutionResult> {
+        let storage = self.get_storage();
+        let mut storage_guard = storage.write();
+
+        let result = storage_guard.drop_constraint(&self.space_name, &self.constraint_name);
+
+        match result {
+            Ok(true) => Ok(ExecutionResult::Success),
+            Ok(false) => {
+                if self.if_exists {
+                    Ok(ExecutionResult::Success)
+                } else {
+                    Ok(ExecutionResult::Error(format!(
+                        "Constraint '{}' not found",
+                        self.constraint_name
+                    )))
+                }
+            }
+            Err(e) => Ok(ExecutionResult::Error(format!(
+                "Failed to drop constraint: {}",
+                e
+            ))),
+        }
+    }
+
+    fn open(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.open()
+    }
+    fn close(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.close()
+    }
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+    fn name(&self) -> &str {
+        "DropConstraintExecutor"
+    }
+    fn description(&self) -> &str {
+        "Drops a tag constraint"
+    }
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for DropConstraintExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+/// List the constraint executor
+#[derive(Debug)]
+pub struct ShowConstraintsExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    space_name: String,
+}
+
+impl<S: StorageClient> ShowConstraintsExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: String,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "ShowConstraintsExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+        }
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for ShowConstraintsExecutor<S> {
+    fn execute(&mut self) -> crate::query::executor::base::DBResult<ExecutionResult> {
+        let storage = self.get_storage();
+        let storage_guard = storage.read();
+
+        match storage_guard.list_constraints(&self.space_name) {
+            Ok(constraints) => {
+                let rows: Vec<Vec<Value>> = constraints
+                    .iter()
+                    .map(|constraint| {
+                        vec![
+                            Value::String(constraint.name.clone()),
+                            Value::String(constraint.schema_name.clone()),
+                            Value::String(constraint.kind.to_string()),
+                            Value::String(constraint.properties.join(", ")),
+                        ]
+                    })
+                    .collect();
+
+                let dataset = DataSet {
+                    col_names: vec![
+                        "Constraint Name".to_string(),
+                        "Tag Name".to_string(),
+                        "Type".to_string(),
+                        "Properties".to_string(),
+                    ],
+                    rows,
+                };
+                Ok(ExecutionResult::DataSet(dataset))
+            }
+            Err(e) => Ok(ExecutionResult::Error(format!(
+                "Failed to show constraints: {}",
+                e
+            ))),
+        }
+    }
+
+    fn open(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.open()
+    }
+    fn close(&mut self) -> crate::query::executor::base::DBResult<()> {
+        self.base.close()
+    }
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+    fn name(&self) -> &str {
+        "ShowConstraintsExecutor"
+    }
+    fn description(&self) -> &str {
+        "Shows all tag constraints"
+    }
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for ShowConstraintsExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
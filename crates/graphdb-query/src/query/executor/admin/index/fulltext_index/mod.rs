@@ -1,6 +1,7 @@
 //! Fulltext Index Management Executor
 //!
-//! Provide functions for creating, deleting, altering, describing, and showing fulltext indexes.
+//! Provide functions for creating, deleting, altering, describing, and showing fulltext indexes,
+//! and for managing the dead letter queue of failed index sync operations.
 
 #[cfg(feature = "fulltext-search")]
 pub mod alter_fulltext_index;
@@ -12,6 +13,8 @@ pub mod describe_fulltext_index;
 pub mod drop_fulltext_index;
 #[cfg(feature = "fulltext-search")]
 pub mod show_fulltext_index;
+#[cfg(feature = "fulltext-search")]
+pub mod sync_dlq;
 
 #[cfg(all(test, feature = "fulltext-search"))]
 mod tests;
@@ -26,3 +29,5 @@ pub use describe_fulltext_index::DescribeFulltextIndexExecutor;
 pub use drop_fulltext_index::DropFulltextIndexExecutor;
 #[cfg(feature = "fulltext-search")]
 pub use show_fulltext_index::ShowFulltextIndexExecutor;
+#[cfg(feature = "fulltext-search")]
+pub use sync_dlq::SyncDlqExecutor;
@@ -0,0 +1,178 @@
+//! Sync Dead Letter Queue Executor
+//!
+//! Lists, retries and purges index sync operations that failed after all retries.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::core::error::DBError;
+use crate::core::Value;
+use crate::query::executor::base::{BaseExecutor, DBResult, ExecutionResult, Executor, HasStorage};
+use crate::query::parser::ast::stmt::SyncDlqAction;
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+use crate::storage::StorageClient;
+use crate::sync::SyncManager;
+
+#[derive(Debug)]
+pub struct SyncDlqExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    sync_manager: Arc<SyncManager>,
+    action: SyncDlqAction,
+}
+
+impl<S: StorageClient> SyncDlqExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+        sync_manager: Arc<SyncManager>,
+        action: SyncDlqAction,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(id, "SyncDlqExecutor".to_string(), storage, expr_context),
+            sync_manager,
+            action,
+        }
+    }
+
+    fn show(&self) -> DataSet {
+        let to_rfc3339 =
+            |time: SystemTime| chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339();
+
+        let col_names = vec![
+            "Id".to_string(),
+            "Space ID".to_string(),
+            "Tag Name".to_string(),
+            "Field Name".to_string(),
+            "Change Type".to_string(),
+            "Doc Id".to_string(),
+            "Error".to_string(),
+            "Retry Attempts".to_string(),
+            "Last Failure".to_string(),
+            "Recovered".to_string(),
+        ];
+
+        let rows: Vec<Vec<Value>> = self
+            .sync_manager
+            .get_dead_letter_entries()
+            .into_iter()
+            .map(|entry| {
+                vec![
+                    Value::BigInt(entry.id as i64),
+                    Value::BigInt(entry.operation.key.space_id as i64),
+                    Value::String(entry.operation.key.tag_name),
+                    Value::String(entry.operation.key.field_name),
+                    Value::String(format!("{:?}", entry.operation.change_type)),
+                    Value::String(entry.operation.id),
+                    Value::String(entry.error),
+                    Value::BigInt(entry.retry_attempts as i64),
+                    Value::String(to_rfc3339(entry.last_failure)),
+                    Value::Bool(entry.recovered),
+                ]
+            })
+            .collect();
+
+        DataSet { col_names, rows }
+    }
+
+    fn retry(&self, id: Option<u64>) -> DBResult<DataSet> {
+        let (total, recovered, failed, skipped) = match id {
+            Some(id) => {
+                if self.sync_manager.get_dead_letter_entry(id).is_none() {
+                    return Err(DBError::fulltext(format!(
+                        "Dead letter entry not found: {}",
+                        id
+                    )));
+                }
+                futures::executor::block_on(self.sync_manager.retry_dead_letter_entry(id))
+                    .map_err(|e| DBError::fulltext(e.to_string()))?;
+                (1, 1, 0, 0)
+            }
+            None => {
+                let result =
+                    futures::executor::block_on(self.sync_manager.retry_dead_letters(None))
+                        .map_err(|e| DBError::fulltext(e.to_string()))?;
+                (
+                    result.total(),
+                    result.recovered(),
+                    result.failed(),
+                    result.skipped(),
+                )
+            }
+        };
+
+        Ok(DataSet {
+            col_names: vec![
+                "Total".to_string(),
+                "Recovered".to_string(),
+                "Failed".to_string(),
+                "Skipped".to_string(),
+            ],
+            rows: vec![vec![
+                Value::BigInt(total as i64),
+                Value::BigInt(recovered as i64),
+                Value::BigInt(failed as i64),
+                Value::BigInt(skipped as i64),
+            ]],
+        })
+    }
+
+    fn purge(&self, recovered_only: bool) -> DataSet {
+        let purged = self.sync_manager.purge_dead_letters(recovered_only);
+        DataSet {
+            col_names: vec!["Purged".to_string()],
+            rows: vec![vec![Value::BigInt(purged as i64)]],
+        }
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for SyncDlqExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+impl<S: StorageClient> Executor<S> for SyncDlqExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = match self.action {
+            SyncDlqAction::Show => self.show(),
+            SyncDlqAction::Retry(id) => self.retry(id)?,
+            SyncDlqAction::Purge { recovered_only } => self.purge(recovered_only),
+        };
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id()
+    }
+
+    fn name(&self) -> &str {
+        "SyncDlqExecutor"
+    }
+
+    fn description(&self) -> &str {
+        "Executor for managing the index sync dead letter queue"
+    }
+
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.stats_mut()
+    }
+}
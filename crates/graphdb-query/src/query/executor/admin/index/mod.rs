@@ -2,6 +2,7 @@
 //!
 //! Provide functions for creating, deleting, describing, listing, reconstructing, and displaying the tag index and the edge index.
 
+pub mod constraint;
 pub mod edge_index;
 #[cfg(feature = "fulltext-search")]
 pub mod fulltext_index;
@@ -12,6 +13,8 @@ pub mod tag_index;
 #[cfg(test)]
 mod tests;
 
+pub use constraint::{CreateConstraintExecutor, DropConstraintExecutor, ShowConstraintsExecutor};
+
 pub use tag_index::{
     CreateTagIndexExecutor, DescTagIndexExecutor, DropTagIndexExecutor, ShowTagIndexesExecutor,
 };
@@ -27,4 +30,5 @@ pub use show_tag_index_status::ShowTagIndexStatusExecutor;
 pub use fulltext_index::{
     AlterFulltextIndexExecutor, CreateFulltextIndexConfig, CreateFulltextIndexExecutor,
     DescribeFulltextIndexExecutor, DropFulltextIndexExecutor, ShowFulltextIndexExecutor,
+    SyncDlqExecutor,
 };
@@ -1,12 +1,12 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::core::types::{Index, IndexType};
+    use crate::core::types::{Constraint, ConstraintKind, Index, IndexType};
     use crate::query::executor::admin::index::{
-        CreateEdgeIndexExecutor, CreateTagIndexExecutor, DescEdgeIndexExecutor,
-        DescTagIndexExecutor, DropEdgeIndexExecutor, DropTagIndexExecutor,
-        RebuildEdgeIndexExecutor, RebuildTagIndexExecutor, ShowEdgeIndexesExecutor,
-        ShowTagIndexesExecutor,
+        CreateConstraintExecutor, CreateEdgeIndexExecutor, CreateTagIndexExecutor,
+        DescEdgeIndexExecutor, DescTagIndexExecutor, DropConstraintExecutor, DropEdgeIndexExecutor,
+        DropTagIndexExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor,
+        ShowConstraintsExecutor, ShowEdgeIndexesExecutor, ShowTagIndexesExecutor,
     };
     use crate::query::executor::Executor;
     use crate::query::validator::context::ExpressionAnalysisContext;
@@ -153,6 +153,55 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_constraint_executors() {
+        let storage = Arc::new(RwLock::new(
+            MockStorage::new().expect("Failed to create MockStorage"),
+        ));
+        let constraint = Constraint::new(
+            "person_email_unique".to_string(),
+            "person".to_string(),
+            ConstraintKind::Unique,
+            vec!["email".to_string()],
+        );
+
+        let mut create = CreateConstraintExecutor::new(
+            7,
+            storage.clone(),
+            "test_space".to_string(),
+            constraint,
+            false,
+            create_test_context(),
+        );
+        assert!(matches!(
+            create.execute().expect("Failed to execute query"),
+            crate::query::executor::base::ExecutionResult::Success
+        ));
+
+        let mut show = ShowConstraintsExecutor::new(
+            8,
+            storage.clone(),
+            "test_space".to_string(),
+            create_test_context(),
+        );
+        match show.execute().expect("Failed to execute query") {
+            crate::query::executor::base::ExecutionResult::DataSet(dataset) => {
+                assert_eq!(dataset.col_names.len(), 4);
+            }
+            _ => panic!("Expected DataSet result"),
+        }
+
+        let mut drop = DropConstraintExecutor::new(
+            9,
+            storage,
+            "test_space".to_string(),
+            "person_email_unique".to_string(),
+            true,
+            create_test_context(),
+        );
+        assert!(drop.execute().is_ok());
+    }
+
     #[test]
     fn test_rebuild_tag_index_executor() {
         let storage = Arc::new(RwLock::new(
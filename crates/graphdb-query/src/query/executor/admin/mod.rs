@@ -30,20 +30,24 @@ pub use self::edge::{
 pub use self::edge::alter_edge::{AlterEdgeInfo, AlterEdgeItem, AlterEdgeOp};
 
 pub use self::index::{
-    CreateEdgeIndexExecutor, CreateTagIndexExecutor, DescEdgeIndexExecutor, DescTagIndexExecutor,
-    DropEdgeIndexExecutor, DropTagIndexExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor,
-    ShowEdgeIndexesExecutor, ShowTagIndexStatusExecutor, ShowTagIndexesExecutor,
+    CreateConstraintExecutor, CreateEdgeIndexExecutor, CreateTagIndexExecutor,
+    DescEdgeIndexExecutor, DescTagIndexExecutor, DropConstraintExecutor, DropEdgeIndexExecutor,
+    DropTagIndexExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor,
+    ShowConstraintsExecutor, ShowEdgeIndexesExecutor, ShowTagIndexStatusExecutor,
+    ShowTagIndexesExecutor,
 };
 
 #[cfg(feature = "fulltext-search")]
 pub use self::index::{
     AlterFulltextIndexExecutor, CreateFulltextIndexConfig, CreateFulltextIndexExecutor,
     DescribeFulltextIndexExecutor, DropFulltextIndexExecutor, ShowFulltextIndexExecutor,
+    SyncDlqExecutor,
 };
 
 pub use self::user::{
-    AlterUserExecutor, ChangePasswordExecutor, CreateUserExecutor, DescribeUserExecutor,
-    DropUserExecutor, GrantRoleExecutor, RevokeRoleExecutor,
+    AlterUserExecutor, ApiKeyExecutor, ChangePasswordExecutor, CreateRoleExecutor,
+    CreateUserExecutor, DescribeUserExecutor, DropUserExecutor, GrantPrivilegeExecutor,
+    GrantRoleExecutor, RevokeRoleExecutor,
 };
 
 pub use self::query_management::ShowStatsExecutor;
@@ -8,7 +8,9 @@ use std::sync::Arc;
 use crate::core::types::PropertyDef;
 use crate::query::executor::base::{BaseExecutor, ExecutionResult, Executor, HasStorage};
 use crate::query::validator::context::ExpressionAnalysisContext;
-use crate::storage::{StorageReader, StorageSchemaOps};
+use crate::storage::{
+    conversion_kind, ConversionKind, PropertyTypeChange, StorageReader, StorageSchemaOps,
+};
 
 /// Type of label modification operation
 #[derive(Debug, Clone)]
@@ -24,6 +26,8 @@ pub struct AlterTagItem {
     pub op: AlterTagOp,
     pub property: Option<PropertyDef>,
     pub property_name: Option<String>,
+    /// Permit lossy type conversions for `Change` (the `FORCE` keyword)
+    pub allow_lossy: bool,
 }
 
 impl AlterTagItem {
@@ -32,6 +36,7 @@ impl AlterTagItem {
             op: AlterTagOp::Add,
             property: Some(property),
             property_name: None,
+            allow_lossy: false,
         }
     }
 
@@ -40,6 +45,7 @@ impl AlterTagItem {
             op: AlterTagOp::Drop,
             property: None,
             property_name: Some(property_name),
+            allow_lossy: false,
         }
     }
 
@@ -47,11 +53,13 @@ impl AlterTagItem {
         old_name: String,
         new_name: String,
         data_type: crate::core::DataType,
+        allow_lossy: bool,
     ) -> Self {
         Self {
             op: AlterTagOp::Change,
             property: Some(PropertyDef::new(new_name, data_type)),
             property_name: Some(old_name),
+            allow_lossy,
         }
     }
 }
@@ -137,46 +145,116 @@ impl<S: StorageReader + StorageSchemaOps + Send + Sync + 'static> Executor<S>
             })
             .collect();
 
-        let changes: Vec<(String, String)> = self
+        let change_items: Vec<&AlterTagItem> = self
             .alter_info
             .items
             .iter()
-            .filter_map(|item| match item.op {
-                AlterTagOp::Change => {
-                    let old_name = item.property_name.clone()?;
-                    let new_name = item.property.as_ref().map(|p| p.name.clone())?;
-                    Some((old_name, new_name))
-                }
-                _ => None,
+            .filter(|item| matches!(item.op, AlterTagOp::Change))
+            .collect();
+
+        // A CHANGE with the same old and new name only changes the type
+        let changes: Vec<(String, String)> = change_items
+            .iter()
+            .filter_map(|item| {
+                let old_name = item.property_name.clone()?;
+                let new_name = item.property.as_ref().map(|p| p.name.clone())?;
+                (old_name != new_name).then_some((old_name, new_name))
             })
             .collect();
 
-        if !deletions.is_empty() {
-            let tag_info =
-                storage_guard.get_tag(&self.alter_info.space_name, &self.alter_info.tag_name);
-            if let Ok(Some(tag)) = tag_info {
-                for del_name in &deletions {
-                    if !tag.properties.iter().any(|p| &p.name == del_name) {
-                        return Ok(ExecutionResult::Error(format!(
-                            "Property '{}' not found in tag '{}'",
-                            del_name, self.alter_info.tag_name
-                        )));
-                    }
+        let tag =
+            match storage_guard.get_tag(&self.alter_info.space_name, &self.alter_info.tag_name) {
+                Ok(Some(tag)) => tag,
+                Ok(None) => {
+                    return Ok(ExecutionResult::Error(format!(
+                        "Tag '{}' not found in space '{}'",
+                        self.alter_info.tag_name, self.alter_info.space_name
+                    )))
+                }
+                Err(e) => {
+                    return Ok(ExecutionResult::Error(format!(
+                        "Failed to alter tag: {}",
+                        e
+                    )))
+                }
+            };
+
+        // Validate every item before mutating anything, so a rejected item
+        // leaves the tag as it was
+        for del_name in &deletions {
+            if !tag.properties.iter().any(|p| &p.name == del_name) {
+                return Ok(ExecutionResult::Error(format!(
+                    "Property '{}' not found in tag '{}'",
+                    del_name, self.alter_info.tag_name
+                )));
+            }
+        }
+
+        // Type changes as (current name, new type, allow lossy)
+        let mut type_changes: Vec<PropertyTypeChange> = Vec::new();
+        for item in &change_items {
+            let (Some(old_name), Some(target)) = (&item.property_name, &item.property) else {
+                continue;
+            };
+            let Some(current) = tag.properties.iter().find(|p| &p.name == old_name) else {
+                return Ok(ExecutionResult::Error(format!(
+                    "Property '{}' not found in tag '{}'",
+                    old_name, self.alter_info.tag_name
+                )));
+            };
+            if current.data_type == target.data_type {
+                continue;
+            }
+            match conversion_kind(&current.data_type, &target.data_type) {
+                ConversionKind::Unsupported => {
+                    return Ok(ExecutionResult::Error(format!(
+                        "Failed to change type of property '{}': cannot change {} to {}",
+                        old_name, current.data_type, target.data_type
+                    )));
+                }
+                ConversionKind::Lossy if !item.allow_lossy => {
+                    return Ok(ExecutionResult::Error(format!(
+                        "Failed to change type of property '{}': {} to {} loses precision; use FORCE to apply it",
+                        old_name, current.data_type, target.data_type
+                    )));
                 }
+                _ => {}
             }
+            type_changes.push(PropertyTypeChange::new(
+                old_name.clone(),
+                target.data_type.clone(),
+                item.allow_lossy,
+            ));
         }
 
-        if !changes.is_empty() {
-            for (old_name, new_name) in &changes {
-                storage_guard.rename_tag_property(
-                    &self.alter_info.space_name,
-                    &self.alter_info.tag_name,
-                    old_name,
-                    new_name,
-                )?;
+        // Type changes run first: they are the only items that can still fail on
+        // existing values (a string that does not parse, an integer that overflows).
+        // They apply together, converting every column before any catalog change, so
+        // a failure leaves every property untouched and no rename, addition or drop
+        // applied. The columns are rewritten while the storage write guard above is
+        // held, so other queries wait until the statement finishes.
+        if !type_changes.is_empty() {
+            if let Err(e) = storage_guard.change_tag_property_types(
+                &self.alter_info.space_name,
+                &self.alter_info.tag_name,
+                &type_changes,
+            ) {
+                return Ok(ExecutionResult::Error(format!(
+                    "Failed to change property types: {}",
+                    e
+                )));
             }
         }
 
+        for (old_name, new_name) in &changes {
+            storage_guard.rename_tag_property(
+                &self.alter_info.space_name,
+                &self.alter_info.tag_name,
+                old_name,
+                new_name,
+            )?;
+        }
+
         let result = storage_guard.alter_tag(
             &self.alter_info.space_name,
             &self.alter_info.tag_name,
@@ -186,13 +264,8 @@ impl<S: StorageReader + StorageSchemaOps + Send + Sync + 'static> Executor<S>
 
         match result {
             Ok(true) => {
-                if let Ok(Some(tag)) =
-                    storage_guard.get_tag(&self.alter_info.space_name, &self.alter_info.tag_name)
-                {
-                    for (old_name, new_name) in &changes {
-                        let _ =
-                            storage_guard.rename_vertex_property(tag.tag_id, old_name, new_name);
-                    }
+                for (old_name, new_name) in &changes {
+                    let _ = storage_guard.rename_vertex_property(tag.tag_id, old_name, new_name);
                 }
                 Ok(ExecutionResult::Success)
             }
@@ -0,0 +1,210 @@
+//! ApiKeyExecutor – The API key management executor
+//!
+//! Issues, revokes and lists the API keys users authenticate with. The plaintext
+//! token is only returned by CREATE API KEY; storage keeps a hash of the secret.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::core::error::DBError;
+use crate::core::types::ApiKeyInfo;
+use crate::core::{DataSet, NullType, Permission, Value};
+use crate::query::executor::base::{BaseExecutor, DBResult, ExecutionResult, Executor, HasStorage};
+use crate::query::parser::ast::stmt::ApiKeyAction;
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::storage::StorageClient;
+
+/// API key executor
+#[derive(Debug)]
+pub struct ApiKeyExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    action: ApiKeyAction,
+}
+
+impl<S: StorageClient> ApiKeyExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        action: ApiKeyAction,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(id, "ApiKeyExecutor".to_string(), storage, expr_context),
+            action,
+        }
+    }
+
+    fn create(
+        &self,
+        username: &str,
+        scopes: &[Permission],
+        expires_in_secs: Option<u64>,
+    ) -> DBResult<DataSet> {
+        let expires_at = expires_in_secs.map(|secs| {
+            chrono::Utc::now()
+                .timestamp_millis()
+                .saturating_add(secs.saturating_mul(1000).min(i64::MAX as u64) as i64)
+        });
+        let (key, token) = ApiKeyInfo::generate(username.to_string(), scopes.to_vec(), expires_at);
+
+        self.get_storage()
+            .write()
+            .create_api_key(&key)
+            .map_err(|e| DBError::storage(format!("Failed to create API key: {}", e)))?;
+
+        Ok(DataSet {
+            col_names: vec![
+                "Id".to_string(),
+                "Username".to_string(),
+                "Scopes".to_string(),
+                "Expires At".to_string(),
+                "Token".to_string(),
+            ],
+            rows: vec![vec![
+                Value::String(key.id.clone()),
+                Value::String(key.username.clone()),
+                Value::String(format_scopes(&key.scopes)),
+                format_timestamp(key.expires_at),
+                Value::String(token),
+            ]],
+        })
+    }
+
+    fn revoke(&self, key_id: &str) -> DBResult<DataSet> {
+        let revoked = self
+            .get_storage()
+            .write()
+            .revoke_api_key(key_id)
+            .map_err(|e| DBError::storage(format!("Failed to revoke API key: {}", e)))?;
+        if !revoked {
+            return Err(DBError::storage(format!(
+                "API key {} does not exist",
+                key_id
+            )));
+        }
+
+        Ok(DataSet {
+            col_names: vec!["Result".to_string()],
+            rows: vec![vec![Value::String(format!("API key {} revoked", key_id))]],
+        })
+    }
+
+    fn show(&self, username: Option<&str>) -> DBResult<DataSet> {
+        let storage = self.get_storage();
+        let storage = storage.read();
+        if let Some(username) = username {
+            if !storage.user_exists(username) {
+                return Err(DBError::storage(format!(
+                    "User {} does not exist",
+                    username
+                )));
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let rows = storage
+            .list_api_keys(username)
+            .into_iter()
+            .map(|key| {
+                let status = if key.revoked {
+                    "REVOKED"
+                } else if key.is_expired_at(now) {
+                    "EXPIRED"
+                } else {
+                    "ACTIVE"
+                };
+                vec![
+                    Value::String(key.id.clone()),
+                    Value::String(key.username.clone()),
+                    Value::String(format_scopes(&key.scopes)),
+                    format_timestamp(Some(key.created_at)),
+                    format_timestamp(key.expires_at),
+                    Value::String(status.to_string()),
+                ]
+            })
+            .collect();
+
+        Ok(DataSet {
+            col_names: vec![
+                "Id".to_string(),
+                "Username".to_string(),
+                "Scopes".to_string(),
+                "Created At".to_string(),
+                "Expires At".to_string(),
+                "Status".to_string(),
+            ],
+            rows,
+        })
+    }
+}
+
+fn format_scopes(scopes: &[Permission]) -> String {
+    if scopes.is_empty() {
+        return "ALL".to_string();
+    }
+    scopes
+        .iter()
+        .map(|scope| scope.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_timestamp(millis: Option<i64>) -> Value {
+    match millis.and_then(chrono::DateTime::<chrono::Utc>::from_timestamp_millis) {
+        Some(time) => Value::String(time.to_rfc3339()),
+        None => Value::Null(NullType::Null),
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for ApiKeyExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = match &self.action {
+            ApiKeyAction::Create {
+                username,
+                scopes,
+                expires_in_secs,
+            } => self.create(username, scopes, *expires_in_secs)?,
+            ApiKeyAction::Revoke { key_id } => self.revoke(key_id)?,
+            ApiKeyAction::Show { username } => self.show(username.as_deref())?,
+        };
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        "ApiKeyExecutor"
+    }
+
+    fn description(&self) -> &str {
+        "Creates, revokes and lists API keys"
+    }
+
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for ApiKeyExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
@@ -0,0 +1,119 @@
+//! CreateRoleExecutor – The custom role creation executor
+//!
+//! Responsible for creating custom roles that carry tag/edge/property grants.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::core::CustomRole;
+use crate::query::executor::base::{BaseExecutor, DBResult, ExecutionResult, Executor, HasStorage};
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::storage::StorageClient;
+
+/// Create custom role executor
+///
+/// The new role starts without privileges and inherits the grants of its parents.
+#[derive(Debug)]
+pub struct CreateRoleExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    role: CustomRole,
+    if_not_exists: bool,
+}
+
+impl<S: StorageClient> CreateRoleExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        role: CustomRole,
+        if_not_exists: bool,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(id, "CreateRoleExecutor".to_string(), storage, expr_context),
+            role,
+            if_not_exists,
+        }
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for CreateRoleExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let storage = self.get_storage();
+        let mut storage_guard = storage.write();
+
+        match storage_guard.create_role(&self.role) {
+            Ok(true) => Ok(ExecutionResult::Success),
+            Ok(false) if self.if_not_exists => Ok(ExecutionResult::Success),
+            Ok(false) => Ok(ExecutionResult::Error(format!(
+                "Role {} already exists",
+                self.role.name
+            ))),
+            Err(e) => Ok(ExecutionResult::Error(format!(
+                "Failed to create role: {}",
+                e
+            ))),
+        }
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        "CreateRoleExecutor"
+    }
+
+    fn description(&self) -> &str {
+        "Creates a custom role"
+    }
+
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for CreateRoleExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MockStorage;
+
+    #[test]
+    fn test_create_role_executor() {
+        let storage = Arc::new(RwLock::new(
+            MockStorage::new().expect("Failed to create MockStorage"),
+        ));
+        let expr_context = Arc::new(ExpressionAnalysisContext::new());
+        let mut executor = CreateRoleExecutor::new(
+            1,
+            storage,
+            CustomRole::new("analyst".to_string()),
+            false,
+            expr_context,
+        );
+
+        assert!(executor.execute().is_ok());
+        assert_eq!(executor.name(), "CreateRoleExecutor");
+    }
+}
@@ -2,6 +2,7 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 use crate::core::error::DBError;
+use crate::core::{DataSet, Value};
 use crate::query::executor::base::{BaseExecutor, DBResult, ExecutionResult, Executor, HasStorage};
 use crate::query::validator::context::ExpressionAnalysisContext;
 use crate::storage::StorageClient;
@@ -41,7 +42,33 @@ impl<S: StorageClient + Send + Sync + 'static> Executor<S> for DescribeUserExecu
                 self.username
             )));
         }
-        Ok(ExecutionResult::Success)
+
+        let (roles, privileges) = match storage.effective_privileges(&self.username) {
+            Some(effective) => (
+                effective.roles().join(", "),
+                effective
+                    .grants()
+                    .iter()
+                    .map(|grant| grant.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ),
+            None => (String::new(), String::new()),
+        };
+
+        let dataset = DataSet {
+            col_names: vec![
+                "User".to_string(),
+                "Roles".to_string(),
+                "Privileges".to_string(),
+            ],
+            rows: vec![vec![
+                Value::String(self.username.clone()),
+                Value::String(roles),
+                Value::String(privileges),
+            ]],
+        };
+        Ok(ExecutionResult::DataSet(dataset))
     }
 
     fn open(&mut self) -> DBResult<()> {
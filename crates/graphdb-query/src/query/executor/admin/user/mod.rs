@@ -1,19 +1,26 @@
 //! User management actuator
 //!
-//! Provide user management function (support multi-user, 5-level permission model).
+//! Provide user management function (support multi-user, 5-level permission model,
+//! custom roles with tag/edge/property privileges, API keys).
 
 pub mod alter_user;
+pub mod api_key;
 pub mod change_password;
+pub mod create_role;
 pub mod create_user;
 pub mod describe_user;
 pub mod drop_user;
+pub mod grant_privilege;
 pub mod grant_role;
 pub mod revoke_role;
 
 pub use alter_user::AlterUserExecutor;
+pub use api_key::ApiKeyExecutor;
 pub use change_password::ChangePasswordExecutor;
+pub use create_role::CreateRoleExecutor;
 pub use create_user::CreateUserExecutor;
 pub use describe_user::DescribeUserExecutor;
 pub use drop_user::DropUserExecutor;
+pub use grant_privilege::GrantPrivilegeExecutor;
 pub use grant_role::GrantRoleExecutor;
 pub use revoke_role::RevokeRoleExecutor;
@@ -1,11 +1,14 @@
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use super::execution_result::ExecutionResult;
-use crate::core::Value;
+use crate::core::types::ContextualExpression;
+use crate::core::{Expression, RoleType, Value};
 use crate::query::executor::expression::functions::global_registry_ref;
 use crate::query::executor::expression::functions::OwnedFunctionRef;
+use crate::query::executor::utils::recursion_detector::ParallelConfig;
 use crate::query::validator::context::ExpressionAnalysisContext;
 #[cfg(feature = "fulltext-search")]
 use crate::search::tantivy_index::TantivySearchEngine;
@@ -17,7 +20,16 @@ pub struct ExecutionContext {
     pub expression_context: Arc<ExpressionAnalysisContext>,
     #[cfg(feature = "fulltext-search")]
     pub search_engine: Option<Arc<TantivySearchEngine>>,
+    /// Values of the `$name` placeholders for this execution
     pub parameters: Arc<HashMap<String, crate::core::Value>>,
+    /// Set when the query is killed; long-running executors poll it
+    pub kill_flag: Option<Arc<AtomicBool>>,
+    /// Degree of parallelism requested by the PARALLEL hint
+    pub parallelism: Option<usize>,
+    /// Role of the session user, checked against the mode of called procedures
+    pub role: Option<RoleType>,
+    /// Values of the row an enclosing `CALL { }` or `FOREACH` runs its body for
+    pub row_variables: Arc<HashMap<String, Value>>,
 }
 
 impl ExecutionContext {
@@ -29,20 +41,10 @@ impl ExecutionContext {
             #[cfg(feature = "fulltext-search")]
             search_engine: None,
             parameters: Arc::new(HashMap::new()),
-        }
-    }
-
-    pub fn with_parameters(
-        expression_context: Arc<ExpressionAnalysisContext>,
-        parameters: HashMap<String, crate::core::Value>,
-    ) -> Self {
-        Self {
-            results: Arc::new(RwLock::new(HashMap::new())),
-            variables: Arc::new(RwLock::new(HashMap::new())),
-            expression_context,
-            #[cfg(feature = "fulltext-search")]
-            search_engine: None,
-            parameters: Arc::new(parameters),
+            kill_flag: None,
+            parallelism: None,
+            role: None,
+            row_variables: Arc::new(HashMap::new()),
         }
     }
 
@@ -57,6 +59,77 @@ impl ExecutionContext {
             expression_context,
             search_engine: Some(search_engine),
             parameters: Arc::new(HashMap::new()),
+            kill_flag: None,
+            parallelism: None,
+            role: None,
+            row_variables: Arc::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_kill_flag(mut self, kill_flag: Arc<AtomicBool>) -> Self {
+        self.kill_flag = Some(kill_flag);
+        self
+    }
+
+    pub fn with_parallelism(mut self, parallelism: Option<usize>) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    pub fn with_role(mut self, role: Option<RoleType>) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn with_parameters(mut self, parameters: HashMap<String, Value>) -> Self {
+        self.parameters = Arc::new(parameters);
+        self
+    }
+
+    /// Child context for one row of a `CALL { }` or `FOREACH` body
+    ///
+    /// `variables` are bound on top of the rows of enclosing subqueries.
+    pub fn for_row(&self, variables: HashMap<String, Value>) -> Self {
+        let mut row_variables = self.row_variables.as_ref().clone();
+        row_variables.extend(variables);
+        Self {
+            row_variables: Arc::new(row_variables),
+            ..self.clone()
+        }
+    }
+
+    /// `expr` as seen by this execution, with the parameters and row variables bound
+    ///
+    /// Builders read plan expressions through this so the values of the current
+    /// execution never leak into the plan, which the plan cache shares with other
+    /// executions.
+    pub fn bind(&self, expr: &ContextualExpression) -> ContextualExpression {
+        if self.parameters.is_empty() && self.row_variables.is_empty() {
+            return expr.clone();
+        }
+        let bound = expr
+            .context()
+            .with_bindings(self.parameters.clone(), self.row_variables.clone());
+        ContextualExpression::new(expr.id().clone(), Arc::new(bound))
+    }
+
+    /// Expression of `expr` with the parameters and row variables of this execution bound
+    pub fn resolve(&self, expr: &ContextualExpression) -> Option<Expression> {
+        self.bind(expr).get_expression()
+    }
+
+    /// Whether the query this context belongs to has been killed
+    pub fn is_killed(&self) -> bool {
+        self.kill_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    }
+
+    /// Parallel execution settings, with the worker count taken from the PARALLEL hint if given
+    pub fn parallel_config(&self) -> ParallelConfig {
+        match self.parallelism {
+            Some(parallelism) => ParallelConfig::with_parallelism(parallelism),
+            None => ParallelConfig::default(),
         }
     }
 
@@ -115,6 +188,10 @@ impl Default for ExecutionContext {
             #[cfg(feature = "fulltext-search")]
             search_engine: None,
             parameters: Arc::new(HashMap::new()),
+            kill_flag: None,
+            parallelism: None,
+            role: None,
+            row_variables: Arc::new(HashMap::new()),
         }
     }
 }
@@ -19,8 +19,11 @@ use crate::query::executor::base::{
     UserManageExecutor,
 };
 use crate::query::executor::control_flow::{
-    ForLoopExecutor, LoopExecutor, SelectExecutor, WhileLoopExecutor,
+    CallSubqueryExecutor, ForLoopExecutor, ForeachExecutor, LoopExecutor, SelectExecutor,
+    WhileLoopExecutor,
 };
+#[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+use crate::query::executor::data_access::HybridSearchExecutor;
 #[cfg(feature = "fulltext-search")]
 use crate::query::executor::data_access::{
     FulltextScanExecutor, FulltextSearchExecutor, MatchFulltextExecutor,
@@ -44,7 +47,7 @@ use crate::query::executor::graph_operations::graph_traversal::{
 use crate::query::executor::graph_operations::MaterializeExecutor;
 use crate::query::executor::relational_algebra::join::{
     CrossJoinExecutor, FullOuterJoinExecutor, HashInnerJoinExecutor, HashLeftJoinExecutor,
-    InnerJoinExecutor, LeftJoinExecutor,
+    InnerJoinExecutor, LeftJoinExecutor, MultiwayJoinExecutor,
 };
 use crate::query::executor::relational_algebra::set_operations::{
     IntersectExecutor, MinusExecutor, UnionAllExecutor, UnionExecutor,
@@ -53,8 +56,8 @@ use crate::query::executor::relational_algebra::{
     AggregateExecutor, FilterExecutor, GroupByExecutor, HavingExecutor, ProjectExecutor,
 };
 use crate::query::executor::result_processing::transformations::{
-    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, RollUpApplyExecutor,
-    UnwindExecutor,
+    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, PatternSubqueryExecutor,
+    RollUpApplyExecutor, UnwindExecutor,
 };
 use crate::query::executor::result_processing::{
     DedupExecutor, LimitExecutor, SampleExecutor, SortExecutor, TopNExecutor,
@@ -85,6 +88,7 @@ pub enum ExecutorEnum<S: StorageClient + Send + 'static> {
     HashLeftJoin(HashLeftJoinExecutor<S>),
     FullOuterJoin(FullOuterJoinExecutor<S>),
     CrossJoin(CrossJoinExecutor<S>),
+    MultiwayJoin(MultiwayJoinExecutor<S>),
     Union(UnionExecutor<S>),
     UnionAll(UnionAllExecutor<S>),
     Minus(MinusExecutor<S>),
@@ -100,6 +104,7 @@ pub enum ExecutorEnum<S: StorageClient + Send + 'static> {
     Having(HavingExecutor<S>),
     Dedup(DedupExecutor<S>),
     Unwind(UnwindExecutor<S>),
+    PatternSubquery(PatternSubqueryExecutor<S>),
     Assign(AssignExecutor<S>),
     Materialize(MaterializeExecutor<S>),
     AppendVertices(AppendVerticesExecutor<S>),
@@ -114,6 +119,8 @@ pub enum ExecutorEnum<S: StorageClient + Send + 'static> {
     Loop(LoopExecutor<S>),
     ForLoop(ForLoopExecutor<S>),
     WhileLoop(WhileLoopExecutor<S>),
+    CallSubquery(CallSubqueryExecutor<S>),
+    Foreach(ForeachExecutor<S>),
     Select(SelectExecutor<S>),
     ScanEdges(ScanEdgesExecutor<S>),
     ScanVertices(ScanVerticesExecutor<S>),
@@ -136,6 +143,8 @@ pub enum ExecutorEnum<S: StorageClient + Send + 'static> {
 
     // Statistics
     ShowStats(crate::query::executor::admin::query_management::show_stats::ShowStatsExecutor<S>),
+    ProcedureCall(crate::query::executor::data_access::ProcedureCallExecutor<S>),
+    MaterializedViewScan(crate::query::executor::data_access::MaterializedViewScanExecutor<S>),
     Analyze(AnalyzeExecutor<S>),
 
     // Full-text Search Executors (data access)
@@ -153,6 +162,10 @@ pub enum ExecutorEnum<S: StorageClient + Send + 'static> {
     VectorLookup(VectorLookupExecutor<S>),
     #[cfg(feature = "qdrant")]
     VectorMatch(VectorMatchExecutor<S>),
+
+    // Hybrid Search Executors (data access)
+    #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+    HybridSearch(HybridSearchExecutor<S>),
 }
 
 impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
@@ -178,6 +191,7 @@ impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
             ExecutorEnum::HashLeftJoin(exec) => ("HashLeftJoin", exec.name()),
             ExecutorEnum::FullOuterJoin(exec) => ("FullOuterJoin", exec.name()),
             ExecutorEnum::CrossJoin(exec) => ("CrossJoin", exec.name()),
+            ExecutorEnum::MultiwayJoin(exec) => ("MultiwayJoin", exec.name()),
             ExecutorEnum::Union(exec) => ("Union", exec.name()),
             ExecutorEnum::UnionAll(exec) => ("UnionAll", exec.name()),
             ExecutorEnum::Minus(exec) => ("Minus", exec.name()),
@@ -193,6 +207,7 @@ impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
             ExecutorEnum::Having(exec) => ("Having", exec.name()),
             ExecutorEnum::Dedup(exec) => ("Dedup", exec.name()),
             ExecutorEnum::Unwind(exec) => ("Unwind", exec.name()),
+            ExecutorEnum::PatternSubquery(exec) => ("PatternSubquery", exec.name()),
             ExecutorEnum::Assign(exec) => ("Assign", exec.name()),
             ExecutorEnum::Materialize(exec) => ("Materialize", exec.name()),
             ExecutorEnum::AppendVertices(exec) => ("AppendVertices", exec.name()),
@@ -207,6 +222,8 @@ impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
             ExecutorEnum::Loop(exec) => ("Loop", exec.name()),
             ExecutorEnum::ForLoop(exec) => ("ForLoop", exec.name()),
             ExecutorEnum::WhileLoop(exec) => ("WhileLoop", exec.name()),
+            ExecutorEnum::CallSubquery(exec) => ("CallSubquery", exec.name()),
+            ExecutorEnum::Foreach(exec) => ("Foreach", exec.name()),
             ExecutorEnum::Select(exec) => ("Select", exec.name()),
             ExecutorEnum::ScanEdges(exec) => ("ScanEdges", exec.name()),
             ExecutorEnum::ScanVertices(exec) => ("ScanVertices", exec.name()),
@@ -227,6 +244,8 @@ impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
             ExecutorEnum::VectorManage(exec) => ("VectorManage", exec.name()),
             // Statistics
             ExecutorEnum::ShowStats(exec) => ("ShowStats", exec.name()),
+            ExecutorEnum::ProcedureCall(exec) => ("ProcedureCall", exec.name()),
+            ExecutorEnum::MaterializedViewScan(exec) => ("MaterializedViewScan", exec.name()),
             ExecutorEnum::Analyze(exec) => ("Analyze", exec.name()),
             // Full-text Search Executors (data access)
             #[cfg(feature = "fulltext-search")]
@@ -242,6 +261,9 @@ impl<S: StorageClient + Send + 'static> Debug for ExecutorEnum<S> {
             ExecutorEnum::VectorLookup(exec) => ("VectorLookup", exec.name()),
             #[cfg(feature = "qdrant")]
             ExecutorEnum::VectorMatch(exec) => ("VectorMatch", exec.name()),
+            // Hybrid Search Executors (data access)
+            #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+            ExecutorEnum::HybridSearch(exec) => ("HybridSearch", exec.name()),
         };
         f.write_str(&format!("ExecutorEnum::{}({})", variant_name, exec_name))
     }
@@ -327,6 +349,9 @@ impl<S: StorageClient + Send + 'static> InputExecutor<S> for ExecutorEnum<S> {
             ExecutorEnum::Remove(exec) => exec.set_input(input),
             ExecutorEnum::Materialize(exec) => exec.set_input(input),
             ExecutorEnum::Unwind(exec) => exec.set_input(input),
+            ExecutorEnum::PatternSubquery(exec) => exec.set_input(input),
+            ExecutorEnum::CallSubquery(exec) => exec.set_input(input),
+            ExecutorEnum::Foreach(exec) => exec.set_input(input),
             ExecutorEnum::PipeDelete(exec) => exec.set_input(input),
             _ => {}
         }
@@ -352,6 +377,9 @@ impl<S: StorageClient + Send + 'static> InputExecutor<S> for ExecutorEnum<S> {
             ExecutorEnum::Remove(exec) => exec.get_input(),
             ExecutorEnum::Materialize(exec) => exec.get_input(),
             ExecutorEnum::Unwind(exec) => exec.get_input(),
+            ExecutorEnum::PatternSubquery(exec) => exec.get_input(),
+            ExecutorEnum::CallSubquery(exec) => exec.get_input(),
+            ExecutorEnum::Foreach(exec) => exec.get_input(),
             ExecutorEnum::PipeDelete(exec) => exec.get_input(),
             _ => None,
         }
@@ -397,6 +425,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::HashLeftJoin(_) => "hash_left_join",
             ExecutorEnum::FullOuterJoin(_) => "full_outer_join",
             ExecutorEnum::CrossJoin(_) => "cross_join",
+            ExecutorEnum::MultiwayJoin(_) => "multiway_join",
             ExecutorEnum::Union(_) => "union",
             ExecutorEnum::UnionAll(_) => "union_all",
             ExecutorEnum::Minus(_) => "minus",
@@ -412,6 +441,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Having(_) => "having",
             ExecutorEnum::Dedup(_) => "dedup",
             ExecutorEnum::Unwind(_) => "unwind",
+            ExecutorEnum::PatternSubquery(_) => "pattern_subquery",
             ExecutorEnum::Assign(_) => "assign",
             ExecutorEnum::Materialize(_) => "materialize",
             ExecutorEnum::AppendVertices(_) => "append_vertices",
@@ -423,6 +453,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Loop(_) => "loop",
             ExecutorEnum::ForLoop(_) => "for_loop",
             ExecutorEnum::WhileLoop(_) => "while_loop",
+            ExecutorEnum::CallSubquery(_) => "call_subquery",
+            ExecutorEnum::Foreach(_) => "foreach",
             ExecutorEnum::Select(_) => "select",
             ExecutorEnum::ScanEdges(_) => "scan_edges",
             ExecutorEnum::ScanVertices(_) => "scan_vertices",
@@ -443,6 +475,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorManage(e) => e.node_type_id(),
             // Statistics
             ExecutorEnum::ShowStats(_) => "show_stats",
+            ExecutorEnum::ProcedureCall(_) => "procedure_call",
+            ExecutorEnum::MaterializedViewScan(_) => "materialized_view_scan",
             ExecutorEnum::Analyze(_) => "analyze",
             ExecutorEnum::Delete(_) => "delete",
             ExecutorEnum::PipeDelete(_) => "pipe_delete",
@@ -461,6 +495,9 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorLookup(_) => "vector_lookup",
             #[cfg(feature = "qdrant")]
             ExecutorEnum::VectorMatch(_) => "vector_match",
+            // Hybrid Search Executors (data access)
+            #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+            ExecutorEnum::HybridSearch(_) => "hybrid_search",
         }
     }
 
@@ -486,6 +523,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::HashLeftJoin(_) => "Hash Left Join",
             ExecutorEnum::FullOuterJoin(_) => "Full Outer Join",
             ExecutorEnum::CrossJoin(_) => "Cross Join",
+            ExecutorEnum::MultiwayJoin(_) => "Multiway Join",
             ExecutorEnum::Union(_) => "Union",
             ExecutorEnum::UnionAll(_) => "Union All",
             ExecutorEnum::Minus(_) => "Minus",
@@ -501,6 +539,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Having(_) => "Having",
             ExecutorEnum::Dedup(_) => "Dedup",
             ExecutorEnum::Unwind(_) => "Unwind",
+            ExecutorEnum::PatternSubquery(_) => "Pattern Subquery",
             ExecutorEnum::Assign(_) => "Assign",
             ExecutorEnum::Materialize(_) => "Materialize",
             ExecutorEnum::AppendVertices(_) => "Append Vertices",
@@ -512,6 +551,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Loop(_) => "Loop",
             ExecutorEnum::ForLoop(_) => "For Loop",
             ExecutorEnum::WhileLoop(_) => "While Loop",
+            ExecutorEnum::CallSubquery(_) => "Call Subquery",
+            ExecutorEnum::Foreach(_) => "Foreach",
             ExecutorEnum::Select(_) => "Select",
             ExecutorEnum::ScanEdges(_) => "Scan Edges",
             ExecutorEnum::ScanVertices(_) => "Scan Vertices",
@@ -532,6 +573,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorManage(e) => e.node_type_name(),
             // Statistics
             ExecutorEnum::ShowStats(_) => "Show Stats",
+            ExecutorEnum::ProcedureCall(_) => "Procedure Call",
+            ExecutorEnum::MaterializedViewScan(_) => "Materialized View Scan",
             ExecutorEnum::Analyze(_) => "Analyze",
             ExecutorEnum::Delete(_) => "Delete",
             ExecutorEnum::PipeDelete(_) => "Pipe Delete",
@@ -550,6 +593,9 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorLookup(_) => "Vector Lookup",
             #[cfg(feature = "qdrant")]
             ExecutorEnum::VectorMatch(_) => "Vector Match",
+            // Hybrid Search Executors (data access)
+            #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+            ExecutorEnum::HybridSearch(_) => "Hybrid Search",
         }
     }
 
@@ -575,6 +621,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::HashLeftJoin(_) => NodeCategory::Join,
             ExecutorEnum::FullOuterJoin(_) => NodeCategory::Join,
             ExecutorEnum::CrossJoin(_) => NodeCategory::Join,
+            ExecutorEnum::MultiwayJoin(_) => NodeCategory::Join,
             ExecutorEnum::Union(_) => NodeCategory::SetOp,
             ExecutorEnum::UnionAll(_) => NodeCategory::SetOp,
             ExecutorEnum::Minus(_) => NodeCategory::SetOp,
@@ -590,6 +637,7 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Having(_) => NodeCategory::Filter,
             ExecutorEnum::Dedup(_) => NodeCategory::Other,
             ExecutorEnum::Unwind(_) => NodeCategory::Other,
+            ExecutorEnum::PatternSubquery(_) => NodeCategory::Other,
             ExecutorEnum::Assign(_) => NodeCategory::Other,
             ExecutorEnum::Materialize(_) => NodeCategory::Other,
             ExecutorEnum::AppendVertices(_) => NodeCategory::Traversal,
@@ -601,6 +649,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::Loop(_) => NodeCategory::Control,
             ExecutorEnum::ForLoop(_) => NodeCategory::Control,
             ExecutorEnum::WhileLoop(_) => NodeCategory::Control,
+            ExecutorEnum::CallSubquery(_) => NodeCategory::Control,
+            ExecutorEnum::Foreach(_) => NodeCategory::Control,
             ExecutorEnum::Select(_) => NodeCategory::Control,
             ExecutorEnum::ScanEdges(_) => NodeCategory::Scan,
             ExecutorEnum::ScanVertices(_) => NodeCategory::Scan,
@@ -621,6 +671,8 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorManage(_) => NodeCategory::Admin,
             // Statistics
             ExecutorEnum::ShowStats(_) => NodeCategory::Admin,
+            ExecutorEnum::ProcedureCall(_) => NodeCategory::DataAccess,
+            ExecutorEnum::MaterializedViewScan(_) => NodeCategory::DataAccess,
             ExecutorEnum::Analyze(_) => NodeCategory::Admin,
             ExecutorEnum::Delete(_) => NodeCategory::Admin,
             ExecutorEnum::PipeDelete(_) => NodeCategory::Admin,
@@ -639,6 +691,9 @@ impl<S: StorageClient + Send + 'static> NodeType for ExecutorEnum<S> {
             ExecutorEnum::VectorLookup(_) => NodeCategory::Scan,
             #[cfg(feature = "qdrant")]
             ExecutorEnum::VectorMatch(_) => NodeCategory::Scan,
+            // Hybrid Search Executors (data access)
+            #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+            ExecutorEnum::HybridSearch(_) => NodeCategory::Scan,
         }
     }
 }
@@ -669,6 +724,7 @@ mod macros {
                 ExecutorEnum::HashLeftJoin(exec) => exec.$method(),
                 ExecutorEnum::FullOuterJoin(exec) => exec.$method(),
                 ExecutorEnum::CrossJoin(exec) => exec.$method(),
+                ExecutorEnum::MultiwayJoin(exec) => exec.$method(),
                 ExecutorEnum::Union(exec) => exec.$method(),
                 ExecutorEnum::UnionAll(exec) => exec.$method(),
                 ExecutorEnum::Minus(exec) => exec.$method(),
@@ -684,6 +740,7 @@ mod macros {
                 ExecutorEnum::Having(exec) => exec.$method(),
                 ExecutorEnum::Dedup(exec) => exec.$method(),
                 ExecutorEnum::Unwind(exec) => exec.$method(),
+                ExecutorEnum::PatternSubquery(exec) => exec.$method(),
                 ExecutorEnum::Assign(exec) => exec.$method(),
                 ExecutorEnum::Materialize(exec) => exec.$method(),
                 ExecutorEnum::AppendVertices(exec) => exec.$method(),
@@ -698,6 +755,8 @@ mod macros {
                 ExecutorEnum::Loop(exec) => exec.$method(),
                 ExecutorEnum::ForLoop(exec) => exec.$method(),
                 ExecutorEnum::WhileLoop(exec) => exec.$method(),
+                ExecutorEnum::CallSubquery(exec) => exec.$method(),
+                ExecutorEnum::Foreach(exec) => exec.$method(),
                 ExecutorEnum::Select(exec) => exec.$method(),
                 ExecutorEnum::ScanEdges(exec) => exec.$method(),
                 ExecutorEnum::ScanVertices(exec) => exec.$method(),
@@ -718,6 +777,8 @@ mod macros {
                 ExecutorEnum::VectorManage(exec) => exec.$method(),
                 // Statistics
                 ExecutorEnum::ShowStats(exec) => exec.$method(),
+                ExecutorEnum::ProcedureCall(exec) => exec.$method(),
+                ExecutorEnum::MaterializedViewScan(exec) => exec.$method(),
                 ExecutorEnum::Analyze(exec) => exec.$method(),
                 // Full-text Search Executors (data access)
                 #[cfg(feature = "fulltext-search")]
@@ -733,6 +794,9 @@ mod macros {
                 ExecutorEnum::VectorLookup(exec) => exec.$method(),
                 #[cfg(feature = "qdrant")]
                 ExecutorEnum::VectorMatch(exec) => exec.$method(),
+                // Hybrid Search Executors (data access)
+                #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+                ExecutorEnum::HybridSearch(exec) => exec.$method(),
             }
         };
     }
@@ -761,6 +825,7 @@ mod macros {
                 ExecutorEnum::HashLeftJoin(exec) => exec.$method(),
                 ExecutorEnum::FullOuterJoin(exec) => exec.$method(),
                 ExecutorEnum::CrossJoin(exec) => exec.$method(),
+                ExecutorEnum::MultiwayJoin(exec) => exec.$method(),
                 ExecutorEnum::Union(exec) => exec.$method(),
                 ExecutorEnum::UnionAll(exec) => exec.$method(),
                 ExecutorEnum::Minus(exec) => exec.$method(),
@@ -776,6 +841,7 @@ mod macros {
                 ExecutorEnum::Having(exec) => exec.$method(),
                 ExecutorEnum::Dedup(exec) => exec.$method(),
                 ExecutorEnum::Unwind(exec) => exec.$method(),
+                ExecutorEnum::PatternSubquery(exec) => exec.$method(),
                 ExecutorEnum::Assign(exec) => exec.$method(),
                 ExecutorEnum::Materialize(exec) => exec.$method(),
                 ExecutorEnum::AppendVertices(exec) => exec.$method(),
@@ -790,6 +856,8 @@ mod macros {
                 ExecutorEnum::Loop(exec) => exec.$method(),
                 ExecutorEnum::ForLoop(exec) => exec.$method(),
                 ExecutorEnum::WhileLoop(exec) => exec.$method(),
+                ExecutorEnum::CallSubquery(exec) => exec.$method(),
+                ExecutorEnum::Foreach(exec) => exec.$method(),
                 ExecutorEnum::Select(exec) => exec.$method(),
                 ExecutorEnum::ScanEdges(exec) => exec.$method(),
                 ExecutorEnum::ScanVertices(exec) => exec.$method(),
@@ -810,6 +878,8 @@ mod macros {
                 ExecutorEnum::VectorManage(exec) => exec.$method(),
                 // Statistics
                 ExecutorEnum::ShowStats(exec) => exec.$method(),
+                ExecutorEnum::ProcedureCall(exec) => exec.$method(),
+                ExecutorEnum::MaterializedViewScan(exec) => exec.$method(),
                 ExecutorEnum::Analyze(exec) => exec.$method(),
                 // Full-text Search Executors (data access)
                 #[cfg(feature = "fulltext-search")]
@@ -825,6 +895,9 @@ mod macros {
                 ExecutorEnum::VectorLookup(exec) => exec.$method(),
                 #[cfg(feature = "qdrant")]
                 ExecutorEnum::VectorMatch(exec) => exec.$method(),
+                // Hybrid Search Executors (data access)
+                #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+                ExecutorEnum::HybridSearch(exec) => exec.$method(),
             }
         };
     }
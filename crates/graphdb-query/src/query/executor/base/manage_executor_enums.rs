@@ -18,12 +18,13 @@ use crate::query::executor::admin::edge::{
 #[cfg(feature = "fulltext-search")]
 use crate::query::executor::admin::index::{
     AlterFulltextIndexExecutor, CreateFulltextIndexExecutor, DescribeFulltextIndexExecutor,
-    DropFulltextIndexExecutor, ShowFulltextIndexExecutor,
+    DropFulltextIndexExecutor, ShowFulltextIndexExecutor, SyncDlqExecutor,
 };
 use crate::query::executor::admin::index::{
-    CreateEdgeIndexExecutor, CreateTagIndexExecutor, DescEdgeIndexExecutor, DescTagIndexExecutor,
-    DropEdgeIndexExecutor, DropTagIndexExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor,
-    ShowEdgeIndexesExecutor, ShowTagIndexesExecutor,
+    CreateConstraintExecutor, CreateEdgeIndexExecutor, CreateTagIndexExecutor,
+    DescEdgeIndexExecutor, DescTagIndexExecutor, DropConstraintExecutor, DropEdgeIndexExecutor,
+    DropTagIndexExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor,
+    ShowConstraintsExecutor, ShowEdgeIndexesExecutor, ShowTagIndexesExecutor,
 };
 use crate::query::executor::admin::space::{
     AlterSpaceExecutor, ClearSpaceExecutor, CreateSpaceExecutor, DescSpaceExecutor,
@@ -34,8 +35,9 @@ use crate::query::executor::admin::tag::{
     ShowTagsExecutor,
 };
 use crate::query::executor::admin::user::{
-    AlterUserExecutor, ChangePasswordExecutor, CreateUserExecutor, DescribeUserExecutor,
-    DropUserExecutor, GrantRoleExecutor, RevokeRoleExecutor,
+    AlterUserExecutor, ApiKeyExecutor, ChangePasswordExecutor, CreateRoleExecutor,
+    CreateUserExecutor, DescribeUserExecutor, DropUserExecutor, GrantPrivilegeExecutor,
+    GrantRoleExecutor, RevokeRoleExecutor,
 };
 #[cfg(feature = "qdrant")]
 use crate::query::executor::data_access::{CreateVectorIndexExecutor, DropVectorIndexExecutor};
@@ -178,6 +180,9 @@ define_manage_executor_enum! {
         DescEdgeIndex(DescEdgeIndexExecutor<S>, "desc_edge_index", "Describe Edge Index"),
         ShowEdgeIndexes(ShowEdgeIndexesExecutor<S>, "show_edge_indexes", "Show Edge Indexes"),
         RebuildEdgeIndex(RebuildEdgeIndexExecutor<S>, "rebuild_edge_index", "Rebuild Edge Index"),
+        CreateConstraint(CreateConstraintExecutor<S>, "create_constraint", "Create Constraint"),
+        DropConstraint(DropConstraintExecutor<S>, "drop_constraint", "Drop Constraint"),
+        ShowConstraints(ShowConstraintsExecutor<S>, "show_constraints", "Show Constraints"),
     }
 }
 
@@ -190,6 +195,9 @@ define_manage_executor_enum! {
         ChangePassword(ChangePasswordExecutor<S>, "change_password", "Change Password"),
         GrantRole(GrantRoleExecutor<S>, "grant_role", "Grant Role"),
         RevokeRole(RevokeRoleExecutor<S>, "revoke_role", "Revoke Role"),
+        CreateRole(CreateRoleExecutor<S>, "create_role", "Create Role"),
+        GrantPrivilege(GrantPrivilegeExecutor<S>, "grant_privilege", "Grant Privilege"),
+        ApiKey(ApiKeyExecutor<S>, "api_key", "Api Key"),
         Describe(DescribeUserExecutor<S>, "describe_user", "Describe User"),
     }
 }
@@ -203,6 +211,7 @@ define_manage_executor_enum! {
         Alter(AlterFulltextIndexExecutor<S>, "alter_fulltext_index", "Alter Fulltext Index"),
         Show(ShowFulltextIndexExecutor<S>, "show_fulltext_index", "Show Fulltext Index"),
         Describe(DescribeFulltextIndexExecutor<S>, "describe_fulltext_index", "Describe Fulltext Index"),
+        SyncDlq(SyncDlqExecutor<S>, "sync_dlq", "Sync Dead Letter Queue"),
     }
 }
 
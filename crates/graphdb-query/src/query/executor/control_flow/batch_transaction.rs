@@ -0,0 +1,31 @@
+//! Batch Transaction Control
+//!
+//! `CALL { } IN TRANSACTIONS` commits its work in batches, and the `.write` graph algorithm
+//! procedures write their results in a transaction of their own. The executors do not own the
+//! transaction manager; the service that runs the query installs a [`BatchTransactionControl`]
+//! on the executor factory instead.
+
+use std::sync::Arc;
+
+use crate::core::error::DBResult;
+use crate::core::types::TransactionId;
+
+/// Begins, commits and rolls back the transactions of a batched subquery
+pub trait BatchTransactionControl: Send + Sync {
+    /// Whether the statement already runs inside an explicit transaction
+    ///
+    /// Batches commit independently, so they cannot be nested in an outer transaction.
+    fn in_explicit_transaction(&self) -> bool;
+
+    /// Start the transaction of the next batch; writes go to it until it ends
+    fn begin_batch(&self) -> DBResult<TransactionId>;
+
+    /// Commit the transaction of a finished batch
+    fn commit_batch(&self, txn_id: TransactionId) -> DBResult<()>;
+
+    /// Roll back the transaction of a failed batch
+    fn rollback_batch(&self, txn_id: TransactionId) -> DBResult<()>;
+}
+
+/// Shared handle installed on the executor factory
+pub type SharedBatchTransactionControl = Arc<dyn BatchTransactionControl>;
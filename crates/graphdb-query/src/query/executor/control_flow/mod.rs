@@ -4,8 +4,14 @@
 //! LoopExecutor (General Loop Control)
 //! WhileLoopExecutor (conditional loop)
 //! ForLoopExecutor (counting loop)
+//! CallSubqueryExecutor (CALL { }, optionally IN TRANSACTIONS)
+//! ForeachExecutor (FOREACH update clauses)
 //!
 
+pub mod batch_transaction;
 pub mod loops;
+pub mod subquery;
 
+pub use batch_transaction::{BatchTransactionControl, SharedBatchTransactionControl};
 pub use loops::{ForLoopExecutor, LoopExecutor, LoopState, SelectExecutor, WhileLoopExecutor};
+pub use subquery::{CallSubqueryExecutor, ForeachExecutor};
@@ -0,0 +1,411 @@
+//! Per-row Subquery Executors
+//!
+//! `CALL { }` runs its body once per input row and `FOREACH` runs its update clauses once per
+//! list element. Builders resolve most write expressions when an executor is created, so the
+//! body is rebuilt for every row from a child execution context that carries the row's values.
+//!
+//! With `IN TRANSACTIONS`, the rows are split into batches that are committed one by one
+//! through the [`BatchTransactionControl`] installed on the executor factory.
+
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::error::{DBError, DBResult};
+use crate::core::types::ContextualExpression;
+use crate::core::{NullType, Value};
+use crate::query::executor::base::{
+    BaseExecutor, ExecutionContext, ExecutionResult, Executor, ExecutorEnum, HasStorage,
+    InputExecutor,
+};
+use crate::query::executor::control_flow::batch_transaction::BatchTransactionControl;
+use crate::query::executor::expression::evaluator::expression_evaluator::ExpressionEvaluator;
+use crate::query::executor::expression::evaluator::traits::ExpressionContext;
+use crate::query::executor::expression::DefaultExpressionContext;
+use crate::query::executor::factory::engine::PlanExecutor;
+use crate::query::executor::factory::ExecutorFactory;
+use crate::query::parser::ast::stmt::{InTransactions, OnErrorAction};
+use crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum;
+use crate::query::DataSet;
+use crate::storage::StorageClient;
+
+/// Rows the subquery runs for; a statement without input runs once with no variables
+fn input_rows<S: StorageClient + Send + 'static>(
+    input_executor: Option<&mut Box<ExecutorEnum<S>>>,
+) -> DBResult<DataSet> {
+    let input_result = match input_executor {
+        Some(input_exec) => input_exec.execute()?,
+        None => ExecutionResult::Success,
+    };
+
+    match input_result {
+        // A standalone statement reads an unbound argument, which has no columns.
+        ExecutionResult::DataSet(data) if !data.col_names.is_empty() => Ok(data),
+        ExecutionResult::DataSet(_)
+        | ExecutionResult::Success
+        | ExecutionResult::Empty
+        | ExecutionResult::SpaceSwitched(_) => Ok(DataSet {
+            col_names: Vec::new(),
+            rows: vec![Vec::new()],
+        }),
+        ExecutionResult::Error(e) => Err(DBError::query(format!("Error in input result: {}", e))),
+    }
+}
+
+/// Variables of one input row
+fn row_variables(col_names: &[String], row: &[Value]) -> HashMap<String, Value> {
+    col_names.iter().cloned().zip(row.iter().cloned()).collect()
+}
+
+/// Build the executors of `plan` and run them with `variables` bound
+///
+/// The bindings of an enclosing subquery stay visible to the body.
+fn run_plan<S: StorageClient + Send + Sync + 'static>(
+    plan: &PlanNodeEnum,
+    variables: HashMap<String, Value>,
+    factory: &ExecutorFactory<S>,
+    storage: &Arc<RwLock<S>>,
+    context: &ExecutionContext,
+) -> DBResult<()> {
+    let row_context = context.for_row(variables);
+    let result = PlanExecutor::new(factory.clone())
+        .build_executor_chain(plan, storage.clone(), &row_context)
+        .map_err(DBError::from)
+        .and_then(|mut executor| executor.execute())?;
+
+    match result {
+        ExecutionResult::Error(e) => Err(DBError::query(e)),
+        _ => Ok(()),
+    }
+}
+
+/// CALL { } Executor
+pub struct CallSubqueryExecutor<S: StorageClient + Send + 'static> {
+    base: BaseExecutor<S>,
+    body: PlanNodeEnum,
+    in_transactions: Option<InTransactions>,
+    factory: ExecutorFactory<S>,
+    context: ExecutionContext,
+    input_executor: Option<Box<ExecutorEnum<S>>>,
+}
+
+impl<S: StorageClient + Send + Sync + 'static> CallSubqueryExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        body: PlanNodeEnum,
+        in_transactions: Option<InTransactions>,
+        factory: ExecutorFactory<S>,
+        context: ExecutionContext,
+    ) -> Self {
+        let expr_context = context.expression_context().clone();
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "CallSubqueryExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            body,
+            in_transactions,
+            factory,
+            context,
+            input_executor: None,
+        }
+    }
+
+    fn run_row(&self, col_names: &[String], row: &[Value]) -> DBResult<()> {
+        run_plan(
+            &self.body,
+            row_variables(col_names, row),
+            &self.factory,
+            self.get_storage(),
+            &self.context,
+        )
+    }
+
+    fn execute_call_subquery(&mut self) -> DBResult<DataSet> {
+        let input = input_rows(self.input_executor.as_mut())?;
+
+        let in_transactions = match self.in_transactions {
+            Some(in_transactions) => in_transactions,
+            None => {
+                for row in &input.rows {
+                    self.run_row(&input.col_names, row)?;
+                }
+                return Ok(input);
+            }
+        };
+
+        // Without a control every batch would write outside of any transaction
+        let Some(control) = self.factory.batch_transaction_control() else {
+            return Err(DBError::transaction(
+                "CALL { } IN TRANSACTIONS requires a transaction manager to commit its batches",
+            ));
+        };
+        if control.in_explicit_transaction() {
+            return Err(DBError::transaction(
+                "CALL { } IN TRANSACTIONS cannot run inside an explicit transaction",
+            ));
+        }
+
+        let mut dataset = DataSet {
+            col_names: InTransactions::STATISTICS_COLUMNS
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            rows: Vec::new(),
+        };
+
+        for (index, batch) in input.rows.chunks(in_transactions.batch_size).enumerate() {
+            let outcome = self.run_batch(control.as_ref(), &input.col_names, batch);
+            let error = outcome.as_ref().err().map(|e| e.to_string());
+
+            dataset.rows.push(vec![
+                Value::BigInt(index as i64 + 1),
+                Value::BigInt(batch.len() as i64),
+                Value::Bool(outcome.is_ok()),
+                error
+                    .clone()
+                    .map(Value::String)
+                    .unwrap_or(Value::Null(NullType::Null)),
+            ]);
+
+            if let Some(error) = error {
+                match in_transactions.on_error {
+                    OnErrorAction::Continue => {}
+                    OnErrorAction::Break => break,
+                    OnErrorAction::Fail => {
+                        return Err(DBError::transaction(format!(
+                            "Batch {} of CALL {{ }} IN TRANSACTIONS failed: {}",
+                            index + 1,
+                            error
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(dataset)
+    }
+
+    /// Run one batch in its own transaction; a failed batch is rolled back
+    fn run_batch(
+        &self,
+        control: &dyn BatchTransactionControl,
+        col_names: &[String],
+        batch: &[Vec<Value>],
+    ) -> DBResult<()> {
+        let txn_id = control.begin_batch()?;
+
+        let result = batch
+            .iter()
+            .try_for_each(|row| self.run_row(col_names, row))
+            .and_then(|()| control.commit_batch(txn_id));
+
+        if result.is_err() {
+            if let Err(e) = control.rollback_batch(txn_id) {
+                log::warn!("Failed to roll back batch transaction {}: {}", txn_id, e);
+            }
+        }
+
+        result
+    }
+}
+
+impl<S: StorageClient + Send + 'static> InputExecutor<S> for CallSubqueryExecutor<S> {
+    fn set_input(&mut self, input: ExecutorEnum<S>) {
+        self.input_executor = Some(Box::new(input));
+    }
+
+    fn get_input(&self) -> Option<&ExecutorEnum<S>> {
+        self.input_executor.as_ref().map(|b| b.as_ref())
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for CallSubqueryExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = self.execute_call_subquery()?;
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        Ok(())
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        Ok(())
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient + Send + 'static> HasStorage<S> for CallSubqueryExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+/// FOREACH Executor
+pub struct ForeachExecutor<S: StorageClient + Send + 'static> {
+    base: BaseExecutor<S>,
+    variable: String,
+    list: ContextualExpression,
+    body: Vec<PlanNodeEnum>,
+    factory: ExecutorFactory<S>,
+    context: ExecutionContext,
+    input_executor: Option<Box<ExecutorEnum<S>>>,
+}
+
+impl<S: StorageClient + Send + Sync + 'static> ForeachExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        variable: String,
+        list: ContextualExpression,
+        body: Vec<PlanNodeEnum>,
+        factory: ExecutorFactory<S>,
+        context: ExecutionContext,
+    ) -> Self {
+        let expr_context = context.expression_context().clone();
+        Self {
+            base: BaseExecutor::new(id, "ForeachExecutor".to_string(), storage, expr_context),
+            variable,
+            list,
+            body,
+            factory,
+            context,
+            input_executor: None,
+        }
+    }
+
+    /// Elements of the list for one row; NULL iterates over nothing
+    fn evaluate_list(&self, variables: &HashMap<String, Value>) -> DBResult<Vec<Value>> {
+        let expression = self
+            .context
+            .resolve(&self.list)
+            .ok_or_else(|| DBError::query("FOREACH list expression does not exist"))?;
+
+        let mut eval_context = DefaultExpressionContext::new();
+        for (name, value) in self
+            .context
+            .row_variables
+            .iter()
+            .chain(variables)
+            .map(|(name, value)| (name.clone(), value.clone()))
+        {
+            eval_context.set_variable(name, value);
+        }
+
+        match ExpressionEvaluator::evaluate(&expression, &mut eval_context)
+            .map_err(|e| DBError::query(e.to_string()))?
+        {
+            Value::List(list) => Ok(list.values),
+            Value::Null(_) => Ok(Vec::new()),
+            other => Err(DBError::query(format!(
+                "FOREACH expects a list, got {:?}",
+                other.get_type()
+            ))),
+        }
+    }
+
+    fn execute_foreach(&mut self) -> DBResult<DataSet> {
+        let input = input_rows(self.input_executor.as_mut())?;
+
+        for row in &input.rows {
+            let variables = row_variables(&input.col_names, row);
+            for element in self.evaluate_list(&variables)? {
+                let mut element_variables = variables.clone();
+                element_variables.insert(self.variable.clone(), element);
+                for plan in &self.body {
+                    run_plan(
+                        plan,
+                        element_variables.clone(),
+                        &self.factory,
+                        self.get_storage(),
+                        &self.context,
+                    )?;
+                }
+            }
+        }
+
+        Ok(input)
+    }
+}
+
+impl<S: StorageClient + Send + 'static> InputExecutor<S> for ForeachExecutor<S> {
+    fn set_input(&mut self, input: ExecutorEnum<S>) {
+        self.input_executor = Some(Box::new(input));
+    }
+
+    fn get_input(&self) -> Option<&ExecutorEnum<S>> {
+        self.input_executor.as_ref().map(|b| b.as_ref())
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for ForeachExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = self.execute_foreach()?;
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        Ok(())
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        Ok(())
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient + Send + 'static> HasStorage<S> for ForeachExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
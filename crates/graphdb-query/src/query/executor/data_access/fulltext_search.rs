@@ -120,7 +120,7 @@ impl<S: StorageReader> FulltextSearchExecutor<S> {
     }
 
     /// Convert FulltextQueryExpr to search query string
-    fn convert_query_to_string(&self, expr: &FulltextQueryExpr) -> String {
+    pub(crate) fn convert_query_to_string(expr: &FulltextQueryExpr) -> String {
         match expr {
             FulltextQueryExpr::Simple(text) => text.clone(),
             FulltextQueryExpr::Field(field, text) => format!("{}:{}", field, text),
@@ -139,7 +139,7 @@ impl<S: StorageReader> FulltextSearchExecutor<S> {
                     parts.push(format!(
                         "+({})",
                         must.iter()
-                            .map(|e| self.convert_query_to_string(e))
+                            .map(|e| Self::convert_query_to_string(e))
                             .collect::<Vec<_>>()
                             .join(" ")
                     ));
@@ -149,7 +149,7 @@ impl<S: StorageReader> FulltextSearchExecutor<S> {
                         "({})",
                         should
                             .iter()
-                            .map(|e| self.convert_query_to_string(e))
+                            .map(|e| Self::convert_query_to_string(e))
                             .collect::<Vec<_>>()
                             .join(" ")
                     ));
@@ -159,7 +159,7 @@ impl<S: StorageReader> FulltextSearchExecutor<S> {
                         "-({})",
                         must_not
                             .iter()
-                            .map(|e| self.convert_query_to_string(e))
+                            .map(|e| Self::convert_query_to_string(e))
                             .collect::<Vec<_>>()
                             .join(" ")
                     ));
@@ -408,7 +408,7 @@ impl<S: StorageReader> Executor<S> for FulltextSearchExecutor<S> {
     fn execute(&mut self) -> DBResult<ExecutionResult> {
         let (space_id, tag_name, field_name) = self.resolve_metadata()?;
 
-        let query_string = self.convert_query_to_string(&self.statement.query);
+        let query_string = Self::convert_query_to_string(&self.statement.query);
 
         let limit = self.statement.limit.unwrap_or(100);
 
@@ -0,0 +1,635 @@
+//! Hybrid Search Executor
+//!
+//! This module implements the executor for `SEARCH HYBRID`, which runs a full-text
+//! query and a vector query against the same tag and fuses the two rankings.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::error::DBError;
+use crate::core::types::VertexId;
+use crate::core::value::null::NullType;
+use crate::core::{Value, Vertex};
+use crate::query::executor::base::{
+    BaseExecutor, DBResult, ExecutionResult, Executor, ExecutorStats, HasStorage,
+};
+use crate::query::executor::data_access::fulltext_search::FulltextSearchExecutor;
+use crate::query::executor::expression::evaluator::expression_evaluator::ExpressionEvaluator;
+use crate::query::executor::expression::evaluator::traits::ExpressionContext;
+use crate::query::executor::expression::DefaultExpressionContext;
+use crate::query::parser::ast::hybrid::{FusionMethod, HybridSearchStatement};
+use crate::query::parser::ast::vector::{VectorQueryExpr, VectorQueryType};
+use crate::query::planning::plan::core::nodes::search::hybrid::HybridSearchNode;
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+use crate::search::manager::FulltextIndexManager;
+use crate::storage::StorageReader;
+use crate::sync::vector_sync::VectorSyncCoordinator;
+use parking_lot::RwLock;
+
+/// A hit after fusing the full-text and vector rankings
+#[derive(Debug, Clone, PartialEq)]
+pub struct FusedHit {
+    pub vid: VertexId,
+    /// Fused score; higher is better
+    pub score: f64,
+    pub fulltext_score: Option<f64>,
+    pub vector_score: Option<f64>,
+    /// 1-based rank in the full-text list
+    pub fulltext_rank: Option<usize>,
+    /// 1-based rank in the vector list
+    pub vector_rank: Option<usize>,
+}
+
+impl FusedHit {
+    fn new(vid: VertexId) -> Self {
+        Self {
+            vid,
+            score: 0.0,
+            fulltext_score: None,
+            vector_score: None,
+            fulltext_rank: None,
+            vector_rank: None,
+        }
+    }
+
+    fn best_rank(&self) -> usize {
+        self.fulltext_rank
+            .into_iter()
+            .chain(self.vector_rank)
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/// Fuse two ranked lists of `(vid, score)` pairs, each ordered best first
+///
+/// A vertex that appears more than once in a list keeps its first (best) position.
+/// The result is ordered by fused score, ties broken by the best component rank.
+pub fn fuse(
+    fulltext: &[(VertexId, f64)],
+    vector: &[(VertexId, f64)],
+    method: FusionMethod,
+) -> Vec<FusedHit> {
+    let mut order: Vec<VertexId> = Vec::new();
+    let mut hits: HashMap<VertexId, FusedHit> = HashMap::new();
+
+    for (rank, (vid, score)) in fulltext.iter().enumerate() {
+        let hit = hits.entry(*vid).or_insert_with(|| {
+            order.push(*vid);
+            FusedHit::new(*vid)
+        });
+        if hit.fulltext_rank.is_none() {
+            hit.fulltext_rank = Some(rank + 1);
+            hit.fulltext_score = Some(*score);
+        }
+    }
+    for (rank, (vid, score)) in vector.iter().enumerate() {
+        let hit = hits.entry(*vid).or_insert_with(|| {
+            order.push(*vid);
+            FusedHit::new(*vid)
+        });
+        if hit.vector_rank.is_none() {
+            hit.vector_rank = Some(rank + 1);
+            hit.vector_score = Some(*score);
+        }
+    }
+
+    let fulltext_range = score_range(fulltext);
+    let vector_range = score_range(vector);
+
+    let mut fused: Vec<FusedHit> = order
+        .into_iter()
+        .filter_map(|vid| hits.remove(&vid))
+        .map(|mut hit| {
+            hit.score = match method {
+                FusionMethod::Rrf { k } => {
+                    let rrf = |rank: Option<usize>| rank.map(|r| 1.0 / (k + r as f64));
+                    rrf(hit.fulltext_rank).unwrap_or(0.0) + rrf(hit.vector_rank).unwrap_or(0.0)
+                }
+                FusionMethod::Weighted {
+                    fulltext: fulltext_weight,
+                    vector: vector_weight,
+                } => {
+                    let fulltext_part = hit
+                        .fulltext_score
+                        .map(|s| normalize(s, fulltext_range))
+                        .unwrap_or(0.0);
+                    let vector_part = hit
+                        .vector_score
+                        .map(|s| normalize(s, vector_range))
+                        .unwrap_or(0.0);
+                    fulltext_weight * fulltext_part + vector_weight * vector_part
+                }
+            };
+            hit
+        })
+        .collect();
+
+    fused.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.best_rank().cmp(&b.best_rank()))
+    });
+    fused
+}
+
+fn score_range(hits: &[(VertexId, f64)]) -> (f64, f64) {
+    hits.iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (_, s)| {
+            (min.min(*s), max.max(*s))
+        })
+}
+
+/// Min-max normalisation; a list whose scores are all equal maps to 1.0
+fn normalize(score: f64, (min, max): (f64, f64)) -> f64 {
+    if max > min {
+        (score - min) / (max - min)
+    } else {
+        1.0
+    }
+}
+
+/// Hybrid search executor (SEARCH HYBRID statement)
+pub struct HybridSearchExecutor<S: StorageReader> {
+    base: BaseExecutor<S>,
+    node: HybridSearchNode,
+    fulltext_manager: Arc<FulltextIndexManager>,
+    coordinator: Arc<VectorSyncCoordinator>,
+}
+
+/// Tag and fields shared by the two indexes of a hybrid search
+struct HybridTarget {
+    space_id: u64,
+    tag_name: String,
+    fulltext_field: String,
+    vector_field: String,
+}
+
+impl<S: StorageReader> HybridSearchExecutor<S> {
+    /// Create a new hybrid search executor
+    pub fn new(
+        id: i64,
+        node: HybridSearchNode,
+        storage: Arc<RwLock<S>>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+        fulltext_manager: Arc<FulltextIndexManager>,
+        coordinator: Arc<VectorSyncCoordinator>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "HybridSearchExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            node,
+            fulltext_manager,
+            coordinator,
+        }
+    }
+
+    /// Use the metadata pre-resolved by the planner, or look both indexes up
+    fn resolve_target(&self) -> DBResult<HybridTarget> {
+        if !self.node.tag_name.is_empty()
+            && !self.node.fulltext_field.is_empty()
+            && !self.node.vector_field.is_empty()
+        {
+            return Ok(HybridTarget {
+                space_id: self.node.space_id,
+                tag_name: self.node.tag_name.clone(),
+                fulltext_field: self.node.fulltext_field.clone(),
+                vector_field: self.node.vector_field.clone(),
+            });
+        }
+
+        let fulltext_index = self
+            .fulltext_manager
+            .list_indexes()
+            .into_iter()
+            .find(|index| index.index_name == self.node.fulltext_index)
+            .ok_or_else(|| {
+                DBError::validation(format!(
+                    "Fulltext index '{}' not found",
+                    self.node.fulltext_index
+                ))
+            })?;
+
+        let vector_name = &self.node.vector_index;
+        let vector_index = self
+            .coordinator
+            .list_indexes()
+            .into_iter()
+            .find(|index| {
+                index.collection_name == *vector_name
+                    || index.index_name.as_deref() == Some(vector_name.as_str())
+                    || format!("{}_{}", index.tag_name, index.field_name) == *vector_name
+            })
+            .ok_or_else(|| {
+                DBError::validation(format!("Vector index '{}' not found", vector_name))
+            })?;
+
+        if fulltext_index.tag_name != vector_index.tag_name {
+            return Err(DBError::validation(format!(
+                "SEARCH HYBRID requires both indexes on the same tag, got '{}' and '{}'",
+                fulltext_index.tag_name, vector_index.tag_name
+            )));
+        }
+
+        Ok(HybridTarget {
+            space_id: fulltext_index.space_id,
+            tag_name: fulltext_index.tag_name,
+            fulltext_field: fulltext_index.field_name,
+            vector_field: vector_index.field_name,
+        })
+    }
+
+    /// Ranked full-text hits, best first
+    fn search_fulltext(&self, target: &HybridTarget) -> DBResult<Vec<(VertexId, f64)>> {
+        let query_string =
+            FulltextSearchExecutor::<S>::convert_query_to_string(&self.node.fulltext_query);
+
+        let results = futures::executor::block_on(self.fulltext_manager.search(
+            target.space_id,
+            &target.tag_name,
+            &target.fulltext_field,
+            &query_string,
+            self.node.candidate_limit(),
+        ))
+        .map_err(DBError::from)?;
+
+        results
+            .iter()
+            .map(|result| {
+                let vid = VertexId::try_from(&result.doc_id).map_err(DBError::from)?;
+                Ok((vid, result.score as f64))
+            })
+            .collect()
+    }
+
+    /// Ranked vector hits, best first
+    ///
+    /// Hits whose payload carries no vertex id cannot be joined with the graph and are dropped.
+    fn search_vector(&self, target: &HybridTarget) -> DBResult<Vec<(VertexId, f64)>> {
+        // If engine is disabled, the vector side contributes nothing
+        if self.coordinator.is_disabled_engine() {
+            return Ok(Vec::new());
+        }
+
+        let query_vector = self.parse_query_vector(&self.node.vector_query)?;
+        let limit = self.node.candidate_limit();
+
+        let results = tokio::runtime::Handle::current()
+            .block_on(async {
+                self.coordinator
+                    .search_by_location(
+                        target.space_id,
+                        &target.tag_name,
+                        &target.vector_field,
+                        query_vector,
+                        limit,
+                    )
+                    .await
+            })
+            .map_err(|e| DBError::internal(format!("Vector search failed: {}", e)))?;
+
+        Ok(results
+            .into_iter()
+            .filter_map(|result| {
+                let vid = result
+                    .payload
+                    .as_ref()
+                    .and_then(|payload| payload.get("vertex_id"))
+                    .and_then(|v| serde_json::from_value::<VertexId>(v.clone()).ok())?;
+                Some((vid, result.score as f64))
+            })
+            .collect())
+    }
+
+    /// Parse query vector from VectorQueryExpr
+    fn parse_query_vector(&self, query: &VectorQueryExpr) -> DBResult<Vec<f32>> {
+        match query.query_type {
+            VectorQueryType::Vector => {
+                let text = query
+                    .query_data
+                    .trim()
+                    .trim_start_matches('[')
+                    .trim_end_matches(']');
+                text.split(',')
+                    .map(|s| s.trim().parse::<f32>().ok())
+                    .collect::<Option<Vec<f32>>>()
+                    .ok_or_else(|| {
+                        DBError::validation(format!("Invalid vector format: {}", query.query_data))
+                    })
+            }
+            VectorQueryType::Text => {
+                let text = &query.query_data;
+                let coordinator = self.coordinator.clone();
+                tokio::runtime::Handle::current().block_on(async move {
+                    coordinator
+                        .embed_text(text)
+                        .await
+                        .map_err(|e| DBError::internal(format!("Text embedding failed: {}", e)))
+                })
+            }
+            VectorQueryType::Parameter => {
+                let param_name = &query.query_data;
+                match self.base.context.get_param(param_name) {
+                    Some(Value::List(values)) => values
+                        .iter()
+                        .map(|v| match v {
+                            Value::Float(f) => Ok(*f),
+                            Value::Double(f) => Ok(*f as f32),
+                            _ => Err(DBError::validation(format!(
+                                "Parameter {} contains non-float value",
+                                param_name
+                            ))),
+                        })
+                        .collect(),
+                    Some(_) => Err(DBError::validation(format!(
+                        "Parameter {} is not a vector",
+                        param_name
+                    ))),
+                    None => Err(DBError::validation(format!(
+                        "Parameter {} not found",
+                        param_name
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// Variables visible to WHERE and YIELD for one hit
+    fn hit_variables(
+        &self,
+        hit: &FusedHit,
+        vertex: &Vertex,
+        tag_name: &str,
+    ) -> HashMap<String, Value> {
+        let mut variables = HashMap::new();
+        if let Some(tag) = vertex
+            .tags
+            .iter()
+            .find(|tag| tag.name == tag_name)
+            .or_else(|| vertex.tags.first())
+        {
+            for (name, value) in &tag.properties {
+                variables.insert(name.clone(), value.clone());
+            }
+        }
+
+        let optional_score = |score: Option<f64>| {
+            score
+                .map(Value::Double)
+                .unwrap_or(Value::Null(NullType::Null))
+        };
+        let optional_rank = |rank: Option<usize>| {
+            rank.map(|r| Value::BigInt(r as i64))
+                .unwrap_or(Value::Null(NullType::Null))
+        };
+        variables.insert("vid".to_string(), Value::from(hit.vid));
+        variables.insert("score".to_string(), Value::Double(hit.score));
+        variables.insert(
+            "fulltext_score".to_string(),
+            optional_score(hit.fulltext_score),
+        );
+        variables.insert("vector_score".to_string(), optional_score(hit.vector_score));
+        variables.insert(
+            "fulltext_rank".to_string(),
+            optional_rank(hit.fulltext_rank),
+        );
+        variables.insert("vector_rank".to_string(), optional_rank(hit.vector_rank));
+        variables.insert(
+            "vertex".to_string(),
+            Value::Vertex(Box::new(vertex.clone())),
+        );
+        variables
+    }
+
+    /// Evaluate the WHERE filter; NULL and non-boolean results reject the hit
+    fn matches_filter(&self, variables: &HashMap<String, Value>) -> DBResult<bool> {
+        let Some(where_clause) = &self.node.where_clause else {
+            return Ok(true);
+        };
+        let expression = where_clause
+            .get_expression()
+            .ok_or_else(|| DBError::query("SEARCH HYBRID WHERE expression does not exist"))?;
+
+        let mut eval_context = DefaultExpressionContext::new();
+        for (name, value) in where_clause
+            .context()
+            .bound_variables()
+            .into_iter()
+            .chain(variables.clone())
+        {
+            eval_context.set_variable(name, value);
+        }
+
+        let value = ExpressionEvaluator::evaluate(&expression, &mut eval_context)
+            .map_err(|e| DBError::query(e.to_string()))?;
+        Ok(matches!(value, Value::Bool(true)))
+    }
+
+    fn execute_search(&self) -> DBResult<DataSet> {
+        let target = self.resolve_target()?;
+        let fulltext_hits = self.search_fulltext(&target)?;
+        let vector_hits = self.search_vector(&target)?;
+        let fused = fuse(&fulltext_hits, &vector_hits, self.node.fusion);
+
+        let columns: Vec<(String, String)> = match &self.node.yield_clause {
+            Some(yield_clause) => yield_clause
+                .items
+                .iter()
+                .map(|item| {
+                    let alias = item.alias.clone().unwrap_or_else(|| item.expr.clone());
+                    (item.expr.clone(), alias)
+                })
+                .collect(),
+            None => HybridSearchStatement::DEFAULT_COLUMNS
+                .iter()
+                .map(|name| (name.to_string(), name.to_string()))
+                .collect(),
+        };
+
+        let mut dataset = DataSet::new();
+        dataset.col_names = columns.iter().map(|(_, alias)| alias.clone()).collect();
+
+        let storage = self.get_storage().clone();
+        let storage_guard = storage.read();
+        let mut skipped = 0;
+
+        for hit in fused {
+            if dataset.rows.len() >= self.node.limit {
+                break;
+            }
+            // Index entries can outlive their vertex; such hits are dropped
+            let Some(vertex) = storage_guard
+                .get_vertex("", &hit.vid)
+                .map_err(DBError::from)?
+            else {
+                continue;
+            };
+
+            let variables = self.hit_variables(&hit, &vertex, &target.tag_name);
+            if !self.matches_filter(&variables)? {
+                continue;
+            }
+            if skipped < self.node.offset {
+                skipped += 1;
+                continue;
+            }
+
+            let row = columns
+                .iter()
+                .map(|(expr, _)| {
+                    let name = if expr == "id" { "vid" } else { expr.as_str() };
+                    variables
+                        .get(name)
+                        .cloned()
+                        .unwrap_or(Value::Null(NullType::Null))
+                })
+                .collect();
+            dataset.rows.push(row);
+        }
+
+        Ok(dataset)
+    }
+}
+
+impl<S: StorageReader> Executor<S> for HybridSearchExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = self.execute_search()?;
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+
+    fn id(&self) -> i64 {
+        self.base.id()
+    }
+
+    fn name(&self) -> &str {
+        "HybridSearchExecutor"
+    }
+
+    fn description(&self) -> &str {
+        "Hybrid Search Executor"
+    }
+
+    fn stats(&self) -> &ExecutorStats {
+        self.base.stats()
+    }
+
+    fn stats_mut(&mut self) -> &mut ExecutorStats {
+        self.base.stats_mut()
+    }
+}
+
+impl<S: StorageReader> HasStorage<S> for HybridSearchExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vid(id: i64) -> VertexId {
+        VertexId::from(id)
+    }
+
+    #[test]
+    fn test_rrf_rewards_hits_in_both_lists() {
+        let fulltext = vec![(vid(1), 9.0), (vid(2), 5.0), (vid(3), 1.0)];
+        let vector = vec![(vid(3), 0.9), (vid(4), 0.8)];
+
+        let fused = fuse(&fulltext, &vector, FusionMethod::Rrf { k: 60.0 });
+
+        assert_eq!(fused.len(), 4);
+        assert_eq!(fused[0].vid, vid(3));
+        let expected = 1.0 / 63.0 + 1.0 / 61.0;
+        assert!((fused[0].score - expected).abs() < 1e-12);
+        assert_eq!(fused[0].fulltext_rank, Some(3));
+        assert_eq!(fused[0].vector_rank, Some(1));
+
+        // Among hits found by a single list, the best-ranked one comes first
+        assert_eq!(fused[1].vid, vid(1));
+        assert_eq!(fused[1].vector_score, None);
+    }
+
+    #[test]
+    fn test_rrf_ties_prefer_better_rank() {
+        let fulltext = vec![(vid(1), 3.0)];
+        let vector = vec![(vid(2), 0.5)];
+
+        let fused = fuse(&fulltext, &vector, FusionMethod::Rrf { k: 60.0 });
+
+        assert_eq!(fused.len(), 2);
+        assert_eq!(fused[0].score, fused[1].score);
+        assert_eq!(fused[0].vid, vid(1));
+    }
+
+    #[test]
+    fn test_weighted_normalises_each_list() {
+        // Full-text scores are on a very different scale from vector scores
+        let fulltext = vec![(vid(1), 20.0), (vid(2), 10.0)];
+        let vector = vec![(vid(2), 0.9), (vid(1), 0.1)];
+
+        let fused = fuse(
+            &fulltext,
+            &vector,
+            FusionMethod::Weighted {
+                fulltext: 0.3,
+                vector: 0.7,
+            },
+        );
+
+        assert_eq!(fused[0].vid, vid(2));
+        assert!((fused[0].score - 0.7).abs() < 1e-12);
+        assert!((fused[1].score - 0.3).abs() < 1e-12);
+        assert_eq!(fused[1].fulltext_score, Some(20.0));
+    }
+
+    #[test]
+    fn test_weighted_single_hit_list_counts_fully() {
+        let fulltext = vec![(vid(1), 4.2)];
+        let vector: Vec<(VertexId, f64)> = Vec::new();
+
+        let fused = fuse(&fulltext, &vector, FusionMethod::default());
+        assert_eq!(fused.len(), 1);
+
+        let fused = fuse(
+            &fulltext,
+            &vector,
+            FusionMethod::Weighted {
+                fulltext: 0.5,
+                vector: 0.5,
+            },
+        );
+        assert!((fused[0].score - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_duplicate_hits_keep_best_position() {
+        let fulltext = vec![(vid(1), 2.0), (vid(1), 1.0)];
+        let fused = fuse(&fulltext, &[], FusionMethod::Rrf { k: 1.0 });
+
+        assert_eq!(fused.len(), 1);
+        assert_eq!(fused[0].fulltext_rank, Some(1));
+        assert_eq!(fused[0].fulltext_score, Some(2.0));
+        assert!((fused[0].score - 0.5).abs() < 1e-12);
+    }
+}
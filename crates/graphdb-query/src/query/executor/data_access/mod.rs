@@ -5,11 +5,15 @@
 pub mod edge;
 #[cfg(feature = "fulltext-search")]
 pub mod fulltext_search;
+#[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+pub mod hybrid_search;
 pub mod index;
 #[cfg(feature = "fulltext-search")]
 pub mod match_fulltext;
+pub mod materialized_view;
 pub mod neighbor;
 pub mod path;
+pub mod procedure;
 pub mod property;
 pub mod search;
 #[cfg(feature = "qdrant")]
@@ -23,11 +27,15 @@ pub use edge::{GetEdgesExecutor, ScanEdgesExecutor};
 pub use fulltext_search::{
     FulltextScanConfig, FulltextScanExecutor, FulltextSearchExecutor, FulltextSearchExecutorParams,
 };
+#[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+pub use hybrid_search::HybridSearchExecutor;
 pub use index::LookupIndexExecutor;
 #[cfg(feature = "fulltext-search")]
 pub use match_fulltext::MatchFulltextExecutor;
+pub use materialized_view::MaterializedViewScanExecutor;
 pub use neighbor::GetNeighborsExecutor;
 pub use path::AllPathsExecutor;
+pub use procedure::ProcedureCallExecutor;
 pub use property::GetPropExecutor;
 pub use search::IndexScanExecutor;
 #[cfg(feature = "qdrant")]
@@ -0,0 +1,1179 @@
+//! Procedure Call Executor
+//!
+//! Executes `CALL name(args)`: evaluates the arguments, binds them against the procedure
+//! signature and returns the procedure rows, restricted to the columns selected by YIELD.
+//!
+//! Graph algorithm procedures load their projection while holding the storage read lock,
+//! so every scan sees the same snapshot at the statement's read timestamp. Iteration and
+//! convergence figures are recorded in the executor statistics and shown by PROFILE.
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::core::types::{ContextualExpression, EdgeDirection, VertexId};
+use crate::core::value::list::List;
+use crate::core::{RoleType, Value, Vertex};
+use crate::query::executor::base::{BaseExecutor, DBResult, ExecutionResult, Executor, HasStorage};
+use crate::query::executor::control_flow::SharedBatchTransactionControl;
+use crate::query::executor::expression::evaluator::expression_evaluator::ExpressionEvaluator;
+use crate::query::executor::expression::functions::{
+    BuiltinProcedure, Procedure, ProcedureSignature, SharedProcedureRegistry,
+};
+use crate::query::executor::expression::DefaultExpressionContext;
+use crate::query::executor::graph_operations::graph_traversal::algorithms::{
+    betweenness_centrality, closeness_centrality, degree_centrality, label_propagation, louvain,
+    page_rank, strongly_connected_components, triangle_count, weakly_connected_components,
+    CentralityAlgorithm, CentralityScores, CommunityAlgorithm, EdgeWeightConfig, GraphProjection,
+    LabelPropagationConfig, LouvainConfig, PageRankConfig, ProjectionConfig,
+};
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+use crate::storage::StorageClient;
+
+/// Statistics of a call, recorded as `ExecutorStats::other_stats` entries
+type ProcedureStats = Vec<(&'static str, String)>;
+
+/// Procedure call executor
+pub struct ProcedureCallExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    space_name: Option<String>,
+    procedure: String,
+    args: Vec<ContextualExpression>,
+    yield_columns: Vec<String>,
+    registry: SharedProcedureRegistry,
+    /// Transactions of the `.write` procedures, installed by the service running the query
+    transaction_control: Option<SharedBatchTransactionControl>,
+    /// Role of the session user; procedures whose mode needs a higher role are refused
+    role: Option<RoleType>,
+}
+
+impl<S: StorageClient> std::fmt::Debug for ProcedureCallExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProcedureCallExecutor")
+            .field("base", &"BaseExecutor")
+            .field("space_name", &self.space_name)
+            .field("procedure", &self.procedure)
+            .field("args", &self.args)
+            .field("yield_columns", &self.yield_columns)
+            .field("registry", &self.registry)
+            .field(
+                "transaction_control",
+                &self
+                    .transaction_control
+                    .as_ref()
+                    .map(|_| "Arc<dyn BatchTransactionControl>"),
+            )
+            .field("role", &self.role)
+            .finish()
+    }
+}
+
+impl<S: StorageClient> ProcedureCallExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: Option<String>,
+        procedure: String,
+        args: Vec<ContextualExpression>,
+        yield_columns: Vec<String>,
+        registry: SharedProcedureRegistry,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "ProcedureCallExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+            procedure,
+            args,
+            yield_columns,
+            registry,
+            transaction_control: None,
+            role: None,
+        }
+    }
+
+    pub fn with_transaction_control(
+        mut self,
+        transaction_control: Option<SharedBatchTransactionControl>,
+    ) -> Self {
+        self.transaction_control = transaction_control;
+        self
+    }
+
+    pub fn with_role(mut self, role: Option<RoleType>) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Evaluate the call arguments; they may only reference literals and parameters.
+    fn evaluate_args(&self) -> Result<Vec<Value>, String> {
+        let mut context = DefaultExpressionContext::new();
+        self.args
+            .iter()
+            .map(|arg| {
+                let expression = arg
+                    .get_expression()
+                    .ok_or_else(|| format!("Invalid argument expression for {}", self.procedure))?;
+                ExpressionEvaluator::evaluate(&expression, &mut context).map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    fn current_space(&self, procedure: BuiltinProcedure) -> Result<&str, String> {
+        self.space_name.as_deref().ok_or_else(|| {
+            format!(
+                "No space selected, USE a space before calling {}",
+                procedure.name()
+            )
+        })
+    }
+
+    fn call_builtin(
+        &self,
+        procedure: BuiltinProcedure,
+        args: &[Value],
+    ) -> Result<(Vec<Vec<Value>>, ProcedureStats), String> {
+        let storage = self.get_storage().read();
+        let mut stats = ProcedureStats::new();
+        let rows = match procedure {
+            BuiltinProcedure::Labels => storage
+                .list_tags(self.current_space(procedure)?)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|tag| vec![Value::String(tag.tag_name)])
+                .collect(),
+            BuiltinProcedure::RelationshipTypes => storage
+                .list_edge_types(self.current_space(procedure)?)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|edge| vec![Value::String(edge.edge_type_name)])
+                .collect(),
+            BuiltinProcedure::Indexes => storage
+                .list_tag_indexes(self.current_space(procedure)?)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|index| {
+                    vec![
+                        Value::String(index.name),
+                        Value::String(index.schema_name),
+                        string_list(index.properties),
+                        Value::Bool(index.is_unique),
+                    ]
+                })
+                .collect(),
+            BuiltinProcedure::Constraints => storage
+                .list_constraints(self.current_space(procedure)?)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|constraint| {
+                    vec![
+                        Value::String(constraint.name),
+                        Value::String(constraint.schema_name),
+                        Value::String(constraint.kind.to_string()),
+                        string_list(constraint.properties),
+                    ]
+                })
+                .collect(),
+            BuiltinProcedure::Procedures => self
+                .registry
+                .read()
+                .signatures()
+                .into_iter()
+                .map(|signature| {
+                    vec![
+                        Value::String(signature.name.clone()),
+                        Value::String(signature.to_string()),
+                        Value::String(signature.mode.to_string()),
+                        Value::String(signature.description),
+                    ]
+                })
+                .collect(),
+            BuiltinProcedure::Centrality(algorithm) => {
+                let space = self.current_space(procedure)?;
+                let options = CentralityOptions::parse(algorithm, args)?;
+                let graph = GraphProjection::load(&*storage, space, &options.projection)
+                    .map_err(|e| e.to_string())?;
+                let result = options.run(algorithm, &graph, args)?;
+                stats.push(("iterations", result.iterations.to_string()));
+                graph
+                    .vertices()
+                    .iter()
+                    .zip(result.scores)
+                    .map(|(vid, score)| vec![Value::from(*vid), Value::Double(score)])
+                    .collect()
+            }
+            BuiltinProcedure::CentralityWrite(algorithm) => {
+                // Writing needs the write lock.
+                drop(storage);
+                return self.write_centrality(procedure, algorithm, args);
+            }
+            BuiltinProcedure::Community(algorithm) => {
+                let space = self.current_space(procedure)?;
+                let options = CommunityOptions::parse(args)?;
+                let graph = GraphProjection::load(&*storage, space, &options.projection)
+                    .map_err(|e| e.to_string())?;
+                let result = options.run(algorithm, &graph);
+                stats = result.stats;
+                graph
+                    .vertices()
+                    .iter()
+                    .zip(result.values)
+                    .map(|(vid, value)| vec![Value::from(*vid), value])
+                    .collect()
+            }
+            BuiltinProcedure::CommunityWrite(algorithm) => {
+                drop(storage);
+                return self.write_community(procedure, algorithm, args);
+            }
+        };
+        Ok((rows, stats))
+    }
+
+    /// Compute centrality scores and store them in `config.writeProperty`
+    fn write_centrality(
+        &self,
+        procedure: BuiltinProcedure,
+        algorithm: CentralityAlgorithm,
+        args: &[Value],
+    ) -> Result<(Vec<Vec<Value>>, ProcedureStats), String> {
+        let space = self.current_space(procedure)?;
+        let options = CentralityOptions::parse(algorithm, args)?;
+        let (property, target_tags) = options.write.target(procedure, &options.projection)?;
+
+        let (graph, result) = {
+            let storage = self.get_storage().read();
+            let graph = GraphProjection::load(&*storage, space, &options.projection)
+                .map_err(|e| e.to_string())?;
+            let result = options.run(algorithm, &graph, args)?;
+            (graph, result)
+        };
+
+        let values = result.scores.iter().map(|score| Value::Double(*score));
+        let written =
+            self.write_values(procedure, space, &graph, values, &property, &target_tags)?;
+
+        Ok((
+            vec![vec![
+                Value::BigInt(graph.vertex_count() as i64),
+                Value::BigInt(written as i64),
+                Value::BigInt(result.iterations as i64),
+            ]],
+            vec![("iterations", result.iterations.to_string())],
+        ))
+    }
+
+    /// Compute community results and store them in `config.writeProperty`
+    fn write_community(
+        &self,
+        procedure: BuiltinProcedure,
+        algorithm: CommunityAlgorithm,
+        args: &[Value],
+    ) -> Result<(Vec<Vec<Value>>, ProcedureStats), String> {
+        let space = self.current_space(procedure)?;
+        let options = CommunityOptions::parse(args)?;
+        let (property, target_tags) = options.write.target(procedure, &options.projection)?;
+
+        let (graph, result) = {
+            let storage = self.get_storage().read();
+            let graph = GraphProjection::load(&*storage, space, &options.projection)
+                .map_err(|e| e.to_string())?;
+            let result = options.run(algorithm, &graph);
+            (graph, result)
+        };
+
+        let written = self.write_values(
+            procedure,
+            space,
+            &graph,
+            result.values.into_iter(),
+            &property,
+            &target_tags,
+        )?;
+
+        Ok((
+            vec![vec![
+                Value::BigInt(graph.vertex_count() as i64),
+                Value::BigInt(written as i64),
+                Value::BigInt(result.iterations as i64),
+                Value::Bool(result.converged),
+            ]],
+            result.stats,
+        ))
+    }
+
+    /// Store one value per projected vertex in `property`, returning the number written
+    ///
+    /// All values are computed before the first write. The writes run in a transaction of
+    /// their own, or in the session's explicit transaction when one is open, so a failed
+    /// write rolls back every value stored by the statement.
+    fn write_values(
+        &self,
+        procedure: BuiltinProcedure,
+        space: &str,
+        graph: &GraphProjection,
+        values: impl Iterator<Item = Value>,
+        property: &str,
+        target_tags: &[String],
+    ) -> Result<usize, String> {
+        let control = match self.transaction_control.as_deref() {
+            Some(control) => control,
+            None => {
+                return self.write_values_with_restore(
+                    procedure,
+                    space,
+                    graph,
+                    values,
+                    property,
+                    target_tags,
+                )
+            }
+        };
+
+        // Inside an explicit transaction the writes join it and are undone with it.
+        if control.in_explicit_transaction() {
+            let mut storage = self.get_storage().write();
+            return update_vertices(&mut *storage, space, graph, values, property, target_tags)
+                .map(|originals| originals.len())
+                .map_err(|(e, _)| e);
+        }
+
+        let txn_id = control.begin_batch().map_err(|e| e.to_string())?;
+        let written = {
+            let mut storage = self.get_storage().write();
+            update_vertices(&mut *storage, space, graph, values, property, target_tags)
+                .map(|originals| originals.len())
+        };
+        match written {
+            Ok(written) => control
+                .commit_batch(txn_id)
+                .map(|()| written)
+                .map_err(|e| e.to_string()),
+            Err((e, _)) => match control.rollback_batch(txn_id) {
+                Ok(()) => Err(e),
+                Err(rollback_error) => Err(format!(
+                    "{}; rolling back transaction {} failed: {}",
+                    e, txn_id, rollback_error
+                )),
+            },
+        }
+    }
+
+    /// Write-back when the pipeline runs without a transaction manager
+    ///
+    /// There is no transaction to abort, so the vertices updated before a failed write are
+    /// restored to their original values before the write lock is released.
+    fn write_values_with_restore(
+        &self,
+        procedure: BuiltinProcedure,
+        space: &str,
+        graph: &GraphProjection,
+        values: impl Iterator<Item = Value>,
+        property: &str,
+        target_tags: &[String],
+    ) -> Result<usize, String> {
+        let mut storage = self.get_storage().write();
+        let (e, originals) =
+            match update_vertices(&mut *storage, space, graph, values, property, target_tags) {
+                Ok(originals) => return Ok(originals.len()),
+                Err(failure) => failure,
+            };
+
+        let mut failed_restores = 0;
+        for original in originals.into_iter().rev() {
+            if storage.update_vertex(space, original).is_err() {
+                failed_restores += 1;
+            }
+        }
+        if failed_restores == 0 {
+            return Err(e);
+        }
+        Err(format!(
+            "{}; {} vertices could not be restored after the aborted {}",
+            e,
+            failed_restores,
+            procedure.name()
+        ))
+    }
+
+    /// Keep only the yielded columns, in YIELD order
+    fn select_columns(
+        &self,
+        signature: &ProcedureSignature,
+        rows: Vec<Vec<Value>>,
+    ) -> Result<DataSet, String> {
+        if self.yield_columns.is_empty() {
+            return Ok(DataSet {
+                col_names: signature.output_names(),
+                rows,
+            });
+        }
+
+        let indices = self
+            .yield_columns
+            .iter()
+            .map(|column| {
+                signature
+                    .outputs
+                    .iter()
+                    .position(|output| &output.name == column)
+                    .ok_or_else(|| {
+                        format!(
+                            "Procedure {} has no output column '{}'",
+                            signature.name, column
+                        )
+                    })
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+
+        Ok(DataSet {
+            col_names: self.yield_columns.clone(),
+            rows: rows
+                .into_iter()
+                .map(|row| indices.iter().map(|&i| row[i].clone()).collect())
+                .collect(),
+        })
+    }
+
+    fn call(&self) -> Result<(DataSet, ProcedureStats), String> {
+        let procedure = self
+            .registry
+            .read()
+            .get(&self.procedure)
+            .ok_or_else(|| format!("Unknown procedure: {}", self.procedure))?;
+        let signature = procedure.signature();
+        if let Some(role) = self.role {
+            let required = signature.mode.required_role();
+            if role.to_byte() > required.to_byte() {
+                return Err(format!(
+                    "Permission denied: {} procedure {} requires the {} role, the session has {}",
+                    signature.mode, self.procedure, required, role
+                ));
+            }
+        }
+
+        let args = self.evaluate_args()?;
+        let args = signature.bind_args(&args).map_err(|e| e.to_string())?;
+
+        let (rows, stats) = match &procedure {
+            Procedure::Builtin(builtin) => self.call_builtin(*builtin, &args)?,
+            Procedure::Custom(custom) => (
+                custom.execute(&args).map_err(|e| e.to_string())?,
+                ProcedureStats::new(),
+            ),
+        };
+
+        Ok((self.select_columns(&signature, rows)?, stats))
+    }
+}
+
+fn string_list(values: Vec<String>) -> Value {
+    Value::list(List::from(
+        values.into_iter().map(Value::String).collect::<Vec<_>>(),
+    ))
+}
+
+/// Store one value per projected vertex in `property`, returning the original of every
+/// updated vertex
+///
+/// On failure the error comes with the originals of the vertices updated before it.
+fn update_vertices<S: StorageClient>(
+    storage: &mut S,
+    space: &str,
+    graph: &GraphProjection,
+    values: impl Iterator<Item = Value>,
+    property: &str,
+    target_tags: &[String],
+) -> Result<Vec<Vertex>, (String, Vec<Vertex>)> {
+    let mut originals = Vec::with_capacity(graph.vertex_count());
+    for (vid, value) in graph.vertices().iter().zip(values) {
+        let written = storage
+            .get_vertex(space, vid)
+            .map_err(|e| e.to_string())
+            .and_then(|vertex| match vertex {
+                Some(vertex) => {
+                    let mut updated = vertex.clone();
+                    set_property(&mut updated, target_tags, property, value)?;
+                    storage
+                        .update_vertex(space, updated)
+                        .map_err(|e| e.to_string())?;
+                    Ok(Some(vertex))
+                }
+                None => Ok(None),
+            });
+        match written {
+            Ok(Some(original)) => originals.push(original),
+            Ok(None) => {}
+            Err(e) => {
+                return Err((
+                    format!("Writing {} for vertex {} failed: {}", property, vid, e),
+                    originals,
+                ))
+            }
+        }
+    }
+    Ok(originals)
+}
+
+/// Store a value in the first target tag the vertex carries, or its first tag when no
+/// target tags are given
+fn set_property(
+    vertex: &mut Vertex,
+    target_tags: &[String],
+    property: &str,
+    value: Value,
+) -> Result<(), String> {
+    let position = vertex
+        .tags
+        .iter()
+        .position(|tag| target_tags.is_empty() || target_tags.contains(&tag.name));
+    match position {
+        Some(i) => {
+            vertex.tags[i]
+                .properties
+                .insert(property.to_string(), value);
+        }
+        None if vertex.tags.is_empty() => {
+            vertex.properties.insert(property.to_string(), value);
+        }
+        None => {
+            return Err(format!(
+                "vertex has none of the tags {}",
+                target_tags.join(", ")
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Graph selection keys shared by the config maps of all algorithm procedures
+///
+/// Returns `Ok(false)` for keys that are not about the projection.
+fn parse_projection_key(
+    projection: &mut ProjectionConfig,
+    key: &str,
+    value: &Value,
+) -> Result<bool, String> {
+    match key {
+        "vertexTags" => projection.tags = string_values(key, value)?,
+        "edgeTypes" => projection.edge_types = string_values(key, value)?,
+        "direction" => {
+            projection.direction = match string_value(key, value)?.to_uppercase().as_str() {
+                "OUT" => EdgeDirection::Out,
+                "IN" => EdgeDirection::In,
+                "BOTH" => EdgeDirection::Both,
+                other => {
+                    return Err(format!(
+                        "config.direction must be OUT, IN or BOTH, got {}",
+                        other
+                    ))
+                }
+            }
+        }
+        "weightProperty" => {
+            projection.weight = EdgeWeightConfig::Property(string_value(key, value)?)
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Write-back target of the `.write` procedures
+#[derive(Debug, Default)]
+struct WriteOptions {
+    property: Option<String>,
+    tag: Option<String>,
+}
+
+impl WriteOptions {
+    /// Returns `Ok(false)` for keys that are not about write-back
+    fn parse_key(&mut self, key: &str, value: &Value) -> Result<bool, String> {
+        match key {
+            "writeProperty" => self.property = Some(string_value(key, value)?),
+            "writeTag" => self.tag = Some(string_value(key, value)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Property to write and the tags it may be written to
+    fn target(
+        &self,
+        procedure: BuiltinProcedure,
+        projection: &ProjectionConfig,
+    ) -> Result<(String, Vec<String>), String> {
+        let property = self
+            .property
+            .clone()
+            .ok_or_else(|| format!("{} requires config.writeProperty", procedure.name()))?;
+        let tags = match &self.tag {
+            Some(tag) => vec![tag.clone()],
+            None => projection.tags.clone(),
+        };
+        Ok((property, tags))
+    }
+}
+
+/// Options of the centrality procedures, read from their config map
+#[derive(Debug, Default)]
+struct CentralityOptions {
+    projection: ProjectionConfig,
+    page_rank: PageRankConfig,
+    normalized: bool,
+    write: WriteOptions,
+}
+
+impl CentralityOptions {
+    fn parse(algorithm: CentralityAlgorithm, args: &[Value]) -> Result<Self, String> {
+        let config_index = usize::from(algorithm == CentralityAlgorithm::PersonalizedPageRank);
+        let mut options = Self {
+            normalized: true,
+            ..Self::default()
+        };
+        let Some(Value::Map(config)) = args.get(config_index) else {
+            return Ok(options);
+        };
+
+        for (key, value) in config.iter() {
+            if parse_projection_key(&mut options.projection, key, value)?
+                || options.write.parse_key(key, value)?
+            {
+                continue;
+            }
+            match key.as_str() {
+                "dampingFactor" => {
+                    let damping = number_value(key, value)?;
+                    if !(0.0..1.0).contains(&damping) {
+                        return Err("config.dampingFactor must be in [0, 1)".to_string());
+                    }
+                    options.page_rank.damping_factor = damping;
+                }
+                "maxIterations" => {
+                    options.page_rank.max_iterations = number_value(key, value)?.max(1.0) as usize
+                }
+                "tolerance" => options.page_rank.tolerance = number_value(key, value)?,
+                "normalized" => match value {
+                    Value::Bool(normalized) => options.normalized = *normalized,
+                    _ => return Err("config.normalized must be a boolean".to_string()),
+                },
+                other => return Err(format!("Unknown config key '{}'", other)),
+            }
+        }
+        Ok(options)
+    }
+
+    fn run(
+        &self,
+        algorithm: CentralityAlgorithm,
+        graph: &GraphProjection,
+        args: &[Value],
+    ) -> Result<CentralityScores, String> {
+        match algorithm {
+            CentralityAlgorithm::PageRank => Ok(page_rank(graph, &self.page_rank, &[])),
+            CentralityAlgorithm::PersonalizedPageRank => {
+                let sources = source_indices(graph, args.first())?;
+                Ok(page_rank(graph, &self.page_rank, &sources))
+            }
+            CentralityAlgorithm::Betweenness => {
+                betweenness_centrality(graph, self.normalized).map_err(|e| e.to_string())
+            }
+            CentralityAlgorithm::Closeness => {
+                closeness_centrality(graph).map_err(|e| e.to_string())
+            }
+            CentralityAlgorithm::Degree => Ok(degree_centrality(graph, self.normalized)),
+        }
+    }
+}
+
+/// Options of the community detection procedures, read from their config map
+#[derive(Debug, Default)]
+struct CommunityOptions {
+    projection: ProjectionConfig,
+    label_propagation: LabelPropagationConfig,
+    louvain: LouvainConfig,
+    write: WriteOptions,
+}
+
+/// Per-vertex values of a community detection procedure
+struct CommunityResult {
+    values: Vec<Value>,
+    iterations: usize,
+    converged: bool,
+    stats: ProcedureStats,
+}
+
+impl CommunityOptions {
+    fn parse(args: &[Value]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let Some(Value::Map(config)) = args.first() else {
+            return Ok(options);
+        };
+
+        for (key, value) in config.iter() {
+            if parse_projection_key(&mut options.projection, key, value)?
+                || options.write.parse_key(key, value)?
+            {
+                continue;
+            }
+            match key.as_str() {
+                "maxIterations" => {
+                    let max_iterations = number_value(key, value)?.max(1.0) as usize;
+                    options.label_propagation.max_iterations = max_iterations;
+                    options.louvain.max_iterations = max_iterations;
+                }
+                "maxLevels" => {
+                    options.louvain.max_levels = number_value(key, value)?.max(1.0) as usize
+                }
+                "tolerance" => options.louvain.tolerance = number_value(key, value)?,
+                other => return Err(format!("Unknown config key '{}'", other)),
+            }
+        }
+        Ok(options)
+    }
+
+    fn run(&self, algorithm: CommunityAlgorithm, graph: &GraphProjection) -> CommunityResult {
+        let communities = match algorithm {
+            CommunityAlgorithm::WeaklyConnectedComponents => weakly_connected_components(graph),
+            CommunityAlgorithm::StronglyConnectedComponents => strongly_connected_components(graph),
+            CommunityAlgorithm::LabelPropagation => {
+                label_propagation(graph, &self.label_propagation)
+            }
+            CommunityAlgorithm::Louvain => louvain(graph, &self.louvain),
+            CommunityAlgorithm::TriangleCount | CommunityAlgorithm::LocalClusteringCoefficient => {
+                let counts = triangle_count(graph);
+                let values = if algorithm == CommunityAlgorithm::TriangleCount {
+                    counts
+                        .triangles
+                        .iter()
+                        .map(|t| Value::BigInt(*t as i64))
+                        .collect()
+                } else {
+                    counts
+                        .coefficients
+                        .iter()
+                        .map(|c| Value::Double(*c))
+                        .collect()
+                };
+                return CommunityResult {
+                    values,
+                    iterations: 0,
+                    converged: true,
+                    stats: vec![("triangles", counts.total.to_string())],
+                };
+            }
+        };
+
+        let mut stats = vec![
+            ("communities", communities.count.to_string()),
+            ("iterations", communities.iterations.to_string()),
+            ("converged", communities.converged.to_string()),
+        ];
+        if let Some(modularity) = communities.modularity {
+            stats.push(("modularity", format!("{:.6}", modularity)));
+        }
+        CommunityResult {
+            values: communities
+                .labels
+                .iter()
+                .map(|label| Value::BigInt(*label as i64))
+                .collect(),
+            iterations: communities.iterations,
+            converged: communities.converged,
+            stats,
+        }
+    }
+}
+
+/// Positions of the personalised PageRank sources in the projection
+fn source_indices(graph: &GraphProjection, sources: Option<&Value>) -> Result<Vec<usize>, String> {
+    let sources = match sources {
+        Some(Value::List(list)) if !list.values.is_empty() => &list.values,
+        _ => return Err("sources must be a non-empty list of vertex ids".to_string()),
+    };
+    sources
+        .iter()
+        .map(|source| {
+            let vid = VertexId::try_from(source).map_err(|e| e.to_string())?;
+            graph
+                .index_of(&vid)
+                .ok_or_else(|| format!("Source vertex {} is not part of the projection", source))
+        })
+        .collect()
+}
+
+fn string_value(key: &str, value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        _ => Err(format!("config.{} must be a string", key)),
+    }
+}
+
+fn string_values(key: &str, value: &Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::String(s) => Ok(vec![s.clone()]),
+        Value::List(list) => list
+            .values
+            .iter()
+            .map(|item| string_value(key, item))
+            .collect(),
+        _ => Err(format!(
+            "config.{} must be a string or a list of strings",
+            key
+        )),
+    }
+}
+
+fn number_value(key: &str, value: &Value) -> Result<f64, String> {
+    match value {
+        Value::SmallInt(i) => Ok(*i as f64),
+        Value::Int(i) => Ok(*i as f64),
+        Value::BigInt(i) => Ok(*i as f64),
+        Value::Float(f) => Ok(*f as f64),
+        Value::Double(f) => Ok(*f),
+        _ => Err(format!("config.{} must be a number", key)),
+    }
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for ProcedureCallExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        match self.call() {
+            Ok((dataset, stats)) => {
+                let executor_stats = self.base.get_stats_mut();
+                for (key, value) in stats {
+                    executor_stats.add_stat(key.to_string(), value);
+                }
+                Ok(ExecutionResult::DataSet(dataset))
+            }
+            Err(e) => Ok(ExecutionResult::Error(format!(
+                "Failed to call procedure {}: {}",
+                self.procedure, e
+            ))),
+        }
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+    fn name(&self) -> &str {
+        "ProcedureCallExecutor"
+    }
+    fn description(&self) -> &str {
+        "Calls a registered procedure"
+    }
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for ProcedureCallExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::expr::ExpressionMeta;
+    use crate::core::types::TransactionId;
+    use crate::core::Expression;
+    use crate::query::executor::control_flow::BatchTransactionControl;
+    use crate::query::executor::expression::functions::{
+        CustomProcedure, ProcedureMode, ProcedureParam, ProcedureRegistry, ValueType,
+    };
+    use crate::query::executor::expression::ExpressionError;
+    use crate::storage::MockStorage;
+
+    fn repeat(args: &[Value]) -> Result<Vec<Vec<Value>>, ExpressionError> {
+        let times = match args[1] {
+            Value::Int(n) => n,
+            _ => 1,
+        };
+        Ok((0..times)
+            .map(|i| vec![Value::Int(i), args[0].clone()])
+            .collect())
+    }
+
+    fn registry() -> SharedProcedureRegistry {
+        let mut registry = ProcedureRegistry::new();
+        registry
+            .register_custom(CustomProcedure::new(
+                ProcedureSignature::new("test.repeat", ProcedureMode::Read)
+                    .param(ProcedureParam::new("text", ValueType::String))
+                    .param(ProcedureParam::new("times", ValueType::Int).with_default(Value::Int(1)))
+                    .output("index", ValueType::Int)
+                    .output("text", ValueType::String),
+                repeat,
+            ))
+            .expect("register should succeed");
+        Arc::new(RwLock::new(registry))
+    }
+
+    fn literal(
+        expr_context: &Arc<ExpressionAnalysisContext>,
+        value: Value,
+    ) -> ContextualExpression {
+        let expr_id =
+            expr_context.register_expression(ExpressionMeta::new(Expression::Literal(value)));
+        ContextualExpression::new(expr_id, expr_context.clone())
+    }
+
+    fn call_executor(
+        procedure: &str,
+        args: Vec<Value>,
+        yield_columns: Vec<&str>,
+    ) -> ProcedureCallExecutor<MockStorage> {
+        let storage = Arc::new(RwLock::new(
+            MockStorage::new().expect("Failed to create MockStorage"),
+        ));
+        let expr_context = Arc::new(ExpressionAnalysisContext::new());
+        let args = args
+            .into_iter()
+            .map(|value| literal(&expr_context, value))
+            .collect();
+        ProcedureCallExecutor::new(
+            1,
+            storage,
+            None,
+            procedure.to_string(),
+            args,
+            yield_columns.into_iter().map(String::from).collect(),
+            registry(),
+            expr_context,
+        )
+    }
+
+    #[test]
+    fn test_call_custom_procedure_with_yield() {
+        let mut executor = call_executor(
+            "test.repeat",
+            vec![Value::String("x".to_string()), Value::Int(2)],
+            vec!["text", "index"],
+        );
+        match executor.execute().expect("execute should succeed") {
+            ExecutionResult::DataSet(dataset) => {
+                assert_eq!(dataset.col_names, vec!["text", "index"]);
+                assert_eq!(
+                    dataset.rows,
+                    vec![
+                        vec![Value::String("x".to_string()), Value::Int(0)],
+                        vec![Value::String("x".to_string()), Value::Int(1)],
+                    ]
+                );
+            }
+            other => panic!("Expected DataSet result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_call_reports_signature_errors() {
+        let mut executor = call_executor("test.repeat", vec![Value::Int(1)], vec![]);
+        assert!(matches!(
+            executor.execute().expect("execute should succeed"),
+            ExecutionResult::Error(_)
+        ));
+
+        let mut executor = call_executor(
+            "test.repeat",
+            vec![Value::String("x".to_string())],
+            vec!["missing"],
+        );
+        assert!(matches!(
+            executor.execute().expect("execute should succeed"),
+            ExecutionResult::Error(_)
+        ));
+
+        let mut executor = call_executor("db.labels", vec![], vec![]);
+        assert!(matches!(
+            executor.execute().expect("execute should succeed"),
+            ExecutionResult::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_call_centrality_procedures() {
+        let mut executor = call_executor("algo.pageRank", vec![], vec![]);
+        executor.space_name = Some("test_space".to_string());
+        match executor.execute().expect("execute should succeed") {
+            ExecutionResult::DataSet(dataset) => {
+                assert_eq!(dataset.col_names, vec!["vid", "score"]);
+                assert!(dataset.rows.is_empty());
+            }
+            other => panic!("Expected DataSet result, got {:?}", other),
+        }
+
+        let config = |key: &str, value: Value| {
+            Value::map(std::collections::HashMap::from([(key.to_string(), value)]))
+        };
+        let failing = [
+            ("algo.pageRank.write", vec![]),
+            (
+                "algo.degree",
+                vec![config("direction", Value::String("sideways".to_string()))],
+            ),
+            ("algo.closeness", vec![config("unknown", Value::Int(1))]),
+            (
+                "algo.personalizedPageRank",
+                vec![Value::list(List::from(Vec::<Value>::new()))],
+            ),
+        ];
+        for (procedure, args) in failing {
+            let mut executor = call_executor(procedure, args, vec![]);
+            executor.space_name = Some("test_space".to_string());
+            assert!(
+                matches!(
+                    executor.execute().expect("execute should succeed"),
+                    ExecutionResult::Error(_)
+                ),
+                "{} should fail",
+                procedure
+            );
+        }
+    }
+
+    #[test]
+    fn test_call_community_procedures() {
+        let mut executor = call_executor("algo.louvain", vec![], vec![]);
+        executor.space_name = Some("test_space".to_string());
+        match executor.execute().expect("execute should succeed") {
+            ExecutionResult::DataSet(dataset) => {
+                assert_eq!(dataset.col_names, vec!["vid", "communityId"]);
+                assert!(dataset.rows.is_empty());
+            }
+            other => panic!("Expected DataSet result, got {:?}", other),
+        }
+        let stats = executor.stats();
+        assert_eq!(stats.get_stat("communities"), Some(&"0".to_string()));
+        assert_eq!(stats.get_stat("converged"), Some(&"true".to_string()));
+
+        let config = |key: &str, value: Value| {
+            Value::map(std::collections::HashMap::from([(key.to_string(), value)]))
+        };
+        let failing = [
+            ("algo.wcc.write", vec![]),
+            (
+                "algo.scc",
+                vec![config("dampingFactor", Value::Double(0.5))],
+            ),
+            (
+                "algo.labelPropagation",
+                vec![config("maxIterations", Value::String("ten".to_string()))],
+            ),
+        ];
+        for (procedure, args) in failing {
+            let mut executor = call_executor(procedure, args, vec![]);
+            executor.space_name = Some("test_space".to_string());
+            assert!(
+                matches!(
+                    executor.execute().expect("execute should succeed"),
+                    ExecutionResult::Error(_)
+                ),
+                "{} should fail",
+                procedure
+            );
+        }
+    }
+
+    /// Records the transaction calls of the `.write` procedures
+    #[derive(Default)]
+    struct RecordingTransactions {
+        explicit: bool,
+        calls: parking_lot::Mutex<Vec<&'static str>>,
+    }
+
+    impl BatchTransactionControl for RecordingTransactions {
+        fn in_explicit_transaction(&self) -> bool {
+            self.explicit
+        }
+
+        fn begin_batch(&self) -> DBResult<TransactionId> {
+            self.calls.lock().push("begin");
+            Ok(TransactionId::new(1))
+        }
+
+        fn commit_batch(&self, _txn_id: TransactionId) -> DBResult<()> {
+            self.calls.lock().push("commit");
+            Ok(())
+        }
+
+        fn rollback_batch(&self, _txn_id: TransactionId) -> DBResult<()> {
+            self.calls.lock().push("rollback");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_procedures_run_in_a_transaction() {
+        let config = Value::map(std::collections::HashMap::from([(
+            "writeProperty".to_string(),
+            Value::String("rank".to_string()),
+        )]));
+
+        for explicit in [false, true] {
+            let transactions = Arc::new(RecordingTransactions {
+                explicit,
+                ..Default::default()
+            });
+            let mut executor = call_executor("algo.pageRank.write", vec![config.clone()], vec![])
+                .with_transaction_control(Some(transactions.clone()));
+            executor.space_name = Some("test_space".to_string());
+            assert!(matches!(
+                executor.execute().expect("execute should succeed"),
+                ExecutionResult::DataSet(_)
+            ));
+
+            // Inside an explicit transaction the writes join it instead of starting their own.
+            let expected: Vec<&str> = if explicit {
+                vec![]
+            } else {
+                vec!["begin", "commit"]
+            };
+            assert_eq!(*transactions.calls.lock(), expected);
+        }
+    }
+
+    #[test]
+    fn test_procedure_mode_is_checked_against_the_role() {
+        let denied = |role: RoleType, procedure: &str| {
+            let mut executor = call_executor(procedure, vec![], vec![]).with_role(Some(role));
+            match executor.execute().expect("execute should succeed") {
+                ExecutionResult::Error(message) => message.contains("Permission denied"),
+                _ => false,
+            }
+        };
+
+        assert!(denied(RoleType::Guest, "algo.pageRank.write"));
+        assert!(!denied(RoleType::User, "algo.pageRank.write"));
+        assert!(!denied(RoleType::Guest, "dbms.procedures"));
+        assert!(!denied(RoleType::Guest, "test.repeat"));
+    }
+
+    #[test]
+    fn test_call_dbms_procedures() {
+        let mut executor = call_executor("dbms.procedures", vec![], vec!["name"]);
+        match executor.execute().expect("execute should succeed") {
+            ExecutionResult::DataSet(dataset) => {
+                let names: Vec<Value> = dataset.rows.into_iter().map(|mut r| r.remove(0)).collect();
+                assert!(names.contains(&Value::String("db.labels".to_string())));
+                assert!(names.contains(&Value::String("test.repeat".to_string())));
+            }
+            other => panic!("Expected DataSet result, got {:?}", other),
+        }
+    }
+}
@@ -5,6 +5,7 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use crate::core::types::Index;
 use crate::core::{Edge, Value, Vertex};
 use crate::query::executor::base::{BaseExecutor, ExecutorStats};
 use crate::query::executor::base::{DBResult, ExecutionResult, Executor, HasStorage};
@@ -172,10 +173,22 @@ impl<S: StorageReader + StorageWriter + Send + Sync + 'static> InsertExecutor<S>
 
         if let Some(vertices) = &self.vertex_data {
             let mut storage = self.get_storage().write();
+            // MERGE inserts with IF NOT EXISTS; a vertex whose unique key is already
+            // taken matches the existing vertex through the constraint's index.
+            let unique_indexes: Vec<Index> = if self.if_not_exists {
+                storage
+                    .list_constraints(&self.space_name)?
+                    .iter()
+                    .filter_map(|constraint| constraint.backing_index())
+                    .collect()
+            } else {
+                Vec::new()
+            };
             for vertex in vertices.iter() {
                 // If IF NOT EXISTS is enabled, check whether the vertex already exists.
                 if self.if_not_exists
-                    && storage.get_vertex(&self.space_name, &vertex.vid)?.is_some()
+                    && (storage.get_vertex(&self.space_name, &vertex.vid)?.is_some()
+                        || self.unique_key_exists(&*storage, &unique_indexes, vertex)?)
                 {
                     // The vertex already exists; the insertion step will be skipped.
                     continue;
@@ -208,4 +221,33 @@ impl<S: StorageReader + StorageWriter + Send + Sync + 'static> InsertExecutor<S>
 
         Ok(total_inserted)
     }
+
+    fn unique_key_exists(
+        &self,
+        storage: &S,
+        unique_indexes: &[Index],
+        vertex: &Vertex,
+    ) -> DBResult<bool> {
+        for tag in &vertex.tags {
+            let props: Vec<(String, Value)> = tag
+                .properties
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect();
+            for index in unique_indexes
+                .iter()
+                .filter(|index| index.schema_name == tag.name)
+            {
+                if let Some(key) = index.unique_key(&props) {
+                    if !storage
+                        .lookup_index(&self.space_name, &index.name, &key)?
+                        .is_empty()
+                    {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
 }
@@ -132,7 +132,7 @@ impl<S: StorageClient + Send + 'static> ExplainExecutor<S> {
                     exec_duration_in_us: stats.actual_time_us() as i64,
                     total_duration_in_us: stats.executor_stats.total_time_us as i64,
                     other_stats: {
-                        let mut map = std::collections::HashMap::new();
+                        let mut map = stats.executor_stats.other_stats.clone();
                         map.insert(
                             "startup_time_ms".to_string(),
                             format!("{:.3}", stats.startup_time_us as f64 / 1000.0),
@@ -155,20 +155,38 @@ impl<S: StorageClient + Send + 'static> ExplainExecutor<S> {
 
     /// Format plan description according to output format
     fn format_output(&self, plan_desc: &PlanDescription) -> DBResult<String> {
-        match self.format {
+        let output = match self.format {
             ExplainFormat::Table => {
                 // Use output module's table formatter
                 format_plan_with_output_table(plan_desc).map_err(|e| {
                     crate::core::error::DBError::from(crate::core::error::QueryError::execution(
                         e.to_string(),
                     ))
-                })
+                })?
             }
             ExplainFormat::Dot => {
                 // Keep existing DOT format (not migrated to output module)
-                Ok(format_plan_as_dot(plan_desc))
+                format_plan_as_dot(plan_desc)
             }
+        };
+        Ok(self.append_hints(output))
+    }
+
+    /// Append the optimizer hints and the warnings for the ones not applied
+    fn append_hints(&self, mut output: String) -> String {
+        let hints = &self.inner_plan.hints;
+        // DOT output stays a valid graph
+        let prefix = match self.format {
+            ExplainFormat::Table => "",
+            ExplainFormat::Dot => "// ",
+        };
+        if !hints.hints.is_empty() {
+            output.push_str(&format!("\n{}Hints: {}", prefix, hints));
+        }
+        for warning in &hints.warnings {
+            output.push_str(&format!("\n{}Warning: {}", prefix, warning));
         }
+        output
     }
 }
 
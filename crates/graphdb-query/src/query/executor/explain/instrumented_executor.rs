@@ -47,6 +47,10 @@ impl<S: StorageClient + Send + 'static> InstrumentedExecutor<S> {
     fn collect_inner_stats(&mut self) {
         let inner_stats = self.inner.stats();
         self.stats.memory_peak = inner_stats.memory_peak;
+        // Operator-specific figures, such as algorithm iterations, are shown by PROFILE.
+        self.stats
+            .other_stats
+            .extend(inner_stats.other_stats.clone());
         // Cache stats are now handled internally by CacheStats
         // No need to copy cache_hits and cache_misses
     }
@@ -3,6 +3,7 @@
 //! Executor for PROFILE statements.
 //! Executes the query and returns detailed performance statistics.
 
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -20,6 +21,9 @@ use crate::storage::StorageClient;
 use super::execution_stats_context::ExecutionStatsContext;
 use super::instrumented_executor::InstrumentedExecutor;
 
+/// Statistics attached to every node; the profiling column only lists the others
+const GENERIC_PROFILE_STATS: [&str; 3] = ["startup_time_ms", "memory_used", "cache_hit_rate"];
+
 /// Profile executor
 ///
 /// Handles PROFILE statements.
@@ -28,6 +32,7 @@ pub struct ProfileExecutor<S: StorageClient> {
     base: BaseExecutor<S>,
     inner_plan: ExecutionPlan,
     format: ExplainFormat,
+    kill_flag: Option<Arc<AtomicBool>>,
 }
 
 impl<S: StorageClient + Send + 'static> ProfileExecutor<S> {
@@ -36,9 +41,16 @@ impl<S: StorageClient + Send + 'static> ProfileExecutor<S> {
             base,
             inner_plan,
             format,
+            kill_flag: None,
         }
     }
 
+    /// Stop the profiled query when this flag is set.
+    pub fn with_kill_flag(mut self, kill_flag: Arc<AtomicBool>) -> Self {
+        self.kill_flag = Some(kill_flag);
+        self
+    }
+
     fn get_storage(&self) -> &Arc<parking_lot::RwLock<S>> {
         self.base.storage.as_ref().expect("Storage not set")
     }
@@ -70,9 +82,14 @@ impl<S: StorageClient + Send + 'static> ProfileExecutor<S> {
 
         let _exec_result = if let Some(ref root) = self.inner_plan.root {
             let mut factory = ExecutorFactory::with_storage(self.get_storage().clone());
-            let context = crate::query::executor::base::ExecutionContext::new(std::sync::Arc::new(
-                crate::query::validator::context::ExpressionAnalysisContext::new(),
-            ));
+            let mut context =
+                crate::query::executor::base::ExecutionContext::new(std::sync::Arc::new(
+                    crate::query::validator::context::ExpressionAnalysisContext::new(),
+                ))
+                .with_parallelism(self.inner_plan.hints.parallelism());
+            if let Some(ref kill_flag) = self.kill_flag {
+                context = context.with_kill_flag(kill_flag.clone());
+            }
             let executor = factory
                 .create_executor(root, self.get_storage().clone(), &context)
                 .map_err(|e| {
@@ -116,7 +133,7 @@ impl<S: StorageClient + Send + 'static> ProfileExecutor<S> {
                     exec_duration_in_us: stats.actual_time_us() as i64,
                     total_duration_in_us: stats.executor_stats.total_time_us as i64,
                     other_stats: {
-                        let mut map = std::collections::HashMap::new();
+                        let mut map = stats.executor_stats.other_stats.clone();
                         map.insert(
                             "startup_time_ms".to_string(),
                             format!("{:.3}", stats.startup_time_us as f64 / 1000.0),
@@ -169,7 +186,20 @@ impl<S: StorageClient + Send + 'static> ProfileExecutor<S> {
             let profile_str = if let Some(ref profiles) = node_desc.profiles {
                 profiles
                     .iter()
-                    .map(|p| format!("rows: {}, exec_time: {}us", p.rows, p.exec_duration_in_us))
+                    .map(|p| {
+                        let mut profile =
+                            format!("rows: {}, exec_time: {}us", p.rows, p.exec_duration_in_us);
+                        let mut extra: Vec<_> = p
+                            .other_stats
+                            .iter()
+                            .filter(|(key, _)| !GENERIC_PROFILE_STATS.contains(&key.as_str()))
+                            .collect();
+                        extra.sort();
+                        for (key, value) in extra {
+                            profile.push_str(&format!(", {}: {}", key, value));
+                        }
+                        profile
+                    })
                     .collect::<Vec<_>>()
                     .join("; ")
             } else {
@@ -274,6 +274,10 @@ impl ExpressionEvaluator {
                 "The query parameter '{}' requires values provided by the runtime context.",
                 name
             ))),
+            Expression::PatternSubquery(subquery) => Err(ExpressionError::type_error(format!(
+                "Pattern subquery '{}' must be planned as a PatternSubquery operator",
+                subquery.to_expression_string()
+            ))),
         }
     }
 
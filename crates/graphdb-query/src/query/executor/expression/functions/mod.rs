@@ -6,6 +6,7 @@
 //!
 //! - `signature.rs` - 类型签名系统
 //! - `registry.rs` - 函数注册表
+//! - `procedure.rs` - 过程注册表（CALL 语句）
 //! - `builtin/` – Implementation of built-in functions
 //!
 //! ## How to use it
@@ -18,6 +19,7 @@
 //! ```
 
 pub mod builtin;
+pub mod procedure;
 pub mod registry;
 pub mod signature;
 
@@ -28,6 +30,10 @@ pub use fulltext::{FulltextExecutionContext, FulltextFunction};
 // Vector functions (re-export from builtin)
 pub use builtin::vector::VectorFunction;
 
+pub use procedure::{
+    BuiltinProcedure, CustomProcedure, Procedure, ProcedureColumn, ProcedureMode, ProcedureParam,
+    ProcedureRegistry, ProcedureSignature, SharedProcedureRegistry,
+};
 pub use registry::{global_registry, global_registry_ref, FunctionRegistry};
 pub use signature::ValueType;
 
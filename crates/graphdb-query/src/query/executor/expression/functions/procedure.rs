@@ -0,0 +1,722 @@
+//! Procedure Registry
+//!
+//! Procedures are invoked with `CALL name(args) YIELD ...` and return rows instead of a
+//! single value. Every procedure carries a typed signature (input parameters and output
+//! columns) and an access mode that decides which role may call it.
+//!
+//! Built-in procedures are described by the `BuiltinProcedure` enumeration and executed by
+//! the `ProcedureCallExecutor`, since most of them read metadata from storage. Custom
+//! procedures are plain Rust callbacks registered by embedders.
+
+use super::signature::ValueType;
+use crate::core::{Permission, RoleType, Value};
+use crate::query::executor::expression::{ExpressionError, ExpressionErrorType};
+use crate::query::executor::graph_operations::graph_traversal::algorithms::{
+    CentralityAlgorithm, CommunityAlgorithm,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+/// Procedure access mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcedureMode {
+    /// Only reads data or metadata
+    Read,
+    /// Modifies graph data
+    Write,
+    /// Modifies the schema
+    Schema,
+    /// Database management operations
+    Dbms,
+}
+
+impl ProcedureMode {
+    /// Permission checked by the permission manager before the call
+    pub fn permission(&self) -> Permission {
+        match self {
+            ProcedureMode::Read => Permission::Read,
+            ProcedureMode::Write => Permission::Write,
+            ProcedureMode::Schema => Permission::Schema,
+            ProcedureMode::Dbms => Permission::Admin,
+        }
+    }
+
+    /// Lowest role allowed to call a procedure with this mode
+    pub fn required_role(&self) -> RoleType {
+        match self {
+            ProcedureMode::Read => RoleType::Guest,
+            ProcedureMode::Write => RoleType::User,
+            ProcedureMode::Schema => RoleType::Dba,
+            ProcedureMode::Dbms => RoleType::Admin,
+        }
+    }
+}
+
+impl fmt::Display for ProcedureMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcedureMode::Read => write!(f, "READ"),
+            ProcedureMode::Write => write!(f, "WRITE"),
+            ProcedureMode::Schema => write!(f, "SCHEMA"),
+            ProcedureMode::Dbms => write!(f, "DBMS"),
+        }
+    }
+}
+
+/// Input parameter of a procedure
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcedureParam {
+    pub name: String,
+    pub value_type: ValueType,
+    /// Value used when the argument is omitted; `None` makes the parameter mandatory
+    pub default: Option<Value>,
+}
+
+impl ProcedureParam {
+    pub fn new(name: impl Into<String>, value_type: ValueType) -> Self {
+        Self {
+            name: name.into(),
+            value_type,
+            default: None,
+        }
+    }
+
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Check whether a value can be passed for this parameter
+    fn accepts(&self, value: &Value) -> bool {
+        let actual = ValueType::from_value(value);
+        self.value_type == ValueType::Any
+            || matches!(actual, ValueType::Null | ValueType::Empty)
+            || actual == self.value_type
+            || (actual.is_numeric() && self.value_type.is_numeric())
+    }
+}
+
+/// Output column of a procedure
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcedureColumn {
+    pub name: String,
+    pub value_type: ValueType,
+}
+
+impl ProcedureColumn {
+    pub fn new(name: impl Into<String>, value_type: ValueType) -> Self {
+        Self {
+            name: name.into(),
+            value_type,
+        }
+    }
+}
+
+/// Typed procedure signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcedureSignature {
+    /// Qualified name, such as `db.labels`
+    pub name: String,
+    pub params: Vec<ProcedureParam>,
+    pub outputs: Vec<ProcedureColumn>,
+    pub mode: ProcedureMode,
+    pub description: String,
+}
+
+impl ProcedureSignature {
+    pub fn new(name: impl Into<String>, mode: ProcedureMode) -> Self {
+        Self {
+            name: name.into(),
+            params: Vec::new(),
+            outputs: Vec::new(),
+            mode,
+            description: String::new(),
+        }
+    }
+
+    pub fn param(mut self, param: ProcedureParam) -> Self {
+        self.params.push(param);
+        self
+    }
+
+    pub fn output(mut self, name: impl Into<String>, value_type: ValueType) -> Self {
+        self.outputs.push(ProcedureColumn::new(name, value_type));
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Names of the output columns, in declaration order
+    pub fn output_names(&self) -> Vec<String> {
+        self.outputs.iter().map(|c| c.name.clone()).collect()
+    }
+
+    /// Check the arguments against the parameters and fill in defaults
+    pub fn bind_args(&self, args: &[Value]) -> Result<Vec<Value>, ExpressionError> {
+        if args.len() > self.params.len() {
+            return Err(ExpressionError::new(
+                ExpressionErrorType::ArgumentCountError,
+                format!(
+                    "Procedure {} takes at most {} arguments, got {}",
+                    self.name,
+                    self.params.len(),
+                    args.len()
+                ),
+            ));
+        }
+
+        let mut bound = Vec::with_capacity(self.params.len());
+        for (i, param) in self.params.iter().enumerate() {
+            let value = match args.get(i) {
+                Some(value) => value.clone(),
+                None => param.default.clone().ok_or_else(|| {
+                    ExpressionError::new(
+                        ExpressionErrorType::ArgumentCountError,
+                        format!(
+                            "Procedure {} is missing argument '{}'",
+                            self.name, param.name
+                        ),
+                    )
+                })?,
+            };
+            if !param.accepts(&value) {
+                return Err(ExpressionError::new(
+                    ExpressionErrorType::TypeError,
+                    format!(
+                        "Argument '{}' of procedure {} expects {}, got {}",
+                        param.name,
+                        self.name,
+                        param.value_type,
+                        ValueType::from_value(&value)
+                    ),
+                ));
+            }
+            bound.push(value);
+        }
+        Ok(bound)
+    }
+}
+
+impl fmt::Display for ProcedureSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} :: {}", param.name, param.value_type)?;
+            if let Some(default) = &param.default {
+                write!(f, " = {}", default)?;
+            }
+        }
+        write!(f, ") :: (")?;
+        for (i, column) in self.outputs.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} :: {}", column.name, column.value_type)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Built-in procedure enumeration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinProcedure {
+    /// db.labels() - tags of the current space
+    Labels,
+    /// db.relationshipTypes() - edge types of the current space
+    RelationshipTypes,
+    /// db.indexes() - tag indexes of the current space
+    Indexes,
+    /// db.constraints() - constraints of the current space
+    Constraints,
+    /// dbms.procedures() - registered procedures
+    Procedures,
+    /// algo.<algorithm>(...) - stream centrality scores
+    Centrality(CentralityAlgorithm),
+    /// algo.<algorithm>.write(...) - write centrality scores to a vertex property
+    CentralityWrite(CentralityAlgorithm),
+    /// algo.<algorithm>(...) - stream communities, triangle counts or clustering coefficients
+    Community(CommunityAlgorithm),
+    /// algo.<algorithm>.write(...) - write community results to a vertex property
+    CommunityWrite(CommunityAlgorithm),
+}
+
+impl BuiltinProcedure {
+    pub const ALL: [BuiltinProcedure; 27] = [
+        BuiltinProcedure::Labels,
+        BuiltinProcedure::RelationshipTypes,
+        BuiltinProcedure::Indexes,
+        BuiltinProcedure::Constraints,
+        BuiltinProcedure::Procedures,
+        BuiltinProcedure::Centrality(CentralityAlgorithm::PageRank),
+        BuiltinProcedure::Centrality(CentralityAlgorithm::PersonalizedPageRank),
+        BuiltinProcedure::Centrality(CentralityAlgorithm::Betweenness),
+        BuiltinProcedure::Centrality(CentralityAlgorithm::Closeness),
+        BuiltinProcedure::Centrality(CentralityAlgorithm::Degree),
+        BuiltinProcedure::CentralityWrite(CentralityAlgorithm::PageRank),
+        BuiltinProcedure::CentralityWrite(CentralityAlgorithm::PersonalizedPageRank),
+        BuiltinProcedure::CentralityWrite(CentralityAlgorithm::Betweenness),
+        BuiltinProcedure::CentralityWrite(CentralityAlgorithm::Closeness),
+        BuiltinProcedure::CentralityWrite(CentralityAlgorithm::Degree),
+        BuiltinProcedure::Community(CommunityAlgorithm::WeaklyConnectedComponents),
+        BuiltinProcedure::Community(CommunityAlgorithm::StronglyConnectedComponents),
+        BuiltinProcedure::Community(CommunityAlgorithm::LabelPropagation),
+        BuiltinProcedure::Community(CommunityAlgorithm::Louvain),
+        BuiltinProcedure::Community(CommunityAlgorithm::TriangleCount),
+        BuiltinProcedure::Community(CommunityAlgorithm::LocalClusteringCoefficient),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::WeaklyConnectedComponents),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::StronglyConnectedComponents),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::LabelPropagation),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::Louvain),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::TriangleCount),
+        BuiltinProcedure::CommunityWrite(CommunityAlgorithm::LocalClusteringCoefficient),
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltinProcedure::Labels => "db.labels",
+            BuiltinProcedure::RelationshipTypes => "db.relationshipTypes",
+            BuiltinProcedure::Indexes => "db.indexes",
+            BuiltinProcedure::Constraints => "db.constraints",
+            BuiltinProcedure::Procedures => "dbms.procedures",
+            BuiltinProcedure::Centrality(algorithm) => match algorithm {
+                CentralityAlgorithm::PageRank => "algo.pageRank",
+                CentralityAlgorithm::PersonalizedPageRank => "algo.personalizedPageRank",
+                CentralityAlgorithm::Betweenness => "algo.betweenness",
+                CentralityAlgorithm::Closeness => "algo.closeness",
+                CentralityAlgorithm::Degree => "algo.degree",
+            },
+            BuiltinProcedure::CentralityWrite(algorithm) => match algorithm {
+                CentralityAlgorithm::PageRank => "algo.pageRank.write",
+                CentralityAlgorithm::PersonalizedPageRank => "algo.personalizedPageRank.write",
+                CentralityAlgorithm::Betweenness => "algo.betweenness.write",
+                CentralityAlgorithm::Closeness => "algo.closeness.write",
+                CentralityAlgorithm::Degree => "algo.degree.write",
+            },
+            BuiltinProcedure::Community(algorithm) => match algorithm {
+                CommunityAlgorithm::WeaklyConnectedComponents => "algo.wcc",
+                CommunityAlgorithm::StronglyConnectedComponents => "algo.scc",
+                CommunityAlgorithm::LabelPropagation => "algo.labelPropagation",
+                CommunityAlgorithm::Louvain => "algo.louvain",
+                CommunityAlgorithm::TriangleCount => "algo.triangleCount",
+                CommunityAlgorithm::LocalClusteringCoefficient => "algo.localClusteringCoefficient",
+            },
+            BuiltinProcedure::CommunityWrite(algorithm) => match algorithm {
+                CommunityAlgorithm::WeaklyConnectedComponents => "algo.wcc.write",
+                CommunityAlgorithm::StronglyConnectedComponents => "algo.scc.write",
+                CommunityAlgorithm::LabelPropagation => "algo.labelPropagation.write",
+                CommunityAlgorithm::Louvain => "algo.louvain.write",
+                CommunityAlgorithm::TriangleCount => "algo.triangleCount.write",
+                CommunityAlgorithm::LocalClusteringCoefficient => {
+                    "algo.localClusteringCoefficient.write"
+                }
+            },
+        }
+    }
+
+    /// Whether the procedure reads the current space
+    pub fn requires_space(&self) -> bool {
+        !matches!(self, BuiltinProcedure::Procedures)
+    }
+
+    pub fn signature(&self) -> ProcedureSignature {
+        match self {
+            BuiltinProcedure::Labels => ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                .output("label", ValueType::String)
+                .description("List all tags in the current space"),
+            BuiltinProcedure::RelationshipTypes => {
+                ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                    .output("relationshipType", ValueType::String)
+                    .description("List all edge types in the current space")
+            }
+            BuiltinProcedure::Indexes => ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                .output("name", ValueType::String)
+                .output("schema", ValueType::String)
+                .output("properties", ValueType::List)
+                .output("unique", ValueType::Bool)
+                .description("List all tag indexes in the current space"),
+            BuiltinProcedure::Constraints => {
+                ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                    .output("name", ValueType::String)
+                    .output("schema", ValueType::String)
+                    .output("type", ValueType::String)
+                    .output("properties", ValueType::List)
+                    .description("List all constraints in the current space")
+            }
+            BuiltinProcedure::Procedures => {
+                ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                    .output("name", ValueType::String)
+                    .output("signature", ValueType::String)
+                    .output("mode", ValueType::String)
+                    .output("description", ValueType::String)
+                    .description("List all registered procedures")
+            }
+            BuiltinProcedure::Centrality(algorithm) => Self::centrality_params(
+                ProcedureSignature::new(self.name(), ProcedureMode::Read),
+                *algorithm,
+            )
+            .output("vid", ValueType::Any)
+            .output("score", ValueType::Double)
+            .description(format!("Stream {} centrality scores", algorithm.name())),
+            BuiltinProcedure::CentralityWrite(algorithm) => Self::centrality_params(
+                ProcedureSignature::new(self.name(), ProcedureMode::Write),
+                *algorithm,
+            )
+            .output("nodes", ValueType::BigInt)
+            .output("propertiesWritten", ValueType::BigInt)
+            .output("iterations", ValueType::BigInt)
+            .description(format!(
+                "Write {} centrality scores to config.writeProperty",
+                algorithm.name()
+            )),
+            BuiltinProcedure::Community(algorithm) => {
+                let signature = ProcedureSignature::new(self.name(), ProcedureMode::Read)
+                    .param(Self::config_param())
+                    .output("vid", ValueType::Any);
+                match algorithm {
+                    CommunityAlgorithm::TriangleCount => signature
+                        .output("triangles", ValueType::BigInt)
+                        .description("Stream the number of triangles through every vertex"),
+                    CommunityAlgorithm::LocalClusteringCoefficient => signature
+                        .output("coefficient", ValueType::Double)
+                        .description("Stream the local clustering coefficient of every vertex"),
+                    _ => signature
+                        .output("communityId", ValueType::BigInt)
+                        .description(format!("Stream {} communities", algorithm.name())),
+                }
+            }
+            BuiltinProcedure::CommunityWrite(algorithm) => {
+                ProcedureSignature::new(self.name(), ProcedureMode::Write)
+                    .param(Self::config_param())
+                    .output("nodes", ValueType::BigInt)
+                    .output("propertiesWritten", ValueType::BigInt)
+                    .output("iterations", ValueType::BigInt)
+                    .output("converged", ValueType::Bool)
+                    .description(format!(
+                        "Write {} results to config.writeProperty",
+                        algorithm.name()
+                    ))
+            }
+        }
+    }
+
+    /// Optional algorithm config map
+    fn config_param() -> ProcedureParam {
+        ProcedureParam::new("config", ValueType::Map).with_default(Value::map(HashMap::new()))
+    }
+
+    /// Centrality procedures take an optional config map, preceded by the source
+    /// vertices for personalised PageRank
+    fn centrality_params(
+        signature: ProcedureSignature,
+        algorithm: CentralityAlgorithm,
+    ) -> ProcedureSignature {
+        let signature = if algorithm == CentralityAlgorithm::PersonalizedPageRank {
+            signature.param(ProcedureParam::new("sources", ValueType::List))
+        } else {
+            signature
+        };
+        signature.param(Self::config_param())
+    }
+}
+
+/// Implementation of a custom procedure: bound arguments in, rows out
+pub type CustomProcedureImpl = fn(&[Value]) -> Result<Vec<Vec<Value>>, ExpressionError>;
+
+/// Custom procedure registered by an embedder
+#[derive(Debug, Clone)]
+pub struct CustomProcedure {
+    pub signature: ProcedureSignature,
+    pub implementation: CustomProcedureImpl,
+}
+
+impl CustomProcedure {
+    pub fn new(signature: ProcedureSignature, implementation: CustomProcedureImpl) -> Self {
+        Self {
+            signature,
+            implementation,
+        }
+    }
+
+    /// Execute the procedure with already bound arguments
+    pub fn execute(&self, args: &[Value]) -> Result<Vec<Vec<Value>>, ExpressionError> {
+        let rows = (self.implementation)(args)?;
+        let width = self.signature.outputs.len();
+        if let Some(row) = rows.iter().find(|row| row.len() != width) {
+            return Err(ExpressionError::new(
+                ExpressionErrorType::FunctionError,
+                format!(
+                    "Procedure {} returned a row with {} columns, expected {}",
+                    self.signature.name,
+                    row.len(),
+                    width
+                ),
+            ));
+        }
+        Ok(rows)
+    }
+}
+
+/// A resolved procedure
+#[derive(Debug, Clone)]
+pub enum Procedure {
+    Builtin(BuiltinProcedure),
+    Custom(CustomProcedure),
+}
+
+impl Procedure {
+    pub fn signature(&self) -> ProcedureSignature {
+        match self {
+            Procedure::Builtin(p) => p.signature(),
+            Procedure::Custom(p) => p.signature.clone(),
+        }
+    }
+
+    pub fn mode(&self) -> ProcedureMode {
+        match self {
+            Procedure::Builtin(p) => p.signature().mode,
+            Procedure::Custom(p) => p.signature.mode,
+        }
+    }
+}
+
+/// Procedure Registry
+///
+/// Names are matched case-insensitively. Custom procedures may not shadow built-in ones.
+#[derive(Debug)]
+pub struct ProcedureRegistry {
+    builtin_procedures: HashMap<String, BuiltinProcedure>,
+    custom_procedures: HashMap<String, CustomProcedure>,
+}
+
+impl Default for ProcedureRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcedureRegistry {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            builtin_procedures: HashMap::new(),
+            custom_procedures: HashMap::new(),
+        };
+        for procedure in BuiltinProcedure::ALL {
+            registry
+                .builtin_procedures
+                .insert(procedure.name().to_lowercase(), procedure);
+        }
+        registry
+    }
+
+    /// Check whether the procedure exists.
+    pub fn contains(&self, name: &str) -> bool {
+        let key = name.to_lowercase();
+        self.builtin_procedures.contains_key(&key) || self.custom_procedures.contains_key(&key)
+    }
+
+    /// Look up a procedure by name
+    pub fn get(&self, name: &str) -> Option<Procedure> {
+        let key = name.to_lowercase();
+        if let Some(procedure) = self.builtin_procedures.get(&key) {
+            return Some(Procedure::Builtin(*procedure));
+        }
+        self.custom_procedures
+            .get(&key)
+            .cloned()
+            .map(Procedure::Custom)
+    }
+
+    /// Register a custom procedure, replacing an earlier one with the same name
+    pub fn register_custom(&mut self, procedure: CustomProcedure) -> Result<(), ExpressionError> {
+        let key = procedure.signature.name.to_lowercase();
+        if self.builtin_procedures.contains_key(&key) {
+            return Err(ExpressionError::new(
+                ExpressionErrorType::InvalidOperation,
+                format!(
+                    "Cannot override built-in procedure {}",
+                    procedure.signature.name
+                ),
+            ));
+        }
+        self.custom_procedures.insert(key, procedure);
+        Ok(())
+    }
+
+    /// Remove a custom procedure
+    pub fn unregister_custom(&mut self, name: &str) -> bool {
+        self.custom_procedures
+            .remove(&name.to_lowercase())
+            .is_some()
+    }
+
+    /// Signatures of all procedures, sorted by name
+    pub fn signatures(&self) -> Vec<ProcedureSignature> {
+        let mut signatures: Vec<ProcedureSignature> = self
+            .builtin_procedures
+            .values()
+            .map(|p| p.signature())
+            .chain(self.custom_procedures.values().map(|p| p.signature.clone()))
+            .collect();
+        signatures.sort_by(|a, b| a.name.cmp(&b.name));
+        signatures
+    }
+}
+
+/// Procedure registry shared by a query pipeline and its sessions
+pub type SharedProcedureRegistry = Arc<RwLock<ProcedureRegistry>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn echo(args: &[Value]) -> Result<Vec<Vec<Value>>, ExpressionError> {
+        Ok(vec![args.to_vec()])
+    }
+
+    fn echo_procedure() -> CustomProcedure {
+        CustomProcedure::new(
+            ProcedureSignature::new("test.echo", ProcedureMode::Read)
+                .param(ProcedureParam::new("text", ValueType::String))
+                .param(ProcedureParam::new("times", ValueType::Int).with_default(Value::Int(1)))
+                .output("text", ValueType::String)
+                .output("times", ValueType::Int),
+            echo,
+        )
+    }
+
+    #[test]
+    fn test_centrality_procedure_signatures() {
+        let registry = ProcedureRegistry::new();
+        let stream = registry
+            .get("algo.pageRank")
+            .expect("pageRank should exist");
+        assert_eq!(stream.mode(), ProcedureMode::Read);
+        assert_eq!(stream.signature().output_names(), vec!["vid", "score"]);
+
+        let write = registry
+            .get("algo.pagerank.write")
+            .expect("pageRank.write should exist");
+        assert_eq!(write.mode(), ProcedureMode::Write);
+
+        let personalized = registry
+            .get("algo.personalizedPageRank")
+            .expect("personalizedPageRank should exist")
+            .signature();
+        assert!(personalized.bind_args(&[]).is_err());
+        let bound = personalized
+            .bind_args(&[Value::list(crate::core::value::list::List::from(vec![
+                Value::Int(1),
+            ]))])
+            .expect("config should default");
+        assert!(matches!(bound[1], Value::Map(_)));
+    }
+
+    #[test]
+    fn test_community_procedure_signatures() {
+        let registry = ProcedureRegistry::new();
+        let louvain = registry.get("algo.louvain").expect("louvain should exist");
+        assert_eq!(louvain.mode(), ProcedureMode::Read);
+        assert_eq!(
+            louvain.signature().output_names(),
+            vec!["vid", "communityId"]
+        );
+
+        let triangles = registry
+            .get("algo.triangleCount")
+            .expect("triangleCount should exist");
+        assert_eq!(
+            triangles.signature().output_names(),
+            vec!["vid", "triangles"]
+        );
+
+        let write = registry
+            .get("algo.wcc.write")
+            .expect("wcc.write should exist");
+        assert_eq!(write.mode(), ProcedureMode::Write);
+        assert_eq!(
+            write.signature().output_names(),
+            vec!["nodes", "propertiesWritten", "iterations", "converged"]
+        );
+    }
+
+    #[test]
+    fn test_builtin_procedures_registered() {
+        let registry = ProcedureRegistry::new();
+        assert!(registry.contains("db.labels"));
+        assert!(registry.contains("DB.LABELS"));
+        assert!(registry.contains("dbms.procedures"));
+        assert!(!registry.contains("db.unknown"));
+        assert_eq!(registry.signatures().len(), BuiltinProcedure::ALL.len());
+    }
+
+    #[test]
+    fn test_register_custom_procedure() {
+        let mut registry = ProcedureRegistry::new();
+        registry
+            .register_custom(echo_procedure())
+            .expect("register should succeed");
+
+        let procedure = registry.get("test.echo").expect("procedure should exist");
+        assert_eq!(procedure.mode(), ProcedureMode::Read);
+        let Procedure::Custom(custom) = procedure else {
+            panic!("expected custom procedure");
+        };
+        let args = custom
+            .signature
+            .bind_args(&[Value::String("hi".to_string())])
+            .expect("bind should succeed");
+        let rows = custom.execute(&args).expect("execute should succeed");
+        assert_eq!(
+            rows,
+            vec![vec![Value::String("hi".to_string()), Value::Int(1)]]
+        );
+
+        assert!(registry.unregister_custom("test.echo"));
+        assert!(!registry.contains("test.echo"));
+    }
+
+    #[test]
+    fn test_builtin_cannot_be_overridden() {
+        let mut registry = ProcedureRegistry::new();
+        let mut procedure = echo_procedure();
+        procedure.signature.name = "db.labels".to_string();
+        assert!(registry.register_custom(procedure).is_err());
+    }
+
+    #[test]
+    fn test_bind_args_checks_signature() {
+        let signature = echo_procedure().signature;
+        assert!(signature.bind_args(&[]).is_err());
+        assert!(signature.bind_args(&[Value::Int(1)]).is_err());
+        assert!(signature
+            .bind_args(&[Value::String("a".to_string()), Value::Int(1), Value::Int(2)])
+            .is_err());
+        assert_eq!(
+            signature
+                .bind_args(&[Value::String("a".to_string()), Value::BigInt(3)])
+                .expect("numeric arguments are interchangeable")
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_signature_display() {
+        let signature = echo_procedure().signature;
+        assert_eq!(
+            signature.to_string(),
+            "test.echo(text :: STRING, times :: INT = 1) :: (text :: STRING, times :: INT)"
+        );
+        assert_eq!(ProcedureMode::Schema.required_role(), RoleType::Dba);
+        assert_eq!(ProcedureMode::Dbms.permission(), Permission::Admin);
+    }
+}
@@ -10,15 +10,17 @@ use crate::core::Value;
 use crate::query::executor::admin::query_management::show_stats::ShowStatsType as ExecutorShowStatsType;
 use crate::query::executor::admin::space::alter_space::SpaceAlterOption as ExecutorSpaceAlterOption;
 use crate::query::executor::admin::{
-    AlterEdgeExecutor, AlterSpaceExecutor, AlterTagExecutor, AlterUserExecutor,
-    ChangePasswordExecutor, ClearSpaceExecutor, CreateEdgeExecutor, CreateEdgeIndexExecutor,
-    CreateSpaceExecutor, CreateTagExecutor, CreateTagIndexExecutor, CreateUserExecutor,
-    DescEdgeExecutor, DescEdgeIndexExecutor, DescSpaceExecutor, DescTagExecutor,
-    DescTagIndexExecutor, DescribeUserExecutor, DropEdgeExecutor, DropEdgeIndexExecutor,
-    DropSpaceExecutor, DropTagExecutor, DropTagIndexExecutor, DropUserExecutor, GrantRoleExecutor,
-    RebuildEdgeIndexExecutor, RebuildTagIndexExecutor, RevokeRoleExecutor, ShowCreateTagExecutor,
-    ShowEdgeIndexesExecutor, ShowEdgesExecutor, ShowSpacesExecutor, ShowStatsExecutor,
-    ShowTagIndexesExecutor, ShowTagsExecutor, SwitchSpaceExecutor,
+    AlterEdgeExecutor, AlterSpaceExecutor, AlterTagExecutor, AlterUserExecutor, ApiKeyExecutor,
+    ChangePasswordExecutor, ClearSpaceExecutor, CreateConstraintExecutor, CreateEdgeExecutor,
+    CreateEdgeIndexExecutor, CreateRoleExecutor, CreateSpaceExecutor, CreateTagExecutor,
+    CreateTagIndexExecutor, CreateUserExecutor, DescEdgeExecutor, DescEdgeIndexExecutor,
+    DescSpaceExecutor, DescTagExecutor, DescTagIndexExecutor, DescribeUserExecutor,
+    DropConstraintExecutor, DropEdgeExecutor, DropEdgeIndexExecutor, DropSpaceExecutor,
+    DropTagExecutor, DropTagIndexExecutor, DropUserExecutor, GrantPrivilegeExecutor,
+    GrantRoleExecutor, RebuildEdgeIndexExecutor, RebuildTagIndexExecutor, RevokeRoleExecutor,
+    ShowConstraintsExecutor, ShowCreateTagExecutor, ShowEdgeIndexesExecutor, ShowEdgesExecutor,
+    ShowSpacesExecutor, ShowStatsExecutor, ShowTagIndexesExecutor, ShowTagsExecutor,
+    SwitchSpaceExecutor,
 };
 use crate::query::executor::base::{
     EdgeManageExecutor, ExecutionContext, ExecutorEnum, IndexManageExecutor, SpaceManageExecutor,
@@ -27,15 +29,16 @@ use crate::query::executor::base::{
 use crate::query::executor::utils::PassThroughExecutor;
 
 use crate::query::planning::plan::core::nodes::management::{
-    AlterEdgeNode, AlterSpaceNode, AlterTagNode, AlterUserNode, ChangePasswordNode, ClearSpaceNode,
-    CreateEdgeIndexNode, CreateEdgeNode, CreateSpaceNode, CreateTagIndexNode, CreateTagNode,
-    CreateUserNode, DescEdgeIndexNode, DescEdgeNode, DescSpaceNode, DescTagIndexNode, DescTagNode,
-    DescribeUserNode, DropEdgeIndexNode, DropEdgeNode, DropSpaceNode, DropTagIndexNode,
-    DropTagNode, DropUserNode, GrantRoleNode, RebuildEdgeIndexNode, RebuildTagIndexNode,
-    RevokeRoleNode, ShowCreateEdgeNode, ShowCreateIndexNode, ShowCreateSpaceNode,
-    ShowCreateTagNode, ShowEdgeIndexesNode, ShowEdgesNode, ShowIndexesNode, ShowRolesNode,
-    ShowSpacesNode, ShowStatsNode, ShowTagIndexesNode, ShowTagsNode, ShowUsersNode,
-    SwitchSpaceNode,
+    AlterEdgeNode, AlterSpaceNode, AlterTagNode, AlterUserNode, ApiKeyNode, ChangePasswordNode,
+    ClearSpaceNode, CreateConstraintNode, CreateEdgeIndexNode, CreateEdgeNode, CreateRoleNode,
+    CreateSpaceNode, CreateTagIndexNode, CreateTagNode, CreateUserNode, DescEdgeIndexNode,
+    DescEdgeNode, DescSpaceNode, DescTagIndexNode, DescTagNode, DescribeUserNode,
+    DropConstraintNode, DropEdgeIndexNode, DropEdgeNode, DropSpaceNode, DropTagIndexNode,
+    DropTagNode, DropUserNode, GrantPrivilegeNode, GrantRoleNode, RebuildEdgeIndexNode,
+    RebuildTagIndexNode, RevokeRoleNode, ShowConstraintsNode, ShowCreateEdgeNode,
+    ShowCreateIndexNode, ShowCreateSpaceNode, ShowCreateTagNode, ShowEdgeIndexesNode,
+    ShowEdgesNode, ShowIndexesNode, ShowRolesNode, ShowSpacesNode, ShowStatsNode,
+    ShowTagIndexesNode, ShowTagsNode, ShowUsersNode, SwitchSpaceNode,
 };
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
@@ -173,6 +176,7 @@ impl<S: StorageClient + Send + 'static> AdminBuilder<S> {
                 change.old_name.clone(),
                 change.new_name.clone(),
                 change.data_type.clone(),
+                change.allow_lossy,
             ));
         }
         let alter_info =
@@ -565,6 +569,61 @@ impl<S: StorageClient + Send + 'static> AdminBuilder<S> {
         ))
     }
 
+    /// Constructing the CreateConstraint executor
+    pub fn build_create_constraint(
+        node: &CreateConstraintNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = CreateConstraintExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().to_string(),
+            node.constraint().clone(),
+            node.if_not_exists(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::IndexManage(
+            IndexManageExecutor::CreateConstraint(executor),
+        ))
+    }
+
+    /// Constructing the DropConstraint executor
+    pub fn build_drop_constraint(
+        node: &DropConstraintNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = DropConstraintExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().to_string(),
+            node.constraint_name().to_string(),
+            node.if_exists(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::IndexManage(
+            IndexManageExecutor::DropConstraint(executor),
+        ))
+    }
+
+    /// Constructing the ShowConstraints executor
+    pub fn build_show_constraints(
+        node: &ShowConstraintsNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = ShowConstraintsExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().to_string(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::IndexManage(
+            IndexManageExecutor::ShowConstraints(executor),
+        ))
+    }
+
     // >User Management Executor==========
 
     /// Constructing the CreateUser executor
@@ -574,6 +633,10 @@ impl<S: StorageClient + Send + 'static> AdminBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         use crate::core::types::UserInfo;
+        storage
+            .read()
+            .check_password_policy(node.password())
+            .map_err(|e| QueryError::execution(e.to_string()))?;
         let user_info = UserInfo::new(node.username().to_string(), node.password().to_string())
             .map_err(|e| {
                 QueryError::execution(format!("Failed to create user information: {}", e))
@@ -607,7 +670,16 @@ impl<S: StorageClient + Send + 'static> AdminBuilder<S> {
         use crate::core::types::UserAlterInfo;
         // AlterUserNode 使用 username() 方法
         // The `AlterUserExecutor::new` method requires a `UserAlterInfo` object.
-        let alter_info = UserAlterInfo::new(node.username().to_string());
+        let quota = node.quota();
+        let alter_info = UserAlterInfo {
+            password: node.password().cloned(),
+            is_locked: node.is_locked(),
+            max_queries_per_hour: quota.max_queries_per_hour,
+            max_updates_per_hour: quota.max_updates_per_hour,
+            max_connections_per_hour: quota.max_connections_per_hour,
+            max_user_connections: quota.max_user_connections,
+            ..UserAlterInfo::new(node.username().to_string())
+        };
         let executor = AlterUserExecutor::new(
             node.id(),
             storage,
@@ -709,6 +781,58 @@ impl<S: StorageClient + Send + 'static> AdminBuilder<S> {
         )))
     }
 
+    /// Building the CreateRole executor
+    pub fn build_create_role(
+        node: &CreateRoleNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = CreateRoleExecutor::new(
+            node.id(),
+            storage,
+            node.role().clone(),
+            node.if_not_exists(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::UserManage(UserManageExecutor::CreateRole(
+            executor,
+        )))
+    }
+
+    /// Building the GrantPrivilege executor
+    pub fn build_grant_privilege(
+        node: &GrantPrivilegeNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = GrantPrivilegeExecutor::new(
+            node.id(),
+            storage,
+            node.kind().clone(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::UserManage(
+            UserManageExecutor::GrantPrivilege(executor),
+        ))
+    }
+
+    /// Building the ApiKey executor
+    pub fn build_api_key(
+        node: &ApiKeyNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = ApiKeyExecutor::new(
+            node.id(),
+            storage,
+            node.action().clone(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::UserManage(UserManageExecutor::ApiKey(
+            executor,
+        )))
+    }
+
     /// Building the SwitchSpace executor
     pub fn build_switch_space(
         node: &SwitchSpaceNode,
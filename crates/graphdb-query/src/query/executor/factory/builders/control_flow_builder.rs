@@ -50,10 +50,7 @@ impl<S: StorageClient + Send + 'static> ControlFlowBuilder<S> {
 
         let body_executor = create_executor_fn(body, storage.clone(), context)?;
 
-        let condition = node
-            .condition()
-            .expression()
-            .map(|meta| meta.inner().clone());
+        let condition = context.resolve(node.condition());
 
         let executor = LoopExecutor::new(
             node.id(),
@@ -73,10 +70,8 @@ impl<S: StorageClient + Send + 'static> ControlFlowBuilder<S> {
         context: &ExecutionContext,
         create_executor_fn: &mut CreateExecutorFn<S>,
     ) -> Result<ExecutorEnum<S>, QueryError> {
-        let condition = node
-            .condition()
-            .expression()
-            .map(|meta| meta.inner().clone())
+        let condition = context
+            .resolve(node.condition())
             .unwrap_or_else(|| crate::core::Expression::Literal(crate::core::Value::Bool(true)));
 
         let if_branch = node
@@ -1,19 +1,21 @@
 //! Data Access Executor Builder
 //!
-//! Responsible for creating executors for different data access types (ScanVertices, ScanEdges, GetVertices, GetNeighbors, IndexScan, GetEdges)
+//! Responsible for creating executors for different data access types (ScanVertices, ScanEdges, GetVertices, GetNeighbors, IndexScan, GetEdges, ProcedureCall, MaterializedViewScan)
 
 use crate::core::error::QueryError;
 use crate::query::executor::base::ExecutorEnum;
 use crate::query::executor::base::{ExecutionContext, ExecutorConfig, IndexScanConfig};
+use crate::query::executor::control_flow::SharedBatchTransactionControl;
 use crate::query::executor::data_access::{
     GetEdgesExecutor, GetNeighborsExecutor, GetVerticesExecutor, GetVerticesParams,
-    IndexScanExecutor, ScanEdgesExecutor,
+    IndexScanExecutor, MaterializedViewScanExecutor, ProcedureCallExecutor, ScanEdgesExecutor,
 };
+use crate::query::executor::expression::functions::SharedProcedureRegistry;
 use crate::query::executor::factory::param_parsing::{parse_edge_direction, parse_vertex_ids};
 use crate::query::planning::plan::core::nodes::access::IndexScanNode;
 use crate::query::planning::plan::core::nodes::{
-    EdgeIndexScanNode, GetEdgesNode, GetNeighborsNode, GetVerticesNode, ScanEdgesNode,
-    ScanVerticesNode,
+    EdgeIndexScanNode, GetEdgesNode, GetNeighborsNode, GetVerticesNode, MaterializedViewScanNode,
+    ProcedureCallNode, ScanEdgesNode, ScanVerticesNode,
 };
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
@@ -49,7 +51,7 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
             space_name: node.space_name().to_string(),
             vertex_ids: None,
             tag_filter: None,
-            vertex_filter: node.vertex_filter().and_then(|f| f.get_expression()),
+            vertex_filter: node.vertex_filter().and_then(|f| context.resolve(f)),
             limit: node.limit().map(|l| l as usize),
             col_names,
         };
@@ -79,7 +81,7 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
             node.id(),
             storage,
             node.edge_type(),
-            node.filter().and_then(|f| f.get_expression()),
+            node.filter().and_then(|f| context.resolve(f)),
             node.limit().map(|l| l as usize),
             context.expression_context().clone(),
         )
@@ -107,7 +109,7 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
                 Some(vertex_ids)
             },
             tag_filter: None,
-            vertex_filter: node.expression().and_then(|e| e.get_expression()),
+            vertex_filter: node.expression().and_then(|e| context.resolve(e)),
             limit: node.limit().map(|l| l as usize),
             col_names,
         };
@@ -174,7 +176,7 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
                 schema_name: node.schema_name().to_string(),
                 scan_type: node.scan_type().as_str().to_string(),
                 scan_limits: node.scan_limits().to_vec(),
-                filter: node.filter().and_then(|f| f.get_expression()),
+                filter: node.filter().and_then(|f| context.resolve(f)),
                 return_columns: node.return_columns().to_vec(),
                 limit: node.limit().map(|l| l as usize),
                 is_edge: true,
@@ -183,6 +185,45 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
         Ok(ExecutorEnum::IndexScan(executor))
     }
 
+    /// Building the ProcedureCall executor
+    pub fn build_procedure_call(
+        node: &ProcedureCallNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+        registry: SharedProcedureRegistry,
+        transaction_control: Option<SharedBatchTransactionControl>,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = ProcedureCallExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().map(|s| s.to_string()),
+            node.procedure().to_string(),
+            node.args().iter().map(|arg| context.bind(arg)).collect(),
+            node.yield_columns().to_vec(),
+            registry,
+            context.expression_context().clone(),
+        )
+        .with_transaction_control(transaction_control)
+        .with_role(context.role);
+        Ok(ExecutorEnum::ProcedureCall(executor))
+    }
+
+    /// Building the MaterializedViewScan executor
+    pub fn build_materialized_view_scan(
+        node: &MaterializedViewScanNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = MaterializedViewScanExecutor::new(
+            node.id(),
+            storage,
+            node.space_id(),
+            node.view_name().to_string(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::MaterializedViewScan(executor))
+    }
+
     /// Constructing the GetEdges executor
     pub fn build_get_edges(
         node: &GetEdgesNode,
@@ -235,7 +276,7 @@ impl<S: StorageClient + Send + 'static> DataAccessBuilder<S> {
                 schema_name: node.schema_name().to_string(),
                 scan_type: node.scan_type().as_str().to_string(),
                 scan_limits: node.scan_limits().to_vec(),
-                filter: node.filter().and_then(|f| f.get_expression()),
+                filter: node.filter().and_then(|f| context.resolve(f)),
                 return_columns: node.return_columns().to_vec(),
                 limit: node.limit().map(|l| l as usize),
                 is_edge: false,
@@ -43,8 +43,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         for (vid_expr, tag_values_list) in node.values() {
             // Obtain the vertex ID expression and evaluate it
-            let vid = vid_expr
-                .get_expression()
+            let vid = context
+                .resolve(vid_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -69,8 +69,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                     for (prop_idx, prop_value) in tag_values.iter().enumerate() {
                         if let Some(prop_name) = tag_info.prop_names.get(prop_idx) {
                             // Evaluate the expression to get the actual value
-                            let value = prop_value
-                                .get_expression()
+                            let value = context
+                                .resolve(prop_value)
                                 .and_then(|e| Self::evaluate_literal(&e))
                                 .unwrap_or(Value::Null(crate::core::NullType::Null));
                             tag_props.insert(prop_name.clone(), value);
@@ -201,8 +201,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         for (src_expr, dst_expr, rank_expr, prop_values) in node.edges() {
             // Obtain the expression for the ID of the source vertex and evaluate it.
-            let src = src_expr
-                .get_expression()
+            let src = context
+                .resolve(src_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -211,8 +211,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 })?;
 
             // Obtain the expression for the target vertex ID and evaluate it.
-            let dst = dst_expr
-                .get_expression()
+            let dst = context
+                .resolve(dst_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -224,7 +224,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
             // Obtain the rank (optional); the default value is 0.
             let rank = rank_expr
                 .as_ref()
-                .and_then(|e| e.get_expression())
+                .and_then(|e| context.resolve(e))
                 .and_then(|expr| Self::evaluate_literal(&expr))
                 .and_then(|v| match v {
                     crate::core::Value::BigInt(v) => Some(v),
@@ -238,7 +238,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
             let prop_names = node.prop_names();
             for (prop_idx, prop_value) in prop_values.iter().enumerate() {
                 if let Some(prop_name) = prop_names.get(prop_idx) {
-                    if let Some(value_expr) = prop_value.get_expression() {
+                    if let Some(value_expr) = context.resolve(prop_value) {
                         // Evaluate the expression to get the actual value
                         let value = Self::evaluate_literal(&value_expr)
                             .unwrap_or(Value::Null(crate::core::NullType::Null));
@@ -298,7 +298,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 };
                 RemoveItem {
                     item_type: item_type_enum,
-                    expression: expr.clone(),
+                    expression: context.bind(expr),
                 }
             })
             .collect();
@@ -323,8 +323,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let mut vertex_ids = Vec::new();
         for vid_expr in node.vertex_ids() {
-            let vid = vid_expr
-                .get_expression()
+            let vid = context
+                .resolve(vid_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -358,8 +358,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let mut edge_ids = Vec::new();
         for (src_expr, dst_expr, _rank_expr) in node.edges() {
-            let src = src_expr
-                .get_expression()
+            let src = context
+                .resolve(src_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -367,8 +367,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                     )
                 })?;
 
-            let dst = dst_expr
-                .get_expression()
+            let dst = context
+                .resolve(dst_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -405,10 +405,12 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let executor =
             PipeDeleteExecutor::new(node.id(), storage, context.expression_context().clone())
-                .with_vertex_expressions(node.vertex_ids().to_vec())
+                .with_vertex_expressions(
+                    node.vertex_ids().iter().map(|v| context.bind(v)).collect(),
+                )
                 .with_space(node.space_name().to_string())
                 .with_edge_flag(node.with_edge())
-                .with_condition(node.condition().cloned());
+                .with_condition(node.condition().map(|c| context.bind(c)));
 
         Ok(ExecutorEnum::PipeDelete(executor))
     }
@@ -423,10 +425,21 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let executor =
             PipeDeleteExecutor::new(node.id(), storage, context.expression_context().clone())
-                .with_edge_expressions(node.edges().to_vec())
+                .with_edge_expressions(
+                    node.edges()
+                        .iter()
+                        .map(|(src, dst, rank)| {
+                            (
+                                context.bind(src),
+                                context.bind(dst),
+                                rank.as_ref().map(|r| context.bind(r)),
+                            )
+                        })
+                        .collect(),
+                )
                 .with_edge_type(node.edge_type().map(|s: &str| s.to_string()))
                 .with_space(node.space_name().to_string())
-                .with_condition(node.condition().cloned());
+                .with_condition(node.condition().map(|c| context.bind(c)));
 
         Ok(ExecutorEnum::PipeDelete(executor))
     }
@@ -441,8 +454,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let mut vertex_ids = Vec::new();
         for vid_expr in node.vertex_ids() {
-            let vid = vid_expr
-                .get_expression()
+            let vid = context
+                .resolve(vid_expr)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -499,9 +512,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         match node.info() {
             UpdateTargetType::Vertex(info) => {
-                let vertex_id = info
-                    .vertex_id
-                    .get_expression()
+                let vertex_id = context
+                    .resolve(&info.vertex_id)
                     .and_then(|e| Self::evaluate_literal(&e))
                     .ok_or_else(|| {
                         QueryError::execution(
@@ -520,7 +532,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 let mut has_non_literal_expr = false;
 
                 for (key, value_expr) in &info.properties {
-                    let expr_opt = value_expr.get_expression();
+                    let expr_opt = context.resolve(value_expr);
                     log::debug!(
                         "[build_update] property '{}' expression={:?}",
                         key,
@@ -531,7 +543,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                         if let Some(value) = Self::evaluate_literal(&expr) {
                             properties.insert(key.clone(), value);
                         } else {
-                            property_expressions.insert(key.clone(), value_expr.clone());
+                            property_expressions.insert(key.clone(), context.bind(value_expr));
                             has_non_literal_expr = true;
                         }
                     } else {
@@ -561,7 +573,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                     storage,
                     Some(vec![vertex_update]),
                     None,
-                    info.condition.clone(),
+                    info.condition.as_ref().map(|c| context.bind(c)),
                     context.expression_context().clone(),
                 )
                 .with_space(info.space_name.clone())
@@ -570,9 +582,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 Ok(ExecutorEnum::Update(executor))
             }
             UpdateTargetType::Edge(info) => {
-                let src = info
-                    .src
-                    .get_expression()
+                let src = context
+                    .resolve(&info.src)
                     .and_then(|e| Self::evaluate_literal(&e))
                     .ok_or_else(|| {
                         QueryError::execution(
@@ -581,9 +592,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                         )
                     })?;
 
-                let dst = info
-                    .dst
-                    .get_expression()
+                let dst = context
+                    .resolve(&info.dst)
                     .and_then(|e| Self::evaluate_literal(&e))
                     .ok_or_else(|| {
                         QueryError::execution(
@@ -595,7 +605,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 let rank = info
                     .rank
                     .as_ref()
-                    .and_then(|r| r.get_expression().and_then(|e| Self::evaluate_literal(&e)))
+                    .and_then(|r| context.resolve(r).and_then(|e| Self::evaluate_literal(&e)))
                     .and_then(|v| match v {
                         Value::BigInt(i) => Some(i),
                         _ => None,
@@ -606,11 +616,11 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                 let mut has_non_literal_expr = false;
 
                 for (key, value_expr) in &info.properties {
-                    if let Some(expr) = value_expr.get_expression() {
+                    if let Some(expr) = context.resolve(value_expr) {
                         if let Some(value) = Self::evaluate_literal(&expr) {
                             properties.insert(key.clone(), value);
                         } else {
-                            property_expressions.insert(key.clone(), value_expr.clone());
+                            property_expressions.insert(key.clone(), context.bind(value_expr));
                             has_non_literal_expr = true;
                         }
                     } else {
@@ -641,7 +651,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                     storage,
                     None,
                     Some(vec![edge_update]),
-                    info.condition.clone(),
+                    info.condition.as_ref().map(|c| context.bind(c)),
                     context.expression_context().clone(),
                 )
                 .with_space(info.space_name.clone())
@@ -662,9 +672,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let mut vertex_updates = Vec::new();
         for info in node.updates() {
-            let vertex_id = info
-                .vertex_id
-                .get_expression()
+            let vertex_id = context
+                .resolve(&info.vertex_id)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -677,11 +686,11 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
             let mut has_non_literal_expr = false;
 
             for (key, value_expr) in &info.properties {
-                if let Some(expr) = value_expr.get_expression() {
+                if let Some(expr) = context.resolve(value_expr) {
                     if let Some(value) = Self::evaluate_literal(&expr) {
                         properties.insert(key.clone(), value);
                     } else {
-                        property_expressions.insert(key.clone(), value_expr.clone());
+                        property_expressions.insert(key.clone(), context.bind(value_expr));
                         has_non_literal_expr = true;
                     }
                 } else {
@@ -737,9 +746,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
 
         let mut edge_updates = Vec::new();
         for info in node.updates() {
-            let src = info
-                .src
-                .get_expression()
+            let src = context
+                .resolve(&info.src)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -747,9 +755,8 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
                     )
                 })?;
 
-            let dst = info
-                .dst
-                .get_expression()
+            let dst = context
+                .resolve(&info.dst)
                 .and_then(|e| Self::evaluate_literal(&e))
                 .ok_or_else(|| {
                     QueryError::execution(
@@ -761,7 +768,7 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
             let rank = info
                 .rank
                 .as_ref()
-                .and_then(|r| r.get_expression().and_then(|e| Self::evaluate_literal(&e)))
+                .and_then(|r| context.resolve(r).and_then(|e| Self::evaluate_literal(&e)))
                 .and_then(|v| match v {
                     Value::BigInt(i) => Some(i),
                     _ => None,
@@ -772,11 +779,11 @@ impl<S: StorageClient + Send + 'static> DataModificationBuilder<S> {
             let mut has_non_literal_expr = false;
 
             for (key, value_expr) in &info.properties {
-                if let Some(expr) = value_expr.get_expression() {
+                if let Some(expr) = context.resolve(value_expr) {
                     if let Some(value) = Self::evaluate_literal(&expr) {
                         properties.insert(key.clone(), value);
                     } else {
-                        property_expressions.insert(key.clone(), value_expr.clone());
+                        property_expressions.insert(key.clone(), context.bind(value_expr));
                         has_non_literal_expr = true;
                     }
                 } else {
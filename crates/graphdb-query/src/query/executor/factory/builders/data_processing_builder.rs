@@ -35,10 +35,10 @@ impl<S: StorageClient + Send + 'static> DataProcessingBuilder<S> {
     pub fn build_filter(
         node: &FilterNode,
         storage: Arc<RwLock<S>>,
-        _context: &ExecutionContext,
+        context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         // The `FilterExecutor::new` method requires a `ContextualExpression`.
-        let condition = node.condition().clone();
+        let condition = context.bind(node.condition());
 
         let executor = FilterExecutor::new(node.id(), storage, condition);
         Ok(ExecutorEnum::Filter(executor))
@@ -55,7 +55,7 @@ impl<S: StorageClient + Send + 'static> DataProcessingBuilder<S> {
         let columns: Vec<ProjectionColumn> = node
             .columns()
             .iter()
-            .map(|col| ProjectionColumn::new(col.alias.clone(), col.expression.clone()))
+            .map(|col| ProjectionColumn::new(col.alias.clone(), context.bind(&col.expression)))
             .collect();
 
         let executor = ProjectExecutor::new(
@@ -11,8 +11,11 @@ use crate::core::types::span::Span;
 use crate::query::executor::admin::{
     AlterFulltextIndexExecutor, CreateFulltextIndexConfig, CreateFulltextIndexExecutor,
     DescribeFulltextIndexExecutor, DropFulltextIndexExecutor, ShowFulltextIndexExecutor,
+    SyncDlqExecutor,
 };
 use crate::query::executor::base::{ExecutionContext, ExecutorEnum, FulltextManageExecutor};
+#[cfg(feature = "qdrant")]
+use crate::query::executor::data_access::HybridSearchExecutor;
 use crate::query::executor::data_access::{
     FulltextScanConfig, FulltextScanExecutor, FulltextSearchExecutor, FulltextSearchExecutorParams,
     MatchFulltextExecutor,
@@ -20,9 +23,12 @@ use crate::query::executor::data_access::{
 use crate::query::parser::ast::SearchStatement;
 use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
 use crate::query::planning::plan::core::nodes::search::fulltext::data_access::FulltextSearchNode;
+#[cfg(feature = "qdrant")]
+use crate::query::planning::plan::core::nodes::HybridSearchNode;
 use crate::query::planning::plan::core::nodes::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
     DropFulltextIndexNode, FulltextLookupNode, MatchFulltextNode, ShowFulltextIndexNode,
+    SyncDlqNode,
 };
 use crate::storage::StorageClient;
 use crate::sync::SyncManager;
@@ -174,6 +180,29 @@ impl<S: StorageClient + Send + 'static> FulltextSearchBuilder<S> {
         ))
     }
 
+    /// Build SyncDlq executor
+    pub fn build_sync_dlq(
+        node: &SyncDlqNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+        sync_manager: Option<&Arc<SyncManager>>,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let sync_manager = sync_manager
+            .ok_or_else(|| QueryError::execution("Sync manager not available".to_string()))?
+            .clone();
+
+        let executor = SyncDlqExecutor::new(
+            node.id(),
+            storage,
+            context.expression_context().clone(),
+            sync_manager,
+            node.action,
+        );
+        Ok(ExecutorEnum::FulltextManage(
+            FulltextManageExecutor::SyncDlq(executor),
+        ))
+    }
+
     /// Build FulltextSearch executor
     pub fn build_fulltext_search(
         node: &FulltextSearchNode,
@@ -274,6 +303,36 @@ impl<S: StorageClient + Send + 'static> FulltextSearchBuilder<S> {
         );
         Ok(ExecutorEnum::MatchFulltext(executor))
     }
+
+    /// Build HybridSearch executor
+    #[cfg(feature = "qdrant")]
+    pub fn build_hybrid_search(
+        node: &HybridSearchNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+        sync_manager: Option<&Arc<SyncManager>>,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let sync_manager = sync_manager
+            .ok_or_else(|| QueryError::execution("Sync manager not available".to_string()))?;
+        let fulltext_manager = sync_manager.fulltext_manager();
+        let coordinator = sync_manager
+            .vector_coordinator()
+            .cloned()
+            .ok_or_else(|| QueryError::execution("Vector coordinator not available".to_string()))?;
+
+        let mut node = node.clone();
+        node.where_clause = node.where_clause.map(|clause| context.bind(&clause));
+
+        let executor = HybridSearchExecutor::new(
+            node.id(),
+            node,
+            storage,
+            context.expression_context().clone(),
+            fulltext_manager,
+            coordinator,
+        );
+        Ok(ExecutorEnum::HybridSearch(executor))
+    }
 }
 
 impl<S: StorageClient + 'static> Default for FulltextSearchBuilder<S> {
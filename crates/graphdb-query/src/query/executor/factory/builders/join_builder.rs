@@ -1,18 +1,18 @@
 //! Connection Executor Builder
 //!
-//! Responsible for creating executors for different types of joins (InnerJoin, LeftJoin, FullOuterJoin, CrossJoin)
+//! Responsible for creating executors for different types of joins (InnerJoin, LeftJoin, FullOuterJoin, CrossJoin, MultiwayJoin)
 
 use crate::core::error::query::QueryError;
 use crate::query::executor::base::ExecutionContext;
 use crate::query::executor::base::ExecutorEnum;
 use crate::query::executor::relational_algebra::join::{
     CrossJoinExecutor, FullOuterJoinExecutor, HashInnerJoinExecutor, HashLeftJoinExecutor,
-    InnerJoinConfig, InnerJoinExecutor, LeftJoinConfig, LeftJoinExecutor,
+    InnerJoinConfig, InnerJoinExecutor, LeftJoinConfig, LeftJoinExecutor, MultiwayJoinExecutor,
 };
 use crate::query::planning::plan::core::nodes::base::plan_node_traits::JoinNode;
 use crate::query::planning::plan::core::nodes::{
     CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode, InnerJoinNode,
-    LeftJoinNode, RightJoinNode, SemiJoinNode,
+    LeftJoinNode, MultiwayJoinNode, RightJoinNode, SemiJoinNode,
 };
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
@@ -54,8 +54,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config = InnerJoinConfig {
             id: node.id(),
@@ -78,8 +86,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config = InnerJoinConfig {
             id: node.id(),
@@ -101,8 +117,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config = LeftJoinConfig {
             id: node.id(),
@@ -124,8 +148,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config = LeftJoinConfig {
             id: node.id(),
@@ -148,8 +180,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config =
             crate::query::executor::relational_algebra::join::full_outer_join::FullOuterJoinConfig {
@@ -224,6 +264,32 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         Ok(ExecutorEnum::CrossJoin(executor))
     }
 
+    /// Building the MultiwayJoin executor
+    pub fn build_multiway_join(
+        node: &MultiwayJoinNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        // The optimizer resolves every candidate; one still carrying its
+        // fallback has not had its pattern filters applied
+        if node.is_candidate() {
+            return Err(QueryError::execution(
+                "Multiway join candidate was not resolved by the optimizer".to_string(),
+            ));
+        }
+
+        let executor = MultiwayJoinExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().to_string(),
+            node.relations().to_vec(),
+            node.variable_order().to_vec(),
+            node.col_names().to_vec(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::MultiwayJoin(executor))
+    }
+
     /// Building the RightJoin executor
     /// RightJoin is implemented by converting to LeftJoin with swapped inputs
     pub fn build_right_join(
@@ -242,8 +308,16 @@ impl<S: StorageClient + Send + 'static> JoinBuilder<S> {
         context: &ExecutionContext,
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let (left_var, right_var) = Self::extract_join_vars(node);
-        let hash_keys: Vec<crate::core::types::ContextualExpression> = node.hash_keys().to_vec();
-        let probe_keys: Vec<crate::core::types::ContextualExpression> = node.probe_keys().to_vec();
+        let hash_keys: Vec<crate::core::types::ContextualExpression> = node
+            .hash_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
+        let probe_keys: Vec<crate::core::types::ContextualExpression> = node
+            .probe_keys()
+            .iter()
+            .map(|key| context.bind(key))
+            .collect();
 
         let config = InnerJoinConfig {
             id: node.id(),
@@ -1,6 +1,6 @@
 //! Data Conversion Executor Builder
 //!
-//! Responsible for creating executors for various data transformation types (Unwind, Assign, Materialize, AppendVertices, RollUpApply, PatternApply).
+//! Responsible for creating executors for various data transformation types (Unwind, Assign, Materialize, AppendVertices, RollUpApply, PatternApply, PatternSubquery).
 
 use crate::core::error::query::QueryError;
 use crate::query::executor::base::ExecutorEnum;
@@ -9,12 +9,12 @@ use crate::query::executor::base::{
 };
 use crate::query::executor::graph_operations::MaterializeExecutor;
 use crate::query::executor::result_processing::transformations::{
-    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, RollUpApplyExecutor,
-    UnwindExecutor,
+    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, PatternSubqueryExecutor,
+    RollUpApplyExecutor, UnwindExecutor,
 };
 use crate::query::planning::plan::core::nodes::{
-    AppendVerticesNode, ApplyNode, AssignNode, MaterializeNode, PatternApplyNode, RollUpApplyNode,
-    UnwindNode,
+    AppendVerticesNode, ApplyNode, AssignNode, MaterializeNode, PatternApplyNode,
+    PatternSubqueryNode, RollUpApplyNode, UnwindNode,
 };
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
@@ -41,13 +41,9 @@ impl<S: StorageClient + Send + 'static> TransformationBuilder<S> {
     ) -> Result<ExecutorEnum<S>, QueryError> {
         use crate::query::planning::plan::core::nodes::base::plan_node_traits::SingleInputNode;
 
-        let unwind_expression = node
-            .list_expression()
-            .expression()
-            .map(|meta| meta.inner().clone())
-            .ok_or_else(|| {
-                QueryError::execution("Expression does not exist in context".to_string())
-            })?;
+        let unwind_expression = context.resolve(node.list_expression()).ok_or_else(|| {
+            QueryError::execution("Expression does not exist in context".to_string())
+        })?;
 
         let input_var = node
             .input()
@@ -75,12 +71,9 @@ impl<S: StorageClient + Send + 'static> TransformationBuilder<S> {
     ) -> Result<ExecutorEnum<S>, QueryError> {
         let mut parsed_assignments = Vec::new();
         for (var_name, ctx_expr) in node.assignments() {
-            let expression = ctx_expr
-                .expression()
-                .map(|meta| meta.inner().clone())
-                .ok_or_else(|| {
-                    QueryError::execution("Expression does not exist in context".to_string())
-                })?;
+            let expression = context.resolve(ctx_expr).ok_or_else(|| {
+                QueryError::execution("Expression does not exist in context".to_string())
+            })?;
             parsed_assignments.push((var_name.clone(), expression));
         }
 
@@ -122,8 +115,7 @@ impl<S: StorageClient + Send + 'static> TransformationBuilder<S> {
 
         let src_expression = node
             .src_expression()
-            .and_then(|ctx_expr| ctx_expr.expression())
-            .map(|meta| meta.inner().clone())
+            .and_then(|ctx_expr| context.resolve(ctx_expr))
             .unwrap_or_else(|| crate::core::Expression::Variable("_".to_string()));
 
         let executor = AppendVerticesExecutor::new(
@@ -197,7 +189,7 @@ impl<S: StorageClient + Send + 'static> TransformationBuilder<S> {
         let key_cols: Vec<crate::core::Expression> = node
             .key_cols()
             .iter()
-            .filter_map(|ctx_expr| ctx_expr.get_expression())
+            .filter_map(|ctx_expr| context.resolve(ctx_expr))
             .collect();
 
         let executor = PatternApplyExecutor::new(
@@ -213,6 +205,25 @@ impl<S: StorageClient + Send + 'static> TransformationBuilder<S> {
         Ok(ExecutorEnum::PatternApply(executor))
     }
 
+    /// Constructing the PatternSubquery executor
+    pub fn build_pattern_subquery(
+        node: &PatternSubqueryNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        let executor = PatternSubqueryExecutor::new(
+            node.id(),
+            storage,
+            node.space_name().to_string(),
+            node.subquery().clone(),
+            node.output().clone(),
+            node.is_decorrelated(),
+            node.col_names().to_vec(),
+            context.expression_context().clone(),
+        );
+        Ok(ExecutorEnum::PatternSubquery(executor))
+    }
+
     /// Constructing the Apply executor
     /// Apply executes a correlated subquery for each row from the left input
     pub fn build_apply(
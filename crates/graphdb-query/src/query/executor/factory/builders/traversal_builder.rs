@@ -99,7 +99,7 @@ impl<S: StorageClient + Send + 'static> TraversalBuilder<S> {
         let mut executor = ExpandAllExecutor::with_context(params, context.clone())
             .with_src_vids(src_vids)
             .with_include_empty_paths(node.include_empty_paths())
-            .with_filter(node.filter().cloned());
+            .with_filter(node.filter().map(|filter| context.bind(filter)));
 
         // If input_var is set, use it to get input from ExecutionContext
         if let Some(input_var) = node.get_input_var() {
@@ -138,7 +138,8 @@ impl<S: StorageClient + Send + 'static> TraversalBuilder<S> {
             Some(node.max_steps() as usize),
             None, // conditions
             context.expression_context().clone(),
-        );
+        )
+        .with_context(context.clone());
         Ok(ExecutorEnum::Traverse(executor))
     }
 
@@ -72,7 +72,7 @@ impl<S: StorageClient + Send + 'static> PlanExecutor<S> {
     /// via `set_input`. For `BinaryInputNode` plan nodes (e.g. Join), both children are built
     /// and executed to store their results in the execution context.
     /// For `ZeroInputNode` plan nodes (leaf nodes), only the executor itself is created.
-    fn build_executor_chain(
+    pub(crate) fn build_executor_chain(
         &mut self,
         plan_node: &crate::query::planning::plan::PlanNodeEnum,
         storage: Arc<RwLock<S>>,
@@ -177,8 +177,17 @@ impl<S: StorageClient + Send + 'static> PlanExecutor<S> {
         storage: Arc<RwLock<S>>,
         expression_context: Arc<ExpressionAnalysisContext>,
     ) -> Result<ExecutionResult, QueryError> {
-        let context = ExecutionContext::new(expression_context);
+        self.execute_plan_with_context(plan, storage, ExecutionContext::new(expression_context))
+    }
 
+    /// Execute the query plan in a prepared context, such as one carrying the
+    /// query's kill flag, and return the result.
+    pub fn execute_plan_with_context(
+        &mut self,
+        plan: &ExecutionPlan,
+        storage: Arc<RwLock<S>>,
+        context: ExecutionContext,
+    ) -> Result<ExecutionResult, QueryError> {
         // Build the executor chain from the plan root
         let root_node = plan
             .root()
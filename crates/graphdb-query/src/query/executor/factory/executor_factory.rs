@@ -6,6 +6,8 @@
 use crate::core::error::query::QueryError;
 use crate::query::executor::base::ExecutionContext;
 use crate::query::executor::base::ExecutorEnum;
+use crate::query::executor::control_flow::SharedBatchTransactionControl;
+use crate::query::executor::expression::functions::{ProcedureRegistry, SharedProcedureRegistry};
 #[cfg(feature = "fulltext-search")]
 use crate::query::executor::factory::builders::FulltextSearchBuilder;
 #[cfg(feature = "qdrant")]
@@ -32,6 +34,8 @@ pub struct ExecutorFactory<S: StorageClient + Send + 'static> {
     pub(crate) config: ExecutorSafetyConfig,
     pub(crate) recursion_detector: RecursionDetector,
     pub(crate) sync_manager: Option<Arc<SyncManager>>,
+    pub(crate) procedure_registry: SharedProcedureRegistry,
+    pub(crate) batch_transaction_control: Option<SharedBatchTransactionControl>,
 }
 
 impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
@@ -45,6 +49,8 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
             config,
             recursion_detector,
             sync_manager: None,
+            procedure_registry: Arc::new(RwLock::new(ProcedureRegistry::new())),
+            batch_transaction_control: None,
         }
     }
 
@@ -83,6 +89,26 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
         self.sync_manager.clone()
     }
 
+    /// Set the procedure registry used by CALL
+    pub fn set_procedure_registry(&mut self, registry: SharedProcedureRegistry) {
+        self.procedure_registry = registry;
+    }
+
+    /// Get the procedure registry used by CALL
+    pub fn procedure_registry(&self) -> SharedProcedureRegistry {
+        self.procedure_registry.clone()
+    }
+
+    /// Set the transaction control used by CALL { } IN TRANSACTIONS
+    pub fn set_batch_transaction_control(&mut self, control: SharedBatchTransactionControl) {
+        self.batch_transaction_control = Some(control);
+    }
+
+    /// Get the transaction control used by CALL { } IN TRANSACTIONS
+    pub fn batch_transaction_control(&self) -> Option<SharedBatchTransactionControl> {
+        self.batch_transaction_control.clone()
+    }
+
     /// Analyzing the lifecycle and security of execution plans
     ///
     /// Traverse the execution plan tree using DFS to detect circular references and verify security.
@@ -246,6 +272,9 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 JoinBuilder::build_full_outer_join(node, storage, context)
             }
             PlanNodeEnum::CrossJoin(node) => JoinBuilder::build_cross_join(node, storage, context),
+            PlanNodeEnum::MultiwayJoin(node) => {
+                JoinBuilder::build_multiway_join(node, storage, context)
+            }
             PlanNodeEnum::SemiJoin(node) => JoinBuilder::build_semi_join(node, storage, context),
 
             // Set Operation Executor
@@ -301,6 +330,9 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
             PlanNodeEnum::PatternApply(node) => {
                 TransformationBuilder::build_pattern_apply(node, storage, context)
             }
+            PlanNodeEnum::PatternSubquery(node) => {
+                TransformationBuilder::build_pattern_subquery(node, storage, context)
+            }
             PlanNodeEnum::Apply(node) => {
                 TransformationBuilder::build_apply(node, storage, context)
             }
@@ -308,6 +340,10 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
             // Control Flow Executor
             PlanNodeEnum::Loop(node) => self.build_loop_executor(node, storage, context),
             PlanNodeEnum::Select(node) => self.build_select_executor(node, storage, context),
+            PlanNodeEnum::CallSubquery(node) => {
+                self.build_call_subquery_executor(node, storage, context)
+            }
+            PlanNodeEnum::Foreach(node) => self.build_foreach_executor(node, storage, context),
             PlanNodeEnum::Argument(node) => {
                 ControlFlowBuilder::build_argument(node, storage, context)
             }
@@ -437,6 +473,15 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 crate::query::planning::plan::core::nodes::management::manage_node_enums::IndexManageNode::ShowCreateIndex(node) => {
                     AdminBuilder::build_show_create_index(node, storage, context)
                 }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::IndexManageNode::CreateConstraint(node) => {
+                    AdminBuilder::build_create_constraint(node, storage, context)
+                }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::IndexManageNode::DropConstraint(node) => {
+                    AdminBuilder::build_drop_constraint(node, storage, context)
+                }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::IndexManageNode::ShowConstraints(node) => {
+                    AdminBuilder::build_show_constraints(node, storage, context)
+                }
             },
 
             // Manage Executor – User Management (parameterized)
@@ -459,6 +504,15 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode::RevokeRole(node) => {
                     AdminBuilder::build_revoke_role(node, storage, context)
                 }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode::CreateRole(node) => {
+                    AdminBuilder::build_create_role(node, storage, context)
+                }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode::GrantPrivilege(node) => {
+                    AdminBuilder::build_grant_privilege(node, storage, context)
+                }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode::ApiKey(node) => {
+                    AdminBuilder::build_api_key(node, storage, context)
+                }
                 crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode::ShowUsers(node) => {
                     AdminBuilder::build_show_users(node, storage, context)
                 }
@@ -498,6 +552,11 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                         node, storage, context, self.sync_manager.as_ref(),
                     )
                 }
+                crate::query::planning::plan::core::nodes::management::manage_node_enums::FulltextManageNode::SyncDlq(node) => {
+                    FulltextSearchBuilder::build_sync_dlq(
+                        node, storage, context, self.sync_manager.as_ref(),
+                    )
+                }
             },
             #[cfg(not(feature = "fulltext-search"))]
             PlanNodeEnum::FulltextManage(_) => Err(QueryError::execution(
@@ -526,6 +585,18 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
             // Management Executor – Query Management
             PlanNodeEnum::ShowStats(node) => AdminBuilder::build_show_stats(node, storage, context),
 
+            // Procedure Executor
+            PlanNodeEnum::ProcedureCall(node) => DataAccessBuilder::build_procedure_call(
+                node,
+                storage,
+                context,
+                self.procedure_registry.clone(),
+                self.batch_transaction_control.clone(),
+            ),
+            PlanNodeEnum::MaterializedViewScan(node) => {
+                DataAccessBuilder::build_materialized_view_scan(node, storage, context)
+            }
+
             // Full-text Search Executors (data access)
             #[cfg(feature = "fulltext-search")]
             PlanNodeEnum::FulltextSearch(node) => FulltextSearchBuilder::build_fulltext_search(
@@ -577,6 +648,19 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 context,
                 self.sync_manager.as_ref(),
             ),
+
+            // Hybrid Search Executors (data access)
+            #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+            PlanNodeEnum::HybridSearch(node) => FulltextSearchBuilder::build_hybrid_search(
+                node,
+                storage,
+                context,
+                self.sync_manager.as_ref(),
+            ),
+            #[cfg(not(all(feature = "fulltext-search", feature = "qdrant")))]
+            PlanNodeEnum::HybridSearch(_) => Err(QueryError::execution(
+                "Hybrid search requires the fulltext-search and qdrant features",
+            )),
         }
     }
 
@@ -609,15 +693,14 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 config,
                 recursion_detector: RecursionDetector::new(max_recursion_depth),
                 sync_manager: self.sync_manager.clone(),
+                procedure_registry: self.procedure_registry.clone(),
+                batch_transaction_control: self.batch_transaction_control.clone(),
             };
 
             temp_factory.create_executor(body, storage.clone(), context)?
         };
 
-        let condition = node
-            .condition()
-            .expression()
-            .map(|meta| meta.inner().clone());
+        let condition = context.resolve(node.condition());
 
         use crate::query::executor::control_flow::LoopExecutor;
         let executor = LoopExecutor::new(
@@ -645,10 +728,8 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 .map_err(|e| QueryError::execution(e.to_string()))?;
         }
 
-        let condition = node
-            .condition()
-            .expression()
-            .map(|meta| meta.inner().clone())
+        let condition = context
+            .resolve(node.condition())
             .unwrap_or_else(|| crate::core::Expression::Literal(crate::core::Value::Bool(true)));
 
         // Construct the `if_branch`.
@@ -664,6 +745,8 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                 config,
                 recursion_detector: RecursionDetector::new(max_recursion_depth),
                 sync_manager: self.sync_manager.clone(),
+                procedure_registry: self.procedure_registry.clone(),
+                batch_transaction_control: self.batch_transaction_control.clone(),
             };
 
             temp_factory.create_executor(if_node, storage.clone(), context)?
@@ -679,6 +762,8 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
                     config,
                     recursion_detector: RecursionDetector::new(max_recursion_depth),
                     sync_manager: self.sync_manager.clone(),
+                    procedure_registry: self.procedure_registry.clone(),
+                    batch_transaction_control: self.batch_transaction_control.clone(),
                 };
 
                 Some(temp_factory.create_executor(else_node, storage.clone(), context)?)
@@ -698,6 +783,45 @@ impl<S: StorageClient + Send + 'static> ExecutorFactory<S> {
         );
         Ok(ExecutorEnum::Select(executor))
     }
+
+    /// Constructing the CALL { } executor; the body is built per row at execution time
+    fn build_call_subquery_executor(
+        &mut self,
+        node: &crate::query::planning::plan::core::nodes::CallSubqueryNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        use crate::query::executor::control_flow::CallSubqueryExecutor;
+        let executor = CallSubqueryExecutor::new(
+            node.id(),
+            storage,
+            node.body().clone(),
+            node.in_transactions().copied(),
+            self.clone(),
+            context.clone(),
+        );
+        Ok(ExecutorEnum::CallSubquery(executor))
+    }
+
+    /// Constructing the FOREACH executor; the body is built per element at execution time
+    fn build_foreach_executor(
+        &mut self,
+        node: &crate::query::planning::plan::core::nodes::ForeachNode,
+        storage: Arc<RwLock<S>>,
+        context: &ExecutionContext,
+    ) -> Result<ExecutorEnum<S>, QueryError> {
+        use crate::query::executor::control_flow::ForeachExecutor;
+        let executor = ForeachExecutor::new(
+            node.id(),
+            storage,
+            node.variable().to_string(),
+            node.list().clone(),
+            node.body().to_vec(),
+            self.clone(),
+            context.clone(),
+        );
+        Ok(ExecutorEnum::Foreach(executor))
+    }
 }
 
 impl<S: StorageClient + 'static> Clone for ExecutorFactory<S> {
@@ -707,6 +831,8 @@ impl<S: StorageClient + 'static> Clone for ExecutorFactory<S> {
             config: self.config.clone(),
             recursion_detector: RecursionDetector::new(self.config.max_recursion_depth),
             sync_manager: self.sync_manager.clone(),
+            procedure_registry: self.procedure_registry.clone(),
+            batch_transaction_control: self.batch_transaction_control.clone(),
         }
     }
 }
@@ -0,0 +1,752 @@
+//! Centrality Algorithms
+//!
+//! PageRank, personalised PageRank, betweenness, closeness and degree centrality.
+//!
+//! The algorithms run over a `GraphProjection`: the vertices of the selected tags and the
+//! edges of the selected edge types, loaded once from storage into compressed sparse row
+//! (CSR) arrays. Work is spread over the rayon thread pool, per vertex for PageRank and
+//! degree and per source vertex for betweenness and closeness.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use rayon::prelude::*;
+
+use crate::core::types::{EdgeDirection, VertexId};
+use crate::query::QueryError;
+use crate::storage::StorageReader;
+
+use super::types::EdgeWeightConfig;
+
+/// Centrality algorithm type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CentralityAlgorithm {
+    PageRank,
+    /// PageRank whose random jumps return to a set of source vertices
+    PersonalizedPageRank,
+    Betweenness,
+    Closeness,
+    Degree,
+}
+
+impl CentralityAlgorithm {
+    pub const ALL: [CentralityAlgorithm; 5] = [
+        CentralityAlgorithm::PageRank,
+        CentralityAlgorithm::PersonalizedPageRank,
+        CentralityAlgorithm::Betweenness,
+        CentralityAlgorithm::Closeness,
+        CentralityAlgorithm::Degree,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CentralityAlgorithm::PageRank => "pageRank",
+            CentralityAlgorithm::PersonalizedPageRank => "personalizedPageRank",
+            CentralityAlgorithm::Betweenness => "betweenness",
+            CentralityAlgorithm::Closeness => "closeness",
+            CentralityAlgorithm::Degree => "degree",
+        }
+    }
+}
+
+/// Selection of the stored graph that an algorithm runs on
+#[derive(Debug, Clone)]
+pub struct ProjectionConfig {
+    /// Tags whose vertices are included; empty includes every vertex
+    pub tags: Vec<String>,
+    /// Edge types that are followed; empty includes every edge type
+    pub edge_types: Vec<String>,
+    /// `Out` follows edges from source to destination, `In` reverses them and
+    /// `Both` treats the graph as undirected
+    pub direction: EdgeDirection,
+    pub weight: EdgeWeightConfig,
+}
+
+impl Default for ProjectionConfig {
+    fn default() -> Self {
+        Self {
+            tags: Vec::new(),
+            edge_types: Vec::new(),
+            direction: EdgeDirection::Out,
+            weight: EdgeWeightConfig::Unweighted,
+        }
+    }
+}
+
+impl ProjectionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_edge_types(mut self, edge_types: Vec<String>) -> Self {
+        self.edge_types = edge_types;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: EdgeDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_weight(mut self, weight: EdgeWeightConfig) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// In-memory CSR projection of the stored graph
+///
+/// Vertices are addressed by their position in `vertices()`. Every edge is turned into
+/// one arc (two for undirected projections), stored both by source and by target.
+#[derive(Debug, Clone, Default)]
+pub struct GraphProjection {
+    vertices: Vec<VertexId>,
+    index: HashMap<VertexId, usize>,
+    out_offsets: Vec<usize>,
+    out_targets: Vec<usize>,
+    out_weights: Vec<f64>,
+    in_offsets: Vec<usize>,
+    in_sources: Vec<usize>,
+    in_weights: Vec<f64>,
+    undirected: bool,
+    weighted: bool,
+}
+
+impl GraphProjection {
+    /// Load the projection described by `config` from a space
+    pub fn load<S: StorageReader + ?Sized>(
+        storage: &S,
+        space: &str,
+        config: &ProjectionConfig,
+    ) -> Result<Self, QueryError> {
+        let vertices: Vec<VertexId> = if config.tags.is_empty() {
+            storage
+                .scan_vertices(space)
+                .map_err(|e| QueryError::storage(e.to_string()))?
+                .into_iter()
+                .map(|vertex| vertex.vid)
+                .collect()
+        } else {
+            let mut seen = HashSet::new();
+            let mut vids = Vec::new();
+            for tag in &config.tags {
+                let tagged = storage
+                    .scan_vertices_by_tag(space, tag)
+                    .map_err(|e| QueryError::storage(e.to_string()))?;
+                for vertex in tagged {
+                    if seen.insert(vertex.vid) {
+                        vids.push(vertex.vid);
+                    }
+                }
+            }
+            vids
+        };
+
+        let edges = if config.edge_types.is_empty() {
+            storage
+                .scan_all_edges(space)
+                .map_err(|e| QueryError::storage(e.to_string()))?
+        } else {
+            let mut edges = Vec::new();
+            for edge_type in &config.edge_types {
+                edges.extend(
+                    storage
+                        .scan_edges_by_type(space, edge_type)
+                        .map_err(|e| QueryError::storage(e.to_string()))?,
+                );
+            }
+            edges
+        };
+
+        let index: HashMap<VertexId, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vid)| (*vid, i))
+            .collect();
+
+        let mut arcs = Vec::with_capacity(edges.len());
+        for edge in &edges {
+            // Edges leaving the projection are ignored.
+            let (Some(&src), Some(&dst)) = (index.get(&edge.src), index.get(&edge.dst)) else {
+                continue;
+            };
+            let weight = config.weight.weight_of(edge);
+            match config.direction {
+                EdgeDirection::Out => arcs.push((src, dst, weight)),
+                EdgeDirection::In => arcs.push((dst, src, weight)),
+                EdgeDirection::Both => {
+                    arcs.push((src, dst, weight));
+                    if src != dst {
+                        arcs.push((dst, src, weight));
+                    }
+                }
+            }
+        }
+
+        Ok(Self::from_arcs(
+            vertices,
+            &arcs,
+            config.direction == EdgeDirection::Both,
+        ))
+    }
+
+    /// Build a projection from `(source, target, weight)` arcs over vertex positions
+    ///
+    /// Undirected projections must contain both arcs of every edge.
+    pub fn from_arcs(
+        vertices: Vec<VertexId>,
+        arcs: &[(usize, usize, f64)],
+        undirected: bool,
+    ) -> Self {
+        let n = vertices.len();
+        let (out_offsets, out_targets, out_weights) = build_csr(n, arcs.iter().copied());
+        let (in_offsets, in_sources, in_weights) =
+            build_csr(n, arcs.iter().map(|&(src, dst, weight)| (dst, src, weight)));
+        let index = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vid)| (*vid, i))
+            .collect();
+
+        Self {
+            vertices,
+            index,
+            out_offsets,
+            out_targets,
+            out_weights,
+            in_offsets,
+            in_sources,
+            in_weights,
+            undirected,
+            weighted: arcs.iter().any(|&(_, _, weight)| weight != 1.0),
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn arc_count(&self) -> usize {
+        self.out_targets.len()
+    }
+
+    pub fn vertices(&self) -> &[VertexId] {
+        &self.vertices
+    }
+
+    pub fn index_of(&self, vid: &VertexId) -> Option<usize> {
+        self.index.get(vid).copied()
+    }
+
+    pub fn is_undirected(&self) -> bool {
+        self.undirected
+    }
+
+    pub(super) fn out_arcs(&self, v: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.out_offsets[v]..self.out_offsets[v + 1];
+        self.out_targets[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.out_weights[range].iter().copied())
+    }
+
+    pub(super) fn in_arcs(&self, v: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.in_offsets[v]..self.in_offsets[v + 1];
+        self.in_sources[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.in_weights[range].iter().copied())
+    }
+
+    /// Shortest-path based algorithms need non-negative weights
+    fn check_non_negative_weights(&self) -> Result<(), QueryError> {
+        if self.out_weights.iter().any(|weight| *weight < 0.0) {
+            return Err(QueryError::execution(
+                "Shortest-path based centrality requires non-negative edge weights",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Single-source shortest paths, BFS for unweighted projections and Dijkstra otherwise
+    fn shortest_paths(&self, source: usize) -> ShortestPathDag {
+        if self.weighted {
+            self.dijkstra_dag(source)
+        } else {
+            self.bfs_dag(source)
+        }
+    }
+
+    fn bfs_dag(&self, source: usize) -> ShortestPathDag {
+        let mut dag = ShortestPathDag::new(self.vertex_count(), source);
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            dag.order.push(v);
+            let next = dag.dist[v] + 1.0;
+            for (w, _) in self.out_arcs(v) {
+                if dag.dist[w].is_infinite() {
+                    dag.dist[w] = next;
+                    queue.push_back(w);
+                }
+                if dag.dist[w] == next {
+                    dag.sigma[w] += dag.sigma[v];
+                    dag.preds[w].push(v);
+                }
+            }
+        }
+        dag
+    }
+
+    fn dijkstra_dag(&self, source: usize) -> ShortestPathDag {
+        let n = self.vertex_count();
+        let mut dag = ShortestPathDag::new(n, source);
+        let mut settled = vec![false; n];
+        let mut heap = BinaryHeap::from([HeapEntry {
+            distance: 0.0,
+            vertex: source,
+        }]);
+        while let Some(HeapEntry {
+            distance,
+            vertex: v,
+        }) = heap.pop()
+        {
+            if settled[v] || distance > dag.dist[v] {
+                continue;
+            }
+            settled[v] = true;
+            dag.order.push(v);
+            for (w, weight) in self.out_arcs(v) {
+                if settled[w] {
+                    continue;
+                }
+                let alt = distance + weight;
+                if alt < dag.dist[w] {
+                    dag.dist[w] = alt;
+                    dag.sigma[w] = dag.sigma[v];
+                    dag.preds[w] = vec![v];
+                    heap.push(HeapEntry {
+                        distance: alt,
+                        vertex: w,
+                    });
+                } else if alt == dag.dist[w] {
+                    dag.sigma[w] += dag.sigma[v];
+                    dag.preds[w].push(v);
+                }
+            }
+        }
+        dag
+    }
+}
+
+/// Group arcs by their first endpoint into CSR offset, neighbour and weight arrays
+fn build_csr(
+    n: usize,
+    arcs: impl Iterator<Item = (usize, usize, f64)> + Clone,
+) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+    let mut offsets = vec![0; n + 1];
+    for (from, _, _) in arcs.clone() {
+        offsets[from + 1] += 1;
+    }
+    for i in 0..n {
+        offsets[i + 1] += offsets[i];
+    }
+
+    let mut cursor = offsets.clone();
+    let mut neighbors = vec![0; offsets[n]];
+    let mut weights = vec![0.0; offsets[n]];
+    for (from, to, weight) in arcs {
+        let pos = cursor[from];
+        neighbors[pos] = to;
+        weights[pos] = weight;
+        cursor[from] += 1;
+    }
+    (offsets, neighbors, weights)
+}
+
+/// Shortest-path DAG from one source, as used by Brandes' algorithm
+struct ShortestPathDag {
+    source: usize,
+    /// Vertices in order of non-decreasing distance
+    order: Vec<usize>,
+    /// Number of shortest paths from the source
+    sigma: Vec<f64>,
+    preds: Vec<Vec<usize>>,
+    dist: Vec<f64>,
+}
+
+impl ShortestPathDag {
+    fn new(n: usize, source: usize) -> Self {
+        let mut dag = Self {
+            source,
+            order: Vec::new(),
+            sigma: vec![0.0; n],
+            preds: vec![Vec::new(); n],
+            dist: vec![f64::INFINITY; n],
+        };
+        dag.sigma[source] = 1.0;
+        dag.dist[source] = 0.0;
+        dag
+    }
+
+    /// Add the pair dependencies of this source to `scores`
+    fn accumulate(&self, scores: &mut [f64]) {
+        let mut delta = vec![0.0; scores.len()];
+        for &w in self.order.iter().rev() {
+            for &v in &self.preds[w] {
+                delta[v] += self.sigma[v] / self.sigma[w] * (1.0 + delta[w]);
+            }
+            if w != self.source {
+                scores[w] += delta[w];
+            }
+        }
+    }
+}
+
+/// Min-heap entry for Dijkstra
+struct HeapEntry {
+    distance: f64,
+    vertex: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance && self.vertex == other.vertex
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.vertex.cmp(&self.vertex))
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Centrality scores, indexed like `GraphProjection::vertices()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CentralityScores {
+    pub scores: Vec<f64>,
+    /// Iterations run by iterative algorithms, 0 otherwise
+    pub iterations: usize,
+}
+
+/// PageRank parameters
+#[derive(Debug, Clone)]
+pub struct PageRankConfig {
+    pub damping_factor: f64,
+    pub max_iterations: usize,
+    /// Convergence threshold on the average absolute change per vertex
+    pub tolerance: f64,
+}
+
+impl Default for PageRankConfig {
+    fn default() -> Self {
+        Self {
+            damping_factor: 0.85,
+            max_iterations: 20,
+            tolerance: 1e-7,
+        }
+    }
+}
+
+/// PageRank, personalised when `sources` is not empty
+///
+/// Random jumps and the rank of dangling vertices go to every vertex, or only to the
+/// sources for personalised PageRank. Arc weights split a vertex's rank proportionally.
+/// Scores sum to 1.
+pub fn page_rank(
+    graph: &GraphProjection,
+    config: &PageRankConfig,
+    sources: &[usize],
+) -> CentralityScores {
+    let n = graph.vertex_count();
+    if n == 0 {
+        return CentralityScores::default();
+    }
+
+    let teleport = if sources.is_empty() {
+        vec![1.0 / n as f64; n]
+    } else {
+        let mut teleport = vec![0.0; n];
+        let share = 1.0 / sources.len() as f64;
+        for &source in sources {
+            teleport[source] += share;
+        }
+        teleport
+    };
+    let out_weight: Vec<f64> = (0..n)
+        .into_par_iter()
+        .map(|v| graph.out_arcs(v).map(|(_, weight)| weight).sum())
+        .collect();
+
+    let damping = config.damping_factor;
+    let mut ranks = teleport.clone();
+    let mut iterations = 0;
+    while iterations < config.max_iterations {
+        iterations += 1;
+
+        let dangling: f64 = (0..n)
+            .into_par_iter()
+            .filter(|&v| out_weight[v] <= 0.0)
+            .map(|v| ranks[v])
+            .sum();
+        let next: Vec<f64> = (0..n)
+            .into_par_iter()
+            .map(|v| {
+                let incoming: f64 = graph
+                    .in_arcs(v)
+                    .filter(|&(u, _)| out_weight[u] > 0.0)
+                    .map(|(u, weight)| ranks[u] * weight / out_weight[u])
+                    .sum();
+                damping * (incoming + dangling * teleport[v]) + (1.0 - damping) * teleport[v]
+            })
+            .collect();
+
+        let change: f64 = next
+            .par_iter()
+            .zip(ranks.par_iter())
+            .map(|(new, old)| (new - old).abs())
+            .sum();
+        ranks = next;
+        if change < config.tolerance * n as f64 {
+            break;
+        }
+    }
+
+    CentralityScores {
+        scores: ranks,
+        iterations,
+    }
+}
+
+/// Betweenness centrality (Brandes), weighted when the projection has weights
+///
+/// Normalised scores are divided by the number of vertex pairs not involving the vertex.
+pub fn betweenness_centrality(
+    graph: &GraphProjection,
+    normalized: bool,
+) -> Result<CentralityScores, QueryError> {
+    graph.check_non_negative_weights()?;
+    let n = graph.vertex_count();
+
+    let mut scores = (0..n)
+        .into_par_iter()
+        .fold(
+            || vec![0.0; n],
+            |mut scores, source| {
+                graph.shortest_paths(source).accumulate(&mut scores);
+                scores
+            },
+        )
+        .reduce(
+            || vec![0.0; n],
+            |mut left, right| {
+                for (l, r) in left.iter_mut().zip(right) {
+                    *l += r;
+                }
+                left
+            },
+        );
+
+    // Undirected projections see every path from both ends.
+    let mut scale = if graph.is_undirected() { 0.5 } else { 1.0 };
+    if normalized && n > 2 {
+        let pairs = ((n - 1) * (n - 2)) as f64;
+        scale = if graph.is_undirected() {
+            2.0 / pairs * scale
+        } else {
+            1.0 / pairs
+        };
+    }
+    scores.iter_mut().for_each(|score| *score *= scale);
+
+    Ok(CentralityScores {
+        scores,
+        iterations: 0,
+    })
+}
+
+/// Closeness centrality over outgoing shortest-path distances
+///
+/// Uses the Wasserman-Faust form, which scales by the fraction of reachable vertices so
+/// that scores stay comparable on disconnected graphs. Use an `In` projection to measure
+/// distances towards each vertex instead.
+pub fn closeness_centrality(graph: &GraphProjection) -> Result<CentralityScores, QueryError> {
+    graph.check_non_negative_weights()?;
+    let n = graph.vertex_count();
+
+    let scores = (0..n)
+        .into_par_iter()
+        .map(|v| {
+            let dag = graph.shortest_paths(v);
+            let total: f64 = dag.order.iter().map(|&w| dag.dist[w]).sum();
+            let reachable = (dag.order.len() - 1) as f64;
+            if total > 0.0 && n > 1 {
+                (reachable / total) * (reachable / (n - 1) as f64)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    Ok(CentralityScores {
+        scores,
+        iterations: 0,
+    })
+}
+
+/// Degree centrality: the (weighted) number of arcs leaving each vertex
+///
+/// Undirected projections count every incident edge. Normalised scores are divided by
+/// `n - 1`.
+pub fn degree_centrality(graph: &GraphProjection, normalized: bool) -> CentralityScores {
+    let n = graph.vertex_count();
+    let scale = if normalized && n > 1 {
+        1.0 / (n - 1) as f64
+    } else {
+        1.0
+    };
+
+    let scores = (0..n)
+        .into_par_iter()
+        .map(|v| graph.out_arcs(v).map(|(_, weight)| weight).sum::<f64>() * scale)
+        .collect();
+
+    CentralityScores {
+        scores,
+        iterations: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vertices(n: i64) -> Vec<VertexId> {
+        (0..n).map(VertexId::from).collect()
+    }
+
+    /// Undirected projection with both arcs of every edge
+    fn undirected(n: i64, edges: &[(usize, usize)]) -> GraphProjection {
+        let arcs: Vec<(usize, usize, f64)> = edges
+            .iter()
+            .flat_map(|&(a, b)| [(a, b, 1.0), (b, a, 1.0)])
+            .collect();
+        GraphProjection::from_arcs(vertices(n), &arcs, true)
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_page_rank_cycle_is_uniform() {
+        let graph = GraphProjection::from_arcs(
+            vertices(3),
+            &[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0)],
+            false,
+        );
+        let result = page_rank(&graph, &PageRankConfig::default(), &[]);
+        assert_close(&result.scores, &[1.0 / 3.0; 3]);
+        assert_eq!(result.iterations, 1);
+    }
+
+    #[test]
+    fn test_page_rank_handles_dangling_vertices() {
+        // Leaves point at the hub, which has no outgoing arcs.
+        let graph = GraphProjection::from_arcs(
+            vertices(4),
+            &[(1, 0, 1.0), (2, 0, 1.0), (3, 0, 1.0)],
+            false,
+        );
+        let config = PageRankConfig {
+            max_iterations: 100,
+            ..PageRankConfig::default()
+        };
+        let result = page_rank(&graph, &config, &[]);
+        let total: f64 = result.scores.iter().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+        assert!(result.scores[0] > result.scores[1]);
+        assert_close(&result.scores[1..], &[result.scores[1]; 3]);
+    }
+
+    #[test]
+    fn test_personalized_page_rank_favours_sources() {
+        let graph = undirected(4, &[(0, 1), (1, 2), (2, 3)]);
+        let config = PageRankConfig {
+            max_iterations: 100,
+            ..PageRankConfig::default()
+        };
+        let result = page_rank(&graph, &config, &[3]);
+        assert!(result.scores[3] > result.scores[2]);
+        assert!(result.scores[2] > result.scores[1]);
+        assert!(result.scores[1] > result.scores[0]);
+    }
+
+    #[test]
+    fn test_betweenness_on_path() {
+        let graph = undirected(3, &[(0, 1), (1, 2)]);
+        let raw = betweenness_centrality(&graph, false).expect("betweenness should succeed");
+        assert_close(&raw.scores, &[0.0, 1.0, 0.0]);
+        let normalized = betweenness_centrality(&graph, true).expect("betweenness should succeed");
+        assert_close(&normalized.scores, &[0.0, 1.0, 0.0]);
+
+        let directed = GraphProjection::from_arcs(vertices(3), &[(0, 1, 1.0), (1, 2, 1.0)], false);
+        let result = betweenness_centrality(&directed, true).expect("betweenness should succeed");
+        assert_close(&result.scores, &[0.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_weighted_betweenness_follows_cheapest_path() {
+        let graph = GraphProjection::from_arcs(
+            vertices(3),
+            &[(0, 2, 5.0), (0, 1, 1.0), (1, 2, 1.0)],
+            false,
+        );
+        let result = betweenness_centrality(&graph, false).expect("betweenness should succeed");
+        assert_close(&result.scores, &[0.0, 1.0, 0.0]);
+
+        let negative = GraphProjection::from_arcs(vertices(2), &[(0, 1, -1.0)], false);
+        assert!(betweenness_centrality(&negative, false).is_err());
+    }
+
+    #[test]
+    fn test_closeness_on_path() {
+        let graph = undirected(3, &[(0, 1), (1, 2)]);
+        let result = closeness_centrality(&graph).expect("closeness should succeed");
+        assert_close(&result.scores, &[2.0 / 3.0, 1.0, 2.0 / 3.0]);
+
+        // An isolated vertex reaches nothing.
+        let graph = undirected(3, &[(0, 1)]);
+        let result = closeness_centrality(&graph).expect("closeness should succeed");
+        assert_close(&result.scores, &[0.5, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_degree_centrality() {
+        let graph = undirected(4, &[(0, 1), (0, 2), (0, 3)]);
+        let result = degree_centrality(&graph, true);
+        assert_close(&result.scores, &[1.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0]);
+
+        let weighted = GraphProjection::from_arcs(vertices(2), &[(0, 1, 2.5)], false);
+        assert_close(&degree_centrality(&weighted, false).scores, &[2.5, 0.0]);
+    }
+}
@@ -0,0 +1,630 @@
+//! Community Detection Algorithms
+//!
+//! Weakly and strongly connected components, label propagation, Louvain modularity
+//! clustering, triangle counting and the local clustering coefficient.
+//!
+//! Like the centrality algorithms they run over a `GraphProjection`. Apart from strongly
+//! connected components, arc direction is ignored: a directed projection is treated as
+//! the undirected graph with the same edges.
+
+use std::collections::{BTreeMap, HashMap};
+
+use rayon::prelude::*;
+
+use super::centrality::GraphProjection;
+
+/// Community detection algorithm type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommunityAlgorithm {
+    WeaklyConnectedComponents,
+    StronglyConnectedComponents,
+    LabelPropagation,
+    Louvain,
+    TriangleCount,
+    LocalClusteringCoefficient,
+}
+
+impl CommunityAlgorithm {
+    pub const ALL: [CommunityAlgorithm; 6] = [
+        CommunityAlgorithm::WeaklyConnectedComponents,
+        CommunityAlgorithm::StronglyConnectedComponents,
+        CommunityAlgorithm::LabelPropagation,
+        CommunityAlgorithm::Louvain,
+        CommunityAlgorithm::TriangleCount,
+        CommunityAlgorithm::LocalClusteringCoefficient,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommunityAlgorithm::WeaklyConnectedComponents => "wcc",
+            CommunityAlgorithm::StronglyConnectedComponents => "scc",
+            CommunityAlgorithm::LabelPropagation => "labelPropagation",
+            CommunityAlgorithm::Louvain => "louvain",
+            CommunityAlgorithm::TriangleCount => "triangleCount",
+            CommunityAlgorithm::LocalClusteringCoefficient => "localClusteringCoefficient",
+        }
+    }
+
+    /// Whether the algorithm assigns every vertex to a community
+    pub fn is_partition(&self) -> bool {
+        !matches!(
+            self,
+            CommunityAlgorithm::TriangleCount | CommunityAlgorithm::LocalClusteringCoefficient
+        )
+    }
+}
+
+/// Partition of the projection into communities, indexed like `GraphProjection::vertices()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Communities {
+    /// Community of every vertex, numbered from 0 in order of first appearance
+    pub labels: Vec<usize>,
+    pub count: usize,
+    /// Sweeps over the vertices run by iterative algorithms, 0 otherwise
+    pub iterations: usize,
+    /// Whether the algorithm stopped before reaching its iteration limit
+    pub converged: bool,
+    /// Modularity of the partition, for label propagation and Louvain
+    pub modularity: Option<f64>,
+}
+
+/// Triangles through every vertex, indexed like `GraphProjection::vertices()`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleCounts {
+    pub triangles: Vec<u64>,
+    /// Local clustering coefficient: closed triangles over neighbour pairs
+    pub coefficients: Vec<f64>,
+    /// Distinct triangles in the projection
+    pub total: u64,
+}
+
+/// Label propagation parameters
+#[derive(Debug, Clone)]
+pub struct LabelPropagationConfig {
+    pub max_iterations: usize,
+}
+
+impl Default for LabelPropagationConfig {
+    fn default() -> Self {
+        Self { max_iterations: 10 }
+    }
+}
+
+/// Louvain parameters
+#[derive(Debug, Clone)]
+pub struct LouvainConfig {
+    /// Maximum number of aggregation levels
+    pub max_levels: usize,
+    /// Maximum number of local moving sweeps per level
+    pub max_iterations: usize,
+    /// A level must improve modularity by at least this much to be kept going
+    pub tolerance: f64,
+}
+
+impl Default for LouvainConfig {
+    fn default() -> Self {
+        Self {
+            max_levels: 10,
+            max_iterations: 10,
+            tolerance: 1e-4,
+        }
+    }
+}
+
+/// Arcs of `v` ignoring their direction
+fn undirected_arcs(
+    graph: &GraphProjection,
+    v: usize,
+) -> Box<dyn Iterator<Item = (usize, f64)> + '_> {
+    if graph.is_undirected() {
+        Box::new(graph.out_arcs(v))
+    } else {
+        Box::new(graph.out_arcs(v).chain(graph.in_arcs(v)))
+    }
+}
+
+/// Renumber labels from 0 in order of first appearance
+fn compact_labels(labels: &[usize]) -> (Vec<usize>, usize) {
+    let mut ids = HashMap::new();
+    let compacted = labels
+        .iter()
+        .map(|label| {
+            let next = ids.len();
+            *ids.entry(*label).or_insert(next)
+        })
+        .collect();
+    (compacted, ids.len())
+}
+
+/// Weakly connected components, using union-find over all arcs
+pub fn weakly_connected_components(graph: &GraphProjection) -> Communities {
+    fn find(parent: &mut [usize], mut x: usize) -> usize {
+        while parent[x] != x {
+            parent[x] = parent[parent[x]];
+            x = parent[x];
+        }
+        x
+    }
+
+    let n = graph.vertex_count();
+    let mut parent: Vec<usize> = (0..n).collect();
+    for v in 0..n {
+        for (w, _) in graph.out_arcs(v) {
+            let (a, b) = (find(&mut parent, v), find(&mut parent, w));
+            if a != b {
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    let roots: Vec<usize> = (0..n).map(|v| find(&mut parent, v)).collect();
+    let (labels, count) = compact_labels(&roots);
+    Communities {
+        labels,
+        count,
+        iterations: 0,
+        converged: true,
+        modularity: None,
+    }
+}
+
+/// Strongly connected components (iterative Tarjan)
+pub fn strongly_connected_components(graph: &GraphProjection) -> Communities {
+    const UNVISITED: usize = usize::MAX;
+
+    let n = graph.vertex_count();
+    let mut index = vec![UNVISITED; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = vec![0; n];
+    let mut next_index = 0;
+    let mut count = 0;
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        index[root] = next_index;
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        let mut call_stack = vec![(root, graph.out_arcs(root))];
+
+        while let Some((v, arcs)) = call_stack.last_mut() {
+            let v = *v;
+            if let Some((w, _)) = arcs.next() {
+                if index[w] == UNVISITED {
+                    index[w] = next_index;
+                    low[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, graph.out_arcs(w)));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(index[w]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some((parent, _)) = call_stack.last() {
+                low[*parent] = low[*parent].min(low[v]);
+            }
+            if low[v] == index[v] {
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    components[w] = count;
+                    if w == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    let (labels, count) = compact_labels(&components);
+    Communities {
+        labels,
+        count,
+        iterations: 0,
+        converged: true,
+        modularity: None,
+    }
+}
+
+/// Label propagation with asynchronous updates in vertex order
+///
+/// Every vertex adopts the label with the largest total arc weight among its neighbours.
+/// It keeps its label on a tie, otherwise ties go to the smallest label, so results are
+/// deterministic.
+pub fn label_propagation(graph: &GraphProjection, config: &LabelPropagationConfig) -> Communities {
+    let n = graph.vertex_count();
+    let mut labels: Vec<usize> = (0..n).collect();
+    let mut weights: HashMap<usize, f64> = HashMap::new();
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < config.max_iterations {
+        iterations += 1;
+        let mut changed = false;
+        for v in 0..n {
+            weights.clear();
+            for (u, weight) in undirected_arcs(graph, v) {
+                if u != v {
+                    *weights.entry(labels[u]).or_insert(0.0) += weight;
+                }
+            }
+            let Some(max) = weights.values().copied().reduce(f64::max) else {
+                continue;
+            };
+            if weights.get(&labels[v]) == Some(&max) {
+                continue;
+            }
+            if let Some(best) = weights
+                .iter()
+                .filter(|(_, weight)| **weight == max)
+                .map(|(label, _)| *label)
+                .min()
+            {
+                labels[v] = best;
+                changed = true;
+            }
+        }
+        if !changed {
+            converged = true;
+            break;
+        }
+    }
+
+    let score = modularity(graph, &labels);
+    let (labels, count) = compact_labels(&labels);
+    Communities {
+        labels,
+        count,
+        iterations,
+        converged,
+        modularity: Some(score),
+    }
+}
+
+/// Symmetric weighted adjacency used by Louvain
+///
+/// A self-loop entry holds the loop's whole contribution to the vertex degree, so the
+/// degree of a vertex is always the sum of its entries.
+#[derive(Debug, Clone)]
+struct WeightedGraph {
+    adjacency: Vec<Vec<(usize, f64)>>,
+}
+
+impl WeightedGraph {
+    fn from_projection(graph: &GraphProjection) -> Self {
+        let n = graph.vertex_count();
+        let mut adjacency = vec![Vec::new(); n];
+        for u in 0..n {
+            for (v, weight) in graph.out_arcs(u) {
+                // Undirected projections store every edge twice.
+                if graph.is_undirected() && v < u {
+                    continue;
+                }
+                if u == v {
+                    adjacency[u].push((u, 2.0 * weight));
+                } else {
+                    adjacency[u].push((v, weight));
+                    adjacency[v].push((u, weight));
+                }
+            }
+        }
+        Self { adjacency }
+    }
+
+    fn degrees(&self) -> Vec<f64> {
+        self.adjacency
+            .iter()
+            .map(|arcs| arcs.iter().map(|(_, weight)| weight).sum())
+            .collect()
+    }
+
+    /// Twice the total edge weight
+    fn total_weight(&self) -> f64 {
+        self.degrees().iter().sum()
+    }
+
+    fn modularity(&self, communities: &[usize], total_weight: f64) -> f64 {
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        let mut internal: HashMap<usize, f64> = HashMap::new();
+        let mut totals: HashMap<usize, f64> = HashMap::new();
+        for (u, arcs) in self.adjacency.iter().enumerate() {
+            for &(v, weight) in arcs {
+                *totals.entry(communities[u]).or_insert(0.0) += weight;
+                if communities[u] == communities[v] {
+                    *internal.entry(communities[u]).or_insert(0.0) += weight;
+                }
+            }
+        }
+        totals
+            .iter()
+            .map(|(community, total)| {
+                internal.get(community).copied().unwrap_or(0.0) / total_weight
+                    - (total / total_weight).powi(2)
+            })
+            .sum()
+    }
+
+    /// Move vertices to the neighbouring community with the best modularity gain until
+    /// no vertex moves. Returns the communities, the sweeps run and whether anything moved.
+    fn local_moving(&self, total_weight: f64, max_iterations: usize) -> (Vec<usize>, usize, bool) {
+        let n = self.adjacency.len();
+        let degrees = self.degrees();
+        let mut communities: Vec<usize> = (0..n).collect();
+        let mut totals = degrees.clone();
+        // Ordered so that ties between communities are broken the same way every run.
+        let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+        let mut sweeps = 0;
+        let mut moved_any = false;
+
+        while sweeps < max_iterations {
+            sweeps += 1;
+            let mut moved = false;
+            for u in 0..n {
+                links.clear();
+                for &(v, weight) in &self.adjacency[u] {
+                    if v != u {
+                        *links.entry(communities[v]).or_insert(0.0) += weight;
+                    }
+                }
+
+                let own = communities[u];
+                totals[own] -= degrees[u];
+                let gain = |community: usize, weight: f64| {
+                    weight - totals[community] * degrees[u] / total_weight
+                };
+                let mut best = own;
+                let mut best_gain = gain(own, links.get(&own).copied().unwrap_or(0.0));
+                for (&community, &weight) in &links {
+                    let candidate = gain(community, weight);
+                    if candidate > best_gain + f64::EPSILON {
+                        best = community;
+                        best_gain = candidate;
+                    }
+                }
+                totals[best] += degrees[u];
+                if best != own {
+                    communities[u] = best;
+                    moved = true;
+                    moved_any = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+        (communities, sweeps, moved_any)
+    }
+
+    /// Collapse every community into one vertex
+    fn aggregate(&self, communities: &[usize], count: usize) -> Self {
+        let mut merged: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); count];
+        for (u, arcs) in self.adjacency.iter().enumerate() {
+            for &(v, weight) in arcs {
+                *merged[communities[u]].entry(communities[v]).or_insert(0.0) += weight;
+            }
+        }
+        Self {
+            adjacency: merged
+                .into_iter()
+                .map(|arcs| arcs.into_iter().collect())
+                .collect(),
+        }
+    }
+}
+
+/// Modularity of a partition of the projection, treating it as undirected
+pub fn modularity(graph: &GraphProjection, communities: &[usize]) -> f64 {
+    let weighted = WeightedGraph::from_projection(graph);
+    weighted.modularity(communities, weighted.total_weight())
+}
+
+/// Louvain modularity clustering
+///
+/// Alternates local moving and aggregation until a level no longer moves any vertex or
+/// improves modularity by less than `config.tolerance`.
+pub fn louvain(graph: &GraphProjection, config: &LouvainConfig) -> Communities {
+    let base = WeightedGraph::from_projection(graph);
+    let total_weight = base.total_weight();
+    let mut membership: Vec<usize> = (0..graph.vertex_count()).collect();
+    let mut best_modularity = base.modularity(&membership, total_weight);
+    let mut level_graph = base.clone();
+    let mut iterations = 0;
+    let mut converged = total_weight <= 0.0;
+
+    if !converged {
+        for _ in 0..config.max_levels {
+            let (communities, sweeps, moved) =
+                level_graph.local_moving(total_weight, config.max_iterations);
+            iterations += sweeps;
+            if !moved {
+                converged = true;
+                break;
+            }
+
+            let (communities, count) = compact_labels(&communities);
+            let candidate: Vec<usize> = membership.iter().map(|&c| communities[c]).collect();
+            let candidate_modularity = base.modularity(&candidate, total_weight);
+            if candidate_modularity - best_modularity < config.tolerance {
+                if candidate_modularity > best_modularity {
+                    membership = candidate;
+                    best_modularity = candidate_modularity;
+                }
+                converged = true;
+                break;
+            }
+            membership = candidate;
+            best_modularity = candidate_modularity;
+            level_graph = level_graph.aggregate(&communities, count);
+        }
+    }
+
+    let (labels, count) = compact_labels(&membership);
+    Communities {
+        labels,
+        count,
+        iterations,
+        converged,
+        modularity: Some(best_modularity),
+    }
+}
+
+/// Triangles through every vertex and the local clustering coefficient
+///
+/// Parallel arcs and self-loops are ignored. Vertices with fewer than two neighbours have
+/// a coefficient of 0.
+pub fn triangle_count(graph: &GraphProjection) -> TriangleCounts {
+    let neighbors: Vec<Vec<usize>> = (0..graph.vertex_count())
+        .into_par_iter()
+        .map(|v| {
+            let mut neighbors: Vec<usize> = undirected_arcs(graph, v)
+                .map(|(u, _)| u)
+                .filter(|&u| u != v)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+
+    let triangles: Vec<u64> = neighbors
+        .par_iter()
+        .map(|own| {
+            // Every triangle through v is seen from both of its other vertices.
+            let shared: u64 = own
+                .iter()
+                .map(|&u| sorted_intersection_len(own, &neighbors[u]))
+                .sum();
+            shared / 2
+        })
+        .collect();
+
+    let coefficients = triangles
+        .par_iter()
+        .zip(neighbors.par_iter())
+        .map(|(&t, own)| {
+            let degree = own.len() as f64;
+            if own.len() < 2 {
+                0.0
+            } else {
+                2.0 * t as f64 / (degree * (degree - 1.0))
+            }
+        })
+        .collect();
+
+    TriangleCounts {
+        total: triangles.iter().sum::<u64>() / 3,
+        triangles,
+        coefficients,
+    }
+}
+
+fn sorted_intersection_len(a: &[usize], b: &[usize]) -> u64 {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::VertexId;
+
+    fn vertices(n: i64) -> Vec<VertexId> {
+        (0..n).map(VertexId::from).collect()
+    }
+
+    fn directed(n: i64, edges: &[(usize, usize)]) -> GraphProjection {
+        let arcs: Vec<(usize, usize, f64)> = edges.iter().map(|&(a, b)| (a, b, 1.0)).collect();
+        GraphProjection::from_arcs(vertices(n), &arcs, false)
+    }
+
+    /// Undirected projection with both arcs of every edge
+    fn undirected(n: i64, edges: &[(usize, usize)]) -> GraphProjection {
+        let arcs: Vec<(usize, usize, f64)> = edges
+            .iter()
+            .flat_map(|&(a, b)| [(a, b, 1.0), (b, a, 1.0)])
+            .collect();
+        GraphProjection::from_arcs(vertices(n), &arcs, true)
+    }
+
+    /// Two 4-cliques joined by the edge 3-4
+    fn two_cliques() -> GraphProjection {
+        let mut edges = Vec::new();
+        for offset in [0, 4] {
+            for a in 0..4 {
+                for b in (a + 1)..4 {
+                    edges.push((offset + a, offset + b));
+                }
+            }
+        }
+        edges.push((3, 4));
+        undirected(8, &edges)
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = directed(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]);
+
+        let weak = weakly_connected_components(&graph);
+        assert_eq!(weak.labels, vec![0, 0, 0, 0, 0, 1]);
+        assert_eq!(weak.count, 2);
+
+        let strong = strongly_connected_components(&graph);
+        assert_eq!(strong.labels, vec![0, 0, 0, 1, 1, 2]);
+        assert_eq!(strong.count, 3);
+    }
+
+    #[test]
+    fn test_label_propagation_converges() {
+        let graph = undirected(7, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        let result = label_propagation(&graph, &LabelPropagationConfig::default());
+        assert_eq!(result.labels, vec![0, 0, 0, 1, 1, 1, 2]);
+        assert_eq!(result.count, 3);
+        assert!(result.converged);
+        assert_eq!(result.iterations, 2);
+    }
+
+    #[test]
+    fn test_louvain_separates_cliques() {
+        let result = louvain(&two_cliques(), &LouvainConfig::default());
+        assert_eq!(result.labels, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+        assert_eq!(result.count, 2);
+        assert!(result.converged);
+        let expected = 24.0 / 26.0 - 0.5;
+        assert!((result.modularity.unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangle_count_and_clustering_coefficient() {
+        // A triangle with a pendant vertex; arcs in one direction only.
+        let graph = directed(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        let result = triangle_count(&graph);
+        assert_eq!(result.triangles, vec![1, 1, 1, 0]);
+        assert_eq!(result.total, 1);
+        let expected = [1.0, 1.0, 1.0 / 3.0, 0.0];
+        for (actual, expected) in result.coefficients.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+}
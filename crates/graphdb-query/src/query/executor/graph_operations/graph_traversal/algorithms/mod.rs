@@ -6,6 +6,8 @@
 //! *a_star*: An A* heuristic search algorithm
 //! `bidirectional_bfs`: Algorithm for finding the shortest path using Bidirectional Breadth-First Search (BFS)
 //! `bfs_shortest`: The executor for finding the shortest path using Breadth-First Search (BFS) algorithm.
+//! `centrality`: PageRank, personalised PageRank, betweenness, closeness and degree centrality
+//! `community`: Connected components, label propagation, Louvain, triangle count and clustering coefficient
 //! Dijkstra: The Dijkstra algorithm for finding the shortest path
 //! `multi_shortest_path`: An algorithm for finding the shortest paths from multiple sources to a single destination.
 //! `subgraphExecutor`: The executor responsible for executing subgraph queries.
@@ -13,6 +15,8 @@
 pub mod a_star;
 pub mod bfs_shortest;
 pub mod bidirectional_bfs;
+pub mod centrality;
+pub mod community;
 pub mod dijkstra;
 pub mod multi_shortest_path;
 pub mod subgraph_executor;
@@ -23,6 +27,15 @@ pub mod types;
 pub use a_star::AStar;
 pub use bfs_shortest::BFSShortestExecutor;
 pub use bidirectional_bfs::BidirectionalBFS;
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, degree_centrality, page_rank,
+    CentralityAlgorithm, CentralityScores, GraphProjection, PageRankConfig, ProjectionConfig,
+};
+pub use community::{
+    label_propagation, louvain, modularity, strongly_connected_components, triangle_count,
+    weakly_connected_components, Communities, CommunityAlgorithm, LabelPropagationConfig,
+    LouvainConfig, TriangleCounts,
+};
 pub use dijkstra::Dijkstra;
 pub use multi_shortest_path::MultiShortestPathExecutor;
 pub use subgraph_executor::{SubgraphConfig, SubgraphExecutor, SubgraphResult};
@@ -327,6 +327,25 @@ impl EdgeWeightConfig {
             _ => None,
         }
     }
+
+    /// Weight of an edge; missing or non-numeric weight properties count as 1.0.
+    pub fn weight_of(&self, edge: &Edge) -> f64 {
+        match self {
+            EdgeWeightConfig::Unweighted => 1.0,
+            EdgeWeightConfig::Ranking => edge.ranking as f64,
+            EdgeWeightConfig::Property(prop_name) => edge
+                .get_property(prop_name)
+                .map(|v| match v {
+                    Value::SmallInt(i) => *i as f64,
+                    Value::Int(i) => *i as f64,
+                    Value::BigInt(i) => *i as f64,
+                    Value::Float(f) => (*f).into(),
+                    Value::Double(f) => *f,
+                    _ => 1.0,
+                })
+                .unwrap_or(1.0),
+        }
+    }
 }
 
 /// Path concatenation tool function
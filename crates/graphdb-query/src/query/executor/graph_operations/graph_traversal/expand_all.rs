@@ -1,9 +1,8 @@
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::core::error::DBResult;
 use crate::core::types::{ContextualExpression, VertexId};
-use crate::core::{Edge, Expression, NPath, Path, Value, Vertex};
+use crate::core::{Expression, NPath, Path, Value, Vertex};
 use crate::query::executor::base::ExecutorEnum;
 use crate::query::executor::base::{BaseExecutor, EdgeDirection, InputExecutor};
 use crate::query::executor::base::{ExecutionResult, Executor, HasStorage};
@@ -11,10 +10,11 @@ use crate::query::executor::expression::evaluator::expression_evaluator::Express
 use crate::query::executor::expression::{DefaultExpressionContext, ExpressionContext};
 use crate::query::validator::context::ExpressionAnalysisContext;
 use crate::query::DataSet;
-use crate::query::QueryError;
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
 
+use super::frontier::{Extension, FrontierExpander, StorageFrontierSource};
+
 /// ExpandAllExecutor – An executor that performs full-path expansion
 ///
 /// Return all possible paths starting from the current node, not just the next-hop node.
@@ -30,8 +30,6 @@ pub struct ExpandAllExecutor<S: StorageClient + Send + 'static> {
     npath_cache: Vec<Arc<NPath>>,
     // Path caching (converted during the final output process)
     path_cache: Vec<Path>,
-    // Set of visited nodes, used to avoid loops.
-    pub visited_nodes: HashSet<VertexId>,
     // Source vertex IDs for starting the expansion (from GO FROM clause)
     pub src_vids: Vec<VertexId>,
     // Whether to include empty paths (paths with no edges) in the result
@@ -62,7 +60,6 @@ impl<S: StorageClient> std::fmt::Debug for ExpandAllExecutor<S> {
             .field("max_depth", &self.max_depth)
             .field("input_executor", &"Option<Box<dyn Executor<S>>>")
             .field("path_cache", &self.path_cache)
-            .field("visited_nodes", &self.visited_nodes)
             .finish()
     }
 }
@@ -96,7 +93,6 @@ impl<S: StorageClient + Send> ExpandAllExecutor<S> {
             input_executor: None,
             npath_cache: Vec::new(),
             path_cache: Vec::new(),
-            visited_nodes: HashSet::new(),
             src_vids: Vec::new(),
             include_empty_paths: true, // Default to true for backward compatibility
             input_var: None,
@@ -127,7 +123,6 @@ impl<S: StorageClient + Send> ExpandAllExecutor<S> {
             input_executor: None,
             npath_cache: Vec::new(),
             path_cache: Vec::new(),
-            visited_nodes: HashSet::new(),
             src_vids: Vec::new(),
             include_empty_paths: true,
             input_var: None,
@@ -166,107 +161,74 @@ impl<S: StorageClient + Send> ExpandAllExecutor<S> {
         self
     }
 
-    fn get_neighbors_with_edges(
-        &self,
-        node_id: &VertexId,
-    ) -> Result<Vec<(VertexId, Edge)>, QueryError> {
-        let storage = self.base.get_storage().clone();
+    /// Expand every input vertex into all paths of up to `max_depth` edges.
+    ///
+    /// Paths grow one hop at a time over the whole frontier, see [`FrontierExpander`].
+    /// A step back onto a vertex already on the path is kept, but not expanded further.
+    fn expand_paths(
+        &mut self,
+        input_nodes: &[Vertex],
+        max_depth: usize,
+    ) -> DBResult<Vec<Arc<NPath>>> {
+        let storage = self.get_storage().clone();
         let edge_types = if self.any_edge_type {
             None
         } else {
             self.edge_types.clone()
         };
-        super::traversal_utils::get_neighbors_with_edges(
-            &storage,
-            node_id,
-            self.edge_direction,
-            &edge_types,
-            &self.space_name,
-            false,
-        )
-        .map_err(|e| QueryError::storage(e.to_string()))
-    }
-
-    /// Recursive expansion of paths (synchronous version)
-    fn expand_paths_recursive(
-        &mut self,
-        current_npath: &Arc<NPath>,
-        current_depth: usize,
-        max_depth: usize,
-    ) -> Result<Vec<Arc<NPath>>, QueryError> {
-        // Get the last node of the current path.
-        let current_node = &current_npath.vertex().vid;
-
-        // Check whether the maximum depth has been reached.
-        if current_depth >= max_depth {
-            // Return to the current path
-            return Ok(vec![current_npath.clone()]);
-        }
-
-        // Obtaining neighbor nodes and edges
-        let neighbors_with_edges = self.get_neighbors_with_edges(current_node)?;
-
-        if neighbors_with_edges.is_empty() {
-            // There are no more neighbors; return to the current path.
-            return Ok(vec![current_npath.clone()]);
-        }
-
-        let mut all_npaths: Vec<Arc<NPath>> = Vec::new();
-
-        // Create a new path for each neighbor.
-        for (neighbor_id, edge) in neighbors_with_edges {
-            // Check whether the node has already been visited (to avoid loops).
-            if self.visited_nodes.contains(&neighbor_id) {
-                // Create a path that contains loops.
-                let path_with_cycle = Arc::new(NPath::extend(
-                    current_npath.clone(),
-                    Arc::new(edge),
-                    Arc::new(Vertex::new(neighbor_id, Vec::new())),
-                ));
-                all_npaths.push(path_with_cycle);
-                continue;
+        // One read guard for all hops, so every hop sees the same snapshot
+        let storage = storage.read();
+        let mut expander = FrontierExpander::new(
+            StorageFrontierSource {
+                storage: &*storage,
+                space_name: &self.space_name,
+                edge_direction: self.edge_direction,
+                edge_types: &edge_types,
+            },
+            self.base.context.parallel_config(),
+            self.base.context.kill_flag.clone(),
+        );
+
+        let mut frontier: Vec<Arc<NPath>> = input_nodes
+            .iter()
+            .map(|vertex| Arc::new(NPath::new(Arc::new(vertex.clone()))))
+            .collect();
+        let mut npaths = frontier.clone();
+        for _ in 0..max_depth {
+            if frontier.is_empty() {
+                break;
             }
-
-            // Obtain the complete information of the neighboring nodes.
-            let neighbor_vertex = {
-                let storage = self.get_storage().read();
-                storage
-                    .get_vertex(&self.space_name, &neighbor_id)
-                    .map_err(|e| QueryError::storage(e.to_string()))?
-            };
-
-            // Create a vertex object: If the vertex already exists, use the actual vertex; otherwise, create a suspended vertex (with an empty Tag list).
-            let vertex = match neighbor_vertex {
-                Some(v) => v,
-                None => {
-                    // Suspension edge processing: Create a vertex for an empty Tag, while retaining the VID (Video Identifier).
-                    Vertex::new(neighbor_id, Vec::new())
+            let hop = expander.expand(&frontier, |path, edge, neighbor_id, vertex| {
+                // A loop back onto the path: keep the step with a bare vertex and stop there
+                if path.contains_vertex(neighbor_id) {
+                    return Extension::Closed(Arc::new(NPath::extend(
+                        path.clone(),
+                        Arc::new(edge.clone()),
+                        Arc::new(Vertex::new(*neighbor_id, Vec::new())),
+                    )));
                 }
-            };
-
-            // Using NPath expansion, O(1) operation
-            let new_npath = Arc::new(NPath::extend(
-                current_npath.clone(),
-                Arc::new(edge),
-                Arc::new(vertex),
-            ));
-
-            // Marked as visited
-            self.visited_nodes.insert(neighbor_id);
-
-            // Recursive expansion (continuing to expand in order to obtain more edges, even if the vertex is "hanging"/not directly connected to other nodes in the graph).
-            let mut expanded_npaths =
-                self.expand_paths_recursive(&new_npath, current_depth + 1, max_depth)?;
-            all_npaths.append(&mut expanded_npaths);
-
-            // Unmark (allows access from other paths)
-            self.visited_nodes.remove(&neighbor_id);
+                // Suspension edge processing: keep the VID of a missing vertex with an empty Tag list
+                let vertex = vertex
+                    .cloned()
+                    .unwrap_or_else(|| Arc::new(Vertex::new(*neighbor_id, Vec::new())));
+                let new_npath =
+                    Arc::new(NPath::extend(path.clone(), Arc::new(edge.clone()), vertex));
+                if new_npath.len() < max_depth {
+                    Extension::Open(new_npath)
+                } else {
+                    Extension::Closed(new_npath)
+                }
+            })?;
+            npaths.extend(hop.open.iter().cloned());
+            npaths.extend(hop.closed);
+            frontier = hop.open;
         }
 
-        // Add the current path
-        all_npaths.push(current_npath.clone());
-
-        Ok(all_npaths)
+        let stats = expander.stats().clone();
+        drop(expander);
+        drop(storage);
+        stats.record(self.base.get_stats_mut());
+        Ok(npaths)
     }
 
     /// Construct the extended result.
@@ -518,7 +480,6 @@ impl<S: StorageClient + Send + 'static> Executor<S> for ExpandAllExecutor<S> {
         // This prevents duplicate results when the executor is reused
         self.npath_cache.clear();
         self.path_cache.clear();
-        self.visited_nodes.clear();
         self.input_dataset = None;
         self.input_vertex_to_row.clear();
 
@@ -700,15 +661,7 @@ impl<S: StorageClient + Send + 'static> Executor<S> for ExpandAllExecutor<S> {
         // Determine the maximum depth.
         let max_depth = self.max_depth.unwrap_or(3); // The default depth is 3.
 
-        for vertex in &input_nodes {
-            self.visited_nodes.clear();
-            self.visited_nodes.insert(vertex.vid);
-
-            let initial_npath = Arc::new(NPath::new(Arc::new(vertex.clone())));
-
-            let mut expanded_npaths = self.expand_paths_recursive(&initial_npath, 0, max_depth)?;
-            self.npath_cache.append(&mut expanded_npaths);
-        }
+        self.npath_cache = self.expand_paths(&input_nodes, max_depth)?;
 
         Ok(self.build_expansion_result())
     }
@@ -716,7 +669,6 @@ impl<S: StorageClient + Send + 'static> Executor<S> for ExpandAllExecutor<S> {
     fn open(&mut self) -> DBResult<()> {
         self.npath_cache.clear();
         self.path_cache.clear();
-        self.visited_nodes.clear();
 
         if let Some(ref mut input_exec) = self.input_executor {
             input_exec.open()?;
@@ -727,7 +679,6 @@ impl<S: StorageClient + Send + 'static> Executor<S> for ExpandAllExecutor<S> {
     fn close(&mut self) -> DBResult<()> {
         self.npath_cache.clear();
         self.path_cache.clear();
-        self.visited_nodes.clear();
 
         if let Some(ref mut input_exec) = self.input_executor {
             input_exec.close()?;
@@ -0,0 +1,502 @@
+//! Frontier-parallel multi-hop expansion
+//!
+//! ExpandAll (GO N STEPS, variable-length MATCH) and Traverse grow their paths one
+//! hop at a time through [`FrontierExpander`]. A hop makes three passes: read the
+//! adjacency list of every distinct vertex at the end of a frontier path, load every
+//! vertex reached for the first time, then extend the paths. A pass over enough items
+//! is cut into small chunks that a rayon pool with one thread per unit of parallelism
+//! works through by work stealing, so a few high-degree vertices do not leave the
+//! other workers idle. Each worker collects the vertices it reaches in its own set;
+//! the sets are merged once per hop, so every vertex is loaded from storage once.
+//!
+//! Smaller passes run on the calling thread, and paths come out in the same order
+//! either way. The caller keeps one storage read guard for the whole traversal, so
+//! every hop sees the same snapshot at the statement's read timestamp. The query's
+//! kill flag is polled for every vertex and path.
+
+use parking_lot::Mutex;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use crate::core::error::{DBError, DBResult};
+use crate::core::types::VertexId;
+use crate::core::{Edge, NPath, Vertex};
+use crate::query::executor::base::{EdgeDirection, ExecutorStats};
+use crate::query::executor::utils::recursion_detector::ParallelConfig;
+use crate::storage::StorageReader;
+
+/// Chunks handed out per worker in a parallel pass; more chunks than workers lets
+/// idle workers steal the rest of a skewed pass
+const CHUNKS_PER_WORKER: usize = 4;
+
+/// Where the expansion reads adjacency lists and vertices from
+pub trait FrontierSource: Sync {
+    /// Neighbors of `vertex`, each with the edge that leads to it
+    fn neighbors(&self, vertex: &VertexId) -> DBResult<Vec<(VertexId, Edge)>>;
+
+    /// The vertex with its tags, or `None` when an edge points at a missing vertex
+    fn vertex(&self, vertex: &VertexId) -> DBResult<Option<Vertex>>;
+}
+
+/// Frontier source backed by a locked storage engine
+pub struct StorageFrontierSource<'a, S: StorageReader> {
+    pub storage: &'a S,
+    pub space_name: &'a str,
+    pub edge_direction: EdgeDirection,
+    pub edge_types: &'a Option<Vec<String>>,
+}
+
+impl<S: StorageReader> FrontierSource for StorageFrontierSource<'_, S> {
+    fn neighbors(&self, vertex: &VertexId) -> DBResult<Vec<(VertexId, Edge)>> {
+        super::traversal_utils::neighbors_with_edges_in(
+            self.storage,
+            vertex,
+            self.edge_direction,
+            self.edge_types,
+            self.space_name,
+            false,
+        )
+    }
+
+    fn vertex(&self, vertex: &VertexId) -> DBResult<Option<Vertex>> {
+        self.storage
+            .get_vertex(self.space_name, vertex)
+            .map_err(|e| DBError::storage(e.to_string()))
+    }
+}
+
+/// What a hop makes of one edge leaving the end of a path
+#[derive(Debug)]
+pub enum Extension {
+    /// Keep the extended path and expand it in the next hop
+    Open(Arc<NPath>),
+    /// Keep the extended path without expanding it any further
+    Closed(Arc<NPath>),
+    /// Drop the edge
+    Skip,
+}
+
+/// Paths produced by one hop, in frontier order
+#[derive(Debug, Default)]
+pub struct Hop {
+    pub open: Vec<Arc<NPath>>,
+    pub closed: Vec<Arc<NPath>>,
+}
+
+impl Hop {
+    fn append(&mut self, mut other: Hop) {
+        self.open.append(&mut other.open);
+        self.closed.append(&mut other.closed);
+    }
+}
+
+/// Figures of a traversal, shown by PROFILE
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrontierStats {
+    /// Worker threads available to a parallel pass
+    pub parallelism: usize,
+    pub hops: usize,
+    /// Hops with at least one pass run in parallel
+    pub parallel_hops: usize,
+    /// Largest number of paths expanded in one hop
+    pub peak_frontier: usize,
+    pub vertices_loaded: usize,
+}
+
+impl FrontierStats {
+    /// Record the figures as `ExecutorStats::other_stats` entries.
+    pub fn record(&self, stats: &mut ExecutorStats) {
+        let entries = [
+            ("parallelism", self.parallelism),
+            ("hops", self.hops),
+            ("parallel_hops", self.parallel_hops),
+            ("peak_frontier", self.peak_frontier),
+            ("vertices_loaded", self.vertices_loaded),
+        ];
+        for (key, value) in entries {
+            stats.add_stat(key.to_string(), value.to_string());
+        }
+    }
+}
+
+/// Adjacency lists read in one hop, with the vertices they reach for the first time
+#[derive(Default)]
+struct Reached {
+    adjacency: HashMap<VertexId, Vec<(VertexId, Edge)>>,
+    new_vertices: HashSet<VertexId>,
+}
+
+impl Reached {
+    fn merge(mut self, other: Reached) -> Self {
+        self.adjacency.extend(other.adjacency);
+        self.new_vertices.extend(other.new_vertices);
+        self
+    }
+}
+
+/// Level-synchronous path expansion over a [`FrontierSource`]
+pub struct FrontierExpander<F: FrontierSource> {
+    source: F,
+    config: ParallelConfig,
+    kill_flag: Option<Arc<AtomicBool>>,
+    /// Every vertex seen so far; `None` marks a missing vertex
+    vertices: HashMap<VertexId, Option<Arc<Vertex>>>,
+    pool: Option<Arc<ThreadPool>>,
+    stats: FrontierStats,
+}
+
+impl<F: FrontierSource> FrontierExpander<F> {
+    pub fn new(source: F, config: ParallelConfig, kill_flag: Option<Arc<AtomicBool>>) -> Self {
+        let parallelism = if config.enable_parallel {
+            config.max_job_size.max(1)
+        } else {
+            1
+        };
+        Self {
+            source,
+            config,
+            kill_flag,
+            vertices: HashMap::new(),
+            pool: None,
+            stats: FrontierStats {
+                parallelism,
+                ..FrontierStats::default()
+            },
+        }
+    }
+
+    pub fn stats(&self) -> &FrontierStats {
+        &self.stats
+    }
+
+    /// Expand every path of `frontier` by one hop.
+    ///
+    /// `extend` decides, for a path, an edge leaving its end, the neighbor the edge
+    /// leads to and that neighbor's vertex (if it exists), whether the hop keeps the
+    /// extended path. It may be called from several threads at once.
+    pub fn expand<E>(&mut self, frontier: &[Arc<NPath>], extend: E) -> DBResult<Hop>
+    where
+        E: Fn(&Arc<NPath>, &Edge, &VertexId, Option<&Arc<Vertex>>) -> Extension + Sync,
+    {
+        self.check_killed()?;
+        self.stats.hops += 1;
+        self.stats.peak_frontier = self.stats.peak_frontier.max(frontier.len());
+
+        let mut ends = Vec::new();
+        let mut seen = HashSet::new();
+        for path in frontier {
+            let end = path.vertex();
+            self.vertices
+                .entry(end.vid)
+                .or_insert_with(|| Some(end.clone()));
+            if seen.insert(end.vid) {
+                ends.push(end.vid);
+            }
+        }
+
+        let mut parallel_hop = false;
+
+        // Pass 1: adjacency lists, with a reached-vertex set per worker
+        let parallel = self.use_parallel(ends.len());
+        parallel_hop |= parallel;
+        let pool = self.pool_for(parallel)?;
+        let reached = {
+            let this = &*self;
+            let read_chunk = |mut reached: Reached, chunk: &[VertexId]| -> DBResult<Reached> {
+                for vid in chunk {
+                    this.check_killed()?;
+                    let neighbors = this.source.neighbors(vid)?;
+                    for (neighbor, _) in &neighbors {
+                        if !this.vertices.contains_key(neighbor) {
+                            reached.new_vertices.insert(*neighbor);
+                        }
+                    }
+                    reached.adjacency.insert(*vid, neighbors);
+                }
+                Ok(reached)
+            };
+            match &pool {
+                Some(pool) => pool.install(|| {
+                    ends.par_chunks(this.chunk_size(ends.len()))
+                        .try_fold(Reached::default, read_chunk)
+                        .try_reduce(Reached::default, |a, b| Ok(a.merge(b)))
+                })?,
+                None => read_chunk(Reached::default(), &ends)?,
+            }
+        };
+
+        // Pass 2: load each newly reached vertex once
+        let to_load: Vec<VertexId> = reached.new_vertices.into_iter().collect();
+        let parallel = self.use_parallel(to_load.len());
+        parallel_hop |= parallel;
+        let pool = self.pool_for(parallel)?;
+        let loaded = {
+            let this = &*self;
+            let load_chunk = |chunk: &[VertexId]| -> DBResult<Vec<(VertexId, Option<Vertex>)>> {
+                chunk
+                    .iter()
+                    .map(|vid| {
+                        this.check_killed()?;
+                        Ok((*vid, this.source.vertex(vid)?))
+                    })
+                    .collect()
+            };
+            match &pool {
+                Some(pool) => pool.install(|| {
+                    to_load
+                        .par_chunks(this.chunk_size(to_load.len()))
+                        .map(load_chunk)
+                        .collect::<DBResult<Vec<_>>>()
+                })?,
+                None => vec![load_chunk(&to_load)?],
+            }
+        };
+        for (vid, vertex) in loaded.into_iter().flatten() {
+            self.vertices.insert(vid, vertex.map(Arc::new));
+            self.stats.vertices_loaded += 1;
+        }
+
+        // Pass 3: extend the paths; chunks are concatenated in frontier order
+        let adjacency = reached.adjacency;
+        let edges: usize = frontier
+            .iter()
+            .map(|path| adjacency.get(&path.vertex().vid).map_or(0, Vec::len))
+            .sum();
+        let parallel = self.use_parallel(edges);
+        parallel_hop |= parallel;
+        let pool = self.pool_for(parallel)?;
+        let hop = {
+            let this = &*self;
+            let extend_chunk = |chunk: &[Arc<NPath>]| -> DBResult<Hop> {
+                let mut hop = Hop::default();
+                for path in chunk {
+                    this.check_killed()?;
+                    let Some(neighbors) = adjacency.get(&path.vertex().vid) else {
+                        continue;
+                    };
+                    for (neighbor, edge) in neighbors {
+                        let vertex = this.vertices.get(neighbor).and_then(Option::as_ref);
+                        match extend(path, edge, neighbor, vertex) {
+                            Extension::Open(extended) => hop.open.push(extended),
+                            Extension::Closed(extended) => hop.closed.push(extended),
+                            Extension::Skip => {}
+                        }
+                    }
+                }
+                Ok(hop)
+            };
+            match &pool {
+                Some(pool) => {
+                    let chunks = pool.install(|| {
+                        frontier
+                            .par_chunks(this.chunk_size(frontier.len()))
+                            .map(extend_chunk)
+                            .collect::<DBResult<Vec<_>>>()
+                    })?;
+                    let mut hop = Hop::default();
+                    for chunk in chunks {
+                        hop.append(chunk);
+                    }
+                    hop
+                }
+                None => extend_chunk(frontier)?,
+            }
+        };
+
+        if parallel_hop {
+            self.stats.parallel_hops += 1;
+        }
+        Ok(hop)
+    }
+
+    fn check_killed(&self) -> DBResult<()> {
+        match &self.kill_flag {
+            Some(flag) if flag.load(Ordering::SeqCst) => {
+                Err(DBError::query("Query was killed during traversal"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn use_parallel(&self, items: usize) -> bool {
+        self.stats.parallelism > 1 && self.config.should_use_parallel(items)
+    }
+
+    fn chunk_size(&self, items: usize) -> usize {
+        items
+            .div_ceil(self.stats.parallelism * CHUNKS_PER_WORKER)
+            .max(1)
+    }
+
+    /// The thread pool for a pass, if the pass runs in parallel
+    fn pool_for(&mut self, parallel: bool) -> DBResult<Option<Arc<ThreadPool>>> {
+        if !parallel {
+            return Ok(None);
+        }
+        if self.pool.is_none() {
+            self.pool = Some(thread_pool(self.stats.parallelism)?);
+        }
+        Ok(self.pool.clone())
+    }
+}
+
+/// Thread pools shared by all traversals, one per degree of parallelism
+fn thread_pool(parallelism: usize) -> DBResult<Arc<ThreadPool>> {
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+    let mut pools = POOLS.get_or_init(|| Mutex::new(HashMap::new())).lock();
+    if let Some(pool) = pools.get(&parallelism) {
+        return Ok(pool.clone());
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallelism)
+        .thread_name(move |i| format!("traverse-{}-{}", parallelism, i))
+        .build()
+        .map_err(|e| DBError::query(format!("Failed to start traversal workers: {}", e)))?;
+    let pool = Arc::new(pool);
+    pools.insert(parallelism, pool.clone());
+    Ok(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory graph standing in for the storage engine
+    struct Graph {
+        edges: Vec<Edge>,
+        missing: Vec<VertexId>,
+    }
+
+    impl FrontierSource for Graph {
+        fn neighbors(&self, vertex: &VertexId) -> DBResult<Vec<(VertexId, Edge)>> {
+            Ok(self
+                .edges
+                .iter()
+                .filter(|edge| edge.src == *vertex)
+                .map(|edge| (edge.dst, edge.clone()))
+                .collect())
+        }
+
+        fn vertex(&self, vertex: &VertexId) -> DBResult<Option<Vertex>> {
+            Ok((!self.missing.contains(vertex)).then(|| Vertex::new(*vertex, Vec::new())))
+        }
+    }
+
+    fn vid(id: i64) -> VertexId {
+        VertexId::from(id)
+    }
+
+    fn start(id: i64) -> Arc<NPath> {
+        Arc::new(NPath::new(Arc::new(Vertex::new(vid(id), Vec::new()))))
+    }
+
+    /// Vertex 0 fans out to 1..=fan_out, each of which points back to 0 and on to 1000
+    fn star(fan_out: i64) -> Graph {
+        let mut edges = Vec::new();
+        for i in 1..=fan_out {
+            edges.push(Edge::new_empty(vid(0), vid(i), "knows".to_string(), 0));
+            edges.push(Edge::new_empty(vid(i), vid(0), "knows".to_string(), 0));
+            edges.push(Edge::new_empty(vid(i), vid(1000), "knows".to_string(), 0));
+        }
+        Graph {
+            edges,
+            missing: vec![vid(1000)],
+        }
+    }
+
+    fn parallel_config(parallelism: usize) -> ParallelConfig {
+        ParallelConfig {
+            parallel_threshold: 1,
+            ..ParallelConfig::with_parallelism(parallelism)
+        }
+    }
+
+    /// ExpandAll-style expansion: cycles close a path, the rest stays open
+    fn expand_all(
+        graph: Graph,
+        config: ParallelConfig,
+        max_depth: usize,
+    ) -> (Vec<Vec<VertexId>>, FrontierStats) {
+        let mut expander = FrontierExpander::new(graph, config, None);
+        let mut frontier = vec![start(0)];
+        let mut paths = Vec::new();
+        for _ in 0..max_depth {
+            let hop = expander
+                .expand(&frontier, |path, edge, neighbor, vertex| {
+                    let vertex = vertex
+                        .cloned()
+                        .unwrap_or_else(|| Arc::new(Vertex::new(*neighbor, Vec::new())));
+                    let extended =
+                        Arc::new(NPath::extend(path.clone(), Arc::new(edge.clone()), vertex));
+                    if path.contains_vertex(neighbor) {
+                        Extension::Closed(extended)
+                    } else {
+                        Extension::Open(extended)
+                    }
+                })
+                .expect("expansion should succeed");
+            paths.extend(hop.open.iter().chain(&hop.closed).cloned());
+            frontier = hop.open;
+        }
+        let paths = paths.iter().map(|path| path.collect_vertex_ids()).collect();
+        (paths, expander.stats().clone())
+    }
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let (serial, serial_stats) = expand_all(star(50), ParallelConfig::with_parallelism(1), 3);
+        let (parallel, parallel_stats) = expand_all(star(50), parallel_config(4), 3);
+
+        // 50 one-hop paths; per spoke: back to 0 (closed) and on to 1000; then 1000 is a dead end
+        assert_eq!(serial.len(), 150);
+        assert_eq!(serial, parallel);
+        assert_eq!(serial_stats.parallel_hops, 0);
+        assert_eq!(parallel_stats.parallelism, 4);
+        assert_eq!(parallel_stats.parallel_hops, 3);
+        assert_eq!(parallel_stats.peak_frontier, 50);
+        // The spokes and 1000 are each loaded once, although 1000 is reached 50 times
+        assert_eq!(parallel_stats.vertices_loaded, 51);
+    }
+
+    #[test]
+    fn test_missing_vertices_are_passed_as_none() {
+        let mut expander = FrontierExpander::new(star(2), parallel_config(2), None);
+        let hop = expander
+            .expand(&[start(1)], |path, edge, _, vertex| match vertex {
+                Some(vertex) => Extension::Open(Arc::new(NPath::extend(
+                    path.clone(),
+                    Arc::new(edge.clone()),
+                    vertex.clone(),
+                ))),
+                None => Extension::Skip,
+            })
+            .expect("expansion should succeed");
+        assert_eq!(hop.open.len(), 1);
+        assert_eq!(hop.open[0].vertex().vid, vid(0));
+        assert!(hop.closed.is_empty());
+    }
+
+    #[test]
+    fn test_killed_query_stops_expansion() {
+        let kill_flag = Arc::new(AtomicBool::new(false));
+        let mut expander =
+            FrontierExpander::new(star(10), parallel_config(2), Some(kill_flag.clone()));
+        let hop = expander
+            .expand(&[start(0)], |path, edge, _, vertex| {
+                kill_flag.store(true, Ordering::SeqCst);
+                Extension::Open(Arc::new(NPath::extend(
+                    path.clone(),
+                    Arc::new(edge.clone()),
+                    vertex.cloned().expect("spokes exist"),
+                )))
+            })
+            .expect("the flag is raised only while extending");
+        assert_eq!(hop.open.len(), 10);
+        assert!(expander
+            .expand(&hop.open, |_, _, _, _| Extension::Skip)
+            .is_err());
+    }
+}
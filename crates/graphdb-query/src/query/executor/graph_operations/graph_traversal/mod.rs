@@ -15,6 +15,7 @@ pub mod all_paths;
 pub mod expand;
 pub mod expand_all;
 pub mod factory;
+pub mod frontier;
 pub mod impls;
 pub mod shortest_path;
 pub mod tests;
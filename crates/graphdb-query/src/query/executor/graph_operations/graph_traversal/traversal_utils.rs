@@ -124,8 +124,29 @@ pub fn get_neighbors_with_edges<S: StorageReader>(
     allow_loop: bool,
 ) -> DBResult<Vec<(VertexId, Edge)>> {
     let storage_guard = storage.read();
+    neighbors_with_edges_in(
+        &*storage_guard,
+        node_id,
+        edge_direction,
+        edge_types,
+        space_name,
+        allow_loop,
+    )
+}
 
-    let edges = storage_guard
+/// Obtaining neighbor nodes and edges through a storage reader that is already locked
+///
+/// Same as [`get_neighbors_with_edges`], for callers that hold one read guard over
+/// many lookups so that they all see the same snapshot.
+pub fn neighbors_with_edges_in<S: StorageReader + ?Sized>(
+    storage: &S,
+    node_id: &VertexId,
+    edge_direction: EdgeDirection,
+    edge_types: &Option<Vec<String>>,
+    space_name: &str,
+    allow_loop: bool,
+) -> DBResult<Vec<(VertexId, Edge)>> {
+    let edges = storage
         .get_node_edges(space_name, node_id, EdgeDirection::Both)
         .map_err(|e| DBError::storage(e.to_string()))?;
 
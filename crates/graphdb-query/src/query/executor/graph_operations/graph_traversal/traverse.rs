@@ -2,10 +2,9 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::core::error::{DBError, DBResult};
-use crate::core::types::VertexId;
 use crate::core::{Edge, Expression, NPath, Path, Value, Vertex};
 use crate::query::executor::base::ExecutorEnum;
-use crate::query::executor::base::{BaseExecutor, EdgeDirection, InputExecutor};
+use crate::query::executor::base::{BaseExecutor, EdgeDirection, ExecutionContext, InputExecutor};
 use crate::query::executor::base::{ExecutionResult, Executor, HasStorage};
 use crate::query::executor::expression::evaluator::expression_evaluator::ExpressionEvaluator;
 use crate::query::executor::expression::evaluator::traits::ExpressionContext;
@@ -16,6 +15,8 @@ use crate::query::QueryError;
 use crate::storage::StorageClient;
 use parking_lot::RwLock;
 
+use super::frontier::{Extension, FrontierExpander, FrontierSource, StorageFrontierSource};
+
 /// Parameters for creating a TraverseExecutor
 pub struct TraverseExecutorParams<S: StorageClient + Send + 'static> {
     pub id: i64,
@@ -49,11 +50,86 @@ pub struct TraverseExecutor<S: StorageClient + Send + 'static> {
     pub visited_nodes: HashSet<Value>,
     track_prev_path: bool,
     generate_path: bool,
+    filters: StepFilters,
+}
+
+/// Vertex, edge and general filters a step must pass
+#[derive(Debug, Clone, Default)]
+struct StepFilters {
     v_filter: Option<Expression>,
     e_filter: Option<Expression>,
     filter: Option<Expression>,
 }
 
+impl StepFilters {
+    /// Check whether the conditions are met.
+    ///
+    /// Refer to the implementation of TraverseExecutor::expand in nebula-graph.
+    /// 支持顶点过滤(vFilter)和边过滤(eFilter)
+    fn check(&self, path: &NPath, edge: &Edge, vertex: &Vertex) -> bool {
+        // Check the edge filtering conditions.
+        if let Some(ref e_filter) = self.e_filter {
+            let mut context = DefaultExpressionContext::new();
+            context.set_variable("edge".to_string(), Value::edge(edge.clone()));
+            context.set_variable(
+                "vertex".to_string(),
+                Value::Vertex(Box::new(vertex.clone())),
+            );
+
+            // The step starts at the end of the path.
+            context.set_variable(
+                "src".to_string(),
+                Value::Vertex(Box::new((**path.vertex()).clone())),
+            );
+            context.set_variable("dst".to_string(), Value::Vertex(Box::new(vertex.clone())));
+
+            match ExpressionEvaluator::evaluate(e_filter, &mut context) {
+                Ok(Value::Bool(true)) => {}
+                _ => return false,
+            }
+        }
+
+        // Check the vertex filtering conditions (applied only in the first step).
+        if path.is_empty() {
+            if let Some(ref v_filter) = self.v_filter {
+                let mut context = DefaultExpressionContext::new();
+                context.set_variable(
+                    "vertex".to_string(),
+                    Value::Vertex(Box::new(vertex.clone())),
+                );
+
+                match ExpressionEvaluator::evaluate(v_filter, &mut context) {
+                    Ok(Value::Bool(true)) => {}
+                    _ => return false,
+                }
+            }
+        }
+
+        // Check the general filtering criteria.
+        if let Some(ref filter) = self.filter {
+            let mut context = DefaultExpressionContext::new();
+            context.set_variable("edge".to_string(), Value::edge(edge.clone()));
+            context.set_variable(
+                "vertex".to_string(),
+                Value::Vertex(Box::new(vertex.clone())),
+            );
+
+            context.set_variable(
+                "src".to_string(),
+                Value::Vertex(Box::new((**path.vertex()).clone())),
+            );
+            context.set_variable("dst".to_string(), Value::Vertex(Box::new(vertex.clone())));
+
+            match ExpressionEvaluator::evaluate(filter, &mut context) {
+                Ok(Value::Bool(true)) => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
 // Manual Debug implementation for TraverseExecutor to avoid requiring Debug trait for Executor trait object
 impl<S: StorageClient> std::fmt::Debug for TraverseExecutor<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -98,12 +174,16 @@ impl<S: StorageClient> TraverseExecutor<S> {
             visited_nodes: HashSet::new(),
             track_prev_path: true,
             generate_path: true,
-            v_filter: None,
-            e_filter: None,
-            filter: None,
+            filters: StepFilters::default(),
         }
     }
 
+    /// Share the execution context of the query, for its kill flag and parallelism.
+    pub fn with_context(mut self, context: ExecutionContext) -> Self {
+        self.base.context = context;
+        self
+    }
+
     /// Set whether to track the previous path.
     pub fn with_track_prev_path(mut self, track_prev_path: bool) -> Self {
         self.track_prev_path = track_prev_path;
@@ -116,118 +196,33 @@ impl<S: StorageClient> TraverseExecutor<S> {
         self
     }
 
-    fn get_neighbors_with_edges(&self, node_id: &Value) -> Result<Vec<(Value, Edge)>, QueryError> {
-        let storage = self.base.get_storage().clone();
-        let node_vid =
-            VertexId::try_from(node_id).map_err(|e| QueryError::storage(e.to_string()))?;
-        let neighbors = super::traversal_utils::get_neighbors_with_edges(
-            &storage,
-            &node_vid,
-            self.edge_direction,
-            &self.edge_types,
-            &self.space_name,
-            false,
-        )
-        .map_err(|e| QueryError::storage(e.to_string()))?;
-        Ok(neighbors
-            .into_iter()
-            .map(|(vid, edge)| (Value::from(vid), edge))
-            .collect())
-    }
-
-    /// Check whether the conditions are met.
-    ///
-    /// Refer to the implementation of TraverseExecutor::expand in nebula-graph.
-    /// 支持顶点过滤(vFilter)和边过滤(eFilter)
-    fn check_conditions(&self, path: &Path, edge: &Edge, vertex: &Vertex) -> bool {
-        // Check the edge filtering conditions.
-        if let Some(ref e_filter) = self.e_filter {
-            let mut context = DefaultExpressionContext::new();
-            context.set_variable("edge".to_string(), Value::edge(edge.clone()));
-            context.set_variable(
-                "vertex".to_string(),
-                Value::Vertex(Box::new(vertex.clone())),
-            );
-
-            // If the path is not empty, add the relevant context.
-            if !path.steps.is_empty() {
-                let last_step = path.steps.last().expect("Path should have steps");
-                context.set_variable("src".to_string(), Value::Vertex(last_step.dst.clone()));
-            } else {
-                context.set_variable("src".to_string(), Value::Vertex(path.src.clone()));
-            }
-            context.set_variable("dst".to_string(), Value::Vertex(Box::new(vertex.clone())));
-
-            match ExpressionEvaluator::evaluate(e_filter, &mut context) {
-                Ok(Value::Bool(true)) => {}
-                _ => return false,
-            }
-        }
-
-        // Check the vertex filtering conditions (applied only in the first step).
-        if path.steps.is_empty() {
-            if let Some(ref v_filter) = self.v_filter {
-                let mut context = DefaultExpressionContext::new();
-                context.set_variable(
-                    "vertex".to_string(),
-                    Value::Vertex(Box::new(vertex.clone())),
-                );
-
-                match ExpressionEvaluator::evaluate(v_filter, &mut context) {
-                    Ok(Value::Bool(true)) => {}
-                    _ => return false,
-                }
-            }
-        }
-
-        // Check the general filtering criteria.
-        if let Some(ref filter) = self.filter {
-            let mut context = DefaultExpressionContext::new();
-            context.set_variable("edge".to_string(), Value::edge(edge.clone()));
-            context.set_variable(
-                "vertex".to_string(),
-                Value::Vertex(Box::new(vertex.clone())),
-            );
-
-            if !path.steps.is_empty() {
-                let last_step = path.steps.last().expect("Path should have steps");
-                context.set_variable("src".to_string(), Value::Vertex(last_step.dst.clone()));
-            } else {
-                context.set_variable("src".to_string(), Value::Vertex(path.src.clone()));
-            }
-            context.set_variable("dst".to_string(), Value::Vertex(Box::new(vertex.clone())));
-
-            match ExpressionEvaluator::evaluate(filter, &mut context) {
-                Ok(Value::Bool(true)) => {}
-                _ => return false,
-            }
-        }
-
-        true
-    }
-
     /// Set vertex filtering criteria
     pub fn with_v_filter(mut self, filter: Expression) -> Self {
-        self.v_filter = Some(filter);
+        self.filters.v_filter = Some(filter);
         self
     }
 
     /// Set edge filtering criteria
     pub fn with_e_filter(mut self, filter: Expression) -> Self {
-        self.e_filter = Some(filter);
+        self.filters.e_filter = Some(filter);
         self
     }
 
     /// Set common filtering criteria
     pub fn with_filter(mut self, filter: Expression) -> Self {
-        self.filter = Some(filter);
+        self.filters.filter = Some(filter);
         self
     }
 }
 
 impl<S: StorageClient> TraverseExecutor<S> {
     /// Perform a step-by-step traversal.
-    fn traverse_step(&mut self, current_depth: usize, max_depth: usize) -> Result<(), QueryError> {
+    fn traverse_step<F: FrontierSource>(
+        &mut self,
+        expander: &mut FrontierExpander<F>,
+        current_depth: usize,
+        max_depth: usize,
+    ) -> DBResult<()> {
         if current_depth >= max_depth {
             // Move the remaining elements from `current_npaths` to `completed_npaths`.
             self.completed_npaths.extend(self.current_npaths.clone());
@@ -235,61 +230,33 @@ impl<S: StorageClient> TraverseExecutor<S> {
             return Ok(());
         }
 
-        self.traverse_step_serial(current_depth, max_depth)
-    }
+        let filters = &self.filters;
+        let hop = expander.expand(&self.current_npaths, |npath, edge, _, vertex| {
+            // Edges to missing vertices are not followed
+            let Some(vertex) = vertex else {
+                return Extension::Skip;
+            };
+            if !filters.check(npath, edge, vertex) {
+                return Extension::Skip;
+            }
 
-    fn traverse_step_serial(
-        &mut self,
-        current_depth: usize,
-        max_depth: usize,
-    ) -> Result<(), QueryError> {
-        let mut next_npaths: Vec<Arc<NPath>> = Vec::new();
-        let mut completed_this_step: Vec<Arc<NPath>> = Vec::new();
-
-        for npath in &self.current_npaths {
-            // Get the last node of the current path.
-            let current_node = &npath.vertex().vid;
-            let current_node_value = Value::from(*current_node);
-
-            // Obtaining neighbor nodes and edges
-            let neighbors_with_edges = self.get_neighbors_with_edges(&current_node_value)?;
-
-            for (neighbor_id, edge) in neighbors_with_edges {
-                // Obtain the complete information of the neighboring nodes.
-                let neighbor_vid = VertexId::try_from(&neighbor_id)
-                    .map_err(|e| QueryError::storage(e.to_string()))?;
-                let storage = self.get_storage().read();
-                let neighbor_vertex = storage
-                    .get_vertex("default", &neighbor_vid)
-                    .map_err(|e| QueryError::storage(e.to_string()))?;
-
-                if let Some(vertex) = neighbor_vertex {
-                    // Convert NPath to Path for use in conditional checks
-                    let path = npath.to_path();
-                    // Check conditions
-                    if !self.check_conditions(&path, &edge, &vertex) {
-                        continue;
-                    }
+            // 使用 NPath 扩展，O(1) 操作
+            let new_npath = Arc::new(NPath::extend(
+                npath.clone(),
+                Arc::new(edge.clone()),
+                vertex.clone(),
+            ));
 
-                    // 使用 NPath 扩展，O(1) 操作
-                    let new_npath = Arc::new(NPath::extend(
-                        npath.clone(),
-                        Arc::new(edge),
-                        Arc::new(vertex),
-                    ));
-
-                    // Check whether the maximum depth has been reached.
-                    if current_depth + 1 >= max_depth {
-                        completed_this_step.push(new_npath);
-                    } else {
-                        next_npaths.push(new_npath);
-                    }
-                }
+            // Check whether the maximum depth has been reached.
+            if current_depth + 1 >= max_depth {
+                Extension::Closed(new_npath)
+            } else {
+                Extension::Open(new_npath)
             }
-        }
+        })?;
 
-        self.completed_npaths.extend(completed_this_step);
-        self.current_npaths = next_npaths;
+        self.completed_npaths.extend(hop.closed);
+        self.current_npaths = hop.open;
         Ok(())
     }
 
@@ -403,17 +370,37 @@ impl<S: StorageClient + Send + 'static> Executor<S> for TraverseExecutor<S> {
         // Determine the maximum depth.
         let max_depth = self.max_depth.unwrap_or(3); // The default depth is 3.
 
+        // One read guard for all hops, so every hop sees the same snapshot
+        let storage = self.get_storage().clone();
+        let storage = storage.read();
+        let space_name = self.space_name.clone();
+        let edge_types = self.edge_types.clone();
+        let mut expander = FrontierExpander::new(
+            StorageFrontierSource {
+                storage: &*storage,
+                space_name: &space_name,
+                edge_direction: self.edge_direction,
+                edge_types: &edge_types,
+            },
+            self.base.context.parallel_config(),
+            self.base.context.kill_flag.clone(),
+        );
+
         // Perform the traversal.
         for current_depth in 0..max_depth {
-            self.traverse_step(current_depth, max_depth)
-                .map_err(DBError::from)?;
+            self.traverse_step(&mut expander, current_depth, max_depth)?;
 
             // If there are no additional paths available for expansion, the process should be terminated in advance.
-            if self.current_paths.is_empty() {
+            if self.current_npaths.is_empty() {
                 break;
             }
         }
 
+        let stats = expander.stats().clone();
+        drop(expander);
+        drop(storage);
+        stats.record(self.base.get_stats_mut());
+
         // Add the remaining parts of the current path to the complete path.
         self.completed_paths.extend(self.current_paths.clone());
 
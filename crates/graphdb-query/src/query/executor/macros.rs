@@ -38,6 +38,7 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::HashLeftJoin(exec) => exec.$method(),
                 ExecutorEnum::FullOuterJoin(exec) => exec.$method(),
                 ExecutorEnum::CrossJoin(exec) => exec.$method(),
+                ExecutorEnum::MultiwayJoin(exec) => exec.$method(),
                 ExecutorEnum::Union(exec) => exec.$method(),
                 ExecutorEnum::UnionAll(exec) => exec.$method(),
                 ExecutorEnum::Minus(exec) => exec.$method(),
@@ -53,6 +54,7 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::Having(exec) => exec.$method(),
                 ExecutorEnum::Dedup(exec) => exec.$method(),
                 ExecutorEnum::Unwind(exec) => exec.$method(),
+                ExecutorEnum::PatternSubquery(exec) => exec.$method(),
                 ExecutorEnum::Assign(exec) => exec.$method(),
                 ExecutorEnum::Materialize(exec) => exec.$method(),
                 ExecutorEnum::AppendVertices(exec) => exec.$method(),
@@ -67,6 +69,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::Loop(exec) => exec.$method(),
                 ExecutorEnum::ForLoop(exec) => exec.$method(),
                 ExecutorEnum::WhileLoop(exec) => exec.$method(),
+                ExecutorEnum::CallSubquery(exec) => exec.$method(),
+                ExecutorEnum::Foreach(exec) => exec.$method(),
                 ExecutorEnum::Select(exec) => exec.$method(),
                 ExecutorEnum::ScanEdges(exec) => exec.$method(),
                 ExecutorEnum::ScanVertices(exec) => exec.$method(),
@@ -85,6 +89,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::VectorManage(exec) => exec.$method(),
                 // Statistics
                 ExecutorEnum::ShowStats(exec) => exec.$method(),
+                ExecutorEnum::ProcedureCall(exec) => exec.$method(),
+                ExecutorEnum::MaterializedViewScan(exec) => exec.$method(),
                 ExecutorEnum::Analyze(exec) => exec.$method(),
                 // Full-text Search Executors (data access)
                 ExecutorEnum::FulltextSearch(exec) => exec.$method(),
@@ -94,6 +100,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::VectorSearch(exec) => exec.$method(),
                 ExecutorEnum::VectorLookup(exec) => exec.$method(),
                 ExecutorEnum::VectorMatch(exec) => exec.$method(),
+                // Hybrid Search Executors (data access)
+                ExecutorEnum::HybridSearch(exec) => exec.$method(),
             }
         }
     };
@@ -122,6 +130,7 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::HashLeftJoin(exec) => exec.$method(),
                 ExecutorEnum::FullOuterJoin(exec) => exec.$method(),
                 ExecutorEnum::CrossJoin(exec) => exec.$method(),
+                ExecutorEnum::MultiwayJoin(exec) => exec.$method(),
                 ExecutorEnum::Union(exec) => exec.$method(),
                 ExecutorEnum::UnionAll(exec) => exec.$method(),
                 ExecutorEnum::Minus(exec) => exec.$method(),
@@ -137,6 +146,7 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::Having(exec) => exec.$method(),
                 ExecutorEnum::Dedup(exec) => exec.$method(),
                 ExecutorEnum::Unwind(exec) => exec.$method(),
+                ExecutorEnum::PatternSubquery(exec) => exec.$method(),
                 ExecutorEnum::Assign(exec) => exec.$method(),
                 ExecutorEnum::Materialize(exec) => exec.$method(),
                 ExecutorEnum::AppendVertices(exec) => exec.$method(),
@@ -151,6 +161,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::Loop(exec) => exec.$method(),
                 ExecutorEnum::ForLoop(exec) => exec.$method(),
                 ExecutorEnum::WhileLoop(exec) => exec.$method(),
+                ExecutorEnum::CallSubquery(exec) => exec.$method(),
+                ExecutorEnum::Foreach(exec) => exec.$method(),
                 ExecutorEnum::Select(exec) => exec.$method(),
                 ExecutorEnum::ScanEdges(exec) => exec.$method(),
                 ExecutorEnum::ScanVertices(exec) => exec.$method(),
@@ -169,6 +181,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::VectorManage(exec) => exec.$method(),
                 // Statistics
                 ExecutorEnum::ShowStats(exec) => exec.$method(),
+                ExecutorEnum::ProcedureCall(exec) => exec.$method(),
+                ExecutorEnum::MaterializedViewScan(exec) => exec.$method(),
                 ExecutorEnum::Analyze(exec) => exec.$method(),
                 // Full-text Search Executors (data access)
                 ExecutorEnum::FulltextSearch(exec) => exec.$method(),
@@ -178,6 +192,8 @@ macro_rules! delegate_executor_method {
                 ExecutorEnum::VectorSearch(exec) => exec.$method(),
                 ExecutorEnum::VectorLookup(exec) => exec.$method(),
                 ExecutorEnum::VectorMatch(exec) => exec.$method(),
+                // Hybrid Search Executors (data access)
+                ExecutorEnum::HybridSearch(exec) => exec.$method(),
             }
         }
     };
@@ -210,6 +226,9 @@ macro_rules! delegate_input_executor_method {
                 ExecutorEnum::Remove(exec) => exec.set_input(input),
                 ExecutorEnum::Materialize(exec) => exec.set_input(input),
                 ExecutorEnum::Unwind(exec) => exec.set_input(input),
+                ExecutorEnum::PatternSubquery(exec) => exec.set_input(input),
+                ExecutorEnum::CallSubquery(exec) => exec.set_input(input),
+                ExecutorEnum::Foreach(exec) => exec.set_input(input),
                 ExecutorEnum::PipeDelete(exec) => exec.set_input(input),
                 _ => {}
             }
@@ -238,6 +257,9 @@ macro_rules! delegate_input_executor_method {
                 ExecutorEnum::Remove(exec) => exec.get_input(),
                 ExecutorEnum::Materialize(exec) => exec.get_input(),
                 ExecutorEnum::Unwind(exec) => exec.get_input(),
+                ExecutorEnum::PatternSubquery(exec) => exec.get_input(),
+                ExecutorEnum::CallSubquery(exec) => exec.get_input(),
+                ExecutorEnum::Foreach(exec) => exec.get_input(),
                 ExecutorEnum::PipeDelete(exec) => exec.get_input(),
                 _ => None,
             }
@@ -271,6 +293,7 @@ macro_rules! delegate_debug_fmt {
                 ExecutorEnum::HashLeftJoin(exec) => ("HashLeftJoin", exec.name()),
                 ExecutorEnum::FullOuterJoin(exec) => ("FullOuterJoin", exec.name()),
                 ExecutorEnum::CrossJoin(exec) => ("CrossJoin", exec.name()),
+                ExecutorEnum::MultiwayJoin(exec) => ("MultiwayJoin", exec.name()),
                 ExecutorEnum::Union(exec) => ("Union", exec.name()),
                 ExecutorEnum::UnionAll(exec) => ("UnionAll", exec.name()),
                 ExecutorEnum::Minus(exec) => ("Minus", exec.name()),
@@ -286,6 +309,7 @@ macro_rules! delegate_debug_fmt {
                 ExecutorEnum::Having(exec) => ("Having", exec.name()),
                 ExecutorEnum::Dedup(exec) => ("Dedup", exec.name()),
                 ExecutorEnum::Unwind(exec) => ("Unwind", exec.name()),
+                ExecutorEnum::PatternSubquery(exec) => ("PatternSubquery", exec.name()),
                 ExecutorEnum::Assign(exec) => ("Assign", exec.name()),
                 ExecutorEnum::Materialize(exec) => ("Materialize", exec.name()),
                 ExecutorEnum::AppendVertices(exec) => ("AppendVertices", exec.name()),
@@ -300,6 +324,8 @@ macro_rules! delegate_debug_fmt {
                 ExecutorEnum::Loop(exec) => ("Loop", exec.name()),
                 ExecutorEnum::ForLoop(exec) => ("ForLoop", exec.name()),
                 ExecutorEnum::WhileLoop(exec) => ("WhileLoop", exec.name()),
+                ExecutorEnum::CallSubquery(exec) => ("CallSubquery", exec.name()),
+                ExecutorEnum::Foreach(exec) => ("Foreach", exec.name()),
                 ExecutorEnum::Select(exec) => ("Select", exec.name()),
                 ExecutorEnum::ScanEdges(exec) => ("ScanEdges", exec.name()),
                 ExecutorEnum::ScanVertices(exec) => ("ScanVertices", exec.name()),
@@ -318,6 +344,8 @@ macro_rules! delegate_debug_fmt {
                 ExecutorEnum::VectorManage(exec) => ("VectorManage", exec.name()),
                 // Statistics
                 ExecutorEnum::ShowStats(exec) => ("ShowStats", exec.name()),
+                ExecutorEnum::ProcedureCall(exec) => ("ProcedureCall", exec.name()),
+                ExecutorEnum::MaterializedViewScan(exec) => ("MaterializedViewScan", exec.name()),
                 ExecutorEnum::Analyze(exec) => ("Analyze", exec.name()),
                 // Full-text Search Executors (data access)
                 ExecutorEnum::FulltextSearch(exec) => ("FulltextSearch", exec.name()),
@@ -327,6 +355,8 @@ macro_rules! delegate_debug_fmt {
                 ExecutorEnum::VectorSearch(exec) => ("VectorSearch", exec.name()),
                 ExecutorEnum::VectorLookup(exec) => ("VectorLookup", exec.name()),
                 ExecutorEnum::VectorMatch(exec) => ("VectorMatch", exec.name()),
+                // Hybrid Search Executors (data access)
+                ExecutorEnum::HybridSearch(exec) => ("HybridSearch", exec.name()),
             };
             f.write_str(&format!("ExecutorEnum::{}({})", variant_name, exec_name))
         }
@@ -359,6 +389,7 @@ macro_rules! delegate_node_type_id {
                 ExecutorEnum::HashLeftJoin(_) => "hash_left_join",
                 ExecutorEnum::FullOuterJoin(_) => "full_outer_join",
                 ExecutorEnum::CrossJoin(_) => "cross_join",
+                ExecutorEnum::MultiwayJoin(_) => "multiway_join",
                 ExecutorEnum::Union(_) => "union",
                 ExecutorEnum::UnionAll(_) => "union_all",
                 ExecutorEnum::Minus(_) => "minus",
@@ -374,6 +405,7 @@ macro_rules! delegate_node_type_id {
                 ExecutorEnum::Having(_) => "having",
                 ExecutorEnum::Dedup(_) => "dedup",
                 ExecutorEnum::Unwind(_) => "unwind",
+                ExecutorEnum::PatternSubquery(_) => "pattern_subquery",
                 ExecutorEnum::Assign(_) => "assign",
                 ExecutorEnum::Materialize(_) => "materialize",
                 ExecutorEnum::AppendVertices(_) => "append_vertices",
@@ -388,6 +420,8 @@ macro_rules! delegate_node_type_id {
                 ExecutorEnum::Loop(_) => "loop",
                 ExecutorEnum::ForLoop(_) => "for_loop",
                 ExecutorEnum::WhileLoop(_) => "while_loop",
+                ExecutorEnum::CallSubquery(_) => "call_subquery",
+                ExecutorEnum::Foreach(_) => "foreach",
                 ExecutorEnum::Select(_) => "select",
                 ExecutorEnum::ScanEdges(_) => "scan_edges",
                 ExecutorEnum::ScanVertices(_) => "scan_vertices",
@@ -406,6 +440,8 @@ macro_rules! delegate_node_type_id {
                 ExecutorEnum::VectorManage(e) => e.node_type_id(),
                 // Statistics
                 ExecutorEnum::ShowStats(_) => "show_stats",
+                ExecutorEnum::ProcedureCall(_) => "procedure_call",
+                ExecutorEnum::MaterializedViewScan(_) => "materialized_view_scan",
                 ExecutorEnum::Analyze(_) => "analyze",
                 // Full-text Search Executors (data access)
                 ExecutorEnum::FulltextSearch(_) => "fulltext_search",
@@ -415,6 +451,8 @@ macro_rules! delegate_node_type_id {
                 ExecutorEnum::VectorSearch(_) => "vector_search",
                 ExecutorEnum::VectorLookup(_) => "vector_lookup",
                 ExecutorEnum::VectorMatch(_) => "vector_match",
+                // Hybrid Search Executors (data access)
+                ExecutorEnum::HybridSearch(_) => "hybrid_search",
             }
         }
     };
@@ -42,8 +42,8 @@ pub use relational_algebra::{
 
 // Re-export transformations (Data conversion executors)
 pub use result_processing::transformations::{
-    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, RollUpApplyExecutor,
-    UnwindExecutor,
+    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, PatternSubqueryExecutor,
+    RollUpApplyExecutor, UnwindExecutor,
 };
 
 // Re-export control flow executors
@@ -5,6 +5,7 @@
 //! LeftJoin (left outer join)
 //! FullOuterJoin
 //! CrossJoin/CartesianProduct (Cartesian product)
+//! MultiwayJoin (worst-case optimal join for cyclic patterns)
 //!
 //! The implementation of the `join` operation is based on the `nebula-graph` framework, and the performance is optimized by using the hash join algorithm.
 //!
@@ -17,6 +18,7 @@ pub mod hash_table;
 pub mod inner_join;
 pub mod join_key_evaluator;
 pub mod left_join;
+pub mod multiway_join;
 
 // Re-export the main types
 pub use base_join::BaseJoinExecutor;
@@ -26,6 +28,7 @@ pub use hash_table::{HashTableBuilder, JoinKey};
 pub use inner_join::{HashInnerJoinExecutor, InnerJoinConfig, InnerJoinExecutor};
 pub use join_key_evaluator::JoinKeyEvaluator;
 pub use left_join::{HashLeftJoinExecutor, LeftJoinConfig, LeftJoinExecutor};
+pub use multiway_join::MultiwayJoinExecutor;
 
 // Import the `JoinType` from the `core` module.
 pub use crate::core::types::JoinType;
@@ -0,0 +1,583 @@
+//! Multiway (worst-case optimal) join executor
+//!
+//! Evaluates a cyclic graph pattern with generic join: node variables are bound
+//! one at a time, and the candidates for a variable are the intersection of the
+//! sorted adjacency lists of every pattern edge that connects it to a variable
+//! bound earlier. The lists are intersected with leapfrog seeks, so a triangle
+//! over a skewed graph never materialises the wedges a binary expand + join
+//! would produce. Edges are attached once all node variables are bound.
+
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::error::{DBError, DBResult};
+use crate::core::types::VertexId;
+use crate::core::value::NullType;
+use crate::core::{Edge, Value, Vertex};
+use crate::query::executor::base::{
+    BaseExecutor, EdgeDirection, ExecutionResult, Executor, HasStorage,
+};
+use crate::query::planning::plan::core::nodes::join::multiway_join_node::JoinRelation;
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+use crate::storage::{StorageClient, StorageReader};
+
+/// Intersect sorted, duplicate-free lists with leapfrog seeks.
+///
+/// Each step seeks the next list to the first value not below the current
+/// candidate; a value is emitted once every list has landed on it in a row.
+pub fn leapfrog_intersect<T: Ord + Copy>(lists: &[&[T]]) -> Vec<T> {
+    let mut result = Vec::new();
+    let Some(first) = lists.first() else {
+        return result;
+    };
+    if lists.len() == 1 {
+        return first.to_vec();
+    }
+    if lists.iter().any(|list| list.is_empty()) {
+        return result;
+    }
+
+    let mut positions = vec![0usize; lists.len()];
+    let mut candidate = lists.iter().map(|list| list[0]).max().unwrap_or(first[0]);
+    let mut agreed = 0;
+    let mut i = 0;
+    loop {
+        let list = lists[i];
+        let pos = positions[i] + list[positions[i]..].partition_point(|v| *v < candidate);
+        if pos == list.len() {
+            return result;
+        }
+        positions[i] = pos;
+
+        if list[pos] == candidate {
+            agreed += 1;
+            if agreed == lists.len() {
+                result.push(candidate);
+                positions[i] += 1;
+                let Some(next) = list.get(positions[i]) else {
+                    return result;
+                };
+                candidate = *next;
+                agreed = 1;
+            }
+        } else {
+            candidate = list[pos];
+            agreed = 1;
+        }
+        i = (i + 1) % lists.len();
+    }
+}
+
+/// Where the join reads edges from
+pub trait EdgeSource {
+    /// Edges leaving (`Out`) or entering (`In`) `vertex`
+    fn edges_of(&self, vertex: &VertexId, direction: EdgeDirection) -> DBResult<Vec<Edge>>;
+
+    /// Edges of the given types, or every edge when `edge_types` is empty
+    fn scan(&self, edge_types: &[String]) -> DBResult<Vec<Edge>>;
+}
+
+/// Edge source backed by the adjacency lists of the storage engine
+struct StorageEdgeSource<'a, S: StorageReader> {
+    storage: &'a S,
+    space_name: &'a str,
+}
+
+impl<S: StorageReader> EdgeSource for StorageEdgeSource<'_, S> {
+    fn edges_of(&self, vertex: &VertexId, direction: EdgeDirection) -> DBResult<Vec<Edge>> {
+        self.storage
+            .get_node_edges(self.space_name, vertex, direction)
+            .map_err(|e| DBError::storage(e.to_string()))
+    }
+
+    fn scan(&self, edge_types: &[String]) -> DBResult<Vec<Edge>> {
+        if edge_types.is_empty() {
+            return self
+                .storage
+                .scan_all_edges(self.space_name)
+                .map_err(|e| DBError::storage(e.to_string()));
+        }
+        let mut edges = Vec::new();
+        for edge_type in edge_types {
+            edges.extend(
+                self.storage
+                    .scan_edges_by_type(self.space_name, edge_type)
+                    .map_err(|e| DBError::storage(e.to_string()))?,
+            );
+        }
+        Ok(edges)
+    }
+}
+
+/// One match of the pattern: a vertex per node variable, in variable order,
+/// and an edge per relation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    pub vertices: Vec<VertexId>,
+    pub edges: Vec<Edge>,
+}
+
+/// Generic join over an edge source, with adjacency lists cached per vertex
+pub struct GenericJoin<'r, E: EdgeSource> {
+    source: E,
+    relations: &'r [JoinRelation],
+    variable_order: &'r [String],
+    adjacency: HashMap<(VertexId, bool), Vec<Edge>>,
+    neighbors: HashMap<(usize, bool, VertexId), Vec<VertexId>>,
+}
+
+impl<'r, E: EdgeSource> GenericJoin<'r, E> {
+    pub fn new(source: E, relations: &'r [JoinRelation], variable_order: &'r [String]) -> Self {
+        Self {
+            source,
+            relations,
+            variable_order,
+            adjacency: HashMap::new(),
+            neighbors: HashMap::new(),
+        }
+    }
+
+    /// Enumerate every match of the pattern.
+    pub fn run(&mut self) -> DBResult<Vec<Binding>> {
+        let mut bindings = Vec::new();
+        let mut bound = Vec::with_capacity(self.variable_order.len());
+        self.bind(&mut bound, &mut bindings)?;
+        Ok(bindings)
+    }
+
+    fn position(&self, variable: &str) -> Option<usize> {
+        self.variable_order.iter().position(|v| v == variable)
+    }
+
+    fn bind(&mut self, bound: &mut Vec<VertexId>, bindings: &mut Vec<Binding>) -> DBResult<()> {
+        let depth = bound.len();
+        if depth == self.variable_order.len() {
+            return self.attach_edges(bound, bindings);
+        }
+
+        for candidate in self.candidates(bound)? {
+            bound.push(candidate);
+            self.bind(bound, bindings)?;
+            bound.pop();
+        }
+        Ok(())
+    }
+
+    /// Values of the next variable that agree with every relation reaching back
+    /// to a variable that is already bound
+    fn candidates(&mut self, bound: &[VertexId]) -> DBResult<Vec<VertexId>> {
+        let order = self.variable_order;
+        let variable = &order[bound.len()];
+        let mut lists = Vec::new();
+        for (index, relation) in self.relations.iter().enumerate() {
+            let (other, bound_is_src) = if relation.dst == *variable {
+                (&relation.src, true)
+            } else if relation.src == *variable {
+                (&relation.dst, false)
+            } else {
+                continue;
+            };
+            if let Some(pos) = self.position(other).filter(|pos| *pos < bound.len()) {
+                lists.push((index, bound_is_src, bound[pos]));
+            }
+        }
+
+        if lists.is_empty() {
+            return self.domain(variable);
+        }
+        for &(index, bound_is_src, vertex) in &lists {
+            self.load_neighbors(index, bound_is_src, vertex)?;
+        }
+        let mut lists: Vec<&[VertexId]> = lists
+            .iter()
+            .map(|key| self.neighbors[key].as_slice())
+            .collect();
+        lists.sort_by_key(|list| list.len());
+        Ok(leapfrog_intersect(&lists))
+    }
+
+    /// Every vertex at the `variable` end of the first relation that touches it
+    fn domain(&self, variable: &str) -> DBResult<Vec<VertexId>> {
+        let Some(relation) = self.relations.iter().find(|r| r.touches(variable)) else {
+            return Ok(Vec::new());
+        };
+        let mut values: Vec<VertexId> = self
+            .source
+            .scan(&relation.edge_types)?
+            .into_iter()
+            .filter(|edge| relation.accepts(&edge.edge_type))
+            .flat_map(|edge| {
+                let forward = if relation.src == variable {
+                    edge.src
+                } else {
+                    edge.dst
+                };
+                let backward = relation.undirected.then_some(if relation.src == variable {
+                    edge.dst
+                } else {
+                    edge.src
+                });
+                std::iter::once(forward).chain(backward)
+            })
+            .collect();
+        values.sort();
+        values.dedup();
+        Ok(values)
+    }
+
+    fn load_adjacency(&mut self, vertex: VertexId, outgoing: bool) -> DBResult<()> {
+        if !self.adjacency.contains_key(&(vertex, outgoing)) {
+            let direction = if outgoing {
+                EdgeDirection::Out
+            } else {
+                EdgeDirection::In
+            };
+            let edges = self.source.edges_of(&vertex, direction)?;
+            self.adjacency.insert((vertex, outgoing), edges);
+        }
+        Ok(())
+    }
+
+    /// Sorted neighbours of `vertex` through a relation, seen from its source
+    /// end (`bound_is_src`) or its destination end
+    fn load_neighbors(
+        &mut self,
+        index: usize,
+        bound_is_src: bool,
+        vertex: VertexId,
+    ) -> DBResult<()> {
+        if self.neighbors.contains_key(&(index, bound_is_src, vertex)) {
+            return Ok(());
+        }
+        let relations = self.relations;
+        let relation = &relations[index];
+        let undirected = relation.undirected;
+        let mut values = Vec::new();
+        for outgoing in [bound_is_src, !bound_is_src] {
+            if outgoing != bound_is_src && !undirected {
+                continue;
+            }
+            self.load_adjacency(vertex, outgoing)?;
+            values.extend(
+                self.adjacency[&(vertex, outgoing)]
+                    .iter()
+                    .filter(|edge| relation.accepts(&edge.edge_type))
+                    .map(|edge| if outgoing { edge.dst } else { edge.src }),
+            );
+        }
+        values.sort();
+        values.dedup();
+        self.neighbors.insert((index, bound_is_src, vertex), values);
+        Ok(())
+    }
+
+    /// Expand the bound vertices into one binding per combination of parallel edges
+    fn attach_edges(&mut self, bound: &[VertexId], bindings: &mut Vec<Binding>) -> DBResult<()> {
+        let mut per_relation = Vec::with_capacity(self.relations.len());
+        for relation in self.relations {
+            let (Some(src), Some(dst)) =
+                (self.position(&relation.src), self.position(&relation.dst))
+            else {
+                return Ok(());
+            };
+            let (src, dst) = (bound[src], bound[dst]);
+            let mut edges = self.edges_between(relation, src, dst)?;
+            if relation.undirected && src != dst {
+                edges.extend(self.edges_between(relation, dst, src)?);
+            }
+            if edges.is_empty() {
+                return Ok(());
+            }
+            per_relation.push(edges);
+        }
+
+        let mut combination = vec![0usize; per_relation.len()];
+        loop {
+            bindings.push(Binding {
+                vertices: bound.to_vec(),
+                edges: combination
+                    .iter()
+                    .zip(&per_relation)
+                    .map(|(i, edges)| edges[*i].clone())
+                    .collect(),
+            });
+
+            // Advance the odometer; stop once every position has wrapped
+            let mut slot = 0;
+            loop {
+                if slot == combination.len() {
+                    return Ok(());
+                }
+                combination[slot] += 1;
+                if combination[slot] < per_relation[slot].len() {
+                    break;
+                }
+                combination[slot] = 0;
+                slot += 1;
+            }
+        }
+    }
+
+    fn edges_between(
+        &mut self,
+        relation: &JoinRelation,
+        src: VertexId,
+        dst: VertexId,
+    ) -> DBResult<Vec<Edge>> {
+        self.load_adjacency(src, true)?;
+        Ok(self.adjacency[&(src, true)]
+            .iter()
+            .filter(|edge| edge.dst == dst && relation.accepts(&edge.edge_type))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Multiway join executor
+#[derive(Debug)]
+pub struct MultiwayJoinExecutor<S: StorageClient> {
+    base: BaseExecutor<S>,
+    space_name: String,
+    relations: Vec<JoinRelation>,
+    variable_order: Vec<String>,
+    col_names: Vec<String>,
+}
+
+impl<S: StorageClient> MultiwayJoinExecutor<S> {
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: String,
+        relations: Vec<JoinRelation>,
+        variable_order: Vec<String>,
+        col_names: Vec<String>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "MultiwayJoinExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+            relations,
+            variable_order,
+            col_names,
+        }
+    }
+
+    fn join(&self) -> DBResult<DataSet> {
+        let storage = self.base.get_storage().read();
+        let source = StorageEdgeSource {
+            storage: &*storage,
+            space_name: &self.space_name,
+        };
+        let bindings = GenericJoin::new(source, &self.relations, &self.variable_order).run()?;
+
+        // Each output column is either a node variable or a named edge
+        let columns: Vec<Column> = self
+            .col_names
+            .iter()
+            .map(|name| {
+                if let Some(pos) = self.variable_order.iter().position(|v| v == name) {
+                    Column::Vertex(pos)
+                } else {
+                    self.relations
+                        .iter()
+                        .position(|r| r.edge_alias.as_deref() == Some(name.as_str()))
+                        .map_or(Column::Missing, Column::Edge)
+                }
+            })
+            .collect();
+
+        let mut vertices: HashMap<VertexId, Value> = HashMap::new();
+        let mut rows = Vec::with_capacity(bindings.len());
+        for binding in bindings {
+            let mut row = Vec::with_capacity(columns.len());
+            for column in &columns {
+                row.push(match column {
+                    Column::Vertex(pos) => {
+                        let vid = binding.vertices[*pos];
+                        match vertices.get(&vid) {
+                            Some(value) => value.clone(),
+                            None => {
+                                let vertex = storage
+                                    .get_vertex(&self.space_name, &vid)
+                                    .map_err(|e| DBError::storage(e.to_string()))?
+                                    .unwrap_or_else(|| Vertex::new(vid, Vec::new()));
+                                let value = Value::Vertex(Box::new(vertex));
+                                vertices.insert(vid, value.clone());
+                                value
+                            }
+                        }
+                    }
+                    Column::Edge(index) => Value::edge(binding.edges[*index].clone()),
+                    Column::Missing => Value::Null(NullType::Null),
+                });
+            }
+            rows.push(row);
+        }
+
+        Ok(DataSet {
+            col_names: self.col_names.clone(),
+            rows,
+        })
+    }
+}
+
+enum Column {
+    Vertex(usize),
+    Edge(usize),
+    Missing,
+}
+
+impl<S: StorageClient + Send + Sync + 'static> Executor<S> for MultiwayJoinExecutor<S> {
+    fn execute(&mut self) -> DBResult<ExecutionResult> {
+        let dataset = self.join()?;
+        self.base.get_stats_mut().add_row(dataset.rows.len());
+        Ok(ExecutionResult::DataSet(dataset))
+    }
+
+    fn open(&mut self) -> DBResult<()> {
+        self.base.open()
+    }
+    fn close(&mut self) -> DBResult<()> {
+        self.base.close()
+    }
+    fn is_open(&self) -> bool {
+        self.base.is_open()
+    }
+    fn id(&self) -> i64 {
+        self.base.id
+    }
+    fn name(&self) -> &str {
+        "MultiwayJoinExecutor"
+    }
+    fn description(&self) -> &str {
+        "Matches a cyclic pattern by intersecting adjacency lists"
+    }
+    fn stats(&self) -> &crate::query::executor::base::ExecutorStats {
+        self.base.get_stats()
+    }
+    fn stats_mut(&mut self) -> &mut crate::query::executor::base::ExecutorStats {
+        self.base.get_stats_mut()
+    }
+}
+
+impl<S: StorageClient> HasStorage<S> for MultiwayJoinExecutor<S> {
+    fn get_storage(&self) -> &Arc<RwLock<S>> {
+        self.base.get_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory edge list standing in for the storage engine
+    struct EdgeList(Vec<Edge>);
+
+    impl EdgeSource for EdgeList {
+        fn edges_of(&self, vertex: &VertexId, direction: EdgeDirection) -> DBResult<Vec<Edge>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|edge| match direction {
+                    EdgeDirection::Out => edge.src == *vertex,
+                    EdgeDirection::In => edge.dst == *vertex,
+                    EdgeDirection::Both => edge.src == *vertex || edge.dst == *vertex,
+                })
+                .cloned()
+                .collect())
+        }
+
+        fn scan(&self, edge_types: &[String]) -> DBResult<Vec<Edge>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|edge| edge_types.is_empty() || edge_types.contains(&edge.edge_type))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn edge(src: i64, dst: i64) -> Edge {
+        Edge::new_empty(src.into(), dst.into(), "knows".to_string(), 0)
+    }
+
+    fn relation(src: &str, dst: &str) -> JoinRelation {
+        JoinRelation {
+            src: src.to_string(),
+            dst: dst.to_string(),
+            edge_alias: None,
+            edge_types: vec!["knows".to_string()],
+            undirected: false,
+        }
+    }
+
+    fn vids(ids: &[i64]) -> Vec<VertexId> {
+        ids.iter().map(|id| VertexId::from(*id)).collect()
+    }
+
+    #[test]
+    fn test_leapfrog_intersect() {
+        let a = [1, 3, 4, 7, 9, 12];
+        let b = [2, 3, 7, 8, 12, 15];
+        let c = [3, 5, 7, 12];
+        assert_eq!(
+            leapfrog_intersect(&[&a[..], &b[..], &c[..]]),
+            vec![3, 7, 12]
+        );
+        assert_eq!(leapfrog_intersect(&[&a[..]]), a.to_vec());
+        assert!(leapfrog_intersect(&[&a[..], &[][..]]).is_empty());
+        assert!(leapfrog_intersect(&[&[1, 2][..], &[3, 4][..]]).is_empty());
+        assert!(leapfrog_intersect::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_directed_triangles() {
+        // 1 -> 2 -> 3 -> 1 is the only directed triangle; 1 -> 4 -> 3 is a dead end
+        let edges = EdgeList(vec![
+            edge(1, 2),
+            edge(2, 3),
+            edge(3, 1),
+            edge(1, 4),
+            edge(4, 3),
+        ]);
+        let relations = vec![relation("a", "b"), relation("b", "c"), relation("c", "a")];
+        let order = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let bindings = GenericJoin::new(edges, &relations, &order)
+            .run()
+            .expect("join should succeed");
+        let mut found: Vec<Vec<VertexId>> = bindings.into_iter().map(|b| b.vertices).collect();
+        found.sort();
+        // One match per rotation of the triangle
+        let mut expected = vec![vids(&[1, 2, 3]), vids(&[2, 3, 1]), vids(&[3, 1, 2])];
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_parallel_and_undirected_edges() {
+        // Two parallel 1 -> 2 edges double the matches; the undirected
+        // relation accepts 3 -> 1 read backwards
+        let mut parallel = edge(1, 2);
+        parallel.ranking = 1;
+        let edges = EdgeList(vec![edge(1, 2), parallel, edge(2, 3), edge(3, 1)]);
+        let mut closing = relation("a", "c");
+        closing.undirected = true;
+        let relations = vec![relation("a", "b"), relation("b", "c"), closing];
+        let order = vec!["b".to_string(), "a".to_string(), "c".to_string()];
+
+        let bindings = GenericJoin::new(edges, &relations, &order)
+            .run()
+            .expect("join should succeed");
+        assert_eq!(bindings.len(), 2);
+        assert!(bindings.iter().all(|b| b.vertices == vids(&[2, 1, 3])));
+        assert_ne!(bindings[0].edges[0], bindings[1].edges[0]);
+    }
+}
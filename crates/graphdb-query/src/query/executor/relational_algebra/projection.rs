@@ -129,6 +129,11 @@ fn _extract_variable_names(expr: &Expression) -> Vec<String> {
                     collect(m, names);
                 }
             }
+            Expression::PatternSubquery(subquery) => {
+                for nested in subquery.expressions() {
+                    collect(nested, names);
+                }
+            }
             Expression::Literal(_)
             | Expression::Label(_)
             | Expression::TagProperty { .. }
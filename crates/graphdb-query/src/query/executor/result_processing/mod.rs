@@ -44,6 +44,6 @@ pub use topn::TopNExecutor;
 // RollUpApply (Aggregation Operation)
 pub mod transformations;
 pub use transformations::{
-    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, RollUpApplyExecutor,
-    UnwindExecutor,
+    AppendVerticesExecutor, AssignExecutor, PatternApplyExecutor, PatternSubqueryExecutor,
+    RollUpApplyExecutor, UnwindExecutor,
 };
@@ -5,6 +5,7 @@
 //! AppendVertices (Adding Vertices)
 //! "Unwind" (list expansion) – This phrase refers to the process of expanding or displaying all the items in a list in detail. For example, if you have a list with only a few items visible at the top of the screen, clicking on the "Unwind" button or option will show all the items in the list.
 //! PatternApply (Pattern Matching)
+//! PatternSubquery (EXISTS / COUNT / pattern comprehension)
 //! RollUpApply (aggregation operation)
 //!
 //! Corresponding NebulaGraph implementation:
@@ -33,6 +34,10 @@ pub use append_vertices::AppendVerticesExecutor;
 pub mod pattern_apply;
 pub use pattern_apply::PatternApplyExecutor;
 
+// Pattern subquery executor
+pub mod pattern_subquery;
+pub use pattern_subquery::PatternSubqueryExecutor;
+
 // Aggregation Operation Executor
 pub mod rollup_apply;
 pub use rollup_apply::RollUpApplyExecutor;
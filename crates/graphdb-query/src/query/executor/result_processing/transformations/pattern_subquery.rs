@@ -0,0 +1,586 @@
+//! Implementation of PatternSubqueryExecutor
+//!
+//! Evaluates `EXISTS { }`, `COUNT { }` and pattern comprehensions once per input row.
+//! Pattern variables that are bound by the row anchor the match; the remaining nodes are
+//! reached by expanding relationships outwards from the anchor, first to the right and then
+//! to the left. Paths without a bound variable start from a label scan.
+//!
+//! When the optimizer marks the subquery as decorrelated, the relationships of the single
+//! hop are read once per execution and probed by hash instead of being expanded per row.
+
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::error::{DBError, DBResult};
+use crate::core::types::expr::{
+    PatternEdge, PatternNode, PatternPath, PatternSubquery, PatternSubqueryKind,
+};
+use crate::core::types::operators::BinaryOperator;
+use crate::core::types::{EdgeDirection, VertexId};
+use crate::core::value::list::List;
+use crate::core::{Edge, Expression, Value, Vertex};
+use crate::query::executor::base::{
+    BaseExecutor, ExecutionResult, Executor, ExecutorEnum, HasStorage, InputExecutor,
+};
+use crate::query::executor::expression::evaluator::expression_evaluator::ExpressionEvaluator;
+use crate::query::executor::expression::evaluator::operations::BinaryOperationEvaluator;
+use crate::query::executor::expression::{
+    DefaultExpressionContext, ExpressionContext as EvalContext,
+};
+use crate::query::planning::plan::core::nodes::PatternSubqueryOutput;
+use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::DataSet;
+use crate::storage::StorageClient;
+
+/// Identity of a relationship; a relationship is matched at most once per pattern match.
+type EdgeKey = (VertexId, VertexId, String, i64);
+
+fn edge_key(edge: &Edge) -> EdgeKey {
+    (edge.src, edge.dst, edge.edge_type.clone(), edge.ranking)
+}
+
+/// Relationships of the decorrelated hop, indexed by both endpoints
+#[derive(Debug, Default)]
+struct EdgeIndex {
+    outgoing: HashMap<VertexId, Vec<Edge>>,
+    incoming: HashMap<VertexId, Vec<Edge>>,
+}
+
+impl EdgeIndex {
+    fn edges(&self, vid: &VertexId, direction: EdgeDirection) -> Vec<Edge> {
+        let outgoing = self.outgoing.get(vid).into_iter().flatten();
+        let incoming = self.incoming.get(vid).into_iter().flatten();
+        match direction {
+            EdgeDirection::Out => outgoing.cloned().collect(),
+            EdgeDirection::In => incoming.cloned().collect(),
+            EdgeDirection::Both => outgoing.chain(incoming).cloned().collect(),
+        }
+    }
+}
+
+/// Pattern Subquery Executor
+pub struct PatternSubqueryExecutor<S: StorageClient + Send + 'static> {
+    base: BaseExecutor<S>,
+    space_name: String,
+    subquery: PatternSubquery,
+    output: PatternSubqueryOutput,
+    decorrelated: bool,
+    col_names: Vec<String>,
+    input_executor: Option<Box<ExecutorEnum<S>>>,
+}
+
+impl<S: StorageClient + Send + 'static> PatternSubqueryExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i64,
+        storage: Arc<RwLock<S>>,
+        space_name: String,
+        subquery: PatternSubquery,
+        output: PatternSubqueryOutput,
+        decorrelated: bool,
+        col_names: Vec<String>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        Self {
+            base: BaseExecutor::new(
+                id,
+                "PatternSubqueryExecutor".to_string(),
+                storage,
+                expr_context,
+            ),
+            space_name,
+            subquery,
+            output,
+            decorrelated,
+            col_names,
+            input_executor: None,
+        }
+    }
+
+    /// Read the relationships of the single hop once, if the subquery was decorrelated.
+    fn build_edge_index(&self, storage: &S) -> DBResult<Option<EdgeIndex>> {
+        let edge = match self.subquery.paths.as_slice() {
+            [path] if self.decorrelated && path.edges.len() == 1 => &path.edges[0],
+            _ => return Ok(None),
+        };
+        let mut index = EdgeIndex::default();
+        for edge_type in &edge.edge_types {
+            for e in storage.scan_edges_by_type(&self.space_name, edge_type)? {
+                index.outgoing.entry(e.src).or_default().push(e.clone());
+                index.incoming.entry(e.dst).or_default().push(e);
+            }
+        }
+        Ok(Some(index))
+    }
+
+    fn execute_pattern_subquery(&mut self) -> DBResult<DataSet> {
+        let input_result = if let Some(ref mut input_exec) = self.input_executor {
+            input_exec.execute()?
+        } else {
+            ExecutionResult::Success
+        };
+
+        let input = match input_result {
+            ExecutionResult::DataSet(data) => data,
+            ExecutionResult::Success
+            | ExecutionResult::Empty
+            | ExecutionResult::SpaceSwitched(_) => DataSet {
+                col_names: Vec::new(),
+                rows: vec![Vec::new()],
+            },
+            ExecutionResult::Error(e) => {
+                return Err(DBError::query(format!("Error in input result: {}", e)));
+            }
+        };
+
+        let storage = self.get_storage().clone();
+        let storage = storage.read();
+        let edge_index = self.build_edge_index(&storage)?;
+
+        let mut dataset = DataSet {
+            col_names: self.col_names.clone(),
+            rows: Vec::new(),
+        };
+
+        for row in input.rows {
+            let outer: HashMap<String, Value> = input
+                .col_names
+                .iter()
+                .cloned()
+                .zip(row.iter().cloned())
+                .collect();
+
+            let mut matcher = PatternMatcher {
+                storage: &*storage,
+                space: &self.space_name,
+                subquery: &self.subquery,
+                edge_index: edge_index.as_ref(),
+                outer: &outer,
+                bindings: HashMap::new(),
+                used_edges: Vec::new(),
+                count: 0,
+                items: Vec::new(),
+            };
+            matcher.match_paths(0)?;
+
+            match &self.output {
+                PatternSubqueryOutput::Semi if matcher.count > 0 => dataset.rows.push(row),
+                PatternSubqueryOutput::Anti if matcher.count == 0 => dataset.rows.push(row),
+                PatternSubqueryOutput::Semi | PatternSubqueryOutput::Anti => {}
+                PatternSubqueryOutput::Column(_) => {
+                    let value = match self.subquery.kind {
+                        PatternSubqueryKind::Exists => Value::Bool(matcher.count > 0),
+                        PatternSubqueryKind::Count => Value::BigInt(matcher.count as i64),
+                        PatternSubqueryKind::Collect => {
+                            Value::list(List::from(std::mem::take(&mut matcher.items)))
+                        }
+                    };
+                    let mut new_row = row;
+                    new_row.push(value);
+                    dataset.rows.push(new_row);
+                }
+            }
+        }
+
+        Ok(dataset)
+    }
+}
+
+/// Backtracking matcher for one input row
+struct PatternMatcher<'a, S: StorageClient> {
+    storage: &'a S,
+    space: &'a str,
+    subquery: &'a PatternSubquery,
+    edge_index: Option<&'a EdgeIndex>,
+    /// Columns of the input row
+    outer: &'a HashMap<String, Value>,
+    /// Variables bound by the pattern itself
+    bindings: HashMap<String, Value>,
+    used_edges: Vec<EdgeKey>,
+    count: u64,
+    items: Vec<Value>,
+}
+
+/// One relationship traversal of a path: `edge` leads from node `from` to node `to`
+struct Step {
+    edge: usize,
+    from: usize,
+    to: usize,
+    direction: EdgeDirection,
+}
+
+impl<'a, S: StorageClient> PatternMatcher<'a, S> {
+    /// EXISTS only needs one match.
+    fn is_done(&self) -> bool {
+        self.subquery.kind == PatternSubqueryKind::Exists && self.count > 0
+    }
+
+    fn lookup(&self, variable: &str) -> Option<&Value> {
+        self.bindings
+            .get(variable)
+            .or_else(|| self.outer.get(variable))
+    }
+
+    fn eval_context(&self) -> DefaultExpressionContext {
+        let mut context = DefaultExpressionContext::new();
+        for (name, value) in self.outer.iter().chain(self.bindings.iter()) {
+            context.set_variable(name.clone(), value.clone());
+        }
+        context
+    }
+
+    fn evaluate(&self, expression: &Expression) -> DBResult<Value> {
+        ExpressionEvaluator::evaluate(expression, &mut self.eval_context())
+            .map_err(|e| DBError::query(e.to_string()))
+    }
+
+    fn properties_match(
+        &self,
+        properties: &[(String, Expression)],
+        actual: impl Fn(&str) -> Option<Value>,
+    ) -> DBResult<bool> {
+        for (name, expected) in properties {
+            let expected = self.evaluate(expected)?;
+            let equal = match actual(name) {
+                Some(value) => {
+                    BinaryOperationEvaluator::evaluate(&value, &BinaryOperator::Equal, &expected)
+                        .map_err(|e| DBError::query(e.to_string()))?
+                }
+                None => return Ok(false),
+            };
+            if !matches!(equal, Value::Bool(true)) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn node_matches(&self, node: &PatternNode, vertex: &Vertex) -> DBResult<bool> {
+        if !node.labels.iter().all(|label| vertex.has_tag(label)) {
+            return Ok(false);
+        }
+        self.properties_match(&node.properties, |name| {
+            vertex.get_property_any(name).cloned()
+        })
+    }
+
+    fn edge_matches(&self, pattern: &PatternEdge, edge: &Edge) -> DBResult<bool> {
+        if !pattern.edge_types.is_empty() && !pattern.edge_types.contains(&edge.edge_type) {
+            return Ok(false);
+        }
+        if self.used_edges.contains(&edge_key(edge)) {
+            return Ok(false);
+        }
+        if let Some(Value::Edge(bound)) = pattern.variable.as_deref().and_then(|v| self.lookup(v)) {
+            if edge_key(bound) != edge_key(edge) {
+                return Ok(false);
+            }
+        }
+        self.properties_match(&pattern.properties, |name| edge.get_property(name).cloned())
+    }
+
+    /// Resolve a bound node variable to its vertex; `None` when it does not denote a vertex.
+    fn bound_vertex(&self, value: &Value) -> DBResult<Option<Vertex>> {
+        match value {
+            Value::Vertex(vertex) => Ok(Some(vertex.as_ref().clone())),
+            Value::Null(_) | Value::Empty => Ok(None),
+            other => match VertexId::try_from(other) {
+                Ok(vid) => Ok(self.storage.get_vertex(self.space, &vid)?),
+                Err(_) => Ok(None),
+            },
+        }
+    }
+
+    /// Start the path at the first node whose variable is already bound.
+    fn anchor(&self, path: &PatternPath) -> usize {
+        path.nodes
+            .iter()
+            .position(|n| {
+                n.variable
+                    .as_deref()
+                    .is_some_and(|v| self.lookup(v).is_some())
+            })
+            .unwrap_or(0)
+    }
+
+    fn anchor_candidates(&self, node: &PatternNode) -> DBResult<Vec<Vertex>> {
+        if let Some(value) = node.variable.as_deref().and_then(|v| self.lookup(v)) {
+            return Ok(self.bound_vertex(value)?.into_iter().collect());
+        }
+        Ok(match node.labels.first() {
+            Some(label) => self.storage.scan_vertices_by_tag(self.space, label)?,
+            None => self.storage.scan_vertices(self.space)?,
+        })
+    }
+
+    /// Bind a variable for the current branch; returns whether it was newly bound.
+    fn bind(&mut self, variable: &Option<String>, value: Value) -> Option<String> {
+        match variable {
+            Some(name) if self.lookup(name).is_none() => {
+                self.bindings.insert(name.clone(), value);
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn unbind(&mut self, bound: Option<String>) {
+        if let Some(name) = bound {
+            self.bindings.remove(&name);
+        }
+    }
+
+    fn match_paths(&mut self, path_index: usize) -> DBResult<()> {
+        let subquery = self.subquery;
+        let Some(path) = subquery.paths.get(path_index) else {
+            return self.emit();
+        };
+
+        let anchor = self.anchor(path);
+        let mut steps = Vec::new();
+        for i in anchor..path.edges.len() {
+            steps.push(Step {
+                edge: i,
+                from: i,
+                to: i + 1,
+                direction: path.edges[i].direction,
+            });
+        }
+        for i in (0..anchor).rev() {
+            steps.push(Step {
+                edge: i,
+                from: i + 1,
+                to: i,
+                direction: reverse(path.edges[i].direction),
+            });
+        }
+
+        for vertex in self.anchor_candidates(&path.nodes[anchor])? {
+            if self.is_done() {
+                break;
+            }
+            if !self.node_matches(&path.nodes[anchor], &vertex)? {
+                continue;
+            }
+            let mut vids = vec![None; path.nodes.len()];
+            vids[anchor] = Some(vertex.vid);
+            let bound = self.bind(
+                &path.nodes[anchor].variable,
+                Value::Vertex(Box::new(vertex)),
+            );
+            self.expand(path_index, &steps, 0, &mut vids)?;
+            self.unbind(bound);
+        }
+        Ok(())
+    }
+
+    fn expand(
+        &mut self,
+        path_index: usize,
+        steps: &[Step],
+        step_index: usize,
+        vids: &mut [Option<VertexId>],
+    ) -> DBResult<()> {
+        let Some(step) = steps.get(step_index) else {
+            return self.match_paths(path_index + 1);
+        };
+        let subquery = self.subquery;
+        let path = &subquery.paths[path_index];
+        let edge_pattern = &path.edges[step.edge];
+        let target = &path.nodes[step.to];
+        let Some(from) = vids[step.from] else {
+            return Ok(());
+        };
+
+        let mut edges = match self.edge_index {
+            Some(index) => index.edges(&from, step.direction),
+            None => self
+                .storage
+                .get_node_edges(self.space, &from, step.direction)?,
+        };
+        if step.direction == EdgeDirection::Both {
+            // Self-loops are reported from both ends.
+            let mut seen = Vec::new();
+            edges.retain(|e| {
+                let key = edge_key(e);
+                let fresh = !seen.contains(&key);
+                seen.push(key);
+                fresh
+            });
+        }
+
+        for edge in edges {
+            if self.is_done() {
+                break;
+            }
+            if !self.edge_matches(edge_pattern, &edge)? {
+                continue;
+            }
+            let neighbor = if edge.src == from { edge.dst } else { edge.src };
+
+            let bound_target = target.variable.as_deref().and_then(|v| self.lookup(v));
+            let vertex = match bound_target {
+                Some(value) => match self.bound_vertex(value)? {
+                    Some(vertex) if vertex.vid == neighbor => Some(vertex),
+                    _ => None,
+                },
+                None => self.storage.get_vertex(self.space, &neighbor)?,
+            };
+            let Some(vertex) = vertex else {
+                continue;
+            };
+            if !self.node_matches(target, &vertex)? {
+                continue;
+            }
+
+            self.used_edges.push(edge_key(&edge));
+            vids[step.to] = Some(neighbor);
+            let bound_edge = self.bind(&edge_pattern.variable, Value::Edge(Box::new(edge)));
+            let bound_node = self.bind(&target.variable, Value::Vertex(Box::new(vertex)));
+
+            self.expand(path_index, steps, step_index + 1, vids)?;
+
+            self.unbind(bound_node);
+            self.unbind(bound_edge);
+            vids[step.to] = None;
+            self.used_edges.pop();
+        }
+        Ok(())
+    }
+
+    /// Every path matched: apply the WHERE filter and record the match.
+    fn emit(&mut self) -> DBResult<()> {
+        if let Some(filter) = &self.subquery.filter {
+            if !matches!(self.evaluate(filter)?, Value::Bool(true)) {
+                return Ok(());
+            }
+        }
+        if let Some(map) = &self.subquery.map {
+            let item = self.evaluate(map)?;
+            self.items.push(item);
+        }
+        self.count += 1;
+        Ok(())
+    }
+}
+
+fn reverse(direction: EdgeDirection) -> EdgeDirection {
+    match direction {
+        EdgeDirection::Out => EdgeDirection::In,
+        EdgeDirection::In => EdgeDirection::Out,
+        EdgeDirection::Both => EdgeDirection::Both,
+    }
+}
+
+impl<S: StorageClient + Send + 'static> InputExecutor<S> for PatternSubqueryExecutor<S> {
+    fn set_input(&mut self, input: ExecutorEnum<S>) {
+        self.input_executor = Some(Box::new(input));
+    }
+
+    fn get_input(&self) -> Option<&ExecutorEnum<S>> {
+        self.input_executor.as_ref().map(|b| b.as_ref())
+    }
+}
+
+impl_executor_with_execute!(PatternSubqueryExecutor, execute_pattern_subquery);
+impl_has_storage!(PatternSubqueryExecutor);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MockStorage;
+
+    fn knows_subquery(kind: PatternSubqueryKind) -> PatternSubquery {
+        let mut path = PatternPath::new(PatternNode::new(Some("a".to_string())));
+        let mut edge = PatternEdge::new(None, EdgeDirection::Out);
+        edge.edge_types.push("KNOWS".to_string());
+        path.push(edge, PatternNode::new(Some("b".to_string())));
+        let map = (kind == PatternSubqueryKind::Collect).then(|| Expression::variable("b"));
+        PatternSubquery::new(kind, vec![path], None, map)
+    }
+
+    fn run(kind: PatternSubqueryKind, output: PatternSubqueryOutput) -> DataSet {
+        let storage = Arc::new(RwLock::new(
+            MockStorage::new().expect("Failed to create MockStorage"),
+        ));
+        let col_names = match &output {
+            PatternSubqueryOutput::Column(alias) => vec![alias.clone()],
+            _ => Vec::new(),
+        };
+        let mut executor = PatternSubqueryExecutor::new(
+            1,
+            storage,
+            "test".to_string(),
+            knows_subquery(kind),
+            output,
+            false,
+            col_names,
+            Arc::new(ExpressionAnalysisContext::new()),
+        );
+        match executor
+            .execute()
+            .expect("Executor should execute successfully")
+        {
+            ExecutionResult::DataSet(dataset) => dataset,
+            _ => panic!("Expected DataSet result"),
+        }
+    }
+
+    #[test]
+    fn test_pattern_subquery_on_empty_graph() {
+        let count = run(
+            PatternSubqueryKind::Count,
+            PatternSubqueryOutput::Column("c".to_string()),
+        );
+        assert_eq!(count.rows, vec![vec![Value::BigInt(0)]]);
+
+        let collect = run(
+            PatternSubqueryKind::Collect,
+            PatternSubqueryOutput::Column("friends".to_string()),
+        );
+        assert_eq!(
+            collect.rows,
+            vec![vec![Value::list(List::from(Vec::<Value>::new()))]]
+        );
+    }
+
+    #[test]
+    fn test_semi_and_anti_output() {
+        assert!(
+            run(PatternSubqueryKind::Exists, PatternSubqueryOutput::Semi)
+                .rows
+                .is_empty()
+        );
+        assert_eq!(
+            run(PatternSubqueryKind::Exists, PatternSubqueryOutput::Anti)
+                .rows
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_edge_index_directions() {
+        let edge = Edge::new_empty(
+            VertexId::from_int64(1),
+            VertexId::from_int64(2),
+            "KNOWS".to_string(),
+            0,
+        );
+        let mut index = EdgeIndex::default();
+        index
+            .outgoing
+            .entry(edge.src)
+            .or_default()
+            .push(edge.clone());
+        index.incoming.entry(edge.dst).or_default().push(edge);
+
+        let one = VertexId::from_int64(1);
+        let two = VertexId::from_int64(2);
+        assert_eq!(index.edges(&one, EdgeDirection::Out).len(), 1);
+        assert!(index.edges(&one, EdgeDirection::In).is_empty());
+        assert_eq!(index.edges(&two, EdgeDirection::In).len(), 1);
+        assert_eq!(index.edges(&two, EdgeDirection::Both).len(), 1);
+    }
+}
@@ -136,6 +136,17 @@ impl ParallelConfig {
         self.enable_parallel && total_size >= self.parallel_threshold
     }
 
+    /// Create a configuration that runs at most `parallelism` tasks at a time.
+    ///
+    /// A parallelism of 1 disables parallel computing.
+    pub fn with_parallelism(parallelism: usize) -> Self {
+        Self {
+            max_job_size: parallelism.max(1),
+            enable_parallel: parallelism > 1,
+            ..Self::default()
+        }
+    }
+
     /// Create a configuration suitable for small amounts of data.
     pub fn for_small_data() -> Self {
         Self {
@@ -347,6 +347,20 @@ impl BatchPlanAnalyzer {
                 self.analyze_recursive(n.input(), context, Some(node_id))
             }
             PlanNodeEnum::Unwind(n) => self.analyze_recursive(n.input(), context, Some(node_id)),
+            PlanNodeEnum::PatternSubquery(n) => {
+                self.analyze_recursive(n.input(), context, Some(node_id))
+            }
+            PlanNodeEnum::CallSubquery(n) => {
+                self.analyze_recursive(n.input(), context, Some(node_id))
+                    + self.analyze_recursive(n.body(), context, Some(node_id))
+            }
+            PlanNodeEnum::Foreach(n) => {
+                self.analyze_recursive(n.input(), context, Some(node_id))
+                    + n.body()
+                        .iter()
+                        .map(|body| self.analyze_recursive(body, context, Some(node_id)))
+                        .sum::<usize>()
+            }
             PlanNodeEnum::Remove(n) => self.analyze_recursive(n.input(), context, Some(node_id)),
             PlanNodeEnum::Materialize(n) => {
                 self.analyze_recursive(n.input(), context, Some(node_id))
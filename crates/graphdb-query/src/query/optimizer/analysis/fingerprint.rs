@@ -113,6 +113,19 @@ impl FingerprintCalculator {
             PlanNodeEnum::Unwind(n) => {
                 self.hash_single_input(n, hasher);
             }
+            PlanNodeEnum::PatternSubquery(n) => {
+                self.hash_single_input(n, hasher);
+            }
+            PlanNodeEnum::CallSubquery(n) => {
+                self.hash_single_input(n, hasher);
+                self.calculate_fingerprint(n.body()).hash(hasher);
+            }
+            PlanNodeEnum::Foreach(n) => {
+                self.hash_single_input(n, hasher);
+                for body in n.body() {
+                    self.calculate_fingerprint(body).hash(hasher);
+                }
+            }
             PlanNodeEnum::DataCollect(n) => {
                 self.hash_single_input(n, hasher);
             }
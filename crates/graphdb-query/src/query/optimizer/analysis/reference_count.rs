@@ -318,6 +318,28 @@ impl ReferenceCountAnalyzer {
                 }
                 total
             }
+            PlanNodeEnum::PatternSubquery(n) => {
+                let mut total = 1;
+                for dep in n.dependencies() {
+                    let count = self.analyze_recursive(dep, context, Some(node_id));
+                    total += count;
+                }
+                total
+            }
+            PlanNodeEnum::CallSubquery(n) => {
+                let mut total = 1;
+                total += self.analyze_recursive(n.input(), context, Some(node_id));
+                total += self.analyze_recursive(n.body(), context, Some(node_id));
+                total
+            }
+            PlanNodeEnum::Foreach(n) => {
+                let mut total = 1;
+                total += self.analyze_recursive(n.input(), context, Some(node_id));
+                for body in n.body() {
+                    total += self.analyze_recursive(body, context, Some(node_id));
+                }
+                total
+            }
             PlanNodeEnum::DataCollect(n) => {
                 let mut total = 1;
                 for dep in n.dependencies() {
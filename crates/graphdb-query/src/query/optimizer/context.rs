@@ -10,6 +10,7 @@ use crate::query::optimizer::analysis::{
 };
 use crate::query::optimizer::cost::{CostCalculator, CostModelConfig, SelectivityEstimator};
 use crate::query::optimizer::stats::StatisticsManager;
+use crate::query::parser::ast::QueryHints;
 use crate::query::validator::context::ExpressionAnalysisContext;
 
 /// Optimization Context
@@ -34,6 +35,8 @@ pub struct OptimizationContext {
     expression_analysis: Option<ExpressionAnalysis>,
     /// Cached batch plan analysis (computed once per optimization)
     batch_plan_analysis: Option<BatchPlanAnalysis>,
+    /// Optimizer hints of the statement being optimized
+    hints: QueryHints,
 }
 
 impl OptimizationContext {
@@ -54,6 +57,7 @@ impl OptimizationContext {
             reference_count_analysis: None,
             expression_analysis: None,
             batch_plan_analysis: None,
+            hints: QueryHints::default(),
         }
     }
 
@@ -112,6 +116,16 @@ impl OptimizationContext {
         self.batch_plan_analysis.as_ref()
     }
 
+    /// Set the optimizer hints of the statement.
+    pub fn set_hints(&mut self, hints: QueryHints) {
+        self.hints = hints;
+    }
+
+    /// Get the optimizer hints of the statement.
+    pub fn hints(&self) -> &QueryHints {
+        &self.hints
+    }
+
     /// Clear all cached analysis results.
     pub fn clear_cache(&mut self) {
         self.reference_count_analysis = None;
@@ -128,6 +128,27 @@ impl ChildAccessor for PlanNodeEnum {
                     None
                 }
             }
+            PlanNodeEnum::PatternSubquery(n) => {
+                if index == 0 {
+                    Some(n.input_mut())
+                } else {
+                    None
+                }
+            }
+            PlanNodeEnum::CallSubquery(n) => {
+                if index == 0 {
+                    Some(n.input_mut())
+                } else {
+                    None
+                }
+            }
+            PlanNodeEnum::Foreach(n) => {
+                if index == 0 {
+                    Some(n.input_mut())
+                } else {
+                    None
+                }
+            }
             PlanNodeEnum::Assign(n) => {
                 if index == 0 {
                     Some(n.input_mut())
@@ -156,6 +177,20 @@ impl ChildAccessor for PlanNodeEnum {
                     None
                 }
             }
+            PlanNodeEnum::PipeDeleteVertices(n) => {
+                if index == 0 {
+                    Some(n.input_mut())
+                } else {
+                    None
+                }
+            }
+            PlanNodeEnum::PipeDeleteEdges(n) => {
+                if index == 0 {
+                    Some(n.input_mut())
+                } else {
+                    None
+                }
+            }
             PlanNodeEnum::Union(n) => n.dependencies_mut().get_mut(index),
             PlanNodeEnum::Minus(n) => n.dependencies_mut().get_mut(index),
             PlanNodeEnum::Intersect(n) => n.dependencies_mut().get_mut(index),
@@ -344,6 +344,11 @@ impl ExpressionPrecomputationOptimizer {
             }
             // Aggregate expressions are deterministic if the argument is deterministic
             Expression::Aggregate { arg, .. } => self.check_expression_deterministic(arg),
+            // Pattern subqueries are deterministic if all nested expressions are deterministic
+            Expression::PatternSubquery(subquery) => subquery
+                .expressions()
+                .into_iter()
+                .all(|e| self.check_expression_deterministic(e)),
             // Predicate expressions are deterministic if all arguments are deterministic
             Expression::Predicate { args, .. } => args
                 .iter()
@@ -16,6 +16,7 @@
 //! - `materialization` – The optimization mechanism for materialized CTEs
 //! - `memory_budget` – Memory budget allocation
 //! - `expression_precomputation` – Expression precomputation optimizer
+//! - `worst_case_optimal_join` – Multiway join selection for cyclic patterns
 //!
 //! ## Usage Examples
 //!
@@ -41,6 +42,7 @@ pub mod topn_optimization;
 pub mod trait_def;
 pub mod traversal_direction;
 pub mod traversal_start;
+pub mod worst_case_optimal_join;
 
 pub use traversal_start::{
     CandidateStart, SelectionReason as TraversalSelectionReason, TraversalStartSelector,
@@ -86,6 +88,8 @@ pub use expression_precomputation::{
     PrecomputationDecision, PrecomputeReason,
 };
 
+pub use worst_case_optimal_join::{MultiwayJoinDecision, WorstCaseOptimalJoinOptimizer};
+
 // Re-export the CTE cache type from the cache module (for backward compatibility)
 pub use crate::query::cache::{
     CteCacheConfig, CteCacheDecision, CteCacheDecisionMaker, CteCacheEntry, CteCacheManager,
@@ -14,6 +14,13 @@
 //! 3. The complexity of the expressions should be less than 50 (avoid using complex expressions).
 //! 4. The subquery estimates that the number of rows is less than 1000 (based on statistical information).
 //!
+//! ## Pattern Subqueries
+//!
+//! `EXISTS { }` / `COUNT { }` / pattern comprehensions (PatternSubquery nodes) are decorrelated
+//! instead: a single-relationship pattern anchored on one outer node, whose relationship types
+//! are known to hold at most `max_subquery_rows` edges, fetches those edges once and probes them
+//! by hash for every outer row instead of expanding per row.
+//!
 //! ## Usage Examples
 //!
 //! ```rust
@@ -29,9 +36,15 @@ use crate::core::types::operators::BinaryOperator;
 use crate::core::types::ContextualExpression;
 use crate::core::Expression;
 use crate::query::optimizer::analysis::BatchPlanAnalysis;
+use crate::query::optimizer::context::OptimizationContext;
+use crate::query::optimizer::cost::child_accessor::ChildAccessor;
+use crate::query::optimizer::cost_based::trait_def::OptimizationStrategy;
+use crate::query::optimizer::error::OptimizeResult;
 use crate::query::optimizer::stats::StatisticsManager;
 use crate::query::planning::plan::core::nodes::PlanNodeEnum;
-use crate::query::planning::plan::core::nodes::{HashInnerJoinNode, PatternApplyNode};
+use crate::query::planning::plan::core::nodes::{
+    HashInnerJoinNode, PatternApplyNode, PatternSubqueryNode,
+};
 use crate::query::validator::context::ExpressionAnalysisContext;
 
 /// Decentralized decision-making using subqueries
@@ -278,6 +291,60 @@ impl SubqueryUnnestingOptimizer {
         build_cost + probe_cost
     }
 
+    /// Determine whether a pattern subquery should be decorrelated.
+    ///
+    /// The pattern must be one relationship with explicit types, anchored on exactly one node
+    /// bound by the outer query, and the statistics must know that those types hold no more
+    /// than `max_subquery_rows` edges.
+    pub fn should_decorrelate(&self, node: &PatternSubqueryNode) -> bool {
+        let subquery = node.subquery();
+        let [path] = subquery.paths.as_slice() else {
+            return false;
+        };
+        let [edge] = path.edges.as_slice() else {
+            return false;
+        };
+        if edge.edge_types.is_empty() {
+            return false;
+        }
+
+        let outer = node.outer_variables();
+        let anchored = outer.len() == 1
+            && path
+                .nodes
+                .iter()
+                .any(|n| n.variable.as_ref() == Some(&outer[0]));
+        if !anchored {
+            return false;
+        }
+
+        let edge_count: u64 = edge
+            .edge_types
+            .iter()
+            .map(|t| self.stats_manager.get_edge_count(t))
+            .sum();
+        edge_count > 0 && edge_count <= self.max_subquery_rows
+    }
+
+    /// Mark every eligible pattern subquery in the plan tree as decorrelated.
+    fn decorrelate_pattern_subqueries(&self, node: &mut PlanNodeEnum) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.get_child_mut(i) {
+                self.decorrelate_pattern_subqueries(child);
+            }
+        }
+
+        if let PlanNodeEnum::PatternSubquery(pattern_subquery) = node {
+            if self.should_decorrelate(pattern_subquery) {
+                log::debug!(
+                    "Decorrelating pattern subquery: {}",
+                    pattern_subquery.subquery().to_expression_string()
+                );
+                pattern_subquery.set_decorrelated(true);
+            }
+        }
+    }
+
     /// Perform the de-association transformation.
     ///
     /// # Parameters
@@ -501,10 +568,28 @@ impl SubqueryUnnestingOptimizer {
             Expression::Parameter(_) => expr.clone(),
             Expression::Literal(_) => expr.clone(),
             Expression::Vector(_) => expr.clone(),
+            Expression::PatternSubquery(subquery) => Expression::PatternSubquery(Box::new(
+                subquery.map_expressions(|e| self.replace_all_variables(e, new_var)),
+            )),
         }
     }
 }
 
+impl OptimizationStrategy for SubqueryUnnestingOptimizer {
+    fn apply(
+        &self,
+        mut node: PlanNodeEnum,
+        _ctx: &OptimizationContext,
+    ) -> OptimizeResult<PlanNodeEnum> {
+        self.decorrelate_pattern_subqueries(&mut node);
+        Ok(node)
+    }
+
+    fn name(&self) -> &str {
+        "SubqueryUnnestingOptimizer"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -549,4 +634,58 @@ mod tests {
         };
         assert!(!optimizer.is_simple_expression(&binary));
     }
+
+    #[test]
+    fn test_pattern_subquery_decorrelation() {
+        use crate::core::types::expr::{
+            PatternEdge, PatternNode, PatternPath, PatternSubquery, PatternSubqueryKind,
+        };
+        use crate::core::types::graph_schema::EdgeDirection;
+        use crate::core::types::YieldColumn;
+        use crate::query::optimizer::stats::EdgeTypeStatistics;
+        use crate::query::planning::plan::core::nodes::{
+            PatternSubqueryOutput, ProjectNode, StartNode,
+        };
+
+        let stats_manager = StatisticsManager::new();
+        let mut knows = EdgeTypeStatistics::new("KNOWS".to_string());
+        knows.edge_count = 200;
+        stats_manager.update_edge_stats(knows);
+        let optimizer = SubqueryUnnestingOptimizer::new(&stats_manager);
+
+        let ctx = std::sync::Arc::new(ExpressionAnalysisContext::new());
+        let id = ctx.register_expression(ExpressionMeta::new(Expression::variable("a")));
+        let input = ProjectNode::new(
+            PlanNodeEnum::Start(StartNode::new()),
+            vec![YieldColumn {
+                expression: ContextualExpression::new(id, ctx),
+                alias: "a".to_string(),
+                is_matched: false,
+            }],
+        )
+        .expect("project node");
+
+        let make_node = |edge_type: &str| {
+            let mut path = PatternPath::new(PatternNode::new(Some("a".to_string())));
+            let mut edge = PatternEdge::new(None, EdgeDirection::Out);
+            edge.edge_types.push(edge_type.to_string());
+            path.push(edge, PatternNode::new(Some("b".to_string())));
+            let subquery =
+                PatternSubquery::new(PatternSubqueryKind::Exists, vec![path], None, None);
+            PatternSubqueryNode::new(
+                PlanNodeEnum::Project(input.clone()),
+                "test",
+                subquery,
+                PatternSubqueryOutput::Semi,
+            )
+            .expect("pattern subquery node")
+        };
+
+        assert!(optimizer.should_decorrelate(&make_node("KNOWS")));
+        // No statistics: the size of the edge set is unknown
+        assert!(!optimizer.should_decorrelate(&make_node("LIKES")));
+        assert!(!optimizer
+            .with_max_rows(100)
+            .should_decorrelate(&make_node("KNOWS")));
+    }
 }
@@ -60,7 +60,7 @@ impl StrategyChain {
         ctx: &OptimizationContext,
     ) -> OptimizeResult<PlanNodeEnum> {
         for strategy in &self.strategies {
-            if !strategy.is_enabled() {
+            if !strategy.is_enabled() || ctx.hints().is_rule_disabled(strategy.name()) {
                 continue;
             }
             node = strategy.apply(node, ctx)?;
@@ -448,11 +448,7 @@ impl TraversalDirectionOptimizer {
 }
 
 impl OptimizationStrategy for TraversalDirectionOptimizer {
-    fn apply(
-        &self,
-        node: PlanNodeEnum,
-        _ctx: &OptimizationContext,
-    ) -> OptimizeResult<PlanNodeEnum> {
+    fn apply(&self, node: PlanNodeEnum, ctx: &OptimizationContext) -> OptimizeResult<PlanNodeEnum> {
         // Only optimize ExpandNode
         if let PlanNodeEnum::Expand(mut expand_node) = node {
             // Extract edge type from ExpandNode
@@ -462,11 +458,17 @@ impl OptimizationStrategy for TraversalDirectionOptimizer {
                 .cloned()
                 .unwrap_or_default();
 
+            // An EXPAND_DIRECTION hint on the edge type pins the direction
+            let explicit_direction = ctx
+                .hints()
+                .expand_direction(&edge_type)
+                .map(|direction| TraversalDirection::from_edge_direction(&direction));
+
             // Create direction context
             let direction_context = DirectionContext {
                 edge_type,
-                start_nodes: 1,            // Default to 1 start node
-                explicit_direction: None,  // No explicit direction specified
+                start_nodes: 1, // Default to 1 start node
+                explicit_direction,
                 allow_bidirectional: true, // Allow bidirectional by default
                 steps: 1,                  // Default to 1 step
             };
@@ -0,0 +1,349 @@
+//! Worst-case optimal join optimizer module
+//!
+//! Decide whether a cyclic MATCH pattern runs as a multiway join or as the
+//! expand + join plan the planner built for it.
+//!
+//! ## Optimization Strategies
+//!
+//! Expand + join materialises every open path of the cycle before the closing
+//! edge filters them, which explodes around super nodes. The multiway join only
+//! pays off when the degree distribution is skewed, so it is kept when an edge
+//! type of the pattern is heavily skewed or has a super node.
+//! The variables are then ordered so that the most constrained one is bound
+//! first and every later one is reached from a bound variable.
+//!
+//! ## Usage Examples
+//!
+//! ```rust
+//! use graphdb::query::optimizer::cost_based::WorstCaseOptimalJoinOptimizer;
+//!
+//! let optimizer = WorstCaseOptimalJoinOptimizer::new(cost_calculator);
+//! let decision = optimizer.decide(&multiway_join_node);
+//! ```
+
+use std::sync::Arc;
+
+use crate::query::optimizer::context::OptimizationContext;
+use crate::query::optimizer::cost::child_accessor::ChildAccessor;
+use crate::query::optimizer::cost::CostCalculator;
+use crate::query::optimizer::cost_based::trait_def::OptimizationStrategy;
+use crate::query::optimizer::error::{OptimizeError, OptimizeResult};
+use crate::query::optimizer::stats::EdgeTypeStatistics;
+use crate::query::planning::plan::core::nodes::{
+    JoinRelation, MultiwayJoinNode, PlanNodeEnum, StartNode,
+};
+
+/// Choice between the multiway join and its fallback plan
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultiwayJoinDecision {
+    /// Keep the multiway join
+    Multiway { skewed_edge_type: String },
+    /// Run the expand + join plan
+    Fallback,
+}
+
+/// Worst-case optimal join optimizer
+///
+/// Resolves the multiway join candidates of a plan using edge statistics
+#[derive(Debug)]
+pub struct WorstCaseOptimalJoinOptimizer {
+    cost_calculator: Arc<CostCalculator>,
+    /// Degree above which a vertex is a super node
+    super_node_threshold: f64,
+    /// Ratio of maximum to average degree above which a distribution is skewed
+    skew_ratio: f64,
+}
+
+impl WorstCaseOptimalJoinOptimizer {
+    /// Create a new worst-case optimal join optimizer.
+    pub fn new(cost_calculator: Arc<CostCalculator>) -> Self {
+        let thresholds = cost_calculator.config().strategy_thresholds;
+        Self {
+            cost_calculator,
+            super_node_threshold: thresholds.traversal_super_node_threshold,
+            skew_ratio: 10.0,
+        }
+    }
+
+    /// Setting the threshold for super nodes
+    pub fn with_super_node_threshold(mut self, threshold: f64) -> Self {
+        self.super_node_threshold = threshold;
+        self
+    }
+
+    /// Setting the ratio of maximum to average degree that counts as skew
+    pub fn with_skew_ratio(mut self, ratio: f64) -> Self {
+        self.skew_ratio = ratio;
+        self
+    }
+
+    /// Decide how a multiway join candidate runs.
+    pub fn decide(&self, node: &MultiwayJoinNode) -> MultiwayJoinDecision {
+        node.relations()
+            .iter()
+            .flat_map(|relation| self.relation_stats(relation))
+            .find(|stats| self.is_skewed(stats))
+            .map_or(MultiwayJoinDecision::Fallback, |stats| {
+                MultiwayJoinDecision::Multiway {
+                    skewed_edge_type: stats.edge_type,
+                }
+            })
+    }
+
+    /// Order the variables of a multiway join.
+    ///
+    /// The first variable is the one with the most pattern edges; each later
+    /// one has the most pattern edges to the variables already ordered. Ties go
+    /// to the variable with the lower average degree, then to the written order.
+    pub fn variable_order(&self, node: &MultiwayJoinNode) -> Vec<String> {
+        let relations = node.relations();
+        let mut remaining: Vec<&String> = node.variable_order().iter().collect();
+        let mut order: Vec<String> = Vec::with_capacity(remaining.len());
+
+        while !remaining.is_empty() {
+            let best = remaining
+                .iter()
+                .enumerate()
+                .map(|(i, variable)| {
+                    let links = relations
+                        .iter()
+                        .filter(|r| {
+                            r.touches(variable)
+                                && (order.is_empty() || order.iter().any(|bound| r.touches(bound)))
+                        })
+                        .count();
+                    (i, links, self.fan_out(relations, variable))
+                })
+                .min_by(|a, b| b.1.cmp(&a.1).then(a.2.total_cmp(&b.2)).then(a.0.cmp(&b.0)))
+                .map_or(0, |(i, _, _)| i);
+            order.push(remaining.remove(best).clone());
+        }
+        order
+    }
+
+    /// Statistics of the edge types a relation accepts
+    fn relation_stats(&self, relation: &JoinRelation) -> Vec<EdgeTypeStatistics> {
+        let stats_manager = self.cost_calculator.statistics_manager();
+        let edge_types = if relation.edge_types.is_empty() {
+            stats_manager.get_all_edge_types()
+        } else {
+            relation.edge_types.clone()
+        };
+        edge_types
+            .iter()
+            .filter_map(|edge_type| stats_manager.get_edge_stats(edge_type))
+            .collect()
+    }
+
+    fn is_skewed(&self, stats: &EdgeTypeStatistics) -> bool {
+        let max_degree = stats.max_out_degree.max(stats.max_in_degree) as f64;
+        stats.is_heavily_skewed()
+            || stats.max_in_degree as f64 > stats.avg_in_degree * self.skew_ratio
+            || max_degree >= self.super_node_threshold
+    }
+
+    /// Average number of neighbours `variable` has through its pattern edges
+    fn fan_out(&self, relations: &[JoinRelation], variable: &str) -> f64 {
+        relations
+            .iter()
+            .filter(|r| r.touches(variable))
+            .map(|r| {
+                self.relation_stats(r)
+                    .iter()
+                    .map(|stats| {
+                        if r.undirected {
+                            stats.avg_out_degree + stats.avg_in_degree
+                        } else if r.src == variable {
+                            stats.avg_out_degree
+                        } else {
+                            stats.avg_in_degree
+                        }
+                    })
+                    .fold(0.0, f64::max)
+            })
+            .sum()
+    }
+
+    /// Resolve the candidates in `node` and below it.
+    fn resolve(&self, node: &mut PlanNodeEnum) -> OptimizeResult<()> {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.get_child_mut(i) {
+                self.resolve(child)?;
+            }
+        }
+
+        let Some(multiway) = node.as_multiway_join().filter(|n| n.is_candidate()) else {
+            return Ok(());
+        };
+        let decision = self.decide(multiway);
+        let PlanNodeEnum::MultiwayJoin(mut multiway) = take_node(node) else {
+            unreachable!("node was checked to be a multiway join");
+        };
+        *node = match decision {
+            MultiwayJoinDecision::Multiway { skewed_edge_type } => {
+                log::debug!(
+                    "Edge type {} is skewed, keeping the multiway join",
+                    skewed_edge_type
+                );
+                multiway.set_variable_order(self.variable_order(&multiway));
+                multiway
+                    .into_chosen()
+                    .map_err(|e| OptimizeError::PlanOptimizationError(e.to_string()))?
+            }
+            MultiwayJoinDecision::Fallback => multiway.into_fallback(),
+        };
+        Ok(())
+    }
+}
+
+/// Replace every multiway join candidate left in the plan by its fallback.
+///
+/// Candidates stay in the plan when the cost-based phase is disabled or the
+/// strategy is switched off by a hint; they then run as expand + join.
+pub fn resolve_to_fallback(node: &mut PlanNodeEnum) {
+    for i in 0..node.child_count() {
+        if let Some(child) = node.get_child_mut(i) {
+            resolve_to_fallback(child);
+        }
+    }
+
+    if node.as_multiway_join().is_some_and(|n| n.is_candidate()) {
+        if let PlanNodeEnum::MultiwayJoin(multiway) = take_node(node) {
+            *node = multiway.into_fallback();
+        }
+    }
+}
+
+fn take_node(node: &mut PlanNodeEnum) -> PlanNodeEnum {
+    std::mem::replace(node, PlanNodeEnum::Start(StartNode::new()))
+}
+
+impl OptimizationStrategy for WorstCaseOptimalJoinOptimizer {
+    fn apply(
+        &self,
+        mut node: PlanNodeEnum,
+        _ctx: &OptimizationContext,
+    ) -> OptimizeResult<PlanNodeEnum> {
+        self.resolve(&mut node)?;
+        Ok(node)
+    }
+
+    fn name(&self) -> &str {
+        "WorstCaseOptimalJoinOptimizer"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::optimizer::stats::StatisticsManager;
+    use crate::query::optimizer::OptimizerEngine;
+    use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
+    use crate::query::planning::plan::core::nodes::ScanVerticesNode;
+
+    fn create_test_optimizer(stats: Vec<EdgeTypeStatistics>) -> WorstCaseOptimalJoinOptimizer {
+        let stats_manager = Arc::new(StatisticsManager::new());
+        for edge_stats in stats {
+            stats_manager.update_edge_stats(edge_stats);
+        }
+        let cost_calculator = Arc::new(CostCalculator::new(stats_manager));
+        WorstCaseOptimalJoinOptimizer::new(cost_calculator).with_super_node_threshold(1000.0)
+    }
+
+    fn edge_stats(edge_type: &str, avg_degree: f64, max_degree: u64) -> EdgeTypeStatistics {
+        let mut stats = EdgeTypeStatistics::new(edge_type.to_string());
+        stats.avg_out_degree = avg_degree;
+        stats.avg_in_degree = avg_degree;
+        stats.max_out_degree = max_degree;
+        stats.max_in_degree = max_degree;
+        stats
+    }
+
+    fn relation(src: &str, dst: &str, edge_type: &str) -> JoinRelation {
+        JoinRelation {
+            src: src.to_string(),
+            dst: dst.to_string(),
+            edge_alias: None,
+            edge_types: vec![edge_type.to_string()],
+            undirected: false,
+        }
+    }
+
+    /// `(a)-[:KNOWS]->(b)-[:KNOWS]->(c)-[:LIKES]->(a)` with a vertex scan as fallback
+    fn triangle() -> MultiwayJoinNode {
+        MultiwayJoinNode::new(
+            1,
+            "test",
+            vec![
+                relation("a", "b", "KNOWS"),
+                relation("b", "c", "KNOWS"),
+                relation("c", "a", "LIKES"),
+            ],
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            Vec::new(),
+            ScanVerticesNode::new(1, "test").into_enum(),
+        )
+    }
+
+    #[test]
+    fn test_skewed_edge_type_keeps_multiway_join() {
+        let optimizer = create_test_optimizer(vec![
+            edge_stats("KNOWS", 5.0, 8),
+            edge_stats("LIKES", 3.0, 5000),
+        ]);
+        assert_eq!(
+            optimizer.decide(&triangle()),
+            MultiwayJoinDecision::Multiway {
+                skewed_edge_type: "LIKES".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_uniform_or_unknown_degrees_fall_back() {
+        let optimizer = create_test_optimizer(vec![edge_stats("KNOWS", 5.0, 8)]);
+        assert_eq!(
+            optimizer.decide(&triangle()),
+            MultiwayJoinDecision::Fallback
+        );
+
+        let optimizer = create_test_optimizer(Vec::new());
+        assert_eq!(
+            optimizer.decide(&triangle()),
+            MultiwayJoinDecision::Fallback
+        );
+    }
+
+    #[test]
+    fn test_variable_order_prefers_low_degree() {
+        // Every variable has two pattern edges; c only fans out over the sparse LIKES edges
+        let mut knows = edge_stats("KNOWS", 50.0, 5000);
+        knows.avg_in_degree = 40.0;
+        let optimizer = create_test_optimizer(vec![knows, edge_stats("LIKES", 1.0, 2)]);
+        assert_eq!(optimizer.variable_order(&triangle()), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_apply_resolves_candidates() {
+        let optimizer = create_test_optimizer(vec![edge_stats("LIKES", 3.0, 5000)]);
+        let ctx = OptimizationContext::from(&OptimizerEngine::default());
+        let plan = optimizer
+            .apply(triangle().into_enum(), &ctx)
+            .expect("apply should succeed");
+        let multiway = plan.as_multiway_join().expect("multiway join is kept");
+        assert!(!multiway.is_candidate());
+
+        let optimizer = create_test_optimizer(Vec::new());
+        let plan = optimizer
+            .apply(triangle().into_enum(), &ctx)
+            .expect("apply should succeed");
+        assert!(plan.as_scan_vertices().is_some());
+    }
+
+    #[test]
+    fn test_resolve_to_fallback() {
+        let mut plan = triangle().into_enum();
+        resolve_to_fallback(&mut plan);
+        assert!(plan.as_scan_vertices().is_some());
+    }
+}
@@ -35,7 +35,10 @@
 
 use std::sync::Arc;
 
+use crate::query::cache::MaterializedViewManager;
+use crate::query::optimizer::cost_based::worst_case_optimal_join::resolve_to_fallback;
 use crate::query::optimizer::heuristic::PlanRewriter;
+use crate::query::optimizer::hints::check_hints;
 use crate::query::optimizer::{
     AggregateStrategySelector, BatchPlanAnalyzer, CostCalculator, CostModelConfig, CteCacheManager,
     MaterializationOptimizer, SelectivityEstimator, SelectivityFeedbackManager,
@@ -44,6 +47,14 @@ use crate::query::optimizer::{
 use crate::query::planning::plan::ExecutionPlan;
 use crate::query::validator::context::ExpressionAnalysisContext;
 
+/// Names of the strategies in the cost-based chain built by `apply_cost_based`
+const COST_BASED_STRATEGIES: &[&str] = &[
+    "MaterializationOptimizer",
+    "SubqueryUnnestingOptimizer",
+    "WorstCaseOptimalJoinOptimizer",
+    "TraversalDirectionOptimizer",
+];
+
 /// Optimizer engine
 ///
 /// A globally unique instance of the optimizer engine, responsible for coordinating and managing all components related to query optimization.
@@ -58,6 +69,8 @@ pub struct OptimizerEngine {
     selectivity_feedback_manager: Arc<SelectivityFeedbackManager>,
     /// CTE Cache Manager
     cte_cache_manager: Arc<CteCacheManager>,
+    /// Materialized View Manager
+    materialized_views: Arc<MaterializedViewManager>,
     /// Cost Calculator
     cost_calculator: Arc<CostCalculator>,
     /// Selective Estimator
@@ -165,6 +178,7 @@ impl OptimizerEngine {
             stats_manager,
             selectivity_feedback_manager,
             cte_cache_manager,
+            materialized_views: Arc::new(MaterializedViewManager::new()),
             cost_calculator,
             selectivity_estimator,
             sort_elimination_optimizer,
@@ -250,6 +264,11 @@ impl OptimizerEngine {
         &self.cte_cache_manager
     }
 
+    /// Obtaining the Materialized View Manager
+    pub fn materialized_views(&self) -> &MaterializedViewManager {
+        &self.materialized_views
+    }
+
     /// Set the stats manager on the CTE cache manager
     pub fn set_cte_cache_stats_manager(
         &self,
@@ -355,6 +374,21 @@ impl OptimizerEngine {
             log::debug!("Phase 2 completed successfully");
         }
 
+        // Multiway join candidates the cost-based phase did not decide run as expand + join
+        if let Some(root) = current_plan.root.as_mut() {
+            resolve_to_fallback(root);
+        }
+
+        // Report the hints the optimized plan does not follow
+        if !current_plan.hints.hints.is_empty() {
+            let known_rules: Vec<&str> = self
+                .heuristic_rewriter
+                .rule_names()
+                .chain(COST_BASED_STRATEGIES.iter().copied())
+                .collect();
+            check_hints(&mut current_plan, &known_rules);
+        }
+
         Ok(current_plan)
     }
 
@@ -370,10 +404,13 @@ impl OptimizerEngine {
         use crate::query::optimizer::context::OptimizationContext;
         use crate::query::optimizer::cost_based::trait_def::StrategyChain;
         use crate::query::optimizer::cost_based::MaterializationOptimizer;
+        use crate::query::optimizer::cost_based::SubqueryUnnestingOptimizer;
         use crate::query::optimizer::cost_based::TraversalDirectionOptimizer;
+        use crate::query::optimizer::cost_based::WorstCaseOptimalJoinOptimizer;
 
         // Create optimization context
         let mut ctx = OptimizationContext::from(self);
+        ctx.set_hints(plan.hints.clone());
 
         // Perform batch plan analysis if we have a root
         let mut current_plan = plan;
@@ -387,11 +424,18 @@ impl OptimizerEngine {
                 MaterializationOptimizer::new(self.stats_manager.as_ref());
             let traversal_direction_optimizer =
                 TraversalDirectionOptimizer::new(self.cost_calculator.clone());
+            let subquery_unnesting_optimizer =
+                SubqueryUnnestingOptimizer::new(self.stats_manager.as_ref());
+            let worst_case_optimal_join_optimizer =
+                WorstCaseOptimalJoinOptimizer::new(self.cost_calculator.clone());
 
             // Create strategy chain with all registered cost-based strategies
-            // Order matters: materialization first, then traversal direction
+            // Order matters: materialization first, then traversal direction, which
+            // sees the expand + join plans of the multiway joins that were not kept
             let chain = StrategyChain::new()
                 .add_strategy(Box::new(materialization_optimizer))
+                .add_strategy(Box::new(subquery_unnesting_optimizer))
+                .add_strategy(Box::new(worst_case_optimal_join_optimizer))
                 .add_strategy(Box::new(traversal_direction_optimizer));
 
             // Apply strategies to the plan root
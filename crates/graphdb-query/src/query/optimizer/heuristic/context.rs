@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::query::parser::ast::QueryHints;
 use crate::query::planning::plan::PlanNodeEnum;
 use crate::query::validator::context::ExpressionAnalysisContext;
 
@@ -26,6 +27,8 @@ pub struct RewriteContext {
     nodes_by_id: RefCell<HashMap<usize, Rc<RefCell<PlanNodeWrapper>>>>,
     /// Expression context
     expr_context: Arc<ExpressionAnalysisContext>,
+    /// Optimizer hints of the statement being rewritten
+    hints: QueryHints,
 }
 
 /// Plan Node Wrapper
@@ -55,6 +58,7 @@ impl Default for RewriteContext {
             plan_node_to_id: RefCell::new(HashMap::new()),
             nodes_by_id: RefCell::new(HashMap::new()),
             expr_context: Arc::new(ExpressionAnalysisContext::new()),
+            hints: QueryHints::default(),
         }
     }
 }
@@ -72,9 +76,21 @@ impl RewriteContext {
             plan_node_to_id: RefCell::new(HashMap::new()),
             nodes_by_id: RefCell::new(HashMap::new()),
             expr_context,
+            hints: QueryHints::default(),
         }
     }
 
+    /// Attach the optimizer hints of the statement.
+    pub fn with_hints(mut self, hints: QueryHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Whether a DISABLE_RULE (or JOIN_ORDER) hint switches the rule off
+    pub fn is_rule_disabled(&self, rule_name: &str) -> bool {
+        self.hints.is_rule_disabled(rule_name)
+    }
+
     /// Assign a new node ID
     pub fn allocate_node_id(&mut self) -> usize {
         let id = self.node_id_counter;
@@ -134,6 +150,15 @@ mod tests {
         assert_eq!(ctx.node_count(), 0);
     }
 
+    #[test]
+    fn test_disabled_rules() {
+        let hints = QueryHints::parse(&["DISABLE_RULE(EliminateFilter)".to_string()]);
+        let ctx = RewriteContext::new().with_hints(hints);
+        assert!(ctx.is_rule_disabled("EliminateFilterRule"));
+        assert!(!ctx.is_rule_disabled("PushFilterDownNodeRule"));
+        assert!(!RewriteContext::new().is_rule_disabled("EliminateFilterRule"));
+    }
+
     #[test]
     fn test_allocate_node_id() {
         let mut ctx = RewriteContext::new();
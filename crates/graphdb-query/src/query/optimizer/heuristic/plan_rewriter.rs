@@ -69,6 +69,11 @@ impl PlanRewriter {
         self.rules.len()
     }
 
+    /// Names of the registered rules
+    pub fn rule_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().map(|rule| rule.name())
+    }
+
     /// Clear rules
     pub fn clear_rules(&mut self) {
         self.rules.clear();
@@ -83,7 +88,7 @@ impl PlanRewriter {
             None => return Ok(plan),
         };
 
-        let mut ctx = RewriteContext::new();
+        let mut ctx = RewriteContext::new().with_hints(plan.hints.clone());
         let root_id = ctx.allocate_node_id();
         let new_root = self.rewrite_node(&mut ctx, &root, root_id)?;
 
@@ -116,7 +121,7 @@ impl PlanRewriter {
 
             for rule in &self.rules {
                 // Check whether the rules are matched.
-                if rule.matches(&current_node) {
+                if !ctx.is_rule_disabled(rule.name()) && rule.matches(&current_node) {
                     // Apply the rules.
                     if let Some(result) = rule.apply(ctx, &current_node)? {
                         if let Some(new_node) = result.first_new_node() {
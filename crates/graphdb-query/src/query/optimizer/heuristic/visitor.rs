@@ -28,6 +28,7 @@ use crate::query::planning::plan::core::nodes::graph_operations::graph_operation
     AssignNode, DataCollectNode, DedupNode, MaterializeNode, PatternApplyNode, RollUpApplyNode,
     UnionNode, UnwindNode,
 };
+use crate::query::planning::plan::core::nodes::graph_operations::pattern_subquery_node::PatternSubqueryNode;
 use crate::query::planning::plan::core::nodes::graph_operations::set_operations_node::{
     IntersectNode, MinusNode,
 };
@@ -35,6 +36,7 @@ use crate::query::planning::plan::core::nodes::join::join_node::{
     CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode, InnerJoinNode,
     LeftJoinNode, RightJoinNode, SemiJoinNode,
 };
+use crate::query::planning::plan::core::nodes::join::multiway_join_node::MultiwayJoinNode;
 use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
 use crate::query::planning::plan::core::nodes::operation::project_node::ProjectNode;
 use crate::query::planning::plan::core::nodes::operation::sample_node::SampleNode;
@@ -50,6 +52,9 @@ use crate::query::planning::plan::core::nodes::control_flow::control_flow_node::
     SelectNode,
 };
 use crate::query::planning::plan::core::nodes::control_flow::start_node::StartNode;
+use crate::query::planning::plan::core::nodes::control_flow::subquery_node::{
+    CallSubqueryNode, ForeachNode,
+};
 use crate::query::planning::plan::core::nodes::data_modification::{
     DeleteEdgesNode, DeleteIndexNode, DeleteTagsNode, DeleteVerticesNode, InsertEdgesNode,
     InsertVerticesNode, PipeDeleteEdgesNode, PipeDeleteVerticesNode, UpdateEdgesNode, UpdateNode,
@@ -65,11 +70,14 @@ use crate::query::planning::plan::core::nodes::search::vector::data_access::{
 };
 use crate::query::planning::plan::core::nodes::RemoveNode;
 
-use crate::query::planning::plan::core::nodes::access::IndexScanNode;
+use crate::query::planning::plan::core::nodes::access::{
+    IndexScanNode, MaterializedViewScanNode, ProcedureCallNode,
+};
 use crate::query::planning::plan::core::nodes::management::stats_nodes::ShowStatsNode;
 use crate::query::planning::plan::core::nodes::search::fulltext::data_access::{
     FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
 };
+use crate::query::planning::plan::core::nodes::search::hybrid::HybridSearchNode;
 use crate::query::planning::plan::core::nodes::traversal::{
     AllPathsNode, BFSShortestNode, MultiShortestPathNode, ShortestPathNode,
 };
@@ -207,6 +215,9 @@ impl<'a> PlanNodeVisitor for ChildRewriteVisitor<'a> {
         visit_dedup => DedupNode, Dedup,
         visit_unwind => UnwindNode, Unwind,
         visit_pattern_apply => PatternApplyNode, PatternApply,
+        visit_pattern_subquery => PatternSubqueryNode, PatternSubquery,
+        visit_call_subquery => CallSubqueryNode, CallSubquery,
+        visit_foreach => ForeachNode, Foreach,
         visit_roll_up_apply => RollUpApplyNode, RollUpApply,
         visit_data_collect => DataCollectNode, DataCollect,
         visit_assign => AssignNode, Assign,
@@ -262,12 +273,15 @@ impl<'a> PlanNodeVisitor for ChildRewriteVisitor<'a> {
         visit_pipe_delete_vertices => PipeDeleteVerticesNode, PipeDeleteVertices,
         visit_pipe_delete_edges => PipeDeleteEdgesNode, PipeDeleteEdges,
         visit_show_stats => ShowStatsNode, ShowStats,
+        visit_procedure_call => ProcedureCallNode, ProcedureCall,
+        visit_materialized_view_scan => MaterializedViewScanNode, MaterializedViewScan,
         visit_begin_transaction => BeginTransactionNode, BeginTransaction,
         visit_commit => CommitNode, Commit,
         visit_rollback => RollbackNode, Rollback,
         visit_fulltext_search => FulltextSearchNode, FulltextSearch,
         visit_fulltext_lookup => FulltextLookupNode, FulltextLookup,
         visit_match_fulltext => MatchFulltextNode, MatchFulltext,
+        visit_hybrid_search => HybridSearchNode, HybridSearch,
     );
 
     // Management nodes (parameterized sub-enums)
@@ -355,6 +369,17 @@ impl<'a> PlanNodeVisitor for ChildRewriteVisitor<'a> {
 
         Ok(PlanNodeEnum::Select(new_node))
     }
+
+    /// The fallback plan of an unresolved candidate is rewritten like any other input
+    fn visit_multiway_join(&mut self, node: &MultiwayJoinNode) -> Self::Result {
+        let mut new_node = node.clone();
+        if let Some(fallback) = node.fallback() {
+            let node_id = self.ctx.allocate_node_id();
+            let new_fallback = self.rewriter.rewrite_node(self.ctx, fallback, node_id)?;
+            new_node.set_fallback(new_fallback);
+        }
+        Ok(PlanNodeEnum::MultiwayJoin(new_node))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,202 @@
+//! Optimizer Hint Verification
+//!
+//! Hints are applied where the corresponding decision is made: the MATCH planner
+//! picks indexes, the join order and the start of a path, the heuristic rewriter
+//! and the cost-based chain skip disabled rules, and multi-hop traversals take
+//! their worker count from PARALLEL when they run. This module inspects the
+//! finished plan and records a warning for every hint that did not make it in,
+//! so EXPLAIN can report it.
+
+use crate::core::types::EdgeDirection;
+use crate::query::parser::ast::{QueryHint, QueryHints};
+use crate::query::planning::plan::{ExecutionPlan, PlanNodeEnum};
+
+/// Record a warning on `plan.hints` for each hint the final plan does not satisfy.
+///
+/// `known_rules` lists the rewrite rules and cost-based strategies a
+/// DISABLE_RULE hint may name.
+pub fn check_hints(plan: &mut ExecutionPlan, known_rules: &[&str]) {
+    let mut nodes = Vec::new();
+    if let Some(root) = plan.root.as_ref() {
+        collect_post_order(root, &mut nodes);
+    }
+
+    let warnings: Vec<String> = plan
+        .hints
+        .hints
+        .iter()
+        .filter_map(|hint| {
+            unsatisfied_reason(hint, &nodes, known_rules)
+                .map(|reason| format!("Hint `{}` not applied: {}", hint, reason))
+        })
+        .collect();
+    for warning in warnings {
+        plan.hints.warn(warning);
+    }
+}
+
+/// Children first, left to right, so inputs joined earlier come first
+fn collect_post_order<'p>(node: &'p PlanNodeEnum, nodes: &mut Vec<&'p PlanNodeEnum>) {
+    for child in node.children() {
+        collect_post_order(child, nodes);
+    }
+    nodes.push(node);
+}
+
+fn unsatisfied_reason(
+    hint: &QueryHint,
+    nodes: &[&PlanNodeEnum],
+    known_rules: &[&str],
+) -> Option<String> {
+    match hint {
+        QueryHint::UseIndex { variable, index } => {
+            let used = index_scans(nodes, variable).any(|name| name == index);
+            (!used).then(|| {
+                format!(
+                    "index {} does not exist on the label of {} or cannot serve any of its conditions",
+                    index, variable
+                )
+            })
+        }
+        QueryHint::NoIndex { variable, index } => index_scans(nodes, variable)
+            .find(|name| index.as_deref().is_none_or(|index| index == *name))
+            .map(|name| format!("{} is still read through index {}", variable, name)),
+        QueryHint::JoinOrder(variables) => {
+            let mut previous = 0;
+            for variable in variables {
+                let Some(position) = nodes
+                    .iter()
+                    .position(|node| node.col_names().contains(variable))
+                else {
+                    return Some(format!("variable {} is not bound by any pattern", variable));
+                };
+                if position < previous {
+                    return Some("the patterns cannot be joined in that order".to_string());
+                }
+                previous = position;
+            }
+            None
+        }
+        QueryHint::StartFrom(variable) => {
+            let starts = nodes.iter().any(|node| {
+                node.children().is_empty() && node.col_names().first() == Some(variable)
+            });
+            (!starts).then(|| {
+                format!(
+                    "{} must be the first or last named node of a path with no optional or repeated parts",
+                    variable
+                )
+            })
+        }
+        QueryHint::ExpandDirection {
+            variable,
+            direction,
+        } => {
+            let directions = expansion_directions(nodes, variable);
+            if directions.is_empty() {
+                Some(format!("no expansion of {} in the plan", variable))
+            } else if directions.iter().any(|d| d != direction) {
+                Some(format!(
+                    "{} cannot be expanded from that side; undirected edges and paths with optional or repeated parts keep their direction",
+                    variable
+                ))
+            } else {
+                None
+            }
+        }
+        QueryHint::DisableRule(name) => {
+            let known = known_rules
+                .iter()
+                .any(|rule| QueryHints::rule_name_matches(name, rule));
+            (!known).then(|| "no such rule or strategy".to_string())
+        }
+        QueryHint::Parallel(_) => {
+            let traverses = nodes
+                .iter()
+                .any(|node| matches!(node, PlanNodeEnum::ExpandAll(_) | PlanNodeEnum::Traverse(_)));
+            (!traverses).then(|| "no multi-hop traversal in the plan".to_string())
+        }
+    }
+}
+
+/// Names of the indexes that read `variable`
+fn index_scans<'p>(
+    nodes: &'p [&'p PlanNodeEnum],
+    variable: &'p str,
+) -> impl Iterator<Item = &'p str> + 'p {
+    nodes.iter().filter_map(move |node| match node {
+        PlanNodeEnum::IndexScan(scan)
+            if node.col_names().first().map(String::as_str) == Some(variable) =>
+        {
+            Some(scan.index_name())
+        }
+        _ => None,
+    })
+}
+
+/// Directions of the expansions over an edge variable or edge type
+fn expansion_directions(nodes: &[&PlanNodeEnum], variable: &str) -> Vec<EdgeDirection> {
+    let names_edge = |edge_types: &[String]| edge_types.iter().any(|t| t == variable);
+    nodes
+        .iter()
+        .filter_map(|node| match node {
+            PlanNodeEnum::ExpandAll(expand)
+                if node.col_names().get(1).map(String::as_str) == Some(variable)
+                    || names_edge(expand.edge_types()) =>
+            {
+                Some(EdgeDirection::from(expand.direction()))
+            }
+            PlanNodeEnum::Expand(expand) if names_edge(expand.edge_types()) => {
+                Some(expand.direction())
+            }
+            PlanNodeEnum::Traverse(traverse)
+                if traverse.edge_alias().map(String::as_str) == Some(variable)
+                    || names_edge(traverse.edge_types()) =>
+            {
+                Some(traverse.direction())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::planning::plan::core::nodes::access::index_scan::{IndexScanNode, ScanType};
+    use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
+
+    fn index_scan_plan(hints: &str) -> ExecutionPlan {
+        let mut scan = IndexScanNode::new(
+            1,
+            0,
+            0,
+            "idx_age".to_string(),
+            "person".to_string(),
+            ScanType::Range,
+        );
+        scan.set_col_names(vec!["p".to_string()]);
+        let mut plan = ExecutionPlan::new(Some(scan.into_enum()));
+        plan.hints = QueryHints::parse(&[hints.to_string()]);
+        plan
+    }
+
+    #[test]
+    fn test_satisfied_hints() {
+        let mut plan =
+            index_scan_plan("USE_INDEX(p, idx_age) START_FROM(p) DISABLE_RULE(EliminateFilter)");
+        check_hints(&mut plan, &["EliminateFilterRule"]);
+        assert!(plan.hints.warnings.is_empty(), "{:?}", plan.hints.warnings);
+    }
+
+    #[test]
+    fn test_unsatisfied_hints() {
+        let mut plan = index_scan_plan(
+            "USE_INDEX(p, idx_name) NO_INDEX(p) START_FROM(f) EXPAND_DIRECTION(e, IN) \
+             JOIN_ORDER(p, f) DISABLE_RULE(MakeItFast) PARALLEL(4)",
+        );
+        check_hints(&mut plan, &["EliminateFilterRule"]);
+        assert_eq!(plan.hints.warnings.len(), 7);
+        assert!(plan.hints.warnings[1].contains("idx_age"));
+    }
+}
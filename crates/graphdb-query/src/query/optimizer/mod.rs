@@ -28,6 +28,7 @@
 //! - `cost` – Cost calculation and estimation
 //! - `analysis` – Plan analysis utilities
 //! - `decision` – Optimization decision types
+//! - `hints` – Verification of the `/*+ ... */` optimizer hints against the final plan
 //!
 //! ## Usage Examples
 //!
@@ -49,6 +50,7 @@ pub mod cost;
 pub mod decision;
 pub mod engine;
 pub mod error;
+pub mod hints;
 pub mod stats;
 
 // Optimization phases
@@ -88,7 +90,7 @@ pub use cost_based::{
     SortEliminationOptimizer, SortKeepReason, SubqueryUnnestingOptimizer, TableInfo,
     TopNConversionReason, TraversalDirection, TraversalDirectionDecision,
     TraversalDirectionOptimizer, TraversalSelectionReason, TraversalStartSelector, UnnestDecision,
-    UnnestReason,
+    UnnestReason, WorstCaseOptimalJoinOptimizer,
 };
 
 // Re-export heuristic types
@@ -0,0 +1,326 @@
+//! Optimizer Hint Definitions
+//!
+//! Hints are written as `/*+ ... */` comments inside a query, for example
+//! `MATCH /*+ USE_INDEX(p, idx_person_age) JOIN_ORDER(a, b) */ ...`.
+//! They are advisory: a hint that is unknown, malformed or cannot be satisfied
+//! never fails the query, it only leaves a warning that EXPLAIN reports.
+
+use crate::core::types::EdgeDirection;
+use std::fmt;
+
+/// Largest degree of parallelism a PARALLEL hint may ask for
+pub const MAX_HINT_PARALLELISM: usize = 64;
+
+/// A single optimizer hint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryHint {
+    /// `USE_INDEX(var, index)`: read `var` through the named index
+    UseIndex { variable: String, index: String },
+    /// `NO_INDEX(var [, index])`: do not read `var` through the index (or any index)
+    NoIndex {
+        variable: String,
+        index: Option<String>,
+    },
+    /// `JOIN_ORDER(a, b, ...)`: join the patterns binding these variables in this order
+    JoinOrder(Vec<String>),
+    /// `START_FROM(var)`: start the path traversal from this node variable
+    StartFrom(String),
+    /// `EXPAND_DIRECTION(edge, IN | OUT)`: expand the edge from the given side
+    ExpandDirection {
+        variable: String,
+        direction: EdgeDirection,
+    },
+    /// `DISABLE_RULE(name)`: skip a rewrite rule or cost-based strategy
+    DisableRule(String),
+    /// `PARALLEL(n)`: expand multi-hop traversals with up to `n` worker threads
+    Parallel(usize),
+}
+
+impl fmt::Display for QueryHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryHint::UseIndex { variable, index } => {
+                write!(f, "USE_INDEX({}, {})", variable, index)
+            }
+            QueryHint::NoIndex {
+                variable,
+                index: Some(index),
+            } => write!(f, "NO_INDEX({}, {})", variable, index),
+            QueryHint::NoIndex {
+                variable,
+                index: None,
+            } => write!(f, "NO_INDEX({})", variable),
+            QueryHint::JoinOrder(variables) => write!(f, "JOIN_ORDER({})", variables.join(", ")),
+            QueryHint::StartFrom(variable) => write!(f, "START_FROM({})", variable),
+            QueryHint::ExpandDirection {
+                variable,
+                direction,
+            } => {
+                let direction = match direction {
+                    EdgeDirection::In => "IN",
+                    EdgeDirection::Out => "OUT",
+                    EdgeDirection::Both => "BOTH",
+                };
+                write!(f, "EXPAND_DIRECTION({}, {})", variable, direction)
+            }
+            QueryHint::DisableRule(name) => write!(f, "DISABLE_RULE({})", name),
+            QueryHint::Parallel(parallelism) => write!(f, "PARALLEL({})", parallelism),
+        }
+    }
+}
+
+/// The hints attached to a statement, together with the warnings collected
+/// while parsing them and, later, while checking them against the final plan
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryHints {
+    pub hints: Vec<QueryHint>,
+    pub warnings: Vec<String>,
+}
+
+impl QueryHints {
+    /// Parse the bodies of the hint comments of a statement.
+    pub fn parse(comments: &[String]) -> Self {
+        let mut result = Self::default();
+        for comment in comments {
+            let mut rest = comment.trim();
+            while !rest.is_empty() {
+                let Some(open) = rest.find('(') else {
+                    result.warn(format!("Ignored hint `{}`: missing argument list", rest));
+                    break;
+                };
+                let Some(close) = rest[open..].find(')').map(|pos| open + pos) else {
+                    result.warn(format!("Ignored hint `{}`: missing `)`", rest));
+                    break;
+                };
+                let name = rest[..open].trim();
+                let args: Vec<String> = rest[open + 1..close]
+                    .split(',')
+                    .map(|arg| arg.trim().trim_matches('`').to_string())
+                    .filter(|arg| !arg.is_empty())
+                    .collect();
+                let text = &rest[..=close];
+                match Self::parse_hint(name, args) {
+                    Ok(hint) => result.hints.push(hint),
+                    Err(reason) => result.warn(format!("Ignored hint `{}`: {}", text, reason)),
+                }
+                rest =
+                    rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            }
+        }
+        result
+    }
+
+    fn parse_hint(name: &str, mut args: Vec<String>) -> Result<QueryHint, String> {
+        let arity = |expected: &[usize], args: &[String]| {
+            if expected.contains(&args.len()) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected {:?} arguments, got {}",
+                    expected,
+                    args.len()
+                ))
+            }
+        };
+        match name.to_uppercase().as_str() {
+            "USE_INDEX" => {
+                arity(&[2], &args)?;
+                let index = args.pop().unwrap_or_default();
+                let variable = args.pop().unwrap_or_default();
+                Ok(QueryHint::UseIndex { variable, index })
+            }
+            "NO_INDEX" => {
+                arity(&[1, 2], &args)?;
+                let index = if args.len() == 2 { args.pop() } else { None };
+                let variable = args.pop().unwrap_or_default();
+                Ok(QueryHint::NoIndex { variable, index })
+            }
+            "JOIN_ORDER" => {
+                if args.len() < 2 {
+                    return Err("at least two variables are required".to_string());
+                }
+                Ok(QueryHint::JoinOrder(args))
+            }
+            "START_FROM" => {
+                arity(&[1], &args)?;
+                Ok(QueryHint::StartFrom(args.pop().unwrap_or_default()))
+            }
+            "EXPAND_DIRECTION" => {
+                arity(&[2], &args)?;
+                let direction = match args[1].to_uppercase().as_str() {
+                    "IN" => EdgeDirection::In,
+                    "OUT" => EdgeDirection::Out,
+                    other => return Err(format!("direction must be IN or OUT, got {}", other)),
+                };
+                Ok(QueryHint::ExpandDirection {
+                    variable: args.swap_remove(0),
+                    direction,
+                })
+            }
+            "DISABLE_RULE" => {
+                arity(&[1], &args)?;
+                Ok(QueryHint::DisableRule(args.pop().unwrap_or_default()))
+            }
+            "PARALLEL" => {
+                arity(&[1], &args)?;
+                match args[0].parse::<usize>() {
+                    Ok(parallelism) if (1..=MAX_HINT_PARALLELISM).contains(&parallelism) => {
+                        Ok(QueryHint::Parallel(parallelism))
+                    }
+                    _ => Err(format!(
+                        "degree of parallelism must be an integer from 1 to {}, got {}",
+                        MAX_HINT_PARALLELISM, args[0]
+                    )),
+                }
+            }
+            _ => Err("unknown hint".to_string()),
+        }
+    }
+
+    /// Record a warning for a hint that was ignored or could not be honoured.
+    pub fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty() && self.warnings.is_empty()
+    }
+
+    /// Index requested for `variable` by USE_INDEX
+    pub fn use_index(&self, variable: &str) -> Option<&str> {
+        self.hints.iter().find_map(|hint| match hint {
+            QueryHint::UseIndex { variable: v, index } if v == variable => Some(index.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Whether NO_INDEX leaves `index` available for `variable`
+    pub fn allows_index(&self, variable: &str, index: &str) -> bool {
+        !self.hints.iter().any(|hint| match hint {
+            QueryHint::NoIndex {
+                variable: v,
+                index: excluded,
+            } => v == variable && excluded.as_deref().is_none_or(|excluded| excluded == index),
+            _ => false,
+        })
+    }
+
+    /// Variables listed by JOIN_ORDER
+    pub fn join_order(&self) -> Option<&[String]> {
+        self.hints.iter().find_map(|hint| match hint {
+            QueryHint::JoinOrder(variables) => Some(variables.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Node variable requested by START_FROM
+    pub fn start_from(&self) -> Option<&str> {
+        self.hints.iter().find_map(|hint| match hint {
+            QueryHint::StartFrom(variable) => Some(variable.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Direction requested by EXPAND_DIRECTION for an edge variable or edge type
+    pub fn expand_direction(&self, variable: &str) -> Option<EdgeDirection> {
+        self.hints.iter().find_map(|hint| match hint {
+            QueryHint::ExpandDirection {
+                variable: v,
+                direction,
+            } if v == variable => Some(*direction),
+            _ => None,
+        })
+    }
+
+    /// Degree of parallelism requested by PARALLEL
+    pub fn parallelism(&self) -> Option<usize> {
+        self.hints.iter().find_map(|hint| match hint {
+            QueryHint::Parallel(parallelism) => Some(*parallelism),
+            _ => None,
+        })
+    }
+
+    /// Whether a rewrite rule or cost-based strategy is switched off.
+    ///
+    /// Names match case-insensitively and may omit the `Rule` / `Optimizer`
+    /// suffix. JOIN_ORDER pins the join order, so it also turns off join reordering.
+    pub fn is_rule_disabled(&self, name: &str) -> bool {
+        self.hints.iter().any(|hint| match hint {
+            QueryHint::DisableRule(disabled) => Self::rule_name_matches(disabled, name),
+            QueryHint::JoinOrder(_) => name == "JoinReorderRule",
+            _ => false,
+        })
+    }
+
+    /// Compare a hinted rule name with the full name of a rule or strategy.
+    pub fn rule_name_matches(hinted: &str, name: &str) -> bool {
+        let hinted = hinted.to_lowercase();
+        let name = name.to_lowercase();
+        hinted == name
+            || ["rule", "optimizer"]
+                .iter()
+                .any(|suffix| name.strip_suffix(suffix) == Some(hinted.as_str()))
+    }
+}
+
+impl fmt::Display for QueryHints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hints: Vec<String> = self.hints.iter().map(|hint| hint.to_string()).collect();
+        write!(f, "{}", hints.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(comment: &str) -> QueryHints {
+        QueryHints::parse(&[comment.to_string()])
+    }
+
+    #[test]
+    fn test_parse_hints() {
+        let hints = parse(
+            "use_index(p, idx_age) NO_INDEX(f) JOIN_ORDER(a, b, c), START_FROM(`v`) \
+             EXPAND_DIRECTION(e, in) DISABLE_RULE(PushFilterDownTraverse) parallel(8)",
+        );
+        assert!(hints.warnings.is_empty());
+        assert_eq!(hints.use_index("p"), Some("idx_age"));
+        assert!(!hints.allows_index("f", "idx_name"));
+        assert!(hints.allows_index("p", "idx_name"));
+        assert_eq!(
+            hints.join_order(),
+            Some(&["a".to_string(), "b".to_string(), "c".to_string()][..])
+        );
+        assert_eq!(hints.start_from(), Some("v"));
+        assert_eq!(hints.expand_direction("e"), Some(EdgeDirection::In));
+        assert_eq!(hints.parallelism(), Some(8));
+        assert_eq!(
+            hints.to_string(),
+            "USE_INDEX(p, idx_age) NO_INDEX(f) JOIN_ORDER(a, b, c) START_FROM(v) \
+             EXPAND_DIRECTION(e, IN) DISABLE_RULE(PushFilterDownTraverse) PARALLEL(8)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_hints_become_warnings() {
+        let hints = parse(
+            "FAST_PLEASE(p) USE_INDEX(p) EXPAND_DIRECTION(e, BOTH) PARALLEL(0) PARALLEL(many) \
+             START_FROM(v",
+        );
+        assert!(hints.hints.is_empty());
+        assert_eq!(hints.warnings.len(), 6);
+    }
+
+    #[test]
+    fn test_rule_disabling() {
+        let hints = parse("DISABLE_RULE(pushfilterdowntraverse) DISABLE_RULE(TraversalDirection)");
+        assert!(hints.is_rule_disabled("PushFilterDownTraverseRule"));
+        assert!(hints.is_rule_disabled("TraversalDirectionOptimizer"));
+        assert!(!hints.is_rule_disabled("PushFilterDownExpandAllRule"));
+        assert!(!hints.is_rule_disabled("JoinReorderRule"));
+        assert!(parse("JOIN_ORDER(a, b)").is_rule_disabled("JoinReorderRule"));
+    }
+}
@@ -0,0 +1,86 @@
+//! Hybrid Search AST Definitions
+//!
+//! `SEARCH HYBRID` runs a full-text query and a vector query against the same tag
+//! and fuses the two ranked lists into one.
+
+use crate::core::types::expr::contextual::ContextualExpression;
+use crate::core::types::span::Span;
+use crate::query::parser::ast::fulltext::FulltextQueryExpr;
+use crate::query::parser::ast::vector::{VectorQueryExpr, VectorYieldClause};
+
+/// SEARCH HYBRID statement
+///
+/// ```text
+/// SEARCH HYBRID FULLTEXT <index> MATCH <query> VECTOR <index> WITH <vector query>
+///     [FUSION RRF [(k = 60)] | FUSION WEIGHTED [(fulltext = 0.5, vector = 0.5)]]
+///     [WHERE <expression>] [LIMIT n] [OFFSET m] [YIELD <columns>]
+/// ```
+#[derive(Debug, Clone)]
+pub struct HybridSearchStatement {
+    pub span: Span,
+    pub fulltext_index: String,
+    pub fulltext_query: FulltextQueryExpr,
+    pub vector_index: String,
+    pub vector_query: VectorQueryExpr,
+    pub fusion: FusionMethod,
+    /// Filter over the fused hits, evaluated against the matched vertex
+    pub where_clause: Option<ContextualExpression>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub yield_clause: Option<VectorYieldClause>,
+}
+
+/// How the full-text and vector rankings are combined
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FusionMethod {
+    /// Reciprocal rank fusion: `sum(1 / (k + rank))` over the lists a hit appears in
+    Rrf { k: f64 },
+    /// Weighted sum of the min-max normalised scores of both lists
+    Weighted { fulltext: f64, vector: f64 },
+}
+
+impl FusionMethod {
+    /// Rank constant used by RRF when none is given
+    pub const DEFAULT_RRF_K: f64 = 60.0;
+    /// Weight of each list when WEIGHTED gives none
+    pub const DEFAULT_WEIGHT: f64 = 0.5;
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FusionMethod::Rrf { .. } => "RRF",
+            FusionMethod::Weighted { .. } => "WEIGHTED",
+        }
+    }
+}
+
+impl Default for FusionMethod {
+    fn default() -> Self {
+        FusionMethod::Rrf {
+            k: Self::DEFAULT_RRF_K,
+        }
+    }
+}
+
+impl HybridSearchStatement {
+    /// Columns returned when the statement has no YIELD clause
+    pub const DEFAULT_COLUMNS: [&'static str; 4] =
+        ["vid", "score", "fulltext_score", "vector_score"];
+
+    /// Number of hits returned when the statement has no LIMIT
+    pub const DEFAULT_LIMIT: usize = 10;
+
+    /// Output column names, taking YIELD aliases into account
+    pub fn output_columns(&self) -> Vec<String> {
+        match &self.yield_clause {
+            Some(yield_clause) => yield_clause
+                .items
+                .iter()
+                .map(|item| item.alias.clone().unwrap_or_else(|| item.expr.clone()))
+                .collect(),
+            None => Self::DEFAULT_COLUMNS
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+        }
+    }
+}
@@ -20,6 +20,13 @@ pub mod fulltext;
 // Vector search definitions
 pub mod vector;
 
+// Hybrid (full-text + vector) search definitions
+pub mod hybrid;
+
+// Optimizer hint definitions
+pub mod hint;
+pub use hint::{QueryHint, QueryHints};
+
 // Utility functions
 pub mod utils;
 pub use utils::*;
@@ -5,6 +5,8 @@
 use std::sync::Arc;
 
 pub use super::fulltext::*;
+use super::hint::QueryHints;
+pub use super::hybrid::{FusionMethod, HybridSearchStatement};
 pub use super::pattern::*;
 pub use super::types::*;
 pub use super::vector::{
@@ -13,7 +15,7 @@ pub use super::vector::{
 };
 use crate::core::types::expr::analysis_utils::collect_variables_from_contextual;
 use crate::core::types::expr::contextual::ContextualExpression;
-use crate::core::types::PropertyDef;
+use crate::core::types::{ConstraintKind, PropertyDef};
 use crate::query::validator::context::ExpressionAnalysisContext;
 
 /// AST Packaging Type – Contains the context of statements and expressions
@@ -24,12 +26,29 @@ use crate::query::validator::context::ExpressionAnalysisContext;
 pub struct Ast {
     pub stmt: Stmt,
     pub expr_context: Arc<ExpressionAnalysisContext>,
+    /// Optimizer hints written as `/*+ ... */` comments in the statement
+    pub hints: QueryHints,
 }
 
 impl Ast {
     /// Create a new AST.
     pub fn new(stmt: Stmt, expr_context: Arc<ExpressionAnalysisContext>) -> Self {
-        Self { stmt, expr_context }
+        Self {
+            stmt,
+            expr_context,
+            hints: QueryHints::default(),
+        }
+    }
+
+    /// Attach optimizer hints.
+    pub fn with_hints(mut self, hints: QueryHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Obtain the optimizer hints.
+    pub fn hints(&self) -> &QueryHints {
+        &self.hints
     }
 
     /// Obtain statement references
@@ -69,6 +88,9 @@ pub enum Stmt {
     Insert(InsertStmt),
     Merge(MergeStmt),
     Unwind(UnwindStmt),
+    Call(CallStmt),
+    CallSubquery(CallSubqueryStmt),
+    Foreach(ForeachStmt),
     Return(ReturnStmt),
     With(WithStmt),
     Yield(YieldStmt),
@@ -84,6 +106,9 @@ pub enum Stmt {
     ChangePassword(ChangePasswordStmt),
     Grant(GrantStmt),
     Revoke(RevokeStmt),
+    CreateRole(CreateRoleStmt),
+    GrantPrivilege(GrantPrivilegeStmt),
+    ApiKey(ApiKeyStmt),
     DescribeUser(DescribeUserStmt),
     ShowUsers(ShowUsersStmt),
     ShowRoles(ShowRolesStmt),
@@ -111,6 +136,12 @@ pub enum Stmt {
     SearchVector(SearchVectorStatement),
     LookupVector(LookupVector),
     MatchVector(MatchVector),
+    // Hybrid search statements
+    HybridSearch(HybridSearchStatement),
+    // Index sync dead letter queue management
+    SyncDlq(SyncDlqStmt),
+    // Materialized views
+    MaterializedView(MaterializedViewStmt),
     // Transaction statements
     BeginTransaction(BeginTransactionStmt),
     CommitTransaction(CommitTransactionStmt),
@@ -139,6 +170,9 @@ impl Stmt {
             Stmt::Insert(s) => s.span,
             Stmt::Merge(s) => s.span,
             Stmt::Unwind(s) => s.span,
+            Stmt::Call(s) => s.span,
+            Stmt::CallSubquery(s) => s.span,
+            Stmt::Foreach(s) => s.span,
             Stmt::Return(s) => s.span,
             Stmt::With(s) => s.span,
             Stmt::Yield(s) => s.span,
@@ -154,6 +188,9 @@ impl Stmt {
             Stmt::ChangePassword(s) => s.span,
             Stmt::Grant(s) => s.span,
             Stmt::Revoke(s) => s.span,
+            Stmt::CreateRole(s) => s.span,
+            Stmt::GrantPrivilege(s) => s.span,
+            Stmt::ApiKey(s) => s.span,
             Stmt::DescribeUser(s) => s.span,
             Stmt::ShowUsers(s) => s.span,
             Stmt::ShowRoles(s) => s.span,
@@ -181,6 +218,12 @@ impl Stmt {
             Stmt::SearchVector(s) => s.span,
             Stmt::LookupVector(s) => s.span,
             Stmt::MatchVector(s) => s.span,
+            // Hybrid search statements
+            Stmt::HybridSearch(s) => s.span,
+            // Index sync dead letter queue management
+            Stmt::SyncDlq(s) => s.span,
+            // Materialized views
+            Stmt::MaterializedView(s) => s.span,
             // Transaction statements
             Stmt::BeginTransaction(s) => s.span,
             Stmt::CommitTransaction(s) => s.span,
@@ -215,6 +258,9 @@ impl Stmt {
             Stmt::Insert(_) => "INSERT",
             Stmt::Merge(_) => "MERGE",
             Stmt::Unwind(_) => "UNWIND",
+            Stmt::Call(_) => "CALL",
+            Stmt::CallSubquery(_) => "CALL SUBQUERY",
+            Stmt::Foreach(_) => "FOREACH",
             Stmt::Return(_) => "RETURN",
             Stmt::With(_) => "WITH",
             Stmt::Yield(_) => "YIELD",
@@ -230,6 +276,13 @@ impl Stmt {
             Stmt::ChangePassword(_) => "CHANGE PASSWORD",
             Stmt::Grant(_) => "GRANT",
             Stmt::Revoke(_) => "REVOKE",
+            Stmt::CreateRole(_) => "CREATE ROLE",
+            Stmt::GrantPrivilege(_) => "GRANT PRIVILEGE",
+            Stmt::ApiKey(s) => match s.action {
+                ApiKeyAction::Create { .. } => "CREATE API KEY",
+                ApiKeyAction::Revoke { .. } => "REVOKE API KEY",
+                ApiKeyAction::Show { .. } => "SHOW API KEYS",
+            },
             Stmt::DescribeUser(_) => "DESCRIBE USER",
             Stmt::ShowUsers(_) => "SHOW USERS",
             Stmt::ShowRoles(_) => "SHOW ROLES",
@@ -257,6 +310,22 @@ impl Stmt {
             Stmt::SearchVector(_) => "SEARCH VECTOR",
             Stmt::LookupVector(_) => "LOOKUP VECTOR",
             Stmt::MatchVector(_) => "MATCH VECTOR",
+            // Hybrid search statements
+            Stmt::HybridSearch(_) => "SEARCH HYBRID",
+            // Index sync dead letter queue management
+            Stmt::SyncDlq(s) => match s.action {
+                SyncDlqAction::Show => "SHOW SYNC DLQ",
+                SyncDlqAction::Retry(_) => "RETRY SYNC DLQ",
+                SyncDlqAction::Purge { .. } => "PURGE SYNC DLQ",
+            },
+            // Materialized views
+            Stmt::MaterializedView(s) => match s.action {
+                MaterializedViewAction::Create { .. } => "CREATE MATERIALIZED VIEW",
+                MaterializedViewAction::Drop { .. } => "DROP MATERIALIZED VIEW",
+                MaterializedViewAction::Refresh { .. } => "REFRESH MATERIALIZED VIEW",
+                MaterializedViewAction::Show => "SHOW MATERIALIZED VIEWS",
+                MaterializedViewAction::Scan { .. } => "MATCH VIEW",
+            },
             // Transaction statements
             Stmt::BeginTransaction(_) => "BEGIN TRANSACTION",
             Stmt::CommitTransaction(_) => "COMMIT TRANSACTION",
@@ -373,6 +442,24 @@ impl Stmt {
             _ => None,
         }
     }
+    pub fn as_call(&self) -> Option<&CallStmt> {
+        match self {
+            Stmt::Call(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_call_subquery(&self) -> Option<&CallSubqueryStmt> {
+        match self {
+            Stmt::CallSubquery(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_foreach(&self) -> Option<&ForeachStmt> {
+        match self {
+            Stmt::Foreach(s) => Some(s),
+            _ => None,
+        }
+    }
     pub fn as_return(&self) -> Option<&ReturnStmt> {
         match self {
             Stmt::Return(s) => Some(s),
@@ -463,6 +550,24 @@ impl Stmt {
             _ => None,
         }
     }
+    pub fn as_create_role(&self) -> Option<&CreateRoleStmt> {
+        match self {
+            Stmt::CreateRole(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_grant_privilege(&self) -> Option<&GrantPrivilegeStmt> {
+        match self {
+            Stmt::GrantPrivilege(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_api_key(&self) -> Option<&ApiKeyStmt> {
+        match self {
+            Stmt::ApiKey(s) => Some(s),
+            _ => None,
+        }
+    }
     pub fn as_describe_user(&self) -> Option<&DescribeUserStmt> {
         match self {
             Stmt::DescribeUser(s) => Some(s),
@@ -607,6 +712,24 @@ impl Stmt {
             _ => None,
         }
     }
+    pub fn as_hybrid_search(&self) -> Option<&HybridSearchStatement> {
+        match self {
+            Stmt::HybridSearch(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_sync_dlq(&self) -> Option<&SyncDlqStmt> {
+        match self {
+            Stmt::SyncDlq(s) => Some(s),
+            _ => None,
+        }
+    }
+    pub fn as_materialized_view(&self) -> Option<&MaterializedViewStmt> {
+        match self {
+            Stmt::MaterializedView(s) => Some(s),
+            _ => None,
+        }
+    }
 }
 
 /// Query statement
@@ -682,6 +805,13 @@ pub enum CreateTarget {
         on: String,
         properties: Vec<String>,
     },
+    /// Property constraint on a tag: UNIQUE or NOT NULL
+    Constraint {
+        name: String,
+        tag: String,
+        kind: ConstraintKind,
+        properties: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -823,6 +953,70 @@ pub struct UpdateStmt {
     pub yield_clause: Option<YieldClause>,
 }
 
+/// CALL subquery statement
+///
+/// `UNWIND $rows AS r CALL { CREATE (:Person {name: r.name}) } IN TRANSACTIONS OF 500 ROWS`
+///
+/// The body runs once per input row with the row's variables bound.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallSubqueryStmt {
+    pub span: Span,
+    pub body: Box<Stmt>,
+    /// `IN TRANSACTIONS`: commit the rows in batches instead of in the enclosing transaction
+    pub in_transactions: Option<InTransactions>,
+}
+
+/// `IN TRANSACTIONS [OF <n> ROWS] [ON ERROR CONTINUE | BREAK | FAIL]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InTransactions {
+    /// Rows committed per transaction
+    pub batch_size: usize,
+    pub on_error: OnErrorAction,
+}
+
+impl InTransactions {
+    pub const DEFAULT_BATCH_SIZE: usize = 1000;
+    /// Columns of the per-batch statistics returned by `CALL { } IN TRANSACTIONS`
+    pub const STATISTICS_COLUMNS: [&'static str; 4] = ["batch", "rows", "committed", "error"];
+}
+
+impl Default for InTransactions {
+    fn default() -> Self {
+        Self {
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            on_error: OnErrorAction::Fail,
+        }
+    }
+}
+
+/// What a failed batch of `CALL { } IN TRANSACTIONS` does to the rest of the statement
+///
+/// The failed batch is always rolled back; batches committed before stay committed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnErrorAction {
+    /// Go on with the next batch
+    Continue,
+    /// Skip the remaining batches, the statement still succeeds
+    Break,
+    /// Skip the remaining batches and fail the statement
+    #[default]
+    Fail,
+}
+
+/// FOREACH statement
+///
+/// `FOREACH (name IN r.names | CREATE (:Person {name: name}))`
+///
+/// Runs the update clauses once per list element; the input rows pass through unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeachStmt {
+    pub span: Span,
+    pub variable: String,
+    pub list: ContextualExpression,
+    /// SET, CREATE, MERGE, DELETE, REMOVE or nested FOREACH clauses
+    pub body: Vec<Stmt>,
+}
+
 /// Update target
 #[derive(Debug, Clone, PartialEq)]
 pub enum UpdateTarget {
@@ -977,6 +1171,7 @@ pub enum ShowTarget {
     Users,
     Roles,
     Stats,
+    Constraints,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -1141,6 +1336,59 @@ pub struct KillQueryStmt {
     pub plan_id: i64,
 }
 
+/// SHOW / RETRY / PURGE SYNC DLQ statement
+///
+/// Manages the dead letter queue of index sync operations that failed after all retries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncDlqStmt {
+    pub span: Span,
+    pub action: SyncDlqAction,
+}
+
+/// Action of a SYNC DLQ statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SyncDlqAction {
+    /// SHOW SYNC DLQ
+    Show,
+    /// RETRY SYNC DLQ <id> | ALL (`None` retries every unrecovered entry)
+    Retry(Option<u64>),
+    /// PURGE SYNC DLQ [RECOVERED]
+    Purge { recovered_only: bool },
+}
+
+/// Materialized view statement
+///
+/// CREATE / DROP / REFRESH MATERIALIZED VIEW, SHOW MATERIALIZED VIEWS and MATCH VIEW.
+#[derive(Debug, Clone)]
+pub struct MaterializedViewStmt {
+    pub span: Span,
+    pub action: MaterializedViewAction,
+}
+
+/// Action of a materialized view statement
+#[derive(Debug, Clone)]
+pub enum MaterializedViewAction {
+    /// CREATE MATERIALIZED VIEW [IF NOT EXISTS] <name> AS <query>
+    Create {
+        name: String,
+        /// Definition text, as written after AS
+        query: String,
+        statement: Box<Stmt>,
+        if_not_exists: bool,
+    },
+    /// DROP MATERIALIZED VIEW [IF EXISTS] <name>
+    Drop { name: String, if_exists: bool },
+    /// REFRESH MATERIALIZED VIEW <name>
+    Refresh { name: String },
+    /// SHOW MATERIALIZED VIEWS
+    Show,
+    /// MATCH VIEW <name> [YIELD ...]
+    Scan {
+        name: String,
+        yield_clause: Option<YieldClause>,
+    },
+}
+
 /// The `SHOW CONFIGS` statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShowConfigsStmt {
@@ -1207,6 +1455,19 @@ pub struct UnwindStmt {
     pub skip: Option<usize>,
 }
 
+/// CALL statement
+///
+/// `CALL db.labels() YIELD label AS l WHERE l STARTS WITH 'P'`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallStmt {
+    pub span: Span,
+    /// Qualified procedure name, such as `db.labels`
+    pub procedure: String,
+    pub args: Vec<ContextualExpression>,
+    /// Without YIELD every output column of the procedure is returned
+    pub yield_clause: Option<YieldClause>,
+}
+
 /// The RETURN statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReturnStmt {
@@ -1440,6 +1701,7 @@ pub enum DropTarget {
         space_name: String,
         index_name: String,
     },
+    Constraint(String),
 }
 
 /// The `DESCRIBE` statement is used to describe the type of a space, tag, or edge.
@@ -1471,11 +1733,15 @@ pub struct AlterStmt {
 }
 
 /// Attribute modification definition (used for the CHANGE operation)
+///
+/// `old_name` and `new_name` may be equal when only the type changes.
+/// `allow_lossy` is set by a trailing `FORCE`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PropertyChange {
     pub old_name: String,
     pub new_name: String,
     pub data_type: super::types::DataType,
+    pub allow_lossy: bool,
 }
 
 /// ALTER target
@@ -1517,6 +1783,27 @@ pub struct AlterUserStmt {
     pub password: Option<String>,
     pub new_role: Option<String>,
     pub is_locked: Option<bool>,
+    pub quota: UserQuotaOptions,
+}
+
+/// Per-user resource limits set by `ALTER USER ... WITH MAX_QUERIES_PER_HOUR <n> ...`
+///
+/// A limit of 0 removes the limit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserQuotaOptions {
+    pub max_queries_per_hour: Option<i32>,
+    pub max_updates_per_hour: Option<i32>,
+    pub max_connections_per_hour: Option<i32>,
+    pub max_user_connections: Option<i32>,
+}
+
+impl UserQuotaOptions {
+    pub fn is_empty(&self) -> bool {
+        self.max_queries_per_hour.is_none()
+            && self.max_updates_per_hour.is_none()
+            && self.max_connections_per_hour.is_none()
+            && self.max_user_connections.is_none()
+    }
 }
 
 /// The `DROP USER` statement
@@ -1591,6 +1878,60 @@ pub struct RevokeStmt {
     pub username: String,
 }
 
+/// The `CREATE ROLE` statement
+///
+/// Creates a custom role that can hold tag/edge/property level grants and inherit
+/// the grants of other custom roles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRoleStmt {
+    pub span: Span,
+    pub role_name: String,
+    /// Roles listed after `INHERITS`
+    pub parents: Vec<String>,
+    pub if_not_exists: bool,
+}
+
+/// Fine-grained `GRANT` statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrantPrivilegeStmt {
+    pub span: Span,
+    pub kind: GrantPrivilegeKind,
+}
+
+/// API key management statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKeyStmt {
+    pub span: Span,
+    pub action: ApiKeyAction,
+}
+
+/// What an API key statement does
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiKeyAction {
+    /// `CREATE API KEY FOR <user> [SCOPES (<permission>, ...)] [EXPIRES <seconds>]`
+    Create {
+        username: String,
+        scopes: Vec<crate::core::Permission>,
+        expires_in_secs: Option<u64>,
+    },
+    /// `REVOKE API KEY '<key_id>'`
+    Revoke { key_id: String },
+    /// `SHOW API KEYS [FOR <user>]`
+    Show { username: Option<String> },
+}
+
+/// What a fine-grained `GRANT` statement grants
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrantPrivilegeKind {
+    /// `GRANT READ|WRITE ON TAG t[(props)] | EDGE e TO <role>`
+    Privilege {
+        grant: crate::core::PrivilegeGrant,
+        role_name: String,
+    },
+    /// `GRANT ROLE <custom role> TO <user>`
+    Role { role_name: String, username: String },
+}
+
 /// “DESCRIBE USER” statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct DescribeUserStmt {
@@ -55,6 +55,8 @@ pub enum TokenKind {
     By,
     Skip,
     Unwind,
+    Call,
+    Foreach,
     Optional,
     Distinct,
     All,
@@ -340,6 +342,8 @@ impl TokenKindExt for TokenKind {
                 | TokenKind::By
                 | TokenKind::Skip
                 | TokenKind::Unwind
+                | TokenKind::Call
+                | TokenKind::Foreach
                 | TokenKind::Optional
                 | TokenKind::Distinct
                 | TokenKind::All
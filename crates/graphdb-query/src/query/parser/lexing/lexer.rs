@@ -16,6 +16,7 @@ pub struct Lexer<'a> {
     column: usize,
     current_token: Token,
     errors: Vec<LexError>,
+    hints: Vec<String>,
 }
 
 impl<'a> Lexer<'a> {
@@ -29,6 +30,7 @@ impl<'a> Lexer<'a> {
             column: 0,
             current_token: Token::new(Tk::Eof, String::new(), 0, 0),
             errors: Vec::new(),
+            hints: Vec::new(),
         };
         lexer.current_token = lexer.next_token();
         lexer
@@ -44,6 +46,7 @@ impl<'a> Lexer<'a> {
             column: 0,
             current_token: Token::new(Tk::Eof, String::new(), 0, 0),
             errors: Vec::new(),
+            hints: Vec::new(),
         };
         lexer.read_char();
         lexer.current_token = lexer.next_token();
@@ -86,6 +89,16 @@ impl<'a> Lexer<'a> {
         &self.current_token
     }
 
+    /// Bodies of the `/*+ ... */` optimizer hint comments seen so far
+    pub fn hint_comments(&self) -> &[String] {
+        &self.hints
+    }
+
+    /// Input text after the most recently produced token
+    pub fn remaining_input(&self) -> String {
+        self.input.chars().skip(self.position).collect()
+    }
+
     fn skip_whitespace(&mut self) {
         while let Some(&ch) = self.peek_char() {
             if ch == ' ' || ch == '\t' || ch == '\r' || ch == '\n' {
@@ -325,6 +338,8 @@ impl<'a> Lexer<'a> {
             "BY" => Tk::By,
             "SKIP" => Tk::Skip,
             "UNWIND" => Tk::Unwind,
+            "CALL" => Tk::Call,
+            "FOREACH" => Tk::Foreach,
             "OPTIONAL" => Tk::Optional,
             "DISTINCT" => Tk::Distinct,
             "ALL" => Tk::All,
@@ -527,6 +542,26 @@ impl<'a> Lexer<'a> {
                         // Block comment: /* ... */
                         self.read_char(); // consume /
                         self.read_char(); // consume *
+                        if let Some(&'+') = self.peek_char() {
+                            // Optimizer hint: /*+ ... */, may span several lines
+                            self.read_char();
+                            let mut body = String::new();
+                            loop {
+                                match self.read_char() {
+                                    Some('*') if self.peek_char() == Some(&'/') => {
+                                        self.read_char();
+                                        self.hints.push(body.trim().to_string());
+                                        return Ok(());
+                                    }
+                                    Some(ch) => body.push(ch),
+                                    None => {
+                                        let error = LexError::unterminated_comment(start_position);
+                                        self.add_error(error.clone());
+                                        return Err(error);
+                                    }
+                                }
+                            }
+                        }
                         loop {
                             match self.peek_char() {
                                 Some(&'*') => {
@@ -592,7 +627,9 @@ impl<'a> Lexer<'a> {
 
         if let Some(&ch) = self.peek_char() {
             if ch == '/' || ch == '-' {
-                if let Ok(()) = self.skip_comment() {
+                let hint_count = self.hints.len();
+                // A hint comment is always followed by the statement it applies to
+                if let (Ok(()), true) = (self.skip_comment(), self.hints.len() == hint_count) {
                     if let Some(&ch) = self.peek_char() {
                         if ch == '\n' || ch == '\0' {
                             return Token::new(Tk::Eof, String::new(), self.line, self.column);
@@ -1063,6 +1100,21 @@ mod tests {
         assert!(lexer.has_errors());
     }
 
+    #[test]
+    fn test_hint_comment() {
+        let input = "MATCH /*+ USE_INDEX(p, idx_age)\n JOIN_ORDER(a, b) */ (p) RETURN p";
+        let mut lexer = Lexer::new(input);
+        assert_eq!(lexer.current_token.kind, Tk::Match);
+
+        lexer.advance();
+        assert_eq!(lexer.current_token.kind, Tk::LParen);
+        assert_eq!(
+            lexer.hint_comments(),
+            &["USE_INDEX(p, idx_age)\n JOIN_ORDER(a, b)".to_string()]
+        );
+        assert!(!lexer.has_errors());
+    }
+
     #[test]
     fn test_integer_literals() {
         let input = "42 100 0";
@@ -2,12 +2,13 @@
 //!
 //! Responsible for parsing statements in the Data Definition Language (DDL), including CREATE, DROP, ALTER, DESC, etc.
 
-use crate::core::types::PropertyDef;
+use crate::core::types::{ConstraintKind, PropertyDef};
 use crate::query::parser::ast::stmt::*;
 use crate::query::parser::ast::types::DataType;
 use crate::query::parser::core::error::{ParseError, ParseErrorKind};
 use crate::query::parser::parsing::expr_parser::ExprParser;
 use crate::query::parser::parsing::parse_context::ParseContext;
+use crate::query::parser::parsing::user_parser::UserParser;
 use crate::query::parser::TokenKind;
 
 /// Tag/Edge definitions result type alias
@@ -420,6 +421,74 @@ impl DdlParser {
         }
     }
 
+    /// Analysis of the CREATE CONSTRAINT statement (the CREATE token has already been consumed).
+    ///
+    /// CREATE CONSTRAINT [IF NOT EXISTS] name {UNIQUE | NOT NULL} (tag.prop[, prop2 ...])
+    pub fn parse_create_constraint_after_create(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: crate::query::parser::ast::types::Span,
+    ) -> Result<Stmt, ParseError> {
+        ctx.next_token(); // consume CONSTRAINT
+        let mut if_not_exists = false;
+        if ctx.match_token(TokenKind::If) {
+            ctx.expect_token(TokenKind::Not)?;
+            ctx.expect_token(TokenKind::Exists)?;
+            if_not_exists = true;
+        }
+        let name = ctx.expect_identifier()?;
+
+        let kind = if ctx.check_keyword("UNIQUE") {
+            ctx.next_token();
+            ConstraintKind::Unique
+        } else if ctx.match_token(TokenKind::Not) {
+            ctx.expect_token(TokenKind::Null)?;
+            ConstraintKind::NotNull
+        } else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken,
+                "Expected UNIQUE or NOT NULL".to_string(),
+                ctx.current_position(),
+            ));
+        };
+
+        // The first property names the tag; later ones may omit it.
+        ctx.expect_token(TokenKind::LParen)?;
+        let tag = ctx.expect_identifier()?;
+        ctx.expect_token(TokenKind::Dot)?;
+        let mut properties = vec![ctx.expect_identifier()?];
+        while ctx.match_token(TokenKind::Comma) {
+            let mut prop = ctx.expect_identifier()?;
+            if ctx.match_token(TokenKind::Dot) {
+                if prop != tag {
+                    return Err(ParseError::new(
+                        ParseErrorKind::SyntaxError,
+                        format!(
+                            "All properties of a constraint must belong to tag '{}', found '{}'",
+                            tag, prop
+                        ),
+                        ctx.current_position(),
+                    ));
+                }
+                prop = ctx.expect_identifier()?;
+            }
+            properties.push(prop);
+        }
+        ctx.expect_token(TokenKind::RParen)?;
+
+        let end_span = ctx.current_span();
+        Ok(Stmt::Create(CreateStmt {
+            span: ctx.merge_span(start_span.start, end_span.end),
+            target: CreateTarget::Constraint {
+                name,
+                tag,
+                kind,
+                properties,
+            },
+            if_not_exists,
+        }))
+    }
+
     /// Parse the DROP statement
     pub fn parse_drop_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
         let start_span = ctx.current_span();
@@ -430,6 +499,38 @@ impl DdlParser {
             return crate::query::parser::parsing::fulltext_parser::parse_drop_fulltext_index_after_drop(ctx);
         }
 
+        if ctx.check_keyword("MATERIALIZED") {
+            ctx.next_token();
+            ctx.consume_keyword("VIEW")?;
+            let mut if_exists = false;
+            if ctx.match_token(TokenKind::If) {
+                ctx.expect_token(TokenKind::Exists)?;
+                if_exists = true;
+            }
+            let name = ctx.expect_identifier()?;
+            let end_span = ctx.current_span();
+            return Ok(Stmt::MaterializedView(MaterializedViewStmt {
+                span: ctx.merge_span(start_span.start, end_span.end),
+                action: MaterializedViewAction::Drop { name, if_exists },
+            }));
+        }
+
+        if ctx.check_keyword("CONSTRAINT") {
+            ctx.next_token();
+            let mut if_exists = false;
+            if ctx.match_token(TokenKind::If) {
+                ctx.expect_token(TokenKind::Exists)?;
+                if_exists = true;
+            }
+            let name = ctx.expect_identifier()?;
+            let end_span = ctx.current_span();
+            return Ok(Stmt::Drop(DropStmt {
+                span: ctx.merge_span(start_span.start, end_span.end),
+                target: DropTarget::Constraint(name),
+                if_exists,
+            }));
+        }
+
         let target = if ctx.match_token(TokenKind::Space) {
             let mut if_exists = false;
             if ctx.match_token(TokenKind::If) {
@@ -727,10 +828,12 @@ impl DdlParser {
                     let new_name = ctx.expect_identifier()?;
                     ctx.expect_token(TokenKind::Colon)?;
                     let data_type = self.parse_data_type(ctx)?;
+                    let allow_lossy = ctx.match_token(TokenKind::Force);
                     changes.push(PropertyChange {
                         old_name,
                         new_name,
                         data_type,
+                        allow_lossy,
                     });
                     if !ctx.match_token(TokenKind::Comma) {
                         break;
@@ -760,12 +863,14 @@ impl DdlParser {
         let mut is_locked = None;
 
         // Analyzing the WITH PASSWORD or SET clauses
+        let mut quota = UserQuotaOptions::default();
         if ctx.match_token(TokenKind::With) {
             if ctx.match_token(TokenKind::Password) {
                 password = Some(ctx.expect_string_literal()?);
             } else if ctx.match_token(TokenKind::Role) {
                 new_role = Some(ctx.expect_identifier()?);
             }
+            quota = UserParser::new().parse_quota_options(ctx)?;
         }
 
         // The SET ROLE = ... and SET LOCKED = ... syntax are also supported.
@@ -780,6 +885,18 @@ impl DdlParser {
             }
         }
 
+        // ACCOUNT LOCK | ACCOUNT UNLOCK
+        if ctx.check_keyword("ACCOUNT") {
+            ctx.next_token();
+            if ctx.check_keyword("UNLOCK") {
+                ctx.next_token();
+                is_locked = Some(false);
+            } else {
+                ctx.consume_keyword("LOCK")?;
+                is_locked = Some(true);
+            }
+        }
+
         let end_span = ctx.current_span();
         let span = ctx.merge_span(start_span.start, end_span.end);
 
@@ -789,6 +906,7 @@ impl DdlParser {
             password,
             new_role,
             is_locked,
+            quota,
         }))
     }
 
@@ -1178,6 +1296,10 @@ impl DdlParser {
                 ctx.next_token();
                 Ok(DataType::Geography)
             }
+            TokenKind::List => {
+                ctx.next_token();
+                Ok(DataType::List)
+            }
             TokenKind::KeywordVector => {
                 ctx.next_token();
                 if ctx.current_token().kind == TokenKind::LParen {
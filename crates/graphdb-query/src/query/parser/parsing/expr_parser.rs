@@ -5,7 +5,11 @@
 
 use std::sync::Arc;
 
-use crate::core::types::expr::{ContextualExpression, Expression, ExpressionMeta};
+use crate::core::types::expr::{
+    ContextualExpression, Expression, ExpressionMeta, PatternEdge, PatternNode, PatternPath,
+    PatternSubquery, PatternSubqueryKind,
+};
+use crate::core::types::graph_schema::EdgeDirection;
 use crate::core::types::operators::{BinaryOperator, UnaryOperator};
 use crate::core::types::{Position, Span};
 use crate::core::Value;
@@ -456,6 +460,19 @@ impl<'a> ExprParser<'a> {
                     span,
                 })
             }
+            TokenKind::Count
+                if ctx.lookahead(|c| {
+                    c.next_token();
+                    c.check_token(TokenKind::LBrace)
+                }) =>
+            {
+                ctx.next_token();
+                self.parse_pattern_subquery(PatternSubqueryKind::Count, start_pos, ctx)
+            }
+            TokenKind::Exists => {
+                ctx.next_token();
+                self.parse_pattern_subquery(PatternSubqueryKind::Exists, start_pos, ctx)
+            }
             TokenKind::Count
             | TokenKind::Sum
             | TokenKind::Avg
@@ -507,7 +524,9 @@ impl<'a> ExprParser<'a> {
             }
             TokenKind::LBracket => {
                 ctx.next_token();
-                if ctx.is_identifier_or_in_token() {
+                if ctx.check_token(TokenKind::LParen) && Self::is_pattern_ahead(ctx) {
+                    self.parse_pattern_comprehension(start_pos, ctx)
+                } else if ctx.is_identifier_or_in_token() {
                     self.parse_list_comprehension(start_pos, ctx)
                 } else if ctx.match_token(TokenKind::RBracket) {
                     let span = ctx.merge_span(start_pos, ctx.current_position());
@@ -825,6 +844,200 @@ impl<'a> ExprParser<'a> {
         })
     }
 
+    /// Check whether the parenthesized expression at the cursor starts a path pattern,
+    /// i.e. whether it is followed by a relationship such as `-[` or `<-`.
+    fn is_pattern_ahead(ctx: &mut ParseContext<'a>) -> bool {
+        ctx.lookahead(|c| {
+            let mut depth = 0usize;
+            loop {
+                match c.current_token().kind {
+                    TokenKind::LParen => depth += 1,
+                    TokenKind::RParen => {
+                        depth -= 1;
+                        if depth == 0 {
+                            c.next_token();
+                            break;
+                        }
+                    }
+                    TokenKind::Eof => return false,
+                    _ => {}
+                }
+                c.next_token();
+            }
+            match c.current_token().kind {
+                TokenKind::BackArrow => true,
+                TokenKind::Minus => {
+                    c.next_token();
+                    c.check_token(TokenKind::LBracket)
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Parse `EXISTS { [MATCH] pattern [, pattern...] [WHERE condition] }` or the COUNT form;
+    /// the keyword has already been consumed.
+    fn parse_pattern_subquery(
+        &mut self,
+        kind: PatternSubqueryKind,
+        start_pos: Position,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<ParseResult, ParseError> {
+        ctx.expect_token(TokenKind::LBrace)?;
+        ctx.match_token(TokenKind::Match);
+
+        let mut paths = vec![self.parse_pattern_path(ctx)?];
+        while ctx.match_token(TokenKind::Comma) {
+            paths.push(self.parse_pattern_path(ctx)?);
+        }
+
+        let filter = if ctx.match_token(TokenKind::Where) {
+            Some(self.parse_expression(ctx)?.expr)
+        } else {
+            None
+        };
+
+        ctx.expect_token(TokenKind::RBrace)?;
+
+        let span = ctx.merge_span(start_pos, ctx.current_position());
+        Ok(ParseResult {
+            expr: Expression::pattern_subquery(PatternSubquery::new(kind, paths, filter, None)),
+            span,
+        })
+    }
+
+    /// Parse `[pattern [WHERE condition] | projection]`; the opening bracket has already been consumed.
+    fn parse_pattern_comprehension(
+        &mut self,
+        start_pos: Position,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<ParseResult, ParseError> {
+        let path = self.parse_pattern_path(ctx)?;
+
+        let filter = if ctx.match_token(TokenKind::Where) {
+            Some(self.parse_expression(ctx)?.expr)
+        } else {
+            None
+        };
+
+        ctx.expect_token(TokenKind::Pipe)?;
+        let map = self.parse_expression(ctx)?.expr;
+        ctx.expect_token(TokenKind::RBracket)?;
+
+        let span = ctx.merge_span(start_pos, ctx.current_position());
+        Ok(ParseResult {
+            expr: Expression::pattern_subquery(PatternSubquery::new(
+                PatternSubqueryKind::Collect,
+                vec![path],
+                filter,
+                Some(map),
+            )),
+            span,
+        })
+    }
+
+    fn parse_pattern_path(
+        &mut self,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<PatternPath, ParseError> {
+        let mut path = PatternPath::new(self.parse_pattern_node(ctx)?);
+        while ctx.check_token(TokenKind::Minus) || ctx.check_token(TokenKind::BackArrow) {
+            let edge = self.parse_pattern_edge(ctx)?;
+            let node = self.parse_pattern_node(ctx)?;
+            path.push(edge, node);
+        }
+        Ok(path)
+    }
+
+    /// Parse `(variable:Label1:Label2 {key: value})`
+    fn parse_pattern_node(
+        &mut self,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<PatternNode, ParseError> {
+        ctx.expect_token(TokenKind::LParen)?;
+
+        let mut node = PatternNode::new(None);
+        if let TokenKind::Identifier(name) = ctx.current_token().kind.clone() {
+            ctx.next_token();
+            node.variable = Some(name);
+        }
+        while ctx.match_token(TokenKind::Colon) {
+            node.labels.push(ctx.expect_identifier()?);
+        }
+        if ctx.match_token(TokenKind::LBrace) {
+            node.properties = self.parse_pattern_properties(ctx)?;
+        }
+
+        ctx.expect_token(TokenKind::RParen)?;
+        Ok(node)
+    }
+
+    /// Parse `-[variable:TYPE1|TYPE2 {key: value}]->` and its `<-...-` / `-...-` forms
+    fn parse_pattern_edge(
+        &mut self,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<PatternEdge, ParseError> {
+        let incoming = ctx.match_token(TokenKind::BackArrow);
+        if !incoming {
+            ctx.expect_token(TokenKind::Minus)?;
+        }
+
+        let mut edge = PatternEdge::new(None, EdgeDirection::Both);
+        if ctx.match_token(TokenKind::LBracket) {
+            if let TokenKind::Identifier(name) = ctx.current_token().kind.clone() {
+                ctx.next_token();
+                edge.variable = Some(name);
+            }
+            if ctx.match_token(TokenKind::Colon) {
+                loop {
+                    ctx.match_token(TokenKind::Colon);
+                    edge.edge_types.push(ctx.expect_identifier()?);
+                    if !ctx.match_token(TokenKind::Pipe) {
+                        break;
+                    }
+                }
+            }
+            if ctx.check_token(TokenKind::Star) {
+                return Err(ParseError::new(
+                    ParseErrorKind::SyntaxError,
+                    "Variable-length relationships are not supported in pattern expressions"
+                        .to_string(),
+                    ctx.current_position(),
+                ));
+            }
+            if ctx.match_token(TokenKind::LBrace) {
+                edge.properties = self.parse_pattern_properties(ctx)?;
+            }
+            ctx.expect_token(TokenKind::RBracket)?;
+        }
+
+        let outgoing = if ctx.match_token(TokenKind::Arrow) {
+            true
+        } else {
+            ctx.expect_token(TokenKind::Minus)?;
+            false
+        };
+        edge.direction = match (incoming, outgoing) {
+            (true, false) => EdgeDirection::In,
+            (false, true) => EdgeDirection::Out,
+            _ => EdgeDirection::Both,
+        };
+        Ok(edge)
+    }
+
+    /// Parse the `key: value` entries of a property map whose `{` has already been consumed.
+    fn parse_pattern_properties(
+        &mut self,
+        ctx: &mut ParseContext<'a>,
+    ) -> Result<Vec<(String, Expression)>, ParseError> {
+        if ctx.match_token(TokenKind::RBrace) {
+            return Ok(Vec::new());
+        }
+        let properties = self.parse_property_list(ctx)?;
+        ctx.expect_token(TokenKind::RBrace)?;
+        Ok(properties.into_iter().map(|(k, v)| (k, v.expr)).collect())
+    }
+
     pub fn set_compat_mode(&mut self, _enabled: bool) {}
 }
 
@@ -855,4 +1068,57 @@ mod tests {
         // Verify that the structure of the expression is correct, without checking the specific precedence of the operators.
         assert!(matches!(parse_result.expr, Expression::Binary { .. }));
     }
+
+    fn parse_pattern_subquery_expr(input: &str) -> PatternSubquery {
+        let ctx = &mut ParseContext::new(input);
+        let mut parser = ExprParser::new(ctx);
+        let result = parser
+            .parse_expression(ctx)
+            .expect("Pattern expression parsing should succeed");
+        match result.expr {
+            Expression::PatternSubquery(subquery) => *subquery,
+            other => panic!("Expected a pattern subquery, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_exists_and_count_subquery() {
+        let exists = parse_pattern_subquery_expr(
+            "EXISTS { MATCH (a)-[:KNOWS]->(b:Person) WHERE b.age > 30 }",
+        );
+        assert_eq!(exists.kind, PatternSubqueryKind::Exists);
+        assert_eq!(exists.pattern_string(), "(a)-[:KNOWS]->(b:Person)");
+        assert!(exists.filter.is_some());
+
+        let count = parse_pattern_subquery_expr("COUNT { (a)<-[e:LIKES|KNOWS]-(), (a)-[]-(c) }");
+        assert_eq!(count.kind, PatternSubqueryKind::Count);
+        assert_eq!(count.paths.len(), 2);
+        assert_eq!(count.paths[0].edges[0].direction, EdgeDirection::In);
+        assert_eq!(count.paths[0].edges[0].edge_types, vec!["LIKES", "KNOWS"]);
+        assert_eq!(count.paths[1].edges[0].direction, EdgeDirection::Both);
+        assert_eq!(count.variables(), vec!["a", "e", "c"]);
+    }
+
+    #[test]
+    fn test_parse_pattern_comprehension() {
+        let collect = parse_pattern_subquery_expr("[(a)-[:KNOWS]->(b {active: true}) | b.name]");
+        assert_eq!(collect.kind, PatternSubqueryKind::Collect);
+        assert_eq!(collect.paths[0].nodes[1].properties.len(), 1);
+        assert!(collect.map.is_some());
+
+        // A list of parenthesized expressions is still a plain list.
+        let ctx = &mut ParseContext::new("[(1 + 2), 3]");
+        let mut parser = ExprParser::new(ctx);
+        let list = parser
+            .parse_expression(ctx)
+            .expect("List parsing should succeed");
+        assert!(matches!(list.expr, Expression::List(_)));
+    }
+
+    #[test]
+    fn test_variable_length_pattern_is_rejected() {
+        let ctx = &mut ParseContext::new("EXISTS { (a)-[:KNOWS*1..3]->(b) }");
+        let mut parser = ExprParser::new(ctx);
+        assert!(parser.parse_expression(ctx).is_err());
+    }
 }
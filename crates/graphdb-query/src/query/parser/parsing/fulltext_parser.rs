@@ -30,11 +30,14 @@ pub fn parse_fulltext(ctx: &mut ParseContext) -> Result<Stmt, crate::query::pars
     } else if ctx.check_keyword("DESCRIBE") || ctx.check_keyword("DESC") {
         return parse_describe_fulltext_index(ctx);
     } else if ctx.check_keyword("SEARCH") {
-        // Check if it's SEARCH VECTOR or SEARCH INDEX
+        // Check if it's SEARCH VECTOR, SEARCH HYBRID or SEARCH INDEX
         if ctx.check_keyword_sequence(&["SEARCH", "VECTOR"]) {
             // Forward to vector parser
             return crate::query::parser::parsing::vector_parser::parse_vector(ctx);
         }
+        if ctx.check_keyword_sequence(&["SEARCH", "HYBRID"]) {
+            return crate::query::parser::parsing::hybrid_parser::parse_hybrid_search(ctx);
+        }
         // Check if it's SEARCH INDEX - if so, we need to consume INDEX here
         let is_search_index = ctx.check_keyword_sequence(&["SEARCH", "INDEX"]);
         // Consume SEARCH and continue with fulltext parsing
@@ -397,7 +400,7 @@ fn parse_search_statement_after_search(
     Ok(Stmt::Search(search))
 }
 
-fn parse_fulltext_query_expr(
+pub(crate) fn parse_fulltext_query_expr(
     ctx: &mut ParseContext,
 ) -> Result<FulltextQueryExpr, crate::query::parser::ParseError> {
     if let Some(text) = ctx.try_consume_string() {
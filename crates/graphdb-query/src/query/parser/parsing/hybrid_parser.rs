@@ -0,0 +1,157 @@
+//! Hybrid Search Parser
+//!
+//! This module implements the parser for `SEARCH HYBRID`, which combines a full-text
+//! query and a vector query over the same tag.
+
+use crate::query::parser::ast::hybrid::{FusionMethod, HybridSearchStatement};
+use crate::query::parser::ast::stmt::Stmt;
+use crate::query::parser::parsing::fulltext_parser::parse_fulltext_query_expr;
+use crate::query::parser::parsing::parse_context::ParseContext;
+use crate::query::parser::parsing::vector_parser::{
+    parse_vector_query_expr, parse_vector_yield_clause,
+};
+use crate::query::parser::TokenKind;
+
+/// Parse SEARCH HYBRID statement
+pub fn parse_hybrid_search(
+    ctx: &mut ParseContext,
+) -> Result<Stmt, crate::query::parser::ParseError> {
+    let span = ctx.current_span();
+
+    ctx.consume_keyword("SEARCH")?;
+    ctx.consume_keyword("HYBRID")?;
+
+    ctx.consume_keyword("FULLTEXT")?;
+    let fulltext_index = ctx.consume_identifier()?;
+    ctx.consume_keyword("MATCH")?;
+    let fulltext_query = parse_fulltext_query_expr(ctx)?;
+
+    ctx.consume_keyword("VECTOR")?;
+    let vector_index = ctx.consume_identifier()?;
+    ctx.consume_keyword("WITH")?;
+    let vector_query = parse_vector_query_expr(ctx)?;
+
+    let mut fusion = FusionMethod::default();
+    if ctx.check_keyword("FUSION") {
+        ctx.consume_keyword("FUSION")?;
+        fusion = parse_fusion_method(ctx)?;
+    }
+
+    let mut where_clause = None;
+    if ctx.check_keyword("WHERE") {
+        ctx.consume_keyword("WHERE")?;
+        let mut expr_parser = crate::query::parser::parsing::ExprParser::new(ctx);
+        where_clause =
+            Some(expr_parser.parse_expression_with_context(ctx, ctx.expression_context_clone())?);
+    }
+
+    let mut limit = None;
+    if ctx.check_keyword("LIMIT") {
+        ctx.consume_keyword("LIMIT")?;
+        limit = Some(ctx.consume_int()? as usize);
+    }
+
+    let mut offset = None;
+    if ctx.check_keyword("OFFSET") {
+        ctx.consume_keyword("OFFSET")?;
+        offset = Some(ctx.consume_int()? as usize);
+    }
+
+    let mut yield_clause = None;
+    if ctx.check_keyword("YIELD") {
+        ctx.consume_keyword("YIELD")?;
+        yield_clause = Some(parse_vector_yield_clause(ctx)?);
+    }
+
+    Ok(Stmt::HybridSearch(HybridSearchStatement {
+        span,
+        fulltext_index,
+        fulltext_query,
+        vector_index,
+        vector_query,
+        fusion,
+        where_clause,
+        limit,
+        offset,
+        yield_clause,
+    }))
+}
+
+/// Parse fusion method: RRF [(k = n)] | WEIGHTED [(fulltext = w, vector = w)]
+fn parse_fusion_method(
+    ctx: &mut ParseContext,
+) -> Result<FusionMethod, crate::query::parser::ParseError> {
+    if ctx.check_keyword("RRF") {
+        ctx.consume_keyword("RRF")?;
+        let mut k = FusionMethod::DEFAULT_RRF_K;
+        for (name, value) in parse_fusion_options(ctx)? {
+            match name.to_lowercase().as_str() {
+                "k" => k = value,
+                _ => return Err(unknown_option(ctx, "RRF", &name)),
+            }
+        }
+        Ok(FusionMethod::Rrf { k })
+    } else if ctx.check_keyword("WEIGHTED") {
+        ctx.consume_keyword("WEIGHTED")?;
+        let mut fulltext = FusionMethod::DEFAULT_WEIGHT;
+        let mut vector = FusionMethod::DEFAULT_WEIGHT;
+        for (name, value) in parse_fusion_options(ctx)? {
+            match name.to_lowercase().as_str() {
+                "fulltext" => fulltext = value,
+                "vector" => vector = value,
+                _ => return Err(unknown_option(ctx, "WEIGHTED", &name)),
+            }
+        }
+        Ok(FusionMethod::Weighted { fulltext, vector })
+    } else {
+        Err(crate::query::parser::ParseError::new(
+            crate::query::parser::core::error::ParseErrorKind::SyntaxError,
+            "Expected 'RRF' or 'WEIGHTED' after FUSION".to_string(),
+            ctx.current_position(),
+        ))
+    }
+}
+
+/// Parse optional `(name = number, ...)` list following a fusion method
+fn parse_fusion_options(
+    ctx: &mut ParseContext,
+) -> Result<Vec<(String, f64)>, crate::query::parser::ParseError> {
+    let mut options = Vec::new();
+    if !ctx.consume_optional_token("(") {
+        return Ok(options);
+    }
+
+    loop {
+        let name = ctx.consume_identifier()?;
+        if ctx.expect_token(TokenKind::Eq).is_err() {
+            ctx.expect_token(TokenKind::Assign)?;
+        }
+        let value = match ctx.current_token().kind {
+            TokenKind::IntegerLiteral(n) => {
+                ctx.next_token();
+                n as f64
+            }
+            _ => ctx.consume_float()?,
+        };
+        options.push((name, value));
+
+        if !ctx.consume_optional_token(",") {
+            break;
+        }
+    }
+
+    ctx.expect_token(TokenKind::RParen)?;
+    Ok(options)
+}
+
+fn unknown_option(
+    ctx: &ParseContext,
+    method: &str,
+    name: &str,
+) -> crate::query::parser::ParseError {
+    crate::query::parser::ParseError::new(
+        crate::query::parser::core::error::ParseErrorKind::SyntaxError,
+        format!("Unknown {} fusion option '{}'", method, name),
+        ctx.current_position(),
+    )
+}
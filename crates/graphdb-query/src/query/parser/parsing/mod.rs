@@ -4,6 +4,7 @@
 
 mod expr_parser;
 mod fulltext_parser;
+mod hybrid_parser;
 mod parse_context;
 mod parser;
 mod stmt_parser;
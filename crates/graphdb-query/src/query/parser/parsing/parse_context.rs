@@ -71,6 +71,11 @@ impl<'a> ParseContext<'a> {
         &self.lexer
     }
 
+    /// Input text after the current token
+    pub fn remaining_input(&self) -> String {
+        self.lexer.remaining_input()
+    }
+
     pub fn lexer_mut(&mut self) -> &mut Lexer<'a> {
         &mut self.lexer
     }
@@ -417,6 +422,8 @@ impl<'a> ParseContext<'a> {
             TokenKind::By => keyword.eq_ignore_ascii_case("BY"),
             TokenKind::Skip => keyword.eq_ignore_ascii_case("SKIP"),
             TokenKind::Unwind => keyword.eq_ignore_ascii_case("UNWIND"),
+            TokenKind::Call => keyword.eq_ignore_ascii_case("CALL"),
+            TokenKind::Foreach => keyword.eq_ignore_ascii_case("FOREACH"),
             TokenKind::Optional => keyword.eq_ignore_ascii_case("OPTIONAL"),
             TokenKind::Distinct => keyword.eq_ignore_ascii_case("DISTINCT"),
             TokenKind::All => keyword.eq_ignore_ascii_case("ALL"),
@@ -455,6 +462,16 @@ impl<'a> ParseContext<'a> {
         }
     }
 
+    /// Run `probe` over the upcoming tokens, then rewind to the current token (non-consuming)
+    pub fn lookahead<R>(&mut self, probe: impl FnOnce(&mut Self) -> R) -> R {
+        let saved_lexer = self.lexer.clone();
+        let saved_token = self.current_token.clone();
+        let result = probe(self);
+        self.lexer = saved_lexer;
+        self.current_token = saved_token;
+        result
+    }
+
     /// Check if the next tokens match the given sequence (non-consuming)
     pub fn check_keyword_sequence(&mut self, keywords: &[&str]) -> bool {
         if keywords.is_empty() {
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::core::types::expr::contextual::ContextualExpression;
+use crate::query::parser::ast::hint::QueryHints;
 use crate::query::parser::ast::stmt::{Ast, Stmt};
 use crate::query::parser::parsing::expr_parser::ExprParser;
 use crate::query::parser::parsing::parse_context::ParseContext;
@@ -68,7 +69,8 @@ impl<'a> Parser<'a> {
         self.expr_context = expr_context;
 
         let stmt = self.parse_statement()?;
-        let ast = Ast::new(stmt, self.expr_context.clone());
+        let hints = QueryHints::parse(self.ctx.lexer().hint_comments());
+        let ast = Ast::new(stmt, self.expr_context.clone()).with_hints(hints);
         Ok(ParserResult { ast: Arc::new(ast) })
     }
 
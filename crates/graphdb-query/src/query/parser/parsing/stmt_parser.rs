@@ -8,8 +8,8 @@ use crate::query::parser::ast::stmt::*;
 use crate::query::parser::core::error::{ParseError, ParseErrorKind};
 use crate::query::parser::parsing::parse_context::ParseContext;
 use crate::query::parser::parsing::{
-    ddl_parser::DdlParser, dml_parser::DmlParser, traversal_parser::TraversalParser,
-    user_parser::UserParser, util_stmt_parser::UtilStmtParser,
+    clause_parser::ClauseParser, ddl_parser::DdlParser, dml_parser::DmlParser,
+    traversal_parser::TraversalParser, user_parser::UserParser, util_stmt_parser::UtilStmtParser,
 };
 use crate::query::parser::TokenKind;
 
@@ -32,6 +32,9 @@ impl StmtParser {
         let token = ctx.current_token().clone();
         match token.kind {
             // Graph traversal statement
+            TokenKind::Match if ctx.check_keyword_sequence(&["MATCH", "VIEW"]) => {
+                self.parse_match_view_statement(ctx)
+            }
             TokenKind::Match | TokenKind::Optional => {
                 TraversalParser::new().parse_match_statement(ctx)
             }
@@ -71,6 +74,9 @@ impl StmtParser {
             TokenKind::Fetch => UtilStmtParser::new().parse_fetch_statement(ctx),
             TokenKind::Lookup => UtilStmtParser::new().parse_lookup_statement(ctx),
             TokenKind::Unwind => UtilStmtParser::new().parse_unwind_statement(ctx),
+            TokenKind::Call if Self::is_call_subquery(ctx) => self.parse_call_subquery(ctx),
+            TokenKind::Call => UtilStmtParser::new().parse_call_statement(ctx),
+            TokenKind::Foreach => self.parse_foreach_statement(ctx),
             TokenKind::Return => UtilStmtParser::new().parse_return_statement(ctx),
             TokenKind::With => UtilStmtParser::new().parse_with_statement(ctx),
             TokenKind::Yield => UtilStmtParser::new().parse_yield_statement(ctx),
@@ -97,6 +103,18 @@ impl StmtParser {
             // Variable assignment statement ($var = statement)
             TokenKind::Dollar => self.parse_assignment_statement(ctx),
 
+            // Index sync dead letter queue management (RETRY / PURGE SYNC DLQ)
+            TokenKind::Identifier(ref kw)
+                if kw.eq_ignore_ascii_case("RETRY") || kw.eq_ignore_ascii_case("PURGE") =>
+            {
+                self.parse_sync_dlq_statement(ctx)
+            }
+
+            // REFRESH MATERIALIZED VIEW
+            TokenKind::Identifier(ref kw) if kw.eq_ignore_ascii_case("REFRESH") => {
+                self.parse_refresh_materialized_view(ctx)
+            }
+
             _ => Err(ParseError::new(
                 ParseErrorKind::UnexpectedToken,
                 format!("Unexpected token: {:?}", token.kind),
@@ -163,6 +181,23 @@ impl StmtParser {
                 right: Box::new(right),
             });
 
+            self.parse_pipe_suffix(ctx, pipe_stmt)
+        } else if matches!(
+            ctx.current_token().kind,
+            TokenKind::Call | TokenKind::Foreach
+        ) {
+            // UNWIND $rows AS r CALL { ... } / ... FOREACH (x IN list | ...)
+            let start_span = left.span();
+            let right = self.parse_single_statement(ctx)?;
+            let end_span = right.span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+
+            let pipe_stmt = Stmt::Pipe(PipeStmt {
+                span,
+                left: Box::new(left),
+                right: Box::new(right),
+            });
+
             self.parse_pipe_suffix(ctx, pipe_stmt)
         } else {
             // Check whether it is a set operation.
@@ -325,7 +360,9 @@ impl StmtParser {
         &mut self,
         ctx: &mut ParseContext,
     ) -> Result<Stmt, ParseError> {
-        use crate::query::parser::ast::stmt::{ShowConfigsStmt, ShowQueriesStmt, ShowSessionsStmt};
+        use crate::query::parser::ast::stmt::{
+            ShowConfigsStmt, ShowQueriesStmt, ShowSessionsStmt, SyncDlqAction, SyncDlqStmt,
+        };
 
         let start_span = ctx.current_span();
         ctx.expect_token(TokenKind::Show)?;
@@ -411,6 +448,48 @@ impl StmtParser {
                     space_name: None,
                 },
             ))
+        } else if ctx.check_keyword("CONSTRAINTS") {
+            ctx.next_token();
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            Ok(Stmt::Show(crate::query::parser::ast::stmt::ShowStmt {
+                span,
+                target: crate::query::parser::ast::stmt::ShowTarget::Constraints,
+            }))
+        } else if ctx.check_keyword("PROCEDURES") {
+            // SHOW PROCEDURES is shorthand for CALL dbms.procedures()
+            ctx.next_token();
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            Ok(Stmt::Call(CallStmt {
+                span,
+                procedure: "dbms.procedures".to_string(),
+                args: Vec::new(),
+                yield_clause: None,
+            }))
+        } else if ctx.check_keyword("API") {
+            // SHOW API KEYS [FOR <user>]
+            UserParser::new().parse_show_api_keys_after_show(ctx, start_span)
+        } else if ctx.check_keyword("SYNC") {
+            // SHOW SYNC DLQ
+            ctx.next_token();
+            ctx.consume_keyword("DLQ")?;
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            Ok(Stmt::SyncDlq(SyncDlqStmt {
+                span,
+                action: SyncDlqAction::Show,
+            }))
+        } else if ctx.check_keyword("MATERIALIZED") {
+            // SHOW MATERIALIZED VIEWS
+            ctx.next_token();
+            ctx.consume_keyword("VIEWS")?;
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            Ok(Stmt::MaterializedView(MaterializedViewStmt {
+                span,
+                action: MaterializedViewAction::Show,
+            }))
         } else if ctx.check_token(TokenKind::Create) {
             // The SHOW CREATE statement: A unified processing method delegated to UtilStmtParser
             // 支持 SHOW CREATE { SPACE | TAG | EDGE | INDEX } <name>
@@ -451,6 +530,138 @@ impl StmtParser {
         }))
     }
 
+    /// Analyzing the RETRY SYNC DLQ / PURGE SYNC DLQ statements
+    ///
+    /// RETRY SYNC DLQ { <id> | ALL }
+    /// PURGE SYNC DLQ [RECOVERED]
+    fn parse_sync_dlq_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
+        use crate::query::parser::ast::stmt::{SyncDlqAction, SyncDlqStmt};
+
+        let start_span = ctx.current_span();
+        let is_retry = ctx.check_keyword("RETRY");
+        ctx.next_token();
+        ctx.consume_keyword("SYNC")?;
+        ctx.consume_keyword("DLQ")?;
+
+        let action = if is_retry {
+            if ctx.check_keyword("ALL") {
+                ctx.next_token();
+                SyncDlqAction::Retry(None)
+            } else {
+                let position = ctx.current_position();
+                let id = ctx.expect_integer_literal()?;
+                let id = u64::try_from(id).map_err(|_| {
+                    ParseError::new(
+                        ParseErrorKind::SyntaxError,
+                        format!("Invalid dead letter entry id: {}", id),
+                        position,
+                    )
+                })?;
+                SyncDlqAction::Retry(Some(id))
+            }
+        } else {
+            let recovered_only = ctx.check_keyword("RECOVERED");
+            if recovered_only {
+                ctx.next_token();
+            }
+            SyncDlqAction::Purge { recovered_only }
+        };
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+        Ok(Stmt::SyncDlq(SyncDlqStmt { span, action }))
+    }
+
+    /// Analyzing the CREATE MATERIALIZED VIEW statement (CREATE already consumed)
+    ///
+    /// CREATE MATERIALIZED VIEW [IF NOT EXISTS] <name> AS <query>
+    fn parse_create_materialized_view_after_create(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: crate::query::parser::ast::types::Span,
+    ) -> Result<Stmt, ParseError> {
+        ctx.consume_keyword("MATERIALIZED")?;
+        ctx.consume_keyword("VIEW")?;
+
+        let mut if_not_exists = false;
+        if ctx.match_token(TokenKind::If) {
+            ctx.expect_token(TokenKind::Not)?;
+            ctx.expect_token(TokenKind::Exists)?;
+            if_not_exists = true;
+        }
+        let name = ctx.expect_identifier()?;
+
+        if !ctx.check_token(TokenKind::As) {
+            return Err(ParseError::new(
+                ParseErrorKind::SyntaxError,
+                "Expected AS before the materialized view query".to_string(),
+                ctx.current_position(),
+            ));
+        }
+        // The definition text is kept verbatim so it can be re-parsed after a restart
+        let text_after_as = ctx.remaining_input();
+        ctx.next_token();
+        let statement = self.parse_statement(ctx)?;
+        let text_after_query = ctx.remaining_input();
+        let query = text_after_as[..text_after_as.len() - text_after_query.len()]
+            .trim()
+            .trim_end_matches(';')
+            .trim_end()
+            .to_string();
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+        Ok(Stmt::MaterializedView(MaterializedViewStmt {
+            span,
+            action: MaterializedViewAction::Create {
+                name,
+                query,
+                statement: Box::new(statement),
+                if_not_exists,
+            },
+        }))
+    }
+
+    /// Analyzing the REFRESH MATERIALIZED VIEW <name> statement
+    fn parse_refresh_materialized_view(
+        &mut self,
+        ctx: &mut ParseContext,
+    ) -> Result<Stmt, ParseError> {
+        let start_span = ctx.current_span();
+        ctx.consume_keyword("REFRESH")?;
+        ctx.consume_keyword("MATERIALIZED")?;
+        ctx.consume_keyword("VIEW")?;
+        let name = ctx.expect_identifier()?;
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+        Ok(Stmt::MaterializedView(MaterializedViewStmt {
+            span,
+            action: MaterializedViewAction::Refresh { name },
+        }))
+    }
+
+    /// Analyzing the MATCH VIEW <name> [YIELD ...] statement
+    fn parse_match_view_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
+        let start_span = ctx.current_span();
+        ctx.expect_token(TokenKind::Match)?;
+        ctx.consume_keyword("VIEW")?;
+        let name = ctx.expect_identifier()?;
+
+        let yield_clause = if ctx.match_token(TokenKind::Yield) {
+            Some(ClauseParser::new().parse_yield_clause(ctx)?)
+        } else {
+            None
+        };
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+        Ok(Stmt::MaterializedView(MaterializedViewStmt {
+            span,
+            action: MaterializedViewAction::Scan { name, yield_clause },
+        }))
+    }
+
     /// Analysis of the extended UPDATE statement (including UPDATE CONFIGS)
     fn parse_update_statement_extended(
         &mut self,
@@ -552,6 +763,16 @@ impl StmtParser {
             return UserParser::new().parse_create_user_statement_after_create(ctx, start_span);
         }
 
+        // Check whether it is a CREATE ROLE statement.
+        if ctx.check_token(TokenKind::Role) {
+            return UserParser::new().parse_create_role_statement_after_create(ctx, start_span);
+        }
+
+        // Check whether it is a CREATE API KEY statement.
+        if ctx.check_keyword("API") {
+            return UserParser::new().parse_create_api_key_statement_after_create(ctx, start_span);
+        }
+
         // Check whether it is a CREATE FULLTEXT INDEX statement.
         if ctx.check_keyword("FULLTEXT") {
             // Parse as full-text index statement (CREATE already consumed)
@@ -564,6 +785,16 @@ impl StmtParser {
             return crate::query::parser::parsing::vector_parser::parse_create_vector_index_after_create(ctx);
         }
 
+        // Check whether it is a CREATE MATERIALIZED VIEW statement.
+        if ctx.check_keyword("MATERIALIZED") {
+            return self.parse_create_materialized_view_after_create(ctx, start_span);
+        }
+
+        // Check whether it is a CREATE CONSTRAINT statement.
+        if ctx.check_keyword("CONSTRAINT") {
+            return DdlParser::new().parse_create_constraint_after_create(ctx, start_span);
+        }
+
         // Check the DDL CREATE type.
         if ctx.check_token(TokenKind::Tag)
             || ctx.check_token(TokenKind::Edge)
@@ -583,6 +814,116 @@ impl StmtParser {
         ))
     }
 
+    /// Whether the CALL at the current position opens a subquery rather than a procedure call
+    fn is_call_subquery(ctx: &mut ParseContext) -> bool {
+        ctx.lookahead(|ctx| {
+            ctx.next_token();
+            ctx.check_token(TokenKind::LBrace)
+        })
+    }
+
+    /// Parse `CALL { <statement> } [IN TRANSACTIONS [OF <n> ROWS] [ON ERROR CONTINUE | BREAK | FAIL]]`
+    fn parse_call_subquery(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
+        let start_span = ctx.current_span();
+        ctx.expect_token(TokenKind::Call)?;
+        ctx.expect_token(TokenKind::LBrace)?;
+        let body = self.parse_statement(ctx)?;
+        ctx.expect_token(TokenKind::RBrace)?;
+
+        let in_transactions = if ctx.match_token(TokenKind::In) {
+            ctx.consume_keyword("TRANSACTIONS")?;
+            Some(self.parse_in_transactions(ctx)?)
+        } else {
+            None
+        };
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::CallSubquery(CallSubqueryStmt {
+            span,
+            body: Box::new(body),
+            in_transactions,
+        }))
+    }
+
+    /// Parse the options following `IN TRANSACTIONS`
+    fn parse_in_transactions(
+        &mut self,
+        ctx: &mut ParseContext,
+    ) -> Result<InTransactions, ParseError> {
+        let mut in_transactions = InTransactions::default();
+
+        if ctx.match_token(TokenKind::Of) {
+            let position = ctx.current_position();
+            let batch_size = ctx.expect_integer_literal()?;
+            if batch_size <= 0 {
+                return Err(ParseError::new(
+                    ParseErrorKind::SyntaxError,
+                    format!("Batch size must be positive, got {}", batch_size),
+                    position,
+                ));
+            }
+            in_transactions.batch_size = batch_size as usize;
+            if ctx.check_keyword("ROW") {
+                ctx.next_token();
+            } else {
+                ctx.consume_keyword("ROWS")?;
+            }
+        }
+
+        if ctx.match_token(TokenKind::On) {
+            ctx.consume_keyword("ERROR")?;
+            in_transactions.on_error = if ctx.check_keyword("CONTINUE") {
+                OnErrorAction::Continue
+            } else if ctx.check_keyword("BREAK") {
+                OnErrorAction::Break
+            } else if ctx.check_keyword("FAIL") {
+                OnErrorAction::Fail
+            } else {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedToken,
+                    format!(
+                        "Expected CONTINUE, BREAK or FAIL after ON ERROR, found {:?}",
+                        ctx.current_token().kind
+                    ),
+                    ctx.current_position(),
+                ));
+            };
+            ctx.next_token();
+        }
+
+        Ok(in_transactions)
+    }
+
+    /// Parse `FOREACH (<variable> IN <list> | <update clause> ...)`
+    fn parse_foreach_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
+        let start_span = ctx.current_span();
+        ctx.expect_token(TokenKind::Foreach)?;
+        ctx.expect_token(TokenKind::LParen)?;
+
+        let variable = ctx.expect_identifier()?;
+        ctx.expect_token(TokenKind::In)?;
+        let list = self.parse_expression(ctx)?;
+        ctx.expect_token(TokenKind::Pipe)?;
+
+        let mut body = Vec::new();
+        while !ctx.check_token(TokenKind::RParen) {
+            body.push(self.parse_single_statement(ctx)?);
+        }
+        ctx.expect_token(TokenKind::RParen)?;
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::Foreach(ForeachStmt {
+            span,
+            variable,
+            list,
+            body,
+        }))
+    }
+
     /// Parse full-text search statements
     fn parse_fulltext_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
         crate::query::parser::parsing::fulltext_parser::parse_fulltext(ctx)
@@ -448,4 +448,332 @@ mod tests {
             panic!("The expectation for the FindPath statement");
         }
     }
+
+    #[test]
+    fn test_create_constraint() {
+        let stmt =
+            parse_statement("CREATE CONSTRAINT person_name UNIQUE (person.first_name, last_name)")
+                .expect("CREATE CONSTRAINT UNIQUE should parse");
+        if let Stmt::Create(create) = stmt {
+            assert_eq!(
+                create.target,
+                CreateTarget::Constraint {
+                    name: "person_name".to_string(),
+                    tag: "person".to_string(),
+                    kind: crate::core::types::ConstraintKind::Unique,
+                    properties: vec!["first_name".to_string(), "last_name".to_string()],
+                }
+            );
+        } else {
+            panic!("The expectation for the Create statement");
+        }
+
+        let stmt = parse_statement("CREATE CONSTRAINT IF NOT EXISTS age_set NOT NULL (person.age)")
+            .expect("CREATE CONSTRAINT NOT NULL should parse");
+        if let Stmt::Create(create) = stmt {
+            assert!(create.if_not_exists);
+            assert!(matches!(
+                create.target,
+                CreateTarget::Constraint {
+                    kind: crate::core::types::ConstraintKind::NotNull,
+                    ..
+                }
+            ));
+        } else {
+            panic!("The expectation for the Create statement");
+        }
+
+        assert!(parse_statement("CREATE CONSTRAINT c UNIQUE (person.a, company.b)").is_err());
+    }
+
+    #[test]
+    fn test_drop_and_show_constraints() {
+        let stmt = parse_statement("DROP CONSTRAINT IF EXISTS person_name")
+            .expect("DROP CONSTRAINT should parse");
+        if let Stmt::Drop(drop) = stmt {
+            assert!(drop.if_exists);
+            assert_eq!(
+                drop.target,
+                DropTarget::Constraint("person_name".to_string())
+            );
+        } else {
+            panic!("The expectation for the Drop statement");
+        }
+
+        let stmt = parse_statement("SHOW CONSTRAINTS").expect("SHOW CONSTRAINTS should parse");
+        assert!(matches!(
+            stmt,
+            Stmt::Show(ShowStmt {
+                target: ShowTarget::Constraints,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_call_statement() {
+        let stmt = parse_statement("CALL db.indexes() YIELD name AS n, unique WHERE unique")
+            .expect("CALL with YIELD should parse");
+        if let Stmt::Call(call) = stmt {
+            assert_eq!(call.procedure, "db.indexes");
+            assert!(call.args.is_empty());
+            let yield_clause = call.yield_clause.expect("YIELD clause expected");
+            assert_eq!(yield_clause.items.len(), 2);
+            assert_eq!(yield_clause.items[0].alias.as_deref(), Some("n"));
+            assert!(yield_clause.where_clause.is_some());
+        } else {
+            panic!("The expectation for the Call statement");
+        }
+
+        let stmt = parse_statement("CALL my.echo('hi', 2)").expect("CALL with args should parse");
+        if let Stmt::Call(call) = stmt {
+            assert_eq!(call.procedure, "my.echo");
+            assert_eq!(call.args.len(), 2);
+            assert!(call.yield_clause.is_none());
+        } else {
+            panic!("The expectation for the Call statement");
+        }
+
+        let stmt = parse_statement("CALL db.labels() YIELD label RETURN label")
+            .expect("CALL followed by RETURN should parse");
+        assert!(matches!(stmt, Stmt::Pipe(_)));
+
+        let stmt = parse_statement("SHOW PROCEDURES").expect("SHOW PROCEDURES should parse");
+        assert!(matches!(
+            stmt,
+            Stmt::Call(CallStmt { ref procedure, .. }) if procedure == "dbms.procedures"
+        ));
+
+        assert!(parse_statement("CALL db.labels").is_err());
+    }
+
+    #[test]
+    fn test_call_subquery_statement() {
+        let stmt = parse_statement(
+            "UNWIND $rows AS r CALL { CREATE (:Person {name: r.name}) } \
+             IN TRANSACTIONS OF 500 ROWS ON ERROR CONTINUE",
+        )
+        .expect("CALL subquery should parse");
+        let Stmt::Pipe(pipe) = stmt else {
+            panic!("The expectation for the Pipe statement");
+        };
+        assert!(matches!(*pipe.left, Stmt::Unwind(_)));
+        if let Stmt::CallSubquery(call) = *pipe.right {
+            assert_eq!(call.body.kind(), "CREATE");
+            assert_eq!(
+                call.in_transactions,
+                Some(InTransactions {
+                    batch_size: 500,
+                    on_error: OnErrorAction::Continue,
+                })
+            );
+        } else {
+            panic!("The expectation for the CallSubquery statement");
+        }
+
+        let stmt = parse_statement("CALL { CREATE (:Person {name: 'a'}) } IN TRANSACTIONS")
+            .expect("IN TRANSACTIONS without options should parse");
+        if let Stmt::CallSubquery(call) = stmt {
+            assert_eq!(call.in_transactions, Some(InTransactions::default()));
+        } else {
+            panic!("The expectation for the CallSubquery statement");
+        }
+
+        let stmt = parse_statement("CALL { CREATE (:Person {name: 'a'}) }")
+            .expect("CALL subquery without IN TRANSACTIONS should parse");
+        assert!(matches!(
+            stmt,
+            Stmt::CallSubquery(CallSubqueryStmt {
+                in_transactions: None,
+                ..
+            })
+        ));
+
+        assert!(parse_statement("CALL { CREATE (:Person) } IN TRANSACTIONS OF 0 ROWS").is_err());
+        assert!(
+            parse_statement("CALL { CREATE (:Person) } IN TRANSACTIONS ON ERROR RETRY").is_err()
+        );
+    }
+
+    #[test]
+    fn test_foreach_statement() {
+        let stmt = parse_statement("FOREACH (name IN ['a', 'b'] | CREATE (:Person {name: name}))")
+            .expect("FOREACH should parse");
+        if let Stmt::Foreach(foreach) = stmt {
+            assert_eq!(foreach.variable, "name");
+            assert_eq!(foreach.body.len(), 1);
+            assert_eq!(foreach.body[0].kind(), "CREATE");
+        } else {
+            panic!("The expectation for the Foreach statement");
+        }
+
+        let stmt = parse_statement(
+            "UNWIND $rows AS r FOREACH (id IN r.ids | SET id.seen = true \
+             FOREACH (x IN [1] | CREATE (:Mark {n: x})))",
+        )
+        .expect("nested FOREACH after UNWIND should parse");
+        let Stmt::Pipe(pipe) = stmt else {
+            panic!("The expectation for the Pipe statement");
+        };
+        let foreach = pipe.right.as_foreach().expect("FOREACH expected");
+        assert_eq!(foreach.body.len(), 2);
+        assert_eq!(foreach.body[1].kind(), "FOREACH");
+
+        assert!(parse_statement("FOREACH (x IN [1] CREATE (:Mark))").is_err());
+    }
+
+    #[test]
+    fn test_search_hybrid_statement() {
+        let stmt = parse_statement(
+            "SEARCH HYBRID FULLTEXT idx_title MATCH 'graph database' \
+             VECTOR idx_embedding WITH vector = [0.1, 0.2, 0.3] \
+             FUSION WEIGHTED (fulltext = 0.3, vector = 0.7) \
+             WHERE vertex.year >= 2020 LIMIT 5 YIELD vid, score, vector_score AS vs",
+        )
+        .expect("SEARCH HYBRID should parse");
+        let Stmt::HybridSearch(search) = stmt else {
+            panic!("The expectation for the HybridSearch statement");
+        };
+        assert_eq!(search.fulltext_index, "idx_title");
+        assert_eq!(search.vector_index, "idx_embedding");
+        assert_eq!(
+            search.fusion,
+            FusionMethod::Weighted {
+                fulltext: 0.3,
+                vector: 0.7
+            }
+        );
+        assert!(search.where_clause.is_some());
+        assert_eq!(search.limit, Some(5));
+        assert_eq!(search.output_columns(), vec!["vid", "score", "vs"]);
+
+        let stmt = parse_statement(
+            "SEARCH HYBRID FULLTEXT idx_title MATCH 'graph' VECTOR idx_embedding \
+             WITH text = 'graph' FUSION RRF (k = 20)",
+        )
+        .expect("SEARCH HYBRID with RRF should parse");
+        let search = stmt.as_hybrid_search().expect("HybridSearch expected");
+        assert_eq!(search.fusion, FusionMethod::Rrf { k: 20.0 });
+        assert_eq!(
+            search.output_columns(),
+            vec!["vid", "score", "fulltext_score", "vector_score"]
+        );
+
+        assert!(parse_statement(
+            "SEARCH HYBRID FULLTEXT a MATCH 'x' VECTOR b WITH text = 'x' FUSION MAX"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sync_dlq_statements() {
+        let cases = [
+            ("SHOW SYNC DLQ", SyncDlqAction::Show),
+            ("RETRY SYNC DLQ 42", SyncDlqAction::Retry(Some(42))),
+            ("retry sync dlq all", SyncDlqAction::Retry(None)),
+            (
+                "PURGE SYNC DLQ",
+                SyncDlqAction::Purge {
+                    recovered_only: false,
+                },
+            ),
+            (
+                "PURGE SYNC DLQ RECOVERED",
+                SyncDlqAction::Purge {
+                    recovered_only: true,
+                },
+            ),
+        ];
+        for (query, expected) in cases {
+            let stmt = parse_statement(query).expect("SYNC DLQ statement should parse");
+            let Stmt::SyncDlq(dlq) = stmt else {
+                panic!("The expectation for the SyncDlq statement: {}", query);
+            };
+            assert_eq!(dlq.action, expected);
+        }
+
+        assert!(parse_statement("RETRY SYNC DLQ").is_err());
+        assert!(parse_statement("SHOW SYNC").is_err());
+    }
+    #[test]
+    fn test_materialized_view_statements() {
+        let stmt = parse_statement(
+            "CREATE MATERIALIZED VIEW IF NOT EXISTS city_counts AS \
+             MATCH (p:person) RETURN p.city AS city, count(*) AS total;",
+        )
+        .expect("CREATE MATERIALIZED VIEW should parse");
+        let Stmt::MaterializedView(view) = stmt else {
+            panic!("Expected a materialized view statement");
+        };
+        let MaterializedViewAction::Create {
+            name,
+            query,
+            statement,
+            if_not_exists,
+        } = view.action
+        else {
+            panic!("Expected CREATE MATERIALIZED VIEW");
+        };
+        assert_eq!(name, "city_counts");
+        assert_eq!(
+            query,
+            "MATCH (p:person) RETURN p.city AS city, count(*) AS total"
+        );
+        assert!(matches!(*statement, Stmt::Match(_)));
+        assert!(if_not_exists);
+
+        let stmt = parse_statement("DROP MATERIALIZED VIEW IF EXISTS city_counts")
+            .expect("DROP MATERIALIZED VIEW should parse");
+        assert_eq!(stmt.kind(), "DROP MATERIALIZED VIEW");
+
+        let stmt = parse_statement("REFRESH MATERIALIZED VIEW city_counts")
+            .expect("REFRESH MATERIALIZED VIEW should parse");
+        assert_eq!(stmt.kind(), "REFRESH MATERIALIZED VIEW");
+
+        let stmt = parse_statement("SHOW MATERIALIZED VIEWS")
+            .expect("SHOW MATERIALIZED VIEWS should parse");
+        assert_eq!(stmt.kind(), "SHOW MATERIALIZED VIEWS");
+
+        let stmt = parse_statement("MATCH VIEW city_counts YIELD city, total WHERE total > 1")
+            .expect("MATCH VIEW should parse");
+        let Stmt::MaterializedView(MaterializedViewStmt {
+            action: MaterializedViewAction::Scan { name, yield_clause },
+            ..
+        }) = stmt
+        else {
+            panic!("Expected MATCH VIEW");
+        };
+        assert_eq!(name, "city_counts");
+        let yield_clause = yield_clause.expect("YIELD clause");
+        assert_eq!(yield_clause.items.len(), 2);
+        assert!(yield_clause.where_clause.is_some());
+
+        // A MATCH pattern is not mistaken for a view scan
+        assert!(matches!(
+            parse_statement("MATCH (view) RETURN view"),
+            Ok(Stmt::Match(_))
+        ));
+        assert!(parse_statement("CREATE MATERIALIZED VIEW v MATCH (n) RETURN n").is_err());
+    }
+
+    #[test]
+    fn test_optimizer_hints() {
+        let query = "MATCH /*+ USE_INDEX(p, idx_person_age) EXPAND_DIRECTION(e, IN) */ \
+                     (p:person)-[e:follow]->(f) WHERE p.age > 30 RETURN f";
+        let result = Parser::new(query)
+            .parse()
+            .expect("hinted MATCH should parse");
+        assert!(matches!(result.ast.stmt, Stmt::Match(_)));
+        assert_eq!(result.ast.hints().use_index("p"), Some("idx_person_age"));
+        assert_eq!(
+            result.ast.hints().expand_direction("e"),
+            Some(EdgeDirection::In)
+        );
+
+        // Ordinary comments carry no hints
+        let result = Parser::new("MATCH /* USE_INDEX(p, idx) */ (p) RETURN p")
+            .parse()
+            .expect("commented MATCH should parse");
+        assert!(result.ast.hints().is_empty());
+    }
 }
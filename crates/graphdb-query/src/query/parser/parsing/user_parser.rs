@@ -1,7 +1,10 @@
 //! User Management Statement Parsing Module
 //!
 //! Responsible for parsing statements related to user management, including CREATE USER, ALTER USER, DROP USER, CHANGE PASSWORD, etc.
+//! Also parses custom roles: CREATE ROLE and the fine-grained forms of GRANT,
+//! and API key management: CREATE API KEY, REVOKE API KEY and SHOW API KEYS.
 
+use crate::core::{Permission, PrivilegeAction, PrivilegeGrant, PrivilegeObject};
 use crate::query::parser::ast::stmt::*;
 use crate::query::parser::ast::types::Span;
 use crate::query::parser::core::error::{ParseError, ParseErrorKind};
@@ -101,12 +104,14 @@ impl UserParser {
         let mut is_locked = None;
 
         // Analyzing the WITH PASSWORD or SET clause
+        let mut quota = UserQuotaOptions::default();
         if ctx.match_token(TokenKind::With) {
             if ctx.match_token(TokenKind::Password) {
                 password = Some(ctx.expect_string_literal()?);
             } else if ctx.match_token(TokenKind::Role) {
                 new_role = Some(ctx.expect_identifier()?);
             }
+            quota = self.parse_quota_options(ctx)?;
         }
 
         // The SET ROLE = ... and SET LOCKED = ... syntax are also supported.
@@ -121,6 +126,18 @@ impl UserParser {
             }
         }
 
+        // ACCOUNT LOCK | ACCOUNT UNLOCK
+        if ctx.check_keyword("ACCOUNT") {
+            ctx.next_token();
+            if ctx.check_keyword("UNLOCK") {
+                ctx.next_token();
+                is_locked = Some(false);
+            } else {
+                ctx.consume_keyword("LOCK")?;
+                is_locked = Some(true);
+            }
+        }
+
         let end_span = ctx.current_span();
         let span = ctx.merge_span(start_span.start, end_span.end);
 
@@ -130,9 +147,47 @@ impl UserParser {
             password,
             new_role,
             is_locked,
+            quota,
         }))
     }
 
+    /// Analysis of the resource limits of ALTER USER
+    /// Syntax: `MAX_QUERIES_PER_HOUR <n> | MAX_UPDATES_PER_HOUR <n> | MAX_CONNECTIONS_PER_HOUR <n> | MAX_USER_CONNECTIONS <n>`, repeated
+    pub fn parse_quota_options(
+        &mut self,
+        ctx: &mut ParseContext,
+    ) -> Result<UserQuotaOptions, ParseError> {
+        let mut quota = UserQuotaOptions::default();
+        loop {
+            let slot = if ctx.check_keyword("MAX_QUERIES_PER_HOUR") {
+                &mut quota.max_queries_per_hour
+            } else if ctx.check_keyword("MAX_UPDATES_PER_HOUR") {
+                &mut quota.max_updates_per_hour
+            } else if ctx.check_keyword("MAX_CONNECTIONS_PER_HOUR") {
+                &mut quota.max_connections_per_hour
+            } else if ctx.check_keyword("MAX_USER_CONNECTIONS") {
+                &mut quota.max_user_connections
+            } else {
+                break;
+            };
+            ctx.next_token();
+
+            let position = ctx.current_position();
+            let limit = ctx.expect_integer_literal()?;
+            match i32::try_from(limit) {
+                Ok(limit) if limit >= 0 => *slot = Some(limit),
+                _ => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::SyntaxError,
+                        format!("Invalid resource limit: {}", limit),
+                        position,
+                    ));
+                }
+            }
+        }
+        Ok(quota)
+    }
+
     /// Analysis of the DROP USER statement
     pub fn parse_drop_user_statement(
         &mut self,
@@ -248,15 +303,186 @@ impl UserParser {
             .map_err(|e| ParseError::new(ParseErrorKind::SyntaxError, e, ctx.current_position()))
     }
 
+    /// Analysis of the CREATE ROLE statement (the CREATE token has already been consumed)
+    /// Syntax: `CREATE ROLE [IF NOT EXISTS] <role_name> [INHERITS <role_name>, ...]`
+    pub fn parse_create_role_statement_after_create(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: Span,
+    ) -> Result<Stmt, ParseError> {
+        ctx.expect_token(TokenKind::Role)?;
+
+        let mut if_not_exists = false;
+        if ctx.match_token(TokenKind::If) {
+            ctx.expect_token(TokenKind::Not)?;
+            ctx.expect_token(TokenKind::Exists)?;
+            if_not_exists = true;
+        }
+
+        let role_name = ctx.expect_identifier()?;
+
+        let mut parents = Vec::new();
+        if ctx.check_keyword("INHERITS") {
+            ctx.consume_keyword("INHERITS")?;
+            loop {
+                parents.push(ctx.expect_identifier()?);
+                if !ctx.match_token(TokenKind::Comma) {
+                    break;
+                }
+            }
+        }
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::CreateRole(CreateRoleStmt {
+            span,
+            role_name,
+            parents,
+            if_not_exists,
+        }))
+    }
+
+    /// Analysis of the CREATE API KEY statement (the CREATE token has already been consumed)
+    /// Syntax: `CREATE API KEY FOR <username> [SCOPES (<permission>, ...)] [EXPIRES <seconds>]`
+    pub fn parse_create_api_key_statement_after_create(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: Span,
+    ) -> Result<Stmt, ParseError> {
+        ctx.consume_keyword("API")?;
+        ctx.consume_keyword("KEY")?;
+        ctx.consume_keyword("FOR")?;
+        let username = ctx.expect_identifier()?;
+
+        let mut scopes = Vec::new();
+        if ctx.check_keyword("SCOPES") {
+            ctx.consume_keyword("SCOPES")?;
+            ctx.expect_token(TokenKind::LParen)?;
+            loop {
+                let scope = self.parse_permission(ctx)?;
+                if !scopes.contains(&scope) {
+                    scopes.push(scope);
+                }
+                if !ctx.match_token(TokenKind::Comma) {
+                    break;
+                }
+            }
+            ctx.expect_token(TokenKind::RParen)?;
+        }
+
+        let mut expires_in_secs = None;
+        if ctx.check_keyword("EXPIRES") {
+            ctx.consume_keyword("EXPIRES")?;
+            let position = ctx.current_position();
+            let secs = ctx.expect_integer_literal()?;
+            if secs <= 0 {
+                return Err(ParseError::new(
+                    ParseErrorKind::SyntaxError,
+                    "EXPIRES must be a positive number of seconds".to_string(),
+                    position,
+                ));
+            }
+            expires_in_secs = Some(secs as u64);
+        }
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::ApiKey(ApiKeyStmt {
+            span,
+            action: ApiKeyAction::Create {
+                username,
+                scopes,
+                expires_in_secs,
+            },
+        }))
+    }
+
+    /// Analysis of the SHOW API KEYS statement (the SHOW token has already been consumed)
+    /// Syntax: `SHOW API KEYS [FOR <username>]`
+    pub fn parse_show_api_keys_after_show(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: Span,
+    ) -> Result<Stmt, ParseError> {
+        ctx.consume_keyword("API")?;
+        ctx.consume_keyword("KEYS")?;
+
+        let username = if ctx.check_keyword("FOR") {
+            ctx.consume_keyword("FOR")?;
+            Some(ctx.expect_identifier()?)
+        } else {
+            None
+        };
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::ApiKey(ApiKeyStmt {
+            span,
+            action: ApiKeyAction::Show { username },
+        }))
+    }
+
+    /// Analysis of a permission name in an API key scope list
+    fn parse_permission(&mut self, ctx: &mut ParseContext) -> Result<Permission, ParseError> {
+        let position = ctx.current_position();
+        let name = match ctx.peek_token().kind {
+            TokenKind::Delete => "DELETE".to_string(),
+            TokenKind::Admin => "ADMIN".to_string(),
+            TokenKind::Identifier(ref name) => name.clone(),
+            ref other => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedToken,
+                    format!("Expected a permission, found {:?}", other),
+                    position,
+                ));
+            }
+        };
+        ctx.next_token();
+        name.parse::<Permission>()
+            .map_err(|e| ParseError::new(ParseErrorKind::SyntaxError, e, position))
+    }
+
     /// Analysis of the GRANT statement
-    /// Syntax:  `GRANT ROLE <role_type> ON <space_name> TO <username>`
+    /// Syntax:
+    /// - `GRANT ROLE <role_type> ON <space_name> TO <username>`
+    /// - `GRANT READ|WRITE ON TAG <tag>[(<prop>, ...)] | EDGE <edge>[(<prop>, ...)] TO <role_name>`
+    /// - `GRANT ROLE <role_name> TO <username>`
     pub fn parse_grant_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
         let start_span = ctx.current_span();
         ctx.expect_token(TokenKind::Grant)?;
 
+        if ctx.check_keyword("READ") || ctx.check_keyword("WRITE") {
+            return self.parse_grant_privilege(ctx, start_span);
+        }
+
         // Analyzing the ROLE keyword (optional)
         let _ = ctx.match_token(TokenKind::Role);
 
+        // A custom role is granted to a user directly, without a space
+        let is_custom_role = ctx.lookahead(|c| {
+            c.next_token();
+            c.check_token(TokenKind::To)
+        });
+        if is_custom_role {
+            let role_name = ctx.expect_identifier()?;
+            ctx.expect_token(TokenKind::To)?;
+            let _ = ctx.match_token(TokenKind::User);
+            let username = ctx.expect_identifier()?;
+
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            return Ok(Stmt::GrantPrivilege(GrantPrivilegeStmt {
+                span,
+                kind: GrantPrivilegeKind::Role {
+                    role_name,
+                    username,
+                },
+            }));
+        }
+
         // Analyzing character types
         let role = self.parse_role_type(ctx)?;
 
@@ -283,12 +509,81 @@ impl UserParser {
         }))
     }
 
+    /// Analysis of `READ|WRITE ON TAG|EDGE <name>[(<prop>, ...)] TO <role_name>`
+    fn parse_grant_privilege(
+        &mut self,
+        ctx: &mut ParseContext,
+        start_span: Span,
+    ) -> Result<Stmt, ParseError> {
+        let action = if ctx.check_keyword("READ") {
+            ctx.consume_keyword("READ")?;
+            PrivilegeAction::Read
+        } else {
+            ctx.consume_keyword("WRITE")?;
+            PrivilegeAction::Write
+        };
+
+        ctx.expect_token(TokenKind::On)?;
+
+        let object = if ctx.match_token(TokenKind::Tag) {
+            PrivilegeObject::Tag(ctx.expect_identifier()?)
+        } else if ctx.match_token(TokenKind::Edge) {
+            PrivilegeObject::Edge(ctx.expect_identifier()?)
+        } else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken,
+                "Expected TAG or EDGE in GRANT".to_string(),
+                ctx.current_position(),
+            ));
+        };
+
+        let mut grant = PrivilegeGrant::new(action, object);
+        if ctx.match_token(TokenKind::LParen) {
+            let mut properties = Vec::new();
+            loop {
+                properties.push(ctx.expect_identifier()?);
+                if !ctx.match_token(TokenKind::Comma) {
+                    break;
+                }
+            }
+            ctx.expect_token(TokenKind::RParen)?;
+            grant = grant.with_properties(properties);
+        }
+
+        ctx.expect_token(TokenKind::To)?;
+        let _ = ctx.match_token(TokenKind::Role);
+        let role_name = ctx.expect_identifier()?;
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::GrantPrivilege(GrantPrivilegeStmt {
+            span,
+            kind: GrantPrivilegeKind::Privilege { grant, role_name },
+        }))
+    }
+
     /// Analysis of the REVOKE statement
-    /// Syntax: `REVOKE ROLE <role_type> ON <space_name> FROM <username>`
+    /// Syntax:
+    /// - `REVOKE ROLE <role_type> ON <space_name> FROM <username>`
+    /// - `REVOKE API KEY '<key_id>'`
     pub fn parse_revoke_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
         let start_span = ctx.current_span();
         ctx.expect_token(TokenKind::Revoke)?;
 
+        if ctx.check_keyword("API") {
+            ctx.consume_keyword("API")?;
+            ctx.consume_keyword("KEY")?;
+            let key_id = ctx.expect_string_literal()?;
+
+            let end_span = ctx.current_span();
+            let span = ctx.merge_span(start_span.start, end_span.end);
+            return Ok(Stmt::ApiKey(ApiKeyStmt {
+                span,
+                action: ApiKeyAction::Revoke { key_id },
+            }));
+        }
+
         // Analysis of the ROLE keyword (optional)
         let _ = ctx.match_token(TokenKind::Role);
 
@@ -476,6 +771,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_alter_user_quota() {
+        let mut parser = Parser::new(
+            "ALTER USER alice WITH MAX_QUERIES_PER_HOUR 1000 max_user_connections 5 MAX_UPDATES_PER_HOUR 0",
+        );
+        let stmt = parser
+            .parse()
+            .expect("ALTER USER with limits should parse")
+            .ast
+            .stmt
+            .clone();
+        let Stmt::AlterUser(alter) = stmt else {
+            panic!("expected ALTER USER, got {:?}", stmt);
+        };
+        assert_eq!(alter.username, "alice");
+        assert_eq!(alter.quota.max_queries_per_hour, Some(1000));
+        assert_eq!(alter.quota.max_updates_per_hour, Some(0));
+        assert_eq!(alter.quota.max_connections_per_hour, None);
+        assert_eq!(alter.quota.max_user_connections, Some(5));
+
+        let mut parser = Parser::new("ALTER USER alice WITH MAX_QUERIES_PER_HOUR 99999999999");
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_parse_alter_user_account_lock() {
+        for (query, expected) in [
+            ("ALTER USER alice ACCOUNT UNLOCK", false),
+            ("ALTER USER alice account lock", true),
+            (
+                "ALTER USER alice WITH PASSWORD 'S3cret' ACCOUNT UNLOCK",
+                false,
+            ),
+        ] {
+            let stmt = Parser::new(query)
+                .parse()
+                .expect("ALTER USER ... ACCOUNT should parse")
+                .ast
+                .stmt
+                .clone();
+            let Stmt::AlterUser(alter) = stmt else {
+                panic!("expected ALTER USER, got {:?}", stmt);
+            };
+            assert_eq!(alter.is_locked, Some(expected), "{}", query);
+        }
+
+        let mut parser = Parser::new("ALTER USER alice ACCOUNT");
+        assert!(parser.parse().is_err());
+    }
+
     #[test]
     fn test_parse_drop_user_unicode() {
         let query = "DROP USER 用户";
@@ -601,6 +946,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_create_role_with_inheritance() {
+        let mut parser = Parser::new("CREATE ROLE IF NOT EXISTS analyst INHERITS reader, auditor");
+        let stmt = parser
+            .parse()
+            .expect("CREATE ROLE should parse")
+            .ast
+            .stmt
+            .clone();
+        let Stmt::CreateRole(create) = stmt else {
+            panic!("expected CREATE ROLE, got {:?}", stmt);
+        };
+        assert_eq!(create.role_name, "analyst");
+        assert_eq!(
+            create.parents,
+            vec!["reader".to_string(), "auditor".to_string()]
+        );
+        assert!(create.if_not_exists);
+    }
+
+    #[test]
+    fn test_parse_api_key_statements() {
+        let parse = |query: &str| {
+            Parser::new(query)
+                .parse()
+                .unwrap_or_else(|e| panic!("{} should parse: {:?}", query, e))
+                .ast
+                .stmt
+                .clone()
+        };
+
+        let stmt = parse("CREATE API KEY FOR alice SCOPES (READ, write, READ) EXPIRES 3600");
+        let Stmt::ApiKey(ApiKeyStmt {
+            action:
+                ApiKeyAction::Create {
+                    username,
+                    scopes,
+                    expires_in_secs,
+                },
+            ..
+        }) = stmt
+        else {
+            panic!("expected CREATE API KEY, got {:?}", stmt);
+        };
+        assert_eq!(username, "alice");
+        assert_eq!(scopes, vec![Permission::Read, Permission::Write]);
+        assert_eq!(expires_in_secs, Some(3600));
+
+        assert!(matches!(
+            parse("REVOKE API KEY '0123abcd'"),
+            Stmt::ApiKey(ApiKeyStmt {
+                action: ApiKeyAction::Revoke { ref key_id },
+                ..
+            }) if key_id == "0123abcd"
+        ));
+        assert!(matches!(
+            parse("SHOW API KEYS FOR alice"),
+            Stmt::ApiKey(ApiKeyStmt {
+                action: ApiKeyAction::Show { username: Some(ref name) },
+                ..
+            }) if name == "alice"
+        ));
+        assert!(matches!(
+            parse("SHOW API KEYS"),
+            Stmt::ApiKey(ApiKeyStmt {
+                action: ApiKeyAction::Show { username: None },
+                ..
+            })
+        ));
+
+        assert!(Parser::new("CREATE API KEY FOR alice SCOPES (OWNER)")
+            .parse()
+            .is_err());
+        assert!(Parser::new("CREATE API KEY FOR alice EXPIRES 0")
+            .parse()
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_grant_property_privilege() {
+        let mut parser = Parser::new("GRANT READ ON TAG person(name, age) TO analyst");
+        let stmt = parser
+            .parse()
+            .expect("GRANT READ should parse")
+            .ast
+            .stmt
+            .clone();
+        let Stmt::GrantPrivilege(grant) = stmt else {
+            panic!("expected GRANT privilege, got {:?}", stmt);
+        };
+        let GrantPrivilegeKind::Privilege { grant, role_name } = grant.kind else {
+            panic!("expected a privilege grant");
+        };
+        assert_eq!(role_name, "analyst");
+        assert_eq!(grant.action, PrivilegeAction::Read);
+        assert_eq!(grant.object, PrivilegeObject::Tag("person".to_string()));
+        assert_eq!(
+            grant.properties,
+            Some(vec!["name".to_string(), "age".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_grant_edge_and_custom_role() {
+        let mut parser = Parser::new("GRANT WRITE ON EDGE click TO ROLE tracker");
+        let stmt = parser
+            .parse()
+            .expect("GRANT WRITE should parse")
+            .ast
+            .stmt
+            .clone();
+        assert!(matches!(
+            stmt,
+            Stmt::GrantPrivilege(GrantPrivilegeStmt {
+                kind: GrantPrivilegeKind::Privilege { .. },
+                ..
+            })
+        ));
+
+        let mut parser = Parser::new("GRANT ROLE tracker TO svc_clicks");
+        let stmt = parser
+            .parse()
+            .expect("GRANT ROLE should parse")
+            .ast
+            .stmt
+            .clone();
+        let Stmt::GrantPrivilege(grant) = stmt else {
+            panic!("expected GRANT role, got {:?}", stmt);
+        };
+        assert_eq!(
+            grant.kind,
+            GrantPrivilegeKind::Role {
+                role_name: "tracker".to_string(),
+                username: "svc_clicks".to_string(),
+            }
+        );
+
+        // The space-scoped form keeps its meaning
+        let mut parser = Parser::new("GRANT ROLE ADMIN ON space TO user");
+        let stmt = parser
+            .parse()
+            .expect("GRANT ROLE ON should parse")
+            .ast
+            .stmt
+            .clone();
+        assert!(matches!(stmt, Stmt::Grant(_)));
+    }
+
     #[test]
     fn test_parse_show_roles_with_and_without_space() {
         let query1 = "SHOW ROLES";
@@ -309,6 +309,68 @@ impl UtilStmtParser {
         }))
     }
 
+    /// Analysis of the CALL statement
+    pub fn parse_call_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
+        let start_span = ctx.current_span();
+        ctx.expect_token(TokenKind::Call)?;
+
+        let procedure = self.parse_procedure_name(ctx)?;
+
+        ctx.expect_token(TokenKind::LParen)?;
+        let args = if ctx.check_token(TokenKind::RParen) {
+            Vec::new()
+        } else {
+            self.parse_expression_list(ctx)?
+        };
+        ctx.expect_token(TokenKind::RParen)?;
+
+        let yield_clause = if ctx.match_token(TokenKind::Yield) {
+            Some(ClauseParser::new().parse_yield_clause(ctx)?)
+        } else {
+            None
+        };
+
+        let end_span = ctx.current_span();
+        let span = ctx.merge_span(start_span.start, end_span.end);
+
+        Ok(Stmt::Call(CallStmt {
+            span,
+            procedure,
+            args,
+            yield_clause,
+        }))
+    }
+
+    /// Parse a dotted procedure name; segments may be keywords, as in `db.indexes`
+    fn parse_procedure_name(&mut self, ctx: &mut ParseContext) -> Result<String, ParseError> {
+        let mut segments = Vec::new();
+        loop {
+            let token = ctx.current_token().clone();
+            let is_word = token
+                .lexeme
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_')
+                && token
+                    .lexeme
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_');
+            if !is_word {
+                return Err(ParseError::new(
+                    ParseErrorKind::SyntaxError,
+                    format!("Expected procedure name, found {:?}", token.kind),
+                    ctx.current_position(),
+                ));
+            }
+            segments.push(token.lexeme);
+            ctx.next_token();
+            if !ctx.match_token(TokenKind::Dot) {
+                break;
+            }
+        }
+        Ok(segments.join("."))
+    }
+
     /// Analysis of the RETURN statement
     pub fn parse_return_statement(&mut self, ctx: &mut ParseContext) -> Result<Stmt, ParseError> {
         let start_span = ctx.current_span();
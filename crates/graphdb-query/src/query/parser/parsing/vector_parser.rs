@@ -258,7 +258,7 @@ pub fn parse_search_vector_statement(
 }
 
 /// Parse vector query expression
-fn parse_vector_query_expr(
+pub(crate) fn parse_vector_query_expr(
     ctx: &mut ParseContext,
 ) -> Result<VectorQueryExpr, crate::query::parser::ParseError> {
     let span = ctx.current_span();
@@ -384,7 +384,7 @@ fn parse_order_clause(
 }
 
 /// Parse YIELD clause
-fn parse_vector_yield_clause(
+pub(crate) fn parse_vector_yield_clause(
     ctx: &mut ParseContext,
 ) -> Result<VectorYieldClause, crate::query::parser::ParseError> {
     let mut items = Vec::new();
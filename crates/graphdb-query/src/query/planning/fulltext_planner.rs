@@ -6,18 +6,21 @@
 
 use std::sync::Arc;
 
-use crate::query::metadata::MetadataContext;
+use crate::query::metadata::{IndexType, MetadataContext};
 use crate::query::parser::ast::fulltext::{
     AlterFulltextIndex, CreateFulltextIndex, DescribeFulltextIndex, DropFulltextIndex,
     FulltextQueryExpr, LookupFulltext, MatchFulltext, SearchStatement, ShowFulltextIndex,
 };
+use crate::query::parser::ast::hybrid::HybridSearchStatement;
+use crate::query::parser::ast::stmt::SyncDlqStmt;
 use crate::query::parser::ast::Stmt;
 use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
 use crate::query::planning::plan::core::nodes::search::fulltext::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
     DropFulltextIndexNode, FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
-    ShowFulltextIndexNode,
+    ShowFulltextIndexNode, SyncDlqNode,
 };
+use crate::query::planning::plan::core::nodes::search::hybrid::HybridSearchNode;
 use crate::query::planning::plan::SubPlan;
 use crate::query::planning::planner::{Planner, PlannerError, ValidatedStatement};
 use crate::query::QueryContext;
@@ -27,6 +30,8 @@ use crate::query::QueryContext;
 /// This planner handles all full-text search related statements including:
 /// - Index management (CREATE/DROP/ALTER/SHOW/DESCRIBE FULLTEXT INDEX)
 /// - Search operations (SEARCH, LOOKUP FULLTEXT, MATCH with full-text)
+/// - Hybrid search (SEARCH HYBRID), fusing a full-text and a vector ranking
+/// - Index sync dead letter queue management (SHOW/RETRY/PURGE SYNC DLQ)
 #[derive(Debug, Clone, Default)]
 pub struct FulltextSearchPlanner {
     /// Metadata context for pre-resolved metadata (optional for backward compatibility)
@@ -69,6 +74,8 @@ impl Planner for FulltextSearchPlanner {
                 | Stmt::Search(_)
                 | Stmt::LookupFulltext(_)
                 | Stmt::MatchFulltext(_)
+                | Stmt::HybridSearch(_)
+                | Stmt::SyncDlq(_)
         )
     }
 
@@ -138,6 +145,13 @@ impl FulltextSearchPlanner {
                 }
                 None => self.transform_match_fulltext(match_stmt),
             },
+            Stmt::HybridSearch(search) => match metadata_context {
+                Some(metadata_context) => {
+                    self.transform_hybrid_search_with_metadata(search, metadata_context)
+                }
+                None => self.transform_hybrid_search(search),
+            },
+            Stmt::SyncDlq(dlq) => self.transform_sync_dlq(dlq),
             _ => Err(PlannerError::PlanGenerationFailed(
                 "Not a full-text search statement".to_string(),
             )),
@@ -262,6 +276,11 @@ impl FulltextSearchPlanner {
         Ok(SubPlan::new(Some(node.into_enum()), None))
     }
 
+    fn transform_sync_dlq(&self, dlq: &SyncDlqStmt) -> Result<SubPlan, PlannerError> {
+        let node = SyncDlqNode::new(dlq.action);
+        Ok(SubPlan::new(Some(node.into_enum()), None))
+    }
+
     fn transform_describe_fulltext_index(
         &self,
         describe: &DescribeFulltextIndex,
@@ -450,6 +469,60 @@ impl FulltextSearchPlanner {
         Ok(SubPlan::new(Some(node.into_enum()), None))
     }
 
+    fn transform_hybrid_search(
+        &self,
+        search: &HybridSearchStatement,
+    ) -> Result<SubPlan, PlannerError> {
+        self.validate_query_expr(&search.fulltext_query)?;
+
+        let node = HybridSearchNode::new(search);
+        Ok(SubPlan::new(Some(node.into_enum()), None))
+    }
+
+    fn transform_hybrid_search_with_metadata(
+        &self,
+        search: &HybridSearchStatement,
+        metadata_context: &MetadataContext,
+    ) -> Result<SubPlan, PlannerError> {
+        self.validate_query_expr(&search.fulltext_query)?;
+
+        let fulltext_metadata = metadata_context
+            .get_index_metadata(&search.fulltext_index)
+            .ok_or_else(|| PlannerError::IndexNotFound(search.fulltext_index.clone()))?;
+        let vector_metadata = metadata_context
+            .get_index_metadata(&search.vector_index)
+            .ok_or_else(|| PlannerError::IndexNotFound(search.vector_index.clone()))?;
+
+        if fulltext_metadata.index_type != IndexType::Fulltext {
+            return Err(PlannerError::InvalidOperation(format!(
+                "Index '{}' is not a full-text index",
+                search.fulltext_index
+            )));
+        }
+        if vector_metadata.index_type != IndexType::Vector {
+            return Err(PlannerError::InvalidOperation(format!(
+                "Index '{}' is not a vector index",
+                search.vector_index
+            )));
+        }
+
+        // Both rankings are fused per vertex, so they must rank the same tag
+        if fulltext_metadata.tag_name != vector_metadata.tag_name {
+            return Err(PlannerError::InvalidOperation(format!(
+                "SEARCH HYBRID requires both indexes on the same tag, got '{}' and '{}'",
+                fulltext_metadata.tag_name, vector_metadata.tag_name
+            )));
+        }
+
+        let node = HybridSearchNode::new(search).with_metadata(
+            fulltext_metadata.space_id,
+            fulltext_metadata.tag_name.clone(),
+            fulltext_metadata.field_name.clone(),
+            vector_metadata.field_name.clone(),
+        );
+        Ok(SubPlan::new(Some(node.into_enum()), None))
+    }
+
     // ============================================================================
     // Validation Helpers
     // ============================================================================
@@ -683,4 +756,71 @@ mod tests {
             .validate_query_expr(&FulltextQueryExpr::Fuzzy("database".to_string(), Some(10)))
             .is_err());
     }
+
+    #[test]
+    fn test_hybrid_search_requires_indexes_on_same_tag() {
+        use crate::query::metadata::IndexMetadata;
+        use crate::query::parser::ast::hybrid::FusionMethod;
+        use crate::query::parser::ast::vector::{VectorQueryExpr, VectorQueryType};
+        use crate::query::planning::plan::PlanNodeEnum;
+
+        let search = HybridSearchStatement {
+            span: Span::default(),
+            fulltext_index: "idx_title".to_string(),
+            fulltext_query: FulltextQueryExpr::Simple("graph".to_string()),
+            vector_index: "idx_embedding".to_string(),
+            vector_query: VectorQueryExpr {
+                span: Span::default(),
+                query_type: VectorQueryType::Vector,
+                query_data: "[0.1, 0.2]".to_string(),
+            },
+            fusion: FusionMethod::default(),
+            where_clause: None,
+            limit: None,
+            offset: None,
+            yield_clause: None,
+        };
+        let index = |name: &str, tag: &str, field: &str, index_type| {
+            IndexMetadata::new(
+                name.to_string(),
+                1,
+                tag.to_string(),
+                field.to_string(),
+                index_type,
+            )
+        };
+
+        let planner = FulltextSearchPlanner::new();
+        let mut metadata_context = MetadataContext::new();
+        metadata_context.set_index_metadata(
+            "idx_title".to_string(),
+            index("idx_title", "paper", "title", IndexType::Fulltext),
+        );
+        metadata_context.set_index_metadata(
+            "idx_embedding".to_string(),
+            index("idx_embedding", "paper", "embedding", IndexType::Vector),
+        );
+
+        let plan = planner
+            .transform_hybrid_search_with_metadata(&search, &metadata_context)
+            .expect("indexes on the same tag should plan");
+        let Some(PlanNodeEnum::HybridSearch(node)) = plan.root else {
+            panic!("HybridSearch node expected");
+        };
+        assert_eq!(node.tag_name, "paper");
+        assert_eq!(node.fulltext_field, "title");
+        assert_eq!(node.vector_field, "embedding");
+        assert_eq!(
+            node.col_names(),
+            ["vid", "score", "fulltext_score", "vector_score"]
+        );
+
+        metadata_context.set_index_metadata(
+            "idx_embedding".to_string(),
+            index("idx_embedding", "author", "embedding", IndexType::Vector),
+        );
+        assert!(planner
+            .transform_hybrid_search_with_metadata(&search, &metadata_context)
+            .is_err());
+    }
 }
@@ -11,8 +11,9 @@ pub use nodes::{
     AggregateNode, AlterSpaceNode, AppendVerticesNode, ArgumentNode, ClearSpaceNode, CrossJoinNode,
     DataCollectNode, DedupNode, ExpandAllNode, ExpandNode, FilterNode, GetEdgesNode,
     GetNeighborsNode, GetVerticesNode, HashInnerJoinNode, InnerJoinNode, LeftJoinNode, LimitNode,
-    LoopNode, PassThroughNode, PatternApplyNode, PlanNodeFactory, ProjectNode, RollUpApplyNode,
-    ScanEdgesNode, ScanVerticesNode, SelectNode, ShowCreateEdgeNode, ShowCreateIndexNode,
-    ShowCreateSpaceNode, ShowIndexesNode, ShowRolesNode, ShowSpacesNode, ShowStatsNode,
-    ShowStatsType, ShowUsersNode, SortNode, StartNode, TraverseNode, UnionNode, UnwindNode,
+    LoopNode, MaterializedViewScanNode, MultiwayJoinNode, PassThroughNode, PatternApplyNode,
+    PlanNodeFactory, ProcedureCallNode, ProjectNode, RollUpApplyNode, ScanEdgesNode,
+    ScanVerticesNode, SelectNode, ShowCreateEdgeNode, ShowCreateIndexNode, ShowCreateSpaceNode,
+    ShowIndexesNode, ShowRolesNode, ShowSpacesNode, ShowStatsNode, ShowStatsType, ShowUsersNode,
+    SortNode, StartNode, TraverseNode, UnionNode, UnwindNode,
 };
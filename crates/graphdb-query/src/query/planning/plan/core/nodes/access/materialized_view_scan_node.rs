@@ -0,0 +1,36 @@
+//! Materialized view scan node
+//!
+//! Leaf node produced by MATCH VIEW and by queries rewritten to read a materialized
+//! view. The rows are looked up when the plan is executed, so a cached plan always
+//! sees the current contents of the view.
+
+use crate::define_plan_node;
+
+define_plan_node! {
+    pub struct MaterializedViewScanNode {
+        space_id: u64,
+        view_name: String,
+    }
+    enum: MaterializedViewScan
+    input: ZeroInputNode
+}
+
+impl MaterializedViewScanNode {
+    pub fn new(id: i64, space_id: u64, view_name: String) -> Self {
+        Self {
+            id,
+            space_id,
+            view_name,
+            col_names: Vec::new(),
+            output_var: None,
+        }
+    }
+
+    pub fn space_id(&self) -> u64 {
+        self.space_id
+    }
+
+    pub fn view_name(&self) -> &str {
+        &self.view_name
+    }
+}
@@ -1,8 +1,12 @@
 pub mod graph_scan_node;
 pub mod index_scan;
+pub mod materialized_view_scan_node;
+pub mod procedure_call_node;
 
 pub use graph_scan_node::{
     EdgeIndexScanNode, GetEdgesNode, GetNeighborsNode, GetVerticesNode, ScanEdgesNode,
     ScanVerticesNode,
 };
 pub use index_scan::{IndexLimit, IndexScanNode, OrderByItem, ScanType};
+pub use materialized_view_scan_node::MaterializedViewScanNode;
+pub use procedure_call_node::ProcedureCallNode;
@@ -0,0 +1,55 @@
+//! Procedure call node
+//!
+//! Leaf node produced by the CALL statement. The procedure is resolved against the
+//! procedure registry when the executor is built, so the node only records the call.
+
+use crate::core::types::ContextualExpression;
+use crate::define_plan_node;
+
+define_plan_node! {
+    pub struct ProcedureCallNode {
+        space_name: Option<String>,
+        procedure: String,
+        args: Vec<ContextualExpression>,
+        yield_columns: Vec<String>,
+    }
+    enum: ProcedureCall
+    input: ZeroInputNode
+}
+
+impl ProcedureCallNode {
+    pub fn new(
+        id: i64,
+        space_name: Option<String>,
+        procedure: String,
+        args: Vec<ContextualExpression>,
+        yield_columns: Vec<String>,
+    ) -> Self {
+        Self {
+            id,
+            space_name,
+            procedure,
+            args,
+            col_names: yield_columns.clone(),
+            yield_columns,
+            output_var: None,
+        }
+    }
+
+    pub fn space_name(&self) -> Option<&str> {
+        self.space_name.as_deref()
+    }
+
+    pub fn procedure(&self) -> &str {
+        &self.procedure
+    }
+
+    pub fn args(&self) -> &[ContextualExpression] {
+        &self.args
+    }
+
+    /// Procedure columns selected by YIELD; empty means every output column
+    pub fn yield_columns(&self) -> &[String] {
+        &self.yield_columns
+    }
+}
@@ -15,6 +15,9 @@ impl PlanNodeEnum {
             | PlanNodeEnum::FulltextManage(_)
             | PlanNodeEnum::VectorManage(_)
             | PlanNodeEnum::ShowStats(_)
+            | PlanNodeEnum::ProcedureCall(_)
+            | PlanNodeEnum::MaterializedViewScan(_)
+            | PlanNodeEnum::MultiwayJoin(_)
             | PlanNodeEnum::InsertVertices(_)
             | PlanNodeEnum::InsertEdges(_)
             | PlanNodeEnum::DeleteVertices(_)
@@ -37,7 +40,8 @@ impl PlanNodeEnum {
             | PlanNodeEnum::MultiShortestPath(_)
             | PlanNodeEnum::FulltextSearch(_)
             | PlanNodeEnum::FulltextLookup(_)
-            | PlanNodeEnum::MatchFulltext(_) => vec![],
+            | PlanNodeEnum::MatchFulltext(_)
+            | PlanNodeEnum::HybridSearch(_) => vec![],
 
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_)
@@ -70,6 +74,15 @@ impl PlanNodeEnum {
             PlanNodeEnum::Unwind(node) => {
                 vec![super::plan_node_traits::SingleInputNode::input(node)]
             }
+            PlanNodeEnum::PatternSubquery(node) => {
+                vec![super::plan_node_traits::SingleInputNode::input(node)]
+            }
+            PlanNodeEnum::CallSubquery(node) => {
+                vec![super::plan_node_traits::SingleInputNode::input(node)]
+            }
+            PlanNodeEnum::Foreach(node) => {
+                vec![super::plan_node_traits::SingleInputNode::input(node)]
+            }
             PlanNodeEnum::Assign(node) => {
                 vec![super::plan_node_traits::SingleInputNode::input(node)]
             }
@@ -9,6 +9,8 @@
 //! (Space, Tag, Edge, Index, User, Fulltext, Vector) is now a single variant
 //! that wraps its corresponding sub-enum.
 
+use crate::query::planning::plan::core::nodes::access::materialized_view_scan_node::MaterializedViewScanNode;
+use crate::query::planning::plan::core::nodes::access::procedure_call_node::ProcedureCallNode;
 use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
 use crate::query::planning::plan::core::nodes::data_modification::{
     DeleteEdgesNode, DeleteIndexNode, DeleteTagsNode, DeleteVerticesNode, InsertEdgesNode,
@@ -23,6 +25,7 @@ use crate::query::planning::plan::core::nodes::management::stats_nodes::ShowStat
 use crate::query::planning::plan::core::nodes::search::fulltext::data_access::{
     FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
 };
+use crate::query::planning::plan::core::nodes::search::hybrid::HybridSearchNode;
 #[cfg(feature = "qdrant")]
 use crate::query::planning::plan::core::nodes::search::vector::data_access::{
     VectorLookupNode, VectorMatchNode, VectorSearchNode,
@@ -41,11 +44,15 @@ pub use crate::query::planning::plan::core::nodes::control_flow::control_flow_no
     SelectNode,
 };
 pub use crate::query::planning::plan::core::nodes::control_flow::start_node::StartNode;
+pub use crate::query::planning::plan::core::nodes::control_flow::subquery_node::{
+    CallSubqueryNode, ForeachNode,
+};
 pub use crate::query::planning::plan::core::nodes::graph_operations::aggregate_node::AggregateNode;
 pub use crate::query::planning::plan::core::nodes::graph_operations::graph_operations_node::{
     ApplyNode, AssignNode, DataCollectNode, DedupNode, MaterializeNode, PatternApplyNode,
     RemoveNode, RollUpApplyNode, UnionNode, UnwindNode,
 };
+pub use crate::query::planning::plan::core::nodes::graph_operations::pattern_subquery_node::PatternSubqueryNode;
 pub use crate::query::planning::plan::core::nodes::graph_operations::set_operations_node::{
     IntersectNode, MinusNode,
 };
@@ -53,6 +60,7 @@ pub use crate::query::planning::plan::core::nodes::join::join_node::{
     CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode, InnerJoinNode,
     LeftJoinNode, RightJoinNode, SemiJoinNode,
 };
+pub use crate::query::planning::plan::core::nodes::join::multiway_join_node::MultiwayJoinNode;
 pub use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
 pub use crate::query::planning::plan::core::nodes::operation::project_node::ProjectNode;
 pub use crate::query::planning::plan::core::nodes::operation::sample_node::SampleNode;
@@ -78,10 +86,10 @@ pub use crate::query::planning::plan::core::nodes::management::edge_nodes::{
     ShowCreateEdgeNode, ShowEdgesNode,
 };
 pub use crate::query::planning::plan::core::nodes::management::index_nodes::{
-    CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode, DescTagIndexNode,
-    DropEdgeIndexNode, DropTagIndexNode, IndexManageInfo, RebuildEdgeIndexNode,
-    RebuildTagIndexNode, ShowCreateIndexNode, ShowEdgeIndexesNode, ShowIndexesNode,
-    ShowTagIndexesNode,
+    CreateConstraintNode, CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode,
+    DescTagIndexNode, DropConstraintNode, DropEdgeIndexNode, DropTagIndexNode, IndexManageInfo,
+    RebuildEdgeIndexNode, RebuildTagIndexNode, ShowConstraintsNode, ShowCreateIndexNode,
+    ShowEdgeIndexesNode, ShowIndexesNode, ShowTagIndexesNode,
 };
 pub use crate::query::planning::plan::core::nodes::management::space_nodes::{
     AlterSpaceNode, ClearSpaceNode, CreateSpaceNode, DescSpaceNode, DropSpaceNode,
@@ -150,6 +158,7 @@ pub enum PlanNodeEnum {
     HashLeftJoin(HashLeftJoinNode),
     FullOuterJoin(FullOuterJoinNode),
     SemiJoin(SemiJoinNode),
+    MultiwayJoin(MultiwayJoinNode),
 
     // Traversal of nodes
     Expand(ExpandNode),
@@ -179,6 +188,9 @@ pub enum PlanNodeEnum {
     Minus(MinusNode),
     Intersect(IntersectNode),
     Unwind(UnwindNode),
+    PatternSubquery(PatternSubqueryNode),
+    CallSubquery(CallSubqueryNode),
+    Foreach(ForeachNode),
     Materialize(MaterializeNode),
     Assign(AssignNode),
     Apply(ApplyNode),
@@ -214,11 +226,20 @@ pub enum PlanNodeEnum {
     // Statistics Nodes ============
     ShowStats(ShowStatsNode),
 
+    // Procedure Nodes
+    ProcedureCall(ProcedureCallNode),
+
+    // Materialized View Nodes
+    MaterializedViewScan(MaterializedViewScanNode),
+
     // Full-text Search Nodes
     FulltextSearch(FulltextSearchNode),
     FulltextLookup(FulltextLookupNode),
     MatchFulltext(MatchFulltextNode),
 
+    // Hybrid Search Nodes
+    HybridSearch(HybridSearchNode),
+
     // Vector Search Nodes
     #[cfg(feature = "qdrant")]
     VectorSearch(VectorSearchNode),
@@ -264,6 +285,7 @@ crate::define_enum_is_methods! {
     (HashLeftJoin, is_hash_left_join),
     (FullOuterJoin, is_full_outer_join),
     (SemiJoin, is_semi_join),
+    (MultiwayJoin, is_multiway_join),
     // Traverse the nodes
     (Expand, is_expand),
     (ExpandAll, is_expand_all),
@@ -285,6 +307,9 @@ crate::define_enum_is_methods! {
     (Minus, is_minus),
     (Intersect, is_intersect),
     (Unwind, is_unwind),
+    (PatternSubquery, is_pattern_subquery),
+    (CallSubquery, is_call_subquery),
+    (Foreach, is_foreach),
     (Materialize, is_materialize),
     (Assign, is_assign),
     (Apply, is_apply),
@@ -315,10 +340,14 @@ crate::define_enum_is_methods! {
     (UpdateEdges, is_update_edges),
     // Statistical nodes
     (ShowStats, is_show_stats),
+    (ProcedureCall, is_procedure_call),
+    (MaterializedViewScan, is_materialized_view_scan),
     // Full-text Search Nodes
     (FulltextSearch, is_fulltext_search),
     (FulltextLookup, is_fulltext_lookup),
     (MatchFulltext, is_match_fulltext),
+    // Hybrid Search Nodes
+    (HybridSearch, is_hybrid_search),
     // Vector Search Nodes
 }
 
@@ -358,6 +387,7 @@ crate::define_enum_as_methods! {
     (HashInnerJoin, as_hash_inner_join, HashInnerJoinNode),
     (HashLeftJoin, as_hash_left_join, HashLeftJoinNode),
     (FullOuterJoin, as_full_outer_join, FullOuterJoinNode),
+    (MultiwayJoin, as_multiway_join, MultiwayJoinNode),
     // Traverse the nodes
     (Expand, as_expand, ExpandNode),
     (ExpandAll, as_expand_all, ExpandAllNode),
@@ -381,6 +411,9 @@ crate::define_enum_as_methods! {
     (Minus, as_minus, MinusNode),
     (Intersect, as_intersect, IntersectNode),
     (Unwind, as_unwind, UnwindNode),
+    (PatternSubquery, as_pattern_subquery, PatternSubqueryNode),
+    (CallSubquery, as_call_subquery, CallSubqueryNode),
+    (Foreach, as_foreach, ForeachNode),
     (Materialize, as_materialize, MaterializeNode),
     (Assign, as_assign, AssignNode),
     // Algorithm node
@@ -410,10 +443,14 @@ crate::define_enum_as_methods! {
     (UpdateEdges, as_update_edges, UpdateEdgesNode),
     // Statistical node
     (ShowStats, as_show_stats, ShowStatsNode),
+    (ProcedureCall, as_procedure_call, ProcedureCallNode),
+    (MaterializedViewScan, as_materialized_view_scan, MaterializedViewScanNode),
     // Full-text Search Nodes
     (FulltextSearch, as_fulltext_search, FulltextSearchNode),
     (FulltextLookup, as_fulltext_lookup, FulltextLookupNode),
     (MatchFulltext, as_match_fulltext, MatchFulltextNode),
+    // Hybrid Search Nodes
+    (HybridSearch, as_hybrid_search, HybridSearchNode),
     // Vector Search Nodes
 }
 
@@ -453,6 +490,7 @@ crate::define_enum_as_mut_methods! {
     (HashInnerJoin, as_hash_inner_join_mut, HashInnerJoinNode),
     (HashLeftJoin, as_hash_left_join_mut, HashLeftJoinNode),
     (FullOuterJoin, as_full_outer_join_mut, FullOuterJoinNode),
+    (MultiwayJoin, as_multiway_join_mut, MultiwayJoinNode),
     // Traverse the nodes
     (Expand, as_expand_mut, ExpandNode),
     (ExpandAll, as_expand_all_mut, ExpandAllNode),
@@ -476,6 +514,9 @@ crate::define_enum_as_mut_methods! {
     (Minus, as_minus_mut, MinusNode),
     (Intersect, as_intersect_mut, IntersectNode),
     (Unwind, as_unwind_mut, UnwindNode),
+    (PatternSubquery, as_pattern_subquery_mut, PatternSubqueryNode),
+    (CallSubquery, as_call_subquery_mut, CallSubqueryNode),
+    (Foreach, as_foreach_mut, ForeachNode),
     (Materialize, as_materialize_mut, MaterializeNode),
     (Assign, as_assign_mut, AssignNode),
     // Algorithm node
@@ -505,10 +546,14 @@ crate::define_enum_as_mut_methods! {
     (UpdateEdges, as_update_edges_mut, UpdateEdgesNode),
     // Statistical node
     (ShowStats, as_show_stats_mut, ShowStatsNode),
+    (ProcedureCall, as_procedure_call_mut, ProcedureCallNode),
+    (MaterializedViewScan, as_materialized_view_scan_mut, MaterializedViewScanNode),
     // Full-text Search Nodes
     (FulltextSearch, as_fulltext_search_mut, FulltextSearchNode),
     (FulltextLookup, as_fulltext_lookup_mut, FulltextLookupNode),
     (MatchFulltext, as_match_fulltext_mut, MatchFulltextNode),
+    // Hybrid Search Nodes
+    (HybridSearch, as_hybrid_search_mut, HybridSearchNode),
     // Vector Search Nodes
 }
 
@@ -550,6 +595,7 @@ crate::define_enum_type_name! {
     (HashLeftJoin, "HashLeftJoin"),
     (FullOuterJoin, "FullOuterJoin"),
     (SemiJoin, "SemiJoin"),
+    (MultiwayJoin, "MultiwayJoin"),
     // Traverse the nodes
     (Expand, "Expand"),
     (ExpandAll, "ExpandAll"),
@@ -575,6 +621,9 @@ crate::define_enum_type_name! {
     (Minus, "Minus"),
     (Intersect, "Intersect"),
     (Unwind, "Unwind"),
+    (PatternSubquery, "PatternSubquery"),
+    (CallSubquery, "CallSubquery"),
+    (Foreach, "Foreach"),
     (Materialize, "Materialize"),
     (Assign, "Assign"),
     (Apply, "Apply"),
@@ -605,10 +654,14 @@ crate::define_enum_type_name! {
     (UpdateEdges, "UpdateEdges"),
     // Statistical nodes
     (ShowStats, "ShowStats"),
+    (ProcedureCall, "ProcedureCall"),
+    (MaterializedViewScan, "MaterializedViewScan"),
     // Full-text Search Nodes
     (FulltextSearch, "FulltextSearch"),
     (FulltextLookup, "FulltextLookup"),
     (MatchFulltext, "MatchFulltext"),
+    // Hybrid Search Nodes
+    (HybridSearch, "HybridSearch"),
     // Vector Search Nodes
     #[cfg(feature = "qdrant")]
     (VectorSearch, "VectorSearch"),
@@ -648,6 +701,7 @@ crate::define_enum_category! {
     (HashLeftJoin, PlanNodeCategory::Join),
     (FullOuterJoin, PlanNodeCategory::Join),
     (SemiJoin, PlanNodeCategory::Join),
+    (MultiwayJoin, PlanNodeCategory::Join),
     // Traverse the nodes
     (Expand, PlanNodeCategory::Traversal),
     (ExpandAll, PlanNodeCategory::Traversal),
@@ -673,6 +727,9 @@ crate::define_enum_category! {
     (Minus, PlanNodeCategory::DataProcessing),
     (Intersect, PlanNodeCategory::DataProcessing),
     (Unwind, PlanNodeCategory::DataProcessing),
+    (PatternSubquery, PlanNodeCategory::DataProcessing),
+    (CallSubquery, PlanNodeCategory::ControlFlow),
+    (Foreach, PlanNodeCategory::ControlFlow),
     (Materialize, PlanNodeCategory::DataProcessing),
     (Assign, PlanNodeCategory::DataProcessing),
     (Apply, PlanNodeCategory::DataProcessing),
@@ -703,10 +760,16 @@ crate::define_enum_category! {
     (UpdateEdges, PlanNodeCategory::Management),
     // Statistical nodes
     (ShowStats, PlanNodeCategory::Management),
+    // Procedure nodes
+    (ProcedureCall, PlanNodeCategory::DataAccess),
+    // Materialized view nodes
+    (MaterializedViewScan, PlanNodeCategory::DataAccess),
     // Full-text Search Nodes
     (FulltextSearch, PlanNodeCategory::DataAccess),
     (FulltextLookup, PlanNodeCategory::DataAccess),
     (MatchFulltext, PlanNodeCategory::DataAccess),
+    // Hybrid Search Nodes
+    (HybridSearch, PlanNodeCategory::DataAccess),
     // Vector Search Nodes
     #[cfg(feature = "qdrant")]
     (VectorSearch, PlanNodeCategory::DataAccess),
@@ -746,6 +809,7 @@ crate::define_enum_describe! {
     (HashLeftJoin, "HashLeftJoin"),
     (FullOuterJoin, "FullOuterJoin"),
     (SemiJoin, "SemiJoin"),
+    (MultiwayJoin, "MultiwayJoin"),
     // Traverse the nodes
     (Expand, "Expand"),
     (ExpandAll, "ExpandAll"),
@@ -771,6 +835,9 @@ crate::define_enum_describe! {
     (Minus, "Minus"),
     (Intersect, "Intersect"),
     (Unwind, "Unwind"),
+    (PatternSubquery, "PatternSubquery"),
+    (CallSubquery, "CallSubquery"),
+    (Foreach, "Foreach"),
     (Materialize, "Materialize"),
     (Assign, "Assign"),
     (Apply, "Apply"),
@@ -801,10 +868,14 @@ crate::define_enum_describe! {
     (UpdateEdges, "UpdateEdges"),
     // Statistical nodes
     (ShowStats, "ShowStats"),
+    (ProcedureCall, "ProcedureCall"),
+    (MaterializedViewScan, "MaterializedViewScan"),
     // Full-text Search Nodes
     (FulltextSearch, "FulltextSearch"),
     (FulltextLookup, "FulltextLookup"),
     (MatchFulltext, "MatchFulltext"),
+    // Hybrid Search Nodes
+    (HybridSearch, "HybridSearch"),
     // Vector Search Nodes
     #[cfg(feature = "qdrant")]
     (VectorSearch, "VectorSearch"),
@@ -24,6 +24,7 @@ macro_rules! match_all_nodes_with_default {
             PlanNodeEnum::HashLeftJoin(node) => node.$method(),
             PlanNodeEnum::FullOuterJoin(node) => node.$method(),
             PlanNodeEnum::SemiJoin(node) => node.$method(),
+            PlanNodeEnum::MultiwayJoin(node) => node.$method(),
             PlanNodeEnum::IndexScan(node) => node.$method(),
             PlanNodeEnum::EdgeIndexScan(node) => node.$method(),
             PlanNodeEnum::GetVertices(node) => node.$method(),
@@ -54,6 +55,9 @@ macro_rules! match_all_nodes_with_default {
             PlanNodeEnum::Minus(node) => node.$method(),
             PlanNodeEnum::Intersect(node) => node.$method(),
             PlanNodeEnum::Unwind(node) => node.$method(),
+            PlanNodeEnum::PatternSubquery(node) => node.$method(),
+            PlanNodeEnum::CallSubquery(node) => node.$method(),
+            PlanNodeEnum::Foreach(node) => node.$method(),
             PlanNodeEnum::Assign(node) => node.$method(),
             PlanNodeEnum::Apply(node) => node.$method(),
             PlanNodeEnum::MultiShortestPath(node) => node.$method(),
@@ -67,6 +71,8 @@ macro_rules! match_all_nodes_with_default {
             PlanNodeEnum::UserManage(node) => node.$method(),
             PlanNodeEnum::FulltextManage(node) => node.$method(),
             PlanNodeEnum::VectorManage(node) => node.$method(),
+            PlanNodeEnum::ProcedureCall(node) => node.$method(),
+            PlanNodeEnum::MaterializedViewScan(node) => node.$method(),
             _ => $default,
         }
     };
@@ -93,6 +99,7 @@ impl PlanNodeEnum {
             PlanNodeEnum::HashLeftJoin(_) => "HashLeftJoin",
             PlanNodeEnum::FullOuterJoin(_) => "FullOuterJoin",
             PlanNodeEnum::SemiJoin(_) => "SemiJoin",
+            PlanNodeEnum::MultiwayJoin(_) => "MultiwayJoin",
             PlanNodeEnum::IndexScan(_) => "IndexScan",
             PlanNodeEnum::GetVertices(_) => "GetVertices",
             PlanNodeEnum::GetEdges(_) => "GetEdges",
@@ -120,6 +127,9 @@ impl PlanNodeEnum {
             PlanNodeEnum::RollUpApply(_) => "RollUpApply",
             PlanNodeEnum::Union(_) => "Union",
             PlanNodeEnum::Unwind(_) => "Unwind",
+            PlanNodeEnum::PatternSubquery(_) => "PatternSubquery",
+            PlanNodeEnum::CallSubquery(_) => "CallSubquery",
+            PlanNodeEnum::Foreach(_) => "Foreach",
             PlanNodeEnum::Assign(_) => "Assign",
             PlanNodeEnum::Apply(_) => "Apply",
             PlanNodeEnum::MultiShortestPath(_) => "MultiShortestPath",
@@ -136,6 +146,8 @@ impl PlanNodeEnum {
             PlanNodeEnum::VectorManage(node) => node.name(),
 
             PlanNodeEnum::ShowStats(_) => "ShowStats",
+            PlanNodeEnum::ProcedureCall(_) => "ProcedureCall",
+            PlanNodeEnum::MaterializedViewScan(_) => "MaterializedViewScan",
             PlanNodeEnum::InsertVertices(_) => "InsertVertices",
             PlanNodeEnum::InsertEdges(_) => "InsertEdges",
             PlanNodeEnum::Remove(_) => "Remove",
@@ -155,6 +167,7 @@ impl PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(_) => "FulltextSearch",
             PlanNodeEnum::FulltextLookup(_) => "FulltextLookup",
             PlanNodeEnum::MatchFulltext(_) => "MatchFulltext",
+            PlanNodeEnum::HybridSearch(_) => "HybridSearch",
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => "VectorSearch",
             #[cfg(feature = "qdrant")]
@@ -202,6 +215,9 @@ impl PlanNodeEnum {
             PlanNodeEnum::RollUpApply(node) => node.output_var(),
             PlanNodeEnum::Union(node) => node.output_var(),
             PlanNodeEnum::Unwind(node) => node.output_var(),
+            PlanNodeEnum::PatternSubquery(node) => node.output_var(),
+            PlanNodeEnum::CallSubquery(node) => node.output_var(),
+            PlanNodeEnum::Foreach(node) => node.output_var(),
             PlanNodeEnum::Assign(node) => node.output_var(),
             PlanNodeEnum::MultiShortestPath(node) => node.output_var(),
             PlanNodeEnum::BFSShortest(node) => node.output_var(),
@@ -248,6 +264,9 @@ impl PlanNodeEnum {
             | PlanNodeEnum::FulltextManage(_)
             | PlanNodeEnum::VectorManage(_)
             | PlanNodeEnum::ShowStats(_)
+            | PlanNodeEnum::ProcedureCall(_)
+            | PlanNodeEnum::MaterializedViewScan(_)
+            | PlanNodeEnum::MultiwayJoin(_)
             | PlanNodeEnum::InsertVertices(_)
             | PlanNodeEnum::InsertEdges(_)
             | PlanNodeEnum::DeleteVertices(_)
@@ -271,6 +290,9 @@ impl PlanNodeEnum {
             PlanNodeEnum::RollUpApply(node) => Cow::Owned(vec![node.input()]),
             PlanNodeEnum::Union(node) => Cow::Owned(vec![node.input()]),
             PlanNodeEnum::Unwind(node) => Cow::Owned(vec![node.input()]),
+            PlanNodeEnum::PatternSubquery(node) => Cow::Owned(vec![node.input()]),
+            PlanNodeEnum::CallSubquery(node) => Cow::Owned(vec![node.input()]),
+            PlanNodeEnum::Foreach(node) => Cow::Owned(vec![node.input()]),
             PlanNodeEnum::Assign(node) => Cow::Owned(vec![node.input()]),
             PlanNodeEnum::Traverse(node) => Cow::Owned(vec![node.input()]),
 
@@ -315,7 +337,8 @@ impl PlanNodeEnum {
 
             PlanNodeEnum::FulltextSearch(_)
             | PlanNodeEnum::FulltextLookup(_)
-            | PlanNodeEnum::MatchFulltext(_) => Cow::Borrowed(&[]),
+            | PlanNodeEnum::MatchFulltext(_)
+            | PlanNodeEnum::HybridSearch(_) => Cow::Borrowed(&[]),
 
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_)
@@ -371,6 +394,9 @@ impl PlanNodeEnum {
             PlanNodeEnum::RollUpApply(node) => node.set_output_var(var),
             PlanNodeEnum::Union(node) => node.set_output_var(var),
             PlanNodeEnum::Unwind(node) => node.set_output_var(var),
+            PlanNodeEnum::PatternSubquery(node) => node.set_output_var(var),
+            PlanNodeEnum::CallSubquery(node) => node.set_output_var(var),
+            PlanNodeEnum::Foreach(node) => node.set_output_var(var),
             PlanNodeEnum::Assign(node) => node.set_output_var(var),
             PlanNodeEnum::MultiShortestPath(node) => node.set_output_var(var),
             PlanNodeEnum::BFSShortest(node) => node.set_output_var(var),
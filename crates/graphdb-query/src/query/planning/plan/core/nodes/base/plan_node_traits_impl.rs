@@ -18,6 +18,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::RightJoin(_) => "right_join",
             PlanNodeEnum::CrossJoin(_) => "cross_join",
             PlanNodeEnum::SemiJoin(_) => "semi_join",
+            PlanNodeEnum::MultiwayJoin(_) => "multiway_join",
             PlanNodeEnum::GetVertices(_) => "get_vertices",
             PlanNodeEnum::GetEdges(_) => "get_edges",
             PlanNodeEnum::GetNeighbors(_) => "get_neighbors",
@@ -52,6 +53,9 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::Minus(_) => "minus",
             PlanNodeEnum::Intersect(_) => "intersect",
             PlanNodeEnum::Unwind(_) => "unwind",
+            PlanNodeEnum::PatternSubquery(_) => "pattern_subquery",
+            PlanNodeEnum::CallSubquery(_) => "call_subquery",
+            PlanNodeEnum::Foreach(_) => "foreach",
             PlanNodeEnum::Materialize(_) => "materialize",
             PlanNodeEnum::Assign(_) => "assign",
             PlanNodeEnum::Apply(_) => "apply",
@@ -67,6 +71,8 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextManage(node) => node.node_type_id(),
             PlanNodeEnum::VectorManage(node) => node.node_type_id(),
             PlanNodeEnum::ShowStats(_) => "show_stats",
+            PlanNodeEnum::ProcedureCall(_) => "procedure_call",
+            PlanNodeEnum::MaterializedViewScan(_) => "materialized_view_scan",
             PlanNodeEnum::InsertVertices(_) => "insert_vertices",
             PlanNodeEnum::InsertEdges(_) => "insert_edges",
             PlanNodeEnum::DeleteVertices(_) => "delete_vertices",
@@ -81,6 +87,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(_) => "fulltext_search",
             PlanNodeEnum::FulltextLookup(_) => "fulltext_lookup",
             PlanNodeEnum::MatchFulltext(_) => "match_fulltext",
+            PlanNodeEnum::HybridSearch(_) => "hybrid_search",
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => "vector_search",
             #[cfg(feature = "qdrant")]
@@ -103,6 +110,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::RightJoin(_) => "Right Join",
             PlanNodeEnum::CrossJoin(_) => "Cross Join",
             PlanNodeEnum::SemiJoin(_) => "Semi Join",
+            PlanNodeEnum::MultiwayJoin(_) => "Multiway Join",
             PlanNodeEnum::GetVertices(_) => "Get Vertices",
             PlanNodeEnum::GetEdges(_) => "Get Edges",
             PlanNodeEnum::GetNeighbors(_) => "Get Neighbors",
@@ -137,6 +145,9 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::Minus(_) => "Minus",
             PlanNodeEnum::Intersect(_) => "Intersect",
             PlanNodeEnum::Unwind(_) => "Unwind",
+            PlanNodeEnum::PatternSubquery(_) => "Pattern Subquery",
+            PlanNodeEnum::CallSubquery(_) => "Call Subquery",
+            PlanNodeEnum::Foreach(_) => "Foreach",
             PlanNodeEnum::Materialize(_) => "Materialize",
             PlanNodeEnum::Assign(_) => "Assign",
             PlanNodeEnum::Apply(_) => "Apply",
@@ -152,6 +163,8 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextManage(node) => node.node_type_name(),
             PlanNodeEnum::VectorManage(node) => node.node_type_name(),
             PlanNodeEnum::ShowStats(_) => "Show Stats",
+            PlanNodeEnum::ProcedureCall(_) => "Procedure Call",
+            PlanNodeEnum::MaterializedViewScan(_) => "Materialized View Scan",
             PlanNodeEnum::InsertVertices(_) => "Insert Vertices",
             PlanNodeEnum::InsertEdges(_) => "Insert Edges",
             PlanNodeEnum::DeleteVertices(_) => "Delete Vertices",
@@ -166,6 +179,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(_) => "Fulltext Search",
             PlanNodeEnum::FulltextLookup(_) => "Fulltext Lookup",
             PlanNodeEnum::MatchFulltext(_) => "Match Fulltext",
+            PlanNodeEnum::HybridSearch(_) => "Hybrid Search",
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => "Vector Search",
             #[cfg(feature = "qdrant")]
@@ -188,6 +202,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::RightJoin(_) => NodeCategory::Join,
             PlanNodeEnum::CrossJoin(_) => NodeCategory::Join,
             PlanNodeEnum::SemiJoin(_) => NodeCategory::Join,
+            PlanNodeEnum::MultiwayJoin(_) => NodeCategory::Join,
             PlanNodeEnum::GetVertices(_) => NodeCategory::Scan,
             PlanNodeEnum::GetEdges(_) => NodeCategory::Scan,
             PlanNodeEnum::GetNeighbors(_) => NodeCategory::Scan,
@@ -222,6 +237,9 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::Minus(_) => NodeCategory::SetOp,
             PlanNodeEnum::Intersect(_) => NodeCategory::SetOp,
             PlanNodeEnum::Unwind(_) => NodeCategory::DataCollect,
+            PlanNodeEnum::PatternSubquery(_) => NodeCategory::DataCollect,
+            PlanNodeEnum::CallSubquery(_) => NodeCategory::Control,
+            PlanNodeEnum::Foreach(_) => NodeCategory::Control,
             PlanNodeEnum::Materialize(_) => NodeCategory::DataCollect,
             PlanNodeEnum::Assign(_) => NodeCategory::DataCollect,
             PlanNodeEnum::Apply(_) => NodeCategory::DataCollect,
@@ -237,6 +255,8 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextManage(_) => NodeCategory::Admin,
             PlanNodeEnum::VectorManage(_) => NodeCategory::Admin,
             PlanNodeEnum::ShowStats(_) => NodeCategory::Admin,
+            PlanNodeEnum::ProcedureCall(_) => NodeCategory::DataAccess,
+            PlanNodeEnum::MaterializedViewScan(_) => NodeCategory::DataAccess,
             PlanNodeEnum::InsertVertices(_) => NodeCategory::Admin,
             PlanNodeEnum::InsertEdges(_) => NodeCategory::Admin,
             PlanNodeEnum::DeleteVertices(_) => NodeCategory::Admin,
@@ -251,6 +271,7 @@ impl NodeType for PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(_) => NodeCategory::DataAccess,
             PlanNodeEnum::FulltextLookup(_) => NodeCategory::DataAccess,
             PlanNodeEnum::MatchFulltext(_) => NodeCategory::DataAccess,
+            PlanNodeEnum::HybridSearch(_) => NodeCategory::DataAccess,
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => NodeCategory::DataAccess,
             #[cfg(feature = "qdrant")]
@@ -275,6 +296,7 @@ impl NodeTypeMapping for PlanNodeEnum {
             PlanNodeEnum::RightJoin(_) => Some("right_join"),
             PlanNodeEnum::CrossJoin(_) => Some("cross_join"),
             PlanNodeEnum::SemiJoin(_) => Some("semi_join"),
+            PlanNodeEnum::MultiwayJoin(_) => Some("multiway_join"),
             PlanNodeEnum::GetVertices(_) => Some("get_vertices"),
             PlanNodeEnum::GetEdges(_) => Some("get_edges"),
             PlanNodeEnum::GetNeighbors(_) => Some("get_neighbors"),
@@ -309,6 +331,9 @@ impl NodeTypeMapping for PlanNodeEnum {
             PlanNodeEnum::Minus(_) => Some("minus"),
             PlanNodeEnum::Intersect(_) => Some("intersect"),
             PlanNodeEnum::Unwind(_) => Some("unwind"),
+            PlanNodeEnum::PatternSubquery(_) => Some("pattern_subquery"),
+            PlanNodeEnum::CallSubquery(_) => Some("call_subquery"),
+            PlanNodeEnum::Foreach(_) => Some("foreach"),
             PlanNodeEnum::Materialize(_) => Some("materialize"),
             PlanNodeEnum::Assign(_) => Some("assign"),
             PlanNodeEnum::Apply(_) => Some("apply"),
@@ -324,6 +349,8 @@ impl NodeTypeMapping for PlanNodeEnum {
             PlanNodeEnum::FulltextManage(node) => node.corresponding_executor_type(),
             PlanNodeEnum::VectorManage(node) => node.corresponding_executor_type(),
             PlanNodeEnum::ShowStats(_) => Some("show_stats"),
+            PlanNodeEnum::ProcedureCall(_) => Some("procedure_call"),
+            PlanNodeEnum::MaterializedViewScan(_) => Some("materialized_view_scan"),
             PlanNodeEnum::InsertVertices(_) => Some("insert_vertices"),
             PlanNodeEnum::InsertEdges(_) => Some("insert_edges"),
             PlanNodeEnum::DeleteVertices(_) => Some("delete_vertices"),
@@ -338,6 +365,7 @@ impl NodeTypeMapping for PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(_) => Some("fulltext_search"),
             PlanNodeEnum::FulltextLookup(_) => Some("fulltext_lookup"),
             PlanNodeEnum::MatchFulltext(_) => Some("match_fulltext"),
+            PlanNodeEnum::HybridSearch(_) => Some("hybrid_search"),
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(_) => Some("vector_search"),
             #[cfg(feature = "qdrant")]
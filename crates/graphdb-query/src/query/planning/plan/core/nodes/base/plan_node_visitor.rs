@@ -1,6 +1,8 @@
 //! Implementation of the PlanNode visitor pattern
 
 use super::plan_node_enum::PlanNodeEnum;
+use crate::query::planning::plan::core::nodes::access::materialized_view_scan_node::MaterializedViewScanNode;
+use crate::query::planning::plan::core::nodes::access::procedure_call_node::ProcedureCallNode;
 use crate::query::planning::plan::core::nodes::data_modification::{
     DeleteEdgesNode, DeleteIndexNode, DeleteTagsNode, DeleteVerticesNode, InsertEdgesNode,
     InsertVerticesNode, PipeDeleteEdgesNode, PipeDeleteVerticesNode, UpdateEdgesNode, UpdateNode,
@@ -14,6 +16,7 @@ use crate::query::planning::plan::core::nodes::management::stats_nodes::ShowStat
 use crate::query::planning::plan::core::nodes::search::fulltext::data_access::{
     FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
 };
+use crate::query::planning::plan::core::nodes::search::hybrid::HybridSearchNode;
 #[cfg(feature = "qdrant")]
 use crate::query::planning::plan::core::nodes::search::vector::data_access::{
     VectorLookupNode, VectorMatchNode, VectorSearchNode,
@@ -29,11 +32,15 @@ pub use crate::query::planning::plan::core::nodes::control_flow::control_flow_no
     SelectNode,
 };
 pub use crate::query::planning::plan::core::nodes::control_flow::start_node::StartNode;
+pub use crate::query::planning::plan::core::nodes::control_flow::subquery_node::{
+    CallSubqueryNode, ForeachNode,
+};
 pub use crate::query::planning::plan::core::nodes::graph_operations::aggregate_node::AggregateNode;
 pub use crate::query::planning::plan::core::nodes::graph_operations::graph_operations_node::{
     ApplyNode, AssignNode, DataCollectNode, DedupNode, MaterializeNode, PatternApplyNode,
     RemoveNode, RollUpApplyNode, UnionNode, UnwindNode,
 };
+pub use crate::query::planning::plan::core::nodes::graph_operations::pattern_subquery_node::PatternSubqueryNode;
 pub use crate::query::planning::plan::core::nodes::graph_operations::set_operations_node::{
     IntersectNode, MinusNode,
 };
@@ -41,6 +48,7 @@ pub use crate::query::planning::plan::core::nodes::join::join_node::{
     CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode, InnerJoinNode,
     LeftJoinNode, RightJoinNode, SemiJoinNode,
 };
+pub use crate::query::planning::plan::core::nodes::join::multiway_join_node::MultiwayJoinNode;
 pub use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
 pub use crate::query::planning::plan::core::nodes::operation::project_node::ProjectNode;
 pub use crate::query::planning::plan::core::nodes::operation::sample_node::SampleNode;
@@ -88,6 +96,7 @@ pub trait PlanNodeVisitor {
         HashLeftJoin, HashLeftJoinNode, visit_hash_left_join;
         FullOuterJoin, FullOuterJoinNode, visit_full_outer_join;
         SemiJoin, SemiJoinNode, visit_semi_join;
+        MultiwayJoin, MultiwayJoinNode, visit_multiway_join;
     );
 
     impl_visitor_methods!(
@@ -136,6 +145,9 @@ pub trait PlanNodeVisitor {
         Minus, MinusNode, visit_minus;
         Intersect, IntersectNode, visit_intersect;
         Unwind, UnwindNode, visit_unwind;
+        PatternSubquery, PatternSubqueryNode, visit_pattern_subquery;
+        CallSubquery, CallSubqueryNode, visit_call_subquery;
+        Foreach, ForeachNode, visit_foreach;
         Materialize, MaterializeNode, visit_materialize;
         Assign, AssignNode, visit_assign;
         Apply, ApplyNode, visit_apply;
@@ -161,6 +173,8 @@ pub trait PlanNodeVisitor {
 
     impl_visitor_methods!(
         ShowStats, ShowStatsNode, visit_show_stats;
+        ProcedureCall, ProcedureCallNode, visit_procedure_call;
+        MaterializedViewScan, MaterializedViewScanNode, visit_materialized_view_scan;
     );
 
     impl_visitor_methods!(
@@ -190,6 +204,7 @@ pub trait PlanNodeVisitor {
         FulltextSearch, FulltextSearchNode, visit_fulltext_search;
         FulltextLookup, FulltextLookupNode, visit_fulltext_lookup;
         MatchFulltext, MatchFulltextNode, visit_match_fulltext;
+        HybridSearch, HybridSearchNode, visit_hybrid_search;
     );
 
     #[cfg(feature = "qdrant")]
@@ -217,6 +232,7 @@ impl PlanNodeEnum {
             PlanNodeEnum::RightJoin(node) => visitor.visit_right_join(node),
             PlanNodeEnum::CrossJoin(node) => visitor.visit_cross_join(node),
             PlanNodeEnum::SemiJoin(node) => visitor.visit_semi_join(node),
+            PlanNodeEnum::MultiwayJoin(node) => visitor.visit_multiway_join(node),
             PlanNodeEnum::GetVertices(node) => visitor.visit_get_vertices(node),
             PlanNodeEnum::GetEdges(node) => visitor.visit_get_edges(node),
             PlanNodeEnum::GetNeighbors(node) => visitor.visit_get_neighbors(node),
@@ -250,6 +266,9 @@ impl PlanNodeEnum {
             PlanNodeEnum::Minus(node) => visitor.visit_minus(node),
             PlanNodeEnum::Intersect(node) => visitor.visit_intersect(node),
             PlanNodeEnum::Unwind(node) => visitor.visit_unwind(node),
+            PlanNodeEnum::PatternSubquery(node) => visitor.visit_pattern_subquery(node),
+            PlanNodeEnum::CallSubquery(node) => visitor.visit_call_subquery(node),
+            PlanNodeEnum::Foreach(node) => visitor.visit_foreach(node),
             PlanNodeEnum::Materialize(node) => visitor.visit_materialize(node),
             PlanNodeEnum::Assign(node) => visitor.visit_assign(node),
             PlanNodeEnum::Apply(node) => visitor.visit_apply(node),
@@ -268,6 +287,8 @@ impl PlanNodeEnum {
             PlanNodeEnum::VectorManage(node) => visitor.visit_vector_manage(node),
 
             PlanNodeEnum::ShowStats(node) => visitor.visit_show_stats(node),
+            PlanNodeEnum::ProcedureCall(node) => visitor.visit_procedure_call(node),
+            PlanNodeEnum::MaterializedViewScan(node) => visitor.visit_materialized_view_scan(node),
             PlanNodeEnum::InsertVertices(node) => visitor.visit_insert_vertices(node),
             PlanNodeEnum::InsertEdges(node) => visitor.visit_insert_edges(node),
             PlanNodeEnum::DeleteVertices(node) => visitor.visit_delete_vertices(node),
@@ -283,6 +304,7 @@ impl PlanNodeEnum {
             PlanNodeEnum::FulltextSearch(node) => visitor.visit_fulltext_search(node),
             PlanNodeEnum::FulltextLookup(node) => visitor.visit_fulltext_lookup(node),
             PlanNodeEnum::MatchFulltext(node) => visitor.visit_match_fulltext(node),
+            PlanNodeEnum::HybridSearch(node) => visitor.visit_hybrid_search(node),
             #[cfg(feature = "qdrant")]
             PlanNodeEnum::VectorSearch(node) => visitor.visit_vector_search(node),
             #[cfg(feature = "qdrant")]
@@ -1,8 +1,10 @@
 pub mod control_flow_node;
 pub mod start_node;
+pub mod subquery_node;
 
 pub use control_flow_node::{
     ArgumentNode, BeginTransactionNode, CommitNode, IsolationLevel, LoopNode, PassThroughNode,
     RollbackNode, SelectNode,
 };
 pub use start_node::StartNode;
+pub use subquery_node::{CallSubqueryNode, ForeachNode};
@@ -0,0 +1,120 @@
+//! Per-row subquery nodes
+//!
+//! `CALL { }` and `FOREACH` run a nested plan once per input row (FOREACH once per list
+//! element). Builders evaluate most write expressions when the executor is created, so the
+//! nested plans are rebuilt for every row while the row's variables are bound in the
+//! expression context shared with the statement.
+
+use std::sync::Arc;
+
+use crate::core::types::ContextualExpression;
+use crate::define_plan_node_with_deps;
+use crate::query::parser::ast::stmt::InTransactions;
+use crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum;
+use crate::query::validator::context::ExpressionAnalysisContext;
+
+define_plan_node_with_deps! {
+    pub struct CallSubqueryNode {
+        body: Box<PlanNodeEnum>,
+        in_transactions: Option<InTransactions>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    }
+    enum: CallSubquery
+    input: SingleInputNode
+}
+
+impl CallSubqueryNode {
+    pub fn new(
+        input: PlanNodeEnum,
+        body: PlanNodeEnum,
+        in_transactions: Option<InTransactions>,
+        expr_context: Arc<ExpressionAnalysisContext>,
+    ) -> Self {
+        // Batched execution reports statistics; otherwise the input rows pass through.
+        let col_names = match in_transactions {
+            Some(_) => InTransactions::STATISTICS_COLUMNS
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            None => input.col_names().to_vec(),
+        };
+
+        Self {
+            id: -1,
+            input: Some(Box::new(input.clone())),
+            deps: vec![input],
+            body: Box::new(body),
+            in_transactions,
+            expr_context,
+            output_var: None,
+            col_names,
+        }
+    }
+
+    pub fn body(&self) -> &PlanNodeEnum {
+        &self.body
+    }
+
+    pub fn set_body(&mut self, body: PlanNodeEnum) {
+        self.body = Box::new(body);
+    }
+
+    pub fn in_transactions(&self) -> Option<&InTransactions> {
+        self.in_transactions.as_ref()
+    }
+
+    /// Context the expressions of the body are registered in
+    pub fn expr_context(&self) -> &Arc<ExpressionAnalysisContext> {
+        &self.expr_context
+    }
+}
+
+define_plan_node_with_deps! {
+    pub struct ForeachNode {
+        variable: String,
+        list: ContextualExpression,
+        body: Vec<PlanNodeEnum>,
+    }
+    enum: Foreach
+    input: SingleInputNode
+}
+
+impl ForeachNode {
+    pub fn new(
+        input: PlanNodeEnum,
+        variable: &str,
+        list: ContextualExpression,
+        body: Vec<PlanNodeEnum>,
+    ) -> Self {
+        let col_names = input.col_names().to_vec();
+
+        Self {
+            id: -1,
+            input: Some(Box::new(input.clone())),
+            deps: vec![input],
+            variable: variable.to_string(),
+            list,
+            body,
+            output_var: None,
+            col_names,
+        }
+    }
+
+    pub fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    pub fn list(&self) -> &ContextualExpression {
+        &self.list
+    }
+
+    /// Plans of the update clauses, executed in order for every element
+    pub fn body(&self) -> &[PlanNodeEnum] {
+        &self.body
+    }
+
+    /// Context the expressions of the body are registered in
+    pub fn expr_context(&self) -> &Arc<ExpressionAnalysisContext> {
+        self.list.context()
+    }
+}
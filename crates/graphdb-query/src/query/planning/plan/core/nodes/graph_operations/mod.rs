@@ -1,5 +1,6 @@
 pub mod aggregate_node;
 pub mod graph_operations_node;
+pub mod pattern_subquery_node;
 pub mod set_operations_node;
 
 pub use aggregate_node::AggregateNode;
@@ -7,4 +8,5 @@ pub use graph_operations_node::{
     ApplyKind, ApplyNode, AssignNode, DataCollectNode, DedupNode, MaterializeNode,
     PatternApplyNode, RemoveNode, RollUpApplyNode, UnionNode, UnwindNode,
 };
+pub use pattern_subquery_node::{PatternSubqueryNode, PatternSubqueryOutput};
 pub use set_operations_node::{IntersectNode, MinusNode};
@@ -0,0 +1,89 @@
+//! Pattern subquery node
+//!
+//! Evaluates an `EXISTS { }`, `COUNT { }` or pattern comprehension once per input row.
+//! Pattern variables that are columns of the input anchor the pattern, so the node behaves
+//! like a correlated expand; used as a top-level WHERE conjunct it becomes a semi/anti join.
+
+use crate::core::types::expr::PatternSubquery;
+use crate::define_plan_node_with_deps;
+use crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum;
+use crate::query::planning::planner::PlannerError;
+
+/// What the node does with the subquery result of each row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternSubqueryOutput {
+    /// Append the result as a new column
+    Column(String),
+    /// Keep the rows for which the pattern matches
+    Semi,
+    /// Keep the rows for which the pattern does not match
+    Anti,
+}
+
+define_plan_node_with_deps! {
+    pub struct PatternSubqueryNode {
+        space_name: String,
+        subquery: PatternSubquery,
+        output: PatternSubqueryOutput,
+        decorrelated: bool,
+    }
+    enum: PatternSubquery
+    input: SingleInputNode
+}
+
+impl PatternSubqueryNode {
+    pub fn new(
+        input: PlanNodeEnum,
+        space_name: &str,
+        subquery: PatternSubquery,
+        output: PatternSubqueryOutput,
+    ) -> Result<Self, PlannerError> {
+        let mut col_names = input.col_names().to_vec();
+        if let PatternSubqueryOutput::Column(alias) = &output {
+            col_names.push(alias.clone());
+        }
+
+        Ok(Self {
+            id: -1,
+            input: Some(Box::new(input.clone())),
+            deps: vec![input],
+            space_name: space_name.to_string(),
+            subquery,
+            output,
+            decorrelated: false,
+            output_var: None,
+            col_names,
+        })
+    }
+
+    pub fn space_name(&self) -> &str {
+        &self.space_name
+    }
+
+    pub fn subquery(&self) -> &PatternSubquery {
+        &self.subquery
+    }
+
+    pub fn output(&self) -> &PatternSubqueryOutput {
+        &self.output
+    }
+
+    /// Pattern variables bound by the input rows
+    pub fn outer_variables(&self) -> Vec<String> {
+        let input_cols = self.deps[0].col_names();
+        self.subquery
+            .variables()
+            .into_iter()
+            .filter(|v| input_cols.contains(v))
+            .collect()
+    }
+
+    /// Whether the relationships are fetched once and probed by hash instead of expanded per row
+    pub fn is_decorrelated(&self) -> bool {
+        self.decorrelated
+    }
+
+    pub fn set_decorrelated(&mut self, decorrelated: bool) {
+        self.decorrelated = decorrelated;
+    }
+}
@@ -1,6 +1,8 @@
 pub mod join_node;
+pub mod multiway_join_node;
 
 pub use join_node::{
     AntiJoinNode, CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode,
     InnerJoinNode, LeftJoinNode, RightJoinNode, SemiJoinNode,
 };
+pub use multiway_join_node::{JoinRelation, MultiwayJoinNode};
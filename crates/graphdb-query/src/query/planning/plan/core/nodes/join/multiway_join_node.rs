@@ -0,0 +1,168 @@
+//! Multiway join node
+//!
+//! Leaf node for a cyclic MATCH pattern such as a triangle. Instead of expanding
+//! edge by edge and joining the partial paths, the executor binds one node
+//! variable at a time and intersects the adjacency lists of every pattern edge
+//! that reaches it (generic join), so no intermediate result grows beyond the
+//! size of the output.
+//!
+//! The planner does not know whether that pays off, so the node is created as a
+//! candidate that carries the expand + join plan of the same pattern. The
+//! cost-based phase either keeps the multiway join, wrapping it in the node and
+//! edge filters of the pattern, or puts the fallback plan back.
+
+use crate::core::types::ContextualExpression;
+use crate::define_plan_node;
+use crate::query::planning::plan::core::node_id_generator::next_node_id;
+use crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum;
+use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
+use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
+use crate::query::planning::planner::PlannerError;
+use std::fmt;
+
+/// One edge of the pattern, written as `(src)-[edge_alias:edge_types]->(dst)`
+///
+/// Edges written right to left are stored with their ends swapped; an
+/// undirected edge matches in either direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinRelation {
+    pub src: String,
+    pub dst: String,
+    pub edge_alias: Option<String>,
+    /// Empty when the pattern accepts any edge type
+    pub edge_types: Vec<String>,
+    pub undirected: bool,
+}
+
+impl JoinRelation {
+    /// Whether an edge of type `edge_type` belongs to this relation
+    pub fn accepts(&self, edge_type: &str) -> bool {
+        self.edge_types.is_empty() || self.edge_types.iter().any(|t| t == edge_type)
+    }
+
+    /// Whether the relation connects `variable` to another variable
+    pub fn touches(&self, variable: &str) -> bool {
+        self.src == variable || self.dst == variable
+    }
+}
+
+impl fmt::Display for JoinRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alias = self.edge_alias.as_deref().unwrap_or("");
+        let types = if self.edge_types.is_empty() {
+            String::new()
+        } else {
+            format!(":{}", self.edge_types.join("|"))
+        };
+        let arrow = if self.undirected { "" } else { ">" };
+        write!(
+            f,
+            "({})-[{}{}]-{}({})",
+            self.src, alias, types, arrow, self.dst
+        )
+    }
+}
+
+define_plan_node! {
+    pub struct MultiwayJoinNode {
+        space_id: u64,
+        space_name: String,
+        relations: Vec<JoinRelation>,
+        variable_order: Vec<String>,
+        filters: Vec<ContextualExpression>,
+        fallback: Option<Box<PlanNodeEnum>>,
+    }
+    enum: MultiwayJoin
+    input: ZeroInputNode
+}
+
+impl MultiwayJoinNode {
+    /// Create a candidate multiway join.
+    ///
+    /// The output has one column per node variable, in `variable_order`, followed
+    /// by one column per named edge.
+    pub fn new(
+        space_id: u64,
+        space_name: &str,
+        relations: Vec<JoinRelation>,
+        variable_order: Vec<String>,
+        filters: Vec<ContextualExpression>,
+        fallback: PlanNodeEnum,
+    ) -> Self {
+        let col_names = variable_order
+            .iter()
+            .cloned()
+            .chain(relations.iter().filter_map(|r| r.edge_alias.clone()))
+            .collect();
+        Self {
+            id: next_node_id(),
+            space_id,
+            space_name: space_name.to_string(),
+            relations,
+            variable_order,
+            filters,
+            fallback: Some(Box::new(fallback)),
+            col_names,
+            output_var: None,
+        }
+    }
+
+    pub fn space_id(&self) -> u64 {
+        self.space_id
+    }
+
+    pub fn space_name(&self) -> &str {
+        &self.space_name
+    }
+
+    pub fn relations(&self) -> &[JoinRelation] {
+        &self.relations
+    }
+
+    pub fn variable_order(&self) -> &[String] {
+        &self.variable_order
+    }
+
+    pub fn set_variable_order(&mut self, variable_order: Vec<String>) {
+        self.variable_order = variable_order;
+    }
+
+    /// Node and edge conditions of the pattern, applied once the candidate is kept
+    pub fn filters(&self) -> &[ContextualExpression] {
+        &self.filters
+    }
+
+    /// The expand + join plan of the pattern, present until the candidate is resolved
+    pub fn fallback(&self) -> Option<&PlanNodeEnum> {
+        self.fallback.as_deref()
+    }
+
+    pub fn set_fallback(&mut self, fallback: PlanNodeEnum) {
+        self.fallback = Some(Box::new(fallback));
+    }
+
+    pub fn is_candidate(&self) -> bool {
+        self.fallback.is_some()
+    }
+
+    /// Resolve the candidate in favour of the multiway join.
+    ///
+    /// The fallback is dropped and the pattern conditions are put on top.
+    pub fn into_chosen(mut self) -> Result<PlanNodeEnum, PlannerError> {
+        self.fallback = None;
+        let filters = std::mem::take(&mut self.filters);
+        let mut plan = PlanNodeEnum::MultiwayJoin(self);
+        for filter in filters {
+            plan = FilterNode::new(plan, filter)?.into_enum();
+        }
+        Ok(plan)
+    }
+
+    /// Resolve the candidate in favour of its expand + join plan.
+    pub fn into_fallback(self) -> PlanNodeEnum {
+        match self.fallback {
+            Some(fallback) => *fallback,
+            None => PlanNodeEnum::MultiwayJoin(self),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Provide definitions for the planning nodes related to index management.
 
+use crate::core::types::Constraint;
 use crate::define_plan_node;
 
 define_plan_node! {
@@ -369,3 +370,96 @@ impl ShowIndexesNode {
         &self.space_name
     }
 }
+
+define_plan_node! {
+    pub struct CreateConstraintNode {
+        space_name: String,
+        constraint: Constraint,
+        if_not_exists: bool,
+    }
+    manage_enum: IndexManageNode::CreateConstraint as IndexManage
+    input: ZeroInputNode
+}
+
+impl CreateConstraintNode {
+    pub fn new(id: i64, space_name: String, constraint: Constraint, if_not_exists: bool) -> Self {
+        Self {
+            id,
+            space_name,
+            constraint,
+            if_not_exists,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn space_name(&self) -> &str {
+        &self.space_name
+    }
+
+    pub fn constraint(&self) -> &Constraint {
+        &self.constraint
+    }
+
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+}
+
+define_plan_node! {
+    pub struct DropConstraintNode {
+        space_name: String,
+        constraint_name: String,
+        if_exists: bool,
+    }
+    manage_enum: IndexManageNode::DropConstraint as IndexManage
+    input: ZeroInputNode
+}
+
+impl DropConstraintNode {
+    pub fn new(id: i64, space_name: String, constraint_name: String, if_exists: bool) -> Self {
+        Self {
+            id,
+            space_name,
+            constraint_name,
+            if_exists,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn space_name(&self) -> &str {
+        &self.space_name
+    }
+
+    pub fn constraint_name(&self) -> &str {
+        &self.constraint_name
+    }
+
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+}
+
+define_plan_node! {
+    pub struct ShowConstraintsNode {
+        space_name: String,
+    }
+    manage_enum: IndexManageNode::ShowConstraints as IndexManage
+    input: ZeroInputNode
+}
+
+impl ShowConstraintsNode {
+    pub fn new(id: i64, space_name: String) -> Self {
+        Self {
+            id,
+            space_name,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn space_name(&self) -> &str {
+        &self.space_name
+    }
+}
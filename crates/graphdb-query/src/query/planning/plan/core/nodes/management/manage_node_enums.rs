@@ -7,7 +7,7 @@
 //! - SpaceManageNode: Space DDL operations (CREATE/DROP/DESC/SHOW/ALTER/CLEAR SPACE)
 //! - TagManageNode: Tag DDL operations (CREATE/ALTER/DESC/DROP/SHOW TAG)
 //! - EdgeManageNode: Edge DDL operations (CREATE/ALTER/DESC/DROP/SHOW EDGE)
-//! - IndexManageNode: Index DDL operations (CREATE/DROP/DESC/SHOW/REBUILD INDEX)
+//! - IndexManageNode: Index DDL operations (CREATE/DROP/DESC/SHOW/REBUILD INDEX, CONSTRAINT)
 //! - UserManageNode: User DDL operations (CREATE/ALTER/DROP USER, GRANT/REVOKE ROLE)
 //! - FulltextManageNode: Fulltext index DDL operations
 //! - VectorManageNode: Vector index DDL operations
@@ -20,9 +20,10 @@ use crate::query::planning::plan::core::nodes::management::edge_nodes::{
     AlterEdgeNode, CreateEdgeNode, DescEdgeNode, DropEdgeNode, ShowCreateEdgeNode, ShowEdgesNode,
 };
 use crate::query::planning::plan::core::nodes::management::index_nodes::{
-    CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode, DescTagIndexNode,
-    DropEdgeIndexNode, DropTagIndexNode, RebuildEdgeIndexNode, RebuildTagIndexNode,
-    ShowCreateIndexNode, ShowEdgeIndexesNode, ShowIndexesNode, ShowTagIndexesNode,
+    CreateConstraintNode, CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode,
+    DescTagIndexNode, DropConstraintNode, DropEdgeIndexNode, DropTagIndexNode,
+    RebuildEdgeIndexNode, RebuildTagIndexNode, ShowConstraintsNode, ShowCreateIndexNode,
+    ShowEdgeIndexesNode, ShowIndexesNode, ShowTagIndexesNode,
 };
 use crate::query::planning::plan::core::nodes::management::space_nodes::{
     AlterSpaceNode, ClearSpaceNode, CreateSpaceNode, DescSpaceNode, DropSpaceNode,
@@ -32,12 +33,13 @@ use crate::query::planning::plan::core::nodes::management::tag_nodes::{
     AlterTagNode, CreateTagNode, DescTagNode, DropTagNode, ShowCreateTagNode, ShowTagsNode,
 };
 use crate::query::planning::plan::core::nodes::management::user_nodes::{
-    AlterUserNode, ChangePasswordNode, CreateUserNode, DescribeUserNode, DropUserNode,
-    GrantRoleNode, RevokeRoleNode, ShowRolesNode, ShowUsersNode,
+    AlterUserNode, ApiKeyNode, ChangePasswordNode, CreateRoleNode, CreateUserNode,
+    DescribeUserNode, DropUserNode, GrantPrivilegeNode, GrantRoleNode, RevokeRoleNode,
+    ShowRolesNode, ShowUsersNode,
 };
 use crate::query::planning::plan::core::nodes::search::fulltext::management::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
-    DropFulltextIndexNode, ShowFulltextIndexNode,
+    DropFulltextIndexNode, ShowFulltextIndexNode, SyncDlqNode,
 };
 use crate::query::planning::plan::core::nodes::search::vector::management::{
     CreateVectorIndexNode, DropVectorIndexNode,
@@ -223,6 +225,9 @@ define_manage_node_enum! {
         RebuildEdgeIndex(RebuildEdgeIndexNode, "RebuildEdgeIndex", "rebuild_edge_index", "Rebuild Edge Index", "rebuild_edge_index"),
         ShowIndexes(ShowIndexesNode, "ShowIndexes", "show_indexes", "Show Indexes", "show_indexes"),
         ShowCreateIndex(ShowCreateIndexNode, "ShowCreateIndex", "show_create_index", "Show Create Index", "show_create_index"),
+        CreateConstraint(CreateConstraintNode, "CreateConstraint", "create_constraint", "Create Constraint", "create_constraint"),
+        DropConstraint(DropConstraintNode, "DropConstraint", "drop_constraint", "Drop Constraint", "drop_constraint"),
+        ShowConstraints(ShowConstraintsNode, "ShowConstraints", "show_constraints", "Show Constraints", "show_constraints"),
     }
 }
 
@@ -235,6 +240,9 @@ define_manage_node_enum! {
         ChangePassword(ChangePasswordNode, "ChangePassword", "change_password", "Change Password", "change_password"),
         GrantRole(GrantRoleNode, "GrantRole", "grant_role", "Grant Role", "grant_role"),
         RevokeRole(RevokeRoleNode, "RevokeRole", "revoke_role", "Revoke Role", "revoke_role"),
+        CreateRole(CreateRoleNode, "CreateRole", "create_role", "Create Role", "create_role"),
+        GrantPrivilege(GrantPrivilegeNode, "GrantPrivilege", "grant_privilege", "Grant Privilege", "grant_privilege"),
+        ApiKey(ApiKeyNode, "ApiKey", "api_key", "Api Key", "api_key"),
         DescribeUser(DescribeUserNode, "DescribeUser", "describe_user", "Describe User", "describe_user"),
         ShowRoles(ShowRolesNode, "ShowRoles", "show_roles", "Show Roles", "show_roles"),
         ShowUsers(ShowUsersNode, "ShowUsers", "show_users", "Show Users", "show_users"),
@@ -249,6 +257,7 @@ define_manage_node_enum! {
         Alter(AlterFulltextIndexNode, "AlterFulltextIndex", "alter_fulltext_index", "Alter Fulltext Index", "alter_fulltext_index"),
         Show(ShowFulltextIndexNode, "ShowFulltextIndex", "show_fulltext_index", "Show Fulltext Index", "show_fulltext_index"),
         Describe(DescribeFulltextIndexNode, "DescribeFulltextIndex", "describe_fulltext_index", "Describe Fulltext Index", "describe_fulltext_index"),
+        SyncDlq(SyncDlqNode, "SyncDlq", "sync_dlq", "Sync Dead Letter Queue", "sync_dlq"),
     }
 }
 
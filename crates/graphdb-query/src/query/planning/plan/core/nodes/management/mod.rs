@@ -11,10 +11,10 @@ pub use edge_nodes::{
     ShowCreateEdgeNode, ShowEdgesNode,
 };
 pub use index_nodes::{
-    CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode, DescTagIndexNode,
-    DropEdgeIndexNode, DropTagIndexNode, IndexManageInfo, RebuildEdgeIndexNode,
-    RebuildTagIndexNode, ShowCreateIndexNode, ShowEdgeIndexesNode, ShowIndexesNode,
-    ShowTagIndexesNode,
+    CreateConstraintNode, CreateEdgeIndexNode, CreateTagIndexNode, DescEdgeIndexNode,
+    DescTagIndexNode, DropConstraintNode, DropEdgeIndexNode, DropTagIndexNode, IndexManageInfo,
+    RebuildEdgeIndexNode, RebuildTagIndexNode, ShowConstraintsNode, ShowCreateIndexNode,
+    ShowEdgeIndexesNode, ShowIndexesNode, ShowTagIndexesNode,
 };
 pub use manage_node_enums::{
     EdgeManageNode, FulltextManageNode, IndexManageNode, SpaceManageNode, TagManageNode,
@@ -30,6 +30,7 @@ pub use tag_nodes::{
     TagAlterInfo, TagManageInfo,
 };
 pub use user_nodes::{
-    AlterUserNode, ChangePasswordNode, CreateUserNode, DescribeUserNode, DropUserNode,
-    GrantRoleNode, RevokeRoleNode, ShowRolesNode, ShowUsersNode,
+    AlterUserNode, ApiKeyNode, ChangePasswordNode, CreateRoleNode, CreateUserNode,
+    DescribeUserNode, DropUserNode, GrantPrivilegeNode, GrantRoleNode, RevokeRoleNode,
+    ShowRolesNode, ShowUsersNode,
 };
@@ -3,7 +3,9 @@
 //! Provide definitions for the planning nodes related to user management.
 
 use crate::core::types::PasswordInfo;
+use crate::core::CustomRole;
 use crate::define_plan_node;
+use crate::query::parser::ast::stmt::{ApiKeyAction, GrantPrivilegeKind, UserQuotaOptions};
 
 define_plan_node! {
     pub struct CreateUserNode {
@@ -59,8 +61,10 @@ impl CreateUserNode {
 define_plan_node! {
     pub struct AlterUserNode {
         username: String,
+        password: Option<String>,
         new_role: Option<String>,
         is_locked: Option<bool>,
+        quota: UserQuotaOptions,
     }
     manage_enum: UserManageNode::Alter as UserManage
     input: ZeroInputNode
@@ -71,13 +75,21 @@ impl AlterUserNode {
         Self {
             id,
             username,
+            password: None,
             new_role: None,
             is_locked: None,
+            quota: UserQuotaOptions::default(),
             output_var: None,
             col_names: Vec::new(),
         }
     }
 
+    /// Reset the password; the plaintext is hashed by the user store
+    pub fn with_password(mut self, password: String) -> Self {
+        self.password = Some(password);
+        self
+    }
+
     pub fn with_role(mut self, role: String) -> Self {
         self.new_role = Some(role);
         self
@@ -88,10 +100,19 @@ impl AlterUserNode {
         self
     }
 
+    pub fn with_quota(mut self, quota: UserQuotaOptions) -> Self {
+        self.quota = quota;
+        self
+    }
+
     pub fn username(&self) -> &str {
         &self.username
     }
 
+    pub fn password(&self) -> Option<&String> {
+        self.password.as_ref()
+    }
+
     pub fn new_role(&self) -> Option<&String> {
         self.new_role.as_ref()
     }
@@ -99,6 +120,10 @@ impl AlterUserNode {
     pub fn is_locked(&self) -> Option<bool> {
         self.is_locked
     }
+
+    pub fn quota(&self) -> &UserQuotaOptions {
+        &self.quota
+    }
 }
 
 define_plan_node! {
@@ -222,6 +247,81 @@ impl RevokeRoleNode {
     }
 }
 
+define_plan_node! {
+    pub struct CreateRoleNode {
+        role: CustomRole,
+        if_not_exists: bool,
+    }
+    manage_enum: UserManageNode::CreateRole as UserManage
+    input: ZeroInputNode
+}
+
+impl CreateRoleNode {
+    pub fn new(id: i64, role: CustomRole, if_not_exists: bool) -> Self {
+        Self {
+            id,
+            role,
+            if_not_exists,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn role(&self) -> &CustomRole {
+        &self.role
+    }
+
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+}
+
+define_plan_node! {
+    pub struct GrantPrivilegeNode {
+        kind: GrantPrivilegeKind,
+    }
+    manage_enum: UserManageNode::GrantPrivilege as UserManage
+    input: ZeroInputNode
+}
+
+impl GrantPrivilegeNode {
+    pub fn new(id: i64, kind: GrantPrivilegeKind) -> Self {
+        Self {
+            id,
+            kind,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn kind(&self) -> &GrantPrivilegeKind {
+        &self.kind
+    }
+}
+
+define_plan_node! {
+    pub struct ApiKeyNode {
+        action: ApiKeyAction,
+    }
+    manage_enum: UserManageNode::ApiKey as UserManage
+    input: ZeroInputNode
+}
+
+impl ApiKeyNode {
+    pub fn new(id: i64, action: ApiKeyAction) -> Self {
+        Self {
+            id,
+            action,
+            output_var: None,
+            col_names: Vec::new(),
+        }
+    }
+
+    pub fn action(&self) -> &ApiKeyAction {
+        &self.action
+    }
+}
+
 define_plan_node! {
     pub struct ShowUsersNode {}
     manage_enum: UserManageNode::ShowUsers as UserManage
@@ -14,12 +14,14 @@ pub use access::{
     EdgeIndexScanNode, GetEdgesNode, GetNeighborsNode, GetVerticesNode, ScanEdgesNode,
     ScanVerticesNode,
 };
-pub use access::{IndexLimit, IndexScanNode, OrderByItem, ScanType};
+pub use access::{
+    IndexLimit, IndexScanNode, MaterializedViewScanNode, OrderByItem, ProcedureCallNode, ScanType,
+};
 pub use base::plan_node_traits::*;
 pub use base::{PlanNodeCategory, PlanNodeEnum, PlanNodeVisitor};
 pub use control_flow::{
-    ArgumentNode, BeginTransactionNode, CommitNode, LoopNode, PassThroughNode, RollbackNode,
-    SelectNode, StartNode,
+    ArgumentNode, BeginTransactionNode, CallSubqueryNode, CommitNode, ForeachNode, LoopNode,
+    PassThroughNode, RollbackNode, SelectNode, StartNode,
 };
 pub use data_modification::{
     DeleteEdgesNode, DeleteIndexNode, DeleteTagsNode, DeleteVerticesNode, EdgeDeleteInfo,
@@ -30,31 +32,32 @@ pub use data_modification::{
 };
 pub use graph_operations::{
     AggregateNode, ApplyKind, ApplyNode, AssignNode, DataCollectNode, DedupNode, IntersectNode,
-    MaterializeNode, MinusNode, PatternApplyNode, RemoveNode, RollUpApplyNode, UnionNode,
-    UnwindNode,
+    MaterializeNode, MinusNode, PatternApplyNode, PatternSubqueryNode, PatternSubqueryOutput,
+    RemoveNode, RollUpApplyNode, UnionNode, UnwindNode,
 };
 pub use join::{
     AntiJoinNode, CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode,
-    InnerJoinNode, LeftJoinNode, RightJoinNode, SemiJoinNode,
+    InnerJoinNode, JoinRelation, LeftJoinNode, MultiwayJoinNode, RightJoinNode, SemiJoinNode,
 };
 pub use management::{
-    AlterEdgeNode, AlterSpaceNode, AlterTagNode, AlterUserNode, ChangePasswordNode, ClearSpaceNode,
-    CreateEdgeIndexNode, CreateEdgeNode, CreateSpaceNode, CreateTagIndexNode, CreateTagNode,
-    CreateUserNode, DescEdgeIndexNode, DescEdgeNode, DescSpaceNode, DescTagIndexNode, DescTagNode,
-    DescribeUserNode, DropEdgeIndexNode, DropEdgeNode, DropSpaceNode, DropTagIndexNode,
-    DropTagNode, DropUserNode, EdgeAlterInfo, EdgeManageInfo, GrantRoleNode, IndexManageInfo,
-    RebuildEdgeIndexNode, RebuildTagIndexNode, RevokeRoleNode, ShowCreateEdgeNode,
-    ShowCreateIndexNode, ShowCreateSpaceNode, ShowCreateTagNode, ShowEdgeIndexesNode,
-    ShowEdgesNode, ShowIndexesNode, ShowRolesNode, ShowSpacesNode, ShowStatsNode, ShowStatsType,
-    ShowTagIndexesNode, ShowTagsNode, ShowUsersNode, SpaceAlterOption, SpaceManageInfo,
-    SwitchSpaceNode, TagAlterInfo, TagManageInfo,
+    AlterEdgeNode, AlterSpaceNode, AlterTagNode, AlterUserNode, ApiKeyNode, ChangePasswordNode,
+    ClearSpaceNode, CreateConstraintNode, CreateEdgeIndexNode, CreateEdgeNode, CreateRoleNode,
+    CreateSpaceNode, CreateTagIndexNode, CreateTagNode, CreateUserNode, DescEdgeIndexNode,
+    DescEdgeNode, DescSpaceNode, DescTagIndexNode, DescTagNode, DescribeUserNode,
+    DropConstraintNode, DropEdgeIndexNode, DropEdgeNode, DropSpaceNode, DropTagIndexNode,
+    DropTagNode, DropUserNode, EdgeAlterInfo, EdgeManageInfo, GrantPrivilegeNode, GrantRoleNode,
+    IndexManageInfo, RebuildEdgeIndexNode, RebuildTagIndexNode, RevokeRoleNode,
+    ShowConstraintsNode, ShowCreateEdgeNode, ShowCreateIndexNode, ShowCreateSpaceNode,
+    ShowCreateTagNode, ShowEdgeIndexesNode, ShowEdgesNode, ShowIndexesNode, ShowRolesNode,
+    ShowSpacesNode, ShowStatsNode, ShowStatsType, ShowTagIndexesNode, ShowTagsNode, ShowUsersNode,
+    SpaceAlterOption, SpaceManageInfo, SwitchSpaceNode, TagAlterInfo, TagManageInfo,
 };
 pub use operation::{FilterNode, LimitNode, ProjectNode, SampleNode, SortItem, SortNode, TopNNode};
 pub use plan_node_factory::PlanNodeFactory;
 pub use search::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
-    DropFulltextIndexNode, FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
-    ShowFulltextIndexNode,
+    DropFulltextIndexNode, FulltextLookupNode, FulltextSearchNode, HybridSearchNode,
+    MatchFulltextNode, ShowFulltextIndexNode, SyncDlqNode,
 };
 pub use search::{CreateVectorIndexNode, DropVectorIndexNode};
 #[cfg(feature = "qdrant")]
@@ -4,6 +4,7 @@
 
 use crate::core::types::FulltextEngineType;
 use crate::query::parser::ast::fulltext::{AlterIndexAction, IndexFieldDef, IndexOptions};
+use crate::query::parser::ast::stmt::SyncDlqAction;
 use crate::query::planning::plan::core::nodes::base::memory_estimation::MemoryEstimatable;
 use crate::query::planning::plan::core::nodes::base::plan_node_category::PlanNodeCategory;
 use crate::query::planning::plan::core::nodes::base::plan_node_traits::{PlanNode, ZeroInputNode};
@@ -278,6 +279,53 @@ impl PlanNode for DescribeFulltextIndexNode {
 
 impl ZeroInputNode for DescribeFulltextIndexNode {}
 
+/// SHOW / RETRY / PURGE SYNC DLQ plan node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncDlqNode {
+    pub action: SyncDlqAction,
+}
+
+impl SyncDlqNode {
+    pub fn new(action: SyncDlqAction) -> Self {
+        Self { action }
+    }
+}
+
+impl PlanNode for SyncDlqNode {
+    fn id(&self) -> i64 {
+        0
+    }
+
+    fn name(&self) -> &'static str {
+        "SyncDlq"
+    }
+
+    fn category(&self) -> PlanNodeCategory {
+        PlanNodeCategory::Management
+    }
+
+    fn output_var(&self) -> Option<&str> {
+        None
+    }
+
+    fn col_names(&self) -> &[String] {
+        &[]
+    }
+
+    fn set_output_var(&mut self, _var: String) {}
+
+    fn set_col_names(&mut self, _names: Vec<String>) {}
+
+    fn into_enum(
+        self,
+    ) -> crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum {
+        use crate::query::planning::plan::core::nodes::management::manage_node_enums::FulltextManageNode;
+        crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum::FulltextManage(FulltextManageNode::SyncDlq(self))
+    }
+}
+
+impl ZeroInputNode for SyncDlqNode {}
+
 impl MemoryEstimatable for CreateFulltextIndexNode {
     fn estimate_memory(&self) -> usize {
         let base = std::mem::size_of::<CreateFulltextIndexNode>();
@@ -335,3 +383,9 @@ impl MemoryEstimatable for DescribeFulltextIndexNode {
         base + index_name_size
     }
 }
+
+impl MemoryEstimatable for SyncDlqNode {
+    fn estimate_memory(&self) -> usize {
+        std::mem::size_of::<SyncDlqNode>()
+    }
+}
@@ -9,5 +9,5 @@ pub mod management;
 pub use data_access::{FulltextLookupNode, FulltextSearchNode, MatchFulltextNode};
 pub use management::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
-    DropFulltextIndexNode, ShowFulltextIndexNode,
+    DropFulltextIndexNode, ShowFulltextIndexNode, SyncDlqNode,
 };
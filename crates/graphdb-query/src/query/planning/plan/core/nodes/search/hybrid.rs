@@ -0,0 +1,152 @@
+//! Hybrid Search Plan Node
+//!
+//! This module defines the plan node for `SEARCH HYBRID`, which fuses a full-text
+//! ranking and a vector ranking of the same tag.
+
+use crate::core::types::expr::contextual::ContextualExpression;
+use crate::query::parser::ast::fulltext::FulltextQueryExpr;
+use crate::query::parser::ast::hybrid::{FusionMethod, HybridSearchStatement};
+use crate::query::parser::ast::vector::{VectorQueryExpr, VectorYieldClause};
+use crate::query::planning::plan::core::node_id_generator::next_node_id;
+use crate::query::planning::plan::core::nodes::base::memory_estimation::MemoryEstimatable;
+use crate::query::planning::plan::core::nodes::base::plan_node_category::PlanNodeCategory;
+use crate::query::planning::plan::core::nodes::base::plan_node_traits::{PlanNode, ZeroInputNode};
+
+/// Hybrid search plan node (SEARCH HYBRID statement)
+///
+/// Unlike the single-index search nodes, the output columns are known at planning time,
+/// so the hits can be piped into traversals such as `GO FROM $-.vid`.
+#[derive(Debug, Clone)]
+pub struct HybridSearchNode {
+    id: i64,
+    pub fulltext_index: String,
+    pub fulltext_query: FulltextQueryExpr,
+    pub vector_index: String,
+    pub vector_query: VectorQueryExpr,
+    pub fusion: FusionMethod,
+    pub where_clause: Option<ContextualExpression>,
+    pub limit: usize,
+    pub offset: usize,
+    pub yield_clause: Option<VectorYieldClause>,
+    /// Pre-resolved space_id from metadata context
+    pub space_id: u64,
+    /// Pre-resolved tag shared by both indexes
+    pub tag_name: String,
+    /// Pre-resolved field of the full-text index
+    pub fulltext_field: String,
+    /// Pre-resolved field of the vector index
+    pub vector_field: String,
+    output_var: Option<String>,
+    col_names: Vec<String>,
+}
+
+impl HybridSearchNode {
+    pub fn new(search: &HybridSearchStatement) -> Self {
+        Self {
+            id: next_node_id(),
+            fulltext_index: search.fulltext_index.clone(),
+            fulltext_query: search.fulltext_query.clone(),
+            vector_index: search.vector_index.clone(),
+            vector_query: search.vector_query.clone(),
+            fusion: search.fusion,
+            where_clause: search.where_clause.clone(),
+            limit: search.limit.unwrap_or(HybridSearchStatement::DEFAULT_LIMIT),
+            offset: search.offset.unwrap_or(0),
+            yield_clause: search.yield_clause.clone(),
+            space_id: 0,
+            tag_name: String::new(),
+            fulltext_field: String::new(),
+            vector_field: String::new(),
+            output_var: None,
+            col_names: search.output_columns(),
+        }
+    }
+
+    pub fn with_metadata(
+        mut self,
+        space_id: u64,
+        tag_name: String,
+        fulltext_field: String,
+        vector_field: String,
+    ) -> Self {
+        self.space_id = space_id;
+        self.tag_name = tag_name;
+        self.fulltext_field = fulltext_field;
+        self.vector_field = vector_field;
+        self
+    }
+
+    /// Number of hits requested from each index before fusion
+    ///
+    /// Filtering and paging happen after fusion, so each list is over-fetched.
+    pub fn candidate_limit(&self) -> usize {
+        ((self.limit + self.offset) * 4).max(50)
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl PlanNode for HybridSearchNode {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        "HybridSearch"
+    }
+
+    fn category(&self) -> PlanNodeCategory {
+        PlanNodeCategory::DataAccess
+    }
+
+    fn output_var(&self) -> Option<&str> {
+        self.output_var.as_deref()
+    }
+
+    fn col_names(&self) -> &[String] {
+        &self.col_names
+    }
+
+    fn set_output_var(&mut self, var: String) {
+        self.output_var = Some(var);
+    }
+
+    fn set_col_names(&mut self, names: Vec<String>) {
+        self.col_names = names;
+    }
+
+    fn into_enum(
+        self,
+    ) -> crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum {
+        crate::query::planning::plan::core::nodes::base::plan_node_enum::PlanNodeEnum::HybridSearch(
+            self,
+        )
+    }
+}
+
+impl ZeroInputNode for HybridSearchNode {}
+
+impl MemoryEstimatable for HybridSearchNode {
+    fn estimate_memory(&self) -> usize {
+        let base = std::mem::size_of::<HybridSearchNode>();
+        let names_size = self.fulltext_index.capacity()
+            + self.vector_index.capacity()
+            + self.tag_name.capacity()
+            + self.fulltext_field.capacity()
+            + self.vector_field.capacity();
+        let query_size = self.vector_query.query_data.capacity();
+        let yield_size = self
+            .yield_clause
+            .as_ref()
+            .map(|_| std::mem::size_of::<VectorYieldClause>())
+            .unwrap_or(0);
+        let col_names_size = self
+            .col_names
+            .iter()
+            .map(|c| std::mem::size_of::<String>() + c.capacity())
+            .sum::<usize>();
+        base + names_size + query_size + yield_size + col_names_size
+    }
+}
@@ -1,16 +1,18 @@
 //! Search Plan Nodes
 //!
 //! This module defines plan nodes for specialized search operations,
-//! including full-text search and vector search.
+//! including full-text search, vector search and their hybrid.
 
 pub mod fulltext;
+pub mod hybrid;
 pub mod vector;
 
 pub use fulltext::{
     AlterFulltextIndexNode, CreateFulltextIndexNode, DescribeFulltextIndexNode,
     DropFulltextIndexNode, FulltextLookupNode, FulltextSearchNode, MatchFulltextNode,
-    ShowFulltextIndexNode,
+    ShowFulltextIndexNode, SyncDlqNode,
 };
+pub use hybrid::HybridSearchNode;
 pub use vector::{CreateVectorIndexNode, CreateVectorIndexParams, DropVectorIndexNode};
 #[cfg(feature = "qdrant")]
 pub use vector::{
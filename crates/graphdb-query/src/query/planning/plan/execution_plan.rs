@@ -1,6 +1,7 @@
 //! Structure definition of the execution plan
 //! Contains the ExecutionPlan and SubPlan structures.
 
+use crate::query::parser::ast::QueryHints;
 use crate::query::planning::plan::PlanNodeEnum;
 
 /// Execution plan structure
@@ -18,6 +19,9 @@ pub struct ExecutionPlan {
 
     /// Of course! Please provide the text you would like to have translated.
     pub format: String,
+
+    /// Optimizer hints of the statement and the warnings for the ones not honoured
+    pub hints: QueryHints,
 }
 
 impl ExecutionPlan {
@@ -28,6 +32,7 @@ impl ExecutionPlan {
             id: -1, // This will be allocated later on.
             optimize_time_in_us: 0,
             format: "default".to_string(),
+            hints: QueryHints::default(),
         }
     }
 
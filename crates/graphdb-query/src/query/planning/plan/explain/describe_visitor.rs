@@ -20,11 +20,15 @@ use crate::query::planning::plan::core::nodes::control_flow::control_flow_node::
     ArgumentNode, LoopNode, PassThroughNode, SelectNode,
 };
 use crate::query::planning::plan::core::nodes::control_flow::start_node::StartNode;
+use crate::query::planning::plan::core::nodes::control_flow::subquery_node::{
+    CallSubqueryNode, ForeachNode,
+};
 use crate::query::planning::plan::core::nodes::graph_operations::aggregate_node::AggregateNode;
 use crate::query::planning::plan::core::nodes::graph_operations::graph_operations_node::{
     AssignNode, DataCollectNode, DedupNode, PatternApplyNode, RollUpApplyNode, UnionNode,
     UnwindNode,
 };
+use crate::query::planning::plan::core::nodes::graph_operations::pattern_subquery_node::PatternSubqueryNode;
 use crate::query::planning::plan::core::nodes::graph_operations::set_operations_node::{
     IntersectNode, MinusNode,
 };
@@ -32,6 +36,7 @@ use crate::query::planning::plan::core::nodes::join::join_node::{
     CrossJoinNode, FullOuterJoinNode, HashInnerJoinNode, HashLeftJoinNode, InnerJoinNode,
     LeftJoinNode,
 };
+use crate::query::planning::plan::core::nodes::join::multiway_join_node::MultiwayJoinNode;
 use crate::query::planning::plan::core::nodes::management::manage_node_enums::{
     EdgeManageNode, FulltextManageNode, IndexManageNode, SpaceManageNode, TagManageNode,
     UserManageNode, VectorManageNode,
@@ -287,6 +292,9 @@ impl PlanNodeVisitor for DescribeVisitor {
         visit_unwind => "Unwind", UnwindNode,
         visit_assign => "Assign", AssignNode,
         visit_pattern_apply => "PatternApply", PatternApplyNode,
+        visit_pattern_subquery => "PatternSubquery", PatternSubqueryNode,
+        visit_call_subquery => "CallSubquery", CallSubqueryNode,
+        visit_foreach => "Foreach", ForeachNode,
         visit_roll_up_apply => "RollUpApply", RollUpApplyNode,
     );
 
@@ -630,6 +638,20 @@ impl PlanNodeVisitor for DescribeVisitor {
         self.visited_ids.insert(node.id());
     }
 
+    fn visit_multiway_join(&mut self, node: &MultiwayJoinNode) {
+        let mut desc = PlanNodeDescription::new("MultiwayJoin", node.id());
+        if let Some(var) = node.output_var() {
+            desc = desc.with_output_var(var.to_string());
+        }
+
+        let relations: Vec<String> = node.relations().iter().map(|r| r.to_string()).collect();
+        desc.add_description("relations", relations.join(", "));
+        desc.add_description("variable_order", node.variable_order().join(", "));
+
+        self.descriptions.push(desc);
+        self.visited_ids.insert(node.id());
+    }
+
     fn visit_multi_shortest_path(&mut self, node: &MultiShortestPathNode) {
         let mut desc = PlanNodeDescription::new("MultiShortestPath", node.id());
         if let Some(var) = node.output_var() {
@@ -23,17 +23,21 @@ use crate::query::planning::statements::ddl::user_management_planner::UserManage
 use crate::query::planning::statements::dml::assignment_planner::AssignmentPlanner;
 use crate::query::planning::statements::dml::create_planner::CreatePlanner;
 use crate::query::planning::statements::dml::delete_planner::DeletePlanner;
+use crate::query::planning::statements::dml::foreach_planner::ForeachPlanner;
 use crate::query::planning::statements::dml::insert_planner::InsertPlanner;
 use crate::query::planning::statements::dml::merge_planner::MergePlanner;
 use crate::query::planning::statements::dml::remove_planner::RemovePlanner;
 use crate::query::planning::statements::dml::set_planner::SetPlanner;
 use crate::query::planning::statements::dml::update_planner::UpdatePlanner;
+use crate::query::planning::statements::dql::call_planner::CallPlanner;
+use crate::query::planning::statements::dql::call_subquery_planner::CallSubqueryPlanner;
 use crate::query::planning::statements::dql::explain_planner::ExplainPlanner;
 use crate::query::planning::statements::dql::fetch_edges_planner::FetchEdgesPlanner;
 use crate::query::planning::statements::dql::fetch_vertices_planner::FetchVerticesPlanner;
 use crate::query::planning::statements::dql::go_planner::GoPlanner;
 use crate::query::planning::statements::dql::group_by_planner::GroupByPlanner;
 use crate::query::planning::statements::dql::lookup_planner::LookupPlanner;
+use crate::query::planning::statements::dql::materialized_view_planner::MaterializedViewPlanner;
 use crate::query::planning::statements::dql::path_planner::PathPlanner;
 use crate::query::planning::statements::dql::pipe_planner::PipePlanner;
 use crate::query::planning::statements::dql::return_planner::ReturnPlanner;
@@ -146,6 +150,7 @@ pub enum PlannerEnum {
     Remove(RemovePlanner),
     Set(SetPlanner),
     Merge(MergePlanner),
+    Foreach(ForeachPlanner),
     GroupBy(GroupByPlanner),
     SetOperation(SetOperationPlanner),
     Use(UsePlanner),
@@ -153,6 +158,9 @@ pub enum PlannerEnum {
     With(WithPlanner),
     Return(ReturnPlanner),
     Yield(YieldPlanner),
+    Call(CallPlanner),
+    MaterializedView(MaterializedViewPlanner),
+    CallSubquery(CallSubqueryPlanner),
     Pipe(PipePlanner),
     Explain(ExplainPlanner),
     FulltextSearch(FulltextSearchPlanner),
@@ -184,6 +192,7 @@ impl PlannerEnum {
             Stmt::Remove(_) => Some(PlannerEnum::Remove(RemovePlanner::new())),
             Stmt::Set(_) => Some(PlannerEnum::Set(SetPlanner::new())),
             Stmt::Merge(_) => Some(PlannerEnum::Merge(MergePlanner::new())),
+            Stmt::Foreach(_) => Some(PlannerEnum::Foreach(ForeachPlanner::new())),
             Stmt::Assignment(_) => Some(PlannerEnum::Assignment(AssignmentPlanner::new())),
             Stmt::GroupBy(_) => Some(PlannerEnum::GroupBy(GroupByPlanner::new())),
             Stmt::SetOperation(_) => Some(PlannerEnum::SetOperation(SetOperationPlanner::new())),
@@ -192,6 +201,11 @@ impl PlannerEnum {
             Stmt::With(_) => Some(PlannerEnum::With(WithPlanner::new())),
             Stmt::Return(_) => Some(PlannerEnum::Return(ReturnPlanner::new())),
             Stmt::Yield(_) => Some(PlannerEnum::Yield(YieldPlanner::new())),
+            Stmt::Call(_) => Some(PlannerEnum::Call(CallPlanner::new())),
+            Stmt::MaterializedView(_) => {
+                Some(PlannerEnum::MaterializedView(MaterializedViewPlanner::new()))
+            }
+            Stmt::CallSubquery(_) => Some(PlannerEnum::CallSubquery(CallSubqueryPlanner::new())),
             Stmt::Pipe(_) => Some(PlannerEnum::Pipe(PipePlanner::new())),
             Stmt::Explain(_) => Some(PlannerEnum::Explain(ExplainPlanner::new())),
             Stmt::Profile(_) => Some(PlannerEnum::Explain(ExplainPlanner::new_profile())),
@@ -203,9 +217,9 @@ impl PlannerEnum {
             | Stmt::DescribeFulltextIndex(_)
             | Stmt::Search(_)
             | Stmt::LookupFulltext(_)
-            | Stmt::MatchFulltext(_) => {
-                Some(PlannerEnum::FulltextSearch(FulltextSearchPlanner::new()))
-            }
+            | Stmt::MatchFulltext(_)
+            | Stmt::HybridSearch(_)
+            | Stmt::SyncDlq(_) => Some(PlannerEnum::FulltextSearch(FulltextSearchPlanner::new())),
             #[cfg(feature = "qdrant")]
             Stmt::CreateVectorIndex(_)
             | Stmt::DropVectorIndex(_)
@@ -226,6 +240,9 @@ impl PlannerEnum {
             | Stmt::ChangePassword(_)
             | Stmt::Grant(_)
             | Stmt::Revoke(_)
+            | Stmt::CreateRole(_)
+            | Stmt::GrantPrivilege(_)
+            | Stmt::ApiKey(_)
             | Stmt::DescribeUser(_)
             | Stmt::ShowUsers(_)
             | Stmt::ShowRoles(_) => Some(PlannerEnum::UserManagement(UserManagementPlanner::new())),
@@ -278,6 +295,7 @@ impl PlannerEnum {
             PlannerEnum::Remove(planner) => planner.transform(validated, qctx),
             PlannerEnum::Set(planner) => planner.transform(validated, qctx),
             PlannerEnum::Merge(planner) => planner.transform(validated, qctx),
+            PlannerEnum::Foreach(planner) => planner.transform(validated, qctx),
             PlannerEnum::GroupBy(planner) => planner.transform(validated, qctx),
             PlannerEnum::SetOperation(planner) => planner.transform(validated, qctx),
             PlannerEnum::Use(planner) => planner.transform(validated, qctx),
@@ -285,6 +303,9 @@ impl PlannerEnum {
             PlannerEnum::With(planner) => planner.transform(validated, qctx),
             PlannerEnum::Return(planner) => planner.transform(validated, qctx),
             PlannerEnum::Yield(planner) => planner.transform(validated, qctx),
+            PlannerEnum::Call(planner) => planner.transform(validated, qctx),
+            PlannerEnum::MaterializedView(planner) => planner.transform(validated, qctx),
+            PlannerEnum::CallSubquery(planner) => planner.transform(validated, qctx),
             PlannerEnum::Pipe(planner) => planner.transform(validated, qctx),
             PlannerEnum::Explain(planner) => planner.transform(validated, qctx),
             PlannerEnum::FulltextSearch(planner) => planner.transform(validated, qctx),
@@ -313,6 +334,7 @@ impl PlannerEnum {
             PlannerEnum::Remove(_) => "RemovePlanner",
             PlannerEnum::Set(_) => "SetPlanner",
             PlannerEnum::Merge(_) => "MergePlanner",
+            PlannerEnum::Foreach(_) => "ForeachPlanner",
             PlannerEnum::GroupBy(_) => "GroupByPlanner",
             PlannerEnum::SetOperation(_) => "SetOperationPlanner",
             PlannerEnum::Use(_) => "UsePlanner",
@@ -320,6 +342,9 @@ impl PlannerEnum {
             PlannerEnum::With(_) => "WithPlanner",
             PlannerEnum::Return(_) => "ReturnPlanner",
             PlannerEnum::Yield(_) => "YieldPlanner",
+            PlannerEnum::Call(_) => "CallPlanner",
+            PlannerEnum::MaterializedView(_) => "MaterializedViewPlanner",
+            PlannerEnum::CallSubquery(_) => "CallSubqueryPlanner",
             PlannerEnum::Pipe(_) => "PipePlanner",
             PlannerEnum::Explain(_) => "ExplainPlanner",
             PlannerEnum::FulltextSearch(_) => "FulltextSearchPlanner",
@@ -348,6 +373,7 @@ impl PlannerEnum {
             PlannerEnum::Remove(planner) => planner.match_planner(stmt),
             PlannerEnum::Set(planner) => planner.match_planner(stmt),
             PlannerEnum::Merge(planner) => planner.match_planner(stmt),
+            PlannerEnum::Foreach(planner) => planner.match_planner(stmt),
             PlannerEnum::GroupBy(planner) => planner.match_planner(stmt),
             PlannerEnum::SetOperation(planner) => planner.match_planner(stmt),
             PlannerEnum::Use(planner) => planner.match_planner(stmt),
@@ -355,6 +381,9 @@ impl PlannerEnum {
             PlannerEnum::With(planner) => planner.match_planner(stmt),
             PlannerEnum::Return(planner) => planner.match_planner(stmt),
             PlannerEnum::Yield(planner) => planner.match_planner(stmt),
+            PlannerEnum::Call(planner) => planner.match_planner(stmt),
+            PlannerEnum::MaterializedView(planner) => planner.match_planner(stmt),
+            PlannerEnum::CallSubquery(planner) => planner.match_planner(stmt),
             PlannerEnum::Pipe(planner) => planner.match_planner(stmt),
             PlannerEnum::Explain(planner) => planner.match_planner(stmt),
             PlannerEnum::FulltextSearch(planner) => planner.match_planner(stmt),
@@ -422,6 +451,9 @@ impl PlannerEnum {
             PlannerEnum::Merge(planner) => {
                 planner.transform_with_metadata(validated, qctx, metadata_context)
             }
+            PlannerEnum::Foreach(planner) => {
+                planner.transform_with_metadata(validated, qctx, metadata_context)
+            }
             PlannerEnum::GroupBy(planner) => {
                 planner.transform_with_metadata(validated, qctx, metadata_context)
             }
@@ -443,6 +475,15 @@ impl PlannerEnum {
             PlannerEnum::Yield(planner) => {
                 planner.transform_with_metadata(validated, qctx, metadata_context)
             }
+            PlannerEnum::Call(planner) => {
+                planner.transform_with_metadata(validated, qctx, metadata_context)
+            }
+            PlannerEnum::MaterializedView(planner) => {
+                planner.transform_with_metadata(validated, qctx, metadata_context)
+            }
+            PlannerEnum::CallSubquery(planner) => {
+                planner.transform_with_metadata(validated, qctx, metadata_context)
+            }
             PlannerEnum::Pipe(planner) => {
                 planner.transform_with_metadata(validated, qctx, metadata_context)
             }
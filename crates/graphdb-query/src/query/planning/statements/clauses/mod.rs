@@ -19,6 +19,7 @@
 
 pub mod order_by_planner;
 pub mod pagination_planner;
+pub mod pattern_subquery_planner;
 pub mod return_clause_planner;
 pub mod unwind_planner;
 pub mod where_clause_planner;
@@ -247,6 +247,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -291,6 +292,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -340,6 +342,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
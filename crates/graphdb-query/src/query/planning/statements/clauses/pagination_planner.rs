@@ -184,6 +184,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -228,6 +229,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -270,6 +272,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -0,0 +1,221 @@
+//! Pattern subquery planning
+//!
+//! `EXISTS { }`, `COUNT { }` and pattern comprehensions cannot be evaluated by the expression
+//! evaluator because they read the graph. The clause planners lift them out of their
+//! expressions into `PatternSubqueryNode`s stacked on top of the clause input:
+//! - a top-level `EXISTS` conjunct of a WHERE clause becomes a semi join,
+//!   a top-level `NOT EXISTS` conjunct an anti join;
+//! - every other occurrence is computed into a hidden `__pattern_<n>` column and the
+//!   expression refers to that column instead.
+
+use crate::core::types::expr::contextual::ContextualExpression;
+use crate::core::types::expr::expression::ExpressionMeta;
+use crate::core::types::expr::{PatternSubquery, PatternSubqueryKind};
+use crate::core::types::operators::{BinaryOperator, UnaryOperator};
+use crate::core::Expression;
+use crate::query::planning::plan::core::nodes::{PatternSubqueryNode, PatternSubqueryOutput};
+use crate::query::planning::plan::core::PlanNodeEnum;
+use crate::query::planning::planner::PlannerError;
+
+/// Prefix of the columns holding pattern subquery results
+pub const PATTERN_COLUMN_PREFIX: &str = "__pattern_";
+
+/// Whether the expression embeds a pattern subquery
+pub fn contains_pattern_subquery(expression: &Expression) -> bool {
+    expression
+        .find(&|e| matches!(e, Expression::PatternSubquery(_)))
+        .is_some()
+}
+
+/// Plan the pattern subqueries of a WHERE condition.
+///
+/// Returns the new input and what is left of the condition; `None` when every conjunct was
+/// turned into a semi or anti join and no Filter is needed anymore.
+pub fn plan_pattern_predicates(
+    input: PlanNodeEnum,
+    space_name: &str,
+    condition: &Expression,
+) -> Result<(PlanNodeEnum, Option<Expression>), PlannerError> {
+    let mut conjuncts = Vec::new();
+    split_conjuncts(condition, &mut conjuncts);
+
+    let mut input = input;
+    let mut remaining: Option<Expression> = None;
+    for conjunct in conjuncts {
+        let join = match conjunct {
+            Expression::PatternSubquery(subquery)
+                if subquery.kind == PatternSubqueryKind::Exists =>
+            {
+                Some((subquery.as_ref().clone(), PatternSubqueryOutput::Semi))
+            }
+            Expression::Unary {
+                op: UnaryOperator::Not,
+                operand,
+            } => match operand.as_ref() {
+                Expression::PatternSubquery(subquery)
+                    if subquery.kind == PatternSubqueryKind::Exists =>
+                {
+                    Some((subquery.as_ref().clone(), PatternSubqueryOutput::Anti))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some((subquery, output)) = join {
+            input = pattern_subquery_node(input, space_name, subquery, output)?;
+            continue;
+        }
+
+        let (new_input, rewritten) = plan_pattern_columns(input, space_name, conjunct)?;
+        input = new_input;
+        remaining = Some(match remaining {
+            Some(left) => Expression::and(left, rewritten),
+            None => rewritten,
+        });
+    }
+
+    Ok((input, remaining))
+}
+
+/// Compute every pattern subquery of `expression` into a column of the input and rewrite the
+/// expression to read those columns.
+pub fn plan_pattern_columns(
+    input: PlanNodeEnum,
+    space_name: &str,
+    expression: &Expression,
+) -> Result<(PlanNodeEnum, Expression), PlannerError> {
+    let mut next_index = input
+        .col_names()
+        .iter()
+        .filter(|c| c.starts_with(PATTERN_COLUMN_PREFIX))
+        .count();
+
+    let mut subqueries = Vec::new();
+    let mut rewritten = expression.clone();
+    extract_subqueries(&mut rewritten, &mut next_index, &mut subqueries);
+
+    let mut input = input;
+    for (alias, subquery) in subqueries {
+        let output = PatternSubqueryOutput::Column(alias);
+        input = pattern_subquery_node(input, space_name, subquery, output)?;
+    }
+    Ok((input, rewritten))
+}
+
+/// Register a rewritten expression in the context of the expression it replaces.
+pub fn rewrite_contextual(
+    original: &ContextualExpression,
+    expression: Expression,
+) -> ContextualExpression {
+    let ctx = original.context();
+    let id = ctx.register_expression(ExpressionMeta::new(expression));
+    ContextualExpression::new(id, ctx.clone())
+}
+
+fn pattern_subquery_node(
+    input: PlanNodeEnum,
+    space_name: &str,
+    subquery: PatternSubquery,
+    output: PatternSubqueryOutput,
+) -> Result<PlanNodeEnum, PlannerError> {
+    let node = PatternSubqueryNode::new(input, space_name, subquery, output)?;
+    Ok(PlanNodeEnum::PatternSubquery(node))
+}
+
+fn split_conjuncts<'a>(expression: &'a Expression, conjuncts: &mut Vec<&'a Expression>) {
+    match expression {
+        Expression::Binary {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjuncts(left, conjuncts);
+            split_conjuncts(right, conjuncts);
+        }
+        _ => conjuncts.push(expression),
+    }
+}
+
+fn extract_subqueries(
+    expression: &mut Expression,
+    next_index: &mut usize,
+    subqueries: &mut Vec<(String, PatternSubquery)>,
+) {
+    if let Expression::PatternSubquery(subquery) = expression {
+        let alias = format!("{}{}", PATTERN_COLUMN_PREFIX, next_index);
+        *next_index += 1;
+        subqueries.push((alias.clone(), subquery.as_ref().clone()));
+        *expression = Expression::variable(alias);
+        return;
+    }
+    for child in expression.children_mut() {
+        extract_subqueries(child, next_index, subqueries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::expr::{PatternEdge, PatternNode, PatternPath};
+    use crate::core::types::graph_schema::EdgeDirection;
+    use crate::query::planning::plan::core::nodes::base::plan_node_traits::SingleInputNode;
+    use crate::query::planning::plan::core::nodes::StartNode;
+
+    fn knows(kind: PatternSubqueryKind) -> Expression {
+        let mut path = PatternPath::new(PatternNode::new(Some("a".to_string())));
+        let mut edge = PatternEdge::new(None, EdgeDirection::Out);
+        edge.edge_types.push("KNOWS".to_string());
+        path.push(edge, PatternNode::new(None));
+        Expression::pattern_subquery(PatternSubquery::new(kind, vec![path], None, None))
+    }
+
+    fn start() -> PlanNodeEnum {
+        PlanNodeEnum::Start(StartNode::new())
+    }
+
+    #[test]
+    fn test_exists_conjuncts_become_joins() {
+        let condition = Expression::and(
+            knows(PatternSubqueryKind::Exists),
+            Expression::unary(UnaryOperator::Not, knows(PatternSubqueryKind::Exists)),
+        );
+
+        let (root, remaining) =
+            plan_pattern_predicates(start(), "test", &condition).expect("planning failed");
+        assert!(remaining.is_none());
+
+        let anti = root.as_pattern_subquery().expect("expected anti join");
+        assert_eq!(anti.output(), &PatternSubqueryOutput::Anti);
+        let semi = anti
+            .input()
+            .as_pattern_subquery()
+            .expect("expected semi join");
+        assert_eq!(semi.output(), &PatternSubqueryOutput::Semi);
+    }
+
+    #[test]
+    fn test_nested_subqueries_become_columns() {
+        let condition = Expression::gt(knows(PatternSubqueryKind::Count), Expression::literal(2));
+
+        let (root, remaining) =
+            plan_pattern_predicates(start(), "test", &condition).expect("planning failed");
+        assert_eq!(
+            remaining,
+            Some(Expression::gt(
+                Expression::variable("__pattern_0"),
+                Expression::literal(2)
+            ))
+        );
+        assert_eq!(
+            root.col_names().last().map(String::as_str),
+            Some("__pattern_0")
+        );
+
+        let (root, rewritten) =
+            plan_pattern_columns(root, "test", &knows(PatternSubqueryKind::Collect))
+                .expect("planning failed");
+        assert_eq!(rewritten, Expression::variable("__pattern_1"));
+        assert_eq!(root.col_names().len(), 2);
+    }
+}
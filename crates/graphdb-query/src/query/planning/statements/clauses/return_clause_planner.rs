@@ -15,6 +15,9 @@ use crate::query::planning::plan::core::nodes::operation::project_node::ProjectN
 use crate::query::planning::plan::core::nodes::AggregateNode;
 use crate::query::planning::plan::SubPlan;
 use crate::query::planning::planner::PlannerError;
+use crate::query::planning::statements::clauses::pattern_subquery_planner::{
+    contains_pattern_subquery, plan_pattern_columns, rewrite_contextual,
+};
 use crate::query::planning::statements::statement_planner::ClausePlanner;
 use crate::query::validator::structs::CypherClauseKind;
 use crate::query::QueryContext;
@@ -116,11 +119,11 @@ impl ClausePlanner for ReturnClausePlanner {
 
     fn transform_clause(
         &self,
-        _qctx: Arc<QueryContext>,
+        qctx: Arc<QueryContext>,
         stmt: &Stmt,
         input_plan: SubPlan,
     ) -> Result<SubPlan, PlannerError> {
-        let yield_columns = extract_return_columns(stmt)?;
+        let mut yield_columns = extract_return_columns(stmt)?;
 
         let input_node = input_plan.root().as_ref().ok_or_else(|| {
             PlannerError::PlanGenerationFailed(
@@ -128,6 +131,21 @@ impl ClausePlanner for ReturnClausePlanner {
             )
         })?;
 
+        // Pattern subqueries are computed into columns before the projection
+        let mut input_node = input_node.clone();
+        for col in &mut yield_columns {
+            let Some(expr) = col.expression.get_expression() else {
+                continue;
+            };
+            if contains_pattern_subquery(&expr) {
+                let space_name = qctx.space_name().unwrap_or_else(|| "default".to_string());
+                let (node, rewritten) = plan_pattern_columns(input_node, &space_name, &expr)?;
+                input_node = node;
+                col.expression = rewrite_contextual(&col.expression, rewritten);
+            }
+        }
+        let input_node = &input_node;
+
         let has_aggregate = yield_columns.iter().any(|col| {
             if let Some(expr_meta) = col.expression.expression() {
                 expression_contains_aggregate(expr_meta.inner())
@@ -473,6 +491,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -536,6 +555,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -596,6 +616,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -2,6 +2,7 @@
 //!
 //! Responsible for planning the execution of the WHERE clause and filtering the input data.
 //! The ClausePlanner interface has been implemented, providing comprehensive filtering capabilities.
+//! Pattern subqueries in the condition are planned by `pattern_subquery_planner`.
 
 use crate::core::types::ContextualExpression;
 use crate::query::parser::ast::Stmt;
@@ -9,6 +10,9 @@ use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
 use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
 use crate::query::planning::plan::SubPlan;
 use crate::query::planning::planner::PlannerError;
+use crate::query::planning::statements::clauses::pattern_subquery_planner::{
+    contains_pattern_subquery, plan_pattern_predicates, rewrite_contextual,
+};
 use crate::query::planning::statements::statement_planner::ClausePlanner;
 use crate::query::validator::structs::CypherClauseKind;
 use crate::query::QueryContext;
@@ -39,7 +43,7 @@ impl ClausePlanner for WhereClausePlanner {
 
     fn transform_clause(
         &self,
-        _qctx: Arc<QueryContext>,
+        qctx: Arc<QueryContext>,
         stmt: &Stmt,
         input_plan: SubPlan,
     ) -> Result<SubPlan, PlannerError> {
@@ -51,8 +55,21 @@ impl ClausePlanner for WhereClausePlanner {
             )
         })?;
 
-        let filter_node = FilterNode::new(input_node.clone(), condition)?;
-        Ok(SubPlan::new(Some(filter_node.into_enum()), input_plan.tail))
+        let (input_node, condition) = match condition.get_expression() {
+            Some(expr) if contains_pattern_subquery(&expr) => {
+                let space_name = qctx.space_name().unwrap_or_else(|| "default".to_string());
+                let (node, remaining) =
+                    plan_pattern_predicates(input_node.clone(), &space_name, &expr)?;
+                (node, remaining.map(|e| rewrite_contextual(&condition, e)))
+            }
+            _ => (input_node.clone(), Some(condition)),
+        };
+
+        let root = match condition {
+            Some(condition) => FilterNode::new(input_node, condition)?.into_enum(),
+            None => input_node,
+        };
+        Ok(SubPlan::new(Some(root), input_plan.tail))
     }
 }
 
@@ -161,6 +178,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -205,6 +223,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
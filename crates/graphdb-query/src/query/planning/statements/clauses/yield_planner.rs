@@ -414,6 +414,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
@@ -465,6 +466,7 @@ mod tests {
                 space_name: None,
                 query: String::new(),
                 parameters: std::collections::HashMap::new(),
+                role: None,
             },
         )));
 
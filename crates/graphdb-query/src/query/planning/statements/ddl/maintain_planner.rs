@@ -1,7 +1,7 @@
 //! Maintenance Operation Planner
 //! Handling query planning related to maintenance tasks (such as SUBMIT JOB, etc.)
 
-use crate::core::types::PropertyDef;
+use crate::core::types::{Constraint, PropertyDef};
 use crate::query::parser::ast::{AlterTarget, CreateTarget, IndexType, ShowTarget, Stmt};
 use crate::query::planning::plan::core::nodes::management::edge_nodes::EdgeAlterInfo;
 use crate::query::planning::plan::core::nodes::management::index_nodes::IndexManageInfo;
@@ -13,7 +13,8 @@ use crate::query::planning::plan::core::nodes::management::space_nodes::{
 };
 use crate::query::planning::plan::core::nodes::management::tag_nodes::TagAlterInfo;
 use crate::query::planning::plan::core::nodes::{
-    AlterEdgeNode, AlterTagNode, CreateEdgeNode, CreateTagNode, EdgeManageInfo, ShowCreateEdgeNode,
+    AlterEdgeNode, AlterTagNode, CreateConstraintNode, CreateEdgeNode, CreateTagNode,
+    DropConstraintNode, EdgeManageInfo, ShowConstraintsNode, ShowCreateEdgeNode,
     ShowCreateIndexNode, ShowCreateSpaceNode, ShowCreateTagNode, ShowEdgesNode, ShowIndexesNode,
     ShowTagsNode, TagManageInfo,
 };
@@ -84,6 +85,10 @@ impl MaintainPlanner {
                     ShowIndexesNode::new(next_node_id(), self.current_space(validated));
                 PlanNodeEnum::IndexManage(IndexManageNode::ShowIndexes(show_indexes_node))
             }
+            ShowTarget::Constraints => {
+                let node = ShowConstraintsNode::new(next_node_id(), self.current_space(validated));
+                PlanNodeEnum::IndexManage(IndexManageNode::ShowConstraints(node))
+            }
             ShowTarget::Tag(_) => {
                 let show_tags_node =
                     ShowTagsNode::new(next_node_id(), self.current_space(validated));
@@ -174,6 +179,24 @@ impl MaintainPlanner {
                 };
                 Ok(Some(plan_node))
             }
+            CreateTarget::Constraint {
+                name,
+                tag,
+                kind,
+                properties,
+            } => {
+                let constraint =
+                    Constraint::new(name.clone(), tag.clone(), *kind, properties.clone());
+                let node = CreateConstraintNode::new(
+                    next_node_id(),
+                    self.current_space(validated),
+                    constraint,
+                    create_stmt.if_not_exists,
+                );
+                Ok(Some(PlanNodeEnum::IndexManage(
+                    IndexManageNode::CreateConstraint(node),
+                )))
+            }
             CreateTarget::Space { name, vid_type, .. } => {
                 let space_info = SpaceManageInfo::new(name.clone()).with_vid_type(vid_type.clone());
                 let node = CreateSpaceNode::new(next_node_id(), space_info);
@@ -391,6 +414,15 @@ impl MaintainPlanner {
                 );
                 PlanNodeEnum::IndexManage(IndexManageNode::DropEdgeIndex(node))
             }
+            DropTarget::Constraint(name) => {
+                let node = DropConstraintNode::new(
+                    next_node_id(),
+                    current_space,
+                    name.clone(),
+                    drop_stmt.if_exists,
+                );
+                PlanNodeEnum::IndexManage(IndexManageNode::DropConstraint(node))
+            }
             DropTarget::Tags(_) => {
                 let node = crate::query::planning::plan::core::nodes::DropTagNode::new(
                     next_node_id(),
@@ -1,5 +1,6 @@
 //! User Management Planner
 //! Handling query planning related to user management (CREATE USER, ALTER USER, DROP USER, CHANGE PASSWORD)
+//! custom roles (CREATE ROLE, fine-grained GRANT) and API keys
 
 use crate::query::parser::ast::Stmt;
 use crate::query::planning::plan::core::nodes::management::manage_node_enums::UserManageNode;
@@ -43,12 +44,16 @@ impl Planner for UserManagementPlanner {
                     2,
                     alter_stmt.username.clone(),
                 );
+                if let Some(ref password) = alter_stmt.password {
+                    node = node.with_password(password.clone());
+                }
                 if let Some(ref role) = alter_stmt.new_role {
                     node = node.with_role(role.clone());
                 }
                 if let Some(locked) = alter_stmt.is_locked {
                     node = node.with_locked(locked);
                 }
+                node = node.with_quota(alter_stmt.quota.clone());
                 PlanNodeEnum::UserManage(UserManageNode::Alter(node))
             }
             Stmt::DropUser(drop_stmt) => {
@@ -106,6 +111,30 @@ impl Planner for UserManagementPlanner {
                 );
                 PlanNodeEnum::UserManage(UserManageNode::DescribeUser(node))
             }
+            Stmt::CreateRole(create_role_stmt) => {
+                let role = crate::core::CustomRole::new(create_role_stmt.role_name.clone())
+                    .with_parents(create_role_stmt.parents.clone());
+                let node = crate::query::planning::plan::core::nodes::CreateRoleNode::new(
+                    10,
+                    role,
+                    create_role_stmt.if_not_exists,
+                );
+                PlanNodeEnum::UserManage(UserManageNode::CreateRole(node))
+            }
+            Stmt::GrantPrivilege(grant_stmt) => {
+                let node = crate::query::planning::plan::core::nodes::GrantPrivilegeNode::new(
+                    11,
+                    grant_stmt.kind.clone(),
+                );
+                PlanNodeEnum::UserManage(UserManageNode::GrantPrivilege(node))
+            }
+            Stmt::ApiKey(api_key_stmt) => {
+                let node = crate::query::planning::plan::core::nodes::ApiKeyNode::new(
+                    12,
+                    api_key_stmt.action.clone(),
+                );
+                PlanNodeEnum::UserManage(UserManageNode::ApiKey(node))
+            }
             _ => {
                 return Err(PlannerError::PlanGenerationFailed(format!(
                     "Unsupported user management operation: {:?}",
@@ -128,6 +157,9 @@ impl Planner for UserManagementPlanner {
                 | Stmt::ChangePassword(_)
                 | Stmt::Grant(_)
                 | Stmt::Revoke(_)
+                | Stmt::CreateRole(_)
+                | Stmt::GrantPrivilege(_)
+                | Stmt::ApiKey(_)
                 | Stmt::ShowUsers(_)
                 | Stmt::ShowRoles(_)
                 | Stmt::DescribeUser(_)
@@ -0,0 +1,85 @@
+//! FOREACH Planner
+//!
+//! Query planning for `FOREACH (x IN list | <update clauses>)`.
+//! Each update clause is planned as a statement of its own and runs once per list element.
+
+use crate::query::parser::ast::stmt::{Ast, ForeachStmt, Stmt};
+use crate::query::planning::plan::core::node_id_generator::next_node_id;
+use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
+use crate::query::planning::plan::core::nodes::{ArgumentNode, ForeachNode};
+use crate::query::planning::plan::{PlanNodeEnum, SubPlan};
+use crate::query::planning::planner::{Planner, PlannerEnum, PlannerError, ValidatedStatement};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// FOREACH planner
+/// Produces a `Foreach` node over an argument that a preceding pipe replaces.
+#[derive(Debug, Clone)]
+pub struct ForeachPlanner;
+
+impl ForeachPlanner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn extract_foreach_stmt(&self, stmt: &Stmt) -> Result<ForeachStmt, PlannerError> {
+        match stmt {
+            Stmt::Foreach(foreach_stmt) => Ok(foreach_stmt.clone()),
+            _ => Err(PlannerError::PlanGenerationFailed(
+                "statement does not contain the FOREACH".to_string(),
+            )),
+        }
+    }
+}
+
+impl Planner for ForeachPlanner {
+    fn transform(
+        &mut self,
+        validated: &ValidatedStatement,
+        qctx: Arc<QueryContext>,
+    ) -> Result<SubPlan, PlannerError> {
+        let foreach_stmt = self.extract_foreach_stmt(validated.stmt())?;
+        let expr_context = validated.ast.expr_context().clone();
+
+        // The clauses share the expression context, so element values bound there reach them.
+        let mut body = Vec::with_capacity(foreach_stmt.body.len());
+        for clause in &foreach_stmt.body {
+            let clause_validated = ValidatedStatement::new(
+                Arc::new(Ast::new(clause.clone(), expr_context.clone())),
+                validated.validation_info.clone(),
+            );
+            let mut planner = PlannerEnum::from_stmt(&Arc::new(clause.clone()))
+                .ok_or_else(|| PlannerError::NoSuitablePlanner(clause.kind().to_string()))?;
+            let root = planner
+                .transform(&clause_validated, qctx.clone())?
+                .root
+                .ok_or_else(|| {
+                    PlannerError::PlanGenerationFailed(format!(
+                        "FOREACH clause {} has no root node",
+                        clause.kind()
+                    ))
+                })?;
+            body.push(root);
+        }
+
+        let arg_node = ArgumentNode::new(next_node_id(), "foreach_input");
+        let node = ForeachNode::new(
+            arg_node.into_enum(),
+            &foreach_stmt.variable,
+            foreach_stmt.list.clone(),
+            body,
+        );
+
+        Ok(SubPlan::new(Some(PlanNodeEnum::Foreach(node)), None))
+    }
+
+    fn match_planner(&self, stmt: &Stmt) -> bool {
+        matches!(stmt, Stmt::Foreach(_))
+    }
+}
+
+impl Default for ForeachPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
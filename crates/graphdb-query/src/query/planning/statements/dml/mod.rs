@@ -3,6 +3,7 @@
 //! This module contains planners for data modification operations:
 //! - CREATE: Create nodes and edges
 //! - DELETE: Delete vertices, edges, tags, or indexes
+//! - FOREACH: Run update clauses once per list element
 //! - INSERT: Insert vertices or edges
 //! - MERGE: Merge nodes or edges (create if not exists)
 //! - REMOVE: Remove properties or tags
@@ -12,6 +13,7 @@
 pub mod assignment_planner;
 pub mod create_planner;
 pub mod delete_planner;
+pub mod foreach_planner;
 pub mod insert_planner;
 pub mod merge_planner;
 pub mod remove_planner;
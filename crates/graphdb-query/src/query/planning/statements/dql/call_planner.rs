@@ -0,0 +1,168 @@
+//! CALL Statement Planner
+//!
+//! Query planning for processing the CALL statement
+
+use crate::core::{Expression, YieldColumn};
+use crate::query::parser::ast::stmt::{CallStmt, OrderDirection, Stmt, YieldClause};
+use crate::query::planning::plan::core::{
+    node_id_generator::next_node_id,
+    nodes::{FilterNode, LimitNode, ProcedureCallNode, ProjectNode, SortItem, SortNode},
+};
+use crate::query::planning::plan::{PlanNodeEnum, SubPlan};
+use crate::query::planning::planner::{Planner, PlannerError, ValidatedStatement};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// CALL Statement Planner
+/// Produces a `ProcedureCall` leaf followed by the YIELD projection and its modifiers.
+#[derive(Debug, Clone)]
+pub struct CallPlanner;
+
+impl CallPlanner {
+    /// Create a new CALL planner.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Extract the CallStmt from the Stmt.
+    fn extract_call_stmt(&self, stmt: &Stmt) -> Result<CallStmt, PlannerError> {
+        match stmt {
+            Stmt::Call(call_stmt) => Ok(call_stmt.clone()),
+            _ => Err(PlannerError::PlanGenerationFailed(
+                "statement does not contain the CALL".to_string(),
+            )),
+        }
+    }
+
+    /// Procedure columns referenced by YIELD, in order
+    fn yielded_columns(yield_clause: &YieldClause) -> Vec<String> {
+        yield_clause
+            .items
+            .iter()
+            .filter_map(|item| match item.expression.get_expression() {
+                Some(Expression::Variable(name)) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Stack the YIELD projection, WHERE, ORDER BY, SKIP and LIMIT on top of the call.
+    pub(crate) fn plan_yield(
+        &self,
+        input: PlanNodeEnum,
+        yield_clause: &YieldClause,
+        columns: &[String],
+    ) -> Result<PlanNodeEnum, PlannerError> {
+        let mut current_node = input;
+
+        let yield_columns: Vec<YieldColumn> = yield_clause
+            .items
+            .iter()
+            .zip(columns)
+            .map(|(item, column)| YieldColumn {
+                expression: item.expression.clone(),
+                alias: item.alias.clone().unwrap_or_else(|| column.clone()),
+                is_matched: false,
+            })
+            .collect();
+        let project_node = ProjectNode::new(current_node, yield_columns).map_err(|e| {
+            PlannerError::PlanGenerationFailed(format!("Failed to create ProjectNode: {}", e))
+        })?;
+        current_node = PlanNodeEnum::Project(project_node);
+
+        if let Some(where_clause) = &yield_clause.where_clause {
+            let filter_node = FilterNode::new(current_node, where_clause.clone()).map_err(|e| {
+                PlannerError::PlanGenerationFailed(format!("Failed to create FilterNode: {}", e))
+            })?;
+            current_node = PlanNodeEnum::Filter(filter_node);
+        }
+
+        if let Some(order_by) = &yield_clause.order_by {
+            let sort_items: Vec<SortItem> = order_by
+                .items
+                .iter()
+                .map(|item| {
+                    let direction = match item.direction {
+                        OrderDirection::Asc => {
+                            crate::core::types::graph_schema::OrderDirection::Asc
+                        }
+                        OrderDirection::Desc => {
+                            crate::core::types::graph_schema::OrderDirection::Desc
+                        }
+                    };
+                    let expression = item.expression.get_expression().unwrap_or_else(|| {
+                        Expression::Variable(item.expression.to_expression_string())
+                    });
+                    SortItem::new(expression, direction)
+                })
+                .collect();
+            let sort_node = SortNode::new(current_node, sort_items).map_err(|e| {
+                PlannerError::PlanGenerationFailed(format!("Failed to create SortNode: {}", e))
+            })?;
+            current_node = PlanNodeEnum::Sort(sort_node);
+        }
+
+        if yield_clause.skip.is_some() || yield_clause.limit.is_some() {
+            let offset = yield_clause.skip.as_ref().map_or(0, |s| s.count as i64);
+            let count = yield_clause
+                .limit
+                .as_ref()
+                .map_or(i64::MAX, |l| l.count as i64);
+            let limit_node = LimitNode::new(current_node, offset, count).map_err(|e| {
+                PlannerError::PlanGenerationFailed(format!("Failed to create LimitNode: {}", e))
+            })?;
+            current_node = PlanNodeEnum::Limit(limit_node);
+        }
+
+        Ok(current_node)
+    }
+}
+
+impl Planner for CallPlanner {
+    fn transform(
+        &mut self,
+        validated: &ValidatedStatement,
+        qctx: Arc<QueryContext>,
+    ) -> Result<SubPlan, PlannerError> {
+        let call_stmt = self.extract_call_stmt(validated.stmt())?;
+
+        // Inside a pipe the validation info belongs to the whole pipe, so fall back to the session space.
+        let space_name = validated
+            .validation_info
+            .semantic_info
+            .space_name
+            .clone()
+            .or_else(|| qctx.space_name());
+
+        let columns = call_stmt
+            .yield_clause
+            .as_ref()
+            .map(Self::yielded_columns)
+            .unwrap_or_default();
+
+        let call_node = PlanNodeEnum::ProcedureCall(ProcedureCallNode::new(
+            next_node_id(),
+            space_name,
+            call_stmt.procedure.clone(),
+            call_stmt.args.clone(),
+            columns.clone(),
+        ));
+
+        let root = match &call_stmt.yield_clause {
+            Some(yield_clause) => self.plan_yield(call_node.clone(), yield_clause, &columns)?,
+            None => call_node.clone(),
+        };
+
+        Ok(SubPlan::new(Some(root), Some(call_node)))
+    }
+
+    fn match_planner(&self, stmt: &Stmt) -> bool {
+        matches!(stmt, Stmt::Call(_))
+    }
+}
+
+impl Default for CallPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
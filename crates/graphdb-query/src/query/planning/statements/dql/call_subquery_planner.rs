@@ -0,0 +1,80 @@
+//! CALL Subquery Planner
+//!
+//! Query planning for `CALL { ... } [IN TRANSACTIONS ...]`.
+//! The body is planned as a statement of its own and runs once per input row.
+
+use crate::query::parser::ast::stmt::{Ast, CallSubqueryStmt, Stmt};
+use crate::query::planning::plan::core::node_id_generator::next_node_id;
+use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
+use crate::query::planning::plan::core::nodes::{ArgumentNode, CallSubqueryNode};
+use crate::query::planning::plan::{PlanNodeEnum, SubPlan};
+use crate::query::planning::planner::{Planner, PlannerEnum, PlannerError, ValidatedStatement};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// CALL subquery planner
+/// Produces a `CallSubquery` node over an argument that a preceding pipe replaces.
+#[derive(Debug, Clone)]
+pub struct CallSubqueryPlanner;
+
+impl CallSubqueryPlanner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn extract_call_subquery_stmt(&self, stmt: &Stmt) -> Result<CallSubqueryStmt, PlannerError> {
+        match stmt {
+            Stmt::CallSubquery(call_stmt) => Ok(call_stmt.clone()),
+            _ => Err(PlannerError::PlanGenerationFailed(
+                "statement does not contain the CALL subquery".to_string(),
+            )),
+        }
+    }
+}
+
+impl Planner for CallSubqueryPlanner {
+    fn transform(
+        &mut self,
+        validated: &ValidatedStatement,
+        qctx: Arc<QueryContext>,
+    ) -> Result<SubPlan, PlannerError> {
+        let call_stmt = self.extract_call_subquery_stmt(validated.stmt())?;
+        let expr_context = validated.ast.expr_context().clone();
+
+        // The body shares the expression context, so row values bound there reach it.
+        let body_validated = ValidatedStatement::new(
+            Arc::new(Ast::new((*call_stmt.body).clone(), expr_context.clone())),
+            validated.validation_info.clone(),
+        );
+        let mut body_planner = PlannerEnum::from_stmt(&Arc::new((*call_stmt.body).clone()))
+            .ok_or_else(|| PlannerError::NoSuitablePlanner("CALL subquery body".to_string()))?;
+        let body = body_planner
+            .transform(&body_validated, qctx)?
+            .root
+            .ok_or_else(|| {
+                PlannerError::PlanGenerationFailed(
+                    "CALL subquery body has no root node".to_string(),
+                )
+            })?;
+
+        let arg_node = ArgumentNode::new(next_node_id(), "call_input");
+        let node = CallSubqueryNode::new(
+            arg_node.into_enum(),
+            body,
+            call_stmt.in_transactions,
+            expr_context,
+        );
+
+        Ok(SubPlan::new(Some(PlanNodeEnum::CallSubquery(node)), None))
+    }
+
+    fn match_planner(&self, stmt: &Stmt) -> bool {
+        matches!(stmt, Stmt::CallSubquery(_))
+    }
+}
+
+impl Default for CallSubqueryPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -160,7 +160,8 @@ impl GroupByPlanner {
             | Expression::TagProperty { .. }
             | Expression::EdgeProperty { .. }
             | Expression::Parameter(_)
-            | Expression::Vector(_) => {}
+            | Expression::Vector(_)
+            | Expression::PatternSubquery(_) => {}
         }
     }
 }
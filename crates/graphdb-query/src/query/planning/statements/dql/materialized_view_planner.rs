@@ -0,0 +1,91 @@
+//! MATCH VIEW Statement Planner
+//!
+//! Query planning for reading a materialized view with `MATCH VIEW name [YIELD ...]`
+
+use crate::query::parser::ast::stmt::{MaterializedViewAction, Stmt};
+use crate::query::planning::plan::core::{
+    node_id_generator::next_node_id, nodes::MaterializedViewScanNode,
+};
+use crate::query::planning::plan::{PlanNodeEnum, SubPlan};
+use crate::query::planning::planner::{Planner, PlannerError, ValidatedStatement};
+use crate::query::planning::statements::dql::call_planner::CallPlanner;
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// MATCH VIEW Statement Planner
+/// Produces a `MaterializedViewScan` leaf followed by the YIELD projection and its modifiers.
+#[derive(Debug, Clone)]
+pub struct MaterializedViewPlanner;
+
+impl MaterializedViewPlanner {
+    /// Create a new MATCH VIEW planner.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Planner for MaterializedViewPlanner {
+    fn transform(
+        &mut self,
+        validated: &ValidatedStatement,
+        qctx: Arc<QueryContext>,
+    ) -> Result<SubPlan, PlannerError> {
+        let (name, yield_clause) = match validated.stmt() {
+            Stmt::MaterializedView(view_stmt) => match &view_stmt.action {
+                MaterializedViewAction::Scan { name, yield_clause } => (name, yield_clause),
+                _ => {
+                    return Err(PlannerError::PlanGenerationFailed(
+                        "view definitions are not planned".to_string(),
+                    ))
+                }
+            },
+            _ => {
+                return Err(PlannerError::PlanGenerationFailed(
+                    "statement does not contain the MATCH VIEW".to_string(),
+                ))
+            }
+        };
+
+        let space_id = qctx.space_id().ok_or_else(|| {
+            PlannerError::PlanGenerationFailed("No image space selected".to_string())
+        })?;
+
+        let scan_node = PlanNodeEnum::MaterializedViewScan(MaterializedViewScanNode::new(
+            next_node_id(),
+            space_id,
+            name.clone(),
+        ));
+
+        let root = match yield_clause {
+            Some(yield_clause) => {
+                let columns: Vec<String> = yield_clause
+                    .items
+                    .iter()
+                    .map(|item| {
+                        item.alias
+                            .clone()
+                            .unwrap_or_else(|| item.expression.to_expression_string())
+                    })
+                    .collect();
+                CallPlanner::new().plan_yield(scan_node.clone(), yield_clause, &columns)?
+            }
+            None => scan_node.clone(),
+        };
+
+        Ok(SubPlan::new(Some(root), Some(scan_node)))
+    }
+
+    fn match_planner(&self, stmt: &Stmt) -> bool {
+        matches!(
+            stmt,
+            Stmt::MaterializedView(view_stmt)
+                if matches!(view_stmt.action, MaterializedViewAction::Scan { .. })
+        )
+    }
+}
+
+impl Default for MaterializedViewPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
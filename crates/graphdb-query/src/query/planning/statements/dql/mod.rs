@@ -21,11 +21,14 @@
 //!
 //! ## Supported Operations
 //!
+//! - CALL: Invoke a registered procedure and project its YIELD columns
+//! - CALL { }: Run a subquery once per input row, optionally in batched transactions
 //! - FETCH EDGES: Fetch edges by source/destination/rank
 //! - FETCH VERTICES: Fetch vertices by ID
 //! - GO: Nebula-style traversal queries
 //! - GROUP BY: Aggregation queries
 //! - LOOKUP: Index-based vertex/edge lookup
+//! - MATCH VIEW: Read the stored rows of a materialized view
 //! - PATH: Path finding queries (shortest path, all paths)
 //! - RETURN: Return results (standalone statement)
 //! - SET OPERATION: Union, Intersect, Minus operations
@@ -44,6 +47,8 @@
 //! The `composite_index_analyzer` module provides optimal index selection for LOOKUP queries
 //! with multiple conditions, supporting prefix matching and range scans.
 
+pub mod call_planner;
+pub mod call_subquery_planner;
 pub mod composite_index_analyzer;
 pub mod explain_planner;
 pub mod fetch_edges_planner;
@@ -51,6 +56,7 @@ pub mod fetch_vertices_planner;
 pub mod go_planner;
 pub mod group_by_planner;
 pub mod lookup_planner;
+pub mod materialized_view_planner;
 pub mod path_planner;
 pub mod pipe_planner;
 pub mod pipe_variable_resolver;
@@ -135,6 +135,22 @@ fn replace_argument_node(plan: PlanNodeEnum, replacement: PlanNodeEnum) -> PlanN
 
             PlanNodeEnum::Unwind(unwind)
         }
+        PlanNodeEnum::CallSubquery(mut call) => {
+            let input = call.input().clone();
+            let new_input = replace_argument_node(input, replacement.clone());
+            call.set_input(new_input);
+            if call.in_transactions().is_none() {
+                call.set_col_names(replacement.col_names().to_vec());
+            }
+            PlanNodeEnum::CallSubquery(call)
+        }
+        PlanNodeEnum::Foreach(mut foreach) => {
+            let input = foreach.input().clone();
+            let new_input = replace_argument_node(input, replacement.clone());
+            foreach.set_input(new_input);
+            foreach.set_col_names(replacement.col_names().to_vec());
+            PlanNodeEnum::Foreach(foreach)
+        }
         PlanNodeEnum::DeleteVertices(delete_vertices) => {
             let info = delete_vertices.info().clone();
             let node = PipeDeleteVerticesNode::new(next_node_id(), info, replacement);
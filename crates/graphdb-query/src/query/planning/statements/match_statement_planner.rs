@@ -3,6 +3,7 @@
 //! Implement the StatementPlanner interface to handle the complete planning of MATCH queries.
 //! It integrates the following functions:
 //!   - Node and edge pattern matching (supports multiple paths)
+//!   - Multiway join candidates for cyclic patterns
 //!   - WHERE condition filtering
 //!   - RETURN Projection
 //!   - ORDER BY: Sorting
@@ -11,8 +12,10 @@
 
 use crate::core::types::ContextualExpression;
 use crate::query::metadata::{IndexMetadata, MetadataContext};
-use crate::query::parser::ast::pattern::{PathElement, Pattern, RepetitionType};
-use crate::query::parser::ast::Stmt;
+use crate::query::parser::ast::pattern::{
+    EdgePattern, NodePattern, PathElement, PathPattern, Pattern, PatternUtils, RepetitionType,
+};
+use crate::query::parser::ast::{EdgeDirection, QueryHints, Stmt};
 use crate::query::planning::plan::core::nodes::access::index_scan::{
     IndexLimit, IndexScanNode, ScanType,
 };
@@ -20,7 +23,8 @@ use crate::query::planning::plan::core::nodes::base::plan_node_traits::PlanNode;
 use crate::query::planning::plan::core::nodes::operation::filter_node::FilterNode;
 use crate::query::planning::plan::core::nodes::ExpandAllNode;
 use crate::query::planning::plan::core::nodes::{
-    ArgumentNode, LeftJoinNode, LoopNode, ScanVerticesNode, UnionNode,
+    ArgumentNode, JoinRelation, LeftJoinNode, LoopNode, MultiwayJoinNode, ScanVerticesNode,
+    UnionNode,
 };
 use crate::query::planning::plan::SubPlan;
 use crate::query::planning::planner::{Planner, PlannerError, ValidatedStatement};
@@ -51,6 +55,7 @@ pub struct MatchStatementPlanner {
     config: MatchPlannerConfig,
     expr_context: Option<Arc<ExpressionAnalysisContext>>,
     metadata_context: Option<MetadataContext>,
+    hints: QueryHints,
     where_planner: WhereClausePlanner,
     return_planner: ReturnClausePlanner,
     order_by_planner: OrderByClausePlanner,
@@ -86,6 +91,7 @@ impl MatchStatementPlanner {
             config: MatchPlannerConfig::default(),
             expr_context: None,
             metadata_context: None,
+            hints: QueryHints::default(),
             where_planner: WhereClausePlanner::new(),
             return_planner: ReturnClausePlanner::new(),
             order_by_planner: OrderByClausePlanner::new(),
@@ -98,6 +104,7 @@ impl MatchStatementPlanner {
             config,
             expr_context: None,
             metadata_context: None,
+            hints: QueryHints::default(),
             where_planner: WhereClausePlanner::new(),
             return_planner: ReturnClausePlanner::new(),
             order_by_planner: OrderByClausePlanner::new(),
@@ -124,6 +131,7 @@ impl Planner for MatchStatementPlanner {
 
         // Set expr_context
         self.expr_context = Some(validated.ast.expr_context().clone());
+        self.hints = validated.ast.hints().clone();
 
         // Check the optimization suggestions.
         for hint in &validation_info.optimization_hints {
@@ -151,6 +159,7 @@ impl Planner for MatchStatementPlanner {
 
         // Set expr_context
         self.expr_context = Some(validated.ast.expr_context().clone());
+        self.hints = validated.ast.hints().clone();
 
         // Check the optimization suggestions.
         for hint in &validation_info.optimization_hints {
@@ -214,10 +223,11 @@ impl MatchStatementPlanner {
                     log::debug!("Quoted tags: {:?}", referenced_tags);
                 }
 
-                let mut plan = if match_stmt.patterns.is_empty() {
+                let patterns = self.order_patterns(&match_stmt.patterns);
+                let mut plan = if patterns.is_empty() {
                     self.plan_node_pattern(space_id, space_name)?
                 } else {
-                    let first_pattern = &match_stmt.patterns[0];
+                    let first_pattern = patterns[0];
                     self.plan_path_pattern(
                         first_pattern,
                         space_id,
@@ -227,7 +237,7 @@ impl MatchStatementPlanner {
                     )?
                 };
 
-                for pattern in match_stmt.patterns.iter().skip(1) {
+                for pattern in patterns.iter().skip(1) {
                     let path_plan = self.plan_path_pattern(
                         pattern,
                         space_id,
@@ -238,6 +248,9 @@ impl MatchStatementPlanner {
                     plan = self.cross_join_plans(plan, path_plan)?;
                 }
 
+                plan =
+                    self.offer_multiway_join(&match_stmt.patterns, space_id, space_name, plan)?;
+
                 if self.has_where_clause(stmt) {
                     plan = self
                         .where_planner
@@ -276,6 +289,90 @@ impl MatchStatementPlanner {
         }
     }
 
+    /// Order the comma-separated patterns of a MATCH for joining.
+    ///
+    /// Without a JOIN_ORDER hint the written order is kept. With one, the
+    /// pattern binding each listed variable is moved forward in the listed
+    /// order; patterns binding none of them follow in their written order.
+    fn order_patterns<'p>(&self, patterns: &'p [Pattern]) -> Vec<&'p Pattern> {
+        let Some(join_order) = self.hints.join_order() else {
+            return patterns.iter().collect();
+        };
+
+        let mut remaining: Vec<&Pattern> = patterns.iter().collect();
+        let mut ordered = Vec::with_capacity(patterns.len());
+        for variable in join_order {
+            if let Some(pos) = remaining
+                .iter()
+                .position(|pattern| PatternUtils::find_variables(pattern).contains(variable))
+            {
+                ordered.push(remaining.remove(pos));
+            }
+        }
+        ordered.extend(remaining);
+        ordered
+    }
+
+    /// Reverse a simple path when START_FROM or EXPAND_DIRECTION asks for it.
+    ///
+    /// Only paths made of plain nodes and edges are reversed, and only when the
+    /// node that loses its place as the start is named, so its filters can be
+    /// applied to the expanded column instead. START_FROM on the first node
+    /// keeps the path as written; EXPAND_DIRECTION reverses it only when every
+    /// hinted edge prefers the other side.
+    fn reverse_path_for_hints(&self, path: &PathPattern) -> Option<PathPattern> {
+        let (Some(PathElement::Node(first)), Some(PathElement::Node(last))) =
+            (path.elements.first(), path.elements.last())
+        else {
+            return None;
+        };
+        if path.elements.len() < 3
+            || first.variable.is_none()
+            || !path
+                .elements
+                .iter()
+                .all(|element| matches!(element, PathElement::Node(_) | PathElement::Edge(_)))
+        {
+            return None;
+        }
+
+        let reverse = match self.hints.start_from() {
+            Some(start) if first.variable.as_deref() == Some(start) => false,
+            Some(start) if last.variable.as_deref() == Some(start) => true,
+            _ => {
+                let mut preferences = path.elements.iter().filter_map(|element| match element {
+                    PathElement::Edge(edge) if edge.direction != EdgeDirection::Both => edge
+                        .variable
+                        .iter()
+                        .chain(edge.edge_types.iter())
+                        .find_map(|name| self.hints.expand_direction(name))
+                        .map(|direction| direction != edge.direction),
+                    _ => None,
+                });
+                let first_preference = preferences.next();
+                first_preference == Some(true) && preferences.all(|reverse| reverse)
+            }
+        };
+        if !reverse {
+            return None;
+        }
+
+        let elements = path
+            .elements
+            .iter()
+            .rev()
+            .map(|element| match element {
+                PathElement::Edge(edge) => {
+                    let mut edge = edge.clone();
+                    edge.direction = edge.direction.reverse();
+                    PathElement::Edge(edge)
+                }
+                other => other.clone(),
+            })
+            .collect();
+        Some(PathPattern::new(elements, path.span))
+    }
+
     /// Planning Path Mode
     fn plan_path_pattern(
         &self,
@@ -287,6 +384,9 @@ impl MatchStatementPlanner {
     ) -> Result<SubPlan, PlannerError> {
         match pattern {
             Pattern::Path(path) => {
+                let reversed = self.reverse_path_for_hints(path);
+                let path = reversed.as_ref().unwrap_or(path);
+
                 if path.elements.is_empty() {
                     return Err(PlannerError::PlanGenerationFailed(
                         "empty path model".to_string(),
@@ -458,6 +558,14 @@ impl MatchStatementPlanner {
                     }
                 }
 
+                // The node written first is now reached by expansion; keep its conditions
+                if reversed.is_some() {
+                    if let Some(PathElement::Node(node)) = path.elements.last() {
+                        let var_name = node.variable.clone().unwrap_or_else(|| "n".to_string());
+                        plan = self.apply_node_filters(node, &var_name, plan)?;
+                    }
+                }
+
                 Ok(plan)
             }
             _ => self.plan_pattern(pattern, space_id, space_name, validation_info, qctx),
@@ -486,63 +594,235 @@ impl MatchStatementPlanner {
         let mut scan_node = ScanVerticesNode::new(space_id, space_name);
         scan_node.set_col_names(vec![var_name.clone()]);
         scan_node.set_output_var(var_name.clone());
-        let mut plan = SubPlan::from_root(scan_node.into_enum());
+        let plan = SubPlan::from_root(scan_node.into_enum());
+
+        self.apply_node_filters(node, &var_name, plan)
+    }
+
+    /// Add the label, property and predicate filters of a node pattern on top of `plan`
+    fn apply_node_filters(
+        &self,
+        node: &NodePattern,
+        var_name: &str,
+        mut plan: SubPlan,
+    ) -> Result<SubPlan, PlannerError> {
+        for filter_expr in self.node_filter_expressions(node, var_name) {
+            let root_node = plan.root.as_ref().expect("The root of plan should exist");
+            let filter_node = FilterNode::new(root_node.clone(), filter_expr)
+                .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
+            plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
+        }
+
+        Ok(plan)
+    }
+
+    /// Label, property and predicate conditions of a node pattern
+    fn node_filter_expressions(
+        &self,
+        node: &NodePattern,
+        var_name: &str,
+    ) -> Vec<ContextualExpression> {
+        let mut filters = Vec::new();
 
-        // If there is a label filtering option, please add the filter.
         if !node.labels.is_empty() {
             let expr_ctx = self
                 .expr_context
                 .as_ref()
                 .expect("expr_context should be set");
-            let label_filter =
-                Self::build_label_filter_expression(&node.variable, &node.labels, expr_ctx);
-            let root_node = plan.root.as_ref().expect("The root of plan should exist");
-            let filter_node = FilterNode::new(root_node.clone(), label_filter)
-                .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-            plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
+            filters.push(Self::build_label_filter_expression(
+                &node.variable,
+                &node.labels,
+                expr_ctx,
+            ));
         }
 
-        // If there is attribute filtering, add the filter.
         if let Some(ref props) = node.properties {
             // Convert property map to filter expression
-            let filter_expr = if let Some(ref expr_ctx) = self.expr_context {
-                Self::convert_properties_to_filter(&var_name, props, expr_ctx)
-            } else {
-                None
-            };
+            let filter_expr = self
+                .expr_context
+                .as_ref()
+                .and_then(|expr_ctx| Self::convert_properties_to_filter(var_name, props, expr_ctx));
+            filters.push(filter_expr.unwrap_or_else(|| props.clone()));
+        }
 
-            let filter_expr = match filter_expr {
-                Some(expr) => expr,
-                None => props.clone(),
-            };
+        filters.extend(node.predicates.iter().cloned());
+        filters
+    }
 
-            let filter_node = FilterNode::new(
-                plan.root
-                    .as_ref()
-                    .expect("The root of plan should exist")
-                    .clone(),
-                filter_expr,
-            )
-            .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-            plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
+    /// Property and predicate conditions of an edge pattern
+    fn edge_filter_expressions(
+        &self,
+        edge: &EdgePattern,
+        edge_var: &str,
+    ) -> Vec<ContextualExpression> {
+        let mut filters = Vec::new();
+
+        if let Some(ref props) = edge.properties {
+            // Convert property map to filter expression
+            let filter_expr = self
+                .expr_context
+                .as_ref()
+                .and_then(|expr_ctx| Self::convert_properties_to_filter(edge_var, props, expr_ctx));
+            filters.push(filter_expr.unwrap_or_else(|| props.clone()));
         }
 
-        // If there is predicate filtering, add the filter.
-        if !node.predicates.is_empty() {
-            for pred in &node.predicates {
-                let filter_node = FilterNode::new(
-                    plan.root
-                        .as_ref()
-                        .expect("The root of plan should exist")
-                        .clone(),
-                    pred.clone(),
-                )
-                .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-                plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
+        filters.extend(edge.predicates.iter().cloned());
+        filters
+    }
+
+    /// Offer a multiway join for a cyclic MATCH pattern.
+    ///
+    /// When the patterns close a cycle, `plan` becomes the fallback of a
+    /// multiway join candidate; the cost-based phase decides which one runs.
+    /// Patterns the multiway join cannot express, and JOIN_ORDER or START_FROM
+    /// hints that pin the expansion order, leave `plan` as it is.
+    fn offer_multiway_join(
+        &self,
+        patterns: &[Pattern],
+        space_id: u64,
+        space_name: &str,
+        plan: SubPlan,
+    ) -> Result<SubPlan, PlannerError> {
+        if self.expr_context.is_none()
+            || self.hints.join_order().is_some()
+            || self.hints.start_from().is_some()
+        {
+            return Ok(plan);
+        }
+        let Some(root) = plan.root.as_ref() else {
+            return Ok(plan);
+        };
+        let Some((relations, variables, filters)) = self.cyclic_join_relations(patterns) else {
+            return Ok(plan);
+        };
+
+        log::debug!(
+            "Cyclic pattern over {} variables, offering a multiway join",
+            variables.len()
+        );
+        let node = MultiwayJoinNode::new(
+            space_id,
+            space_name,
+            relations,
+            variables,
+            filters,
+            root.clone(),
+        );
+        Ok(SubPlan::from_root(node.into_enum()))
+    }
+
+    /// Describe the patterns as join relations if they form a connected, cyclic
+    /// pattern graph.
+    ///
+    /// Every node has to be named and every edge has to be a single hop between
+    /// two distinct nodes; an edge with conditions has to be named as well.
+    /// Returns the relations, the node variables in the order they are first
+    /// written, and the node and edge conditions.
+    fn cyclic_join_relations(
+        &self,
+        patterns: &[Pattern],
+    ) -> Option<(Vec<JoinRelation>, Vec<String>, Vec<ContextualExpression>)> {
+        let mut relations = Vec::new();
+        let mut variables: Vec<String> = Vec::new();
+        let mut filters = Vec::new();
+        // Union-find over the node variables: an edge between two variables
+        // that are already connected closes a cycle
+        let mut parents: Vec<usize> = Vec::new();
+        let mut cyclic = false;
+
+        fn find(parents: &mut [usize], mut i: usize) -> usize {
+            while parents[i] != i {
+                parents[i] = parents[parents[i]];
+                i = parents[i];
             }
+            i
         }
 
-        Ok(plan)
+        for pattern in patterns {
+            let Pattern::Path(path) = pattern else {
+                return None;
+            };
+            let mut prev: Option<&str> = None;
+            let mut pending_edge: Option<&EdgePattern> = None;
+            for element in &path.elements {
+                match element {
+                    PathElement::Node(node) => {
+                        let var = node.variable.as_deref()?;
+                        if !variables.iter().any(|v| v == var) {
+                            variables.push(var.to_string());
+                            parents.push(parents.len());
+                        }
+                        filters.extend(self.node_filter_expressions(node, var));
+
+                        if let Some(edge) = pending_edge.take() {
+                            let from = prev?;
+                            if from == var {
+                                return None;
+                            }
+                            let (src, dst) = match edge.direction {
+                                EdgeDirection::In => (var, from),
+                                EdgeDirection::Out | EdgeDirection::Both => (from, var),
+                            };
+                            let alias = edge.variable.clone();
+                            match &alias {
+                                Some(alias) => {
+                                    let taken = variables.iter().any(|v| v == alias)
+                                        || relations.iter().any(|r: &JoinRelation| {
+                                            r.edge_alias.as_deref() == Some(alias.as_str())
+                                        });
+                                    if taken {
+                                        return None;
+                                    }
+                                    filters.extend(self.edge_filter_expressions(edge, alias));
+                                }
+                                None if edge.properties.is_some()
+                                    || !edge.predicates.is_empty() =>
+                                {
+                                    return None;
+                                }
+                                None => {}
+                            }
+
+                            let a = variables.iter().position(|v| v == src)?;
+                            let b = variables.iter().position(|v| v == dst)?;
+                            let (root_a, root_b) = (find(&mut parents, a), find(&mut parents, b));
+                            if root_a == root_b {
+                                cyclic = true;
+                            } else {
+                                parents[root_a] = root_b;
+                            }
+
+                            relations.push(JoinRelation {
+                                src: src.to_string(),
+                                dst: dst.to_string(),
+                                edge_alias: alias,
+                                edge_types: edge.edge_types.clone(),
+                                undirected: edge.direction == EdgeDirection::Both,
+                            });
+                        }
+                        prev = Some(var);
+                    }
+                    PathElement::Edge(edge) => {
+                        if prev.is_none() || pending_edge.is_some() || edge.range.is_some() {
+                            return None;
+                        }
+                        pending_edge = Some(edge);
+                    }
+                    _ => return None,
+                }
+            }
+            if pending_edge.is_some() {
+                return None;
+            }
+        }
+
+        if !cyclic {
+            return None;
+        }
+        // A node variable that no edge reaches would turn the join into a product
+        let root = find(&mut parents, 0);
+        let connected = (1..variables.len()).all(|i| find(&mut parents, i) == root);
+        connected.then_some((relations, variables, filters))
     }
 
     /// Try to create an index scan plan for the given node pattern
@@ -628,49 +908,28 @@ impl MatchStatementPlanner {
             return Ok(None);
         }
 
+        // USE_INDEX picks the index as long as it can serve one of the conditions
+        if let Some(hinted) = self.hints.use_index(var_name) {
+            if tag_metadata.indexes.iter().any(|name| name == hinted)
+                && self.hints.allows_index(var_name, hinted)
+            {
+                if let Some(index_meta) = metadata_ctx.get_index_metadata(hinted) {
+                    if let Some(limit) = Self::index_limit_for(index_meta, &filter_conditions) {
+                        return Ok(Some((index_meta.clone(), vec![limit])));
+                    }
+                }
+            }
+        }
+
         // Find an index that matches one of the filter conditions
-        for index_name in &tag_metadata.indexes {
+        for index_name in tag_metadata
+            .indexes
+            .iter()
+            .filter(|index_name| self.hints.allows_index(var_name, index_name))
+        {
             if let Some(index_meta) = metadata_ctx.get_index_metadata(index_name) {
-                // Check if any filter condition matches the indexed field
-                for (field, op, value) in &filter_conditions {
-                    if &index_meta.field_name == field {
-                        let index_limit = match op.as_str() {
-                            "=" => Some(IndexLimit::equal(field.clone(), value.clone())),
-                            ">" => Some(IndexLimit::range(
-                                field.clone(),
-                                Some(value.clone()) as Option<String>,
-                                None::<String>,
-                                false,
-                                false,
-                            )),
-                            "<" => Some(IndexLimit::range(
-                                field.clone(),
-                                None::<String>,
-                                Some(value.clone()) as Option<String>,
-                                false,
-                                false,
-                            )),
-                            ">=" => Some(IndexLimit::range(
-                                field.clone(),
-                                Some(value.clone()) as Option<String>,
-                                None::<String>,
-                                true,
-                                false,
-                            )),
-                            "<=" => Some(IndexLimit::range(
-                                field.clone(),
-                                None::<String>,
-                                Some(value.clone()) as Option<String>,
-                                false,
-                                true,
-                            )),
-                            _ => None,
-                        };
-
-                        if let Some(limit) = index_limit {
-                            return Ok(Some((index_meta.clone(), vec![limit])));
-                        }
-                    }
+                if let Some(limit) = Self::index_limit_for(index_meta, &filter_conditions) {
+                    return Ok(Some((index_meta.clone(), vec![limit])));
                 }
             }
         }
@@ -678,6 +937,48 @@ impl MatchStatementPlanner {
         Ok(None)
     }
 
+    /// Build the scan limit of the first filter condition on the indexed field
+    fn index_limit_for(
+        index_meta: &IndexMetadata,
+        filter_conditions: &[(String, String, String)],
+    ) -> Option<IndexLimit> {
+        filter_conditions
+            .iter()
+            .filter(|(field, _, _)| &index_meta.field_name == field)
+            .find_map(|(field, op, value)| match op.as_str() {
+                "=" => Some(IndexLimit::equal(field.clone(), value.clone())),
+                ">" => Some(IndexLimit::range(
+                    field.clone(),
+                    Some(value.clone()) as Option<String>,
+                    None::<String>,
+                    false,
+                    false,
+                )),
+                "<" => Some(IndexLimit::range(
+                    field.clone(),
+                    None::<String>,
+                    Some(value.clone()) as Option<String>,
+                    false,
+                    false,
+                )),
+                ">=" => Some(IndexLimit::range(
+                    field.clone(),
+                    Some(value.clone()) as Option<String>,
+                    None::<String>,
+                    true,
+                    false,
+                )),
+                "<=" => Some(IndexLimit::range(
+                    field.clone(),
+                    None::<String>,
+                    Some(value.clone()) as Option<String>,
+                    false,
+                    true,
+                )),
+                _ => None,
+            })
+    }
+
     /// Extract filter conditions from node properties and predicates
     fn extract_filter_conditions(
         &self,
@@ -824,148 +1125,14 @@ impl MatchStatementPlanner {
 
         let mut plan = SubPlan::from_root(expand_node.into_enum());
 
-        // If there is attribute filtering, add the filter.
-        if let Some(ref props) = edge.properties {
-            // Convert property map to filter expression
-            let filter_expr = if let Some(ref expr_ctx) = self.expr_context {
-                Self::convert_properties_to_filter(&edge_var, props, expr_ctx)
-            } else {
-                None
-            };
-
-            let filter_expr = match filter_expr {
-                Some(expr) => expr,
-                None => props.clone(),
-            };
-
-            let filter_node = FilterNode::new(
-                plan.root
-                    .as_ref()
-                    .expect("The root of plan should exist")
-                    .clone(),
-                filter_expr,
-            )
-            .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-            plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
-        }
-
-        // If there is predicate filtering, add the filter.
-        if !edge.predicates.is_empty() {
-            for pred in &edge.predicates {
-                let filter_node = FilterNode::new(
-                    plan.root
-                        .as_ref()
-                        .expect("The root of plan should exist")
-                        .clone(),
-                    pred.clone(),
-                )
+        let edge_var = edge.variable.clone().unwrap_or_else(|| "e".to_string());
+        for filter_expr in self.edge_filter_expressions(edge, &edge_var) {
+            let root_node = plan.root.as_ref().expect("The root of plan should exist");
+            let filter_node = FilterNode::new(root_node.clone(), filter_expr)
                 .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-                plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
-            }
-        }
-
-        // For edge-only patterns (no source node), the ExpandAllNode will use its fallback
-        // to scan all vertices from the space. This is handled in ExpandAllExecutor::do_execute
-        // when input_nodes is empty and input_executor is None.
-        Ok(plan)
-    }
-
-    /// Planning mode edge with input variable
-    ///
-    /// This method creates an edge expansion plan that takes the source node as input.
-    /// The ExpandAll node will use the input variable to get the source vertices from ExecutionContext.
-    ///
-    /// # Arguments
-    /// * `edge` - The edge pattern to plan
-    /// * `space_id` - The space ID
-    /// * `input_var` - The variable name for the source node
-    /// * `dst_var` - Optional variable name for the destination node. If provided, the dst column will use this name.
-    fn plan_pattern_edge_with_input(
-        &self,
-        edge: &crate::query::parser::ast::pattern::EdgePattern,
-        space_id: u64,
-        input_var: &str,
-        dst_var: Option<&str>,
-    ) -> Result<SubPlan, PlannerError> {
-        let direction = match edge.direction {
-            crate::query::parser::ast::types::EdgeDirection::Out => "out",
-            crate::query::parser::ast::types::EdgeDirection::In => "in",
-            crate::query::parser::ast::types::EdgeDirection::Both => "both",
-        };
-
-        let edge_types = match &edge.edge_types {
-            types if !types.is_empty() => types.clone(),
-            _ => vec![],
-        };
-
-        let mut expand_node = ExpandAllNode::new(space_id, edge_types, direction);
-
-        if edge.edge_types.is_empty() {
-            expand_node.set_any_edge_type(true);
-        }
-
-        // Set step limit to 1 for single edge pattern like (n)-[e]->(m)
-        expand_node.set_step_limit(1);
-
-        // Set the input variable so ExpandAll can get source vertices from ExecutionContext
-        expand_node.set_input_var(input_var.to_string());
-
-        // Set the column names to match ExpandAll's output format: [input_var, edge_var, dst_var]
-        // Use input_var as the first column name so subsequent operations can reference the source node
-        // Use edge variable name if provided, otherwise use "edge" for the edge column
-        // Use dst_var if provided, otherwise use "dst" for the destination column
-        // This allows subsequent operations to reference both source and destination nodes by their variable names
-        let src_col_name = input_var.to_string();
-        let edge_col_name = edge.variable.clone().unwrap_or_else(|| "edge".to_string());
-        let dst_col_name = dst_var.unwrap_or("dst").to_string();
-        expand_node.set_col_names(vec![src_col_name, edge_col_name, dst_col_name]);
-
-        // Disable empty paths for MATCH queries - we only want actual edge expansions
-        expand_node.set_include_empty_paths(false);
-
-        let mut plan = SubPlan::from_root(expand_node.into_enum());
-
-        // If there is attribute filtering, add the filter.
-        if let Some(ref props) = edge.properties {
-            let edge_var = edge.variable.clone().unwrap_or_else(|| "e".to_string());
-            // Convert property map to filter expression
-            let filter_expr = if let Some(ref expr_ctx) = self.expr_context {
-                Self::convert_properties_to_filter(&edge_var, props, expr_ctx)
-            } else {
-                None
-            };
-
-            let filter_expr = match filter_expr {
-                Some(expr) => expr,
-                None => props.clone(),
-            };
-
-            let filter_node = FilterNode::new(
-                plan.root
-                    .as_ref()
-                    .expect("The root of plan should exist")
-                    .clone(),
-                filter_expr,
-            )
-            .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
             plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
         }
 
-        // If there is predicate filtering, add the filter.
-        if !edge.predicates.is_empty() {
-            for pred in &edge.predicates {
-                let filter_node = FilterNode::new(
-                    plan.root
-                        .as_ref()
-                        .expect("The root of plan should exist")
-                        .clone(),
-                    pred.clone(),
-                )
-                .map_err(|e| PlannerError::PlanGenerationFailed(e.to_string()))?;
-                plan = SubPlan::new(Some(filter_node.into_enum()), plan.tail);
-            }
-        }
-
         Ok(plan)
     }
 
@@ -133,6 +133,7 @@ mod tests {
             space_name: None,
             query: String::new(),
             parameters: HashMap::new(),
+            role: None,
         });
         Arc::new(QueryContext::new(rctx))
     }
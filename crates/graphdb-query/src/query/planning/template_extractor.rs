@@ -284,6 +284,9 @@ impl ParameterizingTransformer {
                     .collect();
                 Expression::PathBuild(new_exprs)
             }
+            Expression::PatternSubquery(subquery) => Expression::PatternSubquery(Box::new(
+                subquery.map_expressions(|e| self.transform_with_params(e, result)),
+            )),
             // The following types do not contain literals that require parameterization.
             Expression::Variable(_)
             | Expression::Label(_)
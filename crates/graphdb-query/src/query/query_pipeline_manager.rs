@@ -26,19 +26,28 @@
 use crate::core::error::{DBError, DBResult, QueryError};
 use crate::core::metadata::index_manager::IndexMetadataManager;
 use crate::core::metadata::SchemaManager;
+use crate::core::types::{MaterializedViewData, MaterializedViewInfo, SpaceInfo};
 use crate::core::{
-    ErrorInfo, ErrorType, MetricType, QueryMetrics, QueryPhase, QueryProfile, StatsManager,
+    DataSet, EffectivePrivileges, ErrorInfo, ErrorType, MetricType, NullType, QueryMetrics,
+    QueryPhase, QueryProfile, StatsManager, Value,
 };
-use crate::query::executor::base::{BaseExecutor, ExecutionResult, Executor};
+use crate::query::cache::{ViewChange, ViewDefinition, HIDDEN_COUNT_COLUMN};
+use crate::query::executor::base::{BaseExecutor, ExecutionContext, ExecutionResult, Executor};
 use crate::query::executor::explain::{ExplainExecutor, ExplainMode, ProfileExecutor};
 use crate::query::executor::factory::ExecutorFactory;
 use crate::query::executor::utils::object_pool::{ObjectPoolConfig, ThreadSafeExecutorPool};
 use crate::query::metadata::MetadataContext;
 use crate::query::optimizer::OptimizerEngine;
-use crate::query::parser::ast::stmt::{ExplainStmt, ProfileStmt};
+use crate::query::parser::ast::stmt::{
+    ExplainStmt, MaterializedViewAction, MaterializedViewStmt, ProfileStmt, Stmt,
+};
+use crate::query::parser::ast::QueryHints;
 use crate::query::parser::Parser;
+use crate::query::planning::plan::core::next_node_id;
+use crate::query::planning::plan::{ExecutionPlan, MaterializedViewScanNode, PlanNodeEnum};
 use crate::query::planning::{ParameterizedQueryHandler, PlanCacheConfig, QueryPlanCache};
 use crate::query::validator::context::ExpressionAnalysisContext;
+use crate::query::validator::helpers::PrivilegeChecker;
 use crate::query::validator::{ValidatedStatement, ValidationInfo};
 use crate::query::QueryContext;
 use crate::query::QueryRequestContext;
@@ -226,6 +235,21 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         self
     }
 
+    /// Set the transaction control used by CALL { } IN TRANSACTIONS
+    pub fn set_batch_transaction_control(
+        &mut self,
+        control: crate::query::executor::control_flow::SharedBatchTransactionControl,
+    ) {
+        self.executor_factory.set_batch_transaction_control(control);
+    }
+
+    /// Procedure registry consulted by CALL statements
+    pub fn procedure_registry(
+        &self,
+    ) -> crate::query::executor::expression::functions::SharedProcedureRegistry {
+        self.executor_factory.procedure_registry()
+    }
+
     pub fn execute_query(&mut self, query_text: &str) -> DBResult<ExecutionResult> {
         self.execute_query_with_space(query_text, None)
     }
@@ -246,11 +270,19 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
 
         let query_context = Arc::new(query_context);
 
+        // Queries defining a materialized view are answered from its rows
+        if let Some(result) = self.answer_from_view(query_text, &query_context)? {
+            return Ok(result);
+        }
+
         // 2. Check the query plan cache.
         if let Some(cached_plan) = self.plan_cache.get(query_text) {
             log::debug!("Query plan cache hit");
             let execute_start = Instant::now();
-            let result = self.execute_plan(query_context, cached_plan.plan.clone())?;
+            let maintenance = self.begin_view_maintenance(query_text, None, &query_context);
+            let result = self.execute_plan(query_context, cached_plan.plan.clone());
+            self.finish_view_maintenance(maintenance);
+            let result = result?;
             let execution_time_ms = execute_start.elapsed().as_millis() as f64;
             self.plan_cache
                 .record_execution(query_text, execution_time_ms);
@@ -270,10 +302,13 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         // Check for EXPLAIN/PROFILE statements and route accordingly
         match validated.ast.stmt() {
             crate::query::parser::ast::Stmt::Explain(explain_stmt) => {
-                return self.execute_explain(explain_stmt, query_context);
+                return self.execute_explain(explain_stmt, validated.ast.hints(), query_context);
             }
             crate::query::parser::ast::Stmt::Profile(profile_stmt) => {
-                return self.execute_profile(profile_stmt, query_context);
+                return self.execute_profile(profile_stmt, validated.ast.hints(), query_context);
+            }
+            crate::query::parser::ast::Stmt::MaterializedView(view_stmt) => {
+                return self.execute_materialized_view(view_stmt, &validated, query_context);
             }
             _ => {}
         }
@@ -286,7 +321,13 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
 
         // 7. Execution Plan
         let execute_start = Instant::now();
-        let result = self.execute_plan(query_context, optimized_plan.clone())?;
+        let space_id = query_context.space_id();
+        let maintenance =
+            self.begin_view_maintenance(query_text, Some(validated.ast.stmt()), &query_context);
+        let result = self.execute_plan(query_context, optimized_plan.clone());
+        self.finish_view_maintenance(maintenance);
+        let result = result?;
+        self.invalidate_dependent_plans(validated.ast.stmt(), space_id);
         let execution_time_ms = execute_start.elapsed().as_millis() as f64;
 
         // 8. Caching of query plans
@@ -325,6 +366,11 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
 
         let query_context = Arc::new(query_context);
 
+        // Queries defining a materialized view are answered from its rows
+        if let Some(result) = self.answer_from_view(query_text, &query_context)? {
+            return Ok(result);
+        }
+
         // 2. Analyze the query
         let parser_result = self.parse_into_context(query_text)?;
 
@@ -338,10 +384,13 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         // Check for EXPLAIN/PROFILE statements and route accordingly
         match validated.ast.stmt() {
             crate::query::parser::ast::Stmt::Explain(explain_stmt) => {
-                return self.execute_explain(explain_stmt, query_context);
+                return self.execute_explain(explain_stmt, validated.ast.hints(), query_context);
             }
             crate::query::parser::ast::Stmt::Profile(profile_stmt) => {
-                return self.execute_profile(profile_stmt, query_context);
+                return self.execute_profile(profile_stmt, validated.ast.hints(), query_context);
+            }
+            crate::query::parser::ast::Stmt::MaterializedView(view_stmt) => {
+                return self.execute_materialized_view(view_stmt, &validated, query_context);
             }
             _ => {}
         }
@@ -353,7 +402,159 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         let optimized_plan = self.optimize_execution_plan(execution_plan)?;
 
         // 6. Execution of the plan
-        self.execute_plan(query_context, optimized_plan)
+        let space_id = query_context.space_id();
+        let maintenance =
+            self.begin_view_maintenance(query_text, Some(validated.ast.stmt()), &query_context);
+        let result = self.execute_plan(query_context, optimized_plan);
+        self.finish_view_maintenance(maintenance);
+        if result.is_ok() {
+            self.invalidate_dependent_plans(validated.ast.stmt(), space_id);
+        }
+        result
+    }
+
+    /// Prepare a parameterized query without executing it.
+    ///
+    /// The query is parsed, validated, planned and optimized once with its `$name`
+    /// placeholders intact, and the plan is stored in the plan cache keyed by the
+    /// query text and space. Later calls to [`Self::execute_prepared`] with the same
+    /// text in the same space reuse the cached plan and only bind new values, until
+    /// a schema change to a tag or edge type it reads evicts it.
+    ///
+    /// # Returns
+    /// The placeholders found in the query, in order of appearance
+    pub fn prepare(
+        &mut self,
+        query_text: &str,
+        space_info: Option<crate::core::types::SpaceInfo>,
+    ) -> DBResult<Vec<crate::query::planning::ParamPosition>> {
+        let space_id = space_info.as_ref().map(|space| space.space_id);
+        if self.prepared_plan(query_text, space_id).is_none() {
+            let rctx = Arc::new(QueryRequestContext::new(query_text.to_string()));
+            self.plan_prepared(query_text, rctx, space_info)?;
+        }
+        Ok(self.param_handler.extract_params(query_text))
+    }
+
+    /// Execute a prepared query with the parameters carried by `rctx`.
+    ///
+    /// Uses the cached plan when one exists, otherwise plans the query first.
+    /// Unlike [`Self::execute_query_with_space`], INSERT statements are cached as
+    /// well, since their values come from the bound parameters rather than literals.
+    /// The values are bound to this execution only, never to the cached plan, so
+    /// concurrent executions of the same plan each see their own.
+    pub fn execute_prepared(
+        &mut self,
+        query_text: &str,
+        rctx: Arc<QueryRequestContext>,
+        space_info: Option<crate::core::types::SpaceInfo>,
+    ) -> DBResult<ExecutionResult> {
+        let space_id = space_info.as_ref().map(|space| space.space_id);
+
+        let plan = match self.prepared_plan(query_text, space_id) {
+            Some(prepared) => {
+                log::debug!("Prepared plan cache hit");
+                // The cached plan may have been validated for another user; only
+                // users with custom roles need the statement re-parsed to check it
+                let mut query_context = QueryContext::new(rctx.clone());
+                if let Some(ref space) = space_info {
+                    query_context.set_space_info(space.clone());
+                }
+                if self.effective_privileges(&query_context).is_some() {
+                    let parser_result = self.parse_into_context(query_text)?;
+                    self.check_privileges(&parser_result.ast, &query_context)?;
+                }
+                prepared
+            }
+            None => self.plan_prepared(query_text, rctx.clone(), space_info.clone())?,
+        };
+
+        let mut query_context = QueryContext::new(rctx);
+        if let Some(ref space) = space_info {
+            query_context.set_space_info(space.clone());
+        }
+
+        let execute_start = Instant::now();
+        let maintenance = self.begin_view_maintenance(query_text, None, &query_context);
+        let result = self.execute_plan(Arc::new(query_context), plan);
+        self.finish_view_maintenance(maintenance);
+
+        self.plan_cache.record_prepared_execution(
+            query_text,
+            space_id,
+            execute_start.elapsed().as_millis() as f64,
+        );
+        result
+    }
+
+    /// Look up a cached plan that was stored by [`Self::plan_prepared`]
+    fn prepared_plan(
+        &self,
+        query_text: &str,
+        space_id: Option<u64>,
+    ) -> Option<crate::query::planning::plan::ExecutionPlan> {
+        self.plan_cache
+            .get_prepared(query_text, space_id)
+            .map(|cached| cached.plan.clone())
+    }
+
+    /// Plan a parameterized query and store it in the plan cache
+    fn plan_prepared(
+        &mut self,
+        query_text: &str,
+        rctx: Arc<QueryRequestContext>,
+        space_info: Option<crate::core::types::SpaceInfo>,
+    ) -> DBResult<crate::query::planning::plan::ExecutionPlan> {
+        let space_id = space_info.as_ref().map(|space| space.space_id);
+        let mut query_context = QueryContext::new(rctx);
+        if let Some(ref space) = space_info {
+            query_context.set_space_info(space.clone());
+        }
+        let query_context = Arc::new(query_context);
+
+        let parser_result = self.parse_into_context(query_text)?;
+        let validation_info =
+            self.validate_query_with_context(parser_result.ast.clone(), query_context.clone())?;
+        let validated = ValidatedStatement::new(parser_result.ast.clone(), validation_info);
+
+        if matches!(
+            validated.ast.stmt(),
+            crate::query::parser::ast::Stmt::Explain(_)
+                | crate::query::parser::ast::Stmt::Profile(_)
+        ) {
+            return Err(DBError::from(QueryError::execution(
+                "EXPLAIN and PROFILE statements cannot be prepared".to_string(),
+            )));
+        }
+        if matches!(
+            validated.ast.stmt(),
+            crate::query::parser::ast::Stmt::MaterializedView(_)
+        ) {
+            return Err(DBError::from(QueryError::execution(
+                "Materialized view statements cannot be prepared".to_string(),
+            )));
+        }
+
+        let execution_plan = self.generate_execution_plan(query_context, &validated)?;
+        let optimized_plan = self.optimize_execution_plan(execution_plan)?;
+
+        let param_positions = self.param_handler.extract_params(query_text);
+        let semantic_info = &validated.validation_info.semantic_info;
+        let dependent_tables = semantic_info
+            .referenced_tags
+            .iter()
+            .chain(&semantic_info.referenced_edges)
+            .cloned()
+            .collect();
+        self.plan_cache.put_prepared(
+            query_text,
+            space_id,
+            optimized_plan.clone(),
+            param_positions,
+            dependent_tables,
+        );
+
+        Ok(optimized_plan)
     }
 
     pub fn execute_query_with_metrics(
@@ -453,13 +654,22 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         // Check for EXPLAIN/PROFILE statements and route accordingly
         match validated.ast.stmt() {
             crate::query::parser::ast::Stmt::Explain(explain_stmt) => {
-                let result = self.execute_explain(explain_stmt, query_context)?;
+                let result =
+                    self.execute_explain(explain_stmt, validated.ast.hints(), query_context)?;
                 profile.total_duration_us = total_start.elapsed().as_micros() as u64;
                 metrics.record_total_time(total_start.elapsed());
                 return Ok((result, metrics, profile));
             }
             crate::query::parser::ast::Stmt::Profile(profile_stmt) => {
-                let result = self.execute_profile(profile_stmt, query_context)?;
+                let result =
+                    self.execute_profile(profile_stmt, validated.ast.hints(), query_context)?;
+                profile.total_duration_us = total_start.elapsed().as_micros() as u64;
+                metrics.record_total_time(total_start.elapsed());
+                return Ok((result, metrics, profile));
+            }
+            crate::query::parser::ast::Stmt::MaterializedView(view_stmt) => {
+                let result =
+                    self.execute_materialized_view(view_stmt, &validated, query_context)?;
                 profile.total_duration_us = total_start.elapsed().as_micros() as u64;
                 metrics.record_total_time(total_start.elapsed());
                 return Ok((result, metrics, profile));
@@ -510,7 +720,11 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         };
 
         let execute_start = Instant::now();
-        let result = match self.execute_plan(query_context, optimized_plan) {
+        let maintenance =
+            self.begin_view_maintenance(query_text, Some(validated.ast.stmt()), &query_context);
+        let result = self.execute_plan(query_context, optimized_plan);
+        self.finish_view_maintenance(maintenance);
+        let result = match result {
             Ok(result) => {
                 profile.stages.execute_us = execute_start.elapsed().as_micros() as u64;
                 profile.result_count = result.count();
@@ -600,9 +814,10 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
 
         // Perform verification using the provided QueryContext.
         // Avoid creating temporary contexts and ensure the consistency of resources (such as ID generators, object pools, etc.).
-        let validation_result = validator.validate(ast.clone(), qctx);
+        let validation_result = validator.validate(ast.clone(), qctx.clone());
 
         if validation_result.success {
+            self.check_privileges(&ast, &qctx)?;
             Ok(validation_result.info.unwrap_or_default())
         } else {
             let error_msg = validation_result
@@ -615,6 +830,79 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         }
     }
 
+    /// Custom-role privileges of the session user, `None` when unrestricted
+    fn effective_privileges(&self, qctx: &QueryContext) -> Option<EffectivePrivileges> {
+        let user_name = qctx.rctx().user_name.as_deref()?;
+        let storage = self.executor_factory.storage.as_ref()?;
+        storage.read().effective_privileges(user_name)
+    }
+
+    /// Reject statements touching tags, edge types or properties the session
+    /// user's custom roles do not grant.
+    fn check_privileges(
+        &self,
+        ast: &crate::query::parser::ast::stmt::Ast,
+        qctx: &QueryContext,
+    ) -> DBResult<()> {
+        let Some(privileges) = self.effective_privileges(qctx) else {
+            return Ok(());
+        };
+
+        let mut checker = PrivilegeChecker::new(&privileges);
+        let schema = match (&self.schema_manager, qctx.space_name()) {
+            (Some(schema_manager), Some(space_name)) => Some((
+                schema_manager.list_tags(&space_name).unwrap_or_default(),
+                schema_manager
+                    .list_edge_types(&space_name)
+                    .unwrap_or_default(),
+            )),
+            _ => None,
+        };
+        if let Some((ref tags, ref edge_types)) = schema {
+            checker = checker.with_schema(tags, edge_types);
+        }
+        checker.check(ast)
+    }
+
+    /// Evict cached plans that read a tag or edge type whose schema or indexes
+    /// a statement just changed
+    ///
+    /// Index and constraint drops name no tag, so they evict every plan of the space.
+    fn invalidate_dependent_plans(&self, stmt: &Stmt, space_id: Option<u64>) {
+        use crate::query::parser::ast::stmt::{AlterTarget, CreateTarget, DropTarget};
+
+        let tables: Vec<&String> = match stmt {
+            Stmt::Alter(alter) => match &alter.target {
+                AlterTarget::Tag { tag_name, .. } => vec![tag_name],
+                AlterTarget::Edge { edge_name, .. } => vec![edge_name],
+                AlterTarget::Space { .. } => return,
+            },
+            Stmt::Create(create) => match &create.target {
+                CreateTarget::Index { on, .. } => vec![on],
+                CreateTarget::Constraint { tag, .. } => vec![tag],
+                _ => return,
+            },
+            Stmt::Drop(drop) => match &drop.target {
+                DropTarget::Tags(names) | DropTarget::Edges(names) => names.iter().collect(),
+                DropTarget::TagIndex { .. }
+                | DropTarget::EdgeIndex { .. }
+                | DropTarget::Constraint(_) => {
+                    self.plan_cache.invalidate_space(space_id);
+                    return;
+                }
+                DropTarget::Space(_) => return,
+            },
+            _ => return,
+        };
+
+        for table in tables {
+            let removed = self.plan_cache.invalidate_by_table(space_id, table);
+            if removed > 0 {
+                log::debug!("Evicted {} cached plans reading {}", removed, table);
+            }
+        }
+    }
+
     /// Generate an execution plan using the verified statements.
     fn generate_execution_plan(
         &mut self,
@@ -640,7 +928,9 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
             };
 
             let root = sub_plan.root().clone();
-            crate::query::planning::plan::ExecutionPlan::new(root)
+            let mut plan = crate::query::planning::plan::ExecutionPlan::new(root);
+            plan.hints = validated.ast.hints().clone();
+            plan
         } else {
             return Err(DBError::from(QueryError::pipeline_planning_error(
                 crate::query::planning::planner::PlannerError::NoSuitablePlanner(
@@ -786,6 +1076,33 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
                     }
                 }
             }
+            Stmt::HybridSearch(search) => {
+                // Pre-resolve both indexes; the planner checks that they share a tag
+                #[cfg(all(feature = "fulltext-search", feature = "qdrant"))]
+                if let (Some(ref fulltext_manager), Some(ref vector_coordinator)) =
+                    (&self.fulltext_manager, &self.vector_coordinator)
+                {
+                    let fulltext_metadata = self
+                        .resolve_fulltext_index(space_id, &search.fulltext_index, fulltext_manager)
+                        .map_err(|msg| {
+                            DBError::from(QueryError::invalid_query(format!(
+                                "Fulltext index not found: {}",
+                                msg
+                            )))
+                        })?;
+                    let vector_metadata = self
+                        .resolve_vector_index(space_id, &search.vector_index, vector_coordinator)
+                        .map_err(|msg| {
+                            DBError::from(QueryError::invalid_query(format!(
+                                "Vector index not found: {}",
+                                msg
+                            )))
+                        })?;
+                    context.set_index_metadata(search.fulltext_index.clone(), fulltext_metadata);
+                    context.set_index_metadata(search.vector_index.clone(), vector_metadata);
+                    has_metadata = true;
+                }
+            }
             Stmt::Match(_match_stmt) => {
                 // Pre-resolve tag and index metadata for MATCH statements
                 let referenced_tags = &validated.validation_info.semantic_info.referenced_tags;
@@ -1097,28 +1414,415 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
 
     fn execute_plan(
         &mut self,
-        _query_context: Arc<QueryContext>,
+        query_context: Arc<QueryContext>,
         plan: crate::query::planning::plan::ExecutionPlan,
     ) -> DBResult<ExecutionResult> {
         use crate::query::executor::factory::engine::PlanExecutor;
         let mut plan_executor =
             PlanExecutor::with_object_pool(self.executor_factory.clone(), self.object_pool.clone());
 
-        let expr_ctx = Arc::new(ExpressionAnalysisContext::new());
+        let context = ExecutionContext::new(Arc::new(ExpressionAnalysisContext::new()))
+            .with_kill_flag(query_context.kill_flag())
+            .with_parallelism(plan.hints.parallelism())
+            .with_role(query_context.rctx().role)
+            .with_parameters(query_context.parameters().clone());
 
         let storage = self.executor_factory.storage.clone().ok_or_else(|| {
             DBError::from(QueryError::execution("Storage not available".to_string()))
         })?;
 
-        plan_executor
-            .execute_plan(&plan, storage, expr_ctx)
-            .map_err(|e| DBError::from(QueryError::pipeline_execution_error(e)))
+        let mut result = plan_executor
+            .execute_plan_with_context(&plan, storage, context)
+            .map_err(|e| DBError::from(QueryError::pipeline_execution_error(e)))?;
+
+        // Whole vertices and edges may still carry properties hidden from the user
+        if let ExecutionResult::DataSet(ref mut dataset) = result {
+            if let Some(privileges) = self.effective_privileges(&query_context) {
+                dataset
+                    .rows
+                    .iter_mut()
+                    .flatten()
+                    .for_each(|value| privileges.mask_value(value));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Schema manager of the storage, which keeps materialized view definitions and rows
+    fn view_catalog(&self) -> Option<Arc<SchemaManager>> {
+        self.executor_factory
+            .storage
+            .as_ref()?
+            .read()
+            .get_schema_manager()
+    }
+
+    /// Execute CREATE, DROP, REFRESH, SHOW MATERIALIZED VIEW(S) and MATCH VIEW
+    fn execute_materialized_view(
+        &mut self,
+        view_stmt: &MaterializedViewStmt,
+        validated: &ValidatedStatement,
+        qctx: Arc<QueryContext>,
+    ) -> DBResult<ExecutionResult> {
+        let space_info = qctx.space_info().cloned().ok_or_else(|| {
+            view_error("No image space selected, please execute first USE <space>")
+        })?;
+        let catalog = self
+            .view_catalog()
+            .ok_or_else(|| view_error("Schema manager is not available"))?;
+        let space_id = space_info.space_id;
+
+        match &view_stmt.action {
+            MaterializedViewAction::Create {
+                name,
+                query,
+                if_not_exists,
+                ..
+            } => {
+                if catalog.get_materialized_view(space_id, name).is_some() {
+                    if *if_not_exists {
+                        return Ok(ExecutionResult::Success);
+                    }
+                    return Err(view_error(format!(
+                        "Materialized view {} already exists",
+                        name
+                    )));
+                }
+
+                let mut info = MaterializedViewInfo::new(name.clone(), space_id, query.clone());
+                let definition = self
+                    .optimizer_engine
+                    .materialized_views()
+                    .definition(&info)
+                    .map_err(view_error)?;
+                let dataset = self.run_view_statement(&definition, None, &space_info)?;
+                info.columns = dataset
+                    .col_names
+                    .into_iter()
+                    .filter(|column| column != HIDDEN_COUNT_COLUMN)
+                    .collect();
+                info.incremental = definition.is_incremental();
+
+                let created = catalog
+                    .create_materialized_view(info)
+                    .map_err(|e| DBError::from(QueryError::from(e)))?;
+                if !created && !*if_not_exists {
+                    return Err(view_error(format!(
+                        "Materialized view {} already exists",
+                        name
+                    )));
+                }
+                if created {
+                    catalog.set_materialized_view_data(
+                        space_id,
+                        name,
+                        Some(Arc::new(MaterializedViewData::new(dataset.rows))),
+                    );
+                }
+                Ok(ExecutionResult::Success)
+            }
+            MaterializedViewAction::Drop { name, if_exists } => {
+                self.optimizer_engine
+                    .materialized_views()
+                    .forget(space_id, name);
+                if !catalog.drop_materialized_view(space_id, name) && !*if_exists {
+                    return Err(view_error(format!(
+                        "Materialized view {} does not exist",
+                        name
+                    )));
+                }
+                Ok(ExecutionResult::Success)
+            }
+            MaterializedViewAction::Refresh { name } => {
+                let info = catalog
+                    .get_materialized_view(space_id, name)
+                    .ok_or_else(|| {
+                        view_error(format!("Materialized view {} does not exist", name))
+                    })?;
+                self.refresh_view(&catalog, &info, &space_info)?;
+                Ok(ExecutionResult::Success)
+            }
+            MaterializedViewAction::Show => {
+                let rows = catalog
+                    .list_materialized_views(space_id)
+                    .into_iter()
+                    .map(|info| {
+                        let data = catalog.materialized_view_data(space_id, &info.name);
+                        let mode = if info.incremental {
+                            "INCREMENTAL"
+                        } else {
+                            "FULL"
+                        };
+                        let state = if data.is_some() { "FRESH" } else { "STALE" };
+                        vec![
+                            Value::String(info.name),
+                            Value::String(info.query),
+                            Value::String(info.columns.join(", ")),
+                            Value::String(mode.to_string()),
+                            Value::String(state.to_string()),
+                            data.as_ref().map_or(Value::Null(NullType::Null), |data| {
+                                Value::BigInt(data.rows.len() as i64)
+                            }),
+                            data.and_then(|data| {
+                                chrono::DateTime::<chrono::Utc>::from_timestamp_millis(
+                                    data.refreshed_at,
+                                )
+                            })
+                            .map_or(Value::Null(NullType::Null), |time| {
+                                Value::String(time.to_rfc3339())
+                            }),
+                        ]
+                    })
+                    .collect();
+                let columns = [
+                    "name",
+                    "query",
+                    "columns",
+                    "mode",
+                    "state",
+                    "rows",
+                    "refreshed_at",
+                ];
+                Ok(ExecutionResult::DataSet(DataSet::from_rows(
+                    rows,
+                    columns.iter().map(|column| column.to_string()).collect(),
+                )))
+            }
+            MaterializedViewAction::Scan { name, .. } => {
+                let info = catalog
+                    .get_materialized_view(space_id, name)
+                    .ok_or_else(|| {
+                        view_error(format!("Materialized view {} does not exist", name))
+                    })?;
+                self.prepare_view_scan(&catalog, &info, &space_info, &qctx)?;
+                let execution_plan = self.generate_execution_plan(qctx.clone(), validated)?;
+                let optimized_plan = self.optimize_execution_plan(execution_plan)?;
+                self.execute_plan(qctx, optimized_plan)
+            }
+        }
+    }
+
+    /// Answer a query from a materialized view defined by the same query text
+    ///
+    /// Only the normalized text is compared, not the plan, so an equivalent query written
+    /// differently runs normally. Returns `None` when no view of the current space matches.
+    fn answer_from_view(
+        &mut self,
+        query_text: &str,
+        qctx: &Arc<QueryContext>,
+    ) -> DBResult<Option<ExecutionResult>> {
+        let (Some(space_info), Some(catalog)) = (qctx.space_info().cloned(), self.view_catalog())
+        else {
+            return Ok(None);
+        };
+        let views = catalog.list_materialized_views(space_info.space_id);
+        let Some(info) = self
+            .optimizer_engine
+            .materialized_views()
+            .find_view_for_query(&views, query_text)
+            .cloned()
+        else {
+            return Ok(None);
+        };
+
+        log::debug!("Answering query from materialized view {}", info.name);
+        self.prepare_view_scan(&catalog, &info, &space_info, qctx)?;
+        let scan = MaterializedViewScanNode::new(next_node_id(), info.space_id, info.name);
+        let plan = ExecutionPlan::new(Some(PlanNodeEnum::MaterializedViewScan(scan)));
+        self.execute_plan(qctx.clone(), plan).map(Some)
+    }
+
+    /// Check the session user may read what the view reads, and refresh it when stale
+    fn prepare_view_scan(
+        &mut self,
+        catalog: &SchemaManager,
+        info: &MaterializedViewInfo,
+        space_info: &SpaceInfo,
+        qctx: &QueryContext,
+    ) -> DBResult<()> {
+        if self.effective_privileges(qctx).is_some() {
+            let parser_result = self.parse_into_context(&info.query)?;
+            self.check_privileges(&parser_result.ast, qctx)?;
+        }
+        if catalog
+            .materialized_view_data(info.space_id, &info.name)
+            .is_none()
+        {
+            self.refresh_view(catalog, info, space_info)?;
+        }
+        Ok(())
+    }
+
+    /// Recompute all rows of a view; the view stays stale when its query fails
+    fn refresh_view(
+        &mut self,
+        catalog: &SchemaManager,
+        info: &MaterializedViewInfo,
+        space_info: &SpaceInfo,
+    ) -> DBResult<()> {
+        catalog.set_materialized_view_data(info.space_id, &info.name, None);
+        let definition = self
+            .optimizer_engine
+            .materialized_views()
+            .definition(info)
+            .map_err(view_error)?;
+        let dataset = self.run_view_statement(&definition, None, space_info)?;
+        catalog.set_materialized_view_data(
+            info.space_id,
+            &info.name,
+            Some(Arc::new(MaterializedViewData::new(dataset.rows))),
+        );
+        Ok(())
+    }
+
+    /// Run the defining query of a view, restricted to the rows anchored on `ids` when given
+    ///
+    /// The query runs without a session user: view rows are shared by all users, and
+    /// readers are checked against the definition when they read the view.
+    fn run_view_statement(
+        &mut self,
+        definition: &ViewDefinition,
+        ids: Option<&[Value]>,
+        space_info: &SpaceInfo,
+    ) -> DBResult<DataSet> {
+        let ast = definition.statement(ids).map_err(view_error)?;
+        let rctx = Arc::new(QueryRequestContext::new(definition.query.clone()));
+        let mut query_context = QueryContext::new(rctx);
+        query_context.set_space_info(space_info.clone());
+        let query_context = Arc::new(query_context);
+
+        let validation_info =
+            self.validate_query_with_context(ast.clone(), query_context.clone())?;
+        let validated = ValidatedStatement::new(ast, validation_info);
+        let execution_plan = self.generate_execution_plan(query_context.clone(), &validated)?;
+        let optimized_plan = self.optimize_execution_plan(execution_plan)?;
+        match self.execute_plan(query_context, optimized_plan)? {
+            ExecutionResult::DataSet(dataset) => Ok(dataset),
+            ExecutionResult::Error(e) => Err(view_error(e)),
+            _ => Ok(DataSet::new()),
+        }
+    }
+
+    /// Collect the materialized views a statement may change, before it runs
+    ///
+    /// `stmt` is parsed from `query_text` when not given, which only happens when the
+    /// current space has views. Incremental views remember the rows anchored on the
+    /// written vertices so the write can be applied as a delta afterwards.
+    fn begin_view_maintenance(
+        &mut self,
+        query_text: &str,
+        stmt: Option<&Stmt>,
+        qctx: &QueryContext,
+    ) -> Option<ViewMaintenance> {
+        let space_info = qctx.space_info()?.clone();
+        let catalog = self.view_catalog()?;
+        let views = catalog.list_materialized_views(space_info.space_id);
+        if views.is_empty() {
+            return None;
+        }
+
+        let parsed;
+        let stmt = match stmt {
+            Some(stmt) => stmt,
+            None => {
+                parsed = self.parse_into_context(query_text).ok()?;
+                parsed.ast.stmt()
+            }
+        };
+        let change = ViewChange::from_stmt(stmt)?;
+
+        let engine = self.optimizer_engine.clone();
+        let mut pending = Vec::new();
+        for info in engine.materialized_views().affected_views(&views, &change) {
+            let definition = engine.materialized_views().definition(&info).ok();
+            let before = match (&change.ids, definition) {
+                (Some(ids), Some(definition))
+                    if definition.is_incremental()
+                        && catalog
+                            .materialized_view_data(info.space_id, &info.name)
+                            .is_some() =>
+                {
+                    self.run_view_statement(&definition, Some(ids), &space_info)
+                        .ok()
+                        .map(|dataset| dataset.rows)
+                }
+                _ => None,
+            };
+            pending.push((info, before));
+        }
+
+        Some(ViewMaintenance {
+            space_info,
+            catalog,
+            ids: change.ids,
+            views: pending,
+        })
+    }
+
+    /// Bring the views collected by [`Self::begin_view_maintenance`] up to date
+    ///
+    /// Views that cannot take the change as a delta are marked stale and fully
+    /// refreshed by their next reader.
+    fn finish_view_maintenance(&mut self, maintenance: Option<ViewMaintenance>) {
+        let Some(maintenance) = maintenance else {
+            return;
+        };
+
+        let engine = self.optimizer_engine.clone();
+        for (info, before) in maintenance.views {
+            let rows = before
+                .zip(maintenance.ids.as_deref())
+                .and_then(|(before, ids)| {
+                    let definition = engine.materialized_views().definition(&info).ok()?;
+                    let after = self
+                        .run_view_statement(&definition, Some(ids), &maintenance.space_info)
+                        .ok()?;
+                    let stored = maintenance
+                        .catalog
+                        .materialized_view_data(info.space_id, &info.name)?;
+                    definition
+                        .apply_delta(&stored.rows, before, after.rows)
+                        .inspect_err(|e| log::debug!("Delta of view {} failed: {}", info.name, e))
+                        .ok()
+                });
+
+            if rows.is_none() {
+                log::debug!("Materialized view {} marked stale", info.name);
+            }
+            maintenance.catalog.set_materialized_view_data(
+                info.space_id,
+                &info.name,
+                rows.map(|rows| Arc::new(MaterializedViewData::new(rows))),
+            );
+        }
+    }
+
+    /// Prepare the statement wrapped by PROFILE or EXPLAIN ANALYZE, which gets executed
+    fn prepare_wrapped_statement(
+        &mut self,
+        stmt: &Stmt,
+        qctx: &QueryContext,
+    ) -> DBResult<Option<ViewMaintenance>> {
+        let Stmt::MaterializedView(view_stmt) = stmt else {
+            return Ok(self.begin_view_maintenance("", Some(stmt), qctx));
+        };
+        if let MaterializedViewAction::Scan { name, .. } = &view_stmt.action {
+            if let (Some(space_info), Some(catalog)) =
+                (qctx.space_info().cloned(), self.view_catalog())
+            {
+                if let Some(info) = catalog.get_materialized_view(space_info.space_id, name) {
+                    self.prepare_view_scan(&catalog, &info, &space_info, qctx)?;
+                }
+            }
+        }
+        Ok(None)
     }
 
     /// Execute EXPLAIN statement
     pub fn execute_explain(
         &mut self,
         explain_stmt: &ExplainStmt,
+        hints: &QueryHints,
         qctx: Arc<QueryContext>,
     ) -> DBResult<ExecutionResult> {
         // 1. Get inner statement execution plan (without executing)
@@ -1131,11 +1835,12 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
             )),
             qctx.clone(),
         )?;
+        // Hint comments are lexed with the outer statement
         let inner_validated = ValidatedStatement::new(
-            Arc::new(crate::query::parser::ast::stmt::Ast::new(
-                (**inner_ast).clone(),
-                expr_ctx,
-            )),
+            Arc::new(
+                crate::query::parser::ast::stmt::Ast::new((**inner_ast).clone(), expr_ctx)
+                    .with_hints(hints.clone()),
+            ),
             validation_info,
         );
         let inner_plan = self.generate_execution_plan(qctx.clone(), &inner_validated)?;
@@ -1170,6 +1875,7 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
     pub fn execute_explain_analyze(
         &mut self,
         explain_stmt: &ExplainStmt,
+        hints: &QueryHints,
         qctx: Arc<QueryContext>,
     ) -> DBResult<ExecutionResult> {
         // 1. Get inner statement execution plan
@@ -1182,11 +1888,12 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
             )),
             qctx.clone(),
         )?;
+        // Hint comments are lexed with the outer statement
         let inner_validated = ValidatedStatement::new(
-            Arc::new(crate::query::parser::ast::stmt::Ast::new(
-                (**inner_ast).clone(),
-                expr_ctx,
-            )),
+            Arc::new(
+                crate::query::parser::ast::stmt::Ast::new((**inner_ast).clone(), expr_ctx)
+                    .with_hints(hints.clone()),
+            ),
             validation_info,
         );
         let inner_plan = self.generate_execution_plan(qctx.clone(), &inner_validated)?;
@@ -1212,15 +1919,19 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         );
 
         // 3. Execute Explain Analyze
-        explain_executor
+        let maintenance = self.prepare_wrapped_statement(inner_ast, &qctx)?;
+        let result = explain_executor
             .execute()
-            .map_err(|e| DBError::from(QueryError::execution(e.to_string())))
+            .map_err(|e| DBError::from(QueryError::execution(e.to_string())));
+        self.finish_view_maintenance(maintenance);
+        result
     }
 
     /// Execute PROFILE statement
     pub fn execute_profile(
         &mut self,
         profile_stmt: &ProfileStmt,
+        hints: &QueryHints,
         qctx: Arc<QueryContext>,
     ) -> DBResult<ExecutionResult> {
         // 1. Get inner statement execution plan
@@ -1233,11 +1944,12 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
             )),
             qctx.clone(),
         )?;
+        // Hint comments are lexed with the outer statement
         let inner_validated = ValidatedStatement::new(
-            Arc::new(crate::query::parser::ast::stmt::Ast::new(
-                (**inner_ast).clone(),
-                expr_ctx,
-            )),
+            Arc::new(
+                crate::query::parser::ast::stmt::Ast::new((**inner_ast).clone(), expr_ctx)
+                    .with_hints(hints.clone()),
+            ),
             validation_info,
         );
         let inner_plan = self.generate_execution_plan(qctx.clone(), &inner_validated)?;
@@ -1256,11 +1968,29 @@ impl<S: StorageClient + 'static> QueryPipelineManager<S> {
         );
 
         let mut profile_executor =
-            ProfileExecutor::new(base, optimized_plan, profile_stmt.format.clone());
+            ProfileExecutor::new(base, optimized_plan, profile_stmt.format.clone())
+                .with_kill_flag(qctx.kill_flag());
 
         // 3. Execute Profile
-        profile_executor
+        let maintenance = self.prepare_wrapped_statement(inner_ast, &qctx)?;
+        let result = profile_executor
             .execute()
-            .map_err(|e| DBError::from(QueryError::execution(e.to_string())))
+            .map_err(|e| DBError::from(QueryError::execution(e.to_string())));
+        self.finish_view_maintenance(maintenance);
+        result
     }
 }
+
+/// Materialized views a write statement may change
+struct ViewMaintenance {
+    space_info: SpaceInfo,
+    catalog: Arc<SchemaManager>,
+    /// Vertices the statement writes, when known
+    ids: Option<Vec<Value>>,
+    /// Affected views with their rows anchored on `ids` before the write, if collected
+    views: Vec<(MaterializedViewInfo, Option<Vec<Vec<Value>>>)>,
+}
+
+fn view_error(message: impl Into<String>) -> DBError {
+    DBError::from(QueryError::execution(message))
+}
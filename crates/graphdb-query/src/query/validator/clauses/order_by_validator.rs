@@ -433,6 +433,11 @@ impl OrderByValidator {
             Expression::List(_) => Ok(ValueType::List),
             Expression::Map(_) => Ok(ValueType::Map),
             Expression::Vector(_) => Ok(ValueType::List), // Vector types are treated as List for type inference
+            Expression::PatternSubquery(subquery) => Ok(match subquery.kind {
+                crate::core::types::expr::PatternSubqueryKind::Exists => ValueType::Bool,
+                crate::core::types::expr::PatternSubqueryKind::Count => ValueType::Int,
+                crate::core::types::expr::PatternSubqueryKind::Collect => ValueType::List,
+            }),
             Expression::Case { .. } => Ok(ValueType::Unknown), // The result type of a CASE expression depends on the branch that is executed.
             Expression::TypeCast { target_type, .. } => {
                 // Please provide the text you would like to have translated, as well as the target language you need the translation to. I will then perform the translation for you.
@@ -616,6 +621,8 @@ impl OrderByValidator {
             }
             Expression::Parameter(_) => {}
             Expression::Vector(_) => {}
+            // Variables inside a pattern subquery are scoped to the subquery
+            Expression::PatternSubquery(_) => {}
         }
     }
 }
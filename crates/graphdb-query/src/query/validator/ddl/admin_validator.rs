@@ -42,6 +42,7 @@ pub enum ShowTargetType {
     Queries,
     Configs,
     Stats,
+    Constraints,
 }
 
 /// SHOW statement validator
@@ -88,6 +89,7 @@ impl ShowValidator {
             ShowTarget::Users => ShowTargetType::Users,
             ShowTarget::Roles => ShowTargetType::Roles,
             ShowTarget::Stats => ShowTargetType::Stats,
+            ShowTarget::Constraints => ShowTargetType::Constraints,
         };
 
         self.setup_outputs();
@@ -164,6 +166,24 @@ impl ShowValidator {
                     type_: ValueType::String,
                 },
             ],
+            ShowTargetType::Constraints => vec![
+                ColumnDef {
+                    name: "Constraint Name".to_string(),
+                    type_: ValueType::String,
+                },
+                ColumnDef {
+                    name: "Tag Name".to_string(),
+                    type_: ValueType::String,
+                },
+                ColumnDef {
+                    name: "Type".to_string(),
+                    type_: ValueType::String,
+                },
+                ColumnDef {
+                    name: "Properties".to_string(),
+                    type_: ValueType::String,
+                },
+            ],
             _ => vec![ColumnDef {
                 name: "Result".to_string(),
                 type_: ValueType::String,
@@ -207,7 +227,7 @@ impl StatementValidator for ShowValidator {
 
         // For SHOW TAGS and SHOW EDGES, set the space name from query context
         match self.target_type {
-            ShowTargetType::Tags | ShowTargetType::Edges => {
+            ShowTargetType::Tags | ShowTargetType::Edges | ShowTargetType::Constraints => {
                 if let Some(space_name) = qctx.space_name() {
                     info.semantic_info.space_name = Some(space_name);
                 }
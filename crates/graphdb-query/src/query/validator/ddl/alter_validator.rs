@@ -214,8 +214,9 @@ impl AlterValidator {
             self.validate_property_name(&change.old_name)?;
             self.validate_property_name(&change.new_name)?;
 
-            // The new and old names cannot be the same.
-            if change.old_name == change.new_name {
+            // Tags accept a same-name CHANGE as a pure type change; edges only rename.
+            let is_tag = matches!(self.target_type, AlterTargetType::Tag);
+            if change.old_name == change.new_name && !is_tag {
                 return Err(ValidationError::new(
                     format!(
                         "Old name and new name cannot be the same: {}",
@@ -224,6 +225,15 @@ impl AlterValidator {
                     ValidationErrorType::SemanticError,
                 ));
             }
+            if change.allow_lossy && !is_tag {
+                return Err(ValidationError::new(
+                    format!(
+                        "FORCE is only supported when changing tag property types: {}",
+                        change.old_name
+                    ),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
         }
 
         // Check for conflicts: The same attribute cannot be added and deleted at the same time.
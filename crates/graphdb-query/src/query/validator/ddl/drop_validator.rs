@@ -33,6 +33,7 @@ pub enum DropTargetType {
     Edge,
     TagIndex,
     EdgeIndex,
+    Constraint,
 }
 
 /// Drop Statement Validator
@@ -142,6 +143,17 @@ impl DropValidator {
                     ));
                 }
             }
+            DropTarget::Constraint(name) => {
+                self.target_type = DropTargetType::Constraint;
+                self.target_name = name.clone();
+
+                if name.is_empty() {
+                    return Err(ValidationError::new(
+                        "Constraint name cannot be empty".to_string(),
+                        ValidationErrorType::SemanticError,
+                    ));
+                }
+            }
         }
 
         Ok(())
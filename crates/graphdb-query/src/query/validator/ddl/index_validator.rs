@@ -1,5 +1,5 @@
 //! Index creation statement validator
-//! Verify the CREATE TAG INDEX and CREATE EDGE INDEX statements.
+//! Verify the CREATE TAG INDEX, CREATE EDGE INDEX and CREATE CONSTRAINT statements.
 //!
 //! Design principles:
 //! The StatementValidator trait has been implemented to unify the interface.
@@ -29,6 +29,8 @@ pub struct ValidatedIndexCreate {
 pub enum IndexCreateTarget {
     Tag,
     Edge,
+    /// Tag constraint, backed by a unique tag index when UNIQUE
+    Constraint,
 }
 
 #[derive(Debug)]
@@ -73,9 +75,23 @@ impl CreateIndexValidator {
                 };
                 &stmt.target
             }
+            CreateTarget::Constraint {
+                name,
+                tag,
+                properties,
+                ..
+            } => {
+                self.index_type = IndexCreateTarget::Constraint;
+                self.index_name = name.clone();
+                self.schema_name = tag.clone();
+                self.properties = properties.clone();
+                self.if_not_exists = stmt.if_not_exists;
+                &stmt.target
+            }
             _ => {
                 return Err(ValidationError::new(
-                    "Expected CREATE TAG INDEX or CREATE EDGE INDEX statement".to_string(),
+                    "Expected CREATE TAG INDEX, CREATE EDGE INDEX or CREATE CONSTRAINT statement"
+                        .to_string(),
                     ValidationErrorType::SemanticError,
                 ));
             }
@@ -158,13 +174,14 @@ impl StatementValidator for CreateIndexValidator {
         self.validate_impl_with_context(create_stmt, &qctx)?;
 
         let mut info = ValidationInfo::new();
-        info.semantic_info.query_type = Some(format!(
-            "Create{}Index",
+        info.semantic_info.query_type = Some(
             match self.index_type {
-                IndexCreateTarget::Tag => "Tag",
-                IndexCreateTarget::Edge => "Edge",
+                IndexCreateTarget::Tag => "CreateTagIndex",
+                IndexCreateTarget::Edge => "CreateEdgeIndex",
+                IndexCreateTarget::Constraint => "CreateConstraint",
             }
-        ));
+            .to_string(),
+        );
 
         info.semantic_info.referenced_schemas = vec![self.schema_name.clone()];
         info.semantic_info.space_name = Some(self.space_name.clone());
@@ -174,7 +191,7 @@ impl StatementValidator for CreateIndexValidator {
 
     fn statement_type(&self) -> StatementType {
         match self.index_type {
-            IndexCreateTarget::Tag => StatementType::CreateTagIndex,
+            IndexCreateTarget::Tag | IndexCreateTarget::Constraint => StatementType::CreateTagIndex,
             IndexCreateTarget::Edge => StatementType::CreateEdgeIndex,
         }
     }
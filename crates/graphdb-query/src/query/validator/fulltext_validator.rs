@@ -8,7 +8,8 @@ use std::sync::Arc;
 
 use crate::query::parser::ast::{
     AlterFulltextIndex, CreateFulltextIndex, DescribeFulltextIndex, DropFulltextIndex,
-    FulltextQueryExpr, LookupFulltext, MatchFulltext, SearchStatement,
+    FulltextQueryExpr, FusionMethod, HybridSearchStatement, LookupFulltext, MatchFulltext,
+    SearchStatement,
 };
 use crate::query::validator::error::{ValidationError, ValidationErrorType};
 use crate::query::validator::validator_trait::{
@@ -68,6 +69,10 @@ impl FulltextValidator {
             crate::query::parser::ast::Stmt::MatchFulltext(match_stmt) => {
                 self.validate_match(match_stmt)
             }
+            crate::query::parser::ast::Stmt::HybridSearch(search) => {
+                self.validate_hybrid_search(search)
+            }
+            crate::query::parser::ast::Stmt::SyncDlq(_) => Ok(ValidationInfo::new()),
             _ => Err(ValidationError::new(
                 "Not a full-text search statement",
                 ValidationErrorType::SemanticError,
@@ -221,6 +226,56 @@ impl FulltextValidator {
         Ok(ValidationInfo::new())
     }
 
+    fn validate_hybrid_search(
+        &self,
+        search: &HybridSearchStatement,
+    ) -> Result<ValidationInfo, ValidationError> {
+        if search.fulltext_index.is_empty() || search.vector_index.is_empty() {
+            return Err(ValidationError::new(
+                "Index name cannot be empty",
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        self.validate_query_expr(&search.fulltext_query)?;
+
+        if search.vector_query.query_data.trim().is_empty() {
+            return Err(ValidationError::new(
+                "Vector query cannot be empty",
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        match search.fusion {
+            FusionMethod::Rrf { k } if k <= 0.0 => {
+                return Err(ValidationError::new(
+                    format!("RRF constant k must be positive, got {}", k),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+            FusionMethod::Weighted { fulltext, vector }
+                if fulltext < 0.0 || vector < 0.0 || fulltext + vector == 0.0 =>
+            {
+                return Err(ValidationError::new(
+                    "Fusion weights must be non-negative and not both zero",
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+            _ => {}
+        }
+
+        if let Some(limit) = search.limit {
+            if limit == 0 || limit > 10000 {
+                return Err(ValidationError::new(
+                    format!("Limit must be between 1 and 10000, got {}", limit),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        }
+
+        Ok(ValidationInfo::new())
+    }
+
     fn validate_query_expr(&self, expr: &FulltextQueryExpr) -> Result<(), ValidationError> {
         match expr {
             FulltextQueryExpr::Simple(text) => {
@@ -1,6 +1,10 @@
 pub mod expression_checker;
+pub mod privilege_checker;
 pub mod schema_validator;
+pub mod statement_permission;
 pub mod type_checker;
 pub mod variable_checker;
 
+pub use privilege_checker::PrivilegeChecker;
 pub use schema_validator::SchemaValidator;
+pub use statement_permission::required_permission;
@@ -0,0 +1,692 @@
+//! Fine-grained privilege checking
+//! Verifies that a statement only reads and writes the tags, edge types and
+//! properties granted to the user's custom roles.
+//!
+//! Qualified accesses (`person.name`, `$^.person.name`, `e.follow.degree`) are
+//! checked against the named tag or edge type. Unqualified accesses such as
+//! `v.name` are checked against every tag and edge type referenced by the
+//! statement that defines the property, or against the whole space when the
+//! statement references none.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::error::DBError;
+use crate::core::types::expr::contextual::ContextualExpression;
+use crate::core::types::expr::Expression;
+use crate::core::types::{EdgeTypeInfo, TagInfo};
+use crate::core::{EffectivePrivileges, PrivilegeAction, PrivilegeObject};
+use crate::query::parser::ast::pattern::{PathElement, Pattern};
+use crate::query::parser::ast::stmt::{
+    Assignment, Ast, CreateTarget, DeleteTarget, FetchTarget, InsertTarget, LookupTarget,
+    MatchDeleteTarget, MaterializedViewAction, OverClause, Stmt, UpdateTarget,
+};
+
+/// Properties a statement writes on one tag or edge type
+#[derive(Debug, Clone)]
+enum WriteScope {
+    All,
+    Properties(Vec<String>),
+}
+
+/// Tags or edge types of one pattern node or edge, with its inline property map
+type PatternItem<'p> = (Vec<PrivilegeObject>, Option<&'p ContextualExpression>);
+
+/// Schema objects and properties a statement accesses outside of expressions
+#[derive(Debug, Default)]
+struct Access {
+    reads: Vec<PrivilegeObject>,
+    read_properties: Vec<(PrivilegeObject, Option<Vec<String>>)>,
+    writes: Vec<(PrivilegeObject, WriteScope)>,
+    /// Properties read through a variable whose tag or edge type is unknown
+    unresolved_reads: Vec<String>,
+    /// Properties written through a variable whose tag or edge type is unknown
+    unresolved_writes: Vec<String>,
+    delete_all_tags: bool,
+    delete_all_edges: bool,
+}
+
+pub struct PrivilegeChecker<'a> {
+    privileges: &'a EffectivePrivileges,
+    /// Properties of every tag and edge type in the current space
+    schema: Option<HashMap<PrivilegeObject, HashSet<String>>>,
+}
+
+impl<'a> PrivilegeChecker<'a> {
+    pub fn new(privileges: &'a EffectivePrivileges) -> Self {
+        Self {
+            privileges,
+            schema: None,
+        }
+    }
+
+    /// Use the schema of the current space to resolve unqualified properties
+    pub fn with_schema(mut self, tags: &[TagInfo], edge_types: &[EdgeTypeInfo]) -> Self {
+        let mut schema = HashMap::new();
+        for tag in tags {
+            schema.insert(
+                PrivilegeObject::Tag(tag.tag_name.clone()),
+                tag.properties.iter().map(|p| p.name.clone()).collect(),
+            );
+        }
+        for edge_type in edge_types {
+            schema.insert(
+                PrivilegeObject::Edge(edge_type.edge_type_name.clone()),
+                edge_type
+                    .properties
+                    .iter()
+                    .map(|p| p.name.clone())
+                    .collect(),
+            );
+        }
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Check every schema object and property the statement touches
+    pub fn check(&self, ast: &Ast) -> Result<(), DBError> {
+        let mut access = Access::default();
+        self.collect_stmt(&ast.stmt, &mut access);
+
+        for object in &access.reads {
+            self.require(PrivilegeAction::Read, object, None)?;
+        }
+        for (object, properties) in &access.read_properties {
+            match properties {
+                Some(properties) => {
+                    for property in properties {
+                        self.require(PrivilegeAction::Read, object, Some(property))?;
+                    }
+                }
+                None => self.require_all(PrivilegeAction::Read, object)?,
+            }
+        }
+        for (object, scope) in &access.writes {
+            match scope {
+                WriteScope::All => self.require_all(PrivilegeAction::Write, object)?,
+                WriteScope::Properties(properties) => {
+                    self.require(PrivilegeAction::Write, object, None)?;
+                    for property in properties {
+                        self.require(PrivilegeAction::Write, object, Some(property))?;
+                    }
+                }
+            }
+        }
+        for property in &access.unresolved_reads {
+            self.check_unqualified(property, &access.reads)?;
+        }
+        for property in &access.unresolved_writes {
+            for object in self.candidates(property, &access.reads) {
+                self.require(PrivilegeAction::Write, &object, Some(property))?;
+            }
+        }
+        if access.delete_all_tags || access.delete_all_edges {
+            let schema = self.schema.as_ref().ok_or_else(|| {
+                DBError::permission(
+                    "Permission denied: deleting vertices requires the space schema".to_string(),
+                )
+            })?;
+            for object in schema.keys() {
+                let covered = match object {
+                    PrivilegeObject::Tag(_) => access.delete_all_tags,
+                    PrivilegeObject::Edge(_) => access.delete_all_edges,
+                };
+                if covered {
+                    self.require_all(PrivilegeAction::Write, object)?;
+                }
+            }
+        }
+
+        for meta in ast.expr_context.expressions() {
+            self.check_expression(meta.inner(), &access.reads)?;
+        }
+        Ok(())
+    }
+
+    fn require(
+        &self,
+        action: PrivilegeAction,
+        object: &PrivilegeObject,
+        property: Option<&str>,
+    ) -> Result<(), DBError> {
+        let allowed = match property {
+            Some(property) => self.privileges.allows_property(action, object, property),
+            None => self.privileges.allows(action, object),
+        };
+        if allowed {
+            return Ok(());
+        }
+        Err(DBError::permission(match property {
+            Some(property) => format!("Permission denied: {} on {}({})", action, object, property),
+            None => format!("Permission denied: {} on {}", action, object),
+        }))
+    }
+
+    fn require_all(
+        &self,
+        action: PrivilegeAction,
+        object: &PrivilegeObject,
+    ) -> Result<(), DBError> {
+        if self.privileges.allows_all_properties(action, object) {
+            Ok(())
+        } else {
+            Err(DBError::permission(format!(
+                "Permission denied: {} on all properties of {}",
+                action, object
+            )))
+        }
+    }
+
+    fn has_property(&self, object: &PrivilegeObject, property: &str) -> bool {
+        match &self.schema {
+            Some(schema) => schema
+                .get(object)
+                .map(|properties| properties.contains(property))
+                .unwrap_or(false),
+            None => true,
+        }
+    }
+
+    /// Tags and edge types an unqualified `property` may belong to
+    fn candidates(&self, property: &str, referenced: &[PrivilegeObject]) -> Vec<PrivilegeObject> {
+        if !referenced.is_empty() {
+            return referenced
+                .iter()
+                .filter(|object| self.has_property(object, property))
+                .cloned()
+                .collect();
+        }
+        self.schema
+            .iter()
+            .flatten()
+            .filter(|(_, properties)| properties.contains(property))
+            .map(|(object, _)| object.clone())
+            .collect()
+    }
+
+    /// Resolve a name used as a property qualifier to a tag or edge type
+    fn qualifier(&self, name: &str, referenced: &[PrivilegeObject]) -> Option<PrivilegeObject> {
+        let known = |object: &PrivilegeObject| {
+            referenced.contains(object)
+                || self
+                    .schema
+                    .as_ref()
+                    .map(|schema| schema.contains_key(object))
+                    .unwrap_or(false)
+        };
+        let tag = PrivilegeObject::Tag(name.to_string());
+        if known(&tag) {
+            return Some(tag);
+        }
+        let edge = PrivilegeObject::Edge(name.to_string());
+        known(&edge).then_some(edge)
+    }
+
+    fn check_expression(
+        &self,
+        expr: &Expression,
+        referenced: &[PrivilegeObject],
+    ) -> Result<(), DBError> {
+        match expr {
+            Expression::TagProperty { tag_name, property } => {
+                let object = PrivilegeObject::Tag(tag_name.clone());
+                return self.require(PrivilegeAction::Read, &object, Some(property));
+            }
+            Expression::EdgeProperty {
+                edge_name,
+                property,
+            } => {
+                let object = PrivilegeObject::Edge(edge_name.clone());
+                return self.require(PrivilegeAction::Read, &object, Some(property));
+            }
+            Expression::LabelTagProperty { tag, property } => {
+                if let Expression::Label(name) | Expression::Variable(name) = tag.as_ref() {
+                    if let Some(object) = self.qualifier(name, referenced) {
+                        return self.require(PrivilegeAction::Read, &object, Some(property));
+                    }
+                }
+                self.check_unqualified(property, referenced)?;
+            }
+            Expression::Property { object, property } => match object.as_ref() {
+                // `$-.prop` and `$var.prop` read rows that were checked when produced
+                Expression::Variable(name)
+                    if name.starts_with('$') && name != "$^" && name != "$$" =>
+                {
+                    return Ok(());
+                }
+                Expression::Property {
+                    object: base,
+                    property: qualifier,
+                } => {
+                    if let Some(target) = self.qualifier(qualifier, referenced) {
+                        self.require(PrivilegeAction::Read, &target, Some(property))?;
+                        return self.check_expression(base, referenced);
+                    }
+                    self.check_unqualified(property, referenced)?;
+                }
+                _ => self.check_unqualified(property, referenced)?,
+            },
+            Expression::Function { name, .. }
+                if name.eq_ignore_ascii_case("properties") || name.eq_ignore_ascii_case("keys") =>
+            {
+                let objects: Vec<PrivilegeObject> = if referenced.is_empty() {
+                    self.schema.iter().flat_map(|s| s.keys().cloned()).collect()
+                } else {
+                    referenced.to_vec()
+                };
+                for object in &objects {
+                    self.require_all(PrivilegeAction::Read, object)?;
+                }
+            }
+            _ => {}
+        }
+
+        for child in expr.children() {
+            self.check_expression(child, referenced)?;
+        }
+        Ok(())
+    }
+
+    fn check_unqualified(
+        &self,
+        property: &str,
+        referenced: &[PrivilegeObject],
+    ) -> Result<(), DBError> {
+        for object in self.candidates(property, referenced) {
+            self.require(PrivilegeAction::Read, &object, Some(property))?;
+        }
+        Ok(())
+    }
+
+    fn collect_stmt(&self, stmt: &Stmt, access: &mut Access) {
+        match stmt {
+            Stmt::Query(query) => {
+                for stmt in &query.statements {
+                    self.collect_stmt(stmt, access);
+                }
+            }
+            Stmt::Pipe(pipe) => {
+                self.collect_stmt(&pipe.left, access);
+                self.collect_stmt(&pipe.right, access);
+            }
+            Stmt::SetOperation(set_op) => {
+                self.collect_stmt(&set_op.left, access);
+                self.collect_stmt(&set_op.right, access);
+            }
+            Stmt::Explain(explain) => self.collect_stmt(&explain.statement, access),
+            Stmt::Profile(profile) => self.collect_stmt(&profile.statement, access),
+            Stmt::Assignment(assignment) => self.collect_stmt(&assignment.statement, access),
+            Stmt::CallSubquery(call) => self.collect_stmt(&call.body, access),
+            Stmt::MaterializedView(view) => {
+                if let MaterializedViewAction::Create { statement, .. } = &view.action {
+                    self.collect_stmt(statement, access);
+                }
+            }
+            Stmt::Foreach(foreach) => {
+                for stmt in &foreach.body {
+                    self.collect_stmt(stmt, access);
+                }
+            }
+            Stmt::Match(match_stmt) => {
+                let first_read = access.reads.len();
+                for pattern in &match_stmt.patterns {
+                    Self::collect_pattern_reads(pattern, access);
+                }
+                if let Some(ref delete) = match_stmt.delete_clause {
+                    match delete.target {
+                        MatchDeleteTarget::Vertices(_) => {
+                            access.delete_all_tags = true;
+                            access.delete_all_edges |= delete.with_edge;
+                        }
+                        _ => {
+                            let edges: Vec<PrivilegeObject> = access.reads[first_read..]
+                                .iter()
+                                .filter(|o| matches!(o, PrivilegeObject::Edge(_)))
+                                .cloned()
+                                .collect();
+                            if edges.is_empty() {
+                                access.delete_all_edges = true;
+                            }
+                            for edge in edges {
+                                access.writes.push((edge, WriteScope::All));
+                            }
+                        }
+                    }
+                }
+            }
+            Stmt::Go(go) => self.collect_over(go.over.as_ref(), access),
+            Stmt::Subgraph(subgraph) => self.collect_over(subgraph.over.as_ref(), access),
+            Stmt::FindPath(find_path) => self.collect_over(find_path.over.as_ref(), access),
+            Stmt::Fetch(fetch) => match &fetch.target {
+                FetchTarget::Vertices {
+                    tag_name,
+                    properties,
+                    ..
+                } => match tag_name {
+                    Some(tag_name) => {
+                        let object = PrivilegeObject::Tag(tag_name.clone());
+                        access.reads.push(object.clone());
+                        access.read_properties.push((object, properties.clone()));
+                    }
+                    None => {
+                        for object in self.schema.iter().flat_map(|s| s.keys()) {
+                            if matches!(object, PrivilegeObject::Tag(_)) {
+                                access.read_properties.push((object.clone(), None));
+                            }
+                        }
+                    }
+                },
+                FetchTarget::Edges {
+                    edge_type,
+                    properties,
+                    ..
+                } => {
+                    let object = PrivilegeObject::Edge(edge_type.clone());
+                    access.reads.push(object.clone());
+                    access.read_properties.push((object, properties.clone()));
+                }
+            },
+            Stmt::Lookup(lookup) => {
+                let object = match &lookup.target {
+                    LookupTarget::Tag(name) => PrivilegeObject::Tag(name.clone()),
+                    LookupTarget::Edge(name) => PrivilegeObject::Edge(name.clone()),
+                    LookupTarget::Unspecified(name) => self
+                        .qualifier(name, &[])
+                        .unwrap_or_else(|| PrivilegeObject::Tag(name.clone())),
+                };
+                access.reads.push(object);
+            }
+            Stmt::Insert(insert) => match &insert.target {
+                InsertTarget::Vertices { tags, .. } => {
+                    for tag in tags {
+                        access.writes.push((
+                            PrivilegeObject::Tag(tag.tag_name.clone()),
+                            WriteScope::Properties(tag.prop_names.clone()),
+                        ));
+                    }
+                }
+                InsertTarget::Edge {
+                    edge_name,
+                    prop_names,
+                    ..
+                } => access.writes.push((
+                    PrivilegeObject::Edge(edge_name.clone()),
+                    WriteScope::Properties(prop_names.clone()),
+                )),
+            },
+            Stmt::Update(update) => {
+                let properties = Self::assigned_properties(&update.set_clause.assignments);
+                let object = match &update.target {
+                    UpdateTarget::Tag(tag_name) | UpdateTarget::TagOnVertex { tag_name, .. } => {
+                        Some(PrivilegeObject::Tag(tag_name.clone()))
+                    }
+                    UpdateTarget::Edge {
+                        edge_type: Some(edge_type),
+                        ..
+                    } => Some(PrivilegeObject::Edge(edge_type.clone())),
+                    _ => None,
+                };
+                match object {
+                    Some(object) => access
+                        .writes
+                        .push((object, WriteScope::Properties(properties))),
+                    None => access.unresolved_writes.extend(properties),
+                }
+            }
+            Stmt::Delete(delete) => match &delete.target {
+                DeleteTarget::Vertices(_) => {
+                    access.delete_all_tags = true;
+                    access.delete_all_edges |= delete.with_edge;
+                }
+                DeleteTarget::Edges { edge_type, .. } => match edge_type {
+                    Some(edge_type) => access
+                        .writes
+                        .push((PrivilegeObject::Edge(edge_type.clone()), WriteScope::All)),
+                    None => access.delete_all_edges = true,
+                },
+                DeleteTarget::Tags {
+                    tag_names,
+                    is_all_tags,
+                    ..
+                } => {
+                    if *is_all_tags {
+                        access.delete_all_tags = true;
+                    }
+                    for tag_name in tag_names {
+                        access
+                            .writes
+                            .push((PrivilegeObject::Tag(tag_name.clone()), WriteScope::All));
+                    }
+                }
+                DeleteTarget::Index(_) => {}
+            },
+            Stmt::Create(create) => match &create.target {
+                CreateTarget::Node {
+                    labels, properties, ..
+                } => {
+                    for label in labels {
+                        access.writes.push((
+                            PrivilegeObject::Tag(label.clone()),
+                            Self::map_scope(properties.as_ref()),
+                        ));
+                    }
+                }
+                CreateTarget::Edge {
+                    edge_type,
+                    properties,
+                    ..
+                } => access.writes.push((
+                    PrivilegeObject::Edge(edge_type.clone()),
+                    Self::map_scope(properties.as_ref()),
+                )),
+                CreateTarget::Path { patterns } => {
+                    for pattern in patterns {
+                        Self::collect_pattern_writes(pattern, access);
+                    }
+                }
+                _ => {}
+            },
+            Stmt::Merge(merge) => {
+                Self::collect_pattern_reads(&merge.pattern, access);
+                Self::collect_pattern_writes(&merge.pattern, access);
+                for set_clause in [&merge.on_create, &merge.on_match].into_iter().flatten() {
+                    access
+                        .unresolved_writes
+                        .extend(Self::assigned_properties(&set_clause.assignments));
+                }
+            }
+            Stmt::Set(set) => access
+                .unresolved_writes
+                .extend(Self::assigned_properties(&set.assignments)),
+            Stmt::Remove(remove) => {
+                for item in &remove.items {
+                    match item.expression().as_ref().map(|meta| meta.inner()) {
+                        Some(Expression::Property { property, .. }) => {
+                            access.unresolved_writes.push(property.clone())
+                        }
+                        Some(Expression::Label(label)) => access
+                            .writes
+                            .push((PrivilegeObject::Tag(label.clone()), WriteScope::All)),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_over(&self, over: Option<&OverClause>, access: &mut Access) {
+        let Some(over) = over else {
+            return;
+        };
+        for edge_type in &over.edge_types {
+            if edge_type == "*" {
+                access.reads.extend(
+                    self.schema
+                        .iter()
+                        .flat_map(|s| s.keys())
+                        .filter(|o| matches!(o, PrivilegeObject::Edge(_)))
+                        .cloned(),
+                );
+            } else {
+                access.reads.push(PrivilegeObject::Edge(edge_type.clone()));
+            }
+        }
+    }
+
+    /// Tags or edge types of every node and edge in a pattern, with its inline property map
+    fn pattern_items(pattern: &Pattern) -> Vec<PatternItem<'_>> {
+        let mut items = Vec::new();
+        Self::collect_pattern_items(pattern, &mut items);
+        items
+    }
+
+    fn collect_pattern_items<'p>(pattern: &'p Pattern, items: &mut Vec<PatternItem<'p>>) {
+        match pattern {
+            Pattern::Node(node) => items.push((
+                node.labels
+                    .iter()
+                    .map(|label| PrivilegeObject::Tag(label.clone()))
+                    .collect(),
+                node.properties.as_ref(),
+            )),
+            Pattern::Edge(edge) => items.push((
+                edge.edge_types
+                    .iter()
+                    .map(|edge_type| PrivilegeObject::Edge(edge_type.clone()))
+                    .collect(),
+                edge.properties.as_ref(),
+            )),
+            Pattern::Path(path) => {
+                for element in &path.elements {
+                    Self::collect_element_items(element, items);
+                }
+            }
+            Pattern::Variable(_) => {}
+        }
+    }
+
+    fn collect_element_items<'p>(element: &'p PathElement, items: &mut Vec<PatternItem<'p>>) {
+        match element {
+            PathElement::Node(node) => items.push((
+                node.labels
+                    .iter()
+                    .map(|label| PrivilegeObject::Tag(label.clone()))
+                    .collect(),
+                node.properties.as_ref(),
+            )),
+            PathElement::Edge(edge) => items.push((
+                edge.edge_types
+                    .iter()
+                    .map(|edge_type| PrivilegeObject::Edge(edge_type.clone()))
+                    .collect(),
+                edge.properties.as_ref(),
+            )),
+            PathElement::Alternative(patterns) => {
+                for pattern in patterns {
+                    Self::collect_pattern_items(pattern, items);
+                }
+            }
+            PathElement::Optional(element) | PathElement::Repeated(element, _) => {
+                Self::collect_element_items(element, items)
+            }
+        }
+    }
+
+    /// Objects matched by a pattern; inline `{k: v}` maps filter on their keys
+    fn collect_pattern_reads(pattern: &Pattern, access: &mut Access) {
+        for (objects, properties) in Self::pattern_items(pattern) {
+            if let WriteScope::Properties(keys) = Self::map_scope(properties) {
+                if !keys.is_empty() {
+                    if objects.is_empty() {
+                        access.unresolved_reads.extend(keys);
+                    } else {
+                        for object in &objects {
+                            access
+                                .read_properties
+                                .push((object.clone(), Some(keys.clone())));
+                        }
+                    }
+                }
+            }
+            access.reads.extend(objects);
+        }
+    }
+
+    /// Writes of a CREATE/MERGE pattern, with the properties of its inline maps
+    fn collect_pattern_writes(pattern: &Pattern, access: &mut Access) {
+        for (objects, properties) in Self::pattern_items(pattern) {
+            for object in objects {
+                access.writes.push((object, Self::map_scope(properties)));
+            }
+        }
+    }
+
+    /// Property names of an inline `{k: v}` map; anything else may set any property
+    fn map_scope(properties: Option<&ContextualExpression>) -> WriteScope {
+        let Some(properties) = properties else {
+            return WriteScope::Properties(Vec::new());
+        };
+        match properties.expression().as_ref().map(|meta| meta.inner()) {
+            Some(Expression::Map(pairs)) => {
+                WriteScope::Properties(pairs.iter().map(|(key, _)| key.clone()).collect())
+            }
+            _ => WriteScope::All,
+        }
+    }
+
+    fn assigned_properties(assignments: &[Assignment]) -> Vec<String> {
+        assignments.iter().map(|a| a.property.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CustomRole, PrivilegeGrant};
+    use crate::query::parser::Parser;
+
+    fn analyst() -> EffectivePrivileges {
+        let mut role = CustomRole::new("analyst".to_string());
+        role.add_grant(
+            PrivilegeGrant::new(
+                PrivilegeAction::Read,
+                PrivilegeObject::Tag("person".to_string()),
+            )
+            .with_properties(vec!["name".to_string(), "age".to_string()]),
+        );
+        role.add_grant(PrivilegeGrant::new(
+            PrivilegeAction::Write,
+            PrivilegeObject::Edge("click".to_string()),
+        ));
+        let roles = HashMap::from([(role.name.clone(), role)]);
+        EffectivePrivileges::resolve(&["analyst".to_string()], &roles)
+    }
+
+    fn check(query: &str) -> Result<(), DBError> {
+        let privileges = analyst();
+        let ast = Parser::new(query).parse().expect("query should parse").ast;
+        PrivilegeChecker::new(&privileges).check(&ast)
+    }
+
+    #[test]
+    fn test_read_granted_properties() {
+        assert!(check("MATCH (v:person) RETURN v.name, v.age").is_ok());
+        assert!(check("MATCH (v:person) WHERE v.age > 30 RETURN v.name").is_ok());
+    }
+
+    #[test]
+    fn test_hidden_property_cannot_be_projected_or_filtered() {
+        assert!(check("MATCH (v:person) RETURN v.ssn").is_err());
+        assert!(check("MATCH (v:person) WHERE v.ssn == '1' RETURN v.name").is_err());
+        assert!(check("MATCH (v:person) RETURN properties(v)").is_err());
+        assert!(check("MATCH (v:person {ssn: '1'}) RETURN v.name").is_err());
+    }
+
+    #[test]
+    fn test_ungranted_objects_are_rejected() {
+        assert!(check("MATCH (v:company) RETURN v").is_err());
+        assert!(check("INSERT EDGE click(ts) VALUES 1 -> 2:(1)").is_ok());
+        assert!(check("INSERT EDGE follow(degree) VALUES 1 -> 2:(1)").is_err());
+        assert!(check("INSERT VERTEX person(name) VALUES 1:('Tom')").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Data permission required by a statement
+//! Walks every clause of a parsed statement, including pipes, `CALL { }` bodies
+//! and `FOREACH` bodies, so a write anywhere in the statement is not hidden
+//! behind a leading read clause such as `MATCH` or `UNWIND`.
+
+use crate::core::Permission;
+use crate::query::parser::ast::stmt::{CreateTarget, Stmt};
+
+/// Strongest data permission the clauses of `stmt` need.
+///
+/// `procedure` maps the name of a `CALL`ed procedure to the permission of its
+/// mode. Schema and administration statements are not classified here and
+/// count as reads.
+pub fn required_permission(stmt: &Stmt, procedure: &dyn Fn(&str) -> Permission) -> Permission {
+    let mut permission = Permission::Read;
+    collect(stmt, procedure, &mut permission);
+    permission
+}
+
+fn rank(permission: Permission) -> u8 {
+    match permission {
+        Permission::Read => 0,
+        Permission::Write => 1,
+        Permission::Delete => 2,
+        Permission::Schema => 3,
+        Permission::Admin => 4,
+    }
+}
+
+fn raise(current: &mut Permission, required: Permission) {
+    if rank(required) > rank(*current) {
+        *current = required;
+    }
+}
+
+fn collect(stmt: &Stmt, procedure: &dyn Fn(&str) -> Permission, permission: &mut Permission) {
+    match stmt {
+        Stmt::Query(query) => {
+            for stmt in &query.statements {
+                collect(stmt, procedure, permission);
+            }
+        }
+        Stmt::Pipe(pipe) => {
+            collect(&pipe.left, procedure, permission);
+            collect(&pipe.right, procedure, permission);
+        }
+        Stmt::SetOperation(set_op) => {
+            collect(&set_op.left, procedure, permission);
+            collect(&set_op.right, procedure, permission);
+        }
+        Stmt::Explain(explain) => collect(&explain.statement, procedure, permission),
+        Stmt::Profile(profile) => collect(&profile.statement, procedure, permission),
+        Stmt::Assignment(assignment) => collect(&assignment.statement, procedure, permission),
+        // CALL { } subqueries and FOREACH exist to run writes; their bodies may
+        // still delete or call a stronger procedure
+        Stmt::CallSubquery(call) => {
+            raise(permission, Permission::Write);
+            collect(&call.body, procedure, permission);
+        }
+        Stmt::Foreach(foreach) => {
+            raise(permission, Permission::Write);
+            for stmt in &foreach.body {
+                collect(stmt, procedure, permission);
+            }
+        }
+        Stmt::Call(call) => raise(permission, procedure(&call.procedure)),
+        Stmt::Create(create) => {
+            if matches!(
+                create.target,
+                CreateTarget::Node { .. } | CreateTarget::Edge { .. } | CreateTarget::Path { .. }
+            ) {
+                raise(permission, Permission::Write);
+            }
+        }
+        Stmt::Insert(_) | Stmt::Update(_) | Stmt::Merge(_) | Stmt::Set(_) | Stmt::Remove(_) => {
+            raise(permission, Permission::Write)
+        }
+        Stmt::Delete(_) => raise(permission, Permission::Delete),
+        Stmt::Match(match_stmt) if match_stmt.delete_clause.is_some() => {
+            raise(permission, Permission::Delete)
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::Parser;
+
+    fn permission_of(query: &str) -> Permission {
+        let result = Parser::new(query)
+            .parse()
+            .unwrap_or_else(|e| panic!("{} should parse: {:?}", query, e));
+        required_permission(&result.ast.stmt, &|name| {
+            if name.ends_with(".write") {
+                Permission::Write
+            } else {
+                Permission::Read
+            }
+        })
+    }
+
+    #[test]
+    fn test_reads() {
+        assert_eq!(permission_of("MATCH (n:person) RETURN n"), Permission::Read);
+        assert_eq!(
+            permission_of("GO FROM 1 OVER follow YIELD dst(edge) AS id"),
+            Permission::Read
+        );
+        assert_eq!(permission_of("CALL db.labels()"), Permission::Read);
+    }
+
+    #[test]
+    fn test_writes_behind_read_clauses() {
+        assert_eq!(
+            permission_of("UNWIND $rows AS r CALL { CREATE (:person {name: r}) } IN TRANSACTIONS"),
+            Permission::Write
+        );
+        assert_eq!(
+            permission_of(
+                "MATCH (n:person) RETURN n.name AS name | CALL { CREATE (:person {name: 'x'}) }"
+            ),
+            Permission::Write
+        );
+        assert_eq!(
+            permission_of("MATCH (n:person) FOREACH (x IN [1, 2] | CREATE (:person {age: x}))"),
+            Permission::Write
+        );
+        assert_eq!(
+            permission_of("GO FROM 1 OVER follow YIELD dst(edge) AS id | CALL algo.rank.write()"),
+            Permission::Write
+        );
+        assert_eq!(
+            permission_of("CALL algo . rank . write()"),
+            Permission::Write
+        );
+    }
+
+    #[test]
+    fn test_delete_outranks_write() {
+        assert_eq!(
+            permission_of("MATCH (n:person) WHERE n.age > 100 DELETE VERTEX n"),
+            Permission::Delete
+        );
+    }
+}
@@ -76,11 +76,11 @@ pub use validator_trait::{
 
 // Export a statement-level verifier
 pub use statements::{
-    CreateValidator, DeleteValidator, FetchEdgesValidator, FetchVerticesValidator,
-    FindPathValidator, GetSubgraphValidator, GoValidator, InsertEdgesValidator,
-    InsertVerticesValidator, LookupValidator, MatchValidator, MergeValidator, RemoveValidator,
-    SetItem, SetStatementType, SetValidator, UnwindValidator, UpdateValidator, ValidatedSet,
-    ValidatedSetItem, ValidatedUnwind,
+    CallSubqueryValidator, CallValidator, CreateValidator, DeleteValidator, FetchEdgesValidator,
+    FetchVerticesValidator, FindPathValidator, ForeachValidator, GetSubgraphValidator, GoValidator,
+    InsertEdgesValidator, InsertVerticesValidator, LookupValidator, MatchValidator, MergeValidator,
+    RemoveValidator, SetItem, SetStatementType, SetValidator, UnwindValidator, UpdateValidator,
+    ValidatedSet, ValidatedSetItem, ValidatedUnwind,
 };
 
 // Export the clause-level validator
@@ -107,10 +107,10 @@ pub use dml::{
 
 // Export Tool Validator
 pub use utility::{
-    AlterUserValidator, ChangePasswordValidator, CreateUserValidator, DescribeUserValidator,
-    DropUserValidator, ExplainValidator, GrantValidator, ProfileValidator, RevokeValidator,
-    ShowRolesValidator, ShowUsersValidator, UpdateConfigsValidator, ValidatedExplain,
-    ValidatedGrant, ValidatedUser,
+    AlterUserValidator, ApiKeyValidator, ChangePasswordValidator, CreateRoleValidator,
+    CreateUserValidator, DescribeUserValidator, DropUserValidator, ExplainValidator,
+    GrantPrivilegeValidator, GrantValidator, ProfileValidator, RevokeValidator, ShowRolesValidator,
+    ShowUsersValidator, UpdateConfigsValidator, ValidatedExplain, ValidatedGrant, ValidatedUser,
 };
 
 // Export assistance tools
@@ -0,0 +1,191 @@
+//! CALL subquery validator
+//! Verify the statement `CALL { <statement> } [IN TRANSACTIONS [OF <n> ROWS] [ON ERROR ...]]`.
+//!
+//! The body is planned like a standalone statement and executed once per input row,
+//! so only the constraints of the per-row execution are checked here:
+//! the body cannot control transactions itself and `IN TRANSACTIONS` cannot be nested.
+
+use crate::query::parser::ast::stmt::{Ast, CallSubqueryStmt, InTransactions, Stmt};
+use crate::query::validator::error::{ValidationError, ValidationErrorType};
+use crate::query::validator::structs::validation_info::ValidationInfo;
+use crate::query::validator::validator_trait::{
+    ColumnDef, ExpressionProps, StatementType, StatementValidator, ValidationResult, ValueType,
+};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// CALL Subquery Validator
+#[derive(Debug)]
+pub struct CallSubqueryValidator {
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl CallSubqueryValidator {
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    /// Reject statements that cannot run inside the body
+    fn validate_body(stmt: &Stmt) -> Result<(), ValidationError> {
+        match stmt {
+            Stmt::BeginTransaction(_)
+            | Stmt::CommitTransaction(_)
+            | Stmt::RollbackTransaction(_) => Err(ValidationError::new(
+                format!("{} is not allowed inside CALL {{ }}", stmt.kind()),
+                ValidationErrorType::SemanticError,
+            )),
+            Stmt::CallSubquery(inner) if inner.in_transactions.is_some() => {
+                Err(ValidationError::new(
+                    "CALL { } IN TRANSACTIONS cannot be nested".to_string(),
+                    ValidationErrorType::SemanticError,
+                ))
+            }
+            Stmt::CallSubquery(inner) => Self::validate_body(&inner.body),
+            Stmt::Pipe(pipe) => {
+                Self::validate_body(&pipe.left)?;
+                Self::validate_body(&pipe.right)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_impl(&mut self, stmt: &CallSubqueryStmt) -> Result<(), ValidationError> {
+        Self::validate_body(&stmt.body)?;
+
+        self.outputs.clear();
+        if let Some(in_transactions) = &stmt.in_transactions {
+            if in_transactions.batch_size == 0 {
+                return Err(ValidationError::new(
+                    "Batch size of IN TRANSACTIONS must be positive".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+            let types = [
+                ValueType::Int,
+                ValueType::Int,
+                ValueType::Bool,
+                ValueType::String,
+            ];
+            self.outputs = InTransactions::STATISTICS_COLUMNS
+                .iter()
+                .zip(types)
+                .map(|(name, type_)| ColumnDef {
+                    name: name.to_string(),
+                    type_,
+                })
+                .collect();
+        }
+        Ok(())
+    }
+}
+
+impl StatementValidator for CallSubqueryValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let call_stmt = match &ast.stmt {
+            Stmt::CallSubquery(call_stmt) => call_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected CALL subquery statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(call_stmt)?;
+
+        let mut info = ValidationInfo::new();
+        info.semantic_info.query_type = Some("CallSubquery".to_string());
+        info.semantic_info.space_name = qctx.space_name();
+
+        Ok(ValidationResult::success_with_info(info))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::CallSubquery
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        false
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for CallSubqueryValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::parsing::Parser;
+
+    fn parse_call_subquery(query: &str) -> CallSubqueryStmt {
+        let mut parser = Parser::new(query);
+        match parser.parse().expect("query should parse").ast.stmt.clone() {
+            Stmt::CallSubquery(call_stmt) => call_stmt,
+            other => panic!("expected CALL subquery, got {}", other.kind()),
+        }
+    }
+
+    #[test]
+    fn test_call_subquery_validator_new() {
+        let validator = CallSubqueryValidator::new();
+        assert_eq!(validator.statement_type(), StatementType::CallSubquery);
+        assert!(!validator.is_global_statement());
+    }
+
+    #[test]
+    fn test_in_transactions_outputs_statistics() {
+        let mut validator = CallSubqueryValidator::new();
+        let stmt = parse_call_subquery("CALL { CREATE (:Person {name: 'a'}) } IN TRANSACTIONS");
+        assert!(validator.validate_impl(&stmt).is_ok());
+        let names: Vec<&str> = validator
+            .outputs()
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, InTransactions::STATISTICS_COLUMNS);
+
+        let stmt = parse_call_subquery("CALL { CREATE (:Person {name: 'a'}) }");
+        assert!(validator.validate_impl(&stmt).is_ok());
+        assert!(validator.outputs().is_empty());
+    }
+
+    #[test]
+    fn test_nested_in_transactions_rejected() {
+        let mut validator = CallSubqueryValidator::new();
+        let stmt = parse_call_subquery(
+            "CALL { CALL { CREATE (:Person {name: 'a'}) } IN TRANSACTIONS } IN TRANSACTIONS",
+        );
+        assert!(validator.validate_impl(&stmt).is_err());
+    }
+}
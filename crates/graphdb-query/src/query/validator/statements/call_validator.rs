@@ -0,0 +1,146 @@
+//! CALL statement validator
+//! Verify the statement `CALL <procedure>(<args>) [YIELD <column> [AS <alias>], ... [WHERE ...]]`.
+//!
+//! The procedure itself is resolved at execution time against the procedure registry,
+//! so the validator only checks the parts that are independent of the signature:
+//! yielded items must be plain column names and the resulting names must be unique.
+
+use crate::core::types::expr::contextual::ContextualExpression;
+use crate::core::Expression;
+use crate::query::parser::ast::stmt::{Ast, CallStmt, Stmt};
+use crate::query::validator::error::{ValidationError, ValidationErrorType};
+use crate::query::validator::structs::validation_info::ValidationInfo;
+use crate::query::validator::validator_trait::{
+    ColumnDef, ExpressionProps, StatementType, StatementValidator, ValidationResult, ValueType,
+};
+use crate::query::QueryContext;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// CALL Statement Validator
+#[derive(Debug)]
+pub struct CallValidator {
+    procedure: String,
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl CallValidator {
+    pub fn new() -> Self {
+        Self {
+            procedure: String::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    /// Name of the yielded procedure column, if the item is a plain name
+    fn yielded_column(expression: &ContextualExpression) -> Option<String> {
+        match expression.get_expression()? {
+            Expression::Variable(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn validate_impl(&mut self, stmt: &CallStmt) -> Result<(), ValidationError> {
+        if stmt.procedure.is_empty() {
+            return Err(ValidationError::new(
+                "Procedure name cannot be empty".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+        self.procedure = stmt.procedure.clone();
+        self.outputs.clear();
+
+        let Some(yield_clause) = &stmt.yield_clause else {
+            return Ok(());
+        };
+
+        let mut seen = HashSet::new();
+        for item in &yield_clause.items {
+            let column = Self::yielded_column(&item.expression).ok_or_else(|| {
+                ValidationError::new(
+                    format!(
+                        "YIELD of procedure {} only accepts output column names",
+                        stmt.procedure
+                    ),
+                    ValidationErrorType::SemanticError,
+                )
+            })?;
+            let name = item.alias.clone().unwrap_or(column);
+            if !seen.insert(name.clone()) {
+                return Err(ValidationError::new(
+                    format!("Duplicate column name in YIELD: {}", name),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+            self.outputs.push(ColumnDef {
+                name: name.clone(),
+                type_: ValueType::Unknown,
+            });
+            self.user_defined_vars.push(name);
+        }
+        Ok(())
+    }
+}
+
+impl StatementValidator for CallValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let call_stmt = match &ast.stmt {
+            Stmt::Call(call_stmt) => call_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected CALL statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(call_stmt)?;
+
+        let mut info = ValidationInfo::new();
+        info.semantic_info.query_type = Some("Call".to_string());
+        info.semantic_info.space_name = qctx.space_name();
+
+        Ok(ValidationResult::success_with_info(info))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::Call
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        // Built-in procedures that read the schema check the space themselves.
+        true
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for CallValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
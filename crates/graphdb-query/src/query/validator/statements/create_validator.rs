@@ -723,10 +723,13 @@ impl CreateValidator {
                 ValidationErrorType::SemanticError,
             )),
             // The CREATE INDEX command is now processed by a dedicated component called CreateIndexValidator.
-            CreateTarget::Index { .. } => Err(ValidationError::new(
-                "CreateIndexValidator does not support this type of CREATE statement.".to_string(),
-                ValidationErrorType::SemanticError,
-            )),
+            CreateTarget::Index { .. } | CreateTarget::Constraint { .. } => {
+                Err(ValidationError::new(
+                    "CreateIndexValidator does not support this type of CREATE statement."
+                        .to_string(),
+                    ValidationErrorType::SemanticError,
+                ))
+            }
             // CREATE Node/Edge/Path: This operation requires additional storage space and involves the execution of DML (Data Manipulation Language) validation processes.
             CreateTarget::Node { .. } | CreateTarget::Edge { .. } | CreateTarget::Path { .. } => {
                 if space_name.is_empty() {
@@ -0,0 +1,176 @@
+//! FOREACH statement validator
+//! Verify the statement `FOREACH (<variable> IN <list> | <update clause> ...)`.
+//!
+//! The body may only contain update clauses; FOREACH produces no rows of its own
+//! and its variable is not visible after the closing parenthesis.
+
+use crate::query::parser::ast::stmt::{Ast, CreateTarget, ForeachStmt, Stmt};
+use crate::query::validator::error::{ValidationError, ValidationErrorType};
+use crate::query::validator::structs::validation_info::ValidationInfo;
+use crate::query::validator::validator_trait::{
+    ColumnDef, ExpressionProps, StatementType, StatementValidator, ValidationResult,
+};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// FOREACH Statement Validator
+#[derive(Debug)]
+pub struct ForeachValidator {
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl ForeachValidator {
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    /// Whether the statement is an update clause allowed in the body
+    fn is_update_clause(stmt: &Stmt) -> bool {
+        match stmt {
+            Stmt::Create(create) => matches!(
+                create.target,
+                CreateTarget::Node { .. } | CreateTarget::Edge { .. } | CreateTarget::Path { .. }
+            ),
+            Stmt::Set(_)
+            | Stmt::Merge(_)
+            | Stmt::Delete(_)
+            | Stmt::Remove(_)
+            | Stmt::Insert(_)
+            | Stmt::Update(_)
+            | Stmt::Foreach(_) => true,
+            _ => false,
+        }
+    }
+
+    fn validate_impl(&mut self, stmt: &ForeachStmt) -> Result<(), ValidationError> {
+        if stmt.variable.is_empty() {
+            return Err(ValidationError::new(
+                "FOREACH variable cannot be empty".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+        if stmt.body.is_empty() {
+            return Err(ValidationError::new(
+                "FOREACH requires at least one update clause".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+        for clause in &stmt.body {
+            if !Self::is_update_clause(clause) {
+                return Err(ValidationError::new(
+                    format!(
+                        "FOREACH only accepts update clauses, found {}",
+                        clause.kind()
+                    ),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+            if let Stmt::Foreach(inner) = clause {
+                self.validate_impl(inner)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StatementValidator for ForeachValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let foreach_stmt = match &ast.stmt {
+            Stmt::Foreach(foreach_stmt) => foreach_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected FOREACH statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(foreach_stmt)?;
+
+        let mut info = ValidationInfo::new();
+        info.semantic_info.query_type = Some("Foreach".to_string());
+        info.semantic_info.space_name = qctx.space_name();
+
+        Ok(ValidationResult::success_with_info(info))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::Foreach
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        false
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for ForeachValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::parser::parsing::Parser;
+
+    fn parse_foreach(query: &str) -> ForeachStmt {
+        let mut parser = Parser::new(query);
+        match parser.parse().expect("query should parse").ast.stmt.clone() {
+            Stmt::Foreach(foreach_stmt) => foreach_stmt,
+            other => panic!("expected FOREACH, got {}", other.kind()),
+        }
+    }
+
+    #[test]
+    fn test_foreach_validator_new() {
+        let validator = ForeachValidator::new();
+        assert_eq!(validator.statement_type(), StatementType::Foreach);
+        assert!(!validator.is_global_statement());
+    }
+
+    #[test]
+    fn test_update_clauses_accepted() {
+        let mut validator = ForeachValidator::new();
+        let stmt = parse_foreach(
+            "FOREACH (x IN [1, 2] | CREATE (:Item {id: x}) \
+             FOREACH (y IN [3] | CREATE (:Item {id: y})))",
+        );
+        assert!(validator.validate_impl(&stmt).is_ok());
+    }
+
+    #[test]
+    fn test_read_clause_rejected() {
+        let mut validator = ForeachValidator::new();
+        let stmt = parse_foreach("FOREACH (x IN [1, 2] | MATCH (n) RETURN n)");
+        assert!(validator.validate_impl(&stmt).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! Materialized View Statement Validator
+//!
+//! Checks the statements that define, refresh, list and scan materialized views.
+//! A view is defined by a single MATCH query with a RETURN clause; the query itself
+//! is validated when the view is first populated.
+
+use crate::query::parser::ast::stmt::{Ast, MaterializedViewAction, Stmt};
+use crate::query::validator::error::{ValidationError, ValidationErrorType};
+use crate::query::validator::structs::validation_info::ValidationInfo;
+use crate::query::validator::validator_trait::{
+    ColumnDef, ExpressionProps, StatementType, StatementValidator, ValidationResult,
+};
+use crate::query::QueryContext;
+use std::sync::Arc;
+
+/// Materialized view statement validator
+#[derive(Debug, Clone)]
+pub struct MaterializedViewValidator {
+    expression_props: ExpressionProps,
+}
+
+impl MaterializedViewValidator {
+    pub fn new() -> Self {
+        Self {
+            expression_props: ExpressionProps::default(),
+        }
+    }
+
+    fn validate_definition(statement: &Stmt) -> Result<(), ValidationError> {
+        match statement {
+            Stmt::Match(match_stmt) if match_stmt.return_clause.is_some() => Ok(()),
+            Stmt::Match(_) => Err(ValidationError::new(
+                "A materialized view query must end with a RETURN clause".to_string(),
+                ValidationErrorType::SemanticError,
+            )),
+            other => Err(ValidationError::new(
+                format!(
+                    "A materialized view must be defined by a MATCH query, found {}",
+                    other.kind()
+                ),
+                ValidationErrorType::SemanticError,
+            )),
+        }
+    }
+}
+
+impl Default for MaterializedViewValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatementValidator for MaterializedViewValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let Stmt::MaterializedView(view_stmt) = &ast.stmt else {
+            return Err(ValidationError::new(
+                "Expected a materialized view statement".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        };
+
+        if qctx.space_id().is_none() {
+            return Err(ValidationError::new(
+                "No image space selected, please execute first USE <space>".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        if let MaterializedViewAction::Create { statement, .. } = &view_stmt.action {
+            Self::validate_definition(statement)?;
+        }
+
+        let mut info = ValidationInfo::new();
+        info.semantic_info.space_name = qctx.space_name();
+        Ok(ValidationResult::success_with_info(info))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::MaterializedView
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &[]
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &[]
+    }
+
+    fn is_global_statement(&self) -> bool {
+        false
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expression_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &[]
+    }
+}
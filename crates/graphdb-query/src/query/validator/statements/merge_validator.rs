@@ -410,6 +410,12 @@ impl MergeValidator {
             }
             Expression::Parameter(_) => Ok(()),
             Expression::Vector(_) => Ok(()),
+            Expression::PatternSubquery(subquery) => {
+                for expr in subquery.expressions() {
+                    self.validate_expression_recursive(expr)?;
+                }
+                Ok(())
+            }
         }
     }
 
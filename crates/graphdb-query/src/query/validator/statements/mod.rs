@@ -1,14 +1,18 @@
+pub mod call_subquery_validator;
+pub mod call_validator;
 pub mod create_validator;
 pub mod delete_validator;
 pub mod fetch_edges_validator;
 pub mod fetch_vertices_validator;
 pub mod find_path_validator;
+pub mod foreach_validator;
 pub mod get_subgraph_validator;
 pub mod go_validator;
 pub mod insert_edges_validator;
 pub mod insert_vertices_validator;
 pub mod lookup_validator;
 pub mod match_validator;
+pub mod materialized_view_validator;
 pub mod merge_validator;
 pub mod remove_validator;
 pub mod set_validator;
@@ -17,17 +21,21 @@ pub mod unwind_validator;
 pub mod update_validator;
 
 pub use crate::query::validator::statements::transaction_validator::TransactionValidator;
+pub use call_subquery_validator::CallSubqueryValidator;
+pub use call_validator::CallValidator;
 pub use create_validator::CreateValidator;
 pub use delete_validator::DeleteValidator;
 pub use fetch_edges_validator::FetchEdgesValidator;
 pub use fetch_vertices_validator::FetchVerticesValidator;
 pub use find_path_validator::FindPathValidator;
+pub use foreach_validator::ForeachValidator;
 pub use get_subgraph_validator::GetSubgraphValidator;
 pub use go_validator::GoValidator;
 pub use insert_edges_validator::InsertEdgesValidator;
 pub use insert_vertices_validator::InsertVerticesValidator;
 pub use lookup_validator::LookupValidator;
 pub use match_validator::MatchValidator;
+pub use materialized_view_validator::MaterializedViewValidator;
 pub use merge_validator::MergeValidator;
 pub use remove_validator::RemoveValidator;
 pub use set_validator::{SetItem, SetStatementType, SetValidator, ValidatedSet, ValidatedSetItem};
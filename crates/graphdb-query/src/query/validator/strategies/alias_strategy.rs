@@ -147,7 +147,8 @@ impl AliasValidationStrategy {
             | crate::core::types::expr::Expression::List(_)
             | crate::core::types::expr::Expression::Map(_)
             | crate::core::types::expr::Expression::Vector(_)
-            | crate::core::types::expr::Expression::Predicate { .. } => None,
+            | crate::core::types::expr::Expression::Predicate { .. }
+            | crate::core::types::expr::Expression::PatternSubquery(_) => None,
         }
     }
 
@@ -216,7 +217,8 @@ impl AliasValidationStrategy {
             | crate::core::types::expr::Expression::Reduce { .. }
             | crate::core::types::expr::Expression::PathBuild(_)
             | crate::core::types::expr::Expression::Parameter(_)
-            | crate::core::types::expr::Expression::Vector(_) => Ok(()),
+            | crate::core::types::expr::Expression::Vector(_)
+            | crate::core::types::expr::Expression::PatternSubquery(_) => Ok(()),
             crate::core::types::expr::Expression::TypeCast { expression, .. } => {
                 // Type conversion expressions require that their subexpressions be validated.
                 self.validate_expression_aliases_internal(expression, aliases)
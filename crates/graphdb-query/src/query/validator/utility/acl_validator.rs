@@ -8,8 +8,9 @@
 //! 3. Verify the user's existence and the legitimacy of their role.
 
 use crate::query::parser::ast::stmt::{
-    AlterUserStmt, Ast, ChangePasswordStmt, CreateUserStmt, DescribeUserStmt, DropUserStmt,
-    GrantStmt, RevokeStmt, RoleType, ShowRolesStmt, ShowUsersStmt,
+    AlterUserStmt, ApiKeyAction, ApiKeyStmt, Ast, ChangePasswordStmt, CreateRoleStmt,
+    CreateUserStmt, DescribeUserStmt, DropUserStmt, GrantPrivilegeKind, GrantPrivilegeStmt,
+    GrantStmt, RevokeStmt, RoleType, ShowRolesStmt, ShowUsersStmt, UserQuotaOptions,
 };
 use crate::query::validator::error::{ValidationError, ValidationErrorType};
 use crate::query::validator::structs::validation_info::ValidationInfo;
@@ -276,6 +277,7 @@ pub struct AlterUserValidator {
     password: Option<String>,
     new_role: Option<String>,
     is_locked: Option<bool>,
+    quota: UserQuotaOptions,
     inputs: Vec<ColumnDef>,
     outputs: Vec<ColumnDef>,
     expr_props: ExpressionProps,
@@ -289,6 +291,7 @@ impl AlterUserValidator {
             password: None,
             new_role: None,
             is_locked: None,
+            quota: UserQuotaOptions::default(),
             inputs: Vec::new(),
             outputs: vec![ColumnDef {
                 name: "Result".to_string(),
@@ -304,6 +307,7 @@ impl AlterUserValidator {
         self.password = stmt.password.clone();
         self.new_role = stmt.new_role.clone();
         self.is_locked = stmt.is_locked;
+        self.quota = stmt.quota.clone();
 
         // Verify that the username is not empty.
         if self.username.is_empty() {
@@ -314,7 +318,11 @@ impl AlterUserValidator {
         }
 
         // Verify that there is at least one modification.
-        if self.password.is_none() && self.new_role.is_none() && self.is_locked.is_none() {
+        if self.password.is_none()
+            && self.new_role.is_none()
+            && self.is_locked.is_none()
+            && self.quota.is_empty()
+        {
             return Err(ValidationError::new(
                 "At least one modification is required".to_string(),
                 ValidationErrorType::SemanticError,
@@ -762,6 +770,346 @@ impl Default for RevokeValidator {
     }
 }
 
+/// CREATE ROLE statement validator
+#[derive(Debug)]
+pub struct CreateRoleValidator {
+    role_name: String,
+    parents: Vec<String>,
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl CreateRoleValidator {
+    pub fn new() -> Self {
+        Self {
+            role_name: String::new(),
+            parents: Vec::new(),
+            inputs: Vec::new(),
+            outputs: vec![ColumnDef {
+                name: "Result".to_string(),
+                type_: ValueType::String,
+            }],
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    fn validate_impl(&mut self, stmt: &CreateRoleStmt) -> Result<(), ValidationError> {
+        self.role_name = stmt.role_name.clone();
+        self.parents = stmt.parents.clone();
+
+        if self.role_name.is_empty() {
+            return Err(ValidationError::new(
+                "Role name cannot be empty".to_string(),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        // Built-in role names stay reserved for the space-scoped roles
+        if self.role_name.parse::<RoleType>().is_ok() {
+            return Err(ValidationError::new(
+                format!("Role name {} is reserved", self.role_name),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        if self.parents.contains(&self.role_name) {
+            return Err(ValidationError::new(
+                format!("Role {} cannot inherit from itself", self.role_name),
+                ValidationErrorType::SemanticError,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl StatementValidator for CreateRoleValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        _qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let create_role_stmt = match &ast.stmt {
+            crate::query::parser::ast::Stmt::CreateRole(create_role_stmt) => create_role_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected CREATE ROLE statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(create_role_stmt)?;
+
+        Ok(ValidationResult::success_with_info(ValidationInfo::new()))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::CreateRole
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        true
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for CreateRoleValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Validator for the fine-grained GRANT forms (privileges to roles, custom roles to users)
+#[derive(Debug)]
+pub struct GrantPrivilegeValidator {
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl GrantPrivilegeValidator {
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: vec![ColumnDef {
+                name: "Result".to_string(),
+                type_: ValueType::String,
+            }],
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    fn validate_impl(&mut self, stmt: &GrantPrivilegeStmt) -> Result<(), ValidationError> {
+        match &stmt.kind {
+            GrantPrivilegeKind::Privilege { grant, role_name } => {
+                if role_name.is_empty() {
+                    return Err(ValidationError::new(
+                        "Role name cannot be empty".to_string(),
+                        ValidationErrorType::SemanticError,
+                    ));
+                }
+                if let Some(ref properties) = grant.properties {
+                    if properties.is_empty() {
+                        return Err(ValidationError::new(
+                            "Property list of a GRANT cannot be empty".to_string(),
+                            ValidationErrorType::SemanticError,
+                        ));
+                    }
+                    for (i, property) in properties.iter().enumerate() {
+                        if properties[..i].contains(property) {
+                            return Err(ValidationError::new(
+                                format!("Duplicate property {} in GRANT", property),
+                                ValidationErrorType::SemanticError,
+                            ));
+                        }
+                    }
+                }
+            }
+            GrantPrivilegeKind::Role {
+                role_name,
+                username,
+            } => {
+                if role_name.is_empty() || username.is_empty() {
+                    return Err(ValidationError::new(
+                        "Role name and username cannot be empty".to_string(),
+                        ValidationErrorType::SemanticError,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl StatementValidator for GrantPrivilegeValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        _qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let grant_stmt = match &ast.stmt {
+            crate::query::parser::ast::Stmt::GrantPrivilege(grant_stmt) => grant_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected GRANT statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(grant_stmt)?;
+
+        Ok(ValidationResult::success_with_info(ValidationInfo::new()))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::GrantPrivilege
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        true
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for GrantPrivilegeValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CREATE API KEY / REVOKE API KEY / SHOW API KEYS statement validator
+#[derive(Debug)]
+pub struct ApiKeyValidator {
+    inputs: Vec<ColumnDef>,
+    outputs: Vec<ColumnDef>,
+    expr_props: ExpressionProps,
+    user_defined_vars: Vec<String>,
+}
+
+impl ApiKeyValidator {
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            expr_props: ExpressionProps::default(),
+            user_defined_vars: Vec::new(),
+        }
+    }
+
+    fn string_columns(names: &[&str]) -> Vec<ColumnDef> {
+        names
+            .iter()
+            .map(|name| ColumnDef {
+                name: name.to_string(),
+                type_: ValueType::String,
+            })
+            .collect()
+    }
+
+    fn validate_impl(&mut self, stmt: &ApiKeyStmt) -> Result<(), ValidationError> {
+        self.outputs = match &stmt.action {
+            ApiKeyAction::Create { username, .. } => {
+                if username.is_empty() {
+                    return Err(ValidationError::new(
+                        "Username cannot be empty".to_string(),
+                        ValidationErrorType::SemanticError,
+                    ));
+                }
+                Self::string_columns(&["Id", "Username", "Scopes", "Expires At", "Token"])
+            }
+            ApiKeyAction::Revoke { key_id } => {
+                if key_id.is_empty() {
+                    return Err(ValidationError::new(
+                        "API key id cannot be empty".to_string(),
+                        ValidationErrorType::SemanticError,
+                    ));
+                }
+                Self::string_columns(&["Result"])
+            }
+            ApiKeyAction::Show { .. } => Self::string_columns(&[
+                "Id",
+                "Username",
+                "Scopes",
+                "Created At",
+                "Expires At",
+                "Status",
+            ]),
+        };
+
+        Ok(())
+    }
+}
+
+impl StatementValidator for ApiKeyValidator {
+    fn validate(
+        &mut self,
+        ast: Arc<Ast>,
+        _qctx: Arc<QueryContext>,
+    ) -> Result<ValidationResult, ValidationError> {
+        let api_key_stmt = match &ast.stmt {
+            crate::query::parser::ast::Stmt::ApiKey(api_key_stmt) => api_key_stmt,
+            _ => {
+                return Err(ValidationError::new(
+                    "Expected API KEY statement".to_string(),
+                    ValidationErrorType::SemanticError,
+                ));
+            }
+        };
+
+        self.validate_impl(api_key_stmt)?;
+
+        Ok(ValidationResult::success_with_info(ValidationInfo::new()))
+    }
+
+    fn statement_type(&self) -> StatementType {
+        StatementType::ApiKey
+    }
+
+    fn inputs(&self) -> &[ColumnDef] {
+        &self.inputs
+    }
+
+    fn outputs(&self) -> &[ColumnDef] {
+        &self.outputs
+    }
+
+    fn is_global_statement(&self) -> bool {
+        true
+    }
+
+    fn expression_props(&self) -> &ExpressionProps {
+        &self.expr_props
+    }
+
+    fn user_defined_vars(&self) -> &[String] {
+        &self.user_defined_vars
+    }
+}
+
+impl Default for ApiKeyValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// “DESCRIBE USER” statement validator
 #[derive(Debug)]
 pub struct DescribeUserValidator {
@@ -786,6 +1134,10 @@ impl DescribeUserValidator {
                     name: "Roles".to_string(),
                     type_: ValueType::String,
                 },
+                ColumnDef {
+                    name: "Privileges".to_string(),
+                    type_: ValueType::String,
+                },
             ],
             expr_props: ExpressionProps::default(),
             user_defined_vars: Vec::new(),
@@ -1252,6 +1604,7 @@ mod tests {
             password: Some("newpass".to_string()),
             new_role: None,
             is_locked: None,
+            quota: Default::default(),
         };
         assert!(validator.validate_impl(&stmt).is_err());
     }
@@ -1265,6 +1618,7 @@ mod tests {
             password: Some("newpass123".to_string()),
             new_role: None,
             is_locked: None,
+            quota: Default::default(),
         };
         assert!(validator.validate_impl(&stmt).is_ok());
     }
@@ -1278,6 +1632,7 @@ mod tests {
             password: None,
             new_role: Some("ADMIN".to_string()),
             is_locked: None,
+            quota: Default::default(),
         };
         assert!(validator.validate_impl(&stmt).is_ok());
     }
@@ -1291,10 +1646,28 @@ mod tests {
             password: None,
             new_role: Some("INVALID".to_string()),
             is_locked: None,
+            quota: Default::default(),
         };
         assert!(validator.validate_impl(&stmt).is_err());
     }
 
+    #[test]
+    fn test_alter_user_validator_quota_only() {
+        let mut validator = AlterUserValidator::new();
+        let stmt = AlterUserStmt {
+            span: Default::default(),
+            username: "testuser".to_string(),
+            password: None,
+            new_role: None,
+            is_locked: None,
+            quota: UserQuotaOptions {
+                max_queries_per_hour: Some(1000),
+                ..Default::default()
+            },
+        };
+        assert!(validator.validate_impl(&stmt).is_ok());
+    }
+
     // ==================== ChangePasswordValidator Tests ====================
 
     #[test]
@@ -1396,4 +1769,51 @@ mod tests {
         };
         assert!(validator.validate_impl(&stmt).is_ok());
     }
+
+    // ==================== Custom Role Tests ====================
+
+    #[test]
+    fn test_create_role_validator_rejects_reserved_and_self_inheritance() {
+        let mut validator = CreateRoleValidator::new();
+        let mut stmt = CreateRoleStmt {
+            span: Default::default(),
+            role_name: "analyst".to_string(),
+            parents: vec!["reader".to_string()],
+            if_not_exists: false,
+        };
+        assert!(validator.validate_impl(&stmt).is_ok());
+
+        stmt.role_name = "admin".to_string();
+        assert!(validator.validate_impl(&stmt).is_err());
+
+        stmt.role_name = "reader".to_string();
+        assert!(validator.validate_impl(&stmt).is_err());
+    }
+
+    #[test]
+    fn test_grant_privilege_validator_duplicate_property() {
+        use crate::core::{PrivilegeAction, PrivilegeGrant, PrivilegeObject};
+
+        let mut validator = GrantPrivilegeValidator::new();
+        let grant = PrivilegeGrant::new(
+            PrivilegeAction::Read,
+            PrivilegeObject::Tag("person".to_string()),
+        );
+        let mut stmt = GrantPrivilegeStmt {
+            span: Default::default(),
+            kind: GrantPrivilegeKind::Privilege {
+                grant: grant
+                    .clone()
+                    .with_properties(vec!["name".to_string(), "age".to_string()]),
+                role_name: "analyst".to_string(),
+            },
+        };
+        assert!(validator.validate_impl(&stmt).is_ok());
+
+        stmt.kind = GrantPrivilegeKind::Privilege {
+            grant: grant.with_properties(vec!["name".to_string(), "name".to_string()]),
+            role_name: "analyst".to_string(),
+        };
+        assert!(validator.validate_impl(&stmt).is_err());
+    }
 }
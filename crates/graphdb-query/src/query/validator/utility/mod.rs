@@ -3,9 +3,10 @@ pub mod explain_validator;
 pub mod update_config_validator;
 
 pub use acl_validator::{
-    AlterUserValidator, ChangePasswordValidator, CreateUserValidator, DescribeUserValidator,
-    DropUserValidator, GrantValidator, RevokeValidator, ShowRolesValidator, ShowUsersValidator,
-    ValidatedGrant, ValidatedUser,
+    AlterUserValidator, ApiKeyValidator, ChangePasswordValidator, CreateRoleValidator,
+    CreateUserValidator, DescribeUserValidator, DropUserValidator, GrantPrivilegeValidator,
+    GrantValidator, RevokeValidator, ShowRolesValidator, ShowUsersValidator, ValidatedGrant,
+    ValidatedUser,
 };
 pub use explain_validator::{ExplainValidator, ProfileValidator, ValidatedExplain};
 pub use update_config_validator::UpdateConfigsValidator;
@@ -34,17 +34,21 @@ use crate::query::validator::dml::query_validator::QueryValidator;
 use crate::query::validator::dml::set_operation_validator::SetOperationValidator;
 use crate::query::validator::dml::use_validator::UseValidator;
 use crate::query::validator::fulltext_validator::FulltextValidator;
+use crate::query::validator::statements::call_subquery_validator::CallSubqueryValidator;
+use crate::query::validator::statements::call_validator::CallValidator;
 use crate::query::validator::statements::create_validator::CreateValidator;
 use crate::query::validator::statements::delete_validator::DeleteValidator;
 use crate::query::validator::statements::fetch_edges_validator::FetchEdgesValidator;
 use crate::query::validator::statements::fetch_vertices_validator::FetchVerticesValidator;
 use crate::query::validator::statements::find_path_validator::FindPathValidator;
+use crate::query::validator::statements::foreach_validator::ForeachValidator;
 use crate::query::validator::statements::get_subgraph_validator::GetSubgraphValidator;
 use crate::query::validator::statements::go_validator::GoValidator;
 use crate::query::validator::statements::insert_edges_validator::InsertEdgesValidator;
 use crate::query::validator::statements::insert_vertices_validator::InsertVerticesValidator;
 use crate::query::validator::statements::lookup_validator::LookupValidator;
 use crate::query::validator::statements::match_validator::MatchValidator;
+use crate::query::validator::statements::materialized_view_validator::MaterializedViewValidator;
 use crate::query::validator::statements::merge_validator::MergeValidator;
 use crate::query::validator::statements::remove_validator::RemoveValidator;
 use crate::query::validator::statements::set_validator::SetValidator;
@@ -52,8 +56,9 @@ use crate::query::validator::statements::transaction_validator::TransactionValid
 use crate::query::validator::statements::unwind_validator::UnwindValidator;
 use crate::query::validator::statements::update_validator::UpdateValidator;
 use crate::query::validator::utility::acl_validator::{
-    AlterUserValidator, ChangePasswordValidator, CreateUserValidator, DescribeUserValidator,
-    DropUserValidator, GrantValidator, RevokeValidator, ShowRolesValidator, ShowUsersValidator,
+    AlterUserValidator, ApiKeyValidator, ChangePasswordValidator, CreateRoleValidator,
+    CreateUserValidator, DescribeUserValidator, DropUserValidator, GrantPrivilegeValidator,
+    GrantValidator, RevokeValidator, ShowRolesValidator, ShowUsersValidator,
 };
 use crate::query::validator::utility::explain_validator::{ExplainValidator, ProfileValidator};
 use crate::query::validator::utility::update_config_validator::UpdateConfigsValidator;
@@ -97,6 +102,9 @@ pub enum Validator {
     Grant(GrantValidator),
     /// REVOKE statement validator
     Revoke(RevokeValidator),
+    CreateRole(CreateRoleValidator),
+    GrantPrivilege(GrantPrivilegeValidator),
+    ApiKey(ApiKeyValidator),
     /// The “DESCRIBE USER” statement validator ensures that the provided user information is valid and meets the required criteria. It performs various checks to verify the accuracy, completeness, and consistency of the user data, such as checking the username, password, email address, and other relevant fields. If the user data is invalid or does not meet the specified requirements, the validator generates an error message indicating the issues with the data. This validation process helps to maintain the security and integrity of the system by preventing unauthorized access to user accounts.
     DescribeUser(DescribeUserValidator),
     /// SHOW USERS Statement Validator
@@ -163,6 +171,12 @@ pub enum Validator {
     Remove(RemoveValidator),
     /// UNWIND Statement Validator
     Unwind(UnwindValidator),
+    /// CALL Statement Validator
+    Call(CallValidator),
+    /// CALL Subquery Validator
+    CallSubquery(CallSubqueryValidator),
+    /// FOREACH Statement Validator
+    Foreach(ForeachValidator),
 
     // Clause type validator
     /// ORDER BY statement validator
@@ -197,6 +211,8 @@ pub enum Validator {
     Vector(VectorValidator),
     /// Transaction statement validator
     Transaction(TransactionValidator),
+    /// Materialized view statement validator
+    MaterializedView(MaterializedViewValidator),
 }
 
 impl Validator {
@@ -216,6 +232,9 @@ impl Validator {
             Validator::ChangePassword(v) => v.statement_type(),
             Validator::Grant(v) => v.statement_type(),
             Validator::Revoke(v) => v.statement_type(),
+            Validator::CreateRole(v) => v.statement_type(),
+            Validator::GrantPrivilege(v) => v.statement_type(),
+            Validator::ApiKey(v) => v.statement_type(),
             Validator::DescribeUser(v) => v.statement_type(),
             Validator::ShowUsers(v) => v.statement_type(),
             Validator::ShowRoles(v) => v.statement_type(),
@@ -246,6 +265,9 @@ impl Validator {
             Validator::Merge(v) => v.statement_type(),
             Validator::Remove(v) => v.statement_type(),
             Validator::Unwind(v) => v.statement_type(),
+            Validator::Call(v) => v.statement_type(),
+            Validator::CallSubquery(v) => v.statement_type(),
+            Validator::Foreach(v) => v.statement_type(),
             Validator::OrderBy(v) => v.statement_type(),
             Validator::GroupBy(v) => v.statement_type(),
             Validator::Yield(v) => v.statement_type(),
@@ -260,6 +282,7 @@ impl Validator {
             Validator::Fulltext(v) => v.statement_type(),
             Validator::Vector(v) => v.statement_type(),
             Validator::Transaction(v) => v.statement_type(),
+            Validator::MaterializedView(v) => v.statement_type(),
         }
     }
 
@@ -305,6 +328,15 @@ impl Validator {
             Validator::Revoke(v) => v
                 .validate(ast, qctx)
                 .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::CreateRole(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::GrantPrivilege(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::ApiKey(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
             Validator::DescribeUser(v) => v
                 .validate(ast, qctx)
                 .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
@@ -395,6 +427,15 @@ impl Validator {
             Validator::Unwind(v) => v
                 .validate(ast, qctx)
                 .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::Call(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::CallSubquery(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::Foreach(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
             Validator::OrderBy(v) => v
                 .validate(ast, qctx)
                 .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
@@ -437,6 +478,9 @@ impl Validator {
             Validator::Transaction(v) => v
                 .validate(ast, qctx)
                 .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
+            Validator::MaterializedView(v) => v
+                .validate(ast, qctx)
+                .unwrap_or_else(|e| ValidationResult::failure(vec![e])),
         }
     }
 
@@ -456,6 +500,9 @@ impl Validator {
             Validator::ChangePassword(v) => v.inputs().to_vec(),
             Validator::Grant(v) => v.inputs().to_vec(),
             Validator::Revoke(v) => v.inputs().to_vec(),
+            Validator::CreateRole(v) => v.inputs().to_vec(),
+            Validator::GrantPrivilege(v) => v.inputs().to_vec(),
+            Validator::ApiKey(v) => v.inputs().to_vec(),
             Validator::DescribeUser(v) => v.inputs().to_vec(),
             Validator::ShowUsers(v) => v.inputs().to_vec(),
             Validator::ShowRoles(v) => v.inputs().to_vec(),
@@ -486,6 +533,9 @@ impl Validator {
             Validator::Merge(v) => v.inputs().to_vec(),
             Validator::Remove(v) => v.inputs().to_vec(),
             Validator::Unwind(v) => v.inputs().to_vec(),
+            Validator::Call(v) => v.inputs().to_vec(),
+            Validator::CallSubquery(v) => v.inputs().to_vec(),
+            Validator::Foreach(v) => v.inputs().to_vec(),
             Validator::OrderBy(v) => v.inputs().to_vec(),
             Validator::GroupBy(v) => v.inputs().to_vec(),
             Validator::Yield(v) => v.inputs().to_vec(),
@@ -500,6 +550,7 @@ impl Validator {
             Validator::Fulltext(v) => v.inputs().to_vec(),
             Validator::Vector(v) => v.inputs().to_vec(),
             Validator::Transaction(v) => v.inputs().to_vec(),
+            Validator::MaterializedView(v) => v.inputs().to_vec(),
         }
     }
 
@@ -519,6 +570,9 @@ impl Validator {
             Validator::ChangePassword(v) => v.outputs().to_vec(),
             Validator::Grant(v) => v.outputs().to_vec(),
             Validator::Revoke(v) => v.outputs().to_vec(),
+            Validator::CreateRole(v) => v.outputs().to_vec(),
+            Validator::GrantPrivilege(v) => v.outputs().to_vec(),
+            Validator::ApiKey(v) => v.outputs().to_vec(),
             Validator::DescribeUser(v) => v.outputs().to_vec(),
             Validator::ShowUsers(v) => v.outputs().to_vec(),
             Validator::ShowRoles(v) => v.outputs().to_vec(),
@@ -549,6 +603,9 @@ impl Validator {
             Validator::Merge(v) => v.outputs().to_vec(),
             Validator::Remove(v) => v.outputs().to_vec(),
             Validator::Unwind(v) => v.outputs().to_vec(),
+            Validator::Call(v) => v.outputs().to_vec(),
+            Validator::CallSubquery(v) => v.outputs().to_vec(),
+            Validator::Foreach(v) => v.outputs().to_vec(),
             Validator::OrderBy(v) => v.outputs().to_vec(),
             Validator::GroupBy(v) => v.outputs().to_vec(),
             Validator::Yield(v) => v.outputs().to_vec(),
@@ -563,6 +620,7 @@ impl Validator {
             Validator::Fulltext(v) => v.outputs().to_vec(),
             Validator::Vector(v) => v.outputs().to_vec(),
             Validator::Transaction(v) => v.outputs().to_vec(),
+            Validator::MaterializedView(v) => v.outputs().to_vec(),
         }
     }
 }
@@ -632,6 +690,9 @@ impl Validator {
             },
             Stmt::Merge(_) => StatementType::Merge,
             Stmt::Unwind(_) => StatementType::Unwind,
+            Stmt::Call(_) => StatementType::Call,
+            Stmt::CallSubquery(_) => StatementType::CallSubquery,
+            Stmt::Foreach(_) => StatementType::Foreach,
             Stmt::Return(_) => StatementType::Return,
             Stmt::With(_) => StatementType::With,
             Stmt::Yield(_) => StatementType::Yield,
@@ -647,6 +708,9 @@ impl Validator {
             Stmt::ChangePassword(_) => StatementType::ChangePassword,
             Stmt::Grant(_) => StatementType::Grant,
             Stmt::Revoke(_) => StatementType::Revoke,
+            Stmt::CreateRole(_) => StatementType::CreateRole,
+            Stmt::GrantPrivilege(_) => StatementType::GrantPrivilege,
+            Stmt::ApiKey(_) => StatementType::ApiKey,
             Stmt::DescribeUser(_) => StatementType::DescribeUser,
             Stmt::ShowUsers(_) => StatementType::ShowUsers,
             Stmt::ShowRoles(_) => StatementType::ShowRoles,
@@ -667,6 +731,7 @@ impl Validator {
                         StatementType::CreateEdgeIndex
                     }
                 },
+                CreateTarget::Constraint { .. } => StatementType::CreateTagIndex,
                 _ => StatementType::Create,
             },
             Stmt::Assignment(_) => StatementType::Assignment,
@@ -688,6 +753,12 @@ impl Validator {
             Stmt::SearchVector(_) => StatementType::SearchVector,
             Stmt::LookupVector(_) => StatementType::LookupVector,
             Stmt::MatchVector(_) => StatementType::MatchVector,
+            // Hybrid Search statements
+            Stmt::HybridSearch(_) => StatementType::HybridSearch,
+            // Index sync dead letter queue management
+            Stmt::SyncDlq(_) => StatementType::SyncDlq,
+            // Materialized views
+            Stmt::MaterializedView(_) => StatementType::MaterializedView,
             // Transaction statements
             Stmt::BeginTransaction(_) => StatementType::BeginTransaction,
             Stmt::CommitTransaction(_) => StatementType::CommitTransaction,
@@ -713,6 +784,11 @@ impl Validator {
             }
             StatementType::Grant => Validator::Grant(GrantValidator::new()),
             StatementType::Revoke => Validator::Revoke(RevokeValidator::new()),
+            StatementType::CreateRole => Validator::CreateRole(CreateRoleValidator::new()),
+            StatementType::GrantPrivilege => {
+                Validator::GrantPrivilege(GrantPrivilegeValidator::new())
+            }
+            StatementType::ApiKey => Validator::ApiKey(ApiKeyValidator::new()),
             StatementType::DescribeUser => Validator::DescribeUser(DescribeUserValidator::new()),
             StatementType::ShowUsers => Validator::ShowUsers(ShowUsersValidator::new()),
             StatementType::ShowRoles => Validator::ShowRoles(ShowRolesValidator::new()),
@@ -752,6 +828,9 @@ impl Validator {
             StatementType::Merge => Validator::Merge(MergeValidator::new()),
             StatementType::Remove => Validator::Remove(RemoveValidator::new()),
             StatementType::Unwind => Validator::Unwind(UnwindValidator::new()),
+            StatementType::Call => Validator::Call(CallValidator::new()),
+            StatementType::CallSubquery => Validator::CallSubquery(CallSubqueryValidator::new()),
+            StatementType::Foreach => Validator::Foreach(ForeachValidator::new()),
             StatementType::OrderBy => Validator::OrderBy(OrderByValidator::new()),
             StatementType::GroupBy => Validator::GroupBy(GroupByValidator::new()),
             StatementType::Yield => Validator::Yield(YieldValidator::new()),
@@ -770,7 +849,9 @@ impl Validator {
             | StatementType::DescribeFulltextIndex
             | StatementType::Search
             | StatementType::LookupFulltext
-            | StatementType::MatchFulltext => Validator::Fulltext(FulltextValidator::new()),
+            | StatementType::MatchFulltext
+            | StatementType::HybridSearch
+            | StatementType::SyncDlq => Validator::Fulltext(FulltextValidator::new()),
             StatementType::CreateVectorIndex
             | StatementType::DropVectorIndex
             | StatementType::SearchVector
@@ -786,6 +867,9 @@ impl Validator {
             StatementType::RollbackTransaction => Validator::Transaction(
                 TransactionValidator::new(StatementType::RollbackTransaction),
             ),
+            StatementType::MaterializedView => {
+                Validator::MaterializedView(MaterializedViewValidator::new())
+            }
             StatementType::DropSpace
             | StatementType::DropTag
             | StatementType::DropEdge
@@ -816,6 +900,9 @@ impl Validator {
             Validator::ChangePassword(v) => v.user_defined_vars(),
             Validator::Grant(v) => v.user_defined_vars(),
             Validator::Revoke(v) => v.user_defined_vars(),
+            Validator::CreateRole(v) => v.user_defined_vars(),
+            Validator::GrantPrivilege(v) => v.user_defined_vars(),
+            Validator::ApiKey(v) => v.user_defined_vars(),
             Validator::DescribeUser(v) => v.user_defined_vars(),
             Validator::ShowUsers(v) => v.user_defined_vars(),
             Validator::ShowRoles(v) => v.user_defined_vars(),
@@ -846,6 +933,9 @@ impl Validator {
             Validator::Merge(v) => v.user_defined_vars(),
             Validator::Remove(v) => v.user_defined_vars(),
             Validator::Unwind(v) => v.user_defined_vars(),
+            Validator::Call(v) => v.user_defined_vars(),
+            Validator::CallSubquery(v) => v.user_defined_vars(),
+            Validator::Foreach(v) => v.user_defined_vars(),
             Validator::OrderBy(v) => v.user_defined_vars(),
             Validator::GroupBy(v) => v.user_defined_vars(),
             Validator::Yield(v) => v.user_defined_vars(),
@@ -860,6 +950,7 @@ impl Validator {
             Validator::Fulltext(v) => v.user_defined_vars(),
             Validator::Vector(v) => v.user_defined_vars(),
             Validator::Transaction(v) => v.user_defined_vars(),
+            Validator::MaterializedView(v) => v.user_defined_vars(),
         }
     }
 
@@ -884,6 +975,9 @@ impl Validator {
             Validator::ChangePassword(v) => v.expression_props(),
             Validator::Grant(v) => v.expression_props(),
             Validator::Revoke(v) => v.expression_props(),
+            Validator::CreateRole(v) => v.expression_props(),
+            Validator::GrantPrivilege(v) => v.expression_props(),
+            Validator::ApiKey(v) => v.expression_props(),
             Validator::DescribeUser(v) => v.expression_props(),
             Validator::ShowUsers(v) => v.expression_props(),
             Validator::ShowRoles(v) => v.expression_props(),
@@ -914,6 +1008,9 @@ impl Validator {
             Validator::Merge(v) => v.expression_props(),
             Validator::Remove(v) => v.expression_props(),
             Validator::Unwind(v) => v.expression_props(),
+            Validator::Call(v) => v.expression_props(),
+            Validator::CallSubquery(v) => v.expression_props(),
+            Validator::Foreach(v) => v.expression_props(),
             Validator::OrderBy(v) => v.expression_props(),
             Validator::GroupBy(v) => v.expression_props(),
             Validator::Yield(v) => v.expression_props(),
@@ -928,6 +1025,7 @@ impl Validator {
             Validator::Fulltext(v) => v.expression_props(),
             Validator::Vector(v) => v.expression_props(),
             Validator::Transaction(v) => v.expression_props(),
+            Validator::MaterializedView(v) => v.expression_props(),
         }
     }
 }
@@ -175,6 +175,9 @@ pub enum StatementType {
     OrderBy,
     Limit,
     Unwind,
+    Call,
+    CallSubquery,
+    Foreach,
     Set,
     Sequential,
 
@@ -200,6 +203,9 @@ pub enum StatementType {
     ChangePassword,
     Grant,
     Revoke,
+    CreateRole,
+    GrantPrivilege,
+    ApiKey,
     DescribeUser,
     ShowUsers,
     ShowRoles,
@@ -237,6 +243,15 @@ pub enum StatementType {
     LookupVector,
     MatchVector,
 
+    // Hybrid Search statements
+    HybridSearch,
+
+    // Index sync dead letter queue management
+    SyncDlq,
+
+    // Materialized views
+    MaterializedView,
+
     // Transaction statements
     BeginTransaction,
     CommitTransaction,
@@ -287,6 +302,9 @@ impl StatementType {
             StatementType::OrderBy => "ORDER_BY",
             StatementType::Limit => "LIMIT",
             StatementType::Unwind => "UNWIND",
+            StatementType::Call => "CALL",
+            StatementType::CallSubquery => "CALL_SUBQUERY",
+            StatementType::Foreach => "FOREACH",
             StatementType::Set => "SET",
             StatementType::Sequential => "SEQUENTIAL",
 
@@ -312,6 +330,9 @@ impl StatementType {
             StatementType::ChangePassword => "CHANGE_PASSWORD",
             StatementType::Grant => "GRANT",
             StatementType::Revoke => "REVOKE",
+            StatementType::CreateRole => "CREATE_ROLE",
+            StatementType::GrantPrivilege => "GRANT_PRIVILEGE",
+            StatementType::ApiKey => "API_KEY",
             StatementType::DescribeUser => "DESCRIBE_USER",
             StatementType::ShowUsers => "SHOW_USERS",
             StatementType::ShowRoles => "SHOW_ROLES",
@@ -349,6 +370,15 @@ impl StatementType {
             StatementType::LookupVector => "LOOKUP_VECTOR",
             StatementType::MatchVector => "MATCH_VECTOR",
 
+            // Hybrid Search statements
+            StatementType::HybridSearch => "SEARCH_HYBRID",
+
+            // Index sync dead letter queue management
+            StatementType::SyncDlq => "SYNC_DLQ",
+
+            // Materialized views
+            StatementType::MaterializedView => "MATERIALIZED_VIEW",
+
             // Transaction statements
             StatementType::BeginTransaction => "BEGIN_TRANSACTION",
             StatementType::CommitTransaction => "COMMIT_TRANSACTION",
@@ -533,6 +563,7 @@ pub fn is_global_statement_type(stmt_type: StatementType) -> bool {
             | StatementType::ShowSpaces
             | StatementType::DescribeSpace
             | StatementType::Use
+            | StatementType::Call
             // Management statements
             | StatementType::Show
             | StatementType::ShowTags
@@ -543,6 +574,7 @@ pub fn is_global_statement_type(stmt_type: StatementType) -> bool {
             | StatementType::ShowSessions
             | StatementType::ShowQueries
             | StatementType::KillQuery
+            | StatementType::SyncDlq
             // Permission class statements
             | StatementType::CreateUser
             | StatementType::DropUser
@@ -550,6 +582,9 @@ pub fn is_global_statement_type(stmt_type: StatementType) -> bool {
             | StatementType::ChangePassword
             | StatementType::Grant
             | StatementType::Revoke
+            | StatementType::CreateRole
+            | StatementType::GrantPrivilege
+            | StatementType::ApiKey
             | StatementType::DescribeUser
             | StatementType::ShowUsers
             | StatementType::ShowRoles
@@ -3,7 +3,7 @@
 //! Test coverage:
 //! - ALTER TAG ADD - Add properties to tag
 //! - ALTER TAG DROP - Drop properties from tag
-//! - ALTER TAG CHANGE - Rename properties
+//! - ALTER TAG CHANGE - Rename properties and change their types
 
 use super::common;
 
@@ -62,6 +62,19 @@ fn test_alter_tag_parser_change() {
     assert_eq!(stmt.ast.stmt.kind(), "ALTER");
 }
 
+#[test]
+fn test_alter_tag_parser_change_type_force() {
+    let query = "ALTER TAG Person CHANGE (score score: FLOAT FORCE)";
+    let mut parser = Parser::new(query);
+
+    let result = parser.parse();
+    assert!(
+        result.is_ok(),
+        "ALTER TAG CHANGE ... FORCE parsing should succeed: {:?}",
+        result.err()
+    );
+}
+
 #[test]
 fn test_alter_tag_parser_add_single() {
     let query = "ALTER TAG Person ADD (email: STRING)";
@@ -193,3 +206,65 @@ fn test_alter_tag_change_with_data() {
             HashMap::from([("name", Value::String("Updated Alice".into()))]),
         );
 }
+
+#[test]
+fn test_alter_tag_change_type_with_data() {
+    TestScenario::new()
+        .expect("Failed to create test scenario")
+        .setup_space("test_space")
+        .exec_ddl("CREATE TAG Person(name: STRING, age: INT)")
+        .assert_success()
+        .exec_dml("INSERT VERTEX Person(name, age) VALUES 1:('Alice', 30)")
+        .assert_success()
+        .exec_ddl("ALTER TAG Person CHANGE (age age: BIGINT)")
+        .assert_success()
+        .query("DESC TAG Person")
+        .assert_success()
+        .assert_result_contains(vec![
+            Value::String("age".into()),
+            Value::String("BIGINT".into()),
+        ])
+        .assert_vertex_props(1, "Person", HashMap::from([("age", Value::BigInt(30))]));
+}
+
+#[test]
+fn test_alter_tag_change_type_lossy_requires_force() {
+    TestScenario::new()
+        .expect("Failed to create test scenario")
+        .setup_space("test_space")
+        .exec_ddl("CREATE TAG Person(name: STRING, score: DOUBLE)")
+        .assert_success()
+        .exec_dml("INSERT VERTEX Person(name, score) VALUES 1:('Alice', 1.5)")
+        .assert_success()
+        .exec_ddl("ALTER TAG Person CHANGE (score score: FLOAT)")
+        .assert_error()
+        .exec_ddl("ALTER TAG Person CHANGE (score score: FLOAT FORCE)")
+        .assert_success()
+        .assert_vertex_props(1, "Person", HashMap::from([("score", Value::Float(1.5))]));
+}
+
+#[test]
+fn test_alter_tag_failed_type_change_applies_nothing() {
+    TestScenario::new()
+        .expect("Failed to create test scenario")
+        .setup_space("test_space")
+        .exec_ddl("CREATE TAG Person(name: STRING, code: STRING)")
+        .assert_success()
+        .exec_dml("INSERT VERTEX Person(name, code) VALUES 1:('Alice', 'abc')")
+        .assert_success()
+        .exec_ddl(
+            "ALTER TAG Person ADD (email: STRING) CHANGE (name full_name: STRING, code code: INT)",
+        )
+        .assert_error()
+        .query("DESC TAG Person")
+        .assert_success()
+        .assert_result_count(2)
+        .assert_vertex_props(
+            1,
+            "Person",
+            HashMap::from([
+                ("name", Value::String("Alice".into())),
+                ("code", Value::String("abc".into())),
+            ]),
+        );
+}
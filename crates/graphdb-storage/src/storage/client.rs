@@ -1,12 +1,17 @@
 use crate::core::metadata::SchemaManager;
 use crate::core::types::TransactionContextInfo;
 use crate::core::types::{
-    EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo, LabelId, PasswordInfo, PropertyDef,
-    SpaceInfo, TagInfo, Timestamp, UpdateInfo, UserAlterInfo, UserInfo, VertexId, CompactConfig,
+    ApiKeyInfo, Constraint, EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo, LabelId, PasswordInfo,
+    PropertyDef, SpaceInfo, TagInfo, Timestamp, UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+    CompactConfig, LoginCheck, PasswordPolicy,
+};
+use crate::core::{
+    CustomRole, DataType, Edge, EdgeDirection, EffectivePrivileges, PrivilegeGrant, RoleType,
+    StorageError, StorageResult, Value, Vertex,
 };
-use crate::core::{Edge, EdgeDirection, RoleType, StorageError, StorageResult, Value, Vertex};
 use crate::storage::engine::background_freeze::FreezeStats;
 use crate::storage::engine::graph_storage::context::ExportedEdgeSnapshotRecord;
+use crate::storage::schema::PropertyTypeChange;
 use crate::transaction::wal::recovery::{RecoveryConfig, RecoveryStats};
 use crate::transaction::UndoTarget;
 use std::sync::Arc;
@@ -89,6 +94,7 @@ pub trait StorageReader: Send + Sync + std::fmt::Debug {
 
     fn get_tag_index(&self, space: &str, index: &str) -> Result<Option<Index>, StorageError>;
     fn list_tag_indexes(&self, space: &str) -> Result<Vec<Index>, StorageError>;
+    fn list_constraints(&self, space: &str) -> Result<Vec<Constraint>, StorageError>;
 }
 
 /// Write operations for vertex and edge data.
@@ -175,6 +181,15 @@ pub trait StorageSchemaOps: Send + Sync + std::fmt::Debug {
         old_name: &str,
         new_name: &str,
     ) -> Result<bool, StorageError>;
+    /// Change the types of tag properties, converting stored values. Either every
+    /// change applies or none does. Lossy conversions (e.g. DOUBLE to FLOAT) are
+    /// rejected unless the change sets `allow_lossy`.
+    fn change_tag_property_types(
+        &mut self,
+        space: &str,
+        tag: &str,
+        changes: &[PropertyTypeChange],
+    ) -> Result<bool, StorageError>;
     fn drop_tag(&mut self, space: &str, tag: &str) -> Result<bool, StorageError>;
 
     fn create_edge_type(&mut self, space: &str, edge: &EdgeTypeInfo) -> Result<u32, StorageError>;
@@ -190,6 +205,13 @@ pub trait StorageSchemaOps: Send + Sync + std::fmt::Debug {
     fn create_tag_index(&mut self, space: &str, info: &Index) -> Result<bool, StorageError>;
     fn drop_tag_index(&mut self, space: &str, index: &str) -> Result<bool, StorageError>;
     fn rebuild_tag_index(&mut self, space: &str, index: &str) -> Result<bool, StorageError>;
+
+    fn create_constraint(
+        &mut self,
+        space: &str,
+        constraint: &Constraint,
+    ) -> Result<bool, StorageError>;
+    fn drop_constraint(&mut self, space: &str, name: &str) -> Result<bool, StorageError>;
 }
 
 /// Authentication and authorization operations.
@@ -199,6 +221,13 @@ pub trait StorageAuthOps: Send + Sync + std::fmt::Debug {
     fn alter_user(&mut self, info: &UserAlterInfo) -> Result<bool, StorageError>;
     fn drop_user(&mut self, username: &str) -> Result<bool, StorageError>;
     fn user_exists(&self, username: &str) -> bool;
+    fn get_user(&self, username: &str) -> Option<UserInfo>;
+    /// Install the policy that new and changed passwords are checked against.
+    fn set_password_policy(&self, policy: PasswordPolicy);
+    /// Check a plaintext password for a new user against the password policy.
+    fn check_password_policy(&self, password: &str) -> Result<(), StorageError>;
+    /// Check a password login; failures count towards the stored account lockout.
+    fn verify_login(&self, username: &str, password: &str, max_failed_attempts: u32) -> LoginCheck;
     fn grant_role(
         &mut self,
         username: &str,
@@ -206,6 +235,26 @@ pub trait StorageAuthOps: Send + Sync + std::fmt::Debug {
         role: RoleType,
     ) -> Result<bool, StorageError>;
     fn revoke_role(&mut self, username: &str, space_id: u64) -> Result<bool, StorageError>;
+
+    /// Create a custom role; returns `false` if it already exists.
+    fn create_role(&mut self, role: &CustomRole) -> Result<bool, StorageError>;
+    fn grant_privilege(
+        &mut self,
+        role: &str,
+        grant: &PrivilegeGrant,
+    ) -> Result<bool, StorageError>;
+    fn grant_custom_role(&mut self, username: &str, role: &str) -> Result<bool, StorageError>;
+    fn user_custom_roles(&self, username: &str) -> Vec<CustomRole>;
+    /// Fine-grained privileges of a user, `None` when the user holds no custom role.
+    fn effective_privileges(&self, username: &str) -> Option<EffectivePrivileges>;
+
+    /// Store an issued API key; fails if its user does not exist.
+    fn create_api_key(&mut self, key: &ApiKeyInfo) -> Result<bool, StorageError>;
+    /// Revoke an API key; returns `false` if no such key exists.
+    fn revoke_api_key(&mut self, key_id: &str) -> Result<bool, StorageError>;
+    fn list_api_keys(&self, username: Option<&str>) -> Vec<ApiKeyInfo>;
+    /// Resolve a plaintext API key token to its active key.
+    fn verify_api_key(&self, token: &str) -> Option<ApiKeyInfo>;
 }
 
 /// Administrative operations: stats, maintenance, optional components.
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -8,7 +9,7 @@ use crate::core::metadata::{IndexManager, SchemaManager};
 use crate::transaction::VersionManager;
 use crate::core::types::{
     LabelId, TableTracker, TableTrackerConfig, Timestamp,
-    TransactionContextInfo,
+    TransactionContextInfo, TransactionId,
 };
 use crate::core::stats::StatsManager;
 use crate::core::UserStorage;
@@ -19,6 +20,7 @@ use crate::storage::engine::data_store::GraphDataStore;
 use crate::storage::engine::paths::StoragePaths;
 use crate::storage::engine::persistence_coordinator::PersistenceCoordinator;
 use crate::storage::index::{IndexDataManagerImpl, IndexGcConfig, IndexGcManager};
+use super::index_engine::UniqueKeyClaim;
 use crate::storage::vertex::IdKey;
 
 type LastCompactedVertices = Arc<Mutex<Vec<(LabelId, Vec<IdKey>)>>>;
@@ -213,12 +215,17 @@ impl DeferredWalOps {
 
 }
 
+/// Unique index keys claimed by transactions that have not finished yet,
+/// settled by `release_unique_claims` on abort and dropped on commit.
+type PendingUniqueClaims = Arc<Mutex<HashMap<TransactionId, Vec<UniqueKeyClaim>>>>;
+
 #[derive(Clone)]
 struct GraphStorageRuntime {
     current_txn_context: Arc<RwLock<Option<Arc<TransactionContextInfo>>>>,
     index_gc_manager: Option<Arc<IndexGcManager>>,
     background_freeze_manager: Option<Arc<BackgroundFreezeManager>>,
     deferred_wal_ops: DeferredWalOps,
+    unique_claims: PendingUniqueClaims,
 }
 
 impl GraphStorageRuntime {
@@ -228,6 +235,7 @@ impl GraphStorageRuntime {
             index_gc_manager: None,
             background_freeze_manager: None,
             deferred_wal_ops: DeferredWalOps::new(),
+            unique_claims: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -245,6 +253,7 @@ impl GraphStorageRuntime {
             index_gc_manager: Some(Arc::new(gc_manager)),
             background_freeze_manager: self.background_freeze_manager.clone(),
             deferred_wal_ops: self.deferred_wal_ops.clone(),
+            unique_claims: self.unique_claims.clone(),
         }
     }
 
@@ -257,6 +266,7 @@ impl GraphStorageRuntime {
             index_gc_manager: self.index_gc_manager.clone(),
             background_freeze_manager: Some(manager),
             deferred_wal_ops: self.deferred_wal_ops.clone(),
+            unique_claims: self.unique_claims.clone(),
         }
     }
 
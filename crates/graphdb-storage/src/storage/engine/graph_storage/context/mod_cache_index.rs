@@ -1,9 +1,10 @@
-use crate::core::types::{LabelId, Timestamp};
+use crate::core::types::{Index, LabelId, Timestamp, TransactionId};
 use crate::core::{StorageResult, Value};
 use crate::storage::edge::ExportedEdgeSnapshot;
 use crate::storage::engine::data_store::EdgeTableKey;
 use crate::storage::index::{GcStats, IndexGcOps};
 
+use super::super::index_engine::UniqueKeyClaim;
 use super::GraphStorageContext;
 
 pub struct ExportedEdgeSnapshotRecord {
@@ -43,6 +44,47 @@ impl GraphStorageContext {
         super::super::index_engine::delete_vertex_indexes_mvcc(self, space_id, vertex_id, index_names, ts)
     }
 
+    pub(crate) fn claim_unique_vertex_key(
+        &self,
+        space_id: u64,
+        vertex_id: &Value,
+        index: &Index,
+        key: Option<&Value>,
+        previous_key: Option<&Value>,
+        ts: Timestamp,
+    ) -> StorageResult<()> {
+        super::super::index_engine::claim_unique_vertex_key(
+            self,
+            space_id,
+            vertex_id,
+            index,
+            key,
+            previous_key,
+            ts,
+        )
+    }
+
+    pub(crate) fn record_unique_claim(&self, txn_id: TransactionId, claim: UniqueKeyClaim) {
+        self.runtime
+            .unique_claims
+            .lock()
+            .entry(txn_id)
+            .or_default()
+            .push(claim);
+    }
+
+    pub(crate) fn take_unique_claims(&self, txn_id: TransactionId) -> Vec<UniqueKeyClaim> {
+        self.runtime
+            .unique_claims
+            .lock()
+            .remove(&txn_id)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn release_unique_claims(&self, txn_id: TransactionId) -> StorageResult<()> {
+        super::super::index_engine::release_unique_claims(self, txn_id)
+    }
+
     pub(crate) fn gc_index_tombstones(&self, ts: Timestamp) -> StorageResult<GcStats> {
         self.persistent.index_data_manager.read().gc_tombstones(ts)
     }
@@ -1,7 +1,8 @@
-use crate::core::StorageResult;
+use crate::core::{DataType, StorageResult};
 use crate::core::types::LabelId;
 use crate::storage::engine::params::CreateEdgeTypeParams;
 use crate::storage::edge::EdgeStrategy;
+use crate::storage::schema::PropertyTypeChange;
 use crate::storage::types::StoragePropertyDef;
 
 use super::GraphStorageContext;
@@ -91,6 +92,30 @@ impl GraphStorageContext {
         super::super::schema_engine::rename_vertex_property(self, label, old_name, new_name)
     }
 
+    pub fn change_vertex_property_type(
+        &self,
+        label: LabelId,
+        prop_name: &str,
+        new_type: DataType,
+        allow_lossy: bool,
+    ) -> StorageResult<()> {
+        super::super::schema_engine::change_vertex_property_type(
+            self,
+            label,
+            prop_name,
+            new_type,
+            allow_lossy,
+        )
+    }
+
+    pub fn change_vertex_property_types(
+        &self,
+        label: LabelId,
+        changes: &[PropertyTypeChange],
+    ) -> StorageResult<()> {
+        super::super::schema_engine::change_vertex_property_types(self, label, changes)
+    }
+
     pub fn add_edge_property(
         &self,
         edge_label: LabelId,
@@ -1,6 +1,6 @@
-use crate::core::types::Timestamp;
-use crate::core::{StorageResult, Value};
-use crate::storage::index::VertexIndexOps;
+use crate::core::types::{Index, Timestamp, TransactionId};
+use crate::core::{StorageError, StorageResult, Value};
+use crate::storage::index::{IndexDataManagerImpl, VertexIndexOps};
 
 use super::context::GraphStorageContext;
 
@@ -31,3 +31,111 @@ pub fn delete_vertex_indexes_mvcc(
         ts,
     )
 }
+
+/// A unique key claimed by a transaction that has not finished yet
+#[derive(Debug, Clone)]
+pub(crate) struct UniqueKeyClaim {
+    pub space_id: u64,
+    pub vertex_id: Value,
+    pub index: Index,
+    /// Key the vertex held before the claim, restored if the transaction aborts
+    pub previous_key: Option<Value>,
+    pub ts: Timestamp,
+}
+
+/// Claim the key of a unique index for a vertex
+///
+/// The lookup and the write happen under one write lock and see entries of
+/// writers that have not committed yet, so of two concurrent writers of the
+/// same key only the first succeeds. A `None` key releases the vertex's claim.
+///
+/// Inside a transaction the claim is recorded together with `previous_key`, the
+/// key the vertex held before, so that `release_unique_claims` can undo it when
+/// the transaction aborts.
+pub fn claim_unique_vertex_key(
+    ctx: &GraphStorageContext,
+    space_id: u64,
+    vertex_id: &Value,
+    index: &Index,
+    key: Option<&Value>,
+    previous_key: Option<&Value>,
+    ts: Timestamp,
+) -> StorageResult<()> {
+    {
+        let index_data = ctx.index_data_manager().write();
+        if let Some(key) = key {
+            let holders = index_data.lookup_tag_index(space_id, index, key)?;
+            if holders.iter().any(|holder| holder != vertex_id) {
+                return Err(StorageError::constraint_violation(format!(
+                    "unique constraint '{}' on {}({}): key {} already exists",
+                    index.name,
+                    index.schema_name,
+                    index.properties.join(", "),
+                    key
+                )));
+            }
+        }
+        write_unique_key(&index_data, space_id, vertex_id, index, key, ts)?;
+    }
+
+    if let Some(txn) = ctx.get_transaction_context() {
+        if !txn.is_read_only && key != previous_key {
+            ctx.record_unique_claim(
+                txn.id,
+                UniqueKeyClaim {
+                    space_id,
+                    vertex_id: vertex_id.clone(),
+                    index: index.clone(),
+                    previous_key: previous_key.cloned(),
+                    ts,
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Undo the unique key claims of an aborted transaction, latest first
+///
+/// Each claim is replaced by the key the vertex held before it. The previous key
+/// is written back without a uniqueness check: it was committed before the
+/// transaction started and still belongs to the vertex.
+pub fn release_unique_claims(
+    ctx: &GraphStorageContext,
+    txn_id: TransactionId,
+) -> StorageResult<()> {
+    let claims = ctx.take_unique_claims(txn_id);
+    let index_data = ctx.index_data_manager().write();
+    for claim in claims.iter().rev() {
+        write_unique_key(
+            &index_data,
+            claim.space_id,
+            &claim.vertex_id,
+            &claim.index,
+            claim.previous_key.as_ref(),
+            claim.ts,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_unique_key(
+    index_data: &IndexDataManagerImpl,
+    space_id: u64,
+    vertex_id: &Value,
+    index: &Index,
+    key: Option<&Value>,
+    ts: Timestamp,
+) -> StorageResult<()> {
+    index_data.delete_vertex_indexes_mvcc(space_id, vertex_id, &[index.name.clone()], ts)?;
+    if let Some(key) = key {
+        index_data.update_vertex_indexes_mvcc(
+            space_id,
+            vertex_id,
+            &index.name,
+            &[(index.properties.join(","), key.clone())],
+            ts,
+        )?;
+    }
+    Ok(())
+}
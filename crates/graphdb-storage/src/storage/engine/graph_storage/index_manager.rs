@@ -1,5 +1,5 @@
 use crate::core::metadata::index_manager::IndexMetadataManager;
-use crate::core::types::Index;
+use crate::core::types::{Constraint, ConstraintKind, Index};
 use crate::core::{StorageError, StorageResult, Value};
 use crate::storage::index::VertexIndexOps;
 
@@ -25,6 +25,16 @@ pub(crate) fn drop_tag_index(
     index_name: &str,
 ) -> StorageResult<bool> {
     let space_id = ctx.schema_manager().get_space_id(space)?;
+    if ctx
+        .index_metadata_manager()
+        .get_constraint(space_id, index_name)?
+        .is_some()
+    {
+        return Err(StorageError::invalid_operation(format!(
+            "Index {} backs a constraint, drop the constraint instead",
+            index_name
+        )));
+    }
     let dropped = ctx
         .index_metadata_manager()
         .drop_tag_index(space_id, index_name)?;
@@ -68,13 +78,21 @@ pub(crate) fn rebuild_tag_index(
 
     let ts = ctx.get_write_timestamp();
     for vertex in vertices {
+        if index.is_unique && !vertex.tags.iter().any(|tag| tag.name == index.schema_name) {
+            continue;
+        }
         let props: Vec<(String, Value)> = vertex
             .properties
             .iter()
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
         let vid_value = Value::from(vertex.vid);
-        ctx.update_vertex_indexes_mvcc(space_id, &vid_value, &index.name, &props, ts)?;
+        if index.is_unique {
+            let key = index.unique_key(&props);
+            ctx.claim_unique_vertex_key(space_id, &vid_value, &index, key.as_ref(), None, ts)?;
+        } else {
+            ctx.update_vertex_indexes_mvcc(space_id, &vid_value, &index.name, &props, ts)?;
+        }
     }
 
     Ok(true)
@@ -100,6 +118,128 @@ pub(crate) fn lookup_index(
     Ok(results)
 }
 
+/// Create a constraint and validate the existing vertices of its tag
+///
+/// The constraint is registered before the scan so that concurrent writes are
+/// already checked against it; it is removed again if existing data violates it.
+pub(crate) fn create_constraint(
+    ctx: &GraphStorageContext,
+    space: &str,
+    constraint: &Constraint,
+) -> StorageResult<bool> {
+    let space_id = ctx.schema_manager().get_space_id(space)?;
+    let tag = ctx
+        .schema_manager()
+        .get_tag(space, &constraint.schema_name)?
+        .ok_or_else(|| {
+            StorageError::not_found(format!("Tag {} not found", constraint.schema_name))
+        })?;
+    if let Some(prop) = constraint
+        .properties
+        .iter()
+        .find(|prop| !tag.properties.iter().any(|def| &def.name == *prop))
+    {
+        return Err(StorageError::invalid_input(format!(
+            "Property {} not found in tag {}",
+            prop, constraint.schema_name
+        )));
+    }
+
+    let metadata = ctx.index_metadata_manager();
+    if metadata
+        .get_constraint(space_id, &constraint.name)?
+        .is_some()
+    {
+        return Ok(false);
+    }
+    let mut constraint = constraint.clone();
+    constraint.space_id = space_id;
+    let backing_index = constraint.backing_index();
+    if let Some(ref index) = backing_index {
+        if !metadata.create_tag_index(space_id, index)? {
+            return Err(StorageError::already_exists(format!(
+                "Index {} already exists",
+                index.name
+            )));
+        }
+    }
+    metadata.create_constraint(space_id, &constraint)?;
+
+    if let Err(e) = validate_constraint(ctx, space, space_id, &constraint, backing_index.as_ref()) {
+        metadata.drop_constraint(space_id, &constraint.name)?;
+        if backing_index.is_some() {
+            metadata.drop_tag_index(space_id, &constraint.name)?;
+            ctx.index_data_manager()
+                .write()
+                .clear_tag_index(space_id, &constraint.name)?;
+        }
+        return Err(e);
+    }
+    Ok(true)
+}
+
+fn validate_constraint(
+    ctx: &GraphStorageContext,
+    space: &str,
+    space_id: u64,
+    constraint: &Constraint,
+    backing_index: Option<&Index>,
+) -> StorageResult<()> {
+    let vertices = super::reader::scan_vertices_by_tag(ctx, space, &constraint.schema_name)?;
+    let ts = ctx.get_write_timestamp();
+    for vertex in &vertices {
+        let props: Vec<(String, Value)> = vertex
+            .properties
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        match (constraint.kind, backing_index) {
+            (ConstraintKind::NotNull, _) => {
+                if let Some(prop) = constraint.null_property(&props) {
+                    return Err(StorageError::constraint_violation(format!(
+                        "not null constraint '{}' on {}.{}: vertex {} has no value",
+                        constraint.name, constraint.schema_name, prop, vertex.vid
+                    )));
+                }
+            }
+            (ConstraintKind::Unique, Some(index)) => {
+                let key = index.unique_key(&props);
+                let vid_value = Value::from(vertex.vid);
+                ctx.claim_unique_vertex_key(space_id, &vid_value, index, key.as_ref(), None, ts)?;
+            }
+            (ConstraintKind::Unique, None) => {}
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn drop_constraint(
+    ctx: &GraphStorageContext,
+    space: &str,
+    name: &str,
+) -> StorageResult<bool> {
+    let space_id = ctx.schema_manager().get_space_id(space)?;
+    let metadata = ctx.index_metadata_manager();
+    let Some(constraint) = metadata.get_constraint(space_id, name)? else {
+        return Ok(false);
+    };
+    metadata.drop_constraint(space_id, name)?;
+    if constraint.kind == ConstraintKind::Unique && metadata.drop_tag_index(space_id, name)? {
+        ctx.index_data_manager()
+            .write()
+            .clear_tag_index(space_id, name)?;
+    }
+    Ok(true)
+}
+
+pub(crate) fn list_constraints(
+    ctx: &GraphStorageContext,
+    space: &str,
+) -> StorageResult<Vec<Constraint>> {
+    let space_id = ctx.schema_manager().get_space_id(space)?;
+    ctx.index_metadata_manager().list_constraints(space_id)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::types::{Index, IndexConfig, IndexField, IndexType};
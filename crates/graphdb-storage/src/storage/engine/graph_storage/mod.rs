@@ -8,7 +8,7 @@ pub mod context;
 mod index_engine;
 mod index_manager;
 mod ops;
-mod persistence;
+pub(crate) mod persistence;
 mod reader;
 mod schema_writer;
 mod schema_engine;
@@ -25,10 +25,14 @@ use std::sync::Arc;
 use crate::core::metadata::SchemaManager;
 use crate::core::types::TransactionContextInfo;
 use crate::core::types::{
-    CompactConfig, EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo, LabelId, PasswordInfo,
-    PropertyDef, SpaceInfo, TagInfo, Timestamp, UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+    ApiKeyInfo, CompactConfig, Constraint, EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo,
+    LabelId, LoginCheck, PasswordInfo, PasswordPolicy, PropertyDef, SpaceInfo, TagInfo, Timestamp,
+    UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+};
+use crate::core::{
+    CustomRole, Edge, EdgeDirection, EffectivePrivileges, PrivilegeGrant, RoleType, StorageError,
+    StorageResult, Value, Vertex,
 };
-use crate::core::{Edge, EdgeDirection, RoleType, StorageError, StorageResult, Value, Vertex};
 use crate::storage::engine::background_freeze::{BackgroundFreezeManager, FreezeStats};
 use crate::storage::engine::graph_storage::context::ExportedEdgeSnapshotRecord;
 use crate::core::stats::StatsManager;
@@ -290,6 +294,10 @@ impl StorageReader for GraphStorage {
     fn list_tag_indexes(&self, space: &str) -> Result<Vec<Index>, StorageError> {
         index_manager::list_tag_indexes(&self.ctx, space)
     }
+
+    fn list_constraints(&self, space: &str) -> Result<Vec<Constraint>, StorageError> {
+        index_manager::list_constraints(&self.ctx, space)
+    }
 }
 
 impl StorageWriter for GraphStorage {
@@ -441,6 +449,15 @@ impl StorageSchemaOps for GraphStorage {
             .rename_tag_property(space, tag, old_name, new_name)
     }
 
+    fn change_tag_property_types(
+        &mut self,
+        space: &str,
+        tag: &str,
+        changes: &[crate::storage::PropertyTypeChange],
+    ) -> Result<bool, StorageError> {
+        schema_writer::change_tag_property_types(&self.ctx, space, tag, changes)
+    }
+
     fn drop_tag(&mut self, space: &str, tag: &str) -> Result<bool, StorageError> {
         schema_writer::drop_tag(&self.ctx, space, tag)
     }
@@ -479,6 +496,18 @@ impl StorageSchemaOps for GraphStorage {
         let vertices = reader::scan_vertices(&self.ctx, space)?;
         index_manager::rebuild_tag_index(&self.ctx, space, index_name, &vertices)
     }
+
+    fn create_constraint(
+        &mut self,
+        space: &str,
+        constraint: &Constraint,
+    ) -> Result<bool, StorageError> {
+        index_manager::create_constraint(&self.ctx, space, constraint)
+    }
+
+    fn drop_constraint(&mut self, space: &str, name: &str) -> Result<bool, StorageError> {
+        index_manager::drop_constraint(&self.ctx, space, name)
+    }
 }
 
 impl StorageAuthOps for GraphStorage {
@@ -502,6 +531,24 @@ impl StorageAuthOps for GraphStorage {
         self.ctx.user_storage().user_exists(username)
     }
 
+    fn get_user(&self, username: &str) -> Option<UserInfo> {
+        self.ctx.user_storage().get_user(username)
+    }
+
+    fn set_password_policy(&self, policy: PasswordPolicy) {
+        self.ctx.user_storage().set_password_policy(policy)
+    }
+
+    fn check_password_policy(&self, password: &str) -> Result<(), StorageError> {
+        self.ctx.user_storage().check_password_policy(password)
+    }
+
+    fn verify_login(&self, username: &str, password: &str, max_failed_attempts: u32) -> LoginCheck {
+        self.ctx
+            .user_storage()
+            .verify_login(username, password, max_failed_attempts)
+    }
+
     fn grant_role(
         &mut self,
         username: &str,
@@ -514,6 +561,46 @@ impl StorageAuthOps for GraphStorage {
     fn revoke_role(&mut self, username: &str, space_id: u64) -> Result<bool, StorageError> {
         ops::revoke_role(&self.ctx, username, space_id)
     }
+
+    fn create_role(&mut self, role: &CustomRole) -> Result<bool, StorageError> {
+        ops::create_role(&self.ctx, role)
+    }
+
+    fn grant_privilege(
+        &mut self,
+        role: &str,
+        grant: &PrivilegeGrant,
+    ) -> Result<bool, StorageError> {
+        ops::grant_privilege(&self.ctx, role, grant)
+    }
+
+    fn grant_custom_role(&mut self, username: &str, role: &str) -> Result<bool, StorageError> {
+        ops::grant_custom_role(&self.ctx, username, role)
+    }
+
+    fn user_custom_roles(&self, username: &str) -> Vec<CustomRole> {
+        self.ctx.user_storage().user_custom_roles(username)
+    }
+
+    fn effective_privileges(&self, username: &str) -> Option<EffectivePrivileges> {
+        self.ctx.user_storage().effective_privileges(username)
+    }
+
+    fn create_api_key(&mut self, key: &ApiKeyInfo) -> Result<bool, StorageError> {
+        ops::create_api_key(&self.ctx, key)
+    }
+
+    fn revoke_api_key(&mut self, key_id: &str) -> Result<bool, StorageError> {
+        ops::revoke_api_key(&self.ctx, key_id)
+    }
+
+    fn list_api_keys(&self, username: Option<&str>) -> Vec<ApiKeyInfo> {
+        self.ctx.user_storage().list_api_keys(username)
+    }
+
+    fn verify_api_key(&self, token: &str) -> Option<ApiKeyInfo> {
+        self.ctx.user_storage().verify_api_key(token)
+    }
 }
 
 impl StorageAdmin for GraphStorage {
@@ -807,4 +894,14 @@ impl crate::transaction::UndoTarget for GraphStorage {
             original_names,
         )
     }
+    fn release_unique_claims(
+        &self,
+        txn_id: crate::core::types::TransactionId,
+    ) -> crate::transaction::undo_log::UndoLogResult<()> {
+        crate::core::types::UndoTarget::release_unique_claims(&*self.ctx, txn_id)
+    }
+
+    fn retain_unique_claims(&self, txn_id: crate::core::types::TransactionId) {
+        crate::core::types::UndoTarget::retain_unique_claims(&*self.ctx, txn_id)
+    }
 }
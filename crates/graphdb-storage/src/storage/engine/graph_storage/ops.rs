@@ -4,9 +4,11 @@
 
 use std::collections::HashMap;
 
-use crate::core::types::{LabelId, PasswordInfo, UserAlterInfo, UserInfo, VertexId};
+use crate::core::types::{ApiKeyInfo, LabelId, PasswordInfo, UserAlterInfo, UserInfo, VertexId};
 use crate::core::vertex_edge_path::Tag;
-use crate::core::{Edge, RoleType, StorageError, StorageResult, Value, Vertex};
+use crate::core::{
+    CustomRole, Edge, PrivilegeGrant, RoleType, StorageError, StorageResult, Value, Vertex,
+};
 use crate::storage::edge::EdgeRecord;
 use crate::storage::vertex::VertexRecord;
 use crate::storage::StorageStats;
@@ -176,6 +178,34 @@ pub(crate) fn revoke_role(
     ctx.user_storage().revoke_role(username, space_id)
 }
 
+pub(crate) fn create_role(ctx: &GraphStorageContext, role: &CustomRole) -> StorageResult<bool> {
+    ctx.user_storage().create_role(role.clone())
+}
+
+pub(crate) fn grant_privilege(
+    ctx: &GraphStorageContext,
+    role: &str,
+    grant: &PrivilegeGrant,
+) -> StorageResult<bool> {
+    ctx.user_storage().grant_privilege(role, grant.clone())
+}
+
+pub(crate) fn grant_custom_role(
+    ctx: &GraphStorageContext,
+    username: &str,
+    role: &str,
+) -> StorageResult<bool> {
+    ctx.user_storage().grant_custom_role(username, role)
+}
+
+pub(crate) fn create_api_key(ctx: &GraphStorageContext, key: &ApiKeyInfo) -> StorageResult<bool> {
+    ctx.user_storage().create_api_key(key.clone())
+}
+
+pub(crate) fn revoke_api_key(ctx: &GraphStorageContext, key_id: &str) -> StorageResult<bool> {
+    ctx.user_storage().revoke_api_key(key_id)
+}
+
 pub(crate) fn change_password(
     ctx: &GraphStorageContext,
     info: &PasswordInfo,
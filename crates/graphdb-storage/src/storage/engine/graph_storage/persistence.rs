@@ -509,7 +509,7 @@ fn persistence_dirs(ctx: &GraphStorageContext) -> Option<(PathBuf, PathBuf, Path
     }
 }
 
-fn read_checkpoint_metadata(dir: &Path) -> StorageResult<CheckpointInfo> {
+pub(crate) fn read_checkpoint_metadata(dir: &Path) -> StorageResult<CheckpointInfo> {
     use std::fs::File;
     use std::io::{BufRead, BufReader};
 
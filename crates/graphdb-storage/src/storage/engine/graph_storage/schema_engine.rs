@@ -1,10 +1,13 @@
 use std::sync::atomic::Ordering;
 
+use parking_lot::RwLockUpgradableReadGuard;
+
 use crate::core::types::LabelId;
-use crate::core::{StorageError, StorageResult};
+use crate::core::{DataType, StorageError, StorageResult};
 use crate::storage::edge::{EdgeSchema, EdgeStrategy, EdgeTable};
 use crate::storage::engine::data_store::EdgeTableKey;
 use crate::storage::engine::params::CreateEdgeTypeParams;
+use crate::storage::schema::PropertyTypeChange;
 use crate::storage::types::StoragePropertyDef;
 use crate::storage::vertex::{VertexSchema, VertexTable};
 
@@ -403,6 +406,60 @@ pub fn rename_vertex_property(
     table.rename_property(old_name, new_name)
 }
 
+/// Convert a vertex property to a new type.
+pub fn change_vertex_property_type(
+    ctx: &GraphStorageContext,
+    label: LabelId,
+    prop_name: &str,
+    new_type: DataType,
+    allow_lossy: bool,
+) -> StorageResult<()> {
+    change_vertex_property_types(
+        ctx,
+        label,
+        &[PropertyTypeChange::new(prop_name, new_type, allow_lossy)],
+    )
+}
+
+/// Convert several vertex properties to new types, all or none.
+///
+/// Every converted column is built under an upgradable read lock before any is
+/// swapped in, so queries keep reading the old columns while the rewrite runs and
+/// a value that fails to convert leaves every property unchanged. Only the final
+/// swap takes the write lock; writers to vertex tables wait for the whole conversion.
+pub fn change_vertex_property_types(
+    ctx: &GraphStorageContext,
+    label: LabelId,
+    changes: &[PropertyTypeChange],
+) -> StorageResult<()> {
+    if !ctx.is_open_flag().load(Ordering::Acquire) {
+        return Err(StorageError::storage_not_open());
+    }
+
+    let vertex_tables = ctx.data_store().vertex_tables().upgradable_read();
+    let table = vertex_tables
+        .get(&label)
+        .ok_or_else(|| StorageError::label_not_found(format!("vertex label {}", label)))?;
+
+    let columns = changes
+        .iter()
+        .map(|change| {
+            table.convert_property_column(&change.name, &change.data_type, change.allow_lossy)
+        })
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let mut vertex_tables = RwLockUpgradableReadGuard::upgrade(vertex_tables);
+    let table = vertex_tables
+        .get_mut(&label)
+        .ok_or_else(|| StorageError::label_not_found(format!("vertex label {}", label)))?;
+
+    // Writers were held off since the columns were built, so none is stale
+    for (column, lossy) in columns {
+        table.apply_property_column(column, lossy)?;
+    }
+    Ok(())
+}
+
 pub fn add_edge_property(
     ctx: &GraphStorageContext,
     edge_label: LabelId,
@@ -678,6 +735,23 @@ mod tests {
             .expect("rename_vertex_property should succeed");
     }
 
+    #[test]
+    fn test_change_vertex_property_type() {
+        let ctx = GraphStorageContext::new();
+        ctx.create_vertex_type("Person", name_prop(), "name")
+            .expect("create_vertex_type should succeed");
+        ctx.add_vertex_property(0, StoragePropertyDef::new("age".to_string(), DataType::Int))
+            .expect("add_vertex_property should succeed");
+        ctx.change_vertex_property_type(0, "age", DataType::BigInt, false)
+            .expect("change_vertex_property_type should succeed");
+        assert!(ctx
+            .change_vertex_property_type(0, "age", DataType::Float, false)
+            .is_err());
+        assert!(ctx
+            .change_vertex_property_type(1, "age", DataType::BigInt, false)
+            .is_err());
+    }
+
     #[test]
     fn test_delete_edge_property() {
         let ctx = GraphStorageContext::new();
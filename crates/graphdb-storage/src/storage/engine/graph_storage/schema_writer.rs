@@ -2,14 +2,16 @@ use crate::core::error::storage::StorageErrorKind;
 use crate::core::types::{EdgeTypeInfo, PropertyDef, SpaceInfo, TagInfo};
 use crate::core::{StorageError, StorageResult};
 use crate::storage::engine::params::CreateEdgeTypeParams;
+use crate::storage::schema::PropertyTypeChange;
 use crate::storage::types::StoragePropertyDef;
 use crate::transaction::wal::{
-    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ClearSpaceRedo, CreateEdgeTypeRedo,
-    CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo, DeleteEdgeTypeRedo,
-    DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo, WalOpType,
+    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ChangeVertexPropTypeRedo,
+    ClearSpaceRedo, CreateEdgeTypeRedo, CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo,
+    DeleteEdgeTypeRedo, DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo, WalOpType,
 };
 
 use super::context::GraphStorageContext;
+use super::index_manager;
 use super::ops::{
     edge_type_storage_name, endpoint_label_id, tag_label_id, vertex_type_storage_name,
 };
@@ -296,6 +298,96 @@ pub(crate) fn alter_tag(
     Ok(true)
 }
 
+/// Change the data types of tag properties, converting existing values.
+///
+/// Storage is converted first and all-or-nothing, so a failed conversion of any
+/// property leaves every property and the catalog untouched. Indexes over a
+/// changed property are rebuilt because their keys encode the old type.
+pub(crate) fn change_tag_property_types(
+    ctx: &GraphStorageContext,
+    space: &str,
+    tag_name: &str,
+    changes: &[PropertyTypeChange],
+) -> StorageResult<bool> {
+    let space_id = ctx.schema_manager().get_space_id(space)?;
+    let tag = ctx
+        .schema_manager()
+        .get_tag(space, tag_name)?
+        .ok_or_else(|| StorageError::label_not_found(tag_name.to_string()))?;
+
+    let mut pending: Vec<PropertyTypeChange> = Vec::with_capacity(changes.len());
+    for change in changes {
+        let current = tag
+            .properties
+            .iter()
+            .find(|prop| prop.name == change.name)
+            .ok_or_else(|| StorageError::column_not_found(change.name.clone()))?;
+        if pending.iter().any(|p| p.name == change.name) {
+            return Err(StorageError::invalid_input(format!(
+                "Property '{}' changes type more than once",
+                change.name
+            )));
+        }
+        if current.data_type != change.data_type {
+            pending.push(change.clone());
+        }
+    }
+    if pending.is_empty() {
+        return Ok(true);
+    }
+
+    if let Some(label_id) = tag_label_id(ctx, space, tag_name)? {
+        ctx.change_vertex_property_types(label_id, &pending)?;
+    }
+
+    for change in &pending {
+        let result = ctx.schema_manager().change_tag_property_type(
+            space,
+            tag_name,
+            &change.name,
+            change.data_type.clone(),
+        )?;
+        if !result {
+            return Ok(false);
+        }
+    }
+
+    let dependent_indexes: Vec<_> = index_manager::list_tag_indexes(ctx, space)?
+        .into_iter()
+        .filter(|index| {
+            index.schema_name == tag_name
+                && index
+                    .properties
+                    .iter()
+                    .any(|p| pending.iter().any(|change| &change.name == p))
+        })
+        .collect();
+    if !dependent_indexes.is_empty() {
+        let vertices = super::reader::scan_vertices_by_tag(ctx, space, tag_name)?;
+        for index in &dependent_indexes {
+            ctx.index_data_manager()
+                .write()
+                .clear_tag_index(space_id, &index.name)?;
+            index_manager::rebuild_tag_index(ctx, space, &index.name, &vertices)?;
+        }
+    }
+
+    for change in &pending {
+        append_schema_redo(
+            ctx,
+            WalOpType::ChangeVertexPropType,
+            &ChangeVertexPropTypeRedo {
+                label: tag.tag_id,
+                prop_name: change.name.clone(),
+                data_type: change.data_type.to_string(),
+                allow_lossy: change.allow_lossy,
+            },
+        )?;
+    }
+
+    Ok(true)
+}
+
 pub(crate) fn create_edge_type(
     ctx: &GraphStorageContext,
     space: &str,
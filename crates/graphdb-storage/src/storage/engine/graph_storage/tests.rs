@@ -1,17 +1,20 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
+    use crate::core::error::storage::StorageErrorKind;
     use crate::core::types::{
-        EdgeTypeInfo, Index, IndexConfig, IndexField, IndexType, PropertyDef, SpaceInfo, UserInfo,
-        VertexId,
+        Constraint, ConstraintKind, EdgeTypeInfo, Index, IndexConfig, IndexField, IndexType,
+        PropertyDef, SpaceInfo, TransactionContextInfo, TransactionId, UserInfo, VertexId,
     };
     use crate::core::vertex_edge_path::Tag;
     use crate::core::DataType;
     use crate::core::{Edge, EdgeDirection, RoleType, Value, Vertex};
     use crate::storage::{
-        GraphStorage, StorageAdmin, StorageAuthOps, StoragePersistenceOps, StorageReader,
-        StorageSchemaOps, StorageWriter,
+        GraphStorage, PropertyTypeChange, StorageAdmin, StorageAuthOps, StoragePersistenceOps,
+        StorageReader, StorageSchemaOps, StorageTransactionContextOps, StorageWriter, UndoTarget,
     };
+    use crate::transaction::wal::inspect::WalInspector;
+    use std::sync::Arc;
 
     fn create_test_storage() -> GraphStorage {
         GraphStorage::new().expect("Failed to create GraphStorage")
@@ -569,6 +572,202 @@ mod tests {
         assert_eq!(indexes.len(), 0);
     }
 
+    #[test]
+    fn test_unique_and_not_null_constraints() {
+        let mut storage = create_test_storage();
+        setup_space(&mut storage);
+        setup_person_tag(&mut storage);
+        insert_test_vertex(&mut storage, 1, "Alice");
+        insert_test_vertex(&mut storage, 2, "Alice");
+
+        let unique = Constraint::new(
+            "person_name_unique".to_string(),
+            "Person".to_string(),
+            ConstraintKind::Unique,
+            vec!["name".to_string()],
+        );
+        let err = storage
+            .create_constraint("test_space", &unique)
+            .expect_err("existing duplicates should be rejected");
+        assert_eq!(err.kind(), StorageErrorKind::ConstraintViolation);
+        assert!(storage.list_constraints("test_space").unwrap().is_empty());
+        assert!(storage.list_tag_indexes("test_space").unwrap().is_empty());
+
+        storage
+            .delete_vertex("test_space", &VertexId::from_int64(2))
+            .unwrap();
+        assert!(storage.create_constraint("test_space", &unique).unwrap());
+        assert_eq!(storage.list_constraints("test_space").unwrap().len(), 1);
+
+        let vertex = Vertex::new(
+            VertexId::from_int64(3),
+            vec![Tag::new(
+                "Person".to_string(),
+                vec![("name".to_string(), Value::String("Alice".to_string()))]
+                    .into_iter()
+                    .collect(),
+            )],
+        );
+        let err = storage
+            .insert_vertex("test_space", vertex)
+            .expect_err("duplicate key should be rejected");
+        assert_eq!(err.kind(), StorageErrorKind::ConstraintViolation);
+        assert!(storage
+            .get_vertex("test_space", &VertexId::from_int64(3))
+            .unwrap()
+            .is_none());
+
+        insert_test_vertex(&mut storage, 3, "Bob");
+        let renamed = Vertex::new(
+            VertexId::from_int64(3),
+            vec![Tag::new(
+                "Person".to_string(),
+                vec![("name".to_string(), Value::String("Alice".to_string()))]
+                    .into_iter()
+                    .collect(),
+            )],
+        );
+        assert!(storage.update_vertex("test_space", renamed).is_err());
+        let bob = storage
+            .get_vertex("test_space", &VertexId::from_int64(3))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            bob.properties.get("name"),
+            Some(&Value::String("Bob".to_string()))
+        );
+
+        storage
+            .delete_vertex("test_space", &VertexId::from_int64(1))
+            .unwrap();
+        insert_test_vertex(&mut storage, 4, "Alice");
+        assert_eq!(
+            storage
+                .lookup_index(
+                    "test_space",
+                    "person_name_unique",
+                    &Value::String("Alice".to_string())
+                )
+                .unwrap(),
+            vec![Value::from(VertexId::from_int64(4))]
+        );
+
+        let not_null = Constraint::new(
+            "person_age_not_null".to_string(),
+            "Person".to_string(),
+            ConstraintKind::NotNull,
+            vec!["age".to_string()],
+        );
+        let err = storage
+            .create_constraint("test_space", &not_null)
+            .expect_err("vertices without age should be rejected");
+        assert_eq!(err.kind(), StorageErrorKind::ConstraintViolation);
+
+        assert!(storage
+            .drop_constraint("test_space", "person_name_unique")
+            .unwrap());
+        assert!(storage.list_constraints("test_space").unwrap().is_empty());
+        insert_test_vertex(&mut storage, 5, "Alice");
+    }
+
+    #[test]
+    fn test_unique_claim_released_on_abort() {
+        let mut storage = create_test_storage();
+        setup_space(&mut storage);
+        setup_person_tag(&mut storage);
+        let unique = Constraint::new(
+            "person_name_unique".to_string(),
+            "Person".to_string(),
+            ConstraintKind::Unique,
+            vec!["name".to_string()],
+        );
+        assert!(storage.create_constraint("test_space", &unique).unwrap());
+
+        let aborted = TransactionId::new(1);
+        storage.set_transaction_context(Some(Arc::new(TransactionContextInfo::new(
+            aborted, 1, false, 0,
+        ))));
+        insert_test_vertex(&mut storage, 1, "Alice");
+        storage.set_transaction_context(None);
+        UndoTarget::release_unique_claims(&storage, aborted).unwrap();
+
+        insert_test_vertex(&mut storage, 2, "Alice");
+        assert_eq!(
+            storage
+                .lookup_index(
+                    "test_space",
+                    "person_name_unique",
+                    &Value::String("Alice".to_string())
+                )
+                .unwrap(),
+            vec![Value::from(VertexId::from_int64(2))]
+        );
+
+        let committed = TransactionId::new(2);
+        storage.set_transaction_context(Some(Arc::new(TransactionContextInfo::new(
+            committed, 2, false, 0,
+        ))));
+        insert_test_vertex(&mut storage, 3, "Bob");
+        storage.set_transaction_context(None);
+        storage.retain_unique_claims(committed);
+        UndoTarget::release_unique_claims(&storage, committed).unwrap();
+
+        let vertex = Vertex::new(
+            VertexId::from_int64(4),
+            vec![Tag::new(
+                "Person".to_string(),
+                vec![("name".to_string(), Value::String("Bob".to_string()))]
+                    .into_iter()
+                    .collect(),
+            )],
+        );
+        let err = storage
+            .insert_vertex("test_space", vertex)
+            .expect_err("committed claim should keep the key");
+        assert_eq!(err.kind(), StorageErrorKind::ConstraintViolation);
+    }
+
+    #[test]
+    fn test_rejected_duplicate_is_not_logged() {
+        let (temp_dir, mut storage) = create_persistent_storage();
+        setup_space(&mut storage);
+        setup_person_tag(&mut storage);
+        let unique = Constraint::new(
+            "person_name_unique".to_string(),
+            "Person".to_string(),
+            ConstraintKind::Unique,
+            vec!["name".to_string()],
+        );
+        assert!(storage.create_constraint("test_space", &unique).unwrap());
+
+        insert_test_vertex(&mut storage, 1, "Alice");
+        let duplicate = Vertex::new(
+            VertexId::from_int64(2),
+            vec![Tag::new(
+                "Person".to_string(),
+                vec![("name".to_string(), Value::String("Alice".to_string()))]
+                    .into_iter()
+                    .collect(),
+            )],
+        );
+        let err = storage
+            .insert_vertex("test_space", duplicate)
+            .expect_err("duplicate key should be rejected");
+        assert_eq!(err.kind(), StorageErrorKind::ConstraintViolation);
+        storage.flush().expect("Failed to sync WAL");
+
+        // Recovery replays inserts without the unique check, so the rejected
+        // vertex must not reach the WAL.
+        let inserts: usize = WalInspector::new()
+            .inspect_dir(&temp_dir.path().join("wal"))
+            .expect("Failed to inspect WAL")
+            .iter()
+            .flat_map(|report| &report.records)
+            .filter(|record| record.op_type == "InsertVertex")
+            .count();
+        assert_eq!(inserts, 1);
+    }
+
     // ==================== Vertex Operations ====================
 
     #[test]
@@ -1615,4 +1814,76 @@ mod tests {
         assert_eq!(snapshot_tracker.active_count(), 0);
         assert_eq!(snapshot_tracker.cleanup_threshold(), u32::MAX);
     }
+
+    #[test]
+    fn test_change_tag_property_types_is_all_or_nothing() {
+        let mut storage = create_test_storage();
+        setup_space(&mut storage);
+        let tag = crate::core::types::TagInfo::new("Profile".to_string()).with_properties(vec![
+            PropertyDef::new("id".to_string(), DataType::BigInt),
+            PropertyDef::new("age".to_string(), DataType::BigInt),
+            PropertyDef::new("nick".to_string(), DataType::String),
+        ]);
+        storage.create_tag("test_space", &tag).unwrap();
+
+        let props = vec![
+            ("id".to_string(), Value::BigInt(1)),
+            ("age".to_string(), Value::BigInt(30)),
+            ("nick".to_string(), Value::String("alice".to_string())),
+        ];
+        storage
+            .insert_vertex(
+                "test_space",
+                Vertex::new(
+                    VertexId::from_int64(1),
+                    vec![Tag::new("Profile".to_string(), props.into_iter().collect())],
+                ),
+            )
+            .unwrap();
+
+        // "alice" does not parse as an integer, so the age change must not apply either
+        let result = storage.change_tag_property_types(
+            "test_space",
+            "Profile",
+            &[
+                PropertyTypeChange::new("age", DataType::Int, false),
+                PropertyTypeChange::new("nick", DataType::Int, false),
+            ],
+        );
+        assert!(result.is_err());
+
+        let tag = storage.get_tag("test_space", "Profile").unwrap().unwrap();
+        let type_of = |name: &str| {
+            tag.properties
+                .iter()
+                .find(|p| p.name == name)
+                .map(|p| p.data_type.clone())
+        };
+        assert_eq!(type_of("age"), Some(DataType::BigInt));
+        assert_eq!(type_of("nick"), Some(DataType::String));
+
+        let vertex = storage
+            .get_vertex("test_space", &VertexId::from_int64(1))
+            .unwrap()
+            .expect("vertex should exist");
+        let profile = vertex
+            .tags
+            .iter()
+            .find(|t| t.name == "Profile")
+            .expect("vertex should keep its Profile tag");
+        assert_eq!(profile.properties.get("age"), Some(&Value::BigInt(30)));
+
+        storage
+            .change_tag_property_types(
+                "test_space",
+                "Profile",
+                &[PropertyTypeChange::new("age", DataType::Int, false)],
+            )
+            .expect("a convertible change should apply");
+        let tag = storage.get_tag("test_space", "Profile").unwrap().unwrap();
+        assert!(tag
+            .properties
+            .iter()
+            .any(|p| p.name == "age" && p.data_type == DataType::Int));
+    }
 }
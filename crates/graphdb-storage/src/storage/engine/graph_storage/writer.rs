@@ -2,12 +2,11 @@ use std::collections::HashMap;
 
 use crate::core::metadata::IndexMetadataManager;
 use crate::core::types::{
-    EdgeTypeInfo, InsertEdgeInfo, InsertVertexInfo, LabelId, Timestamp, UpdateInfo, UpdateOp,
-    UpdateTarget, VertexId,
+    ConstraintKind, EdgeTypeInfo, InsertEdgeInfo, InsertVertexInfo, LabelId, Timestamp, UpdateInfo,
+    UpdateOp, UpdateTarget, VertexId,
 };
 use crate::core::{Edge, EdgeDirection, StorageError, StorageResult, Value, Vertex};
 use crate::storage::engine::params::{EdgeOperationParams, InsertEdgeParams};
-use crate::storage::index::index_data_manager::VertexIndexOps;
 use crate::transaction::codec::value_to_bytes;
 use crate::core::wal::redo::{
     DeleteEdgeRedo, DeleteVertexRedo, InsertEdgeRedo, InsertVertexRedo, UpdateVertexPropRedo,
@@ -77,6 +76,21 @@ fn insert_vertex_at_timestamp(
             .iter()
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
+        let vid_value = Value::from(vertex.vid);
+
+        // Constraints are checked before the redo is logged: recovery replays the
+        // redo without checking them again.
+        enforce_vertex_constraints(
+            ctx,
+            ctx.index_metadata_manager(),
+            space_id,
+            &vid_value,
+            &tag.name,
+            &props,
+            None,
+            ts,
+        )?;
+
         let redo = InsertVertexRedo {
             label: label_id,
             vid: vertex.vid,
@@ -85,18 +99,31 @@ fn insert_vertex_at_timestamp(
                 .map(|(name, value)| (name.clone(), value_to_bytes(value)))
                 .collect(),
         };
-        ctx.append_wal_redo(WalOpType::InsertVertex, ts, &redo)?;
-
-        if let Some(vid_int) = vertex.vid.as_int64() {
-            ctx.insert_vertex_by_i64(label_id, vid_int, &props, ts)?;
-        } else if let Some(id_str) = vertex.vid.as_str() {
-            ctx.insert_vertex(label_id, id_str, &props, ts)?;
-        } else {
-            let id_str = vertex.vid.to_string();
-            ctx.insert_vertex(label_id, &id_str, &props, ts)?;
+        let written = ctx
+            .append_wal_redo(WalOpType::InsertVertex, ts, &redo)
+            .and_then(|()| {
+                if let Some(vid_int) = vertex.vid.as_int64() {
+                    ctx.insert_vertex_by_i64(label_id, vid_int, &props, ts)
+                } else if let Some(id_str) = vertex.vid.as_str() {
+                    ctx.insert_vertex(label_id, id_str, &props, ts)
+                } else {
+                    let id_str = vertex.vid.to_string();
+                    ctx.insert_vertex(label_id, &id_str, &props, ts)
+                }
+            });
+        if let Err(e) = written {
+            // Give back the unique keys claimed for this tag.
+            let _ = delete_vertex_indexes(
+                ctx,
+                ctx.index_metadata_manager(),
+                space_id,
+                &vid_value,
+                &tag.name,
+                ts,
+            );
+            return Err(e);
         }
 
-        let vid_value = Value::from(vertex.vid);
         rollback.push(InsertedVertexTag {
             label_id,
             id: vertex.vid.to_string(),
@@ -174,6 +201,24 @@ pub(crate) fn update_vertex(
                 merged_props.insert(prop_name.clone(), value.clone());
             }
 
+            let props: Vec<(String, Value)> = merged_props.into_iter().collect();
+            let vid_value = Value::from(vertex.vid);
+            if let Err(e) = enforce_vertex_constraints(
+                ctx,
+                ctx.index_metadata_manager(),
+                space_info.space_id,
+                &vid_value,
+                &tag.name,
+                &props,
+                current_record
+                    .as_ref()
+                    .map(|record| record.properties.as_slice()),
+                ts,
+            ) {
+                ctx.version_manager().release_insert_timestamp(ts);
+                return Err(e);
+            }
+
             for (prop_name, value) in &tag.properties {
                 let redo = UpdateVertexPropRedo {
                     label: label_id,
@@ -193,8 +238,6 @@ pub(crate) fn update_vertex(
                 }
             }
 
-            let props: Vec<(String, Value)> = merged_props.into_iter().collect();
-            let vid_value = Value::from(vertex.vid);
             refresh_vertex_indexes(
                 ctx,
                 ctx.index_metadata_manager(),
@@ -657,6 +700,31 @@ pub(crate) fn insert_vertex_data(
     };
     let final_result = match result {
         Ok(_) => {
+            if let Err(e) = enforce_vertex_constraints(
+                ctx,
+                ctx.index_metadata_manager(),
+                space_info.space_id,
+                &info.vertex_id,
+                &info.tag_name,
+                &info.props,
+                None,
+                ts,
+            ) {
+                rollback_vertex_tags(
+                    ctx,
+                    space_info.space_id,
+                    &[InsertedVertexTag {
+                        label_id,
+                        id: vid.to_string(),
+                        vid,
+                        vertex_id: info.vertex_id.clone(),
+                        tag_name: info.tag_name.clone(),
+                    }],
+                    ts,
+                );
+                ctx.version_manager().release_insert_timestamp(ts);
+                return Err(e);
+            }
             update_vertex_indexes(
                 ctx,
                 ctx.index_metadata_manager(),
@@ -907,13 +975,30 @@ pub(crate) fn update_data(
             _ => info.value.clone(),
         };
 
-        ctx.update_vertex_property(label_id, &id_str, prop, &value, ts)?;
-
         let mut merged_props: HashMap<String, Value> = current_record
             .as_ref()
             .map(|record| record.properties.iter().cloned().collect())
             .unwrap_or_default();
-        merged_props.insert(prop.clone(), value);
+        merged_props.insert(prop.clone(), value.clone());
+        let props: Vec<(String, Value)> = merged_props.into_iter().collect();
+
+        if let Err(e) = enforce_vertex_constraints(
+            ctx,
+            ctx.index_metadata_manager(),
+            space_info.space_id,
+            id,
+            label,
+            &props,
+            current_record
+                .as_ref()
+                .map(|record| record.properties.as_slice()),
+            ts,
+        ) {
+            ctx.version_manager().release_insert_timestamp(ts);
+            return Err(e);
+        }
+
+        ctx.update_vertex_property(label_id, &id_str, prop, &value, ts)?;
 
         refresh_vertex_indexes(
             ctx,
@@ -921,7 +1006,7 @@ pub(crate) fn update_data(
             space_info.space_id,
             id,
             label,
-            &merged_props.into_iter().collect::<Vec<_>>(),
+            &props,
             ts,
         )?;
         ctx.version_manager().release_insert_timestamp(ts);
@@ -948,6 +1033,54 @@ fn tag_index_names(
         .collect())
 }
 
+/// Enforce the constraints of a tag on a vertex's properties
+///
+/// NOT NULL constraints are checked first, then the key of every unique index
+/// of the tag is claimed for the vertex. `previous` holds the properties the
+/// vertex had before the write, so an aborted transaction can restore its keys.
+#[allow(clippy::too_many_arguments)]
+fn enforce_vertex_constraints(
+    ctx: &GraphStorageContext,
+    index_metadata_manager: &crate::core::metadata::IndexManager,
+    space_id: u64,
+    vertex_id: &Value,
+    tag_name: &str,
+    props: &[(String, Value)],
+    previous: Option<&[(String, Value)]>,
+    ts: u32,
+) -> StorageResult<()> {
+    for constraint in index_metadata_manager.list_constraints(space_id)? {
+        if constraint.schema_name != tag_name || constraint.kind != ConstraintKind::NotNull {
+            continue;
+        }
+        if let Some(prop) = constraint.null_property(props) {
+            return Err(StorageError::constraint_violation(format!(
+                "not null constraint '{}' on {}.{}",
+                constraint.name, tag_name, prop
+            )));
+        }
+    }
+
+    for index in index_metadata_manager.list_tag_indexes(space_id)? {
+        if index.schema_name == tag_name && index.is_unique {
+            let key = index.unique_key(props);
+            let previous_key = previous.and_then(|previous| index.unique_key(previous));
+            ctx.claim_unique_vertex_key(
+                space_id,
+                vertex_id,
+                &index,
+                key.as_ref(),
+                previous_key.as_ref(),
+                ts,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the entries of the tag's non-unique indexes
+///
+/// Unique indexes are maintained by `enforce_vertex_constraints`.
 fn update_vertex_indexes(
     ctx: &GraphStorageContext,
     index_metadata_manager: &crate::core::metadata::IndexManager,
@@ -959,24 +1092,7 @@ fn update_vertex_indexes(
 ) -> StorageResult<()> {
     let indexes = index_metadata_manager.list_tag_indexes(space_id)?;
     for index in indexes {
-        if index.schema_name == tag_name {
-            // Check unique constraint before inserting.
-            // A unique index must not have an existing entry with the same
-            // property value for a different vertex.
-            if index.is_unique {
-                let index_data = ctx.index_data_manager();
-                for (_prop_name, prop_value) in props {
-                    let existing = index_data
-                        .read()
-                        .lookup_tag_index(space_id, &index, prop_value)?;
-                    if !existing.is_empty() && !existing.contains(vertex_id) {
-                        return Err(StorageError::conflict(format!(
-                            "Unique index '{}' violated: value {:?} already exists",
-                            index.name, prop_value
-                        )));
-                    }
-                }
-            }
+        if index.schema_name == tag_name && !index.is_unique {
             ctx.update_vertex_indexes_mvcc(space_id, vertex_id, &index.name, props, ts)?;
         }
     }
@@ -992,7 +1108,12 @@ fn refresh_vertex_indexes(
     props: &[(String, Value)],
     ts: u32,
 ) -> StorageResult<()> {
-    let index_names = tag_index_names(index_metadata_manager, space_id, tag_name)?;
+    let index_names: Vec<String> = index_metadata_manager
+        .list_tag_indexes(space_id)?
+        .into_iter()
+        .filter(|index| index.schema_name == tag_name && !index.is_unique)
+        .map(|index| index.name)
+        .collect();
     if index_names.is_empty() {
         return Ok(());
     }
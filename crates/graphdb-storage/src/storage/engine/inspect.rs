@@ -0,0 +1,283 @@
+//! Data Directory Inspection
+//!
+//! Offline, read-only summary of a database work directory: spaces and labels
+//! from the persisted schema, per-table file counts and sizes, WAL segments,
+//! checkpoints and snapshots. Nothing is created or modified, so it is safe to
+//! run against the directory of a server that failed to recover.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::core::metadata::SchemaManager;
+use crate::core::types::LabelId;
+use crate::core::StorageResult;
+use crate::storage::engine::graph_storage::persistence::read_checkpoint_metadata;
+use crate::storage::engine::paths::StoragePaths;
+use crate::storage::engine::snapshot_manager::{SnapshotInfo, SnapshotManager};
+use crate::transaction::wal::WalInspector;
+
+/// A tag or edge type declared in the schema
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelSummary {
+    pub id: LabelId,
+    pub name: String,
+    pub property_count: usize,
+}
+
+/// A space declared in the schema
+#[derive(Debug, Clone, Serialize)]
+pub struct SpaceSummary {
+    pub space_id: u64,
+    pub space_name: String,
+    pub tags: Vec<LabelSummary>,
+    pub edge_types: Vec<LabelSummary>,
+}
+
+/// On-disk footprint of a persisted vertex or edge table
+#[derive(Debug, Clone, Serialize)]
+pub struct TableDirSummary {
+    /// Directory name, e.g. `label_3` or `1_2_5`
+    pub dir_name: String,
+    /// Label name resolved from the schema, when known
+    pub label: Option<String>,
+    pub file_count: usize,
+    pub size_bytes: u64,
+}
+
+/// WAL directory totals
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WalDirSummary {
+    pub segment_count: usize,
+    pub size_bytes: u64,
+}
+
+/// A checkpoint directory and its metadata
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointSummary {
+    pub checkpoint_id: u64,
+    pub wal_lsn: u64,
+    pub timestamp: u32,
+    pub size_bytes: u64,
+}
+
+/// Summary of a database work directory
+#[derive(Debug, Clone, Serialize)]
+pub struct DataDirSummary {
+    pub root: PathBuf,
+    /// Contents of `data/version`, if present
+    pub format_version: Option<String>,
+    pub spaces: Vec<SpaceSummary>,
+    pub vertex_tables: Vec<TableDirSummary>,
+    pub edge_tables: Vec<TableDirSummary>,
+    pub wal: WalDirSummary,
+    pub checkpoints: Vec<CheckpointSummary>,
+    pub snapshots: Vec<SnapshotInfo>,
+    pub total_size_bytes: u64,
+}
+
+/// Build a read-only summary of the work directory rooted at `root`
+pub fn summarize_data_dir(root: &Path) -> StorageResult<DataDirSummary> {
+    let paths = StoragePaths::new(root);
+
+    let schema = SchemaManager::new();
+    let schema_file = paths.schema_file();
+    if schema_file.exists() {
+        schema.load_schema(&schema_file)?;
+    }
+
+    let mut spaces = Vec::new();
+    for space in schema.list_spaces()? {
+        let tags = schema
+            .list_tags(&space.space_name)?
+            .into_iter()
+            .map(|tag| LabelSummary {
+                id: tag.tag_id,
+                name: tag.tag_name,
+                property_count: tag.properties.len(),
+            })
+            .collect();
+        let edge_types = schema
+            .list_edge_types(&space.space_name)?
+            .into_iter()
+            .map(|edge| LabelSummary {
+                id: edge.edge_type_id,
+                name: edge.edge_type_name,
+                property_count: edge.properties.len(),
+            })
+            .collect();
+        spaces.push(SpaceSummary {
+            space_id: space.space_id,
+            space_name: space.space_name,
+            tags,
+            edge_types,
+        });
+    }
+    spaces.sort_by_key(|s| s.space_id);
+
+    let format_version = fs::read_to_string(paths.version_file())
+        .ok()
+        .map(|v| v.trim().to_string());
+
+    let vertex_tables = table_dirs(&paths.vertices_dir(), |name| {
+        let label_id: LabelId = name.strip_prefix("label_")?.parse().ok()?;
+        schema.find_tag_by_id(label_id).map(|(_, tag)| tag.tag_name)
+    })?;
+
+    let edge_tables = table_dirs(&paths.edges_dir(), |name| {
+        let edge_label: LabelId = name.rsplit('_').next()?.parse().ok()?;
+        schema
+            .find_edge_type_by_id(edge_label)
+            .map(|(_, edge)| edge.edge_type_name)
+    })?;
+
+    let mut wal = WalDirSummary::default();
+    let wal_dir = paths.wal_dir();
+    if wal_dir.exists() {
+        let segments = WalInspector::list_segments(&wal_dir)
+            .map_err(|e| crate::core::StorageError::wal_error(e.to_string()))?;
+        wal.segment_count = segments.len();
+        wal.size_bytes = segments
+            .iter()
+            .filter_map(|p| fs::metadata(p).ok())
+            .map(|m| m.len())
+            .sum();
+    }
+
+    let mut checkpoints = Vec::new();
+    let checkpoint_dir = root.join("checkpoint");
+    if checkpoint_dir.exists() {
+        for entry in fs::read_dir(&checkpoint_dir)?.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let is_checkpoint = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("checkpoint_"));
+            if !path.is_dir() || !is_checkpoint {
+                continue;
+            }
+            if let Ok(info) = read_checkpoint_metadata(&path) {
+                checkpoints.push(CheckpointSummary {
+                    checkpoint_id: info.checkpoint_id,
+                    wal_lsn: info.lsn.as_u64(),
+                    timestamp: info.timestamp,
+                    size_bytes: dir_size(&path).0,
+                });
+            }
+        }
+    }
+    checkpoints.sort_by_key(|c| c.checkpoint_id);
+
+    let mut snapshots = SnapshotManager::read_index(root.join("snapshots"))?;
+    snapshots.sort_by_key(|s| s.id);
+
+    Ok(DataDirSummary {
+        root: root.to_path_buf(),
+        format_version,
+        spaces,
+        vertex_tables,
+        edge_tables,
+        wal,
+        checkpoints,
+        snapshots,
+        total_size_bytes: dir_size(root).0,
+    })
+}
+
+fn table_dirs<F>(dir: &Path, resolve: F) -> StorageResult<Vec<TableDirSummary>>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut tables = Vec::new();
+    for entry in fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        let (size_bytes, file_count) = dir_size(&path);
+        tables.push(TableDirSummary {
+            label: resolve(&dir_name),
+            dir_name,
+            file_count,
+            size_bytes,
+        });
+    }
+    tables.sort_by(|a, b| a.dir_name.cmp(&b.dir_name));
+    Ok(tables)
+}
+
+/// Recursive `(size_bytes, file_count)` of a directory
+fn dir_size(path: &Path) -> (u64, usize) {
+    let mut size = 0;
+    let mut count = 0;
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            match entry.metadata() {
+                Ok(meta) if meta.is_dir() => {
+                    let (s, c) = dir_size(&entry.path());
+                    size += s;
+                    count += c;
+                }
+                Ok(meta) => {
+                    size += meta.len();
+                    count += 1;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+    (size, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_summarize_empty_dir() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let summary = summarize_data_dir(temp_dir.path()).expect("Failed to summarize");
+
+        assert!(summary.spaces.is_empty());
+        assert!(summary.vertex_tables.is_empty());
+        assert_eq!(summary.wal.segment_count, 0);
+        assert!(summary.checkpoints.is_empty());
+        assert!(summary.snapshots.is_empty());
+        assert!(!temp_dir.path().join("snapshots").exists());
+    }
+
+    #[test]
+    fn test_summarize_counts_table_files() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let paths = StoragePaths::new(temp_dir.path());
+        let table_dir = paths.vertex_dir(1);
+        fs::create_dir_all(&table_dir).expect("Failed to create dir");
+        fs::write(table_dir.join("meta.bin"), [0u8; 16]).expect("Failed to write");
+        fs::write(table_dir.join("columns.bin"), [0u8; 32]).expect("Failed to write");
+
+        let checkpoint = temp_dir.path().join("checkpoint").join("checkpoint_3");
+        fs::create_dir_all(&checkpoint).expect("Failed to create dir");
+        fs::write(
+            checkpoint.join("checkpoint.meta"),
+            "checkpoint_id=3\nwal_lsn=128\ntimestamp=7\n",
+        )
+        .expect("Failed to write");
+
+        let summary = summarize_data_dir(temp_dir.path()).expect("Failed to summarize");
+
+        assert_eq!(summary.vertex_tables.len(), 1);
+        assert_eq!(summary.vertex_tables[0].dir_name, "label_1");
+        assert_eq!(summary.vertex_tables[0].file_count, 2);
+        assert_eq!(summary.vertex_tables[0].size_bytes, 48);
+        assert_eq!(summary.checkpoints.len(), 1);
+        assert_eq!(summary.checkpoints[0].checkpoint_id, 3);
+        assert_eq!(summary.checkpoints[0].wal_lsn, 128);
+    }
+}
@@ -242,6 +242,27 @@ impl SnapshotManager {
         Ok(())
     }
 
+    /// Read the snapshot metadata index without creating or modifying anything.
+    ///
+    /// Used by offline inspection tools; returns an empty list when the
+    /// directory has no metadata index yet.
+    pub fn read_index<P: AsRef<Path>>(snapshots_dir: P) -> StorageResult<Vec<SnapshotInfo>> {
+        let index_path = snapshots_dir.as_ref().join(METADATA_INDEX_FILE);
+
+        if !index_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&index_path)
+            .map_err(|e| StorageError::io_error(format!("Failed to read metadata index: {}", e)))?;
+
+        let index: SnapshotMetadataIndex = serde_json::from_str(&content).map_err(|e| {
+            StorageError::deserialize_error(format!("Invalid metadata index: {}", e))
+        })?;
+
+        Ok(index.snapshots.into_values().collect())
+    }
+
     /// Get snapshot directory path
     fn get_snapshot_dir(&self, snapshot_id: u64) -> PathBuf {
         self.snapshots_dir
@@ -214,6 +214,16 @@ impl<S: crate::transaction::UndoTarget + StorageClient> crate::transaction::Undo
             original_names,
         )
     }
+    fn release_unique_claims(
+        &self,
+        txn_id: crate::core::types::TransactionId,
+    ) -> crate::transaction::undo_log::UndoLogResult<()> {
+        self.inner.release_unique_claims(txn_id)
+    }
+
+    fn retain_unique_claims(&self, txn_id: crate::core::types::TransactionId) {
+        self.inner.retain_unique_claims(txn_id)
+    }
 }
 
 macro_rules! forward_storage_methods {
@@ -339,6 +349,10 @@ impl<S: StorageClient + 'static> StorageReader for SyncWrapper<S> {
             &self,
             space: &str,
         ) -> Result<Vec<crate::core::types::Index>, StorageError>;
+        fn list_constraints(
+            &self,
+            space: &str,
+        ) -> Result<Vec<crate::core::types::Constraint>, StorageError>;
     );
 }
 
@@ -369,6 +383,12 @@ impl<S: StorageClient + 'static> StorageSchemaOps for SyncWrapper<S> {
             old_name: &str,
             new_name: &str,
         ) -> Result<bool, StorageError>;
+        fn change_tag_property_types(
+            &mut self,
+            space: &str,
+            tag: &str,
+            changes: &[crate::storage::PropertyTypeChange],
+        ) -> Result<bool, StorageError>;
         fn drop_tag(&mut self, space: &str, tag: &str) -> Result<bool, StorageError>;
         fn create_edge_type(
             &mut self,
@@ -390,6 +410,12 @@ impl<S: StorageClient + 'static> StorageSchemaOps for SyncWrapper<S> {
         ) -> Result<bool, StorageError>;
         fn drop_tag_index(&mut self, space: &str, index: &str) -> Result<bool, StorageError>;
         fn rebuild_tag_index(&mut self, space: &str, index: &str) -> Result<bool, StorageError>;
+        fn create_constraint(
+            &mut self,
+            space: &str,
+            constraint: &crate::core::types::Constraint,
+        ) -> Result<bool, StorageError>;
+        fn drop_constraint(&mut self, space: &str, name: &str) -> Result<bool, StorageError>;
     );
 }
 
@@ -406,6 +432,34 @@ impl<S: StorageClient + 'static> StorageAuthOps for SyncWrapper<S> {
             role: crate::core::RoleType,
         ) -> Result<bool, StorageError>;
         fn revoke_role(&mut self, username: &str, space_id: u64) -> Result<bool, StorageError>;
+        fn create_role(&mut self, role: &crate::core::CustomRole) -> Result<bool, StorageError>;
+        fn grant_privilege(
+            &mut self,
+            role: &str,
+            grant: &crate::core::PrivilegeGrant,
+        ) -> Result<bool, StorageError>;
+        fn grant_custom_role(&mut self, username: &str, role: &str) -> Result<bool, StorageError>;
+        fn create_api_key(&mut self, key: &crate::core::types::ApiKeyInfo) -> Result<bool, StorageError>;
+        fn revoke_api_key(&mut self, key_id: &str) -> Result<bool, StorageError>;
+    );
+
+    forward_storage_methods!(inner;
+        fn user_custom_roles(&self, username: &str) -> Vec<crate::core::CustomRole>;
+        fn effective_privileges(&self, username: &str) -> Option<crate::core::EffectivePrivileges>;
+        fn list_api_keys(&self, username: Option<&str>) -> Vec<crate::core::types::ApiKeyInfo>;
+        fn verify_api_key(&self, token: &str) -> Option<crate::core::types::ApiKeyInfo>;
+        fn get_user(&self, username: &str) -> Option<crate::core::types::UserInfo>;
+        fn check_password_policy(&self, password: &str) -> Result<(), StorageError>;
+        fn verify_login(
+            &self,
+            username: &str,
+            password: &str,
+            max_failed_attempts: u32,
+        ) -> crate::core::types::LoginCheck;
+    );
+
+    forward_storage_methods!(inner;
+        fn set_password_policy(&self, policy: crate::core::types::PasswordPolicy);
     );
 
     fn user_exists(&self, username: &str) -> bool {
@@ -11,10 +11,10 @@ use crate::storage::engine::transaction::{AddEdgeParams, TransactionOps};
 use crate::storage::types::StoragePropertyDef;
 use crate::transaction::codec::bytes_to_value;
 use crate::transaction::wal::{
-    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ClearSpaceRedo, CreateEdgeTypeRedo,
-    CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo, DeleteEdgeRedo, DeleteEdgeTypeRedo,
-    DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo, InsertEdgeRedo, RenameEdgePropRedo,
-    RenameVertexPropRedo, UpdateEdgePropRedo,
+    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ChangeVertexPropTypeRedo,
+    ClearSpaceRedo, CreateEdgeTypeRedo, CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo,
+    DeleteEdgeRedo, DeleteEdgeTypeRedo, DeleteVertexPropRedo, DeleteVertexTypeRedo, DropSpaceRedo,
+    InsertEdgeRedo, RenameEdgePropRedo, RenameVertexPropRedo, UpdateEdgePropRedo,
 };
 use graphdb_core::core::metadata::IndexMetadataManager;
 
@@ -606,6 +606,29 @@ impl RecoveryApplier for GraphStorageContext {
         Ok(())
     }
 
+    fn replay_change_vertex_prop_type(
+        &self,
+        redo: &ChangeVertexPropTypeRedo,
+        _ts: Timestamp,
+    ) -> StorageResult<()> {
+        let data_type = parse_data_type(&redo.data_type)?;
+        let (space_name, mut tag) = self
+            .schema_manager()
+            .find_tag_by_id(redo.label)
+            .ok_or_else(|| StorageError::label_not_found(format!("vertex label {}", redo.label)))?;
+
+        let prop = tag
+            .properties
+            .iter_mut()
+            .find(|prop| prop.name == redo.prop_name)
+            .ok_or_else(|| StorageError::column_not_found(redo.prop_name.clone()))?;
+        prop.data_type = data_type.clone();
+
+        self.change_vertex_property_type(redo.label, &redo.prop_name, data_type, redo.allow_lossy)?;
+        self.schema_manager().update_tag(&space_name, &tag)?;
+        Ok(())
+    }
+
     fn replay_rename_edge_prop(
         &self,
         redo: &RenameEdgePropRedo,
@@ -800,7 +823,16 @@ impl GraphStorageContext {
         let indexes = self.index_metadata_manager().list_tag_indexes(space_id)?;
         let vid_value = Value::from(vid);
         for index in indexes {
-            if index.schema_name == tag_info.tag_name {
+            if index.schema_name != tag_info.tag_name {
+                continue;
+            }
+            if index.is_unique {
+                // Unique indexes hold only the key; it was checked when first written.
+                if let Some(key) = index.unique_key(&props) {
+                    let entry = [(index.properties.join(","), key)];
+                    self.update_vertex_indexes_mvcc(space_id, &vid_value, &index.name, &entry, ts)?;
+                }
+            } else {
                 self.update_vertex_indexes_mvcc(space_id, &vid_value, &index.name, &props, ts)?;
             }
         }
@@ -1020,6 +1052,17 @@ mod tests {
         )
         .expect("Vertex rename replay should succeed");
 
+        ctx.replay_change_vertex_prop_type(
+            &ChangeVertexPropTypeRedo {
+                label: person_label,
+                prop_name: "age".to_string(),
+                data_type: "BIGINT".to_string(),
+                allow_lossy: false,
+            },
+            2,
+        )
+        .expect("Vertex type change replay should succeed");
+
         ctx.replay_delete_vertex_prop(
             &DeleteVertexPropRedo {
                 label: person_label,
@@ -1,6 +1,6 @@
 use crate::core::types::{
     ColumnId, EdgeDeletionContext, EdgeIdentifier, EdgeKey, LabelId, PropertyValue, Timestamp,
-    UndoLogError, UndoLogResult, UndoTarget, VertexIdentifier,
+    TransactionId, UndoLogError, UndoLogResult, UndoTarget, VertexIdentifier,
 };
 use crate::storage::engine::graph_storage::GraphStorageContext;
 use crate::storage::engine::transaction::{
@@ -319,4 +319,13 @@ impl UndoTarget for GraphStorageContext {
         }
         Ok(())
     }
+
+    fn release_unique_claims(&self, txn_id: TransactionId) -> UndoLogResult<()> {
+        GraphStorageContext::release_unique_claims(self, txn_id)
+            .map_err(|e| UndoLogError::UndoFailed(e.to_string()))
+    }
+
+    fn retain_unique_claims(&self, txn_id: TransactionId) {
+        self.take_unique_claims(txn_id);
+    }
 }
@@ -146,12 +146,12 @@ impl VertexIndexManager {
             {
                 if entry.is_visible_at(write_ts) {
                     let key_bytes = compressed_key.as_slice();
-                    reverse_keys_to_delete.push(compressed_key.clone());
 
                     if let Ok((_vertex_id_bytes, index_name)) =
                         KeyParser::parse_vertex_reverse_key_v2(key_bytes)
                     {
                         if index_names.contains(&index_name) {
+                            reverse_keys_to_delete.push(compressed_key.clone());
                             let forward_key_start =
                                 KeyBuilder::build_vertex_index_prefix(space_id, &index_name);
                             let forward_key_end = KeyBuilder::build_range_end(&forward_key_start);
@@ -410,6 +410,34 @@ mod tests {
         assert!(results_after.is_empty());
     }
 
+    #[test]
+    fn test_delete_vertex_indexes_keeps_other_indexes() {
+        let manager = VertexIndexManager::new();
+
+        let space_id = 1u64;
+        let vertex_id = Value::Int(123);
+        let props = vec![("name".to_string(), Value::String("Alice".to_string()))];
+        let name_index = create_test_index("idx_name", "person");
+
+        for index_name in ["idx_name", "idx_other"] {
+            manager
+                .update_vertex_indexes(space_id, &vertex_id, index_name, &props)
+                .expect("Failed to update vertex indexes");
+        }
+
+        manager
+            .delete_vertex_indexes(space_id, &vertex_id, &["idx_other".to_string()])
+            .expect("Failed to delete vertex indexes");
+        manager
+            .delete_vertex_indexes(space_id, &vertex_id, &["idx_name".to_string()])
+            .expect("Failed to delete vertex indexes");
+
+        let results = manager
+            .lookup_tag_index(space_id, &name_index, &Value::String("Alice".to_string()))
+            .expect("Failed to lookup tag index");
+        assert!(results.is_empty());
+    }
+
     #[test]
     fn test_clear_tag_index() {
         let manager = VertexIndexManager::new();
@@ -4,10 +4,14 @@ use std::time::Instant;
 use crate::core::metadata::SchemaManager;
 use crate::core::stats::StatsManager;
 use crate::core::types::{
-    EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo, LabelId, PasswordInfo, PropertyDef,
-    SpaceInfo, TagInfo, TransactionContextInfo, UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+    ApiKeyInfo, Constraint, EdgeTypeInfo, Index, InsertEdgeInfo, InsertVertexInfo, LabelId,
+    LoginCheck, PasswordInfo, PasswordPolicy, PropertyDef, SpaceInfo, TagInfo,
+    TransactionContextInfo, UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+};
+use crate::core::{
+    CustomRole, Edge, EdgeDirection, EffectivePrivileges, PrivilegeGrant, RoleType, StorageError,
+    Value, Vertex,
 };
-use crate::core::{Edge, EdgeDirection, RoleType, StorageError, Value, Vertex};
 use crate::storage::{
     StorageAdmin, StorageAuthOps, StorageClient, StorageGcOps, StoragePersistenceOps,
     StorageReader, StorageRecoveryOps, StorageSchemaContextOps, StorageSchemaOps, StorageSnapshotOps,
@@ -127,6 +131,7 @@ impl<S: StorageClient> StorageReader for MetricsStorage<S> {
     wrap_read!(list_edge_types(self, space: &str) -> Result<Vec<EdgeTypeInfo>, StorageError>);
     wrap_read!(get_tag_index(self, space: &str, index: &str) -> Result<Option<Index>, StorageError>);
     wrap_read!(list_tag_indexes(self, space: &str) -> Result<Vec<Index>, StorageError>);
+    wrap_read!(list_constraints(self, space: &str) -> Result<Vec<Constraint>, StorageError>);
 }
 
 impl<S: StorageClient> StorageWriter for MetricsStorage<S> {
@@ -172,6 +177,7 @@ impl<S: StorageClient> StorageSchemaOps for MetricsStorage<S> {
     wrap_write!(alter_tag(self, space: &str, tag: &str, additions: Vec<PropertyDef>, deletions: Vec<String>) -> Result<bool, StorageError>);
     wrap_write!(rename_vertex_property(self, label: LabelId, old_name: &str, new_name: &str) -> Result<(), StorageError>);
     wrap_write!(rename_tag_property(self, space: &str, tag: &str, old_name: &str, new_name: &str) -> Result<bool, StorageError>);
+    wrap_write!(change_tag_property_types(self, space: &str, tag: &str, changes: &[crate::storage::PropertyTypeChange]) -> Result<bool, StorageError>);
     wrap_write!(drop_tag(self, space: &str, tag: &str) -> Result<bool, StorageError>);
     wrap_write!(create_edge_type(self, space: &str, edge: &EdgeTypeInfo) -> Result<u32, StorageError>);
     wrap_write!(alter_edge_type(self, space: &str, edge_type: &str, additions: Vec<PropertyDef>, deletions: Vec<String>) -> Result<bool, StorageError>);
@@ -179,6 +185,8 @@ impl<S: StorageClient> StorageSchemaOps for MetricsStorage<S> {
     wrap_write!(create_tag_index(self, space: &str, info: &Index) -> Result<bool, StorageError>);
     wrap_write!(drop_tag_index(self, space: &str, index: &str) -> Result<bool, StorageError>);
     wrap_write!(rebuild_tag_index(self, space: &str, index: &str) -> Result<bool, StorageError>);
+    wrap_write!(create_constraint(self, space: &str, constraint: &Constraint) -> Result<bool, StorageError>);
+    wrap_write!(drop_constraint(self, space: &str, name: &str) -> Result<bool, StorageError>);
 }
 
 impl<S: StorageClient> StorageAuthOps for MetricsStorage<S> {
@@ -192,8 +200,61 @@ impl<S: StorageClient> StorageAuthOps for MetricsStorage<S> {
         self.record_read(start.elapsed().as_micros() as u64, true);
         result
     }
+    fn get_user(&self, username: &str) -> Option<UserInfo> {
+        let start = Instant::now();
+        let result = self.inner.get_user(username);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
+    fn set_password_policy(&self, policy: PasswordPolicy) {
+        self.inner.set_password_policy(policy)
+    }
+    fn check_password_policy(&self, password: &str) -> Result<(), StorageError> {
+        self.inner.check_password_policy(password)
+    }
+    fn verify_login(&self, username: &str, password: &str, max_failed_attempts: u32) -> LoginCheck {
+        let start = Instant::now();
+        let result = self
+            .inner
+            .verify_login(username, password, max_failed_attempts);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
     wrap_write!(grant_role(self, username: &str, space_id: u64, role: RoleType) -> Result<bool, StorageError>);
     wrap_write!(revoke_role(self, username: &str, space_id: u64) -> Result<bool, StorageError>);
+    wrap_write!(create_role(self, role: &CustomRole) -> Result<bool, StorageError>);
+    wrap_write!(grant_privilege(self, role: &str, grant: &PrivilegeGrant) -> Result<bool, StorageError>);
+    wrap_write!(grant_custom_role(self, username: &str, role: &str) -> Result<bool, StorageError>);
+    wrap_write!(create_api_key(self, key: &ApiKeyInfo) -> Result<bool, StorageError>);
+    wrap_write!(revoke_api_key(self, key_id: &str) -> Result<bool, StorageError>);
+
+    fn user_custom_roles(&self, username: &str) -> Vec<CustomRole> {
+        let start = Instant::now();
+        let result = self.inner.user_custom_roles(username);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
+
+    fn effective_privileges(&self, username: &str) -> Option<EffectivePrivileges> {
+        let start = Instant::now();
+        let result = self.inner.effective_privileges(username);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
+
+    fn list_api_keys(&self, username: Option<&str>) -> Vec<ApiKeyInfo> {
+        let start = Instant::now();
+        let result = self.inner.list_api_keys(username);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
+
+    fn verify_api_key(&self, token: &str) -> Option<ApiKeyInfo> {
+        let start = Instant::now();
+        let result = self.inner.verify_api_key(token);
+        self.record_read(start.elapsed().as_micros() as u64, true);
+        result
+    }
 }
 
 impl<S: StorageClient> StorageAdmin for MetricsStorage<S> {
@@ -538,6 +599,17 @@ impl<S: crate::transaction::UndoTarget + StorageClient> crate::transaction::Undo
             original_names,
         )
     }
+
+    fn release_unique_claims(
+        &self,
+        txn_id: crate::core::types::TransactionId,
+    ) -> crate::transaction::undo_log::UndoLogResult<()> {
+        crate::transaction::UndoTarget::release_unique_claims(&self.inner, txn_id)
+    }
+
+    fn retain_unique_claims(&self, txn_id: crate::core::types::TransactionId) {
+        crate::transaction::UndoTarget::retain_unique_claims(&self.inner, txn_id)
+    }
 }
 
 #[cfg(test)]
@@ -32,11 +32,16 @@ pub use client::{
     StorageStats, StorageSyncContextOps, StorageTransactionContextOps, StorageWriter,
 };
 pub use engine::graph_storage::GraphStorage;
+pub use engine::inspect::{
+    summarize_data_dir, CheckpointSummary, DataDirSummary, LabelSummary, SpaceSummary,
+    TableDirSummary, WalDirSummary,
+};
 pub use engine::persistence_coordinator::{CheckpointStats, SnapshotStats};
 pub use engine::sync_wrapper::SyncWrapper;
 pub use engine::transaction::UndoTarget;
 pub use metrics::MetricsStorage;
 pub use mvcc::{MVCCTable, SnapshotHandle, TieredTombstoneManager, TombstoneEntry};
+pub use schema::{conversion_kind, ConversionKind, PropertyTypeChange};
 pub use sync::{EdgeTableSync, PropertyTableSync, SnapshotGuard, VertexTableSync};
 pub use types::StoragePropertyDef;
 pub use vertex::{VertexSchema, VertexTable};
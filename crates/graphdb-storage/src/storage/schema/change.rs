@@ -38,10 +38,15 @@ pub enum ChangeDetails {
     },
 
     /// Property type modified: (property_name, old_type, new_type)
+    ///
+    /// `lossy` is set when existing values were rounded, truncated or nulled out
+    /// during the conversion.
     PropertyTypeModified {
         name: String,
         old_type: DataType,
         new_type: DataType,
+        #[serde(default)]
+        lossy: bool,
     },
 
     /// Property nullability changed
@@ -95,10 +100,14 @@ impl ChangeDetails {
                 name,
                 old_type,
                 new_type,
+                lossy,
             } => {
                 format!(
-                    "Modified property '{}' type from {:?} to {:?}",
-                    name, old_type, new_type
+                    "Modified property '{}' type from {:?} to {:?}{}",
+                    name,
+                    old_type,
+                    new_type,
+                    if *lossy { " (lossy)" } else { "" }
                 )
             }
             ChangeDetails::PropertyNullabilityChanged {
@@ -10,25 +10,29 @@
 //!
 //! - `change`: Schema change events (`PropertyChange`, `ChangeDetails`) and change logs
 //! - `version_history`: Version tracking for each label (`LabelVersionHistory`, `SchemaVersionHistory`)
+//! - `type_conversion`: Rules for converting existing values when a property type changes
 //!
 //! ## Current Capabilities
 //!
 //! - Record atomic schema changes via `PropertyChange` events
 //! - Store changes in `ChangeLog` indexed by version number
 //! - Query version history and detect breaking changes via `can_migrate()`
+//! - Rewrite a vertex property column when its type changes (`VertexTable::change_property_type`)
 //!
 //! ## Not Currently Supported
 //!
-//! - Automatic data migration on schema changes
+//! - Automatic data migration for other schema changes
 //! - Zero-downtime schema upgrades
 //! - Compatibility scoring and migration strategies
 //!
 //! If these features are needed in the future, refer to git history for the migration framework design.
 
 pub mod change;
+pub mod type_conversion;
 pub mod version_history;
 
 pub use change::{ChangeDetails, ChangeLog, PropertyChange, SchemaObjectType};
+pub use type_conversion::{conversion_kind, convert_value, ConversionKind, PropertyTypeChange};
 pub use version_history::{
     LabelVersionHistory, SchemaVersionHistory,
 };
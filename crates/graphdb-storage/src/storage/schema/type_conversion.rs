@@ -0,0 +1,234 @@
+//! Property type conversion rules
+//!
+//! Classifies how faithfully existing values survive a property type change and
+//! converts individual values when ALTER TAG rewrites a column. The rewrite runs
+//! synchronously inside the statement, not as a background job.
+//!
+//! - `Lossless`: every value of the old type has an exact representation in the new type
+//! - `Checked`: values that convert are exact, but some may not convert at all
+//!   (narrowing integers, parsing strings); the change aborts on the first failure
+//! - `Lossy`: values are rounded or truncated (double → float, datetime → date);
+//!   the change must be requested explicitly
+//! - `Unsupported`: no conversion exists or the column store cannot hold the new type
+
+use crate::core::value::Decimal128Value;
+use crate::core::{DataType, StorageError, StorageResult, Value};
+
+/// How a property type change treats existing values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionKind {
+    Lossless,
+    Checked,
+    Lossy,
+    Unsupported,
+}
+
+impl ConversionKind {
+    /// Lossy conversions must be requested explicitly
+    pub fn requires_force(&self) -> bool {
+        matches!(self, ConversionKind::Lossy)
+    }
+}
+
+/// One property type change of an ALTER statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyTypeChange {
+    pub name: String,
+    pub data_type: DataType,
+    /// Permit lossy conversions (the `FORCE` keyword)
+    pub allow_lossy: bool,
+}
+
+impl PropertyTypeChange {
+    pub fn new(name: impl Into<String>, data_type: DataType, allow_lossy: bool) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            allow_lossy,
+        }
+    }
+}
+
+/// Types the column store can hold, and therefore the only valid conversion targets
+fn is_column_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Bool
+            | DataType::SmallInt
+            | DataType::Int
+            | DataType::BigInt
+            | DataType::Float
+            | DataType::Double
+            | DataType::Decimal128
+            | DataType::String
+            | DataType::Date
+            | DataType::Time
+            | DataType::DateTime
+    )
+}
+
+/// Classify a type change from `from` to `to`
+pub fn conversion_kind(from: &DataType, to: &DataType) -> ConversionKind {
+    use DataType::*;
+
+    if !is_column_type(from) || !is_column_type(to) {
+        return ConversionKind::Unsupported;
+    }
+    if from == to {
+        return ConversionKind::Lossless;
+    }
+
+    match (from, to) {
+        // Every column type has an exact textual form
+        (_, String) => ConversionKind::Lossless,
+
+        (Bool, SmallInt | Int | BigInt | Decimal128) => ConversionKind::Lossless,
+
+        (SmallInt, Int | BigInt | Float | Double | Decimal128) => ConversionKind::Lossless,
+        (Int, BigInt | Double | Decimal128) => ConversionKind::Lossless,
+        (BigInt, Decimal128) => ConversionKind::Lossless,
+        (Int, SmallInt) | (BigInt, SmallInt | Int) => ConversionKind::Checked,
+        (Int, Float) | (BigInt, Float | Double) => ConversionKind::Lossy,
+
+        (Float, Double) => ConversionKind::Lossless,
+        // NaN and infinities have no decimal representation
+        (Float | Double, Decimal128) => ConversionKind::Checked,
+        (Double, Float) => ConversionKind::Lossy,
+        (Float | Double, SmallInt | Int | BigInt) => ConversionKind::Lossy,
+        (Decimal128, Float | Double | SmallInt | Int | BigInt) => ConversionKind::Lossy,
+
+        (String, _) => ConversionKind::Checked,
+
+        (Date, DateTime) => ConversionKind::Lossless,
+        (DateTime, Date | Time) => ConversionKind::Lossy,
+
+        _ => ConversionKind::Unsupported,
+    }
+}
+
+fn conversion_error(value: &Value, target: &DataType) -> StorageError {
+    StorageError::invalid_input(format!(
+        "Cannot convert value {:?} to {}",
+        value, target
+    ))
+}
+
+fn parse_decimal(s: &str, value: &Value, target: &DataType) -> StorageResult<Value> {
+    s.trim()
+        .parse::<Decimal128Value>()
+        .map(Value::Decimal128)
+        .map_err(|_| conversion_error(value, target))
+}
+
+fn to_decimal(value: &Value, target: &DataType) -> StorageResult<Value> {
+    match value {
+        Value::Decimal128(d) => Ok(Value::Decimal128(d.clone())),
+        Value::Bool(b) => Ok(Value::Decimal128(Decimal128Value::from_i64(*b as i64))),
+        Value::SmallInt(i) => Ok(Value::Decimal128(Decimal128Value::from_i64(*i as i64))),
+        Value::Int(i) => Ok(Value::Decimal128(Decimal128Value::from_i64(*i as i64))),
+        Value::BigInt(i) => Ok(Value::Decimal128(Decimal128Value::from_i64(*i))),
+        // The shortest round-trip representation keeps the value the user sees
+        Value::Float(f) if f.is_finite() => parse_decimal(&f.to_string(), value, target),
+        Value::Double(f) if f.is_finite() => parse_decimal(&f.to_string(), value, target),
+        Value::String(s) | Value::FixedString { data: s, .. } => parse_decimal(s, value, target),
+        _ => Err(conversion_error(value, target)),
+    }
+}
+
+/// Convert a single value to `target`
+///
+/// Nulls stay null. Conversions that cannot produce a value (an unparsable string,
+/// an integer out of range) fail instead of silently producing a null.
+pub fn convert_value(value: &Value, target: &DataType) -> StorageResult<Value> {
+    if value.is_null() || matches!(value, Value::Empty) {
+        return Ok(value.clone());
+    }
+
+    match (value, target) {
+        (_, DataType::Decimal128) => to_decimal(value, target),
+        (Value::Decimal128(d), DataType::String) => Ok(Value::String(d.to_string())),
+        (Value::Decimal128(d), _) => {
+            let as_double = d
+                .to_string()
+                .parse::<f64>()
+                .map(Value::Double)
+                .map_err(|_| conversion_error(value, target))?;
+            convert_value(&as_double, target)
+        }
+        (_, DataType::String) => value
+            .to_string()
+            .map(Value::String)
+            .map_err(|_| conversion_error(value, target)),
+        _ => {
+            let converted = value
+                .try_cast_to(target)
+                .map_err(|_| conversion_error(value, target))?;
+            if converted.is_null() {
+                return Err(conversion_error(value, target));
+            }
+            Ok(converted)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion_kind() {
+        assert_eq!(
+            conversion_kind(&DataType::Int, &DataType::BigInt),
+            ConversionKind::Lossless
+        );
+        assert_eq!(
+            conversion_kind(&DataType::Int, &DataType::String),
+            ConversionKind::Lossless
+        );
+        assert_eq!(
+            conversion_kind(&DataType::String, &DataType::DateTime),
+            ConversionKind::Checked
+        );
+        assert_eq!(
+            conversion_kind(&DataType::Float, &DataType::Decimal128),
+            ConversionKind::Checked
+        );
+        assert_eq!(
+            conversion_kind(&DataType::Double, &DataType::Float),
+            ConversionKind::Lossy
+        );
+        assert_eq!(
+            conversion_kind(&DataType::String, &DataType::Vertex),
+            ConversionKind::Unsupported
+        );
+        assert!(ConversionKind::Lossy.requires_force());
+        assert!(!ConversionKind::Checked.requires_force());
+    }
+
+    #[test]
+    fn test_convert_value() {
+        assert_eq!(
+            convert_value(&Value::Int(42), &DataType::BigInt).unwrap(),
+            Value::BigInt(42)
+        );
+        assert_eq!(
+            convert_value(&Value::Int(42), &DataType::String).unwrap(),
+            Value::String("42".to_string())
+        );
+        assert!(matches!(
+            convert_value(
+                &Value::String("2024-05-01 10:30:00".to_string()),
+                &DataType::DateTime
+            )
+            .unwrap(),
+            Value::DateTime(_)
+        ));
+        match convert_value(&Value::Float(0.1), &DataType::Decimal128).unwrap() {
+            Value::Decimal128(d) => assert_eq!(d.to_string(), "0.1"),
+            other => panic!("Expected Decimal128, got {:?}", other),
+        }
+        assert!(convert_value(&Value::BigInt(i64::MAX), &DataType::Int).is_err());
+        assert!(convert_value(&Value::String("abc".to_string()), &DataType::Int).is_err());
+        assert!(convert_value(&Value::Float(f32::NAN), &DataType::Decimal128).is_err());
+    }
+}
@@ -1,10 +1,12 @@
 use crate::core::error::StorageError;
 use crate::core::types::{
-    EdgeTypeInfo, EdgeTypeSchema, Index, InsertEdgeInfo, InsertVertexInfo, LabelId, PasswordInfo,
-    PropertyDef, SpaceInfo, TagInfo, TransactionContextInfo, UpdateInfo, UserAlterInfo, UserInfo,
-    VertexId,
+    ApiKeyInfo, Constraint, EdgeTypeInfo, EdgeTypeSchema, Index, InsertEdgeInfo, InsertVertexInfo,
+    LabelId, LoginCheck, PasswordInfo, PasswordPolicy, PropertyDef, SpaceInfo, TagInfo,
+    TransactionContextInfo, UpdateInfo, UserAlterInfo, UserInfo, VertexId,
+};
+use crate::core::{
+    CustomRole, Edge, EdgeDirection, EffectivePrivileges, PrivilegeGrant, RoleType, Value, Vertex,
 };
-use crate::core::{Edge, EdgeDirection, RoleType, Value, Vertex};
 use crate::storage::engine::graph_storage::GraphStorageContext;
 use crate::storage::{
     StorageAdmin, StorageAuthOps, StorageGcOps, StoragePersistenceOps, StorageReader,
@@ -94,6 +96,7 @@ impl StorageReader for MockStorage {
     mock_stub!(&self, list_edge_types(_space: &str) -> Result<Vec<EdgeTypeSchema>, StorageError>, Ok(Vec::new()));
     mock_stub!(&self, get_tag_index(_space: &str, _index: &str) -> Result<Option<Index>, StorageError>, Ok(None));
     mock_stub!(&self, list_tag_indexes(_space: &str) -> Result<Vec<Index>, StorageError>, Ok(Vec::new()));
+    mock_stub!(&self, list_constraints(_space: &str) -> Result<Vec<Constraint>, StorageError>, Ok(Vec::new()));
 }
 
 impl StorageWriter for MockStorage {
@@ -149,6 +152,7 @@ impl StorageSchemaOps for MockStorage {
     mock_stub!(&mut self, alter_tag(_space: &str, _tag: &str, _additions: Vec<PropertyDef>, _deletions: Vec<String>) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, rename_vertex_property(_label: LabelId, _old_name: &str, _new_name: &str) -> Result<(), StorageError>, Ok(()));
     mock_stub!(&mut self, rename_tag_property(_space: &str, _tag: &str, _old_name: &str, _new_name: &str) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, change_tag_property_types(_space: &str, _tag: &str, _changes: &[crate::storage::PropertyTypeChange]) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, drop_tag(_space: &str, _tag: &str) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, create_edge_type(_space: &str, _info: &EdgeTypeSchema) -> Result<u32, StorageError>, Ok(1));
     mock_stub!(&mut self, alter_edge_type(_space: &str, _edge_type: &str, _additions: Vec<PropertyDef>, _deletions: Vec<String>) -> Result<bool, StorageError>, Ok(true));
@@ -156,6 +160,8 @@ impl StorageSchemaOps for MockStorage {
     mock_stub!(&mut self, create_tag_index(_space: &str, _info: &Index) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, drop_tag_index(_space: &str, _index: &str) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, rebuild_tag_index(_space: &str, _index: &str) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, create_constraint(_space: &str, _constraint: &Constraint) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, drop_constraint(_space: &str, _name: &str) -> Result<bool, StorageError>, Ok(true));
 }
 
 impl StorageAuthOps for MockStorage {
@@ -164,8 +170,21 @@ impl StorageAuthOps for MockStorage {
     mock_stub!(&mut self, alter_user(_info: &UserAlterInfo) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, drop_user(_username: &str) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&self, user_exists(_username: &str) -> bool, false);
+    mock_stub!(&self, get_user(_username: &str) -> Option<UserInfo>, None);
+    fn set_password_policy(&self, _policy: PasswordPolicy) {}
+    mock_stub!(&self, check_password_policy(_password: &str) -> Result<(), StorageError>, Ok(()));
+    mock_stub!(&self, verify_login(_username: &str, _password: &str, _max_failed_attempts: u32) -> LoginCheck, LoginCheck::UnknownUser);
     mock_stub!(&mut self, grant_role(_username: &str, _space_id: u64, _role: RoleType) -> Result<bool, StorageError>, Ok(true));
     mock_stub!(&mut self, revoke_role(_username: &str, _space_id: u64) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, create_role(_role: &CustomRole) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, grant_privilege(_role: &str, _grant: &PrivilegeGrant) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, grant_custom_role(_username: &str, _role: &str) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&self, user_custom_roles(_username: &str) -> Vec<CustomRole>, Vec::new());
+    mock_stub!(&self, effective_privileges(_username: &str) -> Option<EffectivePrivileges>, None);
+    mock_stub!(&mut self, create_api_key(_key: &ApiKeyInfo) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&mut self, revoke_api_key(_key_id: &str) -> Result<bool, StorageError>, Ok(true));
+    mock_stub!(&self, list_api_keys(_username: Option<&str>) -> Vec<ApiKeyInfo>, Vec::new());
+    mock_stub!(&self, verify_api_key(_token: &str) -> Option<ApiKeyInfo>, None);
 }
 
 impl StorageAdmin for MockStorage {
@@ -396,6 +415,17 @@ impl UndoTarget for MockStorage {
             original_names,
         )
     }
+
+    fn release_unique_claims(
+        &self,
+        txn_id: crate::core::types::TransactionId,
+    ) -> crate::transaction::undo_log::UndoLogResult<()> {
+        crate::transaction::UndoTarget::release_unique_claims(&self.graph, txn_id)
+    }
+
+    fn retain_unique_claims(&self, txn_id: crate::core::types::TransactionId) {
+        self.graph.retain_unique_claims(txn_id)
+    }
 }
 
 impl StorageRecoveryOps for MockStorage {
@@ -5,7 +5,7 @@
 //!
 //! The storage is split into two variants:
 //! - `FixedWidthColumn`: For fixed-length types (Bool, SmallInt, Int, BigInt, Float, Double, Date, Time, Uuid)
-//! - `VariableWidthColumn`: For variable-length types (String)
+//! - `VariableWidthColumn`: For variable-length types (String, Decimal128)
 //! - `Column`: Public wrapper that selects the appropriate variant at construction time
 
 use crate::core::value::{DateTimeValue, DateValue, TimeValue, VectorValue};
@@ -72,6 +72,7 @@ pub fn is_variable_length_type(data_type: &DataType) -> bool {
     matches!(
         data_type,
         DataType::String
+            | DataType::Decimal128
             | DataType::Geography
             | DataType::List
             | DataType::Map
@@ -342,6 +343,11 @@ impl ColumnStorage for VariableWidthColumn {
             } else {
                 None
             }
+        } else if matches!(self.data_type, DataType::Decimal128) {
+            std::str::from_utf8(bytes)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map(Value::Decimal128)
         } else {
             String::from_utf8(bytes.to_vec()).ok().map(Value::String)
         }
@@ -601,6 +607,14 @@ fn write_variable_value(data: &mut Vec<u8>, value: &Value) -> StorageResult<()>
             data.extend_from_slice(&len.to_le_bytes());
             data.extend_from_slice(bytes);
         }
+        Value::Decimal128(d) => {
+            // Stored in canonical text form, which round-trips exactly
+            let text = d.to_string();
+            let bytes = text.as_bytes();
+            let len = bytes.len() as u64;
+            data.extend_from_slice(&len.to_le_bytes());
+            data.extend_from_slice(bytes);
+        }
         Value::Geography(geo) => {
             let json = serde_json::to_vec(geo).map_err(|e| {
                 StorageError::invalid_input(format!("Failed to serialize Geography: {}", e))
@@ -1306,6 +1320,21 @@ impl ColumnStore {
         Ok(())
     }
 
+    /// Swap in a column with the same name, returning the column it replaces.
+    ///
+    /// Used by property type changes: the replacement is built off to the side and
+    /// installed in one step, so readers see either the old or the new column.
+    pub fn replace_column(&mut self, mut column: Column) -> StorageResult<Column> {
+        let index = self
+            .name_to_index
+            .get(&column.name)
+            .copied()
+            .ok_or_else(|| StorageError::column_not_found(column.name.clone()))?;
+
+        column.col_id = index as i32;
+        Ok(std::mem::replace(&mut self.columns[index], column))
+    }
+
     pub fn column_count(&self) -> usize {
         self.columns.len()
     }
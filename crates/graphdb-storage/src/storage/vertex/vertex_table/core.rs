@@ -1171,6 +1171,103 @@ mod tests {
         }
     }
 
+    fn age_of(table: &VertexTable, id: &str) -> Option<Value> {
+        let internal_id = table.get_internal_id(id, 300)?;
+        let record = table.get_by_internal_id(internal_id, 300)?;
+        record
+            .properties
+            .into_iter()
+            .find(|(name, _)| name == "age")
+            .map(|(_, value)| value)
+    }
+
+    #[test]
+    fn test_change_property_type_converts_existing_rows() {
+        use crate::storage::schema::ChangeDetails;
+
+        let schema = create_test_schema();
+        let mut table = VertexTable::new(1, "User".to_string(), schema);
+        table
+            .insert(
+                "v1",
+                &[
+                    ("name".to_string(), Value::String("Alice".to_string())),
+                    ("age".to_string(), Value::Int(30)),
+                ],
+                100,
+            )
+            .unwrap();
+
+        table
+            .change_property_type("age", DataType::BigInt, false)
+            .expect("widening should succeed");
+        assert_eq!(age_of(&table, "v1"), Some(Value::BigInt(30)));
+
+        table
+            .change_property_type("age", DataType::String, false)
+            .expect("conversion to string should succeed");
+        assert_eq!(age_of(&table, "v1"), Some(Value::String("30".to_string())));
+        assert_eq!(table.schema().properties[1].data_type, DataType::String);
+        assert_eq!(table.schema().schema_version, 3);
+
+        let history = table.version_history.lock().unwrap();
+        let changes = history.change_log.get_version_changes(3).unwrap();
+        match &changes[0].details {
+            ChangeDetails::PropertyTypeModified {
+                old_type,
+                new_type,
+                lossy,
+                ..
+            } => {
+                assert_eq!(old_type, &DataType::BigInt);
+                assert_eq!(new_type, &DataType::String);
+                assert!(!lossy);
+            }
+            _ => panic!("Expected PropertyTypeModified change"),
+        }
+    }
+
+    #[test]
+    fn test_change_property_type_rejects_lossy_without_force() {
+        let schema = create_test_schema();
+        let mut table = VertexTable::new(1, "User".to_string(), schema);
+        table
+            .insert(
+                "v1",
+                &[
+                    ("name".to_string(), Value::String("Alice".to_string())),
+                    ("age".to_string(), Value::Int(30)),
+                ],
+                100,
+            )
+            .unwrap();
+        table
+            .change_property_type("age", DataType::String, false)
+            .unwrap();
+        table
+            .update_property(0, "age", &Value::String("unknown".to_string()), 200)
+            .unwrap();
+
+        // A value that does not parse aborts the change and leaves the column untouched
+        assert!(table
+            .change_property_type("age", DataType::Int, false)
+            .is_err());
+        assert_eq!(table.schema().properties[1].data_type, DataType::String);
+
+        // FORCE nulls out what cannot be converted
+        table
+            .change_property_type("age", DataType::Int, true)
+            .unwrap();
+        assert_eq!(age_of(&table, "v1"), None);
+
+        assert!(table
+            .change_property_type("age", DataType::Float, false)
+            .is_err());
+        assert!(table
+            .change_property_type("name", DataType::Int, true)
+            .is_err());
+    }
+
     // ==================== Compaction Edge Case Tests ====================
 
     /// Test: Compact after deleting all vertices
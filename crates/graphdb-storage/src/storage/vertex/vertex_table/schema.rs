@@ -1,11 +1,14 @@
 //! Vertex Table Schema Management
 //!
-//! Handles schema operations like adding, removing, and renaming properties.
+//! Handles schema operations like adding, removing, renaming and retyping properties.
 //! Schema modifications invalidate the property index cache, which is rebuilt on-demand.
 
-use crate::core::StorageResult;
+use crate::core::{DataType, StorageResult};
+use crate::storage::schema::{
+    conversion_kind, convert_value, ChangeDetails, ConversionKind, PropertyChange, SchemaObjectType,
+};
 use crate::storage::types::StoragePropertyDef;
-use crate::storage::schema::{ChangeDetails, PropertyChange, SchemaObjectType};
+use crate::storage::vertex::column_store::Column;
 
 use super::core::VertexTable;
 
@@ -159,4 +162,146 @@ impl VertexTable {
 
         Ok(())
     }
+
+    /// Build a copy of a property column converted to `new_type`.
+    ///
+    /// Only reads the table, so callers can run it under a shared lock and install
+    /// the result with [`VertexTable::apply_property_column`]. Lossy conversions are
+    /// rejected unless `allow_lossy` is set; with it, values that fail to convert
+    /// become null in nullable columns. Returns the column and whether it is lossy.
+    pub fn convert_property_column(
+        &self,
+        prop_name: &str,
+        new_type: &DataType,
+        allow_lossy: bool,
+    ) -> StorageResult<(Column, bool)> {
+        if !self.is_open {
+            return Err(crate::core::StorageError::storage_not_open());
+        }
+
+        let index = self
+            .schema
+            .properties
+            .iter()
+            .position(|prop| prop.name == prop_name)
+            .ok_or_else(|| crate::core::StorageError::column_not_found(prop_name.to_string()))?;
+
+        if index == self.schema.primary_key_index {
+            return Err(crate::core::StorageError::not_supported(
+                "Changing the type of the primary key property is not supported".to_string(),
+            ));
+        }
+
+        let prop = &self.schema.properties[index];
+        let old_column = self
+            .columns
+            .get_column(prop_name)
+            .ok_or_else(|| crate::core::StorageError::column_not_found(prop_name.to_string()))?;
+
+        let kind = conversion_kind(&prop.data_type, new_type);
+        match kind {
+            ConversionKind::Unsupported => {
+                return Err(crate::core::StorageError::not_supported(format!(
+                    "Cannot change property '{}' from {} to {}",
+                    prop_name, prop.data_type, new_type
+                )));
+            }
+            ConversionKind::Lossy if !allow_lossy => {
+                return Err(crate::core::StorageError::invalid_input(format!(
+                    "Changing property '{}' from {} to {} loses precision; use FORCE to apply it",
+                    prop_name, prop.data_type, new_type
+                )));
+            }
+            _ => {}
+        }
+
+        let mut lossy = kind.requires_force();
+        let mut column = Column::new(
+            prop_name.to_string(),
+            old_column.col_id,
+            new_type.clone(),
+            prop.nullable,
+        );
+        for row in 0..old_column.len() {
+            let converted = match old_column.get(row) {
+                None => None,
+                Some(value) => match convert_value(&value, new_type) {
+                    Ok(converted) => Some(converted),
+                    Err(_) if allow_lossy && prop.nullable => {
+                        lossy = true;
+                        None
+                    }
+                    Err(e) => {
+                        return Err(crate::core::StorageError::invalid_input(format!(
+                            "Property '{}' row {}: {}",
+                            prop_name, row, e
+                        )));
+                    }
+                },
+            };
+            column.set(row, converted.as_ref())?;
+        }
+
+        Ok((column, lossy))
+    }
+
+    /// Install a column built by [`VertexTable::convert_property_column`].
+    pub fn apply_property_column(&mut self, column: Column, lossy: bool) -> StorageResult<()> {
+        if !self.is_open {
+            return Err(crate::core::StorageError::storage_not_open());
+        }
+
+        let index = self
+            .schema
+            .properties
+            .iter()
+            .position(|prop| prop.name == column.name)
+            .ok_or_else(|| crate::core::StorageError::column_not_found(column.name.clone()))?;
+
+        // The table may have grown since the column was built
+        if column.len() < self.columns.row_count() {
+            return Err(crate::core::StorageError::invalid_input(format!(
+                "Converted column '{}' is stale; retry the type change",
+                column.name
+            )));
+        }
+
+        let new_type = column.data_type.clone();
+        let name = column.name.clone();
+        self.columns.replace_column(column)?;
+
+        let old_type = std::mem::replace(
+            &mut self.schema.properties[index].data_type,
+            new_type.clone(),
+        );
+
+        self.schema.increment_version();
+
+        let change = PropertyChange::new(
+            self.schema.schema_version,
+            SchemaObjectType::Vertex,
+            self.label,
+            self.label_name.clone(),
+            ChangeDetails::PropertyTypeModified {
+                name,
+                old_type,
+                new_type,
+                lossy,
+            },
+        );
+        self.version_history.lock().unwrap().add_change(change);
+
+        Ok(())
+    }
+
+    /// Convert a property to `new_type` in place.
+    pub fn change_property_type(
+        &mut self,
+        prop_name: &str,
+        new_type: DataType,
+        allow_lossy: bool,
+    ) -> StorageResult<()> {
+        let (column, lossy) = self.convert_property_column(prop_name, &new_type, allow_lossy)?;
+        self.apply_property_column(column, lossy)
+    }
 }
@@ -75,6 +75,7 @@ pub struct SyncCoordinatorBuilder {
     fulltext_manager: Option<Arc<crate::search::manager::FulltextIndexManager>>,
     config: Option<crate::sync::batch::BatchConfig>,
     stats_manager: Option<Arc<crate::core::stats::StatsManager>>,
+    dead_letter_queue: Option<Arc<DeadLetterQueue>>,
 }
 
 #[cfg(feature = "fulltext-search")]
@@ -91,6 +92,7 @@ impl SyncCoordinatorBuilder {
             fulltext_manager: None,
             config: None,
             stats_manager: None,
+            dead_letter_queue: None,
         }
     }
 
@@ -115,6 +117,11 @@ impl SyncCoordinatorBuilder {
         self
     }
 
+    pub fn with_dead_letter_queue(mut self, dlq: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letter_queue = Some(dlq);
+        self
+    }
+
     pub fn build(self) -> Result<Arc<SyncCoordinator>, crate::sync::SyncError> {
         let manager = self.fulltext_manager.ok_or_else(|| {
             crate::sync::SyncError::Internal("FulltextIndexManager is required".to_string())
@@ -127,6 +134,10 @@ impl SyncCoordinatorBuilder {
             coordinator = coordinator.with_stats_manager(stats);
         }
 
+        if let Some(dlq) = self.dead_letter_queue {
+            coordinator = coordinator.with_dead_letter_queue(dlq);
+        }
+
         Ok(Arc::new(coordinator))
     }
 }
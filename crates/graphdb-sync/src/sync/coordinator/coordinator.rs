@@ -15,6 +15,9 @@ use crate::sync::batch::{
     BatchConfig, BatchProcessor, FulltextBatchProcessor, TransactionBatchBuffer,
 };
 
+use crate::sync::circuit_breaker::{
+    with_circuit_breaker, CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError,
+};
 use crate::sync::dead_letter_queue::{DeadLetterEntry, DeadLetterQueue, DeadLetterQueueConfig};
 use crate::sync::retry::{default_local_retry_config, with_retry};
 use crate::sync::types::{IndexOpKey, IndexOperation};
@@ -27,6 +30,8 @@ pub struct SyncCoordinator {
     transaction_buffers: DashMap<crate::core::types::TransactionId, Arc<TransactionBatchBuffer>>,
     config: BatchConfig,
     dead_letter_queue: Arc<DeadLetterQueue>,
+    /// Guards dead letter replays so a still-broken index is not hammered
+    replay_circuit_breaker: Arc<CircuitBreaker>,
     stats_manager: Option<Arc<StatsManager>>,
 }
 
@@ -49,10 +54,20 @@ impl SyncCoordinator {
             transaction_buffers: DashMap::new(),
             config,
             dead_letter_queue,
+            replay_circuit_breaker: Arc::new(CircuitBreaker::new(
+                "dead_letter_replay",
+                CircuitBreakerConfig::default(),
+            )),
             stats_manager: None,
         }
     }
 
+    /// Use the given dead letter queue, e.g. a persistent one opened under the data directory.
+    pub fn with_dead_letter_queue(mut self, dead_letter_queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letter_queue = dead_letter_queue;
+        self
+    }
+
     pub fn with_stats_manager(mut self, stats_manager: Arc<StatsManager>) -> Self {
         self.stats_manager = Some(stats_manager);
         self
@@ -62,6 +77,10 @@ impl SyncCoordinator {
         &self.dead_letter_queue
     }
 
+    pub fn replay_circuit_breaker(&self) -> &Arc<CircuitBreaker> {
+        &self.replay_circuit_breaker
+    }
+
     pub fn fulltext_manager(&self) -> &Arc<FulltextIndexManager> {
         &self.fulltext_manager
     }
@@ -498,17 +517,14 @@ impl SyncCoordinator {
             ..Default::default()
         };
 
-        for (index, entry) in dead_letter_entries.iter().enumerate() {
+        for entry in &dead_letter_entries {
             match self.recover_operation(&entry.operation).await {
                 Ok(()) => {
-                    self.dead_letter_queue.mark_recovered(index);
+                    self.dead_letter_queue.mark_recovered_by_id(entry.id);
                     result.recovered += 1;
                 }
                 Err(e) => {
-                    warn!(
-                        "Failed to recover dead letter entry at index {}: {:?}",
-                        index, e
-                    );
+                    warn!("Failed to recover dead letter entry {}: {:?}", entry.id, e);
                     result.failed += 1;
                 }
             }
@@ -520,44 +536,84 @@ impl SyncCoordinator {
 
     /// Recover operations from the dead letter queue by retrying each one.
     ///
-    /// This retries each failed operation through the normal batch processing pipeline.
-    /// Successfully retried operations are marked as recovered in the DLQ.
+    /// Each unrecovered entry is replayed through the local retry policy, guarded by
+    /// the replay circuit breaker. Successfully retried operations are marked as
+    /// recovered; once the breaker opens, the remaining entries are skipped.
     pub async fn retry_dead_letter(
         &self,
         max_entries: Option<usize>,
     ) -> Result<RecoveryResult, SyncCoordinatorError> {
-        let all_entries = self.dead_letter_queue.get_all();
+        let all_entries = self.dead_letter_queue.get_unrecovered();
         let mut result = RecoveryResult::default();
-        let mut processed = 0;
 
-        for (index, entry) in all_entries.iter().enumerate() {
-            if entry.recovered {
-                continue;
-            }
+        for entry in all_entries.iter() {
             if let Some(limit) = max_entries {
-                if processed >= limit {
+                if result.total >= limit {
                     break;
                 }
             }
             result.total += 1;
 
-            match self.recover_operation(&entry.operation).await {
+            match self.replay_entry(entry).await {
                 Ok(()) => {
-                    self.dead_letter_queue.mark_recovered(index);
                     result.recovered += 1;
+                    result.total_attempted += 1;
                 }
-                Err(e) => {
-                    warn!("Dead letter retry failed at index {}: {:?}", index, e);
+                Err(CircuitBreakerError::CircuitOpen) => {
+                    result.skipped += 1;
+                }
+                Err(CircuitBreakerError::OperationFailed(e)) => {
+                    warn!("Dead letter retry failed for entry {}: {:?}", entry.id, e);
                     result.failed += 1;
+                    result.total_attempted += 1;
                 }
             }
-            processed += 1;
         }
 
-        result.total_attempted = processed;
         Ok(result)
     }
 
+    /// Retry a single dead letter entry by id.
+    pub async fn retry_dead_letter_entry(&self, id: u64) -> Result<(), SyncCoordinatorError> {
+        let entry = self.dead_letter_queue.get(id).ok_or_else(|| {
+            SyncCoordinatorError::InvalidOperation(format!("Dead letter entry {} not found", id))
+        })?;
+
+        match self.replay_entry(&entry).await {
+            Ok(()) => Ok(()),
+            Err(CircuitBreakerError::CircuitOpen) => Err(SyncCoordinatorError::RecoveryError(
+                "Dead letter replay circuit breaker is open".to_string(),
+            )),
+            Err(CircuitBreakerError::OperationFailed(e)) => Err(e),
+        }
+    }
+
+    /// Replay one entry through the retry policy and the replay circuit breaker,
+    /// recording the outcome in the dead letter queue.
+    async fn replay_entry(
+        &self,
+        entry: &DeadLetterEntry,
+    ) -> Result<(), CircuitBreakerError<SyncCoordinatorError>> {
+        let retry_config = default_local_retry_config();
+        let result = with_circuit_breaker(&self.replay_circuit_breaker, || async {
+            with_retry(|| self.recover_operation(&entry.operation), &retry_config).await
+        })
+        .await;
+
+        match &result {
+            Ok(()) => {
+                self.dead_letter_queue.mark_recovered_by_id(entry.id);
+            }
+            Err(CircuitBreakerError::OperationFailed(e)) => {
+                self.dead_letter_queue
+                    .record_failure(entry.id, format!("Dead letter retry failed: {}", e));
+            }
+            Err(CircuitBreakerError::CircuitOpen) => {}
+        }
+
+        result
+    }
+
     /// Recover operations from specific indexes
     pub async fn recover_operations_for_indexes(
         &self,
@@ -569,14 +625,14 @@ impl SyncCoordinator {
         let entries = self.dead_letter_queue.get_all();
         let mut result = RecoveryResult::default();
 
-        for (index, entry) in entries.iter().enumerate() {
+        for entry in entries.iter() {
             let matches = entry.operation.key == target_key;
 
             if matches {
                 result.total += 1;
                 match self.recover_operation(&entry.operation).await {
                     Ok(()) => {
-                        self.dead_letter_queue.mark_recovered(index);
+                        self.dead_letter_queue.mark_recovered_by_id(entry.id);
                         result.recovered += 1;
                     }
                     Err(e) => {
@@ -595,6 +651,9 @@ impl SyncCoordinator {
     }
 
     /// Recover a single operation
+    ///
+    /// The operation is applied and committed immediately, so a failure surfaces here
+    /// instead of being lost in a later background flush.
     async fn recover_operation(
         &self,
         operation: &IndexOperation,
@@ -603,31 +662,17 @@ impl SyncCoordinator {
         if let Some(processor) =
             self.get_or_create_fulltext_processor(key.space_id, &key.tag_name, &key.field_name)
         {
-            match operation.change_type {
-                ChangeType::Insert => {
-                    processor.add(operation.clone()).await.map_err(|e| {
-                        SyncCoordinatorError::BatchError(
-                            crate::sync::batch::BatchError::InvalidOperation(format!(
-                                "Recovery failed: {:?}",
-                                e
-                            )),
-                        )
-                    })?;
-                }
-                ChangeType::Update | ChangeType::Delete => {
-                    processor
-                        .execute_now(vec![operation.clone()])
-                        .await
-                        .map_err(|e| {
-                            SyncCoordinatorError::BatchError(
-                                crate::sync::batch::BatchError::InvalidOperation(format!(
-                                    "Recovery failed: {:?}",
-                                    e
-                                )),
-                            )
-                        })?;
-                }
-            }
+            processor
+                .execute_now(vec![operation.clone()])
+                .await
+                .map_err(|e| {
+                    SyncCoordinatorError::BatchError(
+                        crate::sync::batch::BatchError::InvalidOperation(format!(
+                            "Recovery failed: {:?}",
+                            e
+                        )),
+                    )
+                })?;
         }
 
         Ok(())
@@ -640,6 +685,8 @@ pub struct RecoveryResult {
     pub total: usize,
     pub recovered: usize,
     pub failed: usize,
+    /// Entries not attempted because the replay circuit breaker was open
+    pub skipped: usize,
     pub total_attempted: usize,
 }
 
@@ -656,8 +703,12 @@ impl RecoveryResult {
         self.failed
     }
 
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
     pub fn is_complete(&self) -> bool {
-        self.failed == 0
+        self.failed == 0 && self.skipped == 0
     }
 }
 
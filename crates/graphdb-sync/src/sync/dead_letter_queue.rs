@@ -1,17 +1,29 @@
 //! Dead Letter Queue for failed index operations
 //!
 //! Stores operations that failed after all retry attempts for later analysis and recovery.
+//! A queue opened with [`DeadLetterQueue::open`] journals every change to a local append log
+//! and replays it on startup, so entries survive restarts until they are retried or purged.
 
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, SystemTime};
 
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
-use crate::sync::types::{IndexOperation};
+use crate::sync::types::IndexOperation;
+
+/// File name of the dead letter log inside the sync data directory
+pub const DEAD_LETTER_LOG_FILE_NAME: &str = "dead_letter.log";
 
 /// Dead letter queue entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeadLetterEntry {
+    /// Stable identifier assigned by the queue
+    #[serde(default)]
+    pub id: u64,
     /// The failed operation
     pub operation: IndexOperation,
     /// Error message
@@ -30,6 +42,7 @@ impl DeadLetterEntry {
     pub fn new(operation: IndexOperation, error: String, retry_attempts: u32) -> Self {
         let now = SystemTime::now();
         Self {
+            id: 0,
             operation,
             error,
             retry_attempts,
@@ -86,10 +99,112 @@ impl Default for DeadLetterQueueConfig {
     }
 }
 
+/// A single record of the dead letter log
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum DeadLetterLogRecord {
+    /// Entry added, or its state replaced after a retry
+    Put { entry: DeadLetterEntry },
+    /// Entry removed
+    Remove { id: u64 },
+}
+
+/// Append log backing a persistent dead letter queue
+#[derive(Debug)]
+struct DeadLetterLog {
+    path: PathBuf,
+    file: File,
+}
+
+impl DeadLetterLog {
+    /// Open the log, replaying its records and compacting it to the surviving entries.
+    fn open(path: &Path, max_size: usize) -> io::Result<(Self, Vec<DeadLetterEntry>)> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut entries: Vec<DeadLetterEntry> = Vec::new();
+        if path.exists() {
+            let reader = BufReader::new(File::open(path)?);
+            for (line_no, line) in reader.lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<DeadLetterLogRecord>(&line) {
+                    Ok(record) => Self::apply(&mut entries, record),
+                    Err(e) => {
+                        // A torn write at the tail of the log; everything before it is intact.
+                        log::warn!(
+                            "Skipping malformed dead letter log record at {}:{}: {}",
+                            path.display(),
+                            line_no + 1,
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        if entries.len() > max_size {
+            let overflow = entries.len() - max_size;
+            entries.drain(..overflow);
+        }
+
+        let mut log = Self {
+            path: path.to_path_buf(),
+            file: OpenOptions::new().create(true).append(true).open(path)?,
+        };
+        log.rewrite(&entries)?;
+        Ok((log, entries))
+    }
+
+    fn apply(entries: &mut Vec<DeadLetterEntry>, record: DeadLetterLogRecord) {
+        match record {
+            DeadLetterLogRecord::Put { entry } => {
+                match entries.iter_mut().find(|e| e.id == entry.id) {
+                    Some(existing) => *existing = entry,
+                    None => entries.push(entry),
+                }
+            }
+            DeadLetterLogRecord::Remove { id } => entries.retain(|e| e.id != id),
+        }
+    }
+
+    fn append(&mut self, record: &DeadLetterLogRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record).map_err(io::Error::other)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.sync_data()
+    }
+
+    /// Replace the log with one `Put` record per entry.
+    fn rewrite(&mut self, entries: &[DeadLetterEntry]) -> io::Result<()> {
+        let tmp_path = self.path.with_extension("log.tmp");
+        {
+            let mut tmp = File::create(&tmp_path)?;
+            for entry in entries {
+                let record = DeadLetterLogRecord::Put {
+                    entry: entry.clone(),
+                };
+                let mut line = serde_json::to_vec(&record).map_err(io::Error::other)?;
+                line.push(b'\n');
+                tmp.write_all(&line)?;
+            }
+            tmp.sync_all()?;
+        }
+        fs::rename(&tmp_path, &self.path)?;
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        Ok(())
+    }
+}
+
 /// Dead Letter Queue
 #[derive(Debug)]
 pub struct DeadLetterQueue {
     entries: Mutex<Vec<DeadLetterEntry>>,
+    next_id: AtomicU64,
+    log: Option<Mutex<DeadLetterLog>>,
     config: DeadLetterQueueConfig,
 }
 
@@ -97,10 +212,68 @@ impl DeadLetterQueue {
     pub fn new(config: DeadLetterQueueConfig) -> Self {
         Self {
             entries: Mutex::new(Vec::with_capacity(config.max_size)),
+            next_id: AtomicU64::new(1),
+            log: None,
+            config,
+        }
+    }
+
+    /// Open a persistent queue backed by the append log at `path`.
+    ///
+    /// Existing records are replayed, so entries recorded before a restart are
+    /// available again until they are retried or purged.
+    pub fn open(path: impl AsRef<Path>, config: DeadLetterQueueConfig) -> io::Result<Self> {
+        let (log, entries) = DeadLetterLog::open(path.as_ref(), config.max_size)?;
+        let next_id = entries.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+
+        if !entries.is_empty() {
+            log::info!(
+                "Replayed {} dead letter entries from {}",
+                entries.len(),
+                path.as_ref().display()
+            );
+        }
+
+        Ok(Self {
+            entries: Mutex::new(entries),
+            next_id: AtomicU64::new(next_id),
+            log: Some(Mutex::new(log)),
             config,
+        })
+    }
+
+    /// Open the persistent queue at `<data_dir>/sync/dead_letter.log`.
+    ///
+    /// Falls back to an in-memory queue when the log cannot be opened, so index
+    /// sync keeps working on a read-only or broken data directory.
+    pub fn open_in_data_dir(data_dir: impl AsRef<Path>, config: DeadLetterQueueConfig) -> Self {
+        let path = data_dir
+            .as_ref()
+            .join("sync")
+            .join(DEAD_LETTER_LOG_FILE_NAME);
+        match Self::open(&path, config.clone()) {
+            Ok(dlq) => dlq,
+            Err(e) => {
+                log::error!(
+                    "Failed to open dead letter log at {}: {}. Falling back to an in-memory queue.",
+                    path.display(),
+                    e
+                );
+                Self::new(config)
+            }
         }
     }
 
+    /// Whether entries are journaled to a local append log
+    pub fn is_persistent(&self) -> bool {
+        self.log.is_some()
+    }
+
+    /// Path of the append log, if the queue is persistent
+    pub fn log_path(&self) -> Option<PathBuf> {
+        self.log.as_ref().map(|log| log.lock().path.clone())
+    }
+
     pub fn is_auto_cleanup_enabled(&self) -> bool {
         self.config.auto_cleanup_enabled
     }
@@ -109,22 +282,45 @@ impl DeadLetterQueue {
         self.config.max_age / 2
     }
 
-    /// Add an entry to the dead letter queue
-    pub fn add(&self, entry: DeadLetterEntry) {
+    fn journal(&self, record: &DeadLetterLogRecord) {
+        if let Some(ref log) = self.log {
+            if let Err(e) = log.lock().append(record) {
+                log::error!("Failed to append to dead letter log: {}", e);
+            }
+        }
+    }
+
+    fn journal_snapshot(&self, entries: &[DeadLetterEntry]) {
+        if let Some(ref log) = self.log {
+            if let Err(e) = log.lock().rewrite(entries) {
+                log::error!("Failed to rewrite dead letter log: {}", e);
+            }
+        }
+    }
+
+    /// Add an entry to the dead letter queue, returning its assigned id
+    pub fn add(&self, mut entry: DeadLetterEntry) -> u64 {
         let mut entries = self.entries.lock();
 
         // Check if queue is full
         if entries.len() >= self.config.max_size {
             // Remove oldest entry if full
             if !entries.is_empty() {
-                entries.remove(0);
+                let evicted = entries.remove(0);
+                self.journal(&DeadLetterLogRecord::Remove { id: evicted.id });
                 log::warn!("Dead letter queue is full, removed oldest entry");
             }
         }
 
+        entry.id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let id = entry.id;
+        self.journal(&DeadLetterLogRecord::Put {
+            entry: entry.clone(),
+        });
         entries.push(entry);
 
         log::warn!("Added entry to dead letter queue (size: {})", entries.len());
+        id
     }
 
     /// Get all entries
@@ -132,6 +328,11 @@ impl DeadLetterQueue {
         self.entries.lock().clone()
     }
 
+    /// Get an entry by id
+    pub fn get(&self, id: u64) -> Option<DeadLetterEntry> {
+        self.entries.lock().iter().find(|e| e.id == id).cloned()
+    }
+
     /// Get entries that haven't been recovered
     pub fn get_unrecovered(&self) -> Vec<DeadLetterEntry> {
         self.entries
@@ -157,23 +358,67 @@ impl DeadLetterQueue {
         let mut entries = self.entries.lock();
         if index < entries.len() {
             let entry = entries.remove(index);
+            self.journal(&DeadLetterLogRecord::Remove { id: entry.id });
             Some(entry)
         } else {
             None
         }
     }
 
+    /// Remove an entry by id
+    pub fn remove_by_id(&self, id: u64) -> Option<DeadLetterEntry> {
+        let mut entries = self.entries.lock();
+        let index = entries.iter().position(|e| e.id == id)?;
+        let entry = entries.remove(index);
+        self.journal(&DeadLetterLogRecord::Remove { id });
+        Some(entry)
+    }
+
     /// Mark an entry as recovered
     pub fn mark_recovered(&self, index: usize) -> bool {
         let mut entries = self.entries.lock();
         if index < entries.len() {
             entries[index].mark_recovered();
+            self.journal(&DeadLetterLogRecord::Put {
+                entry: entries[index].clone(),
+            });
             true
         } else {
             false
         }
     }
 
+    /// Mark an entry as recovered by id
+    pub fn mark_recovered_by_id(&self, id: u64) -> bool {
+        let mut entries = self.entries.lock();
+        match entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) => {
+                entry.mark_recovered();
+                self.journal(&DeadLetterLogRecord::Put {
+                    entry: entry.clone(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record another failed replay attempt for an entry
+    pub fn record_failure(&self, id: u64, error: String) -> bool {
+        let mut entries = self.entries.lock();
+        match entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) => {
+                entry.update_failure(error);
+                entry.retry_attempts = entry.retry_attempts.saturating_add(1);
+                self.journal(&DeadLetterLogRecord::Put {
+                    entry: entry.clone(),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Cleanup old entries
     pub fn cleanup(&self) -> usize {
         let mut entries = self.entries.lock();
@@ -184,12 +429,30 @@ impl DeadLetterQueue {
         let removed = initial_len - entries.len();
 
         if removed > 0 {
+            self.journal_snapshot(&entries);
             log::info!("Cleaned up {} old dead letter entries", removed);
         }
 
         removed
     }
 
+    /// Remove entries that have already been recovered
+    pub fn purge_recovered(&self) -> usize {
+        let mut entries = self.entries.lock();
+        let initial_len = entries.len();
+
+        entries.retain(|e| !e.recovered);
+
+        let removed = initial_len - entries.len();
+
+        if removed > 0 {
+            self.journal_snapshot(&entries);
+            log::info!("Purged {} recovered dead letter entries", removed);
+        }
+
+        removed
+    }
+
     /// Get queue size
     pub fn len(&self) -> usize {
         self.entries.lock().len()
@@ -205,6 +468,7 @@ impl DeadLetterQueue {
         let mut entries = self.entries.lock();
         let count = entries.len();
         entries.clear();
+        self.journal_snapshot(&entries);
 
         log::info!("Cleared {} entries from dead letter queue", count);
     }
@@ -615,4 +879,97 @@ mod tests {
             "Second entry should be Delete operation"
         );
     }
+
+    fn temp_log_path(name: &str) -> std::path::PathBuf {
+        let base_dir = std::env::temp_dir()
+            .join("graphdb_dead_letter_queue_test")
+            .join(format!("{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&base_dir);
+        base_dir.join(DEAD_LETTER_LOG_FILE_NAME)
+    }
+
+    #[test]
+    fn test_persistent_dlq_replays_after_reopen() {
+        let path = temp_log_path("replay");
+
+        {
+            let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+                .expect("open should succeed");
+            assert!(dlq.is_persistent());
+            let first = dlq.add(create_test_entry("Error 1", 3));
+            let second = dlq.add(create_test_entry("Error 2", 3));
+            let third = dlq.add(create_test_entry("Error 3", 3));
+            assert!(dlq.mark_recovered_by_id(first));
+            assert!(dlq.record_failure(second, "Still failing".to_string()));
+            assert!(dlq.remove_by_id(third).is_some());
+        }
+
+        let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+            .expect("reopen should succeed");
+        let entries = dlq.get_all();
+        assert_eq!(entries.len(), 2, "Removed entry should not be replayed");
+        assert!(
+            entries[0].recovered,
+            "Recovered flag should survive restart"
+        );
+        assert_eq!(entries[1].error, "Still failing");
+        assert_eq!(entries[1].retry_attempts, 4);
+
+        let next = dlq.add(create_test_entry("Error 4", 3));
+        assert!(
+            next > entries[1].id,
+            "Ids should not be reused after replay"
+        );
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_persistent_dlq_skips_torn_record() {
+        let path = temp_log_path("torn");
+
+        {
+            let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+                .expect("open should succeed");
+            dlq.add(create_test_entry("Error 1", 3));
+        }
+        {
+            use std::io::Write;
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .expect("log should exist");
+            file.write_all(b"{\"op\":\"put\",\"entry\":{")
+                .expect("write should succeed");
+        }
+
+        let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+            .expect("reopen should succeed");
+        assert_eq!(dlq.len(), 1, "Intact records before the torn one are kept");
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_purge_recovered_entries() {
+        let path = temp_log_path("purge");
+
+        let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+            .expect("open should succeed");
+        let first = dlq.add(create_test_entry("Error 1", 3));
+        dlq.add(create_test_entry("Error 2", 3));
+        dlq.mark_recovered_by_id(first);
+
+        assert_eq!(dlq.purge_recovered(), 1);
+        assert_eq!(dlq.len(), 1);
+        assert!(dlq.get(first).is_none());
+
+        dlq.clear();
+        drop(dlq);
+        let dlq = DeadLetterQueue::open(&path, DeadLetterQueueConfig::default())
+            .expect("reopen should succeed");
+        assert!(dlq.is_empty(), "Clear should be durable");
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
 }
@@ -123,13 +123,15 @@ impl SyncManager {
 
     #[cfg(feature = "fulltext-search")]
     pub fn new(sync_coordinator: Arc<SyncCoordinator>) -> Self {
+        // Share the coordinator's queue so failures it records are visible here.
+        let dead_letter_queue = Some(sync_coordinator.dead_letter_queue().clone());
         Self {
             sync_coordinator: Some(sync_coordinator),
             #[cfg(feature = "qdrant")]
             vector_coordinator: None,
             txn_sequences: DashMap::new(),
             running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
-            dead_letter_queue: None,
+            dead_letter_queue,
             handle: Mutex::new(None),
         }
     }
@@ -909,6 +911,52 @@ impl SyncManager {
         }
     }
 
+    pub fn dead_letter_queue(&self) -> Option<&Arc<crate::sync::DeadLetterQueue>> {
+        self.dead_letter_queue.as_ref()
+    }
+
+    pub fn get_dead_letter_entry(&self, id: u64) -> Option<crate::sync::DeadLetterEntry> {
+        self.dead_letter_queue.as_ref().and_then(|dlq| dlq.get(id))
+    }
+
+    /// Retry one dead letter entry through the coordinator's retry and circuit breaker policies.
+    #[cfg(feature = "fulltext-search")]
+    pub async fn retry_dead_letter_entry(&self, id: u64) -> Result<(), SyncError> {
+        let coord = self
+            .sync_coordinator
+            .as_ref()
+            .ok_or_else(|| SyncError::Internal("Fulltext sync is not enabled".to_string()))?;
+        coord.retry_dead_letter_entry(id).await?;
+        Ok(())
+    }
+
+    /// Retry all unrecovered dead letter entries, up to `max_entries` when given.
+    #[cfg(feature = "fulltext-search")]
+    pub async fn retry_dead_letters(
+        &self,
+        max_entries: Option<usize>,
+    ) -> Result<crate::sync::coordinator::RecoveryResult, SyncError> {
+        let coord = self
+            .sync_coordinator
+            .as_ref()
+            .ok_or_else(|| SyncError::Internal("Fulltext sync is not enabled".to_string()))?;
+        Ok(coord.retry_dead_letter(max_entries).await?)
+    }
+
+    /// Drop dead letter entries, either all of them or only the recovered ones.
+    /// Returns the number of entries removed.
+    pub fn purge_dead_letters(&self, recovered_only: bool) -> usize {
+        match self.dead_letter_queue {
+            Some(ref dlq) if recovered_only => dlq.purge_recovered(),
+            Some(ref dlq) => {
+                let removed = dlq.len();
+                dlq.clear();
+                removed
+            }
+            None => 0,
+        }
+    }
+
     pub fn get_dlq_size(&self) -> usize {
         if let Some(ref dlq) = self.dead_letter_queue {
             dlq.get_all().len()
@@ -24,7 +24,10 @@ pub use coordinator::{
     ChangeContext, ChangeData, ChangeType, IndexType, RecoveryResult, SyncCoordinator,
     SyncCoordinatorError,
 };
-pub use dead_letter_queue::{DeadLetterEntry, DeadLetterQueue, DeadLetterQueueConfig};
+pub use dead_letter_queue::{
+    DeadLetterEntry, DeadLetterQueue, DeadLetterQueueConfig, DeadLetterQueueStats,
+    DEAD_LETTER_LOG_FILE_NAME,
+};
 pub use manager::{EdgeProps, EdgeRef, SyncError, SyncManager};
 pub use retry::{with_retry, RetryConfig};
 pub use types::{IndexOpKey, IndexOperation};
@@ -469,12 +469,33 @@ impl TransactionManager {
     }
 
     /// Commit transaction with undo target (for rollback support)
+    ///
+    /// The unique index keys claimed by the transaction are kept on commit. A
+    /// commit that ends the transaction with an error (timeout, sync failure)
+    /// aborts it, so its claims are handed back.
     pub fn commit_transaction_with_undo<T: UndoTarget + ?Sized>(
         &self,
         txn_id: TransactionId,
-        _target: &mut T,
+        target: &T,
     ) -> Result<(), TransactionError> {
-        self.commit_transaction(txn_id)
+        match self.commit_transaction(txn_id) {
+            Ok(()) => {
+                target.retain_unique_claims(txn_id);
+                Ok(())
+            }
+            Err(e) => {
+                if !self.active_transactions.contains_key(&txn_id) {
+                    if let Err(release) = target.release_unique_claims(txn_id) {
+                        log::warn!(
+                            "Failed to release unique keys of transaction {}: {}",
+                            txn_id,
+                            release
+                        );
+                    }
+                }
+                Err(e)
+            }
+        }
     }
 
     /// Abort transaction
@@ -511,7 +532,7 @@ impl TransactionManager {
     ///
     /// Follows atomic abort protocol:
     /// 1. Check state (transaction still active)
-    /// 2. Execute undo log rollback
+    /// 2. Execute undo log rollback and release the unique keys claimed by the transaction
     /// 3. Transition to Aborting
     /// 4. Call sync_manager rollback. If it fails, the transaction is terminated and resources
     ///    are released.
@@ -522,7 +543,7 @@ impl TransactionManager {
     pub fn abort_transaction_with_undo<T: UndoTarget + ?Sized>(
         &self,
         txn_id: TransactionId,
-        target: &mut T,
+        target: &T,
     ) -> Result<(), TransactionError> {
         let context = {
             let entry = self
@@ -545,6 +566,9 @@ impl TransactionManager {
             .execute_rollback(target, context.timestamp())
             .map_err(|e| TransactionError::rollback_failed(e.to_string()))?;
         rollback.clear_logs();
+        target
+            .release_unique_claims(txn_id)
+            .map_err(|e| TransactionError::rollback_failed(e.to_string()))?;
 
         self.abort_transaction_internal(&context)
     }
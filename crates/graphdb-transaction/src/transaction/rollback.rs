@@ -83,7 +83,7 @@ impl<'a, T: UndoLogContext> UndoLogRollback<'a, T> {
 
     pub fn execute_rollback<U: UndoTarget + ?Sized>(
         &self,
-        target: &mut U,
+        target: &U,
         _ts: Timestamp,
     ) -> Result<(), StorageError> {
         self.ctx.execute_undo_logs(target)
@@ -0,0 +1,575 @@
+//! WAL Inspection
+//!
+//! Read-only diagnostics for WAL segments. Unlike the recovery parsers, which
+//! skip or abort on damaged records, the inspector reports every record it
+//! finds together with its checksum status and records the byte offset of
+//! every corruption, so operators can see exactly where a segment went bad.
+//!
+//! Segment listing, decompression and fragment reassembly are shared with the
+//! recovery parser.
+
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::core::types::Timestamp;
+use crate::core::wal::redo::{
+    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ChangeVertexPropTypeRedo,
+    ClearSpaceRedo, CreateEdgeTypeRedo, CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo,
+    DeleteEdgeRedo, DeleteEdgeTypeRedo, DeleteVertexPropRedo, DeleteVertexRedo,
+    DeleteVertexTypeRedo, DropSpaceRedo, InsertEdgeRedo, InsertVertexRedo, RenameEdgePropRedo,
+    RenameVertexPropRedo, UpdateEdgePropRedo, UpdateVertexPropRedo,
+};
+use crate::core::wal::types::{
+    RecordType, WalError, WalFileHeader, WalHeader, WalOpType, WalResult, WAL_FILE_HEADER_SIZE,
+    WAL_HEADER_SIZE,
+};
+use crate::transaction::wal::parser::{list_wal_files, FragmentBuffer};
+use crate::transaction::wal::writer::decompress_payload;
+
+/// Summary of a single WAL record as found on disk
+#[derive(Debug, Clone, Serialize)]
+pub struct WalRecordInfo {
+    /// Byte offset of the record header within the segment
+    pub offset: usize,
+    pub lsn: u64,
+    pub prev_lsn: u64,
+    pub timestamp: Timestamp,
+    /// Operation type name, or `Unknown(n)` for an unrecognised op code
+    pub op_type: String,
+    /// Fragment kind (`Full`, `First`, `Middle`, `Last`)
+    pub record_type: String,
+    /// Stored payload length in bytes
+    pub length: u32,
+    pub compressed: bool,
+    /// `None` when the record was written without a checksum
+    pub checksum_valid: Option<bool>,
+    /// Decoded redo payload, for complete records whose payload could be decoded
+    pub summary: Option<String>,
+}
+
+/// A damaged region found while scanning a segment
+#[derive(Debug, Clone, Serialize)]
+pub struct WalCorruption {
+    /// Byte offset of the damaged record header
+    pub offset: usize,
+    pub reason: String,
+}
+
+/// File header fields of a WAL segment
+#[derive(Debug, Clone, Serialize)]
+pub struct WalSegmentHeaderInfo {
+    pub version: u32,
+    pub thread_id: u32,
+    pub checkpoint_seq: u64,
+    pub start_lsn: u64,
+    pub created_at: u64,
+}
+
+/// Inspection report for one WAL segment file
+#[derive(Debug, Clone, Serialize)]
+pub struct WalSegmentReport {
+    pub path: PathBuf,
+    /// Size of the file on disk, including preallocated space
+    pub file_size: u64,
+    /// Parsed file header, `None` if it is missing or has a bad magic number
+    pub header: Option<WalSegmentHeaderInfo>,
+    pub records: Vec<WalRecordInfo>,
+    pub corruptions: Vec<WalCorruption>,
+    /// End offset of the longest prefix of complete, valid records.
+    ///
+    /// Truncating the segment here removes everything from the first
+    /// corruption onwards.
+    pub valid_end: usize,
+    /// End offset of the scanned region (start of preallocated zero space)
+    pub used_end: usize,
+}
+
+impl WalSegmentReport {
+    /// Smallest LSN of any record in the segment
+    pub fn first_lsn(&self) -> Option<u64> {
+        self.records.iter().map(|r| r.lsn).min()
+    }
+
+    /// Largest LSN of any record in the segment
+    pub fn last_lsn(&self) -> Option<u64> {
+        self.records.iter().map(|r| r.lsn).max()
+    }
+
+    /// Whether the segment has no corruption
+    pub fn is_clean(&self) -> bool {
+        self.corruptions.is_empty()
+    }
+}
+
+/// Read-only WAL segment inspector
+pub struct WalInspector {
+    /// Decode redo payloads into `WalRecordInfo::summary`
+    decode_payloads: bool,
+}
+
+impl WalInspector {
+    /// Create a new inspector
+    pub fn new() -> Self {
+        Self {
+            decode_payloads: true,
+        }
+    }
+
+    /// Enable or disable payload decoding
+    pub fn with_decode_payloads(mut self, decode: bool) -> Self {
+        self.decode_payloads = decode;
+        self
+    }
+
+    /// List WAL segment files in a directory, sorted by name
+    pub fn list_segments(wal_dir: &Path) -> WalResult<Vec<PathBuf>> {
+        if !wal_dir.exists() {
+            return Err(WalError::FileNotFound(
+                wal_dir.to_string_lossy().to_string(),
+            ));
+        }
+        list_wal_files(wal_dir)
+    }
+
+    /// Inspect every segment in a WAL directory
+    pub fn inspect_dir(&self, wal_dir: &Path) -> WalResult<Vec<WalSegmentReport>> {
+        Self::list_segments(wal_dir)?
+            .iter()
+            .map(|path| self.inspect_file(path))
+            .collect()
+    }
+
+    /// Inspect a single WAL segment
+    pub fn inspect_file(&self, path: &Path) -> WalResult<WalSegmentReport> {
+        let buffer = fs::read(path).map_err(|e| WalError::IoError(e.to_string()))?;
+
+        let mut report = WalSegmentReport {
+            path: path.to_path_buf(),
+            file_size: buffer.len() as u64,
+            header: None,
+            records: Vec::new(),
+            corruptions: Vec::new(),
+            valid_end: 0,
+            used_end: 0,
+        };
+
+        if buffer.is_empty() {
+            return Ok(report);
+        }
+
+        if buffer.len() < WAL_FILE_HEADER_SIZE {
+            report.corruptions.push(WalCorruption {
+                offset: 0,
+                reason: format!("file shorter than header ({} bytes)", buffer.len()),
+            });
+            report.used_end = buffer.len();
+            return Ok(report);
+        }
+
+        let file_header = WalFileHeader::from_bytes_safe(&buffer[..WAL_FILE_HEADER_SIZE])
+            .filter(|h| h.is_valid());
+        let file_header = match file_header {
+            Some(h) => h,
+            None => {
+                report.corruptions.push(WalCorruption {
+                    offset: 0,
+                    reason: "invalid file header magic".to_string(),
+                });
+                report.used_end = WAL_FILE_HEADER_SIZE;
+                return Ok(report);
+            }
+        };
+
+        report.header = Some(WalSegmentHeaderInfo {
+            version: file_header.version,
+            thread_id: file_header.thread_id,
+            checkpoint_seq: file_header.checkpoint_seq,
+            start_lsn: file_header.start_lsn,
+            created_at: file_header.created_at,
+        });
+
+        let mut offset = WAL_FILE_HEADER_SIZE;
+        report.valid_end = offset;
+        let mut prefix_valid = true;
+        let mut fragments = FragmentBuffer::new();
+
+        while offset + WAL_HEADER_SIZE <= buffer.len() {
+            let header_bytes = &buffer[offset..offset + WAL_HEADER_SIZE];
+            let header = match WalHeader::from_bytes_safe(header_bytes) {
+                Some(h) => h,
+                None => break,
+            };
+
+            if header.timestamp == 0 && header.length == 0 && header.lsn == 0 {
+                break;
+            }
+
+            let payload_start = offset + WAL_HEADER_SIZE;
+            let payload_end = payload_start + header.length as usize;
+
+            if payload_end > buffer.len() {
+                report.corruptions.push(WalCorruption {
+                    offset,
+                    reason: format!(
+                        "truncated record: declares {} payload bytes, only {} available",
+                        header.length,
+                        buffer.len() - payload_start
+                    ),
+                });
+                offset = buffer.len();
+                break;
+            }
+
+            let payload = &buffer[payload_start..payload_end];
+            let op_type = WalOpType::try_from(header.op_type).ok();
+
+            let checksum_valid = if header.checksum != 0 {
+                Some(header.verify_checksum(payload))
+            } else {
+                None
+            };
+
+            let mut record_ok = true;
+            let record_type_byte = header_bytes[WalHeader::RECORD_TYPE_OFFSET];
+            if RecordType::try_from_u8(record_type_byte).is_none() {
+                record_ok = false;
+                report.corruptions.push(WalCorruption {
+                    offset,
+                    reason: format!("unknown record type {}", record_type_byte),
+                });
+            }
+            if !header.has_valid_flags() {
+                record_ok = false;
+                report.corruptions.push(WalCorruption {
+                    offset,
+                    reason: format!("unknown header flags {:#06x}", header.flags),
+                });
+            }
+            if op_type.is_none() {
+                record_ok = false;
+                report.corruptions.push(WalCorruption {
+                    offset,
+                    reason: format!("unknown op type {}", header.op_type),
+                });
+            }
+            if checksum_valid == Some(false) {
+                record_ok = false;
+                report.corruptions.push(WalCorruption {
+                    offset,
+                    reason: format!("checksum mismatch (stored {:#010x})", header.checksum),
+                });
+            }
+
+            let decoded = if record_ok && header.is_compressed() {
+                match decompress_payload(payload, header.compression()) {
+                    Ok(data) => Some(data),
+                    Err(e) => {
+                        record_ok = false;
+                        report.corruptions.push(WalCorruption {
+                            offset,
+                            reason: format!("decompression failed: {}", e),
+                        });
+                        None
+                    }
+                }
+            } else if record_ok {
+                Some(payload.to_vec())
+            } else {
+                None
+            };
+
+            let mut summary = None;
+            let mut completes_record = false;
+            if let Some(data) = decoded {
+                match header.record_type {
+                    RecordType::Full => {
+                        fragments.reset();
+                        completes_record = true;
+                        summary = self.summarize(op_type, &data);
+                    }
+                    RecordType::First => {
+                        fragments.add_fragment(header, data);
+                    }
+                    RecordType::Middle | RecordType::Last if !fragments.in_progress() => {
+                        record_ok = false;
+                        report.corruptions.push(WalCorruption {
+                            offset,
+                            reason: format!(
+                                "{:?} fragment without a first fragment",
+                                header.record_type
+                            ),
+                        });
+                    }
+                    RecordType::Middle | RecordType::Last => {
+                        if fragments.add_fragment(header, data) {
+                            completes_record = true;
+                            let op = fragments
+                                .get_first_header()
+                                .and_then(|first| WalOpType::try_from(first.op_type).ok());
+                            summary = fragments
+                                .assemble()
+                                .and_then(|payload| self.summarize(op, &payload));
+                            fragments.reset();
+                        }
+                    }
+                }
+            }
+
+            report.records.push(WalRecordInfo {
+                offset,
+                lsn: header.lsn,
+                prev_lsn: header.prev_lsn,
+                timestamp: header.timestamp,
+                op_type: op_type
+                    .map(|op| op.to_string())
+                    .unwrap_or_else(|| format!("Unknown({})", header.op_type)),
+                record_type: format!("{:?}", header.record_type),
+                length: header.length,
+                compressed: header.is_compressed(),
+                checksum_valid,
+                summary,
+            });
+
+            if !record_ok {
+                prefix_valid = false;
+            } else if prefix_valid && completes_record {
+                report.valid_end = payload_end;
+            }
+
+            offset = payload_end;
+        }
+
+        if fragments.in_progress() {
+            report.corruptions.push(WalCorruption {
+                offset: report.valid_end,
+                reason: "fragmented record is missing its last fragment".to_string(),
+            });
+        }
+
+        report.used_end = offset;
+        Ok(report)
+    }
+
+    /// Truncate a segment after its last valid record.
+    ///
+    /// Returns the number of bytes removed; a clean segment is left untouched.
+    /// A segment whose file header is missing or invalid has no valid prefix to
+    /// keep, so it is not truncated and `WalError::InvalidFileHeader` is returned.
+    pub fn truncate_to_valid(&self, path: &Path) -> WalResult<u64> {
+        let report = self.inspect_file(path)?;
+        if report.is_clean() {
+            return Ok(0);
+        }
+        if report.header.is_none() {
+            return Err(WalError::InvalidFileHeader);
+        }
+
+        let file = OpenOptions::new()
+            .write(true)
+            .open(path)
+            .map_err(|e| WalError::IoError(e.to_string()))?;
+        file.set_len(report.valid_end as u64)
+            .map_err(|e| WalError::IoError(e.to_string()))?;
+        file.sync_all()
+            .map_err(|e| WalError::IoError(e.to_string()))?;
+
+        Ok(report.file_size - report.valid_end as u64)
+    }
+
+    fn summarize(&self, op_type: Option<WalOpType>, payload: &[u8]) -> Option<String> {
+        if !self.decode_payloads {
+            return None;
+        }
+        describe_payload(op_type?, payload)
+    }
+}
+
+impl Default for WalInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decode a redo payload into a human-readable string
+pub fn describe_payload(op_type: WalOpType, payload: &[u8]) -> Option<String> {
+    fn decode<T: serde::de::DeserializeOwned + std::fmt::Debug>(payload: &[u8]) -> Option<String> {
+        postcard::from_bytes::<T>(payload)
+            .ok()
+            .map(|redo| format!("{:?}", redo))
+    }
+
+    match op_type {
+        WalOpType::InsertVertex => decode::<InsertVertexRedo>(payload),
+        WalOpType::InsertEdge => decode::<InsertEdgeRedo>(payload),
+        WalOpType::UpdateVertexProp => decode::<UpdateVertexPropRedo>(payload),
+        WalOpType::UpdateEdgeProp => decode::<UpdateEdgePropRedo>(payload),
+        WalOpType::DeleteVertex => decode::<DeleteVertexRedo>(payload),
+        WalOpType::DeleteEdge => decode::<DeleteEdgeRedo>(payload),
+        WalOpType::CreateVertexType => decode::<CreateVertexTypeRedo>(payload),
+        WalOpType::CreateEdgeType => decode::<CreateEdgeTypeRedo>(payload),
+        WalOpType::DeleteVertexType => decode::<DeleteVertexTypeRedo>(payload),
+        WalOpType::DeleteEdgeType => decode::<DeleteEdgeTypeRedo>(payload),
+        WalOpType::AddVertexProp => decode::<AddVertexPropRedo>(payload),
+        WalOpType::AddEdgeProp => decode::<AddEdgePropRedo>(payload),
+        WalOpType::DeleteVertexProp => decode::<DeleteVertexPropRedo>(payload),
+        WalOpType::DeleteEdgeProp => decode::<DeleteEdgePropRedo>(payload),
+        WalOpType::RenameVertexProp => decode::<RenameVertexPropRedo>(payload),
+        WalOpType::RenameEdgeProp => decode::<RenameEdgePropRedo>(payload),
+        WalOpType::ChangeVertexPropType => decode::<ChangeVertexPropTypeRedo>(payload),
+        WalOpType::CreateSpace => decode::<CreateSpaceRedo>(payload),
+        WalOpType::DropSpace => decode::<DropSpaceRedo>(payload),
+        WalOpType::ClearSpace => decode::<ClearSpaceRedo>(payload),
+        WalOpType::AlterSpaceComment => decode::<AlterSpaceCommentRedo>(payload),
+        WalOpType::Compact => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wal::types::WalConfig;
+    use crate::transaction::wal::writer::{LocalWalWriter, WalWriter};
+    use std::io::{Seek, SeekFrom, Write};
+    use tempfile::TempDir;
+
+    fn write_entries(dir: &Path, count: u32) -> PathBuf {
+        let config = WalConfig::new().with_checksum(true);
+        let mut writer = LocalWalWriter::with_config(&dir.to_string_lossy(), 0, config);
+        writer.open().expect("Failed to open WAL");
+        for i in 1..=count {
+            writer
+                .append_entry(
+                    WalOpType::InsertVertex,
+                    i,
+                    format!("payload{}", i).as_bytes(),
+                )
+                .expect("Failed to append");
+        }
+        writer.sync().expect("Failed to sync");
+        writer.close();
+
+        WalInspector::list_segments(dir).expect("Failed to list")[0].clone()
+    }
+
+    #[test]
+    fn test_inspect_clean_segment() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_entries(temp_dir.path(), 3);
+
+        let report = WalInspector::new()
+            .inspect_file(&path)
+            .expect("Failed to inspect");
+
+        assert!(report.header.is_some());
+        assert!(report.is_clean());
+        assert_eq!(report.records.len(), 3);
+        assert!(report
+            .records
+            .iter()
+            .all(|r| r.checksum_valid == Some(true) && r.op_type == "InsertVertex"));
+        assert_eq!(report.valid_end, report.used_end);
+        assert!(report.first_lsn() <= report.last_lsn());
+    }
+
+    #[test]
+    fn test_inspect_reports_checksum_corruption_and_truncates() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_entries(temp_dir.path(), 3);
+
+        let inspector = WalInspector::new();
+        let clean = inspector.inspect_file(&path).expect("Failed to inspect");
+        let second = clean.records[1].offset;
+
+        {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .expect("Failed to open");
+            file.seek(SeekFrom::Start((second + WAL_HEADER_SIZE) as u64))
+                .expect("Failed to seek");
+            file.write_all(b"XX").expect("Failed to corrupt");
+        }
+
+        let report = inspector.inspect_file(&path).expect("Failed to inspect");
+        assert_eq!(report.corruptions.len(), 1);
+        assert_eq!(report.corruptions[0].offset, second);
+        assert_eq!(report.valid_end, second);
+
+        let removed = inspector
+            .truncate_to_valid(&path)
+            .expect("Failed to truncate");
+        assert!(removed > 0);
+
+        let after = inspector.inspect_file(&path).expect("Failed to inspect");
+        assert!(after.is_clean());
+        assert_eq!(after.records.len(), 1);
+    }
+
+    #[test]
+    fn test_inspect_reports_unknown_record_type_and_flags() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_entries(temp_dir.path(), 3);
+
+        let inspector = WalInspector::new();
+        let clean = inspector.inspect_file(&path).expect("Failed to inspect");
+        let second = clean.records[1].offset;
+        let third = clean.records[2].offset;
+
+        {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .expect("Failed to open");
+            file.seek(SeekFrom::Start(
+                (second + WalHeader::RECORD_TYPE_OFFSET) as u64,
+            ))
+            .expect("Failed to seek");
+            file.write_all(&[9]).expect("Failed to corrupt");
+            file.seek(SeekFrom::Start(
+                (third + WalHeader::RECORD_TYPE_OFFSET + 2) as u64,
+            ))
+            .expect("Failed to seek");
+            file.write_all(&0x0100u16.to_le_bytes())
+                .expect("Failed to corrupt");
+        }
+
+        let report = inspector.inspect_file(&path).expect("Failed to inspect");
+        assert!(report
+            .corruptions
+            .iter()
+            .any(|c| c.offset == second && c.reason == "unknown record type 9"));
+        assert!(report
+            .corruptions
+            .iter()
+            .any(|c| c.offset == third && c.reason.starts_with("unknown header flags")));
+        assert_eq!(report.valid_end, second);
+    }
+
+    #[test]
+    fn test_truncate_rejects_invalid_file_header() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = write_entries(temp_dir.path(), 2);
+        let size = fs::metadata(&path).expect("Failed to stat").len();
+
+        {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .expect("Failed to open");
+            file.write_all(b"XXXX").expect("Failed to corrupt");
+        }
+
+        let inspector = WalInspector::new();
+        let report = inspector.inspect_file(&path).expect("Failed to inspect");
+        assert!(report.header.is_none());
+        assert!(!report.is_clean());
+
+        assert!(matches!(
+            inspector.truncate_to_valid(&path),
+            Err(WalError::InvalidFileHeader)
+        ));
+        assert_eq!(fs::metadata(&path).expect("Failed to stat").len(), size);
+    }
+}
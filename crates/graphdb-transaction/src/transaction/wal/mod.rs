@@ -6,6 +6,7 @@
 //!
 //! - `WalWriter`: Write WAL entries to persistent storage
 //! - `WalParser`: Parse WAL files for recovery
+//! - `WalInspector`: Read-only diagnostics of WAL segments (checksums, corruption offsets)
 //! - `WalHeader`: WAL entry header format
 //!
 //! ## Usage
@@ -45,6 +46,7 @@
 //! ```
 
 pub mod checkpoint;
+pub mod inspect;
 pub mod parser;
 pub mod recovery;
 pub mod writer;
@@ -56,6 +58,9 @@ pub use crate::core::wal::traits::*;
 
 pub use crate::core::types::{TableId, TableTracker, TableTrackerConfig, TableType};
 pub use checkpoint::{Checkpoint, CheckpointManager, CheckpointMode, CheckpointResult};
+pub use inspect::{
+    WalCorruption, WalInspector, WalRecordInfo, WalSegmentHeaderInfo, WalSegmentReport,
+};
 pub use parser::{
     LocalWalParser, ParallelWalParser, ParsedWalEntry, RecoveryResult, WalEntryIter, WalParser,
     WalParserFactory,
@@ -92,20 +92,7 @@ impl ParallelWalParser {
             return Ok(RecoveryResult::default());
         }
 
-        let mut wal_files: Vec<PathBuf> = std::fs::read_dir(wal_dir)
-            .map_err(|e| WalError::IoError(e.to_string()))?
-            .filter_map(|entry| entry.ok())
-            .map(|entry| entry.path())
-            .filter(|path| {
-                path.extension().is_some_and(|ext| ext == "wal")
-                    || path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .is_some_and(|n| n.starts_with("thread_") && n.contains("_wal_"))
-            })
-            .collect();
-
-        wal_files.sort();
+        let wal_files = list_wal_files(wal_dir)?;
 
         if wal_files.is_empty() && self.recovery_mode == WalRecoveryMode::ErrorIfMissing {
             return Err(WalError::FileNotFound("No WAL files found".to_string()));
@@ -402,6 +389,25 @@ impl Default for ParallelWalParser {
     }
 }
 
+/// List the WAL segment files in a directory, sorted by name
+pub(crate) fn list_wal_files(wal_dir: &Path) -> WalResult<Vec<PathBuf>> {
+    let mut wal_files: Vec<PathBuf> = std::fs::read_dir(wal_dir)
+        .map_err(|e| WalError::IoError(e.to_string()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "wal")
+                || path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("thread_") && n.contains("_wal_"))
+        })
+        .collect();
+
+    wal_files.sort();
+    Ok(wal_files)
+}
+
 /// Parse result for a single WAL entry
 #[derive(Debug, Clone)]
 pub struct ParsedWalEntry {
@@ -442,7 +448,7 @@ pub struct LocalWalParser {
 
 /// Buffer for reassembling fragmented WAL records
 #[derive(Default)]
-struct FragmentBuffer {
+pub(crate) struct FragmentBuffer {
     /// Current fragments being assembled
     fragments: Vec<Vec<u8>>,
     /// Header of the first fragment
@@ -452,7 +458,7 @@ struct FragmentBuffer {
 }
 
 impl FragmentBuffer {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             fragments: Vec::new(),
             first_header: None,
@@ -460,13 +466,13 @@ impl FragmentBuffer {
         }
     }
 
-    fn reset(&mut self) {
+    pub(crate) fn reset(&mut self) {
         self.fragments.clear();
         self.first_header = None;
         self.expected_next = None;
     }
 
-    fn add_fragment(&mut self, header: WalHeader, payload: Vec<u8>) -> bool {
+    pub(crate) fn add_fragment(&mut self, header: WalHeader, payload: Vec<u8>) -> bool {
         let record_type = header.record_type;
 
         match record_type {
@@ -505,7 +511,7 @@ impl FragmentBuffer {
         }
     }
 
-    fn assemble(&self) -> Option<Vec<u8>> {
+    pub(crate) fn assemble(&self) -> Option<Vec<u8>> {
         if self.fragments.is_empty() {
             return None;
         }
@@ -516,9 +522,14 @@ impl FragmentBuffer {
         Some(result)
     }
 
-    fn get_first_header(&self) -> Option<&WalHeader> {
+    pub(crate) fn get_first_header(&self) -> Option<&WalHeader> {
         self.first_header.as_ref()
     }
+
+    /// Whether a first fragment was seen and its last fragment is still missing
+    pub(crate) fn in_progress(&self) -> bool {
+        self.first_header.is_some()
+    }
 }
 
 impl LocalWalParser {
@@ -581,20 +592,7 @@ impl LocalWalParser {
             return Ok(());
         }
 
-        let mut wal_files: Vec<PathBuf> = std::fs::read_dir(wal_dir)
-            .map_err(|e| WalError::IoError(e.to_string()))?
-            .filter_map(|entry| entry.ok())
-            .map(|entry| entry.path())
-            .filter(|path| {
-                path.extension().is_some_and(|ext| ext == "wal")
-                    || path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .is_some_and(|n| n.starts_with("thread_") && n.contains("_wal_"))
-            })
-            .collect();
-
-        wal_files.sort();
+        let wal_files = list_wal_files(wal_dir)?;
 
         if wal_files.is_empty() && self.recovery_mode == WalRecoveryMode::ErrorIfMissing {
             return Err(WalError::FileNotFound("No WAL files found".to_string()));
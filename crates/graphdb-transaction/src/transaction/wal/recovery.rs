@@ -9,12 +9,12 @@ use postcard::from_bytes;
 use crate::core::types::Timestamp;
 use crate::core::{StorageError, StorageResult};
 use crate::transaction::wal::{
-    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ClearSpaceRedo, CreateEdgeTypeRedo,
-    CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo, DeleteEdgeRedo, DeleteEdgeTypeRedo,
-    DeleteVertexPropRedo, DeleteVertexRedo, DeleteVertexTypeRedo, DropSpaceRedo, InsertEdgeRedo,
-    InsertVertexRedo, LocalWalParser, Lsn, ParallelWalParser, ParsedWalEntry, RecoveryResult,
-    RenameEdgePropRedo, RenameVertexPropRedo, UpdateEdgePropRedo, UpdateVertexPropRedo, WalOpType,
-    WalParser, WalRecoveryMode,
+    AddEdgePropRedo, AddVertexPropRedo, AlterSpaceCommentRedo, ChangeVertexPropTypeRedo,
+    ClearSpaceRedo, CreateEdgeTypeRedo, CreateSpaceRedo, CreateVertexTypeRedo, DeleteEdgePropRedo,
+    DeleteEdgeRedo, DeleteEdgeTypeRedo, DeleteVertexPropRedo, DeleteVertexRedo,
+    DeleteVertexTypeRedo, DropSpaceRedo, InsertEdgeRedo, InsertVertexRedo, LocalWalParser, Lsn,
+    ParallelWalParser, ParsedWalEntry, RecoveryResult, RenameEdgePropRedo, RenameVertexPropRedo,
+    UpdateEdgePropRedo, UpdateVertexPropRedo, WalOpType, WalParser, WalRecoveryMode,
 };
 
 /// Recovery configuration
@@ -392,6 +392,19 @@ impl RecoveryManager {
                         self.stats.errors_encountered += 1;
                     }
                 },
+                WalOpType::ChangeVertexPropType => {
+                    match self.deserialize_change_vertex_prop_type(payload) {
+                        Ok(redo) => {
+                            applier.replay_change_vertex_prop_type(&redo, ts)?;
+                            self.stats.wal_entries_replayed += 1;
+                            self.stats.last_lsn = entry.lsn;
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to deserialize ChangeVertexPropType redo: {}", e);
+                            self.stats.errors_encountered += 1;
+                        }
+                    }
+                }
                 WalOpType::Compact => {
                     applier.replay_compact(ts)?;
                     self.stats.wal_entries_replayed += 1;
@@ -500,6 +513,13 @@ impl RecoveryManager {
         from_bytes(payload).map_err(|e| StorageError::deserialize_error(e.to_string()))
     }
 
+    fn deserialize_change_vertex_prop_type(
+        &self,
+        payload: &[u8],
+    ) -> StorageResult<ChangeVertexPropTypeRedo> {
+        from_bytes(payload).map_err(|e| StorageError::deserialize_error(e.to_string()))
+    }
+
     /// Get recovery statistics
     pub fn stats(&self) -> &RecoveryStats {
         &self.stats
@@ -614,6 +634,7 @@ mod tests {
             replay_delete_edge_prop(redo: &DeleteEdgePropRedo, ts: Timestamp),
             replay_rename_vertex_prop(redo: &RenameVertexPropRedo, ts: Timestamp),
             replay_rename_edge_prop(redo: &RenameEdgePropRedo, ts: Timestamp),
+            replay_change_vertex_prop_type(redo: &ChangeVertexPropTypeRedo, ts: Timestamp),
         }
     }
 
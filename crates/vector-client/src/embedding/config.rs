@@ -1,5 +1,7 @@
 //! Configuration for embedding service
 
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 use super::error::EmbeddingError;
@@ -8,7 +10,8 @@ use super::preprocessor::PreprocessorConfig;
 /// Embedding service configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingConfig {
-    /// API endpoint URL
+    /// API endpoint URL (unused by local models)
+    #[serde(default)]
     pub base_url: String,
     /// API key (optional for some providers)
     #[serde(default)]
@@ -23,12 +26,125 @@ pub struct EmbeddingConfig {
     /// Preprocessor configuration for text transformation
     #[serde(default)]
     pub preprocessor: PreprocessorConfig,
+    /// Run an in-process model instead of calling `base_url`
+    #[serde(default)]
+    pub local: Option<LocalModelConfig>,
 }
 
 fn default_timeout() -> u64 {
     30
 }
 
+/// In-process sentence-transformer model
+///
+/// `model_dir` must contain the Hugging Face export of a BERT-family model:
+/// `config.json`, `tokenizer.json` and `model.safetensors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModelConfig {
+    /// Directory holding the model files
+    pub model_dir: PathBuf,
+    /// Maximum number of tokens per input; longer inputs are truncated
+    #[serde(default = "default_max_length")]
+    pub max_length: usize,
+    /// Maximum number of texts per forward pass
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// Number of embeddings kept for repeated texts (0 disables the cache)
+    #[serde(default = "default_cache_capacity")]
+    pub cache_capacity: u64,
+    /// How token embeddings are pooled into one vector
+    #[serde(default)]
+    pub pooling: PoolingStrategy,
+    /// L2-normalise the pooled vector
+    #[serde(default = "default_normalize")]
+    pub normalize: bool,
+}
+
+fn default_max_length() -> usize {
+    256
+}
+
+fn default_batch_size() -> usize {
+    32
+}
+
+fn default_cache_capacity() -> u64 {
+    10_000
+}
+
+fn default_normalize() -> bool {
+    true
+}
+
+/// Pooling applied to the last hidden state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolingStrategy {
+    /// Average of the non-padding tokens (sentence-transformers default)
+    #[default]
+    Mean,
+    /// Hidden state of the first (`[CLS]`) token, as used by BGE models
+    Cls,
+}
+
+impl LocalModelConfig {
+    /// Create a configuration for the model stored in `model_dir`
+    pub fn new(model_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            model_dir: model_dir.into(),
+            max_length: default_max_length(),
+            batch_size: default_batch_size(),
+            cache_capacity: default_cache_capacity(),
+            pooling: PoolingStrategy::default(),
+            normalize: default_normalize(),
+        }
+    }
+
+    /// Set the maximum input length in tokens
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Set the batch size
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the embedding cache capacity
+    pub fn with_cache_capacity(mut self, cache_capacity: u64) -> Self {
+        self.cache_capacity = cache_capacity;
+        self
+    }
+
+    /// Set the pooling strategy
+    pub fn with_pooling(mut self, pooling: PoolingStrategy) -> Self {
+        self.pooling = pooling;
+        self
+    }
+
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), EmbeddingError> {
+        if self.model_dir.as_os_str().is_empty() {
+            return Err(EmbeddingError::Config(
+                "local.model_dir is required".to_string(),
+            ));
+        }
+        if self.max_length == 0 {
+            return Err(EmbeddingError::Config(
+                "local.max_length must be greater than 0".to_string(),
+            ));
+        }
+        if self.batch_size == 0 {
+            return Err(EmbeddingError::Config(
+                "local.batch_size must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 impl EmbeddingConfig {
     /// Create a new configuration
     pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
@@ -39,6 +155,27 @@ impl EmbeddingConfig {
             timeout_secs: default_timeout(),
             dimension: None,
             preprocessor: PreprocessorConfig::default(),
+            local: None,
+        }
+    }
+
+    /// Create a configuration for an in-process model
+    ///
+    /// The model name defaults to the name of the model directory.
+    pub fn local(local: LocalModelConfig) -> Self {
+        let model = local
+            .model_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self {
+            base_url: String::new(),
+            api_key: None,
+            model,
+            timeout_secs: default_timeout(),
+            dimension: None,
+            preprocessor: PreprocessorConfig::default(),
+            local: Some(local),
         }
     }
 
@@ -66,8 +203,17 @@ impl EmbeddingConfig {
         self
     }
 
+    /// Whether embeddings are produced by an in-process model
+    pub fn is_local(&self) -> bool {
+        self.local.is_some()
+    }
+
     /// Validate the configuration
     pub fn validate(&self) -> Result<(), EmbeddingError> {
+        if let Some(local) = &self.local {
+            return local.validate();
+        }
+
         if self.base_url.is_empty() {
             return Err(EmbeddingError::Config("base_url is required".to_string()));
         }
@@ -94,6 +240,7 @@ impl Default for EmbeddingConfig {
             timeout_secs: default_timeout(),
             dimension: None,
             preprocessor: PreprocessorConfig::default(),
+            local: None,
         }
     }
 }
@@ -169,6 +316,39 @@ mod tests {
         assert!(err.to_string().contains("Invalid base_url"));
     }
 
+    #[test]
+    fn test_local_config() {
+        let cfg = EmbeddingConfig::local(LocalModelConfig::new("/models/all-MiniLM-L6-v2"));
+        assert!(cfg.is_local());
+        assert_eq!(cfg.model, "all-MiniLM-L6-v2");
+        assert!(cfg.validate().is_ok());
+
+        let local = cfg.local.as_ref().expect("local config");
+        assert_eq!(local.max_length, 256);
+        assert_eq!(local.pooling, PoolingStrategy::Mean);
+        assert!(local.normalize);
+    }
+
+    #[test]
+    fn test_validate_local_batch_size() {
+        let cfg = EmbeddingConfig::local(LocalModelConfig::new("/models/bge").with_batch_size(0));
+        let err = cfg.validate().unwrap_err();
+        assert!(err.to_string().contains("batch_size"));
+    }
+
+    #[test]
+    fn test_local_config_deserialize_defaults() {
+        let cfg: EmbeddingConfig = serde_json::from_str(
+            r#"{"model": "bge-small", "local": {"model_dir": "/models/bge", "pooling": "cls"}}"#,
+        )
+        .expect("deserialize");
+        assert!(cfg.base_url.is_empty());
+        let local = cfg.local.expect("local config");
+        assert_eq!(local.pooling, PoolingStrategy::Cls);
+        assert_eq!(local.batch_size, 32);
+        assert_eq!(local.cache_capacity, 10_000);
+    }
+
     #[test]
     fn test_default_config() {
         let cfg = EmbeddingConfig::default();
@@ -25,6 +25,10 @@ pub enum EmbeddingError {
     #[error("Token limit exceeded: {0} > {1}")]
     TokenLimitExceeded(usize, usize),
 
+    /// Local model loading or inference error
+    #[error("Model error: {0}")]
+    Model(String),
+
     /// Internal error
     #[error("Internal error: {0}")]
     Internal(String),
@@ -51,6 +55,13 @@ impl From<serde_json::Error> for EmbeddingError {
     }
 }
 
+#[cfg(feature = "local-embedding")]
+impl From<candle_core::Error> for EmbeddingError {
+    fn from(err: candle_core::Error) -> Self {
+        EmbeddingError::Model(err.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +96,12 @@ mod tests {
         assert_eq!(err.to_string(), "Token limit exceeded: 100 > 50");
     }
 
+    #[test]
+    fn test_display_model() {
+        let err = EmbeddingError::Model("shape mismatch".into());
+        assert_eq!(err.to_string(), "Model error: shape mismatch");
+    }
+
     #[test]
     fn test_display_internal() {
         let err = EmbeddingError::Internal("bug".into());
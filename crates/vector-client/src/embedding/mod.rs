@@ -2,6 +2,7 @@
 //!
 //! Provides text-to-vector embedding capabilities using various providers:
 //! - HTTP-based: OpenAI, Gemini, Azure, Ollama, and compatible endpoints
+//! - In-process: BERT-family sentence-transformer models (`local-embedding` feature)
 
 mod config;
 mod error;
@@ -10,11 +11,13 @@ mod provider;
 mod providers;
 mod service;
 
-pub use config::EmbeddingConfig;
+pub use config::{EmbeddingConfig, LocalModelConfig, PoolingStrategy};
 pub use error::EmbeddingError;
 pub use preprocessor::{NomicTaskType, PreprocessorConfig, PreprocessorImpl, StellaTaskType};
 pub use provider::EmbeddingProvider;
 pub use service::EmbeddingService;
 
 // Re-export providers for advanced usage
+#[cfg(feature = "local-embedding")]
+pub use providers::LocalEmbeddingProvider;
 pub use providers::OpenAICompatibleProvider;
@@ -0,0 +1,3 @@
+//! In-process embedding providers
+
+pub mod sentence_transformer_provider;
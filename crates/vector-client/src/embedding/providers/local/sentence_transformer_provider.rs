@@ -0,0 +1,385 @@
+//! In-process sentence-transformer provider for embeddings
+//!
+//! Runs a BERT-family model (all-MiniLM, BGE, E5, ...) on the CPU with candle, so
+//! text-to-vector search works without a network model server.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use candle_core::{DType, Device, IndexOp, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config as BertConfig, DTYPE};
+use moka::sync::Cache;
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+use tracing::debug;
+
+use crate::embedding::config::{EmbeddingConfig, LocalModelConfig, PoolingStrategy};
+use crate::embedding::error::{EmbeddingError, Result};
+use crate::embedding::preprocessor::PreprocessorImpl;
+use crate::embedding::provider::EmbeddingProvider;
+
+const MODEL_CONFIG_FILE: &str = "config.json";
+const TOKENIZER_FILE: &str = "tokenizer.json";
+const WEIGHTS_FILE: &str = "model.safetensors";
+
+/// Loaded model and tokenizer
+struct LocalModel {
+    model: BertModel,
+    tokenizer: Tokenizer,
+    device: Device,
+    pooling: PoolingStrategy,
+    normalize: bool,
+}
+
+impl LocalModel {
+    /// Load the model files from `local.model_dir`, returning the model and its hidden size
+    fn load(local: &LocalModelConfig) -> Result<(Self, usize)> {
+        let dir = local.model_dir.as_path();
+        let device = Device::Cpu;
+
+        let config_json = read_model_file(dir, MODEL_CONFIG_FILE)?;
+        let bert_config: BertConfig = serde_json::from_str(&config_json)
+            .map_err(|e| EmbeddingError::Config(format!("Invalid {}: {}", MODEL_CONFIG_FILE, e)))?;
+        let hidden_size = serde_json::from_str::<serde_json::Value>(&config_json)?
+            .get("hidden_size")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| {
+                EmbeddingError::Config(format!("{} has no hidden_size", MODEL_CONFIG_FILE))
+            })? as usize;
+
+        let weights = std::fs::read(dir.join(WEIGHTS_FILE)).map_err(|e| {
+            EmbeddingError::Config(format!(
+                "Failed to read {}: {}",
+                dir.join(WEIGHTS_FILE).display(),
+                e
+            ))
+        })?;
+        let vb = VarBuilder::from_buffered_safetensors(weights, DTYPE, &device)?;
+        let model = BertModel::load(vb, &bert_config)?;
+
+        let mut tokenizer = Tokenizer::from_file(dir.join(TOKENIZER_FILE)).map_err(|e| {
+            EmbeddingError::Config(format!("Failed to load {}: {}", TOKENIZER_FILE, e))
+        })?;
+        if tokenizer.get_padding().is_none() {
+            tokenizer.with_padding(Some(PaddingParams {
+                strategy: PaddingStrategy::BatchLongest,
+                ..Default::default()
+            }));
+        }
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: local.max_length,
+                ..Default::default()
+            }))
+            .map_err(|e| EmbeddingError::Config(format!("Invalid truncation: {}", e)))?;
+
+        Ok((
+            Self {
+                model,
+                tokenizer,
+                device,
+                pooling: local.pooling,
+                normalize: local.normalize,
+            },
+            hidden_size,
+        ))
+    }
+
+    /// Run one forward pass over already preprocessed texts
+    fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts, true)
+            .map_err(|e| EmbeddingError::Model(format!("Tokenization failed: {}", e)))?;
+
+        let batch = encodings.len();
+        let seq_len = encodings.first().map(|e| e.get_ids().len()).unwrap_or(0);
+        let mut ids = Vec::with_capacity(batch * seq_len);
+        let mut type_ids = Vec::with_capacity(batch * seq_len);
+        let mut mask = Vec::with_capacity(batch * seq_len);
+        for encoding in &encodings {
+            ids.extend_from_slice(encoding.get_ids());
+            type_ids.extend_from_slice(encoding.get_type_ids());
+            mask.extend_from_slice(encoding.get_attention_mask());
+        }
+
+        let input_ids = Tensor::from_vec(ids, (batch, seq_len), &self.device)?;
+        let token_type_ids = Tensor::from_vec(type_ids, (batch, seq_len), &self.device)?;
+        let attention_mask = Tensor::from_vec(mask, (batch, seq_len), &self.device)?;
+
+        let hidden = self
+            .model
+            .forward(&input_ids, &token_type_ids, Some(&attention_mask))?;
+        let pooled = pool(&hidden, &attention_mask, self.pooling, self.normalize)?;
+        Ok(pooled.to_vec2::<f32>()?)
+    }
+}
+
+fn read_model_file(dir: &Path, name: &str) -> Result<String> {
+    let path = dir.join(name);
+    std::fs::read_to_string(&path)
+        .map_err(|e| EmbeddingError::Config(format!("Failed to read {}: {}", path.display(), e)))
+}
+
+/// Pool `(batch, seq, hidden)` token states into `(batch, hidden)` sentence vectors
+fn pool(
+    hidden: &Tensor,
+    attention_mask: &Tensor,
+    pooling: PoolingStrategy,
+    normalize: bool,
+) -> Result<Tensor> {
+    let pooled = match pooling {
+        PoolingStrategy::Mean => {
+            let mask = attention_mask.to_dtype(DType::F32)?.unsqueeze(2)?;
+            let summed = hidden.broadcast_mul(&mask)?.sum(1)?;
+            let counts = mask.sum(1)?.maximum(1e-9f32)?;
+            summed.broadcast_div(&counts)?
+        }
+        PoolingStrategy::Cls => hidden.i((.., 0))?,
+    };
+
+    if normalize {
+        let norm = pooled.sqr()?.sum_keepdim(1)?.sqrt()?.maximum(1e-12f32)?;
+        Ok(pooled.broadcast_div(&norm)?)
+    } else {
+        Ok(pooled)
+    }
+}
+
+/// In-process sentence-transformer provider
+///
+/// Inputs are preprocessed with the configured `PreprocessorConfig`, looked up in an
+/// embedding cache, and the misses are run through the model in batches of
+/// `LocalModelConfig::batch_size` on a blocking thread.
+pub struct LocalEmbeddingProvider {
+    model: Arc<LocalModel>,
+    config: EmbeddingConfig,
+    preprocessor: PreprocessorImpl,
+    batch_size: usize,
+    dimension: usize,
+    cache: Option<Cache<String, Arc<Vec<f32>>>>,
+}
+
+impl std::fmt::Debug for LocalEmbeddingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalEmbeddingProvider")
+            .field("model", &self.config.model)
+            .field("dimension", &self.dimension)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl LocalEmbeddingProvider {
+    /// Load the model described by `config.local`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vector_client::embedding::{EmbeddingConfig, LocalEmbeddingProvider, LocalModelConfig};
+    ///
+    /// let config = EmbeddingConfig::local(LocalModelConfig::new("/models/all-MiniLM-L6-v2"));
+    /// let provider = LocalEmbeddingProvider::new(config).expect("failed");
+    /// ```
+    pub fn new(config: EmbeddingConfig) -> Result<Self> {
+        config.validate()?;
+        let local = config.local.clone().ok_or_else(|| {
+            EmbeddingError::Config("Local model configuration is required".to_string())
+        })?;
+
+        let (model, hidden_size) = LocalModel::load(&local)?;
+        if let Some(dimension) = config.dimension {
+            if dimension != hidden_size {
+                return Err(EmbeddingError::Config(format!(
+                    "Configured dimension {} does not match model hidden size {}",
+                    dimension, hidden_size
+                )));
+            }
+        }
+
+        let cache = (local.cache_capacity > 0).then(|| Cache::new(local.cache_capacity));
+        let preprocessor = PreprocessorImpl::from_config(&config.preprocessor);
+
+        debug!(
+            "Loaded local embedding model {} (dimension {})",
+            local.model_dir.display(),
+            hidden_size
+        );
+
+        Ok(Self {
+            model: Arc::new(model),
+            config,
+            preprocessor,
+            batch_size: local.batch_size,
+            dimension: hidden_size,
+            cache,
+        })
+    }
+
+    /// Run the model off the async executor when a tokio runtime is available
+    async fn run_model(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+        let model = self.model.clone();
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle
+                .spawn_blocking(move || model.embed(texts))
+                .await
+                .map_err(|e| EmbeddingError::Internal(format!("Embedding task failed: {}", e)))?,
+            Err(_) => model.embed(texts),
+        }
+    }
+
+    /// Create embeddings for texts
+    ///
+    /// Identical inputs within a call are embedded once, and repeated inputs across calls
+    /// are served from the cache.
+    pub async fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut results: Vec<Option<Vec<f32>>> = vec![None; texts.len()];
+        let mut pending: Vec<String> = Vec::new();
+        let mut positions: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (i, text) in self
+            .preprocessor
+            .process_batch(texts)
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(cached) = self.cache.as_ref().and_then(|c| c.get(&text)) {
+                results[i] = Some(cached.as_ref().clone());
+                continue;
+            }
+            positions
+                .entry(text)
+                .or_insert_with_key(|text| {
+                    pending.push(text.clone());
+                    Vec::new()
+                })
+                .push(i);
+        }
+
+        for chunk in pending.chunks(self.batch_size) {
+            let embeddings = self.run_model(chunk.to_vec()).await?;
+            if embeddings.len() != chunk.len() {
+                return Err(EmbeddingError::Model(format!(
+                    "Expected {} embeddings, got {}",
+                    chunk.len(),
+                    embeddings.len()
+                )));
+            }
+            for (text, embedding) in chunk.iter().zip(embeddings) {
+                for &i in &positions[text] {
+                    results[i] = Some(embedding.clone());
+                }
+                if let Some(cache) = &self.cache {
+                    cache.insert(text.clone(), Arc::new(embedding));
+                }
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|r| r.ok_or_else(|| EmbeddingError::Internal("Missing embedding".to_string())))
+            .collect()
+    }
+
+    /// Get configuration
+    pub fn config(&self) -> &EmbeddingConfig {
+        &self.config
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingProvider for LocalEmbeddingProvider {
+    async fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        LocalEmbeddingProvider::embed(self, texts).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn model_name(&self) -> &str {
+        &self.config.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hidden_states() -> Tensor {
+        // batch of 2, 3 tokens, hidden size 2; the second input has one padding token
+        Tensor::new(
+            &[
+                [[1.0f32, 0.0], [3.0, 0.0], [5.0, 0.0]],
+                [[0.0f32, 2.0], [0.0, 4.0], [100.0, 100.0]],
+            ],
+            &Device::Cpu,
+        )
+        .expect("tensor")
+    }
+
+    fn attention_mask() -> Tensor {
+        Tensor::new(&[[1u32, 1, 1], [1, 1, 0]], &Device::Cpu).expect("tensor")
+    }
+
+    #[test]
+    fn test_mean_pooling_ignores_padding() {
+        let pooled = pool(
+            &hidden_states(),
+            &attention_mask(),
+            PoolingStrategy::Mean,
+            false,
+        )
+        .expect("pool");
+        let pooled = pooled.to_vec2::<f32>().expect("vec2");
+        assert_eq!(pooled, vec![vec![3.0, 0.0], vec![0.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_cls_pooling_takes_first_token() {
+        let pooled = pool(
+            &hidden_states(),
+            &attention_mask(),
+            PoolingStrategy::Cls,
+            false,
+        )
+        .expect("pool");
+        let pooled = pooled.to_vec2::<f32>().expect("vec2");
+        assert_eq!(pooled, vec![vec![1.0, 0.0], vec![0.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_normalized_pooling_has_unit_length() {
+        let pooled = pool(
+            &hidden_states(),
+            &attention_mask(),
+            PoolingStrategy::Mean,
+            true,
+        )
+        .expect("pool");
+        for row in pooled.to_vec2::<f32>().expect("vec2") {
+            let norm: f32 = row.iter().map(|v| v * v).sum::<f32>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_missing_model_dir() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let config = EmbeddingConfig::local(LocalModelConfig::new(dir.path()));
+        let err = LocalEmbeddingProvider::new(config).unwrap_err();
+        assert!(err.to_string().contains(MODEL_CONFIG_FILE));
+    }
+
+    #[test]
+    fn test_requires_local_config() {
+        let config = EmbeddingConfig::new("http://localhost:11434/api/embeddings", "model");
+        let err = LocalEmbeddingProvider::new(config).unwrap_err();
+        assert!(err.to_string().contains("Local model configuration"));
+    }
+}
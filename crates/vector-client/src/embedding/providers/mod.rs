@@ -2,7 +2,12 @@
 //!
 //! This module contains concrete provider implementations:
 //! - HTTP-based providers (OpenAI, Gemini, Ollama, etc.)
+//! - In-process sentence-transformer models (`local-embedding` feature)
 
 pub mod http;
+#[cfg(feature = "local-embedding")]
+pub mod local;
 
 pub use http::openai_compatible_provider::OpenAICompatibleProvider;
+#[cfg(feature = "local-embedding")]
+pub use local::sentence_transformer_provider::LocalEmbeddingProvider;
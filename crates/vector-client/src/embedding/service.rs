@@ -4,21 +4,42 @@ use super::config::EmbeddingConfig;
 use super::error::{EmbeddingError, Result};
 use super::provider::EmbeddingProvider;
 
+#[cfg(feature = "local-embedding")]
+use super::providers::LocalEmbeddingProvider;
 use super::providers::OpenAICompatibleProvider;
 
+/// Concrete provider behind the service — no trait object needed
+enum ServiceProvider {
+    Http(OpenAICompatibleProvider),
+    #[cfg(feature = "local-embedding")]
+    Local(LocalEmbeddingProvider),
+}
+
+impl ServiceProvider {
+    fn provider(&self) -> &dyn EmbeddingProvider {
+        match self {
+            ServiceProvider::Http(provider) => provider,
+            #[cfg(feature = "local-embedding")]
+            ServiceProvider::Local(provider) => provider,
+        }
+    }
+}
+
 /// Embedding service wrapper
 ///
-/// This service provides a unified interface for HTTP-based embedding providers.
+/// This service provides a unified interface over HTTP-based providers and, with the
+/// `local-embedding` feature, in-process models.
 pub struct EmbeddingService {
-    provider: OpenAICompatibleProvider,
+    provider: ServiceProvider,
     config: EmbeddingConfig,
     dimension: usize,
 }
 
 impl EmbeddingService {
-    /// Create from configuration (HTTP-based provider)
+    /// Create from configuration
     ///
-    /// This creates an OpenAI-compatible HTTP provider.
+    /// This creates an in-process provider when `config.local` is set, and an
+    /// OpenAI-compatible HTTP provider otherwise.
     ///
     /// # Example
     ///
@@ -34,8 +55,12 @@ impl EmbeddingService {
     pub fn from_config(config: EmbeddingConfig) -> Result<Self> {
         config.validate()?;
 
-        let provider = OpenAICompatibleProvider::new(config.clone())?;
-        let dimension = provider.dimension();
+        let provider = if config.is_local() {
+            Self::local_provider(&config)?
+        } else {
+            ServiceProvider::Http(OpenAICompatibleProvider::new(config.clone())?)
+        };
+        let dimension = provider.provider().dimension();
 
         Ok(Self {
             provider,
@@ -44,9 +69,23 @@ impl EmbeddingService {
         })
     }
 
+    #[cfg(feature = "local-embedding")]
+    fn local_provider(config: &EmbeddingConfig) -> Result<ServiceProvider> {
+        Ok(ServiceProvider::Local(LocalEmbeddingProvider::new(
+            config.clone(),
+        )?))
+    }
+
+    #[cfg(not(feature = "local-embedding"))]
+    fn local_provider(_config: &EmbeddingConfig) -> Result<ServiceProvider> {
+        Err(EmbeddingError::Config(
+            "Local embedding models require the local-embedding feature".to_string(),
+        ))
+    }
+
     /// Embed a single text
     pub async fn embed(&self, text: &str) -> Result<Vec<f32>> {
-        let embeddings = self.provider.embed(&[text]).await?;
+        let embeddings = self.provider.provider().embed(&[text]).await?;
         embeddings
             .into_iter()
             .next()
@@ -55,17 +94,17 @@ impl EmbeddingService {
 
     /// Embed multiple texts in batch
     pub async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
-        self.provider.embed(texts).await
+        self.provider.provider().embed(texts).await
     }
 
     /// Get the dimension
     pub fn dimension(&self) -> usize {
-        self.provider.dimension()
+        self.provider.provider().dimension()
     }
 
     /// Get the model name
     pub fn model_name(&self) -> &str {
-        self.provider.model_name()
+        self.provider.provider().model_name()
     }
 }
 
@@ -89,6 +128,14 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[cfg(not(feature = "local-embedding"))]
+    #[test]
+    fn test_local_config_requires_feature() {
+        let config = EmbeddingConfig::local(crate::embedding::LocalModelConfig::new("/models/bge"));
+        let err = EmbeddingService::from_config(config).unwrap_err();
+        assert!(err.to_string().contains("local-embedding"));
+    }
+
     #[test]
     fn test_service_debug() {
         let config = EmbeddingConfig::new("http://example.com", "my-model").with_dimension(768);
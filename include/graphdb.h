@@ -145,6 +145,26 @@ typedef enum graphdb_value_type_t {
    * binary data
    */
   GRAPHDB_BLOB = 10,
+  /**
+   * vector
+   */
+  GRAPHDB_VECTOR = 11,
+  /**
+   * date
+   */
+  GRAPHDB_DATE = 12,
+  /**
+   * time of day
+   */
+  GRAPHDB_TIME = 13,
+  /**
+   * date and time
+   */
+  GRAPHDB_DATETIME = 14,
+  /**
+   * duration (interval)
+   */
+  GRAPHDB_DURATION = 15,
 } graphdb_value_type_t;
 
 /**
@@ -312,6 +332,20 @@ typedef struct graphdb_result_t {
 
 } graphdb_result_t;
 
+/**
+ * Prepared statement handle (opaque pointer)
+ */
+typedef struct graphdb_stmt_t {
+
+} graphdb_stmt_t;
+
+/**
+ * Value handle (opaque pointer)
+ */
+typedef struct graphdb_value_handle_t {
+
+} graphdb_value_handle_t;
+
 /**
  * SQL Trace Callback Types
  */
@@ -1173,823 +1207,1950 @@ int graphdb_execute_params(struct graphdb_session_t *session,
                            struct graphdb_result_t **result);
 
 /**
- * Releasing the result set
+ * Prepare a parameterized query
+ *
+ * Placeholders are written as `$name`. The query is parsed, validated and planned
+ * immediately, so syntax and semantic errors are reported here rather than by
+ * `graphdb_step`.
  *
  * # Arguments
- * - `result`: Result set handle
+ * - `session`: Session handle
+ * - `query`: Query statement (UTF-8 encoded)
+ * - `stmt`: Output parameter, prepared statement handle
  *
  * # Returns
  * - Success: GRAPHDB_OK
  * - Failure: Error code
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - After calling this function, the result handle becomes invalid and must not be used
- * - Any string pointers obtained from this result set become invalid after this call
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `query` must be a valid pointer to a null-terminated UTF-8 string
+ * - `stmt` must be a valid pointer to store the statement handle
+ * - The statement must be released with `graphdb_finalize` before the session is closed
  */
-int graphdb_result_free(struct graphdb_result_t *result);
+int graphdb_prepare(struct graphdb_session_t *session,
+                    const char *query,
+                    struct graphdb_stmt_t **stmt);
 
 /**
- * Get the number of columns in the result set
+ * Get the number of distinct placeholders in a prepared statement
  *
  * # Arguments
- * - `result`: Result set handle
+ * - `stmt`: Statement handle
  *
  * # Returns
- * - Number of columns, returns -1 on error
+ * - Number of placeholders, returns -1 on error
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_column_count(struct graphdb_result_t *result);
+int graphdb_bind_parameter_count(struct graphdb_stmt_t *stmt);
 
 /**
- * Get the number of rows in the result set
+ * Get the index of a named placeholder
  *
  * # Arguments
- * - `result`: Result set handle
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
  *
  * # Returns
- * - Number of rows, returns -1 on error
+ * - Index of the placeholder (starting from 1), returns 0 if there is no such placeholder
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
  */
-int graphdb_row_count(struct graphdb_result_t *result);
+int graphdb_bind_parameter_index(struct graphdb_stmt_t *stmt,
+                                 const char *name);
 
 /**
- * Getting Column Names
+ * Bind NULL to a placeholder (by name)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `index`: Column index (starting from 0)
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
  *
  * # Returns
- * - Column name (UTF-8 encoded), returns NULL on error
- *
- * # Memory Management
- * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
- * to avoid memory leaks.
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_NOTFOUND if the statement has no such placeholder
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `index` must be a valid column index (0 <= index < column count)
- * - The returned pointer must be freed by the caller to avoid memory leaks
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
  */
-char *graphdb_column_name(struct graphdb_result_t *result,
-                          int index);
+int graphdb_bind_null(struct graphdb_stmt_t *stmt,
+                      const char *name);
 
 /**
- * Get integer value
+ * Bind a boolean value to a placeholder (by name)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column name (UTF-8 encoded)
- * - `value`: Output parameter, integer value
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
+ * - `value`: Boolean value
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_NOTFOUND if the statement has no such placeholder
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `col` must be a valid pointer to a null-terminated UTF-8 string
- * - `value` must be a valid pointer to store the result
- * - `row` must be a valid row index (0 <= row < row count)
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
  */
-int graphdb_get_int(struct graphdb_result_t *result, int row, const char *col, int64_t *value);
+int graphdb_bind_bool(struct graphdb_stmt_t *stmt,
+                      const char *name,
+                      bool value);
 
 /**
- * Getting String Values
+ * Bind an integer value to a placeholder (by name)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column name (UTF-8 encoded)
- * - `len`: Output parameter, string length
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
+ * - `value`: Integer value
  *
  * # Returns
- * - String value (UTF-8 encoded), returns NULL on error
- *
- * # Memory Management
- * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
- * to avoid memory leaks.
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_NOTFOUND if the statement has no such placeholder
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `col` must be a valid pointer to a null-terminated UTF-8 string
- * - `len` must be a valid pointer to store the string length, or NULL if not needed
- * - `row` must be a valid row index (0 <= row < row count)
- * - The returned pointer must be freed by the caller to avoid memory leaks
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
  */
-char *graphdb_get_string(struct graphdb_result_t *result,
-                         int row,
-                         const char *col,
-                         int *len);
+int graphdb_bind_int(struct graphdb_stmt_t *stmt,
+                     const char *name,
+                     int64_t value);
 
 /**
- * Get Binary Data
+ * Bind a floating-point value to a placeholder (by name)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column name (UTF-8 encoded)
- * - `len`: Output parameter, data length (in bytes)
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
+ * - `value`: Floating-point value
  *
  * # Returns
- * - Data pointer, returns NULL on error
- *
- * # Note
- * The returned pointer's lifetime is bound to the result set; the caller should not free it
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_NOTFOUND if the statement has no such placeholder
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `col` must be a valid pointer to a null-terminated UTF-8 string
- * - `len` must be a valid pointer to store the data length, or NULL if not needed
- * - `row` must be a valid row index (0 <= row < row count)
- * - The returned pointer is only valid as long as the result set is not freed
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
  */
-const uint8_t *graphdb_get_blob(struct graphdb_result_t *result,
-                                int row,
-                                const char *col,
-                                int *len);
+int graphdb_bind_float(struct graphdb_stmt_t *stmt,
+                       const char *name,
+                       double value);
 
 /**
- * Get integer values (indexed by column)
+ * Bind a string value to a placeholder (by name)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column index (starting from 0)
- * - `value`: Output parameter, integer value
+ * - `stmt`: Statement handle
+ * - `name`: Placeholder name, with or without the leading `$`
+ * - `value`: String value (UTF-8 encoded)
+ * - `len`: Length of `value` in bytes, or -1 if it is null-terminated
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_NOTFOUND if the statement has no such placeholder
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `value` must be a valid pointer to store the result
- * - `row` must be a valid row index (0 <= row < row count)
- * - `col` must be a valid column index (0 <= col < column count)
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
+ * - `value` must point to at least `len` bytes, or to a null-terminated string if `len` is negative
+ * - The string is copied; the caller keeps ownership of `value`
  */
-int graphdb_get_int_by_index(struct graphdb_result_t *result, int row, int col, int64_t *value);
+int graphdb_bind_string(struct graphdb_stmt_t *stmt,
+                        const char *name,
+                        const char *value,
+                        int len);
 
 /**
- * Get string value (indexed by column)
+ * Bind NULL to a placeholder (by index)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column index (starting from 0)
- * - `len`: Output parameter, string length
+ * - `stmt`: Statement handle
+ * - `index`: Placeholder index (starting from 1)
  *
  * # Returns
- * - String value (UTF-8 encoded), returns NULL on error
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
- * # Memory Management
- * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
- * to avoid memory leaks.
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ */
+int graphdb_bind_null_by_index(struct graphdb_stmt_t *stmt,
+                               int index);
+
+/**
+ * Bind a boolean value to a placeholder (by index)
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ * - `index`: Placeholder index (starting from 1)
+ * - `value`: Boolean value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `len` must be a valid pointer to store the string length, or NULL if not needed
- * - `row` must be a valid row index (0 <= row < row count)
- * - `col` must be a valid column index (0 <= col < column count)
- * - The returned pointer must be freed by the caller to avoid memory leaks
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-char *graphdb_get_string_by_index(struct graphdb_result_t *result,
-                                  int row,
-                                  int col,
-                                  int *len);
+int graphdb_bind_bool_by_index(struct graphdb_stmt_t *stmt,
+                               int index,
+                               bool value);
 
 /**
- * Get Boolean value (indexed by column)
+ * Bind an integer value to a placeholder (by index)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column index (starting from 0)
- * - `value`: Output parameter, boolean value
+ * - `stmt`: Statement handle
+ * - `index`: Placeholder index (starting from 1)
+ * - `value`: Integer value
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `value` must be a valid pointer to store the result
- * - `row` must be a valid row index (0 <= row < row count)
- * - `col` must be a valid column index (0 <= col < column count)
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_get_bool_by_index(struct graphdb_result_t *result, int row, int col, bool *value);
+int graphdb_bind_int_by_index(struct graphdb_stmt_t *stmt,
+                              int index,
+                              int64_t value);
 
 /**
- * Get floating point values (indexed by column)
+ * Bind a floating-point value to a placeholder (by index)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column index (starting from 0)
- * - `value`: Output parameter, float value
+ * - `stmt`: Statement handle
+ * - `index`: Placeholder index (starting from 1)
+ * - `value`: Floating-point value
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `value` must be a valid pointer to store the result
- * - `row` must be a valid row index (0 <= row < row count)
- * - `col` must be a valid column index (0 <= col < column count)
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_get_float_by_index(struct graphdb_result_t *result, int row, int col, double *value);
+int graphdb_bind_float_by_index(struct graphdb_stmt_t *stmt,
+                                int index,
+                                double value);
 
 /**
- * Get binary data (indexed by column)
+ * Bind a string value to a placeholder (by index)
  *
  * # Arguments
- * - `result`: Result set handle
- * - `row`: Row index (starting from 0)
- * - `col`: Column index (starting from 0)
- * - `len`: Output parameter, data length (in bytes)
+ * - `stmt`: Statement handle
+ * - `index`: Placeholder index (starting from 1)
+ * - `value`: String value (UTF-8 encoded)
+ * - `len`: Length of `value` in bytes, or -1 if it is null-terminated
  *
  * # Returns
- * - Data pointer, returns NULL on error
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
- * # Note
- * The returned pointer's lifetime is bound to the result set; the caller should not free it
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `value` must point to at least `len` bytes, or to a null-terminated string if `len` is negative
+ * - The string is copied; the caller keeps ownership of `value`
+ */
+int graphdb_bind_string_by_index(struct graphdb_stmt_t *stmt,
+                                 int index,
+                                 const char *value,
+                                 int len);
+
+/**
+ * Remove all bound values
+ *
+ * Unbound placeholders evaluate to NULL.
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISUSE if the statement has been stepped and not reset
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `len` must be a valid pointer to store the data length, or NULL if not needed
- * - `row` must be a valid row index (0 <= row < row count)
- * - `col` must be a valid column index (0 <= col < column count)
- * - The returned pointer is only valid as long as the result set is not freed
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-const uint8_t *graphdb_get_blob_by_index(struct graphdb_result_t *result,
-                                         int row,
-                                         int col,
-                                         int *len);
+int graphdb_clear_bindings(struct graphdb_stmt_t *stmt);
 
 /**
- * Get column type
+ * Advance a prepared statement to its next row
+ *
+ * The first step after `graphdb_prepare` or `graphdb_reset` executes the statement
+ * with the current bindings. Stepping a statement that has returned GRAPHDB_DONE
+ * resets it and executes it again.
  *
  * # Arguments
- * - `result`: Result set handle
- * - `col`: Column index (starting from 0)
+ * - `stmt`: Statement handle
  *
  * # Returns
- * - Column type, returns GRAPHDB_NULL on error
+ * - GRAPHDB_ROW: a row is available through the `graphdb_stmt_*` column accessors
+ * - GRAPHDB_DONE: the statement has finished executing
+ * - Failure: Error code; the message is available through the session's error functions
  *
  * # Safety
- * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
- * - `col` must be a valid column index (0 <= col < column count)
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - The session the statement was prepared on must still be open
  */
-enum graphdb_value_type_t graphdb_column_type(struct graphdb_result_t *result, int col);
+int graphdb_step(struct graphdb_stmt_t *stmt);
 
 /**
- * Create a session
+ * Reset a prepared statement so it can be executed again
+ *
+ * Bindings are kept; use `graphdb_clear_bindings` to remove them.
  *
  * # Arguments
- * - `db`: Database handle
- * - `session`: Output parameter, session handle
+ * - `stmt`: Statement handle
  *
  * # Returns
  * - Success: GRAPHDB_OK
  * - Failure: Error code
  *
  * # Safety
- * - `db` must be a valid database handle created by `graphdb_open` or `graphdb_open_v2`
- * - `session` must be a valid pointer to store the session handle
- * - The caller is responsible for closing the session using `graphdb_session_close` when done
- * - The session handle must not be used after closing
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_session_create(struct graphdb_t *db, struct graphdb_session_t **session);
+int graphdb_reset(struct graphdb_stmt_t *stmt);
 
 /**
- * Close the session.
+ * Release a prepared statement
  *
  * # Arguments
- * - `session`: Session handle
+ * - `stmt`: Statement handle
  *
  * # Returns
  * - Success: GRAPHDB_OK
  * - Failure: Error code
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - After calling this function, the session handle becomes invalid and must not be used
- * - All pending transactions will be rolled back
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - After calling this function, the statement handle becomes invalid and must not be used
+ * - Any string pointers obtained from the statement's column accessors remain valid
+ *   until freed with `graphdb_free_string`
  */
-int graphdb_session_close(struct graphdb_session_t *session);
+int graphdb_finalize(struct graphdb_stmt_t *stmt);
 
 /**
- * Switch to the image space
+ * Get the number of columns returned by a stepped statement
  *
  * # Arguments
- * - `session`: Session handle
- * - `space_name`: Graph space name (UTF-8 encoded)
+ * - `stmt`: Statement handle
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Number of columns, 0 if the statement has not been stepped, returns -1 on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `space_name` must be a valid pointer to a null-terminated UTF-8 string
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_session_use_space(struct graphdb_session_t *session, const char *space_name);
+int graphdb_stmt_column_count(struct graphdb_stmt_t *stmt);
 
 /**
- * Obtain the current image space
+ * Get the column name of a stepped statement
  *
  * # Arguments
- * - `session`: Session handle
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
  *
  * # Returns
- * - Current graph space name (UTF-8 encoded), returns NULL if none
+ * - Column name (UTF-8 encoded), returns NULL on error
  *
  * # Memory Management
  * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
  * to avoid memory leaks.
  *
- * # Example
- * ```c
- * char* space = graphdb_session_current_space(session);
- * if (space) {
- *     printf("Current space: %s\n", space);
- *     graphdb_free_string(space);  // Must free
- * }
- * ```
- *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  * - The returned pointer must be freed by the caller to avoid memory leaks
  */
-char *graphdb_session_current_space(struct graphdb_session_t *session);
+char *graphdb_stmt_column_name(struct graphdb_stmt_t *stmt,
+                               int col);
 
 /**
- * Enable the automatic submission mode.
+ * Get the value type of a column in the current row
  *
  * # Arguments
- * - `session`: Session handle
- * - `autocommit`: Whether to enable autocommit
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Column type, returns GRAPHDB_NULL on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
  */
-int graphdb_session_set_autocommit(struct graphdb_session_t *session, bool autocommit);
+enum graphdb_value_type_t graphdb_stmt_column_type(struct graphdb_stmt_t *stmt,
+                                                   int col);
 
 /**
- * Enable the automatic submission mode.
+ * Get an integer value from the current row
  *
  * # Arguments
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, integer value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `value` must be a valid pointer to store the result
+ */
+int graphdb_stmt_get_int(struct graphdb_stmt_t *stmt,
+                         int col,
+                         int64_t *value);
+
+/**
+ * Get a floating-point value from the current row
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, floating-point value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `value` must be a valid pointer to store the result
+ */
+int graphdb_stmt_get_float(struct graphdb_stmt_t *stmt,
+                           int col,
+                           double *value);
+
+/**
+ * Get a boolean value from the current row
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, boolean value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `value` must be a valid pointer to store the result
+ */
+int graphdb_stmt_get_bool(struct graphdb_stmt_t *stmt,
+                          int col,
+                          bool *value);
+
+/**
+ * Get a string value from the current row
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
+ * - `len`: Output parameter, string length
+ *
+ * # Returns
+ * - String value (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `len` must be a valid pointer to store the string length, or NULL if not needed
+ * - The returned pointer must be freed by the caller to avoid memory leaks
+ */
+char *graphdb_stmt_get_string(struct graphdb_stmt_t *stmt,
+                              int col,
+                              int *len);
+
+/**
+ * Get a value handle from the current row
+ *
+ * Use this for composite values (vertices, edges, paths, lists, maps, vectors and
+ * temporal values), which are navigated with the `graphdb_value_*` family of functions.
+ *
+ * # Arguments
+ * - `stmt`: Statement handle
+ * - `col`: Column index (starting from 0)
+ * - `out`: Output parameter, value handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Memory Management
+ * The handle stored in `out` is an independent copy owned by the caller. It stays valid
+ * after the statement steps, resets or is finalized, and must be released with `graphdb_value_free`.
+ *
+ * # Safety
+ * - `stmt` must be a valid statement handle created by `graphdb_prepare`
+ * - `out` must be a valid pointer to store the handle
+ */
+int graphdb_stmt_get_value(struct graphdb_stmt_t *stmt,
+                           int col,
+                           struct graphdb_value_handle_t **out);
+
+
+/**
+ * Releasing the result set
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - After calling this function, the result handle becomes invalid and must not be used
+ * - Any string pointers obtained from this result set become invalid after this call
+ */
+int graphdb_result_free(struct graphdb_result_t *result);
+
+/**
+ * Get the number of columns in the result set
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ *
+ * # Returns
+ * - Number of columns, returns -1 on error
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ */
+int graphdb_column_count(struct graphdb_result_t *result);
+
+/**
+ * Get the number of rows in the result set
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ *
+ * # Returns
+ * - Number of rows, returns -1 on error
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ */
+int graphdb_row_count(struct graphdb_result_t *result);
+
+/**
+ * Getting Column Names
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `index`: Column index (starting from 0)
+ *
+ * # Returns
+ * - Column name (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `index` must be a valid column index (0 <= index < column count)
+ * - The returned pointer must be freed by the caller to avoid memory leaks
+ */
+char *graphdb_column_name(struct graphdb_result_t *result,
+                          int index);
+
+/**
+ * Get integer value
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column name (UTF-8 encoded)
+ * - `value`: Output parameter, integer value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `col` must be a valid pointer to a null-terminated UTF-8 string
+ * - `value` must be a valid pointer to store the result
+ * - `row` must be a valid row index (0 <= row < row count)
+ */
+int graphdb_get_int(struct graphdb_result_t *result, int row, const char *col, int64_t *value);
+
+/**
+ * Getting String Values
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column name (UTF-8 encoded)
+ * - `len`: Output parameter, string length
+ *
+ * # Returns
+ * - String value (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `col` must be a valid pointer to a null-terminated UTF-8 string
+ * - `len` must be a valid pointer to store the string length, or NULL if not needed
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - The returned pointer must be freed by the caller to avoid memory leaks
+ */
+char *graphdb_get_string(struct graphdb_result_t *result,
+                         int row,
+                         const char *col,
+                         int *len);
+
+/**
+ * Get Binary Data
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column name (UTF-8 encoded)
+ * - `len`: Output parameter, data length (in bytes)
+ *
+ * # Returns
+ * - Data pointer, returns NULL on error
+ *
+ * # Note
+ * The returned pointer's lifetime is bound to the result set; the caller should not free it
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `col` must be a valid pointer to a null-terminated UTF-8 string
+ * - `len` must be a valid pointer to store the data length, or NULL if not needed
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - The returned pointer is only valid as long as the result set is not freed
+ */
+const uint8_t *graphdb_get_blob(struct graphdb_result_t *result,
+                                int row,
+                                const char *col,
+                                int *len);
+
+/**
+ * Get integer values (indexed by column)
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, integer value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `value` must be a valid pointer to store the result
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ */
+int graphdb_get_int_by_index(struct graphdb_result_t *result, int row, int col, int64_t *value);
+
+/**
+ * Get string value (indexed by column)
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `len`: Output parameter, string length
+ *
+ * # Returns
+ * - String value (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `len` must be a valid pointer to store the string length, or NULL if not needed
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ * - The returned pointer must be freed by the caller to avoid memory leaks
+ */
+char *graphdb_get_string_by_index(struct graphdb_result_t *result,
+                                  int row,
+                                  int col,
+                                  int *len);
+
+/**
+ * Get Boolean value (indexed by column)
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, boolean value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `value` must be a valid pointer to store the result
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ */
+int graphdb_get_bool_by_index(struct graphdb_result_t *result, int row, int col, bool *value);
+
+/**
+ * Get floating point values (indexed by column)
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `value`: Output parameter, float value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `value` must be a valid pointer to store the result
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ */
+int graphdb_get_float_by_index(struct graphdb_result_t *result, int row, int col, double *value);
+
+/**
+ * Get binary data (indexed by column)
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `len`: Output parameter, data length (in bytes)
+ *
+ * # Returns
+ * - Data pointer, returns NULL on error
+ *
+ * # Note
+ * The returned pointer's lifetime is bound to the result set; the caller should not free it
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `len` must be a valid pointer to store the data length, or NULL if not needed
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ * - The returned pointer is only valid as long as the result set is not freed
+ */
+const uint8_t *graphdb_get_blob_by_index(struct graphdb_result_t *result,
+                                         int row,
+                                         int col,
+                                         int *len);
+
+/**
+ * Get a value handle (indexed by column)
+ *
+ * Use this for composite values (vertices, edges, paths, lists, maps, vectors and
+ * temporal values), which are navigated with the `graphdb_value_*` family of functions.
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `row`: Row index (starting from 0)
+ * - `col`: Column index (starting from 0)
+ * - `out`: Output parameter, value handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Memory Management
+ * The handle stored in `out` is an independent copy owned by the caller. It stays valid
+ * after the result set is freed and must be released with `graphdb_value_free`.
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `out` must be a valid pointer to store the handle
+ * - `row` must be a valid row index (0 <= row < row count)
+ * - `col` must be a valid column index (0 <= col < column count)
+ */
+int graphdb_get_value_by_index(struct graphdb_result_t *result,
+                               int row,
+                               int col,
+                               struct graphdb_value_handle_t **out);
+
+
+/**
+ * Get column type
+ *
+ * # Arguments
+ * - `result`: Result set handle
+ * - `col`: Column index (starting from 0)
+ *
+ * # Returns
+ * - Column type, returns GRAPHDB_NULL on error
+ *
+ * # Safety
+ * - `result` must be a valid result handle created by `graphdb_execute` or `graphdb_execute_params`
+ * - `col` must be a valid column index (0 <= col < column count)
+ */
+enum graphdb_value_type_t graphdb_column_type(struct graphdb_result_t *result, int col);
+
+/**
+ * Create a session
+ *
+ * # Arguments
+ * - `db`: Database handle
+ * - `session`: Output parameter, session handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `db` must be a valid database handle created by `graphdb_open` or `graphdb_open_v2`
+ * - `session` must be a valid pointer to store the session handle
+ * - The caller is responsible for closing the session using `graphdb_session_close` when done
+ * - The session handle must not be used after closing
+ */
+int graphdb_session_create(struct graphdb_t *db, struct graphdb_session_t **session);
+
+/**
+ * Close the session.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - After calling this function, the session handle becomes invalid and must not be used
+ * - All pending transactions will be rolled back
+ */
+int graphdb_session_close(struct graphdb_session_t *session);
+
+/**
+ * Switch to the image space
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `space_name`: Graph space name (UTF-8 encoded)
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `space_name` must be a valid pointer to a null-terminated UTF-8 string
+ */
+int graphdb_session_use_space(struct graphdb_session_t *session, const char *space_name);
+
+/**
+ * Obtain the current image space
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Current graph space name (UTF-8 encoded), returns NULL if none
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Example
+ * ```c
+ * char* space = graphdb_session_current_space(session);
+ * if (space) {
+ *     printf("Current space: %s\n", space);
+ *     graphdb_free_string(space);  // Must free
+ * }
+ * ```
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - The returned pointer must be freed by the caller to avoid memory leaks
+ */
+char *graphdb_session_current_space(struct graphdb_session_t *session);
+
+/**
+ * Enable the automatic submission mode.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `autocommit`: Whether to enable autocommit
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int graphdb_session_set_autocommit(struct graphdb_session_t *session, bool autocommit);
+
+/**
+ * Enable the automatic submission mode.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Whether autocommit is enabled
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+bool graphdb_session_get_autocommit(struct graphdb_session_t *session);
+
+/**
+ * Get the number of rows affected by the last operation
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Number of rows affected by last operation, returns 0 if session is invalid
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int graphdb_changes(struct graphdb_session_t *session);
+
+/**
+ * The total number of changes since the database was opened has been retrieved.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Total number of changes
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int64_t graphdb_total_changes(struct graphdb_session_t *session);
+
+/**
+ * Obtain the ID of the last vertex that was inserted.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Last inserted vertex ID, returns 0 if none
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int64_t graphdb_last_insert_vertex_id(struct graphdb_session_t *session);
+
+/**
+ * Obtain the ID of the last inserted edge.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Last inserted edge ID, returns 0 if none
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int64_t graphdb_last_insert_edge_id(struct graphdb_session_t *session);
+
+/**
+ * Setting the busy wait timeout
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `timeout_ms`: Timeout in milliseconds
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int graphdb_busy_timeout(struct graphdb_session_t *session, int timeout_ms);
+
+/**
+ * Busy wait timeout has occurred.
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Timeout in milliseconds, returns -1 on error
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int graphdb_busy_timeout_get(struct graphdb_session_t *session);
+
+/**
+ * Setting up an SQL tracing callback
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `callback`: Trace callback function, NULL to disable tracing
+ * - `user_data`: User data pointer, will be passed to the callback
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Example
+ * ```c
+ * extern void my_trace_callback(const char* sql, void* data) {
+ *     printf("Executing: %s\n", sql);
+ * }
+ *
+ * graphdb_trace(session, my_trace_callback, NULL);
+ * ```
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `callback` must be a valid function pointer, or NULL to disable tracing
+ * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ */
+int graphdb_trace(struct graphdb_session_t *session,
+                  graphdb_trace_callback callback,
+                  void *user_data);
+
+/**
+ * Setting up the commit hook
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `callback`: Commit hook callback function, NULL to disable the hook
+ * - `user_data`: User data pointer, will be passed to the callback
+ *
+ * # Returns
+ * - Previous hook user data pointer (if any)
+ *
+ * # Description
+ * The commit hook is called before a transaction is committed. If the callback returns a non-zero value,
+ * the transaction will be rolled back.
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `callback` must be a valid function pointer, or NULL to disable the hook
+ * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ */
+void *graphdb_commit_hook(struct graphdb_session_t *session,
+                          graphdb_commit_hook_callback callback,
+                          void *user_data);
+
+/**
+ * Setting up a rollback hook
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `callback`: Rollback hook callback function, NULL to disable the hook
+ * - `user_data`: User data pointer, will be passed to the callback
+ *
+ * # Returns
+ * - Previous hook user data pointer (if any)
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `callback` must be a valid function pointer, or NULL to disable the hook
+ * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ */
+void *graphdb_rollback_hook(struct graphdb_session_t *session,
+                            graphdb_rollback_hook_callback callback,
+                            void *user_data);
+
+/**
+ * Set up the update hook
+ *
+ * When data in the database changes, the callback function is called
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `callback`: Update hook callback function, NULL to disable the hook
+ * - `user_data`: User data pointer, will be passed to the callback
+ *
+ * # Returns
+ * - Previous hook user data pointer (if any)
+ *
+ * # Callback Parameters
+ * - `operation`: Operation type (1=INSERT, 2=UPDATE, 3=DELETE)
+ * - `database`: Database/space name
+ * - `table`: Table name (empty string for graph database)
+ * - `rowid`: Affected row ID
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `callback` must be a valid function pointer, or NULL to disable the hook
+ * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ */
+void *graphdb_update_hook(struct graphdb_session_t *session,
+                          graphdb_update_hook_callback callback,
+                          void *user_data);
+
+/**
+ * Get the number of rows affected by the last operation
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Number of rows affected, returns 0 on error
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+uint64_t graphdb_session_changes(struct graphdb_session_t *session);
+
+/**
+ * Get the total number of rows affected
+ *
+ * # Arguments
+ * - `session`: Session handle
+ *
+ * # Returns
+ * - Total number of rows affected, returns 0 on error
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+uint64_t graphdb_session_total_changes(struct graphdb_session_t *session);
+
+/**
+ * Get the ID of the last inserted vertex
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `vertex_id`: Output parameter, vertex ID
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code (GRAPHDB_NOTFOUND if no vertex was inserted)
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `vertex_id` must be a valid pointer to store the result
+ */
+int graphdb_session_last_insert_vertex_id(struct graphdb_session_t *session, int64_t *vertex_id);
+
+/**
+ * Get the ID of the last inserted edge
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `edge_id`: Output parameter, edge ID
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code (GRAPHDB_NOTFOUND if no edge was inserted)
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `edge_id` must be a valid pointer to store the result
+ */
+int graphdb_session_last_insert_edge_id(struct graphdb_session_t *session, int64_t *edge_id);
+
+/**
+ * Get session statistics (total changes)
+ *
+ * # Arguments
+ * - `session`: Session handle
+ * - `stats`: Output parameter, statistics structure
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ */
+int graphdb_session_get_statistics(struct graphdb_session_t *session,
+                                   struct SessionStatistics *stats);
+
+/**
+ * Begin a transaction
+ *
+ * # Parameters
+ * - `session`: session handle
+ * - `txn`: output parameter, transaction handle
+ *
+ * # Return
+ * Success: GRAPHDB_OK
+ * Failure: Error code
+ *
+ * # Safety
+ * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `txn` must be a valid pointer to store the transaction handle
+ * - The session must not have been closed
+ * - The caller is responsible for freeing the transaction using `graphdb_txn_free` when done
+ */
+int graphdb_txn_begin(struct graphdb_session_t *session, struct graphdb_txn_t **txn);
+
+/**
+ * Starting a read-only transaction
+ *
+ * # Parameters
  * - `session`: Session handle
+ * - `txn`: Output parameter, transaction handle
  *
  * # Returns
- * - Whether autocommit is enabled
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
  *
  * # Safety
  * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `txn` must be a valid pointer to store the transaction handle
+ * - The session must not have been closed
+ * - The caller is responsible for freeing the transaction using `graphdb_txn_free` when done
  */
-bool graphdb_session_get_autocommit(struct graphdb_session_t *session);
+int graphdb_txn_begin_readonly(struct graphdb_session_t *session, struct graphdb_txn_t **txn);
 
 /**
- * Get the number of rows affected by the last operation
+ * Executing queries in a transaction
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ * - `query`: Query statement (UTF-8 encoding)
+ * - `result`: Output parameter, result set handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - `query` must be a valid pointer to a null-terminated UTF-8 string
+ * - `result` must be a valid pointer to store the result handle
+ * - The transaction must not have been committed or rolled back
+ * - The caller is responsible for freeing the result using `graphdb_result_free` when done
+ */
+int graphdb_txn_execute(struct graphdb_txn_t *txn,
+                        const char *query,
+                        struct graphdb_result_t **result);
+
+/**
+ * Commit transactions
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - The transaction must not have been committed or rolled back already
+ * - The associated session must still be valid
+ * - After calling this function, the transaction handle should be freed using `graphdb_txn_free`
+ */
+int graphdb_txn_commit(struct graphdb_txn_t *txn);
+
+/**
+ * Rolling back transactions
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - The transaction must not have been committed or rolled back already
+ * - The associated session must still be valid
+ * - After calling this function, the transaction handle should be freed using `graphdb_txn_free`
+ */
+int graphdb_txn_rollback(struct graphdb_txn_t *txn);
+
+/**
+ * Creating a savepoint
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ * - `name`: Name of the savepoint (UTF-8 encoding)
+ *
+ * # Returns
+ * - Success: Savepoint ID
+ * - Failure: -1
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
+ * - The transaction must not have been committed or rolled back
+ */
+int64_t graphdb_txn_savepoint(struct graphdb_txn_t *txn,
+                              const char *name);
+
+/**
+ * Release the savepoint.
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ * - `savepoint_id`: ID of the savepoint
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - `savepoint_id` must be a valid savepoint ID returned by `graphdb_txn_savepoint`
+ * - The transaction must not have been committed or rolled back
+ */
+int graphdb_txn_release_savepoint(struct graphdb_txn_t *txn,
+                                  int64_t savepoint_id);
+
+/**
+ * Roll back to the savepoint.
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ * - `savepoint_id`: Savepoint ID
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - `savepoint_id` must be a valid savepoint ID returned by `graphdb_txn_savepoint`
+ * - The transaction must not have been committed or rolled back
+ */
+int graphdb_txn_rollback_to_savepoint(struct graphdb_txn_t *txn,
+                                      int64_t savepoint_id);
+
+/**
+ * Free the transaction handle
+ *
+ * # Parameters
+ * - `txn`: Transaction handle
+ *
+ * # Safety
+ * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
+ * - `txn` can be null (in which case this function does nothing)
+ * - After calling this function, the handle is invalid and must not be used again
+ */
+void graphdb_txn_free(struct graphdb_txn_t *txn);
+
+/**
+ * Release a value handle
  *
  * # Arguments
- * - `session`: Session handle
+ * - `value`: Value handle
  *
  * # Returns
- * - Number of rows affected by last operation, returns 0 if session is invalid
+ * - Success: GRAPHDB_OK
+ * - Failure: Error code
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `value` must be a valid value handle returned by a GraphDB C API function
+ * - After calling this function, the handle becomes invalid and must not be used
  */
-int graphdb_changes(struct graphdb_session_t *session);
+int graphdb_value_free(struct graphdb_value_handle_t *value);
 
 /**
- * The total number of changes since the database was opened has been retrieved.
+ * Get the type of a value
  *
  * # Arguments
- * - `session`: Session handle
+ * - `value`: Value handle
  *
  * # Returns
- * - Total number of changes
+ * - Value type, returns GRAPHDB_NULL on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `value` must be a valid value handle
  */
-int64_t graphdb_total_changes(struct graphdb_session_t *session);
+enum graphdb_value_type_t graphdb_value_type(struct graphdb_value_handle_t *value);
 
 /**
- * Obtain the ID of the last vertex that was inserted.
+ * Get an integer from a value handle
  *
  * # Arguments
- * - `session`: Session handle
+ * - `value`: Value handle
+ * - `out`: Output parameter, integer value
  *
  * # Returns
- * - Last inserted vertex ID, returns 0 if none
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not an integer
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `value` must be a valid value handle
+ * - `out` must be a valid pointer to store the result
  */
-int64_t graphdb_last_insert_vertex_id(struct graphdb_session_t *session);
+int graphdb_value_get_int(struct graphdb_value_handle_t *value, int64_t *out);
 
 /**
- * Obtain the ID of the last inserted edge.
+ * Get a floating-point number from a value handle
  *
  * # Arguments
- * - `session`: Session handle
+ * - `value`: Value handle
+ * - `out`: Output parameter, floating-point value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not a floating-point number
+ *
+ * # Safety
+ * - `value` must be a valid value handle
+ * - `out` must be a valid pointer to store the result
+ */
+int graphdb_value_get_float(struct graphdb_value_handle_t *value, double *out);
+
+/**
+ * Get a boolean from a value handle
+ *
+ * # Arguments
+ * - `value`: Value handle
+ * - `out`: Output parameter, boolean value
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not a boolean
+ *
+ * # Safety
+ * - `value` must be a valid value handle
+ * - `out` must be a valid pointer to store the result
+ */
+int graphdb_value_get_bool(struct graphdb_value_handle_t *value, bool *out);
+
+/**
+ * Get a string from a value handle
+ *
+ * # Arguments
+ * - `value`: Value handle
+ * - `len`: Output parameter, string length
+ *
+ * # Returns
+ * - String value (UTF-8 encoded), returns NULL if the value is not a string
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `value` must be a valid value handle
+ * - `len` must be a valid pointer to store the string length, or NULL if not needed
+ */
+char *graphdb_value_get_string(struct graphdb_value_handle_t *value, int *len);
+
+/**
+ * Get the ID of a vertex
+ *
+ * The ID is an integer value when the vertex was created with an integer ID,
+ * otherwise a string value.
+ *
+ * # Arguments
+ * - `vertex`: Value handle holding a vertex
+ * - `out`: Output parameter, value handle of the ID
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not a vertex
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
+ *
+ * # Safety
+ * - `vertex` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
+ */
+int graphdb_vertex_id(struct graphdb_value_handle_t *vertex, struct graphdb_value_handle_t **out);
+
+/**
+ * Get the number of tags on a vertex
+ *
+ * # Arguments
+ * - `vertex`: Value handle holding a vertex
+ *
+ * # Returns
+ * - Number of tags, returns -1 on error
+ *
+ * # Safety
+ * - `vertex` must be a valid value handle
+ */
+int graphdb_vertex_tag_count(struct graphdb_value_handle_t *vertex);
+
+/**
+ * Get the name of a vertex tag
+ *
+ * # Arguments
+ * - `vertex`: Value handle holding a vertex
+ * - `index`: Tag index (starting from 0)
+ *
+ * # Returns
+ * - Tag name (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
+ *
+ * # Safety
+ * - `vertex` must be a valid value handle
+ */
+char *graphdb_vertex_tag_name(struct graphdb_value_handle_t *vertex, int index);
+
+/**
+ * Get the properties of a vertex as a map
+ *
+ * # Arguments
+ * - `vertex`: Value handle holding a vertex
+ * - `tag`: Tag name (UTF-8 encoded), or NULL for the properties of all tags
+ * - `out`: Output parameter, value handle of the property map
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_NOTFOUND if the vertex has no such tag
+ * - GRAPHDB_MISMATCH if the value is not a vertex
  *
- * # Returns
- * - Last inserted edge ID, returns 0 if none
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `vertex` must be a valid value handle
+ * - `tag` must be NULL or a valid pointer to a null-terminated UTF-8 string
+ * - `out` must be a valid pointer to store the handle
  */
-int64_t graphdb_last_insert_edge_id(struct graphdb_session_t *session);
+int graphdb_vertex_properties(struct graphdb_value_handle_t *vertex,
+                              const char *tag,
+                              struct graphdb_value_handle_t **out);
 
 /**
- * Setting the busy wait timeout
+ * Get a single vertex property
+ *
+ * Properties stored directly on the vertex are searched first, then each tag in order.
  *
  * # Arguments
- * - `session`: Session handle
- * - `timeout_ms`: Timeout in milliseconds
+ * - `vertex`: Value handle holding a vertex
+ * - `name`: Property name (UTF-8 encoded)
+ * - `out`: Output parameter, value handle of the property
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_NOTFOUND if the vertex has no such property
+ * - GRAPHDB_MISMATCH if the value is not a vertex
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `vertex` must be a valid value handle
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_busy_timeout(struct graphdb_session_t *session, int timeout_ms);
+int graphdb_vertex_property(struct graphdb_value_handle_t *vertex,
+                            const char *name,
+                            struct graphdb_value_handle_t **out);
 
 /**
- * Busy wait timeout has occurred.
+ * Get the source vertex ID of an edge
  *
  * # Arguments
- * - `session`: Session handle
+ * - `edge`: Value handle holding an edge
+ * - `out`: Output parameter, value handle of the ID
  *
  * # Returns
- * - Timeout in milliseconds, returns -1 on error
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not an edge
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `edge` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_busy_timeout_get(struct graphdb_session_t *session);
+int graphdb_edge_src(struct graphdb_value_handle_t *edge, struct graphdb_value_handle_t **out);
 
 /**
- * Setting up an SQL tracing callback
+ * Get the destination vertex ID of an edge
  *
  * # Arguments
- * - `session`: Session handle
- * - `callback`: Trace callback function, NULL to disable tracing
- * - `user_data`: User data pointer, will be passed to the callback
+ * - `edge`: Value handle holding an edge
+ * - `out`: Output parameter, value handle of the ID
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
- *
- * # Example
- * ```c
- * extern void my_trace_callback(const char* sql, void* data) {
- *     printf("Executing: %s\n", sql);
- * }
+ * - GRAPHDB_MISMATCH if the value is not an edge
  *
- * graphdb_trace(session, my_trace_callback, NULL);
- * ```
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `callback` must be a valid function pointer, or NULL to disable tracing
- * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ * - `edge` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_trace(struct graphdb_session_t *session,
-                  graphdb_trace_callback callback,
-                  void *user_data);
+int graphdb_edge_dst(struct graphdb_value_handle_t *edge, struct graphdb_value_handle_t **out);
 
 /**
- * Setting up the commit hook
+ * Get the type name of an edge
  *
  * # Arguments
- * - `session`: Session handle
- * - `callback`: Commit hook callback function, NULL to disable the hook
- * - `user_data`: User data pointer, will be passed to the callback
+ * - `edge`: Value handle holding an edge
  *
  * # Returns
- * - Previous hook user data pointer (if any)
+ * - Edge type name (UTF-8 encoded), returns NULL on error
  *
- * # Description
- * The commit hook is called before a transaction is committed. If the callback returns a non-zero value,
- * the transaction will be rolled back.
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `callback` must be a valid function pointer, or NULL to disable the hook
- * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ * - `edge` must be a valid value handle
  */
-void *graphdb_commit_hook(struct graphdb_session_t *session,
-                          graphdb_commit_hook_callback callback,
-                          void *user_data);
+char *graphdb_edge_type(struct graphdb_value_handle_t *edge);
 
 /**
- * Setting up a rollback hook
+ * Get the rank of an edge
  *
  * # Arguments
- * - `session`: Session handle
- * - `callback`: Rollback hook callback function, NULL to disable the hook
- * - `user_data`: User data pointer, will be passed to the callback
+ * - `edge`: Value handle holding an edge
+ * - `out`: Output parameter, edge rank
  *
  * # Returns
- * - Previous hook user data pointer (if any)
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not an edge
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `callback` must be a valid function pointer, or NULL to disable the hook
- * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ * - `edge` must be a valid value handle
+ * - `out` must be a valid pointer to store the result
  */
-void *graphdb_rollback_hook(struct graphdb_session_t *session,
-                            graphdb_rollback_hook_callback callback,
-                            void *user_data);
+int graphdb_edge_rank(struct graphdb_value_handle_t *edge, int64_t *out);
 
 /**
- * Set up the update hook
- *
- * When data in the database changes, the callback function is called
+ * Get the properties of an edge as a map
  *
  * # Arguments
- * - `session`: Session handle
- * - `callback`: Update hook callback function, NULL to disable the hook
- * - `user_data`: User data pointer, will be passed to the callback
+ * - `edge`: Value handle holding an edge
+ * - `out`: Output parameter, value handle of the property map
  *
  * # Returns
- * - Previous hook user data pointer (if any)
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not an edge
  *
- * # Callback Parameters
- * - `operation`: Operation type (1=INSERT, 2=UPDATE, 3=DELETE)
- * - `database`: Database/space name
- * - `table`: Table name (empty string for graph database)
- * - `rowid`: Affected row ID
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `callback` must be a valid function pointer, or NULL to disable the hook
- * - `user_data` is passed to the callback and must remain valid for the lifetime of the callback
+ * - `edge` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-void *graphdb_update_hook(struct graphdb_session_t *session,
-                          graphdb_update_hook_callback callback,
-                          void *user_data);
+int graphdb_edge_properties(struct graphdb_value_handle_t *edge,
+                            struct graphdb_value_handle_t **out);
 
 /**
- * Get the number of rows affected by the last operation
+ * Get a single edge property
  *
  * # Arguments
- * - `session`: Session handle
+ * - `edge`: Value handle holding an edge
+ * - `name`: Property name (UTF-8 encoded)
+ * - `out`: Output parameter, value handle of the property
  *
  * # Returns
- * - Number of rows affected, returns 0 on error
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_NOTFOUND if the edge has no such property
+ * - GRAPHDB_MISMATCH if the value is not an edge
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `edge` must be a valid value handle
+ * - `name` must be a valid pointer to a null-terminated UTF-8 string
+ * - `out` must be a valid pointer to store the handle
  */
-uint64_t graphdb_session_changes(struct graphdb_session_t *session);
+int graphdb_edge_property(struct graphdb_value_handle_t *edge,
+                          const char *name,
+                          struct graphdb_value_handle_t **out);
 
 /**
- * Get the total number of rows affected
+ * Get the length of a path (its number of edges)
+ *
+ * A path of length `n` has `n + 1` vertices.
  *
  * # Arguments
- * - `session`: Session handle
+ * - `path`: Value handle holding a path
  *
  * # Returns
- * - Total number of rows affected, returns 0 on error
+ * - Path length, returns -1 on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `path` must be a valid value handle
  */
-uint64_t graphdb_session_total_changes(struct graphdb_session_t *session);
+int graphdb_path_length(struct graphdb_value_handle_t *path);
 
 /**
- * Get the ID of the last inserted vertex
+ * Get a vertex of a path
  *
  * # Arguments
- * - `session`: Session handle
- * - `vertex_id`: Output parameter, vertex ID
+ * - `path`: Value handle holding a path
+ * - `index`: Vertex index, from 0 (the start vertex) to the path length
+ * - `out`: Output parameter, value handle of the vertex
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code (GRAPHDB_NOTFOUND if no vertex was inserted)
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISMATCH if the value is not a path
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `vertex_id` must be a valid pointer to store the result
+ * - `path` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_session_last_insert_vertex_id(struct graphdb_session_t *session, int64_t *vertex_id);
+int graphdb_path_vertex(struct graphdb_value_handle_t *path,
+                        int index,
+                        struct graphdb_value_handle_t **out);
 
 /**
- * Get the ID of the last inserted edge
+ * Get an edge of a path
  *
  * # Arguments
- * - `session`: Session handle
- * - `edge_id`: Output parameter, edge ID
+ * - `path`: Value handle holding a path
+ * - `index`: Edge index, from 0 to the path length minus one
+ * - `out`: Output parameter, value handle of the edge
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code (GRAPHDB_NOTFOUND if no edge was inserted)
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISMATCH if the value is not a path
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `edge_id` must be a valid pointer to store the result
+ * - `path` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_session_last_insert_edge_id(struct graphdb_session_t *session, int64_t *edge_id);
+int graphdb_path_edge(struct graphdb_value_handle_t *path,
+                      int index,
+                      struct graphdb_value_handle_t **out);
 
 /**
- * Get session statistics (total changes)
+ * Get the number of elements in a list (or set)
  *
  * # Arguments
- * - `session`: Session handle
- * - `stats`: Output parameter, statistics structure
+ * - `list`: Value handle holding a list
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Number of elements, returns -1 on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
+ * - `list` must be a valid value handle
  */
-int graphdb_session_get_statistics(struct graphdb_session_t *session,
-                                   struct SessionStatistics *stats);
+int graphdb_list_size(struct graphdb_value_handle_t *list);
 
 /**
- * Begin a transaction
+ * Get an element of a list (or set)
  *
- * # Parameters
- * - `session`: session handle
- * - `txn`: output parameter, transaction handle
+ * Sets have no defined order, but the order is stable for a given handle.
  *
- * # Return
- * Success: GRAPHDB_OK
- * Failure: Error code
+ * # Arguments
+ * - `list`: Value handle holding a list
+ * - `index`: Element index (starting from 0)
+ * - `out`: Output parameter, value handle of the element
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_RANGE if the index is out of range
+ * - GRAPHDB_MISMATCH if the value is not a list
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `txn` must be a valid pointer to store the transaction handle
- * - The session must not have been closed
- * - The caller is responsible for freeing the transaction using `graphdb_txn_free` when done
+ * - `list` must be a valid value handle
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_txn_begin(struct graphdb_session_t *session, struct graphdb_txn_t **txn);
+int graphdb_list_get(struct graphdb_value_handle_t *list,
+                     int index,
+                     struct graphdb_value_handle_t **out);
 
 /**
- * Starting a read-only transaction
+ * Get the number of entries in a map
  *
- * # Parameters
- * - `session`: Session handle
- * - `txn`: Output parameter, transaction handle
+ * # Arguments
+ * - `map`: Value handle holding a map
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Number of entries, returns -1 on error
  *
  * # Safety
- * - `session` must be a valid session handle created by `graphdb_session_create`
- * - `txn` must be a valid pointer to store the transaction handle
- * - The session must not have been closed
- * - The caller is responsible for freeing the transaction using `graphdb_txn_free` when done
+ * - `map` must be a valid value handle
  */
-int graphdb_txn_begin_readonly(struct graphdb_session_t *session, struct graphdb_txn_t **txn);
+int graphdb_map_size(struct graphdb_value_handle_t *map);
 
 /**
- * Executing queries in a transaction
+ * Get a map key by position
  *
- * # Parameters
- * - `txn`: Transaction handle
- * - `query`: Query statement (UTF-8 encoding)
- * - `result`: Output parameter, result set handle
+ * Keys are ordered lexicographically, so `0..graphdb_map_size` iterates the map
+ * in a stable order.
+ *
+ * # Arguments
+ * - `map`: Value handle holding a map
+ * - `index`: Key index (starting from 0)
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Key (UTF-8 encoded), returns NULL on error
+ *
+ * # Memory Management
+ * The returned string is dynamically allocated and must be freed by the caller using `graphdb_free_string`
+ * to avoid memory leaks.
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - `query` must be a valid pointer to a null-terminated UTF-8 string
- * - `result` must be a valid pointer to store the result handle
- * - The transaction must not have been committed or rolled back
- * - The caller is responsible for freeing the result using `graphdb_result_free` when done
+ * - `map` must be a valid value handle
  */
-int graphdb_txn_execute(struct graphdb_txn_t *txn,
-                        const char *query,
-                        struct graphdb_result_t **result);
+char *graphdb_map_key(struct graphdb_value_handle_t *map, int index);
 
 /**
- * Commit transactions
+ * Look up a map entry by key
  *
- * # Parameters
- * - `txn`: Transaction handle
+ * # Arguments
+ * - `map`: Value handle holding a map
+ * - `key`: Key (UTF-8 encoded)
+ * - `out`: Output parameter, value handle of the entry
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_NOTFOUND if the map has no such key
+ * - GRAPHDB_MISMATCH if the value is not a map
+ *
+ * # Memory Management
+ * The handle stored in `out` is owned by the caller and must be released with `graphdb_value_free`.
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - The transaction must not have been committed or rolled back already
- * - The associated session must still be valid
- * - After calling this function, the transaction handle should be freed using `graphdb_txn_free`
+ * - `map` must be a valid value handle
+ * - `key` must be a valid pointer to a null-terminated UTF-8 string
+ * - `out` must be a valid pointer to store the handle
  */
-int graphdb_txn_commit(struct graphdb_txn_t *txn);
+int graphdb_map_get(struct graphdb_value_handle_t *map,
+                    const char *key,
+                    struct graphdb_value_handle_t **out);
 
 /**
- * Rolling back transactions
+ * Get the dimension of a vector
  *
- * # Parameters
- * - `txn`: Transaction handle
+ * # Arguments
+ * - `vector`: Value handle holding a vector
  *
  * # Returns
- * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - Vector dimension, returns -1 on error
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - The transaction must not have been committed or rolled back already
- * - The associated session must still be valid
- * - After calling this function, the transaction handle should be freed using `graphdb_txn_free`
+ * - `vector` must be a valid value handle
  */
-int graphdb_txn_rollback(struct graphdb_txn_t *txn);
+int graphdb_vector_dimension(struct graphdb_value_handle_t *vector);
 
 /**
- * Creating a savepoint
+ * Copy the components of a vector into a caller-provided buffer
  *
- * # Parameters
- * - `txn`: Transaction handle
- * - `name`: Name of the savepoint (UTF-8 encoding)
+ * Sparse vectors are expanded to their dense form.
+ *
+ * # Arguments
+ * - `vector`: Value handle holding a vector
+ * - `buf`: Output buffer
+ * - `capacity`: Number of floats `buf` can hold
  *
  * # Returns
- * - Success: Savepoint ID
- * - Failure: -1
+ * - Number of floats written, returns -1 on error or if `capacity` is smaller than the dimension
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - `name` must be a valid pointer to a null-terminated UTF-8 string
- * - The transaction must not have been committed or rolled back
+ * - `vector` must be a valid value handle
+ * - `buf` must be a valid pointer to at least `capacity` floats
  */
-int64_t graphdb_txn_savepoint(struct graphdb_txn_t *txn,
-                              const char *name);
+int graphdb_vector_data(struct graphdb_value_handle_t *vector, float *buf, uintptr_t capacity);
 
 /**
- * Release the savepoint.
+ * Decompose a date or datetime value
  *
- * # Parameters
- * - `txn`: Transaction handle
- * - `savepoint_id`: ID of the savepoint
+ * # Arguments
+ * - `value`: Value handle holding a date or datetime
+ * - `year`, `month`, `day`: Output parameters, each may be NULL if not needed
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_MISMATCH if the value is not a date or datetime
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - `savepoint_id` must be a valid savepoint ID returned by `graphdb_txn_savepoint`
- * - The transaction must not have been committed or rolled back
+ * - `value` must be a valid value handle
+ * - Non-NULL output pointers must be valid for writes
  */
-int graphdb_txn_release_savepoint(struct graphdb_txn_t *txn,
-                                  int64_t savepoint_id);
+int graphdb_value_get_date(struct graphdb_value_handle_t *value,
+                           int32_t *year,
+                           uint32_t *month,
+                           uint32_t *day);
 
 /**
- * Roll back to the savepoint.
+ * Decompose a time or datetime value
  *
- * # Parameters
- * - `txn`: Transaction handle
- * - `savepoint_id`: Savepoint ID
+ * # Arguments
+ * - `value`: Value handle holding a time or datetime
+ * - `hour`, `minute`, `second`, `microsecond`: Output parameters, each may be NULL if not needed
  *
  * # Returns
  * - Success: GRAPHDB_OK
- * - Failure: Error code
+ * - GRAPHDB_MISMATCH if the value is not a time or datetime
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - `savepoint_id` must be a valid savepoint ID returned by `graphdb_txn_savepoint`
- * - The transaction must not have been committed or rolled back
+ * - `value` must be a valid value handle
+ * - Non-NULL output pointers must be valid for writes
  */
-int graphdb_txn_rollback_to_savepoint(struct graphdb_txn_t *txn,
-                                      int64_t savepoint_id);
+int graphdb_value_get_time(struct graphdb_value_handle_t *value,
+                           uint32_t *hour,
+                           uint32_t *minute,
+                           uint32_t *second,
+                           uint32_t *microsecond);
 
 /**
- * Free the transaction handle
+ * Decompose a duration value
  *
- * # Parameters
- * - `txn`: Transaction handle
+ * Months, days and microseconds are kept separate because months and days do not
+ * have a fixed length.
+ *
+ * # Arguments
+ * - `value`: Value handle holding a duration
+ * - `months`, `days`, `microseconds`: Output parameters, each may be NULL if not needed
+ *
+ * # Returns
+ * - Success: GRAPHDB_OK
+ * - GRAPHDB_MISMATCH if the value is not a duration
  *
  * # Safety
- * - `txn` must be a valid transaction handle created by `graphdb_txn_begin` or `graphdb_txn_begin_readonly`
- * - `txn` can be null (in which case this function does nothing)
- * - After calling this function, the handle is invalid and must not be used again
+ * - `value` must be a valid value handle
+ * - Non-NULL output pointers must be valid for writes
  */
-void graphdb_txn_free(struct graphdb_txn_t *txn);
+int graphdb_value_get_duration(struct graphdb_value_handle_t *value,
+                               int32_t *months,
+                               int32_t *days,
+                               int64_t *microseconds);
+
@@ -4,6 +4,10 @@ mod server_main {
     use graphdb::api;
     use graphdb::config::logging;
     use graphdb::config::Config;
+    use graphdb::core::error::{DBError, DBResult};
+    use graphdb::storage::summarize_data_dir;
+    use graphdb::transaction::wal::{WalInspector, WalSegmentReport};
+    use std::path::Path;
 
     #[derive(Parser)]
     #[clap(version = "0.1.0", author = "GraphDB Contributors")]
@@ -18,6 +22,43 @@ mod server_main {
             #[clap(short, long)]
             query: String,
         },
+        /// Inspect WAL segments or a data directory offline
+        Inspect {
+            #[clap(subcommand)]
+            target: InspectTarget,
+        },
+    }
+
+    #[derive(clap::Subcommand)]
+    enum InspectTarget {
+        /// Dump and verify WAL records
+        Wal {
+            /// WAL directory or a single segment file
+            path: String,
+            /// Print the report as JSON
+            #[clap(long)]
+            json: bool,
+            /// Only verify checksums and report corruption, without dumping records
+            #[clap(long)]
+            verify: bool,
+            /// Only show records with LSN >= this value
+            #[clap(long)]
+            from_lsn: Option<u64>,
+            /// Only show records with LSN <= this value
+            #[clap(long)]
+            to_lsn: Option<u64>,
+            /// Truncate corrupted segments after their last valid record
+            #[clap(long)]
+            truncate: bool,
+        },
+        /// Summarize a database work directory
+        Data {
+            /// Work directory containing data/, wal/, schema/ ...
+            path: String,
+            /// Print the summary as JSON
+            #[clap(long)]
+            json: bool,
+        },
     }
 
     pub fn main() {
@@ -95,6 +136,7 @@ mod server_main {
                 logging::shutdown();
                 result
             }
+            Cli::Inspect { target } => run_inspect(target),
         };
 
         if let Err(e) = result {
@@ -102,6 +144,188 @@ mod server_main {
             std::process::exit(1);
         }
     }
+
+    fn run_inspect(target: InspectTarget) -> DBResult<()> {
+        match target {
+            InspectTarget::Wal {
+                path,
+                json,
+                verify,
+                from_lsn,
+                to_lsn,
+                truncate,
+            } => {
+                let path = Path::new(&path);
+                let inspector = WalInspector::new().with_decode_payloads(!verify);
+                let mut reports = if path.is_dir() {
+                    inspector.inspect_dir(path)
+                } else {
+                    inspector.inspect_file(path).map(|r| vec![r])
+                }
+                .map_err(|e| DBError::storage(e.to_string()))?;
+
+                let from = from_lsn.unwrap_or(0);
+                let to = to_lsn.unwrap_or(u64::MAX);
+                for report in &mut reports {
+                    report.records.retain(|r| r.lsn >= from && r.lsn <= to);
+                    if verify {
+                        report.records.clear();
+                    }
+                }
+
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&reports)?);
+                } else {
+                    for report in &reports {
+                        print_wal_report(report);
+                    }
+                }
+
+                let corrupted: usize = reports.iter().map(|r| r.corruptions.len()).sum();
+                if truncate {
+                    for report in reports.iter().filter(|r| !r.is_clean()) {
+                        if report.header.is_none() {
+                            eprintln!(
+                                "Skipped {}: file header is invalid, nothing was truncated",
+                                report.path.display()
+                            );
+                            continue;
+                        }
+                        let removed = inspector
+                            .truncate_to_valid(&report.path)
+                            .map_err(|e| DBError::storage(e.to_string()))?;
+                        eprintln!(
+                            "Truncated {} at offset {} ({} bytes removed)",
+                            report.path.display(),
+                            report.valid_end,
+                            removed
+                        );
+                    }
+                } else if verify && corrupted > 0 {
+                    return Err(DBError::storage(format!(
+                        "{} corruption(s) found in WAL",
+                        corrupted
+                    )));
+                }
+                Ok(())
+            }
+            InspectTarget::Data { path, json } => {
+                let summary = summarize_data_dir(Path::new(&path))?;
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&summary)?);
+                    return Ok(());
+                }
+
+                println!("Data directory: {}", summary.root.display());
+                println!(
+                    "Format version: {}",
+                    summary.format_version.as_deref().unwrap_or("-")
+                );
+                println!("Total size: {} bytes", summary.total_size_bytes);
+                for space in &summary.spaces {
+                    println!("Space {} (id {})", space.space_name, space.space_id);
+                    for tag in &space.tags {
+                        println!(
+                            "  tag  {} (id {}, {} properties)",
+                            tag.name, tag.id, tag.property_count
+                        );
+                    }
+                    for edge in &space.edge_types {
+                        println!(
+                            "  edge {} (id {}, {} properties)",
+                            edge.name, edge.id, edge.property_count
+                        );
+                    }
+                }
+                for (kind, tables) in [
+                    ("Vertex", &summary.vertex_tables),
+                    ("Edge", &summary.edge_tables),
+                ] {
+                    println!("{} tables: {}", kind, tables.len());
+                    for table in tables {
+                        println!(
+                            "  {:<16} {:<20} {:>4} files {:>12} bytes",
+                            table.dir_name,
+                            table.label.as_deref().unwrap_or("?"),
+                            table.file_count,
+                            table.size_bytes
+                        );
+                    }
+                }
+                println!(
+                    "WAL: {} segment(s), {} bytes",
+                    summary.wal.segment_count, summary.wal.size_bytes
+                );
+                for checkpoint in &summary.checkpoints {
+                    println!(
+                        "Checkpoint {}: wal_lsn={} timestamp={} size={} bytes",
+                        checkpoint.checkpoint_id,
+                        checkpoint.wal_lsn,
+                        checkpoint.timestamp,
+                        checkpoint.size_bytes
+                    );
+                }
+                for snapshot in &summary.snapshots {
+                    println!(
+                        "Snapshot {}: wal_lsn={} checkpoint_seq={} vertices={} edges={} size={} bytes",
+                        snapshot.id,
+                        snapshot.wal_lsn,
+                        snapshot.checkpoint_seq,
+                        snapshot.vertex_count,
+                        snapshot.edge_count,
+                        snapshot.size_bytes
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn print_wal_report(report: &WalSegmentReport) {
+        println!("== {} ({} bytes)", report.path.display(), report.file_size);
+        if let Some(header) = &report.header {
+            println!(
+                "   version={} thread={} checkpoint_seq={} start_lsn={}",
+                header.version, header.thread_id, header.checkpoint_seq, header.start_lsn
+            );
+        }
+        match (report.first_lsn(), report.last_lsn()) {
+            (Some(first), Some(last)) => println!(
+                "   {} record(s), LSN {}..={}, valid through offset {}",
+                report.records.len(),
+                first,
+                last,
+                report.valid_end
+            ),
+            _ => println!("   valid through offset {}", report.valid_end),
+        }
+        for record in &report.records {
+            let checksum = match record.checksum_valid {
+                Some(true) => "ok",
+                Some(false) => "BAD",
+                None => "-",
+            };
+            println!(
+                "   @{:<10} lsn={:<10} prev={:<10} ts={:<8} {:<18} {:<6} len={:<6} crc={}{}",
+                record.offset,
+                record.lsn,
+                record.prev_lsn,
+                record.timestamp,
+                record.op_type,
+                record.record_type,
+                record.length,
+                checksum,
+                record
+                    .summary
+                    .as_ref()
+                    .map(|s| format!(" {}", s))
+                    .unwrap_or_default()
+            );
+        }
+        for corruption in &report.corruptions {
+            println!("   CORRUPT @{}: {}", corruption.offset, corruption.reason);
+        }
+    }
 }
 
 #[cfg(feature = "server")]
@@ -215,6 +215,8 @@ impl TestDb {
             auto_commit: true,
             transaction_id: None,
             parameters: None,
+            user_name: None,
+            role: None,
         };
         let result = self.query_api.execute(query, ctx)?;
 
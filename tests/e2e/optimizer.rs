@@ -317,6 +317,58 @@ mod profile {
     }
 }
 
+/// Materialized view substitution tests
+mod materialized_view {
+    use super::*;
+    use graphdb::core::Value;
+
+    fn view_state(db: &mut crate::common::TestDb) -> Value {
+        let result = db
+            .execute_query("SHOW MATERIALIZED VIEWS")
+            .expect("SHOW MATERIALIZED VIEWS should succeed");
+        result.rows[0].values["state"].clone()
+    }
+
+    /// A query with the text of a view definition is answered from the view
+    #[test]
+    fn test_query_answered_from_view() {
+        let mut db = create_test_db();
+        setup_test_space(
+            &mut db,
+            "e2e_optimizer_view",
+            &["CREATE TAG person(name: STRING, age: INT)"],
+            &[],
+        )
+        .expect("Failed to setup test space");
+
+        for i in 0..3 {
+            db.execute_query(&format!(
+                "INSERT VERTEX person(name, age) VALUES 'p{}': ('Person_{}', {})",
+                i,
+                i,
+                20 + i
+            ))
+            .expect("INSERT should succeed");
+        }
+
+        let query = "MATCH (p:person) RETURN p.name AS name ORDER BY name";
+        db.execute_query(&format!("CREATE MATERIALIZED VIEW people AS {}", query))
+            .expect("CREATE MATERIALIZED VIEW should succeed");
+
+        // Views with ORDER BY are not maintained incrementally, so a write marks it stale
+        db.execute_query("INSERT VERTEX person(name, age) VALUES 'p3': ('Person_3', 23)")
+            .expect("INSERT should succeed");
+        assert_eq!(view_state(&mut db), Value::String("STALE".to_string()));
+
+        // Reading through the view refreshes it before scanning
+        let result = db
+            .execute_query(&format!("{};", query))
+            .expect("Query matching the view should succeed");
+        assert_eq!(result.rows.len(), 4);
+        assert_eq!(view_state(&mut db), Value::String("FRESH".to_string()));
+    }
+}
+
 /// Cleanup tests
 mod cleanup {
     use super::*;
@@ -336,6 +388,7 @@ mod cleanup {
             "e2e_optimizer_explain",
             "e2e_optimizer_dot",
             "e2e_optimizer_profile",
+            "e2e_optimizer_view",
         ];
 
         for space in &spaces {
@@ -18,11 +18,11 @@ use std::time::Duration;
 
 use graphdb::api::core::SpaceConfig;
 use graphdb::api::embedded::{
-    BatchConfig, BatchError, BatchItemType, BatchResult, DatabaseConfig, GraphDatabase,
-    QueryResult, ResultMetadata, Row, SyncMode, TransactionConfig,
+    BatchConfig, BatchError, BatchItemType, BatchResult, DatabaseConfig, GraphDatabase, GraphTag,
+    QueryResult, ResultMetadata, Row, Session, SyncMode, TransactionConfig,
 };
 use graphdb::core::types::VertexId;
-use graphdb::core::{Edge, Value, Vertex};
+use graphdb::core::{DateValue, Edge, Value, Vertex};
 use graphdb::storage::GraphStorage;
 
 /// Test the database wrapper to keep the temporary catalog valid
@@ -256,6 +256,183 @@ fn test_session_batch_inserter() {
     assert_eq!(inserter.batch_size(), 100);
 }
 
+#[test]
+fn test_session_call_in_transactions_commits_batches() {
+    let test_db = create_test_database();
+    let db = &test_db.db;
+    let mut session = db.session().expect("创建会话失败");
+
+    db.create_space("test_space", SpaceConfig::default())
+        .expect("创建空间失败");
+    session.use_space("test_space").expect("切换空间失败");
+    session
+        .execute("CREATE TAG person(age int)")
+        .expect("创建标签失败");
+
+    // Each batch runs in a transaction of the embedded transaction manager
+    let result = session
+        .execute(
+            "UNWIND [1, 2, 3] AS r \
+             CALL { INSERT VERTEX person(age) VALUES r:(r) } IN TRANSACTIONS OF 2 ROWS",
+        )
+        .expect("批量写入失败");
+    assert_eq!(result.len(), 2);
+
+    let result = session
+        .execute("MATCH (v:person) RETURN v.age")
+        .expect("查询失败");
+    assert_eq!(result.len(), 3);
+}
+
+/// Ages returned by a prepared query, sorted
+fn prepared_ages(
+    session: &Session<GraphStorage>,
+    query: &str,
+    name: &str,
+    value: Value,
+) -> Vec<i64> {
+    let mut params = HashMap::new();
+    params.insert(name.to_string(), value);
+    let result = session
+        .execute_prepared(query, params)
+        .expect("执行预编译查询失败");
+    let mut ages: Vec<i64> = result.iter().filter_map(|row| row.get_int("age")).collect();
+    ages.sort();
+    ages
+}
+
+#[test]
+fn test_session_prepared_query_rebinds_parameters() {
+    let test_db = create_test_database();
+    let db = &test_db.db;
+    let mut session = db.session().expect("创建会话失败");
+
+    db.create_space("test_space", SpaceConfig::default())
+        .expect("创建空间失败");
+    session.use_space("test_space").expect("切换空间失败");
+    session
+        .execute("CREATE TAG person(age int)")
+        .expect("创建标签失败");
+    session
+        .execute("CREATE TAG INDEX idx_person_age ON person(age)")
+        .expect("创建索引失败");
+    session
+        .execute("INSERT VERTEX person(age) VALUES 1:(20), 2:(30), 3:(40)")
+        .expect("插入顶点失败");
+
+    // The plan is cached and optimized once; every execution binds its own value
+    let filter = "MATCH (v:person) WHERE v.age > $min RETURN v.age AS age";
+    session.prepare(filter).expect("预编译查询失败");
+    assert_eq!(
+        prepared_ages(&session, filter, "min", Value::Int(25)),
+        vec![30, 40]
+    );
+    assert_eq!(
+        prepared_ages(&session, filter, "min", Value::Int(35)),
+        vec![40]
+    );
+    assert_eq!(
+        prepared_ages(&session, filter, "min", Value::Int(25)),
+        vec![30, 40]
+    );
+
+    let lookup = "MATCH (v:person {age: $age}) RETURN v.age AS age";
+    session.prepare(lookup).expect("预编译查询失败");
+    assert_eq!(
+        prepared_ages(&session, lookup, "age", Value::Int(20)),
+        vec![20]
+    );
+    assert_eq!(
+        prepared_ages(&session, lookup, "age", Value::Int(40)),
+        vec![40]
+    );
+    assert!(prepared_ages(&session, lookup, "age", Value::Int(50)).is_empty());
+}
+
+#[test]
+fn test_session_prepared_insert_rebinds_parameters() {
+    let test_db = create_test_database();
+    let db = &test_db.db;
+    let mut session = db.session().expect("创建会话失败");
+
+    db.create_space("test_space", SpaceConfig::default())
+        .expect("创建空间失败");
+    session.use_space("test_space").expect("切换空间失败");
+    session
+        .execute("CREATE TAG person(age int)")
+        .expect("创建标签失败");
+
+    let insert = "INSERT VERTEX person(age) VALUES $id:($age)";
+    session.prepare(insert).expect("预编译插入失败");
+    for (id, age) in [(1i64, 20), (2, 30), (3, 40)] {
+        let mut params = HashMap::new();
+        params.insert("id".to_string(), Value::BigInt(id));
+        params.insert("age".to_string(), Value::Int(age));
+        session
+            .execute_prepared(insert, params)
+            .expect("执行预编译插入失败");
+    }
+
+    let result = session
+        .execute("MATCH (v:person) RETURN v.age AS age")
+        .expect("查询失败");
+    let mut ages: Vec<i64> = result.iter().filter_map(|row| row.get_int("age")).collect();
+    ages.sort();
+    assert_eq!(ages, vec![20, 30, 40]);
+}
+
+#[derive(Debug, Clone, PartialEq, GraphTag)]
+#[graph(tag = "event")]
+struct Event {
+    #[graph(vid)]
+    id: i64,
+    day: DateValue,
+    labels: Vec<String>,
+    scores: Option<Vec<i64>>,
+}
+
+#[test]
+fn test_session_typed_update_round_trips_dates_and_lists() {
+    let test_db = create_test_database();
+    let db = &test_db.db;
+    let mut session = db.session().expect("创建会话失败");
+
+    db.create_space("test_space", SpaceConfig::default())
+        .expect("创建空间失败");
+    session.use_space("test_space").expect("切换空间失败");
+    session.create_tag_schema::<Event>().expect("创建标签失败");
+
+    let mut event = Event {
+        id: 1,
+        day: DateValue {
+            year: 2024,
+            month: 1,
+            day: 31,
+        },
+        labels: vec!["launch".to_string()],
+        scores: None,
+    };
+    assert_eq!(
+        session
+            .insert_vertices(std::slice::from_ref(&event))
+            .expect("插入顶点失败"),
+        1
+    );
+
+    event.day = DateValue {
+        year: 2024,
+        month: 2,
+        day: 29,
+    };
+    event.labels = vec!["say \"hi\"".to_string(), "a\\b".to_string()];
+    event.scores = Some(vec![3, 1, 2]);
+    assert!(session.update_vertex(&event).expect("更新顶点失败"));
+    assert_eq!(
+        session.fetch_vertex::<Event>(1i64).expect("读取顶点失败"),
+        Some(event)
+    );
+}
+
 // ==================== Transaction Testing ====================
 
 #[test]
@@ -398,6 +398,7 @@ fn create_test_config() -> AuthConfig {
         default_username: "root".to_string(),
         default_password: "root".to_string(),
         force_change_default_password: true,
+        ..Default::default()
     }
 }
 
@@ -659,6 +660,7 @@ fn test_password_authenticator_default() {
         default_username: "admin".to_string(),
         default_password: "admin123".to_string(),
         force_change_default_password: false,
+        ..Default::default()
     };
 
     let auth = PasswordAuthenticator::new_default(config);
@@ -708,6 +710,7 @@ fn test_password_authenticator_login_attempts_limit() {
         default_username: "root".to_string(),
         default_password: "root".to_string(),
         force_change_default_password: false,
+        ..Default::default()
     };
 
     let auth = PasswordAuthenticator::new(|_username: &str, _password: &str| Ok(false), config);
@@ -297,6 +297,81 @@ async fn test_graph_service_permission_enforcement() {
     );
 }
 
+/// Test that roles and privileges can only be managed by administrators
+#[tokio::test]
+async fn test_graph_service_privilege_statements_require_admin() {
+    let mut config = Config::default();
+    config.server.auth.enable_authorize = false;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("test.db");
+    let storage = Arc::new(SyncWrapper::new(
+        GraphStorage::new_with_path(db_path).expect("Failed to create storage"),
+    ));
+
+    let graph_service = GraphService::new(config, storage).await;
+
+    let root_session = graph_service
+        .authenticate("root", "root")
+        .await
+        .expect("Root auth should succeed");
+    let root_sid = root_session.id();
+
+    graph_service
+        .execute(root_sid, "CREATE SPACE acl_space (vid_type=INT64)")
+        .await
+        .expect("Root: CREATE SPACE should succeed");
+    graph_service
+        .execute(root_sid, "USE acl_space")
+        .await
+        .expect("Root: USE should succeed");
+    graph_service
+        .execute(root_sid, "CREATE TAG Person(name STRING, ssn STRING)")
+        .await
+        .expect("Root: CREATE TAG should succeed");
+    graph_service
+        .execute(root_sid, "CREATE ROLE analyst")
+        .await
+        .expect("Root: CREATE ROLE should succeed");
+
+    // A USER role may read and write data, but not hand out privileges
+    let user_session = graph_service
+        .authenticate("testuser", "test123")
+        .await
+        .expect("User auth should succeed");
+    let user_sid = user_session.id();
+    graph_service
+        .get_permission_manager()
+        .grant_role("testuser", 1, graphdb::core::RoleType::User)
+        .expect("Grant USER role should succeed");
+    graph_service
+        .execute(user_sid, "USE acl_space")
+        .await
+        .expect("USE should succeed");
+
+    for stmt in [
+        "CREATE ROLE escalated",
+        "GRANT READ ON TAG Person(ssn) TO analyst",
+        "GRANT ROLE analyst TO testuser",
+        "GRANT ROLE ADMIN ON acl_space TO testuser",
+        "REVOKE ROLE USER ON acl_space FROM testuser",
+    ] {
+        let result = graph_service.execute(user_sid, stmt).await;
+        assert!(
+            result
+                .as_ref()
+                .is_err_and(|e| e.contains("Permission check failed")),
+            "Non-admin should be denied `{}`: {:?}",
+            stmt,
+            result
+        );
+    }
+
+    graph_service
+        .execute(root_sid, "GRANT READ ON TAG Person(ssn) TO analyst")
+        .await
+        .expect("Root: GRANT privilege should succeed");
+}
+
 /// Integration test: Complete workflow from storage to query execution
 #[test]
 fn test_complete_storage_to_query_workflow() {
@@ -316,6 +391,8 @@ fn test_complete_storage_to_query_workflow() {
         auto_commit: true,
         transaction_id: None,
         parameters: None,
+        user_name: None,
+        role: None,
     };
 
     let result = query_api.execute("CREATE SPACE workflow_test (vid_type=STRING)", request);
@@ -5,6 +5,8 @@
 //! - Schema management (spaces, tags, edge types, indexes)
 //! - Data browsing
 //! - Graph data queries
+//! - Published query endpoints
+//! - Streaming queries with typed parameters
 
 #![cfg(feature = "server")]
 
@@ -323,3 +325,102 @@ async fn test_auth_middleware_invalid_session() {
     let response = app.oneshot(request).await.unwrap();
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
+
+/// Test that concurrent publishes of one endpoint get distinct versions
+#[tokio::test]
+async fn test_concurrent_endpoint_publishes_get_distinct_versions() {
+    use graphdb::api::server::web::models::endpoint::EndpointItem;
+    use graphdb::api::server::web::storage::{MetadataStorage, SqliteStorage};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let url = format!(
+        "sqlite://{}?mode=rwc",
+        temp_dir.path().join("metadata.db").display()
+    );
+    let storage = Arc::new(SqliteStorage::new(&url).await.unwrap());
+
+    let publishes = (0..8).map(|i| {
+        let storage = storage.clone();
+        tokio::spawn(async move {
+            let item: EndpointItem = serde_json::from_value(json!({
+                "name": "adults",
+                "version": 0,
+                "query": format!("MATCH (p:person) WHERE p.age > {} RETURN p", i),
+                "description": null,
+                "space": null,
+                "params": [],
+                "required_role": null,
+                "response": {},
+                "published_by": "root",
+                "created_at": "2026-01-01T00:00:00Z"
+            }))
+            .unwrap();
+            storage.add_endpoint(&item).await
+        })
+    });
+
+    let mut versions = Vec::new();
+    for publish in publishes.collect::<Vec<_>>() {
+        versions.push(publish.await.unwrap().expect("publish should succeed"));
+    }
+    versions.sort();
+    assert_eq!(versions, (1..=8).collect::<Vec<i64>>());
+
+    let latest = storage.get_endpoint("adults", None).await.unwrap();
+    assert_eq!(latest.version, 8);
+}
+
+/// Test that the streaming query endpoint binds typed parameters
+#[tokio::test]
+async fn test_stream_query_binds_typed_parameters() {
+    let (web_state, session_id) = create_test_web_state().await;
+    let app = graphdb::api::server::http::router::create_router(web_state.core_state, None);
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/v1/query/stream")
+        .header("content-type", "application/json")
+        .header("X-Session-ID", session_id.to_string())
+        .body(Body::from(
+            json!({
+                "query": "RETURN $day AS day, $n + 1 AS next",
+                "parameters": {
+                    "day": {"$date": "2024-01-31"},
+                    "n": 41
+                }
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(!body.contains("event: error"), "unexpected error: {}", body);
+    assert!(body.contains(r#""day":"2024-01-31""#), "{}", body);
+    assert!(body.contains(r#""next":42"#), "{}", body);
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/v1/query/stream")
+        .header("content-type", "application/json")
+        .header("X-Session-ID", session_id.to_string())
+        .body(Body::from(
+            json!({
+                "query": "RETURN $day AS day",
+                "parameters": {"day": {"$date": "not a date"}}
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(body.contains("INVALID_PARAMETER"), "{}", body);
+}